@@ -8,6 +8,7 @@ use crate::{
     ir::{
         repr::{IntermediateRepr, Walker},
         Class, Client, Enum, EnumValue, Field, FunctionNode, RetryPolicy, TemplateString, TestCase,
+        TypeAlias,
     },
 };
 use anyhow::{Context, Result};
@@ -20,6 +21,7 @@ pub type FunctionWalker<'a> = Walker<'a, &'a FunctionNode>;
 pub type EnumWalker<'a> = Walker<'a, &'a Enum>;
 pub type EnumValueWalker<'a> = Walker<'a, &'a EnumValue>;
 pub type ClassWalker<'a> = Walker<'a, &'a Class>;
+pub type TypeAliasWalker<'a> = Walker<'a, &'a TypeAlias>;
 pub type TemplateStringWalker<'a> = Walker<'a, &'a TemplateString>;
 pub type ClientWalker<'a> = Walker<'a, &'a Client>;
 pub type RetryPolicyWalker<'a> = Walker<'a, &'a RetryPolicy>;
@@ -29,6 +31,7 @@ pub type ClassFieldWalker<'a> = Walker<'a, &'a Field>;
 pub trait IRHelper {
     fn find_enum(&self, enum_name: &str) -> Result<EnumWalker<'_>>;
     fn find_class(&self, class_name: &str) -> Result<ClassWalker<'_>>;
+    fn find_type_alias(&self, alias_name: &str) -> Result<TypeAliasWalker<'_>>;
     fn find_function(&self, function_name: &str) -> Result<FunctionWalker<'_>>;
     fn find_client(&self, client_name: &str) -> Result<ClientWalker<'_>>;
     fn find_retry_policy(&self, retry_policy_name: &str) -> Result<RetryPolicyWalker<'_>>;
@@ -88,6 +91,20 @@ impl IRHelper for IntermediateRepr {
         }
     }
 
+    fn find_type_alias<'a>(&'a self, alias_name: &str) -> Result<TypeAliasWalker<'a>> {
+        match self.walk_type_aliases().find(|e| e.name() == alias_name) {
+            Some(e) => Ok(e),
+            None => {
+                // Get best match.
+                let aliases = self
+                    .walk_type_aliases()
+                    .map(|e| e.name())
+                    .collect::<Vec<_>>();
+                error_not_found!("type alias", alias_name, &aliases)
+            }
+        }
+    }
+
     fn find_function<'a>(&'a self, function_name: &str) -> Result<FunctionWalker<'a>> {
         match self.walk_functions().find(|f| f.name() == function_name) {
             Some(f) => match f.item.elem {
@@ -197,6 +214,11 @@ impl IRHelper for IntermediateRepr {
         let (unconstrained_type, _) = field_type.distribute_constraints();
         match (value, unconstrained_type) {
 
+            (value, FieldType::RecursiveTypeAlias(name)) => {
+                let target = self.find_type_alias(name)?.target().clone();
+                self.distribute_type(value, target)
+            }
+
             (BamlValue::String(s), FieldType::Primitive(TypeValue::String)) => Ok(BamlValueWithMeta::String(s, field_type)),
             (BamlValue::String(_), _) => anyhow::bail!("Could not unify Strinig with {:?}", field_type),
 
@@ -212,6 +234,15 @@ impl IRHelper for IntermediateRepr {
             (BamlValue::Null, FieldType::Primitive(TypeValue::Null)) => Ok(BamlValueWithMeta::Null(field_type)),
             (BamlValue::Null, _) => anyhow::bail!("Could not unify Null with {:?}", field_type),
 
+            (BamlValue::Date(d), FieldType::Primitive(TypeValue::Date)) => Ok(BamlValueWithMeta::Date(d, field_type)),
+            (BamlValue::Date(_), _) => anyhow::bail!("Could not unify Date with {:?}", field_type),
+
+            (BamlValue::DateTime(d), FieldType::Primitive(TypeValue::DateTime)) => Ok(BamlValueWithMeta::DateTime(d, field_type)),
+            (BamlValue::DateTime(_), _) => anyhow::bail!("Could not unify DateTime with {:?}", field_type),
+
+            (BamlValue::Duration(d), FieldType::Primitive(TypeValue::Duration)) => Ok(BamlValueWithMeta::Duration(d, field_type)),
+            (BamlValue::Duration(_), _) => anyhow::bail!("Could not unify Duration with {:?}", field_type),
+
             (BamlValue::Map(pairs), FieldType::Map(k,val_type)) => {
                 let mapped_fields: BamlMap<String, BamlValueWithMeta<FieldType>> =
                     pairs
@@ -276,6 +307,9 @@ pub fn infer_type<'a>(value: &'a BamlValue) -> FieldType {
        BamlValue::Float(_) => FieldType::Primitive(TypeValue::Float),
        BamlValue::String(_) => FieldType::Primitive(TypeValue::String),
        BamlValue::Null => FieldType::Primitive(TypeValue::Null),
+       BamlValue::Date(_) => FieldType::Primitive(TypeValue::Date),
+       BamlValue::DateTime(_) => FieldType::Primitive(TypeValue::DateTime),
+       BamlValue::Duration(_) => FieldType::Primitive(TypeValue::Duration),
        BamlValue::Map(pairs) => match pairs.iter().next() {
            Some((k,v)) => FieldType::Map(Box::new(FieldType::Primitive(TypeValue::String)), Box::new( infer_type(v) )),
            None => FieldType::Map(Box::new(FieldType::Primitive(TypeValue::String)), Box::new(FieldType::Primitive(TypeValue::Null)))
@@ -1,5 +1,5 @@
 use baml_types::{
-    BamlMap, BamlValue, BamlValueWithMeta, Constraint, ConstraintLevel, FieldType, LiteralValue, TypeValue
+    temporal, BamlMap, BamlValue, BamlValueWithMeta, Constraint, ConstraintLevel, FieldType, LiteralValue, TypeValue
 };
 use core::result::Result;
 use std::path::PathBuf;
@@ -51,6 +51,9 @@ impl ArgCoercer {
                     BamlValue::Bool(true) => Ok(BamlValue::String("true".to_string())),
                     BamlValue::Bool(false) => Ok(BamlValue::String("false".to_string())),
                     BamlValue::Null => Ok(BamlValue::String("null".to_string())),
+                    BamlValue::Date(d) => Ok(BamlValue::String(temporal::format_date(d))),
+                    BamlValue::DateTime(d) => Ok(BamlValue::String(temporal::format_datetime(d))),
+                    BamlValue::Duration(d) => Ok(BamlValue::String(temporal::format_duration(d))),
                     _ => {
                         scope.push_error(format!("Expected type {:?}, got `{}`", t, value));
                         Err(())
@@ -67,6 +70,55 @@ impl ArgCoercer {
                 },
                 TypeValue::Bool if matches!(value, BamlValue::Bool(_)) => Ok(value.clone()),
                 TypeValue::Null if matches!(value, BamlValue::Null) => Ok(value.clone()),
+                TypeValue::Date => match value {
+                    BamlValue::Date(_) => Ok(value.clone()),
+                    // TypeScript has no date-only type, so dates arrive as `Date`s.
+                    BamlValue::DateTime(d) => Ok(BamlValue::Date(d.date_naive())),
+                    BamlValue::String(s) => match temporal::parse_date(s) {
+                        Some(d) => Ok(BamlValue::Date(d)),
+                        None => {
+                            scope.push_error(format!("Expected a date formatted as YYYY-MM-DD, got `{}`", s));
+                            Err(())
+                        }
+                    },
+                    _ => {
+                        scope.push_error(format!("Expected type {:?}, got `{}`", t, value));
+                        Err(())
+                    }
+                },
+                TypeValue::DateTime => match value {
+                    BamlValue::DateTime(_) => Ok(value.clone()),
+                    BamlValue::String(s) => match temporal::parse_datetime(s) {
+                        Some(d) => Ok(BamlValue::DateTime(d)),
+                        None => {
+                            scope.push_error(format!("Expected an RFC 3339 datetime, got `{}`", s));
+                            Err(())
+                        }
+                    },
+                    _ => {
+                        scope.push_error(format!("Expected type {:?}, got `{}`", t, value));
+                        Err(())
+                    }
+                },
+                // Clients without a duration type pass a number of seconds.
+                TypeValue::Duration => match value {
+                    BamlValue::Duration(_) => Ok(value.clone()),
+                    BamlValue::Int(secs) => Ok(BamlValue::Duration(chrono::Duration::seconds(*secs))),
+                    BamlValue::Float(secs) => Ok(BamlValue::Duration(chrono::Duration::nanoseconds(
+                        (secs * 1e9).round() as i64,
+                    ))),
+                    BamlValue::String(s) => match temporal::parse_duration(s) {
+                        Some(d) => Ok(BamlValue::Duration(d)),
+                        None => {
+                            scope.push_error(format!("Expected an ISO 8601 duration, got `{}`", s));
+                            Err(())
+                        }
+                    },
+                    _ => {
+                        scope.push_error(format!("Expected type {:?}, got `{}`", t, value));
+                        Err(())
+                    }
+                },
                 TypeValue::Media(media_type) => match value {
                     BamlValue::Media(v) => Ok(BamlValue::Media(v.clone())),
                     BamlValue::Map(kv) => {
@@ -203,6 +255,10 @@ impl ArgCoercer {
             },
             (FieldType::Literal(literal), _) => Ok(match (literal, value) {
                 (LiteralValue::Int(lit), BamlValue::Int(baml)) if lit == baml => value.clone(),
+                (LiteralValue::Float(lit), BamlValue::Float(baml)) if lit == baml => value.clone(),
+                (LiteralValue::Float(lit), BamlValue::Int(baml)) if *lit == *baml as f64 => {
+                    BamlValue::Float(*lit)
+                }
                 (LiteralValue::String(lit), BamlValue::String(baml)) if lit == baml => {
                     value.clone()
                 }
@@ -262,6 +318,14 @@ impl ArgCoercer {
                     Err(())
                 }
             },
+            (FieldType::RecursiveTypeAlias(name), _) => match ir.find_type_alias(name) {
+                // The value is finite, so following the alias always terminates.
+                Ok(alias) => self.coerce_arg(ir, alias.target(), value, scope),
+                Err(_) => {
+                    scope.push_error(format!("Type alias {} not found", name));
+                    Err(())
+                }
+            },
             (FieldType::List(item), _) => match value {
                 BamlValue::List(arr) => {
                     let mut items = Vec::new();
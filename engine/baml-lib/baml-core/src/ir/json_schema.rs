@@ -1,15 +1,93 @@
 // JSON Schema
 
-use baml_types::TypeValue;
+use baml_types::{LiteralValue, Refinement, TypeValue};
+use indexmap::IndexSet;
 use serde_json::json;
 
 use super::{
     repr::{self},
-    Class, Enum, FieldType, FunctionArgs, FunctionNode, IntermediateRepr, Walker,
+    Class, Enum, FieldType, FunctionArgs, FunctionNode, IRHelper, IntermediateRepr, Walker,
 };
 
 pub trait WithJsonSchema {
     fn json_schema(&self) -> serde_json::Value;
+
+    /// The schema in the form providers accept for strict structured output:
+    /// every property is required (optional ones are nullable instead), no
+    /// other properties are allowed, and literals are single-value enums.
+    /// Bounds like `minLength` or `maximum` are left out, since strict mode
+    /// rejects them; `@range` and `@length` still check the parsed value.
+    ///
+    /// `None` if the type can't be described strictly: maps need
+    /// `additionalProperties` to be a schema, and tuples need `prefixItems`,
+    /// both of which strict mode rejects.
+    fn strict_json_schema(&self) -> Option<serde_json::Value> {
+        let mut schema = self.json_schema();
+        make_strict(&mut schema);
+        is_strict(&schema).then_some(schema)
+    }
+}
+
+fn is_strict(schema: &serde_json::Value) -> bool {
+    let serde_json::Value::Object(map) = schema else {
+        return true;
+    };
+    map.iter().all(|(key, value)| match (key.as_str(), value) {
+        ("prefixItems", _) => false,
+        ("additionalProperties", value) => value == &json!(false),
+        ("properties" | "definitions", serde_json::Value::Object(schemas)) => {
+            schemas.values().all(is_strict)
+        }
+        ("anyOf", serde_json::Value::Array(schemas)) => schemas.iter().all(is_strict),
+        ("items", schema) => is_strict(schema),
+        _ => true,
+    })
+}
+
+/// Keywords that strict mode rejects.
+const UNSUPPORTED_STRICT_KEYWORDS: &[&str] = &[
+    "minLength",
+    "maxLength",
+    "pattern",
+    "minimum",
+    "maximum",
+    "minItems",
+    "maxItems",
+    "minProperties",
+    "maxProperties",
+];
+
+fn make_strict(schema: &mut serde_json::Value) {
+    let serde_json::Value::Object(map) = schema else {
+        return;
+    };
+    map.remove("default");
+    map.retain(|k, _| !UNSUPPORTED_STRICT_KEYWORDS.contains(&k.as_str()));
+    if map.contains_key("$ref") {
+        // Strict schemas don't allow keywords next to a reference.
+        map.retain(|k, _| k == "$ref");
+        return;
+    }
+    if let Some(value) = map.remove("const") {
+        map.insert("enum".to_string(), json!([value]));
+    }
+    if let Some(serde_json::Value::Object(properties)) = map.get("properties") {
+        let required = properties.keys().cloned().collect::<Vec<_>>();
+        map.insert("required".to_string(), json!(required));
+        map.insert("additionalProperties".to_string(), json!(false));
+    }
+    for (key, value) in map.iter_mut() {
+        match (key.as_str(), value) {
+            ("properties" | "definitions", serde_json::Value::Object(schemas)) => {
+                schemas.values_mut().for_each(make_strict)
+            }
+            ("anyOf" | "prefixItems", serde_json::Value::Array(schemas)) => {
+                schemas.iter_mut().for_each(make_strict)
+            }
+            ("items" | "additionalProperties", schema) => make_strict(schema),
+            _ => {}
+        }
+    }
 }
 
 impl WithJsonSchema for IntermediateRepr {
@@ -20,6 +98,10 @@ impl WithJsonSchema for IntermediateRepr {
         let classes = self
             .walk_classes()
             .map(|c| (c.elem().name.clone(), c.json_schema()));
+        let aliases = self
+            .walk_type_aliases()
+            .filter(|a| a.is_recursive())
+            .map(|a| (a.name().to_string(), a.target().json_schema()));
         let function_inputs = self
             .walk_functions()
             .map(|f| (format!("{}_input", f.name()), (f.item, true).json_schema()));
@@ -33,6 +115,7 @@ impl WithJsonSchema for IntermediateRepr {
         // Combine all the definitions into one object of key-value pairs
         let definitions = enums
             .chain(classes)
+            .chain(aliases)
             .chain(function_inputs)
             .chain(function_outputs)
             .collect::<serde_json::Map<_, _>>();
@@ -118,15 +201,21 @@ impl WithJsonSchema for Vec<(String, FieldType)> {
 
 impl WithJsonSchema for Walker<'_, &Enum> {
     fn json_schema(&self) -> serde_json::Value {
+        // Like class fields, values are matched against their alias.
+        let values = self
+            .elem()
+            .values
+            .iter()
+            .filter(|v| !matches!(v.attributes.get("skip"), Some(repr::Expression::Bool(true))))
+            .map(|v| match v.attributes.get("alias") {
+                Some(repr::Expression::String(alias)) => alias.clone(),
+                _ => v.elem.0.clone(),
+            })
+            .collect::<Vec<_>>();
         json!({
                 "title": self.elem().name,
-                "enum": self.elem().values
-                    .iter()
-                    .map(|v| json!({
-                        "const": v.elem.0.clone()
-                    }))
-                    .collect::<Vec<_>>(),
-
+                "type": "string",
+                "enum": values,
         })
     }
 }
@@ -136,11 +225,23 @@ impl WithJsonSchema for Walker<'_, &Class> {
         let mut properties = json!({});
         let mut required_props = vec![];
         for field in self.elem().static_fields.iter() {
-            properties[field.elem.name.clone()] = field.elem.r#type.elem.json_schema();
+            // The parser matches keys against the alias, so that's what the
+            // model has to send.
+            let name = match field.attributes.get("alias") {
+                Some(repr::Expression::String(alias)) => alias.clone(),
+                _ => field.elem.name.clone(),
+            };
+            let mut schema = field.elem.r#type.elem.json_schema();
+            if let (Some(repr::Expression::String(description)), serde_json::Value::Object(s)) =
+                (field.attributes.get("description"), &mut schema)
+            {
+                s.insert("description".to_string(), json!(description));
+            }
+            properties[name.clone()] = schema;
             match field.elem.r#type.elem {
                 FieldType::Optional(_) => {}
                 _ => {
-                    required_props.push(field.elem.name.clone());
+                    required_props.push(name);
                 }
             }
         }
@@ -153,15 +254,108 @@ impl WithJsonSchema for Walker<'_, &Class> {
     }
 }
 
+/// The schema of a type along with the classes and enums it refers to, under
+/// `definitions`, so that it can be sent on its own (e.g. as the parameters
+/// of a tool). A class is inlined at the top level.
+impl WithJsonSchema for (&IntermediateRepr, &FieldType) {
+    fn json_schema(&self) -> serde_json::Value {
+        let (ir, target) = *self;
+
+        let mut classes = IndexSet::new();
+        let mut enums = IndexSet::new();
+        let mut aliases = IndexSet::new();
+        let mut pending = vec![target];
+        while let Some(t) = pending.pop() {
+            match t {
+                FieldType::Class(name) => {
+                    if let Ok(class) = ir.find_class(name) {
+                        if classes.insert(name.as_str()) {
+                            pending.extend(
+                                class
+                                    .elem()
+                                    .static_fields
+                                    .iter()
+                                    .map(|f| &f.elem.r#type.elem),
+                            );
+                        }
+                    }
+                }
+                FieldType::Enum(name) => {
+                    enums.insert(name.as_str());
+                }
+                FieldType::RecursiveTypeAlias(name) => {
+                    if let Ok(alias) = ir.find_type_alias(name) {
+                        if aliases.insert(name.as_str()) {
+                            pending.push(alias.target());
+                        }
+                    }
+                }
+                FieldType::List(inner) | FieldType::Optional(inner) => pending.push(inner),
+                FieldType::Map(k, v) => pending.extend([k.as_ref(), v.as_ref()]),
+                FieldType::Union(options) | FieldType::Tuple(options) => pending.extend(options),
+                FieldType::Constrained { base, .. } => pending.push(base),
+                FieldType::Primitive(_) | FieldType::Literal(_) => {}
+            }
+        }
+
+        let definitions = ir
+            .walk_enums()
+            .filter(|e| enums.contains(e.name()))
+            .map(|e| (e.name().to_string(), e.json_schema()))
+            .chain(
+                ir.walk_classes()
+                    .filter(|c| classes.contains(c.name()))
+                    .map(|c| (c.name().to_string(), c.json_schema())),
+            )
+            .chain(
+                ir.walk_type_aliases()
+                    .filter(|a| aliases.contains(a.name()))
+                    .map(|a| (a.name().to_string(), a.target().json_schema())),
+            )
+            .collect::<serde_json::Map<_, _>>();
+
+        let mut res = match target {
+            FieldType::Class(name) => match ir.find_class(name) {
+                Ok(class) => class.json_schema(),
+                Err(_) => target.json_schema(),
+            },
+            _ => target.json_schema(),
+        };
+        if let serde_json::Value::Object(res) = &mut res {
+            if !definitions.is_empty() {
+                res.insert("definitions".to_string(), definitions.into());
+            }
+        }
+        res
+    }
+}
+
 impl<'db> WithJsonSchema for FieldType {
     fn json_schema(&self) -> serde_json::Value {
         match self {
-            FieldType::Class(name) | FieldType::Enum(name) => json!({
+            FieldType::Class(name)
+            | FieldType::Enum(name)
+            | FieldType::RecursiveTypeAlias(name) => json!({
                 "$ref": format!("#/definitions/{}", name),
             }),
-            FieldType::Literal(v) => json!({
-                "const": v.to_string(),
-            }),
+            FieldType::Literal(v) => match v {
+                LiteralValue::String(s) => json!({
+                    "type": "string",
+                    "const": s,
+                }),
+                LiteralValue::Int(i) => json!({
+                    "type": "integer",
+                    "const": i,
+                }),
+                LiteralValue::Float(f) => json!({
+                    "type": "number",
+                    "const": f,
+                }),
+                LiteralValue::Bool(b) => json!({
+                    "type": "boolean",
+                    "const": b,
+                }),
+            },
             FieldType::Primitive(t) => match t {
                 TypeValue::String => json!({
                     "type": "string",
@@ -178,6 +372,18 @@ impl<'db> WithJsonSchema for FieldType {
                 TypeValue::Null => json!({
                     "type": "null",
                 }),
+                TypeValue::Date => json!({
+                    "type": "string",
+                    "format": "date",
+                }),
+                TypeValue::DateTime => json!({
+                    "type": "string",
+                    "format": "date-time",
+                }),
+                TypeValue::Duration => json!({
+                    "type": "string",
+                    "format": "duration",
+                }),
                 TypeValue::Media(_) => json!({
                     // anyOf either an object that has a uri, or it has a base64 string
                     "type": "object",
@@ -195,9 +401,7 @@ impl<'db> WithJsonSchema for FieldType {
             }),
             FieldType::Map(_k, v) => json!({
                 "type": "object",
-                "additionalProperties": {
-                    "type": v.json_schema(),
-                }
+                "additionalProperties": v.json_schema(),
             }),
             FieldType::Union(options) => json!({
                 "anyOf": options.iter().map(|t| {
@@ -236,7 +440,245 @@ impl<'db> WithJsonSchema for FieldType {
                     }
                 }
             }
-            FieldType::Constrained { base, .. } => base.json_schema(),
+            FieldType::Constrained { base, constraints } => {
+                let mut schema = base.json_schema();
+                if let serde_json::Value::Object(map) = &mut schema {
+                    for refinement in constraints.iter().filter_map(|c| c.refinement.as_ref()) {
+                        map.extend(bound_keywords(refinement, base));
+                    }
+                }
+                schema
+            }
         }
     }
 }
+
+/// The keywords for the bounds of a `@range` or `@length`, e.g. `minimum`
+/// or `maxItems`.
+fn bound_keywords(
+    refinement: &Refinement,
+    base: &FieldType,
+) -> serde_json::Map<String, serde_json::Value> {
+    // Whole numbers are written without a fraction, like the `int`s they bound.
+    let number = |n: f64| {
+        if n.fract() == 0.0 && n.abs() < i64::MAX as f64 {
+            json!(n as i64)
+        } else {
+            json!(n)
+        }
+    };
+    let ((min_key, min), (max_key, max)) = match refinement {
+        Refinement::Range { min, max } => {
+            (("minimum", min.map(number)), ("maximum", max.map(number)))
+        }
+        Refinement::Length { min, max } => {
+            let base = match base {
+                FieldType::Optional(inner) => inner.as_ref(),
+                other => other,
+            };
+            let (min_key, max_key) = match base {
+                FieldType::List(_) => ("minItems", "maxItems"),
+                FieldType::Map(..) => ("minProperties", "maxProperties"),
+                _ => ("minLength", "maxLength"),
+            };
+            (
+                (min_key, min.map(|n| json!(n))),
+                (max_key, max.map(|n| json!(n))),
+            )
+        }
+    };
+    [(min_key, min), (max_key, max)]
+        .into_iter()
+        .filter_map(|(key, bound)| Some((key.to_string(), bound?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::repr::make_test_ir;
+
+    #[test]
+    fn strict_schema_requires_every_property() {
+        let ir = make_test_ir(
+            r##"
+            enum Mood {
+              Happy @alias("happy")
+              Sad
+            }
+
+            class Reply {
+              kind "reply"
+              mood Mood
+              note string?
+              tags string[]
+            }
+            "##,
+        )
+        .unwrap();
+
+        let schema = (&ir, &FieldType::Class("Reply".to_string()))
+            .strict_json_schema()
+            .unwrap();
+        assert_eq!(schema["required"], json!(["kind", "mood", "note", "tags"]));
+        assert_eq!(schema["additionalProperties"], json!(false));
+        assert_eq!(
+            schema["properties"]["kind"],
+            json!({ "type": "string", "enum": ["reply"] })
+        );
+        assert_eq!(
+            schema["properties"]["note"],
+            json!({ "type": ["string", "null"] })
+        );
+        assert_eq!(
+            schema["properties"]["mood"],
+            json!({ "$ref": "#/definitions/Mood" })
+        );
+        assert_eq!(
+            schema["definitions"]["Mood"],
+            json!({ "title": "Mood", "type": "string", "enum": ["happy", "Sad"] })
+        );
+    }
+
+    #[test]
+    fn optional_class_is_nullable() {
+        let ir = make_test_ir(
+            r##"
+            class Address {
+              city string
+            }
+
+            class Person {
+              address Address?
+            }
+            "##,
+        )
+        .unwrap();
+
+        let schema = (&ir, &FieldType::Class("Person".to_string()))
+            .strict_json_schema()
+            .unwrap();
+        assert_eq!(
+            schema["properties"]["address"],
+            json!({
+                "anyOf": [
+                    { "$ref": "#/definitions/Address" },
+                    { "type": "null", "title": "null" },
+                ],
+            })
+        );
+        assert_eq!(
+            schema["definitions"]["Address"]["additionalProperties"],
+            json!(false)
+        );
+    }
+
+    #[test]
+    fn maps_have_no_strict_schema() {
+        let ir = make_test_ir(
+            r##"
+            class Scores {
+              by_name map<string, int>
+            }
+
+            class Report {
+              scores Scores[]
+            }
+
+            class Plain {
+              name string
+            }
+            "##,
+        )
+        .unwrap();
+
+        assert_eq!(
+            (&ir, &FieldType::Class("Report".to_string())).strict_json_schema(),
+            None
+        );
+        let tuple = FieldType::Tuple(vec![
+            FieldType::Primitive(TypeValue::String),
+            FieldType::Primitive(TypeValue::Int),
+        ]);
+        assert_eq!((&ir, &tuple).strict_json_schema(), None);
+        assert!((&ir, &FieldType::Class("Plain".to_string()))
+            .strict_json_schema()
+            .is_some());
+    }
+
+    #[test]
+    fn recursive_types_are_referenced() {
+        let ir = make_test_ir(
+            r##"
+            type Json = string | int | Json[] | map<string, Json>
+
+            class Node {
+              value int
+              children Node[]
+            }
+
+            class Doc {
+              data Json
+              tree Node
+            }
+            "##,
+        )
+        .unwrap();
+
+        let schema = (&ir, &FieldType::Class("Doc".to_string())).json_schema();
+        assert_eq!(
+            schema["properties"]["data"],
+            json!({ "$ref": "#/definitions/Json" })
+        );
+        assert_eq!(
+            schema["definitions"]["Json"]["anyOf"][2]["items"],
+            json!({ "$ref": "#/definitions/Json" })
+        );
+        assert_eq!(
+            schema["definitions"]["Node"]["properties"]["children"]["items"],
+            json!({ "$ref": "#/definitions/Node" })
+        );
+    }
+
+    #[test]
+    fn refinements_become_bounds() {
+        let ir = make_test_ir(
+            r##"
+            class Review {
+              score int @range(1, 5)
+              weight float @range(0.5, null)
+              title string @length(1, 280)
+              tags string[] @length(null, 3)
+            }
+            "##,
+        )
+        .unwrap();
+
+        let schema = (&ir, &FieldType::Class("Review".to_string())).json_schema();
+        let properties = &schema["properties"];
+        assert_eq!(
+            properties["score"],
+            json!({ "type": "integer", "minimum": 1, "maximum": 5 })
+        );
+        assert_eq!(
+            properties["weight"],
+            json!({ "type": "number", "minimum": 0.5 })
+        );
+        assert_eq!(
+            properties["title"],
+            json!({ "type": "string", "minLength": 1, "maxLength": 280 })
+        );
+        assert_eq!(properties["tags"]["maxItems"], json!(3));
+
+        let strict = (&ir, &FieldType::Class("Review".to_string()))
+            .strict_json_schema()
+            .unwrap();
+        let properties = &strict["properties"];
+        assert_eq!(properties["score"], json!({ "type": "integer" }));
+        assert_eq!(properties["title"], json!({ "type": "string" }));
+        assert_eq!(
+            properties["tags"],
+            json!({ "type": "array", "items": { "type": "string" } })
+        );
+    }
+}
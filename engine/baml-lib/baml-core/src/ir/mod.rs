@@ -7,7 +7,9 @@ mod walker;
 pub use ir_helpers::{
     scope_diagnostics, ArgCoercer, ClassFieldWalker, ClassWalker, ClientWalker, EnumValueWalker,
     EnumWalker, FunctionWalker, IRHelper, RetryPolicyWalker, TemplateStringWalker, TestCaseWalker,
+    TypeAliasWalker,
 };
+pub use json_schema::WithJsonSchema;
 
 pub(super) use repr::IntermediateRepr;
 
@@ -16,6 +18,7 @@ pub type Enum = repr::Node<repr::Enum>;
 pub type EnumValue = repr::Node<repr::EnumValue>;
 pub type Class = repr::Node<repr::Class>;
 pub type Field = repr::Node<repr::Field>;
+pub type TypeAlias = repr::Node<repr::TypeAlias>;
 pub type FieldType = baml_types::FieldType;
 pub type Expression = repr::Expression;
 pub type Identifier = repr::Identifier;
@@ -1,15 +1,17 @@
 use std::collections::HashSet;
 
-use anyhow::{anyhow, Result};
-use baml_types::{Constraint, ConstraintLevel, FieldType};
+use anyhow::{anyhow, Context, Result};
+use baml_types::{Constraint, FieldType};
 use either::Either;
 use indexmap::IndexMap;
 use internal_baml_parser_database::{
     walkers::{
         ClassWalker, ClientSpec as AstClientSpec, ClientWalker, ConfigurationWalker,
         EnumValueWalker, EnumWalker, FieldWalker, FunctionWalker, TemplateStringWalker,
+        TypeAliasWalker,
     },
-    Attributes, ParserDatabase, PromptAst, RetryPolicyStrategy,
+    Attributes, CircuitBreakerConfig, ExpectedMatch, ParserDatabase, PromptAst,
+    RetryPolicyStrategy,
 };
 use internal_baml_schema_ast::ast::SubType;
 
@@ -27,6 +29,7 @@ use crate::Configuration;
 pub struct IntermediateRepr {
     enums: Vec<Node<Enum>>,
     classes: Vec<Node<Class>>,
+    type_aliases: Vec<Node<TypeAlias>>,
     functions: Vec<Node<Function>>,
     clients: Vec<Node<Client>>,
     retry_policies: Vec<Node<RetryPolicy>>,
@@ -50,6 +53,7 @@ impl IntermediateRepr {
         IntermediateRepr {
             enums: vec![],
             classes: vec![],
+            type_aliases: vec![],
             functions: vec![],
             clients: vec![],
             retry_policies: vec![],
@@ -82,6 +86,14 @@ impl IntermediateRepr {
         self.classes.iter().map(|e| Walker { db: self, item: e })
     }
 
+    pub fn walk_type_aliases<'a>(
+        &'a self,
+    ) -> impl ExactSizeIterator<Item = Walker<'a, &'a Node<TypeAlias>>> {
+        self.type_aliases
+            .iter()
+            .map(|e| Walker { db: self, item: e })
+    }
+
     pub fn function_names(&self) -> impl ExactSizeIterator<Item = &str> {
         self.functions.iter().map(|f| f.elem.name())
     }
@@ -139,6 +151,10 @@ impl IntermediateRepr {
                 .walk_classes()
                 .map(|e| e.node(db))
                 .collect::<Result<Vec<_>>>()?,
+            type_aliases: db
+                .walk_type_aliases()
+                .map(|e| e.node(db))
+                .collect::<Result<Vec<_>>>()?,
             functions: db
                 .walk_functions()
                 .map(|e| e.node(db))
@@ -161,6 +177,8 @@ impl IntermediateRepr {
         // Sort each item by name.
         repr.enums.sort_by(|a, b| a.elem.name.cmp(&b.elem.name));
         repr.classes.sort_by(|a, b| a.elem.name.cmp(&b.elem.name));
+        repr.type_aliases
+            .sort_by(|a, b| a.elem.name.cmp(&b.elem.name));
         repr.functions
             .sort_by(|a, b| a.elem.name().cmp(&b.elem.name()));
         repr.clients.sort_by(|a, b| a.elem.name.cmp(&b.elem.name));
@@ -209,6 +227,10 @@ impl NodeAttributes {
     pub fn get(&self, key: &str) -> Option<&Expression> {
         self.meta.get(key)
     }
+
+    pub fn constraints(&self) -> &Vec<Constraint> {
+        &self.constraints
+    }
 }
 
 impl Default for NodeAttributes {
@@ -233,6 +255,10 @@ fn to_ir_attributes(
             dynamic_type,
             skip,
             constraints,
+            default_value,
+            stream_done,
+            stream_not_null,
+            stream_with_state,
         } = attributes;
         let description = description.as_ref().and_then(|d| {
             let name = "description".to_string();
@@ -268,8 +294,25 @@ fn to_ir_attributes(
             }
         });
 
-        let meta = vec![description, alias, dynamic_type, skip]
+        // `FieldWalker::repr` fails on defaults that can't be represented, so
+        // none are dropped here.
+        let default_value = default_value
+            .as_ref()
+            .and_then(|v| v.repr(db).ok())
+            .map(|v| ("default".to_string(), v));
+
+        let streaming = [
+            ("stream.done", stream_done),
+            ("stream.not_null", stream_not_null),
+            ("stream.with_state", stream_with_state),
+        ]
+        .into_iter()
+        .filter(|(_, v)| v.unwrap_or(false))
+        .map(|(name, _)| (name.to_string(), Expression::Bool(true)));
+
+        let meta = vec![description, alias, dynamic_type, skip, default_value]
             .into_iter()
+            .chain(streaming.map(Some))
             .filter_map(|s| s)
             .collect();
         (meta, constraints.clone())
@@ -320,23 +363,12 @@ impl WithRepr<FieldType> for ast::FieldType {
             .attributes()
             .iter()
             .filter_map(|attr| {
-                let level = match attr.name.to_string().as_str() {
-                    "assert" => Some(ConstraintLevel::Assert),
-                    "check" => Some(ConstraintLevel::Check),
-                    _ => None
-                }?;
-                let (label, expression) = match attr.arguments.arguments.as_slice() {
-                    [arg1, arg2] => match (arg1.clone().value, arg2.clone().value) {
-                        (ast::Expression::Identifier(ast::Identifier::Local(s, _)), ast::Expression::JinjaExpressionValue(j,_)) => Some((Some(s), j)),
-                        _ => None
-                    },
-                    [arg1] => match arg1.clone().value {
-                        ast::Expression::JinjaExpressionValue(JinjaExpression(j),_) => Some((None, JinjaExpression(j.clone()))),
-                        _ => None
-                    }
-                    _ => None,
-                }?;
-                Some(Constraint{ level, expression, label })
+                if let Some(refinement) = attr.refinement() {
+                    return refinement
+                        .ok()
+                        .map(|r| Constraint::from_refinement(r, self.is_optional()));
+                }
+                attr.constraint()?.ok()
             })
             .collect::<Vec<Constraint>>();
         let attributes = NodeAttributes {
@@ -376,7 +408,16 @@ impl WithRepr<FieldType> for ast::FieldType {
                     Some(Either::Right(enum_walker)) => {
                         FieldType::Enum(enum_walker.name().to_string())
                     }
-                    None => return Err(anyhow!("Field type uses unresolvable local identifier")),
+                    None => match db.find_type_alias(idn) {
+                        // Recursive aliases stay named, the rest are expanded in place.
+                        Some(alias) if alias.is_recursive() => {
+                            FieldType::RecursiveTypeAlias(alias.name().to_string())
+                        }
+                        Some(alias) => alias.target().repr(db)?,
+                        None => {
+                            return Err(anyhow!("Field type uses unresolvable local identifier"))
+                        }
+                    },
                 },
                 arity,
             ),
@@ -638,6 +679,14 @@ impl WithRepr<Field> for FieldWalker<'_> {
     }
 
     fn repr(&self, db: &ParserDatabase) -> Result<Field> {
+        if let Some(value) = self
+            .get_default_attributes()
+            .and_then(|attrs| attrs.default_value().as_ref())
+        {
+            value
+                .repr(db)
+                .with_context(|| format!("Invalid default value of field {:?}", self.name()))?;
+        }
         Ok(Field {
             name: self.name().to_string(),
             r#type: Node {
@@ -664,11 +713,17 @@ pub struct Class {
     /// User defined class name.
     pub name: ClassId,
 
-    /// Fields of the class.
+    /// Fields of the class, including inherited ones.
     pub static_fields: Vec<Node<Field>>,
 
     /// Parameters to the class definition.
     pub inputs: Vec<(String, FieldType)>,
+
+    /// The class named in `extends`, if any.
+    pub base_class: Option<ClassId>,
+
+    /// Fields in `static_fields` that come unchanged from `base_class`.
+    pub inherited_fields: Vec<String>,
 }
 
 impl WithRepr<Class> for ClassWalker<'_> {
@@ -702,6 +757,12 @@ impl WithRepr<Class> for ClassWalker<'_> {
                     .collect::<Result<Vec<_>>>()?,
                 None => Vec::new(),
             },
+            base_class: self.base_class().map(|base| base.name().to_string()),
+            inherited_fields: self
+                .static_fields()
+                .filter(|f| self.inherits_from_base(*f))
+                .map(|f| f.name().to_string())
+                .collect(),
         })
     }
 }
@@ -711,6 +772,36 @@ impl Class {
         &self.inputs
     }
 }
+
+#[derive(serde::Serialize, Debug)]
+pub struct TypeAlias {
+    pub name: String,
+
+    /// The aliased type. References to recursive aliases inside it are left
+    /// as [`FieldType::RecursiveTypeAlias`].
+    pub r#type: FieldType,
+
+    /// Whether the alias refers back to itself.
+    pub recursive: bool,
+}
+
+impl WithRepr<TypeAlias> for TypeAliasWalker<'_> {
+    fn attributes(&self, _: &ParserDatabase) -> NodeAttributes {
+        NodeAttributes {
+            meta: Default::default(),
+            constraints: Vec::new(),
+            span: Some(self.span().clone()),
+        }
+    }
+
+    fn repr(&self, db: &ParserDatabase) -> Result<TypeAlias> {
+        Ok(TypeAlias {
+            name: self.name().to_string(),
+            r#type: self.target().repr(db)?,
+            recursive: self.is_recursive(),
+        })
+    }
+}
 #[derive(serde::Serialize, Debug)]
 pub enum OracleType {
     LLM,
@@ -805,6 +896,8 @@ pub struct FunctionConfig {
     #[serde(skip)]
     pub prompt_span: ast::Span,
     pub client: ClientSpec,
+    /// Classes the model may call as tools.
+    pub tools: Vec<String>,
 }
 
 // NB(sam): we used to use this to bridge the wasm layer, but
@@ -928,6 +1021,12 @@ impl WithRepr<Function> for FunctionWalker<'_> {
                     Ok(spec) => ClientSpec::from(spec),
                     Err(e) => anyhow::bail!("{}", e.message()),
                 },
+                tools: self
+                    .metadata()
+                    .tools
+                    .iter()
+                    .map(|(tool, _)| tool.clone())
+                    .collect(),
             }],
             default_config: "default_config".to_string(),
             tests: self
@@ -984,6 +1083,10 @@ pub struct RetryPolicy {
     pub name: RetryPolicyId,
     pub max_retries: u32,
     pub strategy: RetryPolicyStrategy,
+    pub retry_on_parse_failure: bool,
+    pub circuit_breaker: Option<CircuitBreakerConfig>,
+    pub retry_on: Option<Vec<String>>,
+    pub error_strategies: Vec<(String, RetryPolicyStrategy)>,
     // NB: the parser DB has a notion of "empty options" vs "no options"; we collapse
     // those here into an empty vec
     options: Vec<(String, Expression)>,
@@ -1003,6 +1106,10 @@ impl WithRepr<RetryPolicy> for ConfigurationWalker<'_> {
             name: RetryPolicyId(self.name().to_string()),
             max_retries: self.retry_policy().max_retries,
             strategy: self.retry_policy().strategy,
+            retry_on_parse_failure: self.retry_policy().retry_on_parse_failure,
+            circuit_breaker: self.retry_policy().circuit_breaker,
+            retry_on: self.retry_policy().retry_on.clone(),
+            error_strategies: self.retry_policy().error_strategies.clone(),
             options: match &self.retry_policy().options {
                 Some(o) => o
                     .iter()
@@ -1028,6 +1135,10 @@ pub struct TestCase {
     pub name: String,
     pub functions: Vec<Node<TestCaseFunction>>,
     pub args: IndexMap<String, Expression>,
+    /// The output the functions should produce. The test's `@@assert`s and
+    /// `@@check`s live in the node's constraints.
+    pub expected: Option<Expression>,
+    pub expected_match: ExpectedMatch,
 }
 
 impl WithRepr<TestCaseFunction> for (&ConfigurationWalker<'_>, usize) {
@@ -1052,7 +1163,7 @@ impl WithRepr<TestCase> for ConfigurationWalker<'_> {
         NodeAttributes {
             meta: IndexMap::new(),
             span: Some(self.span().clone()),
-            constraints: Vec::new(),
+            constraints: self.test_case().constraints.clone(),
         }
     }
 
@@ -1069,6 +1180,13 @@ impl WithRepr<TestCase> for ConfigurationWalker<'_> {
                 .map(|(k, (_, v))| Ok((k.clone(), v.repr(db)?)))
                 .collect::<Result<IndexMap<_, _>>>()?,
             functions,
+            expected: self
+                .test_case()
+                .expected
+                .as_ref()
+                .map(|(_, v)| v.repr(db))
+                .transpose()?,
+            expected_match: self.test_case().expected_match,
         })
     }
 }
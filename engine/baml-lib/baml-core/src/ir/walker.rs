@@ -1,8 +1,8 @@
 use anyhow::Result;
-use baml_types::BamlValue;
+use baml_types::{BamlValue, Constraint, StreamingBehavior};
 use indexmap::IndexMap;
 
-use internal_baml_parser_database::RetryPolicyStrategy;
+use internal_baml_parser_database::{CircuitBreakerConfig, ExpectedMatch, RetryPolicyStrategy};
 
 use std::collections::HashMap;
 
@@ -10,7 +10,7 @@ use crate::ir::jinja_helpers::render_expression;
 use super::{
     repr::{self, FunctionConfig},
     Class, Client, Enum, EnumValue, Expression, Field, FunctionNode, Identifier, Impl, RetryPolicy,
-    TemplateString, TestCase, Walker,
+    TemplateString, TestCase, TypeAlias, Walker,
 };
 
 impl<'a> Walker<'a, &'a FunctionNode> {
@@ -274,6 +274,23 @@ impl<'a> Walker<'a, (&'a FunctionNode, &'a TestCase)> {
             .collect()
     }
 
+    /// The `@@assert`s and `@@check`s to run over the output.
+    pub fn constraints(&self) -> &Vec<Constraint> {
+        self.item.1.attributes.constraints()
+    }
+
+    /// The output the function should produce, if the test names one.
+    pub fn expected(&self, env_values: &HashMap<String, String>) -> Option<Result<BamlValue>> {
+        self.test_case()
+            .expected
+            .as_ref()
+            .map(|v| v.normalize(env_values))
+    }
+
+    pub fn expected_match(&self) -> ExpectedMatch {
+        self.test_case().expected_match
+    }
+
     pub fn function(&'a self) -> Walker<'a, &'a FunctionNode> {
         Walker {
             db: self.db,
@@ -295,6 +312,14 @@ impl<'a> Walker<'a, &'a Class> {
             .transpose()
     }
 
+    /// Streaming behavior set with `@@stream.done`.
+    pub fn streaming_behavior(&self) -> StreamingBehavior {
+        StreamingBehavior {
+            done: self.item.attributes.get("stream.done").is_some(),
+            ..Default::default()
+        }
+    }
+
     pub fn walk_fields(&'a self) -> impl Iterator<Item = Walker<'a, &'a Field>> {
         self.item.elem.static_fields.iter().map(|f| Walker {
             db: self.db,
@@ -318,6 +343,16 @@ impl<'a> Walker<'a, &'a Class> {
         &self.item.elem
     }
 
+    /// The class named in `extends`, if any.
+    pub fn base_class(&self) -> Option<&'a str> {
+        self.elem().base_class.as_deref()
+    }
+
+    /// Whether the field comes unchanged from the class named in `extends`.
+    pub fn inherits_field(&self, name: &str) -> bool {
+        self.elem().inherited_fields.iter().any(|f| f == name)
+    }
+
     pub fn span(&self) -> Option<&crate::Span> {
         self.item.attributes.span.as_ref()
     }
@@ -327,6 +362,28 @@ impl<'a> Walker<'a, &'a Class> {
     }
 }
 
+impl<'a> Walker<'a, &'a TypeAlias> {
+    pub fn name(&self) -> &'a str {
+        &self.elem().name
+    }
+
+    pub fn elem(&self) -> &'a repr::TypeAlias {
+        &self.item.elem
+    }
+
+    pub fn target(&self) -> &'a baml_types::FieldType {
+        &self.elem().r#type
+    }
+
+    pub fn is_recursive(&self) -> bool {
+        self.elem().recursive
+    }
+
+    pub fn span(&self) -> Option<&crate::Span> {
+        self.item.attributes.span.as_ref()
+    }
+}
+
 impl<'a> Walker<'a, &'a Client> {
     pub fn elem(&self) -> &'a repr::Client {
         &self.item.elem
@@ -366,6 +423,22 @@ impl<'a> Walker<'a, &'a RetryPolicy> {
         &self.elem().strategy
     }
 
+    pub fn retry_on_parse_failure(&self) -> bool {
+        self.elem().retry_on_parse_failure
+    }
+
+    pub fn circuit_breaker(&self) -> Option<&CircuitBreakerConfig> {
+        self.elem().circuit_breaker.as_ref()
+    }
+
+    pub fn retry_on(&self) -> Option<&[String]> {
+        self.elem().retry_on.as_deref()
+    }
+
+    pub fn error_strategies(&self) -> &[(String, RetryPolicyStrategy)] {
+        &self.elem().error_strategies
+    }
+
     pub fn span(&self) -> Option<&crate::Span> {
         self.item.attributes.span.as_ref()
     }
@@ -414,6 +487,25 @@ impl<'a> Walker<'a, &'a Field> {
             .transpose()
     }
 
+    /// The value set with `@default(...)`, used when the LLM leaves the field out.
+    pub fn default_value(&self, env_values: &HashMap<String, String>) -> Result<Option<BamlValue>> {
+        self.item
+            .attributes
+            .get("default")
+            .map(|v| v.normalize(env_values))
+            .transpose()
+    }
+
+    /// Streaming behavior set with the `@stream.*` field attributes.
+    pub fn streaming_behavior(&self) -> StreamingBehavior {
+        let attributes = &self.item.attributes;
+        StreamingBehavior {
+            done: attributes.get("stream.done").is_some(),
+            not_null: attributes.get("stream.not_null").is_some(),
+            with_state: attributes.get("stream.with_state").is_some(),
+        }
+    }
+
     pub fn description(&self, env_values: &HashMap<String, String>) -> Result<Option<String>> {
         self.item
             .attributes
@@ -436,6 +528,7 @@ impl<'a> Walker<'a, &'a Field> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ir::{repr::make_test_ir, IRHelper};
     use baml_types::JinjaExpression;
 
     #[test]
@@ -450,4 +543,103 @@ mod tests {
             _ => panic!("Expected String Expression"),
         }
     }
+
+    fn field_names<'a>(class: &Walker<'a, &'a Class>) -> Vec<&'a str> {
+        class
+            .elem()
+            .static_fields
+            .iter()
+            .map(|f| f.elem.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn inherited_fields_come_first() {
+        let ir = make_test_ir(
+            r##"
+            class Timestamps {
+              created_at string
+              updated_at string
+            }
+
+            class Entity {
+              id string
+              ...Timestamps
+            }
+
+            class Contact {
+              email string
+            }
+
+            class User extends Entity {
+              name string
+              ...Contact
+            }
+            "##,
+        )
+        .unwrap();
+
+        let entity = ir.find_class("Entity").unwrap();
+        assert_eq!(field_names(&entity), vec!["created_at", "updated_at", "id"]);
+
+        let user = ir.find_class("User").unwrap();
+        assert_eq!(
+            field_names(&user),
+            vec!["created_at", "updated_at", "id", "email", "name"]
+        );
+        assert_eq!(user.base_class(), Some("Entity"));
+        assert!(user.inherits_field("id"));
+        // Spread fields aren't inherited from the base class.
+        assert!(!user.inherits_field("email"));
+        assert!(!user.inherits_field("name"));
+    }
+
+    #[test]
+    fn declared_fields_override_inherited_ones() {
+        let ir = make_test_ir(
+            r##"
+            class Left {
+              side string
+            }
+
+            class Right {
+              side int
+            }
+
+            class Entity {
+              id string
+              kind string
+            }
+
+            class Admin extends Entity {
+              ...Left
+              ...Right
+              id int @description("Numeric id")
+              side bool
+            }
+            "##,
+        )
+        .unwrap();
+
+        let admin = ir.find_class("Admin").unwrap();
+        // An overriding field keeps the position of the one it replaces.
+        assert_eq!(field_names(&admin), vec!["id", "kind", "side"]);
+        assert_eq!(
+            admin.find_field("id").unwrap().r#type(),
+            &baml_types::FieldType::Primitive(baml_types::TypeValue::Int)
+        );
+        assert_eq!(
+            admin.find_field("side").unwrap().r#type(),
+            &baml_types::FieldType::Primitive(baml_types::TypeValue::Bool)
+        );
+        assert!(admin
+            .find_field("id")
+            .unwrap()
+            .item
+            .attributes
+            .get("description")
+            .is_some());
+        assert!(!admin.inherits_field("id"));
+        assert!(admin.inherits_field("kind"));
+    }
 }
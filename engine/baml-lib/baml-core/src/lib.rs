@@ -97,7 +97,7 @@ pub fn validate(root_path: &PathBuf, files: Vec<SourceFile>) -> ValidatedSchema
     }
 
     // Some last linker stuff can only happen post validation.
-    db.finalize(&mut diagnostics);
+    db.finalize();
 
     ValidatedSchema {
         db,
@@ -1,3 +1,4 @@
+mod aliases;
 mod classes;
 mod clients;
 mod configurations;
@@ -11,6 +12,7 @@ use super::context::Context;
 pub(super) fn validate(ctx: &mut Context<'_>) {
     enums::validate(ctx);
     classes::validate(ctx);
+    aliases::validate(ctx);
     functions::validate(ctx);
     clients::validate(ctx);
     configurations::validate(ctx);
@@ -1,17 +1,49 @@
-use internal_baml_schema_ast::ast::{WithName, WithSpan};
+use either::Either;
+use internal_baml_schema_ast::ast::{
+    Expression, FieldArity, FieldType, Identifier, WithName, WithSpan,
+};
 
 use super::types::validate_type;
+use crate::ir::repr::WithRepr;
 use crate::validate::validation_pipeline::context::Context;
+use baml_types::{LiteralValue, TypeValue};
 use internal_baml_diagnostics::DatamodelError;
 
 pub(super) fn validate(ctx: &mut Context<'_>) {
     let mut defined_types = internal_baml_jinja_types::PredefinedTypes::default();
 
     for cls in ctx.db.walk_classes() {
-        for c in cls.static_fields() {
+        for c in cls.declared_fields() {
             let field = c.ast_field();
             if let Some(ft) = &field.expr {
                 validate_type(ctx, &ft);
+
+                let default_value = c
+                    .get_default_attributes()
+                    .and_then(|attrs| attrs.default_value().as_ref());
+                if let Some(value) = default_value {
+                    if !default_matches_type(ctx, value, ft) {
+                        ctx.push_error(DatamodelError::new_validation_error(
+                            &format!(
+                                "The default value of field `{}` does not match its type.",
+                                field.name()
+                            ),
+                            value.span().clone(),
+                        ));
+                    } else if let Err(e) = value
+                        .repr(ctx.db)
+                        .and_then(|v| v.normalize(&Default::default()))
+                    {
+                        ctx.push_error(DatamodelError::new_validation_error(
+                            &format!(
+                                "The default value of field `{}` can't be used: {}",
+                                field.name(),
+                                e
+                            ),
+                            value.span().clone(),
+                        ));
+                    }
+                }
             }
         }
 
@@ -43,3 +75,115 @@ pub(super) fn validate(ctx: &mut Context<'_>) {
         defined_types.errors_mut().clear();
     }
 }
+
+/// Whether `value` is a valid `@default(...)` for a field of type `field_type`.
+/// Classes, media and dates can't have defaults, and neither can values that
+/// are only known at runtime, like environment variables.
+fn default_matches_type(ctx: &Context<'_>, value: &Expression, field_type: &FieldType) -> bool {
+    if field_type.is_optional() && is_null(value) {
+        return true;
+    }
+
+    match field_type {
+        FieldType::Primitive(_, TypeValue::String, ..) => as_word_or_string(value).is_some(),
+        FieldType::Primitive(_, TypeValue::Int, ..) => value
+            .as_numeric_value()
+            .map_or(false, |(n, _)| n.parse::<i64>().is_ok()),
+        FieldType::Primitive(_, TypeValue::Float, ..) => value.as_numeric_value().is_some(),
+        FieldType::Primitive(_, TypeValue::Bool, ..) => as_bool(value).is_some(),
+        FieldType::Primitive(_, TypeValue::Null, ..) => is_null(value),
+        FieldType::Primitive(
+            _,
+            TypeValue::Media(_) | TypeValue::Date | TypeValue::DateTime | TypeValue::Duration,
+            ..,
+        ) => false,
+        FieldType::Literal(_, literal, ..) => match literal {
+            LiteralValue::String(s) => as_word_or_string(value) == Some(s.as_str()),
+            LiteralValue::Int(i) => value
+                .as_numeric_value()
+                .map_or(false, |(n, _)| n.parse::<i64>().ok() == Some(*i)),
+            LiteralValue::Float(f) => value
+                .as_numeric_value()
+                .map_or(false, |(n, _)| n.parse::<f64>().ok() == Some(*f)),
+            LiteralValue::Bool(b) => as_bool(value) == Some(*b),
+        },
+        FieldType::Symbol(_, idn, ..) => match ctx.db.find_type(idn) {
+            Some(Either::Right(enm)) => {
+                as_word_or_string(value).map_or(false, |v| enm.find_value(v).is_some())
+            }
+            Some(Either::Left(_)) => false,
+            None => ctx.db.find_type_alias(idn).map_or(false, |alias| {
+                default_matches_type(ctx, value, alias.target())
+            }),
+        },
+        FieldType::Union(_, variants, ..) => variants
+            .iter()
+            .any(|variant| default_matches_type(ctx, value, variant)),
+        FieldType::Tuple(_, items, ..) => match value {
+            Expression::Array(values, _) => {
+                values.len() == items.len()
+                    && values
+                        .iter()
+                        .zip(items)
+                        .all(|(v, item)| default_matches_type(ctx, v, item))
+            }
+            _ => false,
+        },
+        FieldType::List(_, item, dims, span, _) => match value {
+            Expression::Array(values, _) => {
+                let item = match *dims {
+                    1 => item.as_ref().clone(),
+                    _ => FieldType::List(
+                        FieldArity::Required,
+                        item.clone(),
+                        dims - 1,
+                        span.clone(),
+                        None,
+                    ),
+                };
+                values.iter().all(|v| default_matches_type(ctx, v, &item))
+            }
+            _ => false,
+        },
+        FieldType::Map(_, kv, ..) => match value {
+            Expression::Map(entries, _) => entries.iter().all(|(k, v)| {
+                default_matches_type(ctx, k, &kv.0) && default_matches_type(ctx, v, &kv.1)
+            }),
+            _ => false,
+        },
+    }
+}
+
+fn is_null(value: &Expression) -> bool {
+    matches!(value, Expression::Identifier(Identifier::Local(s, _)) if s == "null")
+}
+
+fn as_bool(value: &Expression) -> Option<bool> {
+    match value {
+        Expression::BoolValue(b, _) => Some(*b),
+        Expression::Identifier(Identifier::Local(s, _)) => match s.as_str() {
+            "true" => Some(true),
+            "false" => Some(false),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Unquoted words like `Active`, which are strings everywhere but in
+/// `true`, `false` and `null`.
+fn is_word(value: &Expression) -> bool {
+    matches!(value, Expression::Identifier(Identifier::Local(..)))
+        && as_bool(value).is_none()
+        && !is_null(value)
+}
+
+fn as_word_or_string(value: &Expression) -> Option<&str> {
+    match value {
+        Expression::StringValue(s, _) => Some(s.as_str()),
+        Expression::RawStringValue(s) => Some(s.value()),
+        Expression::Identifier(Identifier::String(s, _)) => Some(s.as_str()),
+        Expression::Identifier(Identifier::Local(s, _)) if is_word(value) => Some(s.as_str()),
+        _ => None,
+    }
+}
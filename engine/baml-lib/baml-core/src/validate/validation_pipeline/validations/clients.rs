@@ -21,6 +21,12 @@ pub(super) fn validate(ctx: &mut Context<'_>) {
             "round-robin",
             "baml-fallback",
             "fallback",
+            "baml-weighted",
+            "weighted",
+            "baml-least-latency",
+            "least-latency",
+            "baml-least-errors",
+            "least-errors",
             "google-ai",
             "vertex-ai",
             "aws-bedrock",
@@ -41,7 +47,10 @@ pub(super) fn validate(ctx: &mut Context<'_>) {
             ));
         }
 
-        if provider.as_str() == "fallback" || provider.as_str() == "round-robin" {
+        if matches!(
+            provider.as_str(),
+            "fallback" | "round-robin" | "weighted" | "least-latency" | "least-errors"
+        ) {
             let strategy_option = f.properties().options.iter().find(|(k, _)| k == "strategy");
             if let Some((_, strategy_expr)) = strategy_option {
                 if let Expression::Array(strategy_vec, _span) = strategy_expr {
@@ -1,28 +1,28 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use internal_baml_diagnostics::DatamodelError;
-use internal_baml_schema_ast::ast::{TypeExpId, WithIdentifier, WithName, WithSpan};
+use internal_baml_parser_database::{ParserDatabase, Tarjan};
+use internal_baml_schema_ast::ast::{
+    FieldArity, FieldType, TypeExpId, WithIdentifier, WithName, WithSpan,
+};
 
 use crate::validate::validation_pipeline::context::Context;
 
 pub(super) fn validate(ctx: &mut Context<'_>) {
-    // Validates if there's a cycle in any dependency graph.
+    validate_type_aliases(ctx);
+
+    // Validates if there's a cycle in any dependency graph. Only required
+    // fields count: a class can refer to itself through an optional field, a
+    // list or a map, since those let the recursion end.
     let mut deps_list = ctx
         .db
         .walk_classes()
         .map(|f| {
             (
                 f.id,
-                f.dependencies()
-                    .into_iter()
-                    .filter(|f| match ctx.db.find_type_by_str(f) {
-                        Some(either::Either::Left(_cls)) => true,
-                        // Don't worry about enum dependencies, they can't form cycles.
-                        Some(either::Either::Right(_enm)) => false,
-                        None => {
-                            panic!("Unknown class `{}`", f);
-                        }
-                    })
+                f.static_fields()
+                    .filter_map(|field| field.ast_field().expr.as_ref())
+                    .flat_map(|ft| required_class_deps(ctx.db, ft, &mut HashSet::new()))
                     .collect::<HashSet<_>>(),
             )
         })
@@ -97,3 +97,90 @@ pub(super) fn validate(ctx: &mut Context<'_>) {
         }
     }
 }
+
+/// The classes a value of `field_type` must contain, i.e. the ones it can't
+/// avoid by being null or empty.
+fn required_class_deps(
+    db: &ParserDatabase,
+    field_type: &FieldType,
+    visited_aliases: &mut HashSet<String>,
+) -> HashSet<String> {
+    match field_type {
+        FieldType::Symbol(FieldArity::Required, idn, ..) => match db.find_type(idn) {
+            Some(either::Either::Left(cls)) => HashSet::from([cls.name().to_string()]),
+            // Don't worry about enum dependencies, they can't form cycles.
+            Some(either::Either::Right(_enm)) => HashSet::new(),
+            None => match db.find_type_alias(idn) {
+                Some(alias) if visited_aliases.insert(alias.name().to_string()) => {
+                    required_class_deps(db, alias.target(), visited_aliases)
+                }
+                _ => HashSet::new(),
+            },
+        },
+        // Any one variant is enough, so only classes every variant needs count.
+        FieldType::Union(FieldArity::Required, variants, ..) => variants
+            .iter()
+            .map(|ft| required_class_deps(db, ft, &mut visited_aliases.clone()))
+            .reduce(|acc, deps| acc.intersection(&deps).cloned().collect())
+            .unwrap_or_default(),
+        FieldType::Tuple(FieldArity::Required, items, ..) => items
+            .iter()
+            .flat_map(|ft| required_class_deps(db, ft, visited_aliases))
+            .collect(),
+        _ => HashSet::new(),
+    }
+}
+
+/// Aliases may only refer to themselves through a list or a map, e.g.
+/// `type Json = string | Json[]`. Anything else, like `type A = B` and
+/// `type B = A`, never bottoms out in a real type.
+fn validate_type_aliases(ctx: &mut Context<'_>) {
+    let graph = ctx
+        .db
+        .walk_type_aliases()
+        .map(|alias| {
+            let mut deps = Vec::new();
+            direct_alias_deps(ctx.db, alias.target(), &mut deps);
+            deps.sort();
+            deps.dedup();
+            (alias.id, deps)
+        })
+        .collect::<HashMap<_, _>>();
+
+    for component in Tarjan::components(&graph) {
+        let cycle = component
+            .iter()
+            .map(|id| ctx.db.ast()[*id].name())
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        for id in &component {
+            ctx.push_error(DatamodelError::new_validation_error(
+                &format!("These aliases form a dependency cycle: {}", cycle),
+                ctx.db.ast()[*id].identifier().span().clone(),
+            ));
+        }
+    }
+}
+
+fn direct_alias_deps(
+    db: &ParserDatabase,
+    field_type: &FieldType,
+    deps: &mut Vec<internal_baml_schema_ast::ast::TypeAliasId>,
+) {
+    match field_type {
+        FieldType::Symbol(_, idn, ..) => {
+            if let Some(alias) = db.find_type_alias(idn) {
+                deps.push(alias.id);
+            }
+        }
+        FieldType::Union(_, items, ..) | FieldType::Tuple(_, items, ..) => {
+            for ft in items {
+                direct_alias_deps(db, ft, deps);
+            }
+        }
+        FieldType::List(..)
+        | FieldType::Map(..)
+        | FieldType::Primitive(..)
+        | FieldType::Literal(..) => {}
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use crate::{validate::validation_pipeline::context::Context};
 
 use either::Either;
@@ -82,7 +84,7 @@ pub(super) fn validate(ctx: &mut Context<'_>) {
             let field_type = &arg.1.field_type;
 
             let span = field_type.span().clone();
-            if has_checks_nested(ctx, field_type) {
+            if has_checks_nested(ctx, field_type, &mut HashSet::new()) {
                 ctx.push_error(DatamodelError::new_validation_error("Types with checks are not allowed as function parameters.", span));
             }
 
@@ -112,6 +114,53 @@ pub(super) fn validate(ctx: &mut Context<'_>) {
             }
         }
 
+        // Each tool call is parsed into the class named by its tool, so every
+        // tool must be a class the function can return as is (or a list of).
+        let mut returned_classes = HashSet::new();
+        if let Some(output) = func.walk_output_args().next() {
+            tool_classes(
+                ctx,
+                &output.ast_arg().1.field_type,
+                true,
+                &mut returned_classes,
+            );
+        }
+        for (tool, span) in func.metadata().tools.iter() {
+            match ctx.db.find_type_by_str(tool) {
+                Some(Either::Left(_)) => {
+                    if !returned_classes.contains(tool) {
+                        ctx.push_error(DatamodelError::new_validation_error(
+                            &format!(
+                                "Tool `{}` must be returned by `{}` directly, e.g. `-> {}`, `-> {} | OtherTool` or `-> {}[]`",
+                                tool,
+                                func.name(),
+                                tool,
+                                tool,
+                                tool
+                            ),
+                            span.clone(),
+                        ));
+                    }
+                }
+                Some(Either::Right(_)) => {
+                    ctx.push_error(DatamodelError::new_validation_error(
+                        &format!("Tool `{}` must be a class, not an enum", tool),
+                        span.clone(),
+                    ));
+                }
+                None => {
+                    ctx.push_error(DatamodelError::new_type_not_found_error(
+                        tool,
+                        ctx.db
+                            .walk_classes()
+                            .map(|c| c.name().to_string())
+                            .collect(),
+                        span.clone(),
+                    ));
+                }
+            }
+        }
+
         let prompt = match func.metadata().prompt.as_ref() {
             Some(prompt) => prompt,
             None => {
@@ -171,31 +220,65 @@ pub(super) fn validate(ctx: &mut Context<'_>) {
     }
 }
 
+/// Collects the classes a tool call can be parsed into for a function returning
+/// `field_type`: the type itself, the options of a union, or, when `allow_list`
+/// is set, the items of a list of those. Classes nested in fields don't count.
+fn tool_classes(
+    ctx: &Context<'_>,
+    field_type: &FieldType,
+    allow_list: bool,
+    classes: &mut HashSet<String>,
+) {
+    match field_type {
+        FieldType::Symbol(_, id, ..) => match ctx.db.find_type_alias(id) {
+            // Recursive aliases aren't expanded, so they can't be seen through.
+            Some(alias) if alias.is_recursive() => {}
+            Some(alias) => tool_classes(ctx, alias.target(), allow_list, classes),
+            None => {
+                classes.insert(id.name().to_string());
+            }
+        },
+        FieldType::Union(_, options, ..) => options
+            .iter()
+            .for_each(|option| tool_classes(ctx, option, false, classes)),
+        FieldType::List(_, item, 1, ..) if allow_list => tool_classes(ctx, item, false, classes),
+        _ => {}
+    }
+}
+
 /// Recusively search for `check` attributes in a field type and all of its
-/// composed children.
-fn has_checks_nested(ctx: &Context<'_>, field_type: &FieldType) -> bool {
+/// composed children. `visited` holds the classes and aliases already searched,
+/// which keeps recursive types from looping.
+fn has_checks_nested(ctx: &Context<'_>, field_type: &FieldType, visited: &mut HashSet<String>) -> bool {
     if field_type.has_checks() {
         return true;
     }
 
     match field_type {
         FieldType::Symbol(_, id, ..) => {
+            if !visited.insert(id.name().to_string()) {
+                return false;
+            }
             match ctx.db.find_type(id) {
                 Some(Either::Left(class_walker)) => {
                     let mut fields = class_walker.static_fields();
-                    fields.any(|field| field.ast_field().expr.as_ref().map_or(false, |ft| has_checks_nested(ctx, &ft)))
+                    fields.any(|field| field.ast_field().expr.as_ref().map_or(false, |ft| has_checks_nested(ctx, &ft, visited)))
                 }
                 ,
-                _ => false,
+                Some(Either::Right(_)) => false,
+                None => match ctx.db.find_type_alias(id) {
+                    Some(alias) => has_checks_nested(ctx, alias.target(), visited),
+                    None => false,
+                },
             }
         },
 
         FieldType::Primitive(..) => false,
-        FieldType::Union(_, children, ..) => children.iter().any(|ft| has_checks_nested(ctx, ft)),
+        FieldType::Union(_, children, ..) => children.iter().any(|ft| has_checks_nested(ctx, ft, visited)),
         FieldType::Literal(..) => false,
-        FieldType::Tuple(_, children, ..) => children.iter().any(|ft| has_checks_nested(ctx, ft)),
-        FieldType::List(_, child, ..) => has_checks_nested(ctx, child),
+        FieldType::Tuple(_, children, ..) => children.iter().any(|ft| has_checks_nested(ctx, ft, visited)),
+        FieldType::List(_, child, ..) => has_checks_nested(ctx, child, visited),
         FieldType::Map(_, kv, ..) =>
-            has_checks_nested(ctx, &kv.as_ref().0) || has_checks_nested(ctx, &kv.as_ref().1),
+            has_checks_nested(ctx, &kv.as_ref().0, visited) || has_checks_nested(ctx, &kv.as_ref().1, visited),
     }
 }
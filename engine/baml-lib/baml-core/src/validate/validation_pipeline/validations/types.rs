@@ -1,6 +1,6 @@
-use baml_types::TypeValue;
+use baml_types::{Refinement, TypeValue};
 use internal_baml_diagnostics::DatamodelError;
-use internal_baml_schema_ast::ast::{Argument, Attribute, Expression, FieldArity, FieldType, Identifier, WithName, WithSpan};
+use internal_baml_schema_ast::ast::{FieldArity, FieldType, Identifier, WithName, WithSpan};
 
 use crate::validate::validation_pipeline::context::Context;
 
@@ -24,6 +24,7 @@ pub(crate) fn validate_type(ctx: &mut Context<'_>, field_type: &FieldType) {
     validate_type_exists(ctx, field_type);
     validate_type_allowed(ctx, field_type);
     validate_type_constraints(ctx, field_type);
+    validate_type_refinements(ctx, field_type);
 }
 
 fn validate_type_exists(ctx: &mut Context<'_>, field_type: &FieldType) -> bool {
@@ -33,6 +34,7 @@ fn validate_type_exists(ctx: &mut Context<'_>, field_type: &FieldType) -> bool {
         .iter()
         .for_each(|f| match ctx.db.find_type(f) {
             Some(_) => {}
+            None if ctx.db.find_type_alias(f).is_some() => {}
 
             None => match field_type {
                 FieldType::Primitive(..) => {}
@@ -89,28 +91,76 @@ fn validate_type_allowed(ctx: &mut Context<'_>, field_type: &FieldType) {
 }
 
 fn validate_type_constraints(ctx: &mut Context<'_>, field_type: &FieldType) {
-    let constraint_attrs = field_type.attributes().iter().filter(|attr| ["assert", "check"].contains(&attr.name.name())).collect::<Vec<_>>();
-    for Attribute { arguments, span, name, .. } in constraint_attrs.iter() {
-        let arg_expressions = arguments.arguments.iter().map(|Argument{value,..}| value).collect::<Vec<_>>();
+    for attr in field_type.attributes() {
+        if let Some(Err(message)) = attr.constraint() {
+            ctx.push_error(DatamodelError::new_validation_error(
+                &message,
+                attr.span.clone(),
+            ));
+        }
+    }
+}
 
-            match arg_expressions.as_slice() {
-                [ Expression::Identifier(Identifier::Local(s,_)), Expression::JinjaExpressionValue(_, _)] => {
-                    // Ok.
-                },
-                [Expression::JinjaExpressionValue(_, _)] => {
-                    if name.to_string() == "check" {
-                        ctx.push_error(DatamodelError::new_validation_error(
-                            "Check constraints must have a name.",
-                            span.clone()
-                        ))
-                    }
-                },
-                _ => {
+/// Checks the arguments of every `@range` and `@length`, and that they're on
+/// types they can bound.
+fn validate_type_refinements(ctx: &mut Context<'_>, field_type: &FieldType) {
+    for attr in field_type.attributes() {
+        match attr.refinement() {
+            None => {}
+            Some(Err(message)) => ctx.push_error(DatamodelError::new_validation_error(
+                &message,
+                attr.span.clone(),
+            )),
+            Some(Ok(refinement)) => {
+                if !can_refine(ctx, &refinement, field_type) {
+                    let allowed = match refinement {
+                        Refinement::Range { .. } => "`int` and `float`",
+                        Refinement::Length { .. } => "`string`, list and map",
+                    };
                     ctx.push_error(DatamodelError::new_validation_error(
-                        "A constraint must have one Jinja argument such as {{ expr }}, and optionally one String label",
-                        span.clone()
+                        &format!(
+                            "`@{}` can only be used on {} types.",
+                            attr.name.name(),
+                            allowed
+                        ),
+                        attr.span.clone(),
                     ));
                 }
+            }
+        }
+    }
+
+    match field_type {
+        FieldType::List(_, item, ..) => validate_type_refinements(ctx, item),
+        FieldType::Map(_, kv, ..) => {
+            validate_type_refinements(ctx, &kv.0);
+            validate_type_refinements(ctx, &kv.1);
+        }
+        FieldType::Tuple(_, items, ..) | FieldType::Union(_, items, ..) => {
+            for item in items {
+                validate_type_refinements(ctx, item);
+            }
         }
+        FieldType::Primitive(..) | FieldType::Literal(..) | FieldType::Symbol(..) => {}
+    }
+}
+
+fn can_refine(ctx: &Context<'_>, refinement: &Refinement, field_type: &FieldType) -> bool {
+    match (refinement, field_type) {
+        (
+            Refinement::Range { .. },
+            FieldType::Primitive(_, TypeValue::Int | TypeValue::Float, ..),
+        ) => true,
+        (Refinement::Length { .. }, FieldType::Primitive(_, TypeValue::String, ..)) => true,
+        (Refinement::Length { .. }, FieldType::List(..) | FieldType::Map(..)) => true,
+        (_, FieldType::Union(_, variants, ..)) => variants.iter().all(|variant| {
+            matches!(variant, FieldType::Primitive(_, TypeValue::Null, ..))
+                || can_refine(ctx, refinement, variant)
+        }),
+        (_, FieldType::Symbol(_, idn, ..)) => ctx
+            .db
+            .find_type_alias(idn)
+            .map_or(false, |alias| can_refine(ctx, refinement, alias.target())),
+        _ => false,
     }
 }
@@ -5,8 +5,10 @@ use indexmap::IndexMap;
 use serde::ser::{SerializeMap, SerializeSeq};
 use serde::{de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
 
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate};
+
 use crate::media::BamlMediaType;
-use crate::{BamlMap, BamlMedia, ResponseCheck};
+use crate::{temporal, BamlMap, BamlMedia, ResponseCheck};
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum BamlValue {
@@ -14,6 +16,9 @@ pub enum BamlValue {
     Int(i64),
     Float(f64),
     Bool(bool),
+    Date(NaiveDate),
+    DateTime(DateTime<FixedOffset>),
+    Duration(Duration),
     Map(BamlMap<String, BamlValue>),
     List(Vec<BamlValue>),
     Media(BamlMedia),
@@ -29,6 +34,9 @@ impl serde::Serialize for BamlValue {
             BamlValue::Int(i) => serializer.serialize_i64(*i),
             BamlValue::Float(f) => serializer.serialize_f64(*f),
             BamlValue::Bool(b) => serializer.serialize_bool(*b),
+            BamlValue::Date(d) => serializer.serialize_str(&temporal::format_date(d)),
+            BamlValue::DateTime(d) => serializer.serialize_str(&temporal::format_datetime(d)),
+            BamlValue::Duration(d) => serializer.serialize_str(&temporal::format_duration(d)),
             BamlValue::Map(m) => m.serialize(serializer),
             BamlValue::List(l) => l.serialize(serializer),
             BamlValue::Media(m) => {
@@ -68,6 +76,9 @@ impl BamlValue {
             BamlValue::Int(_) => "int".into(),
             BamlValue::Float(_) => "float".into(),
             BamlValue::Bool(_) => "bool".into(),
+            BamlValue::Date(_) => "date".into(),
+            BamlValue::DateTime(_) => "datetime".into(),
+            BamlValue::Duration(_) => "duration".into(),
             BamlValue::Map(kv) => {
                 let value_types = kv
                     .values()
@@ -358,6 +369,9 @@ pub enum BamlValueWithMeta<T> {
     Int(i64, T),
     Float(f64, T),
     Bool(bool, T),
+    Date(NaiveDate, T),
+    DateTime(DateTime<FixedOffset>, T),
+    Duration(Duration, T),
     Map(BamlMap<String, BamlValueWithMeta<T>>, T),
     List(Vec<BamlValueWithMeta<T>>, T),
     Media(BamlMedia, T),
@@ -385,6 +399,9 @@ impl<T> BamlValueWithMeta<T> {
             BamlValueWithMeta::Int(v, _) => BamlValue::Int(v),
             BamlValueWithMeta::Float(v, _) => BamlValue::Float(v),
             BamlValueWithMeta::Bool(v, _) => BamlValue::Bool(v),
+            BamlValueWithMeta::Date(v, _) => BamlValue::Date(v),
+            BamlValueWithMeta::DateTime(v, _) => BamlValue::DateTime(v),
+            BamlValueWithMeta::Duration(v, _) => BamlValue::Duration(v),
             BamlValueWithMeta::Map(v, _) => {
                 BamlValue::Map(v.into_iter().map(|(k, v)| (k, v.value())).collect())
             }
@@ -406,6 +423,9 @@ impl<T> BamlValueWithMeta<T> {
             BamlValueWithMeta::Int(_, m) => m,
             BamlValueWithMeta::Float(_, m) => m,
             BamlValueWithMeta::Bool(_, m) => m,
+            BamlValueWithMeta::Date(_, m) => m,
+            BamlValueWithMeta::DateTime(_, m) => m,
+            BamlValueWithMeta::Duration(_, m) => m,
             BamlValueWithMeta::Map(_, m) => m,
             BamlValueWithMeta::List(_, m) => m,
             BamlValueWithMeta::Media(_, m) => m,
@@ -421,6 +441,9 @@ impl<T> BamlValueWithMeta<T> {
             BamlValueWithMeta::Int(_, m) => m,
             BamlValueWithMeta::Float(_, m) => m,
             BamlValueWithMeta::Bool(_, m) => m,
+            BamlValueWithMeta::Date(_, m) => m,
+            BamlValueWithMeta::DateTime(_, m) => m,
+            BamlValueWithMeta::Duration(_, m) => m,
             BamlValueWithMeta::Map(_, m) => m,
             BamlValueWithMeta::List(_, m) => m,
             BamlValueWithMeta::Media(_, m) => m,
@@ -437,6 +460,9 @@ impl<T> BamlValueWithMeta<T> {
             BamlValue::Int(i) => Int(*i, T::default()),
             BamlValue::Float(f) => Float(*f, T::default()),
             BamlValue::Bool(b) => Bool(*b, T::default()),
+            BamlValue::Date(d) => Date(*d, T::default()),
+            BamlValue::DateTime(d) => DateTime(*d, T::default()),
+            BamlValue::Duration(d) => Duration(*d, T::default()),
             BamlValue::Map(entries) => BamlValueWithMeta::Map(entries.iter().map(|(k,v)| (k.clone(), Self::with_default_meta(v))).collect(), T::default()),
             BamlValue::List(items) => List(items.iter().map(|i| Self::with_default_meta(i)).collect(), T::default()),
             BamlValue::Media(m) => Media(m.clone(), T::default()),
@@ -455,6 +481,9 @@ impl<T> BamlValueWithMeta<T> {
             BamlValueWithMeta::Int(v, m) => BamlValueWithMeta::Int(*v, f(m)),
             BamlValueWithMeta::Float(v, m) => BamlValueWithMeta::Float(*v, f(m)),
             BamlValueWithMeta::Bool(v, m) => BamlValueWithMeta::Bool(*v, f(m)),
+            BamlValueWithMeta::Date(v, m) => BamlValueWithMeta::Date(*v, f(m)),
+            BamlValueWithMeta::DateTime(v, m) => BamlValueWithMeta::DateTime(*v, f(m)),
+            BamlValueWithMeta::Duration(v, m) => BamlValueWithMeta::Duration(*v, f(m)),
             BamlValueWithMeta::Map(v, m) => BamlValueWithMeta::Map(
                 v.iter().map(|(k, v)| (k.clone(), v.map_meta(f))).collect(),
                 f(m),
@@ -513,6 +542,8 @@ impl <'a,T:'a> Iterator for BamlValueWithMetaIterator<'a,T> {
                 // These items have to children.
                 BamlValueWithMeta::String(..) | BamlValueWithMeta::Int(..) |
                 BamlValueWithMeta::Float(..) | BamlValueWithMeta::Bool(..) |
+                BamlValueWithMeta::Date(..) | BamlValueWithMeta::DateTime(..) |
+                BamlValueWithMeta::Duration(..) |
                 BamlValueWithMeta::Media(..) | BamlValueWithMeta::Enum(..) |
                 BamlValueWithMeta::Null(..) => {}
             }
@@ -540,6 +571,9 @@ impl <T> From<&BamlValueWithMeta<T>> for BamlValue {
             Int(v, _) => BamlValue::Int(v.clone()),
             Float(v, _) => BamlValue::Float(v.clone()),
             Bool(v, _) => BamlValue::Bool(v.clone()),
+            Date(v, _) => BamlValue::Date(*v),
+            DateTime(v, _) => BamlValue::DateTime(*v),
+            Duration(v, _) => BamlValue::Duration(*v),
             Map(v, _) => BamlValue::Map(v.into_iter().map(|(k,v)| (k.clone(), v.into())).collect()),
             List(v, _) => BamlValue::List(v.into_iter().map(|v| v.into()).collect()),
             Media(v, _) => BamlValue::Media(v.clone()),
@@ -558,6 +592,9 @@ impl <T> From<BamlValueWithMeta<T>> for BamlValue {
             Int(v, _) => BamlValue::Int(v),
             Float(v, _) => BamlValue::Float(v),
             Bool(v, _) => BamlValue::Bool(v),
+            Date(v, _) => BamlValue::Date(v),
+            DateTime(v, _) => BamlValue::DateTime(v),
+            Duration(v, _) => BamlValue::Duration(v),
             Map(v, _) => BamlValue::Map(v.into_iter().map(|(k,v)| (k, v.into())).collect()),
             List(v, _) => BamlValue::List(v.into_iter().map(|v| v.into()).collect()),
             Media(v, _) => BamlValue::Media(v),
@@ -580,6 +617,15 @@ impl Serialize for BamlValueWithMeta<Vec<ResponseCheck>> {
             BamlValueWithMeta::Int(v, cr) => serialize_with_checks(v, cr, serializer),
             BamlValueWithMeta::Float(v, cr) => serialize_with_checks(v, cr, serializer),
             BamlValueWithMeta::Bool(v, cr) => serialize_with_checks(v, cr, serializer),
+            BamlValueWithMeta::Date(v, cr) => {
+                serialize_with_checks(&temporal::format_date(v), cr, serializer)
+            }
+            BamlValueWithMeta::DateTime(v, cr) => {
+                serialize_with_checks(&temporal::format_datetime(v), cr, serializer)
+            }
+            BamlValueWithMeta::Duration(v, cr) => {
+                serialize_with_checks(&temporal::format_duration(v), cr, serializer)
+            }
             BamlValueWithMeta::Map(v, cr) => {
                 let mut map = serializer.serialize_map(None)?;
                 for (key, value) in v {
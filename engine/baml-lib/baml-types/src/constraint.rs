@@ -5,6 +5,28 @@ pub struct Constraint {
     pub level: ConstraintLevel,
     pub expression: JinjaExpression,
     pub label: Option<String>,
+    /// Set for the constraints built from `@range` and `@length`, whose
+    /// `expression` is generated.
+    pub refinement: Option<Refinement>,
+}
+
+impl Constraint {
+    /// The `@assert` equivalent of a `@range` or `@length` attribute. A
+    /// `nullable` type accepts `null` as well as values within the bounds.
+    pub fn from_refinement(refinement: Refinement, nullable: bool) -> Self {
+        let expression = refinement.expression();
+        let expression = if nullable {
+            format!("this is none or ({expression})")
+        } else {
+            expression
+        };
+        Constraint {
+            level: ConstraintLevel::Assert,
+            expression: JinjaExpression(expression),
+            label: None,
+            refinement: Some(refinement),
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, serde::Serialize)]
@@ -21,6 +43,57 @@ pub struct ResponseCheck {
     pub status: String,
 }
 
+/// Inclusive bounds declared with `@range(min, max)` on a number or
+/// `@length(min, max)` on a string, list or map. Either bound may be open.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+pub enum Refinement {
+    Range { min: Option<f64>, max: Option<f64> },
+    Length { min: Option<u64>, max: Option<u64> },
+}
+
+impl Refinement {
+    fn bounds(&self) -> (Option<String>, Option<String>) {
+        match self {
+            Refinement::Range { min, max } => {
+                (min.map(|n| n.to_string()), max.map(|n| n.to_string()))
+            }
+            Refinement::Length { min, max } => {
+                (min.map(|n| n.to_string()), max.map(|n| n.to_string()))
+            }
+        }
+    }
+
+    /// The Jinja predicate on `this`, e.g. `this >= 0 and this <= 10`.
+    fn expression(&self) -> String {
+        let subject = match self {
+            Refinement::Range { .. } => "this",
+            Refinement::Length { .. } => "this|length",
+        };
+        match self.bounds() {
+            (Some(min), Some(max)) => format!("{subject} >= {min} and {subject} <= {max}"),
+            (Some(min), None) => format!("{subject} >= {min}"),
+            (None, Some(max)) => format!("{subject} <= {max}"),
+            (None, None) => "true".to_string(),
+        }
+    }
+
+    /// How the bounds are described in the output format, e.g.
+    /// `between 0 and 10` or `length at most 280`.
+    pub fn description(&self) -> String {
+        let prefix = match self {
+            Refinement::Range { .. } => "",
+            Refinement::Length { .. } => "length ",
+        };
+        match self.bounds() {
+            (Some(min), Some(max)) if min == max => format!("{prefix}exactly {min}"),
+            (Some(min), Some(max)) => format!("{prefix}between {min} and {max}"),
+            (Some(min), None) => format!("{prefix}at least {min}"),
+            (None, Some(max)) => format!("{prefix}at most {max}"),
+            (None, None) => format!("{prefix}unbounded"),
+        }
+    }
+}
+
 impl ResponseCheck {
     /// Convert a Constraint and its status to a ResponseCheck.
     /// Returns `None` if the Constraint is not a check (i.e.,
@@ -32,6 +105,7 @@ impl ResponseCheck {
                 level,
                 expression,
                 label,
+                ..
             },
             succeeded,
         ): (Constraint, bool),
@@ -53,3 +127,29 @@ impl ResponseCheck {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn refinements_as_expressions() {
+        let range = Refinement::Range {
+            min: Some(0.0),
+            max: Some(2.5),
+        };
+        assert_eq!(range.expression(), "this >= 0 and this <= 2.5");
+        assert_eq!(range.description(), "between 0 and 2.5");
+
+        let length = Refinement::Length {
+            min: Some(1),
+            max: None,
+        };
+        let constraint = Constraint::from_refinement(length.clone(), true);
+        assert_eq!(
+            constraint.expression.0,
+            "this is none or (this|length >= 1)"
+        );
+        assert_eq!(length.description(), "length at least 1");
+    }
+}
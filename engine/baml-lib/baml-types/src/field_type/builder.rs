@@ -13,6 +13,10 @@ impl FieldType {
         FieldType::Literal(super::LiteralValue::Int(value))
     }
 
+    pub fn literal_float(value: f64) -> Self {
+        FieldType::Literal(super::LiteralValue::Float(value))
+    }
+
     pub fn literal_bool(value: bool) -> Self {
         FieldType::Literal(super::LiteralValue::Bool(value))
     }
@@ -37,6 +41,18 @@ impl FieldType {
         FieldType::Primitive(TypeValue::Media(BamlMediaType::Image))
     }
 
+    pub fn date() -> Self {
+        FieldType::Primitive(TypeValue::Date)
+    }
+
+    pub fn datetime() -> Self {
+        FieldType::Primitive(TypeValue::DateTime)
+    }
+
+    pub fn duration() -> Self {
+        FieldType::Primitive(TypeValue::Duration)
+    }
+
     pub fn r#enum(name: &str) -> Self {
         FieldType::Enum(name.to_string())
     }
@@ -45,6 +61,10 @@ impl FieldType {
         FieldType::Class(name.to_string())
     }
 
+    pub fn recursive_type_alias(name: &str) -> Self {
+        FieldType::RecursiveTypeAlias(name.to_string())
+    }
+
     pub fn list(inner: FieldType) -> Self {
         FieldType::List(Box::new(inner))
     }
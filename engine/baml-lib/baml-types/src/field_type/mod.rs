@@ -13,6 +13,9 @@ pub enum TypeValue {
     // Char,
     Null,
     Media(BamlMediaType),
+    Date,
+    DateTime,
+    Duration,
 }
 impl TypeValue {
     pub fn from_str(s: &str) -> Option<TypeValue> {
@@ -24,6 +27,9 @@ impl TypeValue {
             "null" => Some(TypeValue::Null),
             "image" => Some(TypeValue::Media(BamlMediaType::Image)),
             "audio" => Some(TypeValue::Media(BamlMediaType::Audio)),
+            "date" => Some(TypeValue::Date),
+            "datetime" => Some(TypeValue::DateTime),
+            "duration" => Some(TypeValue::Duration),
             _ => None,
         }
     }
@@ -38,15 +44,19 @@ impl std::fmt::Display for TypeValue {
             TypeValue::Null => write!(f, "null"),
             TypeValue::Media(BamlMediaType::Image) => write!(f, "image"),
             TypeValue::Media(BamlMediaType::Audio) => write!(f, "audio"),
+            TypeValue::Date => write!(f, "date"),
+            TypeValue::DateTime => write!(f, "datetime"),
+            TypeValue::Duration => write!(f, "duration"),
         }
     }
 }
 
 /// Subset of [`crate::BamlValue`] allowed for literal type definitions.
-#[derive(serde::Serialize, Debug, Clone, PartialOrd, Ord, PartialEq, Eq)]
+#[derive(serde::Serialize, Debug, Clone)]
 pub enum LiteralValue {
     String(String),
     Int(i64),
+    Float(f64),
     Bool(bool),
 }
 
@@ -55,16 +65,56 @@ impl LiteralValue {
         match self {
             Self::String(_) => FieldType::string(),
             Self::Int(_) => FieldType::int(),
+            Self::Float(_) => FieldType::float(),
             Self::Bool(_) => FieldType::bool(),
         }
     }
+
+    fn variant_index(&self) -> u8 {
+        match self {
+            Self::String(_) => 0,
+            Self::Int(_) => 1,
+            Self::Float(_) => 2,
+            Self::Bool(_) => 3,
+        }
+    }
+}
+
+// Floats are ordered with `f64::total_cmp` so literals can still be sorted
+// and deduplicated.
+impl Ord for LiteralValue {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Self::String(a), Self::String(b)) => a.cmp(b),
+            (Self::Int(a), Self::Int(b)) => a.cmp(b),
+            (Self::Float(a), Self::Float(b)) => a.total_cmp(b),
+            (Self::Bool(a), Self::Bool(b)) => a.cmp(b),
+            _ => self.variant_index().cmp(&other.variant_index()),
+        }
+    }
 }
 
+impl PartialOrd for LiteralValue {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for LiteralValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for LiteralValue {}
+
 impl std::fmt::Display for LiteralValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             LiteralValue::String(str) => write!(f, "\"{str}\""),
             LiteralValue::Int(int) => write!(f, "{int}"),
+            // Debug keeps the decimal point, so `1.0` doesn't read as an int.
+            LiteralValue::Float(float) => write!(f, "{float:?}"),
             LiteralValue::Bool(bool) => write!(f, "{bool}"),
         }
     }
@@ -77,6 +127,9 @@ pub enum FieldType {
     Enum(String),
     Literal(LiteralValue),
     Class(String),
+    /// A reference to a `type` alias that refers to itself, e.g.
+    /// `type Json = string | Json[]`. Other aliases are expanded in place.
+    RecursiveTypeAlias(String),
     List(Box<FieldType>),
     Map(Box<FieldType>, Box<FieldType>),
     Union(Vec<FieldType>),
@@ -89,7 +142,9 @@ pub enum FieldType {
 impl std::fmt::Display for FieldType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            FieldType::Enum(name) | FieldType::Class(name) => {
+            FieldType::Enum(name)
+            | FieldType::Class(name)
+            | FieldType::RecursiveTypeAlias(name) => {
                 write!(f, "{}", name)
             }
             FieldType::Primitive(t) => write!(f, "{}", t),
@@ -206,7 +261,7 @@ mod tests {
     #[test]
     fn test_nested_constraint_distribution() {
         fn mk_constraint(s: &str) -> Constraint {
-            Constraint { level: ConstraintLevel::Assert, expression: JinjaExpression(s.to_string()), label: Some(s.to_string()) }
+            Constraint { level: ConstraintLevel::Assert, expression: JinjaExpression(s.to_string()), label: Some(s.to_string()), refinement: None }
         }
 
         let input = FieldType::Constrained {
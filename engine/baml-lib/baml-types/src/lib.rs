@@ -2,10 +2,12 @@ mod constraint;
 mod map;
 mod media;
 mod minijinja;
+mod streaming;
 
 mod baml_value;
 mod field_type;
 mod generator;
+pub mod temporal;
 
 pub use baml_value::{BamlValue, BamlValueWithMeta};
 pub use constraint::*;
@@ -14,3 +16,4 @@ pub use generator::{GeneratorDefaultClientMode, GeneratorOutputType};
 pub use map::Map as BamlMap;
 pub use media::{BamlMedia, BamlMediaContent, BamlMediaType, MediaBase64, MediaUrl};
 pub use minijinja::JinjaExpression;
+pub use streaming::{CompletionState, StreamingBehavior, STREAM_STATE_CLASS};
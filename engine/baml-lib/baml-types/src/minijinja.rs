@@ -21,6 +21,9 @@ impl From<BamlValue> for minijinja::Value {
             BamlValue::Int(n) => minijinja::Value::from(n),
             BamlValue::Float(n) => minijinja::Value::from(n),
             BamlValue::Bool(b) => minijinja::Value::from(b),
+            BamlValue::Date(d) => minijinja::Value::from(crate::temporal::format_date(&d)),
+            BamlValue::DateTime(d) => minijinja::Value::from(crate::temporal::format_datetime(&d)),
+            BamlValue::Duration(d) => minijinja::Value::from(crate::temporal::format_duration(&d)),
             BamlValue::Map(m) => {
                 let map = m.into_iter().map(|(k, v)| (k, minijinja::Value::from(v)));
                 minijinja::Value::from_iter(map)
@@ -0,0 +1,42 @@
+/// How a class field (or a whole class) behaves while its value is being
+/// streamed, as set by the `@stream.*` attributes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize)]
+pub struct StreamingBehavior {
+    /// `@stream.done`: only emit the value once it has been fully parsed.
+    pub done: bool,
+    /// `@stream.not_null`: hold back the parent until this field is present.
+    pub not_null: bool,
+    /// `@stream.with_state`: wrap the value with its [`CompletionState`].
+    pub with_state: bool,
+}
+
+/// The class that partial `@stream.with_state` values are wrapped in, with a
+/// `value` and its `state`.
+pub const STREAM_STATE_CLASS: &str = "StreamState";
+
+/// How far along a streamed value is, reported for `@stream.with_state` fields.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+pub enum CompletionState {
+    /// Nothing has been received for the value yet.
+    Pending,
+    /// Part of the value has been received.
+    Incomplete,
+    /// The whole value has been received.
+    Complete,
+}
+
+impl CompletionState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CompletionState::Pending => "Pending",
+            CompletionState::Incomplete => "Incomplete",
+            CompletionState::Complete => "Complete",
+        }
+    }
+}
+
+impl std::fmt::Display for CompletionState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
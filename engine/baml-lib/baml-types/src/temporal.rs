@@ -0,0 +1,150 @@
+//! Text forms of the `date`, `datetime` and `duration` primitives.
+//!
+//! These are the strict ISO 8601 forms used to exchange values with the
+//! language clients. The parser in `jsonish` accepts a lot more when reading
+//! LLM output.
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Utc};
+
+/// `YYYY-MM-DD`.
+pub fn format_date(date: &NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+/// RFC 3339, e.g. `2024-01-31T09:30:00+00:00`.
+pub fn format_datetime(datetime: &DateTime<FixedOffset>) -> String {
+    datetime.to_rfc3339()
+}
+
+/// ISO 8601, e.g. `PT1H30M` or `P2DT4H`. Days are always 24 hours.
+pub fn format_duration(duration: &Duration) -> String {
+    let (sign, duration) = if *duration < Duration::zero() {
+        ("-", -*duration)
+    } else {
+        ("", *duration)
+    };
+    let total = duration.num_seconds();
+    let nanos = (duration - Duration::seconds(total))
+        .num_nanoseconds()
+        .unwrap_or(0);
+
+    let (days, hours, minutes, seconds) = (
+        total / 86_400,
+        total / 3_600 % 24,
+        total / 60 % 60,
+        total % 60,
+    );
+
+    let mut out = format!("{sign}P");
+    if days > 0 {
+        out.push_str(&format!("{days}D"));
+    }
+    if days == 0 || hours > 0 || minutes > 0 || seconds > 0 || nanos > 0 {
+        out.push('T');
+        if hours > 0 {
+            out.push_str(&format!("{hours}H"));
+        }
+        if minutes > 0 {
+            out.push_str(&format!("{minutes}M"));
+        }
+        if nanos > 0 {
+            let fraction = format!("{nanos:09}");
+            out.push_str(&format!("{seconds}.{}S", fraction.trim_end_matches('0')));
+        } else if seconds > 0 || (hours == 0 && minutes == 0) {
+            out.push_str(&format!("{seconds}S"));
+        }
+    }
+    out
+}
+
+/// Parses `YYYY-MM-DD`.
+pub fn parse_date(s: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(s.trim(), "%Y-%m-%d").ok()
+}
+
+/// Parses RFC 3339. A datetime without an offset is taken to be UTC.
+pub fn parse_datetime(s: &str) -> Option<DateTime<FixedOffset>> {
+    let s = s.trim();
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(s) {
+        return Some(datetime);
+    }
+    ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(s, format).ok())
+        .map(|naive| Utc.from_utc_datetime(&naive).into())
+}
+
+/// Parses an ISO 8601 duration made of weeks, days, hours, minutes and
+/// seconds, e.g. `P1W`, `PT90M` or `-P1DT2.5S`. Years and months are rejected
+/// since their length depends on the calendar.
+pub fn parse_duration(s: &str) -> Option<Duration> {
+    let s = s.trim();
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    let s = s.strip_prefix('P').or_else(|| s.strip_prefix('p'))?;
+    if s.is_empty() {
+        return None;
+    }
+
+    let mut total = Duration::zero();
+    let mut in_time = false;
+    // Parts read so far, e.g. `1H` or `30M`, and how many came after `T`.
+    let (mut parts, mut time_parts) = (0, 0);
+    let mut number = String::new();
+    for c in s.chars() {
+        match c.to_ascii_uppercase() {
+            'T' if !in_time && number.is_empty() => in_time = true,
+            c if c.is_ascii_digit() || c == '.' => number.push(c),
+            unit => {
+                let value: f64 = number.parse().ok()?;
+                number.clear();
+                let seconds = match (unit, in_time) {
+                    ('W', false) => 604_800.0,
+                    ('D', false) => 86_400.0,
+                    ('H', true) => 3_600.0,
+                    ('M', true) => 60.0,
+                    ('S', true) => 1.0,
+                    _ => return None,
+                };
+                total = total + Duration::nanoseconds((value * seconds * 1e9).round() as i64);
+                parts += 1;
+                if in_time {
+                    time_parts += 1;
+                }
+            }
+        }
+    }
+    if !number.is_empty() || parts == 0 || (in_time && time_parts == 0) {
+        return None;
+    }
+
+    Some(if negative { -total } else { total })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn durations_round_trip() {
+        for text in ["PT0S", "PT1H30M", "P2DT4H", "PT0.25S", "-PT45S", "P1DT1S"] {
+            let duration = parse_duration(text).unwrap();
+            assert_eq!(format_duration(&duration), text);
+        }
+        assert_eq!(parse_duration("P1W"), Some(Duration::days(7)));
+        assert_eq!(parse_duration("P1M"), None);
+        assert_eq!(parse_duration("PT"), None);
+    }
+
+    #[test]
+    fn datetimes_without_offset_are_utc() {
+        let datetime = parse_datetime("2024-01-31T09:30:00").unwrap();
+        assert_eq!(format_datetime(&datetime), "2024-01-31T09:30:00+00:00");
+        assert_eq!(
+            parse_datetime("2024-01-31T09:30:00-05:00").map(|d| d.offset().local_minus_utc()),
+            Some(-5 * 3_600)
+        );
+    }
+}
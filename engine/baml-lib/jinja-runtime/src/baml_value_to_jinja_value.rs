@@ -25,6 +25,10 @@ impl IntoMiniJinjaValue for BamlValue {
             BamlValue::Int(n) => minijinja::Value::from(n.clone()),
             BamlValue::Float(n) => minijinja::Value::from(n.clone()),
             BamlValue::Bool(b) => minijinja::Value::from(b.clone()),
+            // Dates are rendered into prompts in their ISO 8601 form.
+            BamlValue::Date(_) | BamlValue::DateTime(_) | BamlValue::Duration(_) => {
+                minijinja::Value::from(self.clone())
+            }
             BamlValue::Map(m) => {
                 let map = m
                     .into_iter()
@@ -147,10 +147,27 @@ impl minijinja::value::Object for OutputFormat {
             None
         };
 
+        let hide_defaulted_fields = if kwargs.has("hide_defaulted_fields") {
+            match kwargs.get::<bool>("hide_defaulted_fields") {
+                Ok(hide_defaulted_fields) => Some(hide_defaulted_fields),
+                Err(e) => {
+                    return Err(Error::new(
+                        ErrorKind::SyntaxError,
+                        format!(
+                            "Invalid value for hide_defaulted_fields (expected bool): {}",
+                            e
+                        ),
+                    ))
+                }
+            }
+        } else {
+            None
+        };
+
         let Ok(_) = kwargs.assert_all_used() else {
             return Err(Error::new(
                 ErrorKind::TooManyArguments,
-                "output_format() got an unexpected keyword argument (only 'prefix', 'always_host_enums', 'enum_value_prefix', and 'or_splitter' are allowed)",
+                "output_format() got an unexpected keyword argument (only 'prefix', 'always_host_enums', 'enum_value_prefix', 'or_splitter', 'map_style' and 'hide_defaulted_fields' are allowed)",
             ));
         };
 
@@ -160,6 +177,7 @@ impl minijinja::value::Object for OutputFormat {
             enum_value_prefix,
             always_hoist_enums,
             map_style,
+            hide_defaulted_fields,
         ))?;
 
         match content {
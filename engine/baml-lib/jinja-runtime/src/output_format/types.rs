@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use anyhow::Result;
-use baml_types::{FieldType, LiteralValue, TypeValue, Constraint};
+use baml_types::{BamlValue, Constraint, FieldType, LiteralValue, StreamingBehavior, TypeValue};
 use indexmap::{IndexMap, IndexSet};
 
 #[derive(Debug)]
@@ -48,15 +48,27 @@ pub struct Enum {
 #[derive(Debug)]
 pub struct Class {
     pub name: Name,
-    // fields have name, type and description.
-    pub fields: Vec<(Name, FieldType, Option<String>)>,
+    // fields have name, type, description, streaming behavior and default value.
+    pub fields: Vec<(
+        Name,
+        FieldType,
+        Option<String>,
+        StreamingBehavior,
+        Option<BamlValue>,
+    )>,
     pub constraints: Vec<Constraint>,
+    pub streaming_behavior: StreamingBehavior,
 }
 
 #[derive(Debug, Clone)]
 pub struct OutputFormatContent {
     enums: Arc<IndexMap<String, Enum>>,
     classes: Arc<IndexMap<String, Class>>,
+    /// Classes that refer to themselves, directly or through other classes.
+    /// They're rendered by name and defined once above the schema.
+    recursive_classes: Arc<IndexSet<String>>,
+    /// The targets of `type` aliases that refer to themselves, by alias name.
+    structural_recursive_aliases: Arc<IndexMap<String, FieldType>>,
     target: FieldType,
 }
 
@@ -87,6 +99,7 @@ pub(crate) struct RenderOptions {
     enum_value_prefix: RenderSetting<String>,
     always_hoist_enums: RenderSetting<bool>,
     map_style: MapStyle,
+    hide_defaulted_fields: bool,
 }
 
 impl Default for RenderOptions {
@@ -97,6 +110,7 @@ impl Default for RenderOptions {
             enum_value_prefix: RenderSetting::Auto,
             always_hoist_enums: RenderSetting::Auto,
             map_style: MapStyle::TypeParameters,
+            hide_defaulted_fields: false,
         }
     }
 }
@@ -108,6 +122,7 @@ impl RenderOptions {
         enum_value_prefix: Option<Option<String>>,
         always_hoist_enums: Option<bool>,
         map_style: Option<MapStyle>,
+        hide_defaulted_fields: Option<bool>,
     ) -> Self {
         Self {
             prefix: prefix.map_or(RenderSetting::Auto, |p| {
@@ -120,6 +135,7 @@ impl RenderOptions {
             always_hoist_enums: always_hoist_enums
                 .map_or(RenderSetting::Auto, RenderSetting::Always),
             map_style: map_style.unwrap_or(MapStyle::TypeParameters),
+            hide_defaulted_fields: hide_defaulted_fields.unwrap_or(false),
         }
     }
 }
@@ -210,10 +226,18 @@ impl<'s> std::fmt::Display for MapRender<'s> {
 
 struct RenderState {
     hoisted_enums: IndexSet<String>,
+    hoisted_classes: IndexSet<String>,
+    hoisted_aliases: IndexSet<String>,
 }
 
 impl OutputFormatContent {
     pub fn new(enums: Vec<Enum>, classes: Vec<Class>, target: FieldType) -> Self {
+        let classes: IndexMap<String, Class> = classes
+            .into_iter()
+            .map(|c| (c.name.name.clone(), c))
+            .collect();
+        let recursive_classes = find_recursive_classes(&classes);
+
         Self {
             enums: Arc::new(
                 enums
@@ -221,16 +245,19 @@ impl OutputFormatContent {
                     .map(|e| (e.name.name.clone(), e))
                     .collect(),
             ),
-            classes: Arc::new(
-                classes
-                    .into_iter()
-                    .map(|c| (c.name.name.clone(), c))
-                    .collect(),
-            ),
+            classes: Arc::new(classes),
+            recursive_classes: Arc::new(recursive_classes),
+            structural_recursive_aliases: Arc::new(IndexMap::new()),
             target,
         }
     }
 
+    /// Adds the recursive `type` aliases the target refers to.
+    pub fn with_recursive_aliases(mut self, aliases: IndexMap<String, FieldType>) -> Self {
+        self.structural_recursive_aliases = Arc::new(aliases);
+        self
+    }
+
     fn prefix<'a>(&self, options: &'a RenderOptions) -> Option<&'a str> {
         fn auto_prefix(ft: &FieldType) -> Option<&'static str> {
             match ft {
@@ -239,6 +266,7 @@ impl OutputFormatContent {
                 FieldType::Literal(_) => Some("Answer using this specific value:\n"),
                 FieldType::Enum(_) => Some("Answer with any of the categories:\n"),
                 FieldType::Class(_) => Some("Answer in JSON using this schema:\n"),
+                FieldType::RecursiveTypeAlias(_) => Some("Answer in JSON using this schema:\n"),
                 FieldType::List(_) => Some("Answer with a JSON Array using this schema:\n"),
                 FieldType::Union(_) => Some("Answer in JSON using any of these schemas:\n"),
                 FieldType::Optional(_) => Some("Answer in JSON using this schema:\n"),
@@ -284,6 +312,9 @@ impl OutputFormatContent {
                 TypeValue::Float => "float".to_string(),
                 TypeValue::Bool => "bool".to_string(),
                 TypeValue::Null => "null".to_string(),
+                TypeValue::Date => "date (YYYY-MM-DD)".to_string(),
+                TypeValue::DateTime => "datetime (ISO 8601, e.g. 2024-01-31T09:30:00Z)".to_string(),
+                TypeValue::Duration => "duration (ISO 8601, e.g. PT1H30M)".to_string(),
                 TypeValue::Media(media_type) => {
                     return Err(minijinja::Error::new(
                         minijinja::ErrorKind::BadSerialization,
@@ -294,10 +325,24 @@ impl OutputFormatContent {
             FieldType::Literal(v) => match v {
                 LiteralValue::String(s) => format!("\"{}\"", s),
                 LiteralValue::Int(i) => i.to_string(),
+                LiteralValue::Float(f) => format!("{f:?}"),
                 LiteralValue::Bool(b) => b.to_string(),
             },
-            FieldType::Constrained { base, .. } => {
-                self.inner_type_render(options, base, render_state, group_hoisted_literals)?
+            FieldType::Constrained { base, constraints } => {
+                let base =
+                    self.inner_type_render(options, base, render_state, group_hoisted_literals)?;
+                // Only the bounds from `@range` and `@length` are shown, other
+                // assertions are left to the prompt.
+                let bounds = constraints
+                    .iter()
+                    .filter_map(|c| c.refinement.as_ref())
+                    .map(|r| r.description())
+                    .collect::<Vec<_>>();
+                if bounds.is_empty() {
+                    base
+                } else {
+                    format!("{base} ({})", bounds.join(", "))
+                }
             }
             FieldType::Enum(e) => {
                 let Some(enm) = self.enums.get(e) else {
@@ -333,21 +378,22 @@ impl OutputFormatContent {
                     ));
                 };
 
-                ClassRender {
-                    name: class.name.rendered_name().to_string(),
-                    values: class
-                        .fields
-                        .iter()
-                        .map(|(n, t, d)| {
-                            Ok(ClassFieldRender {
-                                name: n.rendered_name().to_string(),
-                                r#type: self.inner_type_render(options, t, render_state, false)?,
-                                description: d.clone(),
-                            })
-                        })
-                        .collect::<Result<_, minijinja::Error>>()?,
+                if self.recursive_classes.contains(cls) {
+                    render_state.hoisted_classes.insert(cls.clone());
+                    class.name.rendered_name().to_string()
+                } else {
+                    self.class_to_string(class, options, render_state)?
                 }
-                .to_string()
+            }
+            FieldType::RecursiveTypeAlias(name) => {
+                if !self.structural_recursive_aliases.contains_key(name) {
+                    return Err(minijinja::Error::new(
+                        minijinja::ErrorKind::BadSerialization,
+                        format!("Type alias {} not found", name),
+                    ));
+                }
+                render_state.hoisted_aliases.insert(name.clone());
+                name.clone()
             }
             FieldType::List(inner) => {
                 let inner_str = self.inner_type_render(options, inner, render_state, false)?;
@@ -356,6 +402,10 @@ impl OutputFormatContent {
                     FieldType::Primitive(_) => false,
                     FieldType::Optional(t) => !t.is_primitive(),
                     FieldType::Enum(e) => inner_str.len() > 15,
+                    // Hoisted recursive types render as a single name.
+                    FieldType::Class(_) | FieldType::RecursiveTypeAlias(_) => {
+                        inner_str.contains('\n')
+                    }
                     _ => true,
                 } {
                     format!("[\n  {}\n]", inner_str.replace('\n', "\n  "))
@@ -395,6 +445,68 @@ impl OutputFormatContent {
         })
     }
 
+    fn class_to_string(
+        &self,
+        class: &Class,
+        options: &RenderOptions,
+        render_state: &mut RenderState,
+    ) -> Result<String, minijinja::Error> {
+        Ok(ClassRender {
+            name: class.name.rendered_name().to_string(),
+            values: class
+                .fields
+                .iter()
+                // The parser fills in defaulted fields, so the LLM may skip them.
+                .filter(|(.., default)| !(options.hide_defaulted_fields && default.is_some()))
+                .map(|(n, t, d, ..)| {
+                    Ok(ClassFieldRender {
+                        name: n.rendered_name().to_string(),
+                        r#type: self.inner_type_render(options, t, render_state, false)?,
+                        description: d.clone(),
+                    })
+                })
+                .collect::<Result<_, minijinja::Error>>()?,
+        }
+        .to_string())
+    }
+
+    /// Definitions of the recursive classes and aliases hoisted while
+    /// rendering. Each definition may hoist more, so this runs until no new
+    /// names show up.
+    fn recursive_definitions(
+        &self,
+        options: &RenderOptions,
+        render_state: &mut RenderState,
+    ) -> Result<Vec<String>, minijinja::Error> {
+        let mut class_definitions = Vec::new();
+        let mut alias_definitions = Vec::new();
+        let (mut next_class, mut next_alias) = (0, 0);
+        loop {
+            if let Some(cls) = render_state.hoisted_classes.get_index(next_class).cloned() {
+                next_class += 1;
+                let class = self.classes.get(&cls).expect("Class not found");
+                class_definitions.push(format!(
+                    "{} {}",
+                    class.name.rendered_name(),
+                    self.class_to_string(class, options, render_state)?
+                ));
+            } else if let Some(alias) = render_state.hoisted_aliases.get_index(next_alias).cloned()
+            {
+                next_alias += 1;
+                let target = &self.structural_recursive_aliases[&alias];
+                alias_definitions.push(format!(
+                    "{} = {}",
+                    alias,
+                    self.inner_type_render(options, target, render_state, false)?
+                ));
+            } else {
+                break;
+            }
+        }
+        class_definitions.extend(alias_definitions);
+        Ok(class_definitions)
+    }
+
     pub(crate) fn render(
         &self,
         options: RenderOptions,
@@ -403,6 +515,8 @@ impl OutputFormatContent {
 
         let mut render_state = RenderState {
             hoisted_enums: IndexSet::new(),
+            hoisted_classes: IndexSet::new(),
+            hoisted_aliases: IndexSet::new(),
         };
 
         let message = match &self.target {
@@ -420,6 +534,8 @@ impl OutputFormatContent {
             _ => Some(self.inner_type_render(&options, &self.target, &mut render_state, false)?),
         };
 
+        let recursive_definitions = self.recursive_definitions(&options, &mut render_state)?;
+
         let enum_definitions = render_state
             .hoisted_enums
             .iter()
@@ -427,6 +543,7 @@ impl OutputFormatContent {
                 let enm = self.enums.get(e).expect("Enum not found");
                 self.enum_to_string(enm, &options)
             })
+            .chain(recursive_definitions)
             .collect::<Vec<_>>();
 
         match (prefix, message) {
@@ -502,11 +619,70 @@ impl OutputFormatContent {
             .get(name)
             .ok_or_else(|| anyhow::anyhow!("Class {} not found", name))
     }
+
+    pub fn find_recursive_alias_target(&self, name: &str) -> Result<&FieldType> {
+        self.structural_recursive_aliases
+            .get(name)
+            .ok_or_else(|| anyhow::anyhow!("Type alias {} not found", name))
+    }
+}
+
+/// Classes that can reach themselves through their fields.
+fn find_recursive_classes(classes: &IndexMap<String, Class>) -> IndexSet<String> {
+    fn class_refs<'a>(ft: &'a FieldType, refs: &mut Vec<&'a str>) {
+        match ft {
+            FieldType::Class(name) => refs.push(name),
+            FieldType::List(inner) | FieldType::Optional(inner) => class_refs(inner, refs),
+            FieldType::Map(k, v) => {
+                class_refs(k, refs);
+                class_refs(v, refs);
+            }
+            FieldType::Union(items) | FieldType::Tuple(items) => {
+                items.iter().for_each(|t| class_refs(t, refs))
+            }
+            FieldType::Constrained { base, .. } => class_refs(base, refs),
+            FieldType::Primitive(_)
+            | FieldType::Enum(_)
+            | FieldType::Literal(_)
+            | FieldType::RecursiveTypeAlias(_) => {}
+        }
+    }
+
+    let edges = classes
+        .iter()
+        .map(|(name, class)| {
+            let mut refs = Vec::new();
+            class
+                .fields
+                .iter()
+                .for_each(|(_, t, ..)| class_refs(t, &mut refs));
+            (name.as_str(), refs)
+        })
+        .collect::<IndexMap<_, _>>();
+
+    edges
+        .keys()
+        .filter(|start| {
+            let mut visited = IndexSet::new();
+            let mut stack = edges[*start].clone();
+            while let Some(next) = stack.pop() {
+                if next == **start {
+                    return true;
+                }
+                if visited.insert(next) {
+                    stack.extend(edges.get(next).into_iter().flatten().copied());
+                }
+            }
+            false
+        })
+        .map(|name| name.to_string())
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use baml_types::Refinement;
 
     #[test]
     fn test_render_string() {
@@ -559,14 +735,19 @@ mod tests {
                     Name::new("name".to_string()),
                     FieldType::Primitive(TypeValue::String),
                     Some("The person's name".to_string()),
+                    StreamingBehavior::default(),
+                    None,
                 ),
                 (
                     Name::new("age".to_string()),
                     FieldType::Primitive(TypeValue::Int),
                     Some("The person's age".to_string()),
+                    StreamingBehavior::default(),
+                    None,
                 ),
             ],
             constraints: Vec::new(),
+            streaming_behavior: StreamingBehavior::default(),
         });
 
         let content =
@@ -581,6 +762,152 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_render_class_with_dates() {
+        let classes = vec![Class {
+            name: Name::new("Meeting".to_string()),
+            fields: vec![
+                (
+                    Name::new("day".to_string()),
+                    FieldType::Primitive(TypeValue::Date),
+                    None,
+                    StreamingBehavior::default(),
+                    None,
+                ),
+                (
+                    Name::new("starts_at".to_string()),
+                    FieldType::Primitive(TypeValue::DateTime),
+                    None,
+                    StreamingBehavior::default(),
+                    None,
+                ),
+                (
+                    Name::new("length".to_string()),
+                    FieldType::Primitive(TypeValue::Duration),
+                    None,
+                    StreamingBehavior::default(),
+                    None,
+                ),
+            ],
+            constraints: Vec::new(),
+            streaming_behavior: StreamingBehavior::default(),
+        }];
+
+        let content =
+            OutputFormatContent::new(vec![], classes, FieldType::Class("Meeting".to_string()));
+        let rendered = content.render(RenderOptions::default()).unwrap();
+        assert_eq!(
+            rendered,
+            Some(
+                "Answer in JSON using this schema:\n{\n  day: date (YYYY-MM-DD),\n  starts_at: datetime (ISO 8601, e.g. 2024-01-31T09:30:00Z),\n  length: duration (ISO 8601, e.g. PT1H30M),\n}"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_render_class_with_refinements() {
+        let bounded = |base: FieldType, refinement: Refinement| FieldType::Constrained {
+            base: Box::new(base),
+            constraints: vec![Constraint::from_refinement(refinement, false)],
+        };
+        let classes = vec![Class {
+            name: Name::new("Review".to_string()),
+            fields: vec![
+                (
+                    Name::new("score".to_string()),
+                    bounded(
+                        FieldType::int(),
+                        Refinement::Range {
+                            min: Some(1.0),
+                            max: Some(5.0),
+                        },
+                    ),
+                    None,
+                    StreamingBehavior::default(),
+                    None,
+                ),
+                (
+                    Name::new("title".to_string()),
+                    bounded(
+                        FieldType::string(),
+                        Refinement::Length {
+                            min: None,
+                            max: Some(280),
+                        },
+                    ),
+                    None,
+                    StreamingBehavior::default(),
+                    None,
+                ),
+                (
+                    Name::new("weight".to_string()),
+                    FieldType::literal_float(0.5),
+                    None,
+                    StreamingBehavior::default(),
+                    None,
+                ),
+            ],
+            constraints: Vec::new(),
+            streaming_behavior: StreamingBehavior::default(),
+        }];
+
+        let content =
+            OutputFormatContent::new(vec![], classes, FieldType::Class("Review".to_string()));
+        let rendered = content.render(RenderOptions::default()).unwrap();
+        assert_eq!(
+            rendered,
+            Some(
+                "Answer in JSON using this schema:\n{\n  score: int (between 1 and 5),\n  title: string (length at most 280),\n  weight: 0.5,\n}"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_render_class_hiding_defaulted_fields() {
+        let classes = vec![Class {
+            name: Name::new("Person".to_string()),
+            fields: vec![
+                (
+                    Name::new("name".to_string()),
+                    FieldType::Primitive(TypeValue::String),
+                    None,
+                    StreamingBehavior::default(),
+                    None,
+                ),
+                (
+                    Name::new("country".to_string()),
+                    FieldType::Primitive(TypeValue::String),
+                    None,
+                    StreamingBehavior::default(),
+                    Some(BamlValue::String("US".to_string())),
+                ),
+            ],
+            constraints: Vec::new(),
+            streaming_behavior: StreamingBehavior::default(),
+        }];
+
+        let content =
+            OutputFormatContent::new(vec![], classes, FieldType::Class("Person".to_string()));
+        let rendered = content.render(RenderOptions::default()).unwrap();
+        assert_eq!(
+            rendered,
+            Some(
+                "Answer in JSON using this schema:\n{\n  name: string,\n  country: string,\n}"
+                    .to_string()
+            )
+        );
+
+        let rendered = content
+            .render(RenderOptions::new(None, None, None, None, None, Some(true)))
+            .unwrap();
+        assert_eq!(
+            rendered,
+            Some("Answer in JSON using this schema:\n{\n  name: string,\n}".to_string())
+        );
+    }
+
     #[test]
     fn test_render_class_with_multiline_descriptions() {
         let mut classes = vec![];
@@ -591,19 +918,26 @@ mod tests {
                     Name::new("school".to_string()),
                     FieldType::Optional(Box::new(FieldType::Primitive(TypeValue::String))),
                     Some("111\n  ".to_string()),
+                    StreamingBehavior::default(),
+                    None,
                 ),
                 (
                     Name::new("degree".to_string()),
                     FieldType::Primitive(TypeValue::String),
                     Some("2222222".to_string()),
+                    StreamingBehavior::default(),
+                    None,
                 ),
                 (
                     Name::new("year".to_string()),
                     FieldType::Primitive(TypeValue::Int),
                     None,
+                    StreamingBehavior::default(),
+                    None,
                 ),
             ],
             constraints: Vec::new(),
+            streaming_behavior: StreamingBehavior::default(),
         });
 
         let content =
@@ -617,4 +951,68 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn test_render_recursive_class() {
+        let classes = vec![Class {
+            name: Name::new("Node".to_string()),
+            fields: vec![
+                (
+                    Name::new("value".to_string()),
+                    FieldType::Primitive(TypeValue::Int),
+                    None,
+                    StreamingBehavior::default(),
+                    None,
+                ),
+                (
+                    Name::new("next".to_string()),
+                    FieldType::Optional(Box::new(FieldType::Class("Node".to_string()))),
+                    None,
+                    StreamingBehavior::default(),
+                    None,
+                ),
+            ],
+            constraints: Vec::new(),
+            streaming_behavior: StreamingBehavior::default(),
+        }];
+
+        let content =
+            OutputFormatContent::new(vec![], classes, FieldType::Class("Node".to_string()));
+        let rendered = content.render(RenderOptions::default()).unwrap();
+        assert_eq!(
+            rendered,
+            Some(
+                "Node {\n  value: int,\n  next: Node or null,\n}\n\nAnswer in JSON using this schema:\nNode"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_render_recursive_alias() {
+        let json = FieldType::RecursiveTypeAlias("Json".to_string());
+        let aliases = IndexMap::from([(
+            "Json".to_string(),
+            FieldType::Union(vec![
+                FieldType::Primitive(TypeValue::String),
+                FieldType::Primitive(TypeValue::Int),
+                FieldType::List(Box::new(json.clone())),
+                FieldType::Map(
+                    Box::new(FieldType::Primitive(TypeValue::String)),
+                    Box::new(json.clone()),
+                ),
+            ]),
+        )]);
+
+        let content =
+            OutputFormatContent::new(vec![], vec![], json).with_recursive_aliases(aliases);
+        let rendered = content.render(RenderOptions::default()).unwrap();
+        assert_eq!(
+            rendered,
+            Some(
+                "Json = string or int or Json[] or map<string, Json>\n\nAnswer in JSON using this schema:\nJson"
+                    .to_string()
+            )
+        );
+    }
 }
@@ -211,6 +211,10 @@ impl PredefinedTypes {
                                 "always_hoist_enums".into(),
                                 Type::merge(vec![Type::Bool, Type::None]),
                             ),
+                            (
+                                "hide_defaulted_fields".into(),
+                                Type::merge(vec![Type::Bool, Type::None]),
+                            ),
                         ],
                     ),
                 ),
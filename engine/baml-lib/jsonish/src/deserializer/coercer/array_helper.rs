@@ -12,7 +12,17 @@ pub fn coerce_array_to_singular(
     items: &[&crate::jsonish::Value],
     coercion: &dyn (Fn(&crate::jsonish::Value) -> Result<BamlValueWithFlags, ParsingError>),
 ) -> Result<BamlValueWithFlags, ParsingError> {
-    let parsed = items.iter().map(|item| coercion(item)).collect::<Vec<_>>();
+    let parsed = items
+        .iter()
+        .map(|item| match item {
+            // The last item may be marked as cut off by the end of a stream.
+            crate::jsonish::Value::FixedJson(inner, fixes) => coercion(inner).map(|mut v| {
+                v.add_flag(Flag::ObjectFromFixedJson(fixes.to_vec()));
+                v
+            }),
+            _ => coercion(item),
+        })
+        .collect::<Vec<_>>();
 
     pick_best(ctx, target, &parsed)
 }
@@ -33,7 +33,15 @@ pub(super) fn coerce_array(
     match &value {
         Some(crate::jsonish::Value::Array(arr)) => {
             for (i, item) in arr.iter().enumerate() {
-                match inner.coerce(&ctx.enter_scope(&format!("{i}")), inner, Some(item)) {
+                let item_ctx = ctx.enter_scope(&format!("{i}"));
+                // Only the last item of a streamed list can still grow.
+                let coerced = if i + 1 < arr.len() {
+                    item_ctx
+                        .coerce_complete(inner, item, || inner.coerce(&item_ctx, inner, Some(item)))
+                } else {
+                    inner.coerce(&item_ctx, inner, Some(item))
+                };
+                match coerced {
                     Ok(v) => items.push(v),
                     // TODO(vbv): document why we penalize in proportion to how deep into an array a parse error is
                     Err(e) => flags.add_flag(Flag::ArrayItemParseError(i, e)),
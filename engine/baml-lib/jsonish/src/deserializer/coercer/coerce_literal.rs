@@ -12,7 +12,10 @@ use crate::{
     jsonish,
 };
 
-use super::{coerce_primitive::coerce_int, ParsingContext, ParsingError};
+use super::{
+    coerce_primitive::{coerce_float, coerce_int},
+    ParsingContext, ParsingError,
+};
 
 impl TypeCoercer for LiteralValue {
     fn coerce(
@@ -60,6 +63,20 @@ impl TypeCoercer for LiteralValue {
                 }
             }
 
+            LiteralValue::Float(literal_float) => {
+                let BamlValueWithFlags::Float(coerced_float) =
+                    coerce_float(ctx, target, Some(value))?
+                else {
+                    unreachable!("coerce_float returned a non-float value");
+                };
+
+                if coerced_float.value() == literal_float {
+                    Ok(BamlValueWithFlags::Float(coerced_float))
+                } else {
+                    Err(ctx.error_unexpected_type(target, value))
+                }
+            }
+
             LiteralValue::Bool(literal_bool) => {
                 let BamlValueWithFlags::Bool(coerced_bool) = coerce_bool(ctx, target, Some(value))?
                 else {
@@ -1,5 +1,6 @@
 use anyhow::Result;
-use baml_types::BamlMediaType;
+use baml_types::{temporal, BamlMediaType};
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Utc};
 use internal_baml_core::ir::{FieldType, TypeValue};
 
 use crate::deserializer::{
@@ -7,10 +8,33 @@ use crate::deserializer::{
     deserialize_flags::{DeserializerConditions, Flag},
     types::BamlValueWithFlags,
 };
+use once_cell::sync::Lazy;
 use regex::Regex;
 
 use super::{array_helper::coerce_array_to_singular, ParsingContext, ParsingError};
 
+static NUMBER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"([-+]?)\$?(?:\d+(?:,\d+)*(?:\.\d+)?|\d+\.\d+|\d+|\.\d+)(?:e[-+]?\d+)?").unwrap()
+});
+static CURRENCY: Lazy<Regex> = Lazy::new(|| Regex::new(r"\p{Sc}").unwrap());
+static WEEKDAY: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)^(mon|tue|tues|wed|thu|thur|thurs|fri|sat|sun)(day|nesday|sday|urday)?\.?,?\s+",
+    )
+    .unwrap()
+});
+static ORDINAL: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)\b(\d{1,2})(st|nd|rd|th)\b").unwrap());
+static UTC_OFFSET: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\s*(z|utc|gmt|[+-]\d{2}:?\d{2})$").unwrap());
+static CLOCK_DURATION: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(\d+):(\d{2}):(\d{2})$").unwrap());
+static DURATION_PART: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)(\d+(?:\.\d+)?)\s*(weeks?|wks?|w|days?|d|hours?|hrs?|h|minutes?|mins?|m|seconds?|secs?|s)\b",
+    )
+    .unwrap()
+});
+static DURATION_SEPARATORS: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)^(\s|,|and)*$").unwrap());
+
 impl TypeCoercer for TypeValue {
     fn coerce(
         &self,
@@ -38,6 +62,9 @@ impl TypeCoercer for TypeValue {
             TypeValue::Float => coerce_float(ctx, target, value),
             TypeValue::Bool => coerce_bool(ctx, target, value),
             TypeValue::Null => coerce_null(ctx, target, value),
+            TypeValue::Date => coerce_date(ctx, target, value),
+            TypeValue::DateTime => coerce_datetime(ctx, target, value),
+            TypeValue::Duration => coerce_duration(ctx, target, value),
             TypeValue::Media(BamlMediaType::Image) => Err(ctx.error_image_not_supported()),
             TypeValue::Media(BamlMediaType::Audio) => Err(ctx.error_audio_not_supported()),
         }
@@ -150,9 +177,7 @@ fn float_from_maybe_fraction(value: &str) -> Option<f64> {
 }
 
 fn float_from_comma_separated(value: &str) -> Option<f64> {
-    let re = Regex::new(r"([-+]?)\$?(?:\d+(?:,\d+)*(?:\.\d+)?|\d+\.\d+|\d+|\.\d+)(?:e[-+]?\d+)?")
-        .unwrap();
-    let matches: Vec<_> = re.find_iter(value).collect();
+    let matches: Vec<_> = NUMBER.find_iter(value).collect();
 
     if matches.len() != 1 {
         return None;
@@ -161,13 +186,12 @@ fn float_from_comma_separated(value: &str) -> Option<f64> {
     let number_str = matches[0].as_str();
     let without_commas = number_str.replace(",", "");
     // Remove all Unicode currency symbols
-    let re_currency = Regex::new(r"\p{Sc}").unwrap();
-    let without_currency = re_currency.replace_all(&without_commas, "");
+    let without_currency = CURRENCY.replace_all(&without_commas, "");
 
     without_currency.parse::<f64>().ok()
 }
 
-fn coerce_float(
+pub(super) fn coerce_float(
     ctx: &ParsingContext,
     target: &FieldType,
     value: Option<&crate::jsonish::Value>,
@@ -268,10 +292,297 @@ pub(super) fn coerce_bool(
     }
 }
 
+fn coerce_date(
+    ctx: &ParsingContext,
+    target: &FieldType,
+    value: Option<&crate::jsonish::Value>,
+) -> Result<BamlValueWithFlags, ParsingError> {
+    if let Some(value) = value {
+        match value {
+            crate::jsonish::Value::String(s) => {
+                if let Some(date) = temporal::parse_date(s) {
+                    Ok(BamlValueWithFlags::Date(date.into()))
+                } else if let Some(date) = date_from_natural_format(s) {
+                    Ok(BamlValueWithFlags::Date(
+                        (date, Flag::NonIsoTemporal(s.clone())).into(),
+                    ))
+                } else if let Some(datetime) = datetime_from_natural_format(s) {
+                    // A datetime was given where only the day was asked for.
+                    Ok(BamlValueWithFlags::Date(
+                        (datetime.date_naive(), Flag::NonIsoTemporal(s.clone())).into(),
+                    ))
+                } else {
+                    Err(ctx.error_unexpected_type(target, value))
+                }
+            }
+            crate::jsonish::Value::Array(items) => {
+                coerce_array_to_singular(ctx, target, &items.iter().collect::<Vec<_>>(), &|value| {
+                    coerce_date(ctx, target, Some(value))
+                })
+            }
+            _ => Err(ctx.error_unexpected_type(target, value)),
+        }
+    } else {
+        Err(ctx.error_unexpected_null(target))
+    }
+}
+
+fn coerce_datetime(
+    ctx: &ParsingContext,
+    target: &FieldType,
+    value: Option<&crate::jsonish::Value>,
+) -> Result<BamlValueWithFlags, ParsingError> {
+    if let Some(value) = value {
+        match value {
+            crate::jsonish::Value::String(s) => {
+                if let Some(datetime) = temporal::parse_datetime(s) {
+                    Ok(BamlValueWithFlags::DateTime(datetime.into()))
+                } else if let Some(datetime) = datetime_from_natural_format(s) {
+                    Ok(BamlValueWithFlags::DateTime(
+                        (datetime, Flag::NonIsoTemporal(s.clone())).into(),
+                    ))
+                } else if let Some(date) =
+                    temporal::parse_date(s).or_else(|| date_from_natural_format(s))
+                {
+                    // Only the day was given, so take the start of it in UTC.
+                    let datetime: DateTime<FixedOffset> = Utc
+                        .from_utc_datetime(&date.and_time(Default::default()))
+                        .into();
+                    Ok(BamlValueWithFlags::DateTime(
+                        (datetime, Flag::NonIsoTemporal(s.clone())).into(),
+                    ))
+                } else {
+                    Err(ctx.error_unexpected_type(target, value))
+                }
+            }
+            crate::jsonish::Value::Array(items) => {
+                coerce_array_to_singular(ctx, target, &items.iter().collect::<Vec<_>>(), &|value| {
+                    coerce_datetime(ctx, target, Some(value))
+                })
+            }
+            _ => Err(ctx.error_unexpected_type(target, value)),
+        }
+    } else {
+        Err(ctx.error_unexpected_null(target))
+    }
+}
+
+fn coerce_duration(
+    ctx: &ParsingContext,
+    target: &FieldType,
+    value: Option<&crate::jsonish::Value>,
+) -> Result<BamlValueWithFlags, ParsingError> {
+    if let Some(value) = value {
+        match value {
+            crate::jsonish::Value::String(s) => {
+                if let Some(duration) = temporal::parse_duration(s) {
+                    Ok(BamlValueWithFlags::Duration(duration.into()))
+                } else if let Some(duration) = duration_from_natural_format(s) {
+                    Ok(BamlValueWithFlags::Duration(
+                        (duration, Flag::NonIsoTemporal(s.clone())).into(),
+                    ))
+                } else {
+                    Err(ctx.error_unexpected_type(target, value))
+                }
+            }
+            crate::jsonish::Value::Array(items) => {
+                coerce_array_to_singular(ctx, target, &items.iter().collect::<Vec<_>>(), &|value| {
+                    coerce_duration(ctx, target, Some(value))
+                })
+            }
+            // A bare number has no unit, so there's no telling what it means.
+            _ => Err(ctx.error_unexpected_type(target, value)),
+        }
+    } else {
+        Err(ctx.error_unexpected_null(target))
+    }
+}
+
+/// Strips what LLMs tend to wrap dates in: a leading weekday, ordinal
+/// suffixes and trailing punctuation. `Wed, Jan 31st, 2024.` becomes
+/// `Jan 31, 2024`.
+fn normalize_date_text(value: &str) -> String {
+    let value = value.trim().trim_end_matches(|c| c == '.' || c == ',');
+    let value = WEEKDAY.replace(value, "");
+    ORDINAL.replace_all(&value, "$1").to_string()
+}
+
+fn date_from_natural_format(value: &str) -> Option<NaiveDate> {
+    const FORMATS: &[&str] = &[
+        "%Y/%m/%d",
+        "%m/%d/%Y",
+        "%d.%m.%Y",
+        "%B %d, %Y",
+        "%B %d %Y",
+        "%d %B %Y",
+        "%d %B, %Y",
+    ];
+    let value = normalize_date_text(value);
+    FORMATS
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(&value, format).ok())
+}
+
+/// Parses datetimes like `2024-01-31 09:30`, `01/31/2024 9:30 PM` or
+/// `January 31, 2024 at 9:30 AM`. A trailing `Z`, `UTC`, `GMT` or numeric
+/// offset sets the timezone; without one the time is taken to be UTC.
+fn datetime_from_natural_format(value: &str) -> Option<DateTime<FixedOffset>> {
+    const FORMATS: &[&str] = &[
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%d %H:%M",
+        "%Y/%m/%d %H:%M:%S",
+        "%Y/%m/%d %H:%M",
+        "%m/%d/%Y %H:%M:%S",
+        "%m/%d/%Y %H:%M",
+        "%m/%d/%Y %I:%M %p",
+        "%m/%d/%Y %I:%M%p",
+        "%B %d, %Y %H:%M",
+        "%B %d, %Y %I:%M %p",
+        "%B %d, %Y %I:%M%p",
+        "%B %d, %Y at %H:%M",
+        "%B %d, %Y at %I:%M %p",
+        "%B %d, %Y at %I:%M%p",
+        "%d %B %Y %H:%M",
+    ];
+    let value = normalize_date_text(value);
+    let (value, offset) = match UTC_OFFSET.captures(&value) {
+        Some(captures) => {
+            let offset = match &captures[1] {
+                z if z.eq_ignore_ascii_case("z")
+                    || z.eq_ignore_ascii_case("utc")
+                    || z.eq_ignore_ascii_case("gmt") =>
+                {
+                    FixedOffset::east_opt(0)?
+                }
+                numeric => {
+                    let digits = numeric[1..].replace(':', "");
+                    let seconds = digits[..2].parse::<i32>().ok()? * 3_600
+                        + digits[2..].parse::<i32>().ok()? * 60;
+                    if numeric.starts_with('-') {
+                        FixedOffset::west_opt(seconds)?
+                    } else {
+                        FixedOffset::east_opt(seconds)?
+                    }
+                }
+            };
+            (&value[..captures.get(0)?.start()], offset)
+        }
+        None => (value.as_str(), FixedOffset::east_opt(0)?),
+    };
+
+    FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .and_then(|naive| offset.from_local_datetime(&naive).single())
+}
+
+/// Parses durations like `90 minutes`, `1h 30m`, `2 days and 4 hours` or
+/// `01:30:00`. Durations too long to represent aren't parsed.
+fn duration_from_natural_format(value: &str) -> Option<Duration> {
+    let value = value.trim().trim_end_matches(|c| c == '.' || c == ',');
+
+    if let Some(captures) = CLOCK_DURATION.captures(value) {
+        let (hours, minutes, seconds) = (
+            captures[1].parse::<i64>().ok()?,
+            captures[2].parse::<i64>().ok()?,
+            captures[3].parse::<i64>().ok()?,
+        );
+        let total = hours
+            .checked_mul(3_600)?
+            .checked_add(minutes * 60 + seconds)?;
+        return Duration::try_seconds(total);
+    }
+
+    let mut total = Duration::zero();
+    let mut last_end = 0;
+    for captures in DURATION_PART.captures_iter(value) {
+        let whole = captures.get(0)?;
+        if !DURATION_SEPARATORS.is_match(&value[last_end..whole.start()]) {
+            return None;
+        }
+        last_end = whole.end();
+
+        let amount = captures[1].parse::<f64>().ok()?;
+        let unit = captures[2].to_lowercase();
+        let seconds = match unit.chars().next()? {
+            'w' => 604_800.0,
+            'd' => 86_400.0,
+            'h' => 3_600.0,
+            'm' => 60.0,
+            _ => 1.0,
+        };
+        let nanos = (amount * seconds * 1e9).round();
+        // Casting would saturate instead of failing.
+        if nanos >= i64::MAX as f64 {
+            return None;
+        }
+        total = total.checked_add(&Duration::nanoseconds(nanos as i64))?;
+    }
+    if last_end == 0 || !DURATION_SEPARATORS.is_match(&value[last_end..]) {
+        return None;
+    }
+    Some(total)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_dates_from_natural_formats() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 31);
+        for input in [
+            "2024/01/31",
+            "01/31/2024",
+            "31.01.2024",
+            "January 31, 2024",
+            "Jan 31st, 2024",
+            "Wednesday, January 31st, 2024.",
+            "31 January 2024",
+        ] {
+            assert_eq!(date_from_natural_format(input), date, "{input}");
+        }
+        assert_eq!(date_from_natural_format("sometime next week"), None);
+    }
+
+    #[test]
+    fn test_datetimes_from_natural_formats() {
+        let cases = [
+            ("2024-01-31 09:30", "2024-01-31T09:30:00+00:00"),
+            ("2024-01-31T09:30Z", "2024-01-31T09:30:00+00:00"),
+            ("01/31/2024 9:30 PM", "2024-01-31T21:30:00+00:00"),
+            ("January 31, 2024 at 9:30 AM", "2024-01-31T09:30:00+00:00"),
+            ("2024-01-31 09:30:00 -05:00", "2024-01-31T09:30:00-05:00"),
+            ("2024-01-31 09:30 UTC", "2024-01-31T09:30:00+00:00"),
+        ];
+        for (input, expected) in cases {
+            let datetime =
+                datetime_from_natural_format(input).map(|d| temporal::format_datetime(&d));
+            assert_eq!(datetime.as_deref(), Some(expected), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_durations_from_natural_formats() {
+        let cases = [
+            ("90 minutes", Some(Duration::minutes(90))),
+            ("1h 30m", Some(Duration::minutes(90))),
+            ("2 days and 4 hours", Some(Duration::hours(52))),
+            ("1.5 hours", Some(Duration::minutes(90))),
+            ("01:30:00", Some(Duration::minutes(90))),
+            ("1 week, 2 days", Some(Duration::days(9))),
+            ("about an hour", None),
+            ("30", None),
+            ("9999999999999:00:00", None),
+            ("99999999999 weeks", None),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(duration_from_natural_format(input), expected, "{input}");
+        }
+    }
+
     #[test]
     fn test_float_from_comma_separated() {
         // Note we don't handle european numbers correctly.
@@ -1,14 +1,18 @@
 use anyhow::Result;
 use internal_baml_core::ir::FieldType;
 
-use crate::deserializer::{coercer::array_helper, types::BamlValueWithFlags};
+use crate::deserializer::{
+    coercer::array_helper, deserialize_flags::Flag, types::BamlValueWithFlags,
+};
 
 use super::{ParsingContext, ParsingError, TypeCoercer};
 
+/// `keep_shape` is set for the union a recursive alias stands for, see below.
 pub(super) fn coerce_union(
     ctx: &ParsingContext,
     union_target: &FieldType,
     value: Option<&crate::jsonish::Value>,
+    keep_shape: bool,
 ) -> Result<BamlValueWithFlags, ParsingError> {
     assert!(matches!(union_target, FieldType::Union(_)));
     log::debug!(
@@ -28,5 +32,42 @@ pub(super) fn coerce_union(
         .map(|option| option.coerce(ctx, option, value))
         .collect::<Vec<_>>();
 
+    if !keep_shape {
+        return array_helper::pick_best(ctx, union_target, &parsed);
+    }
+
+    // Keep the shape of an array or an object when an option can, rather
+    // than picking one of the array's items or turning the object into a
+    // string. Recursive aliases like
+    // `type Json = int | string | Json[] | map<string, Json>` rely on this.
+    let keeps_shape = |res: &Result<BamlValueWithFlags, ParsingError>| {
+        let Ok(parsed) = res else {
+            return false;
+        };
+        let has_errors = parsed.conditions().flags.iter().any(|f| {
+            matches!(
+                f,
+                Flag::ArrayItemParseError(..)
+                    | Flag::MapKeyParseError(..)
+                    | Flag::MapValueParseError(..)
+            )
+        });
+        let same_shape = matches!(
+            (value, parsed),
+            (
+                Some(crate::jsonish::Value::Array(_)),
+                BamlValueWithFlags::List(..)
+            ) | (
+                Some(crate::jsonish::Value::Object(_)),
+                BamlValueWithFlags::Map(..) | BamlValueWithFlags::Class(..)
+            )
+        );
+        same_shape && !has_errors
+    };
+    if parsed.iter().any(keeps_shape) {
+        let parsed = parsed.into_iter().filter(keeps_shape).collect::<Vec<_>>();
+        return array_helper::pick_best(ctx, union_target, &parsed);
+    }
+
     array_helper::pick_best(ctx, union_target, &parsed)
 }
@@ -72,6 +72,18 @@ impl TypeCoercer for FieldType {
                 );
                 let mut v = self.coerce(ctx, target, Some(v))?;
                 v.add_flag(Flag::ObjectFromFixedJson(fixes.to_vec()));
+                // Classes marked `@@stream.done` are held back until complete.
+                if ctx.allow_partials && v.conditions().is_unterminated() {
+                    if let BamlValueWithFlags::Class(name, ..) = &v {
+                        if ctx
+                            .of
+                            .find_class(name)
+                            .map_or(false, |c| c.streaming_behavior.done)
+                        {
+                            return Err(ctx.error_incomplete_value(target));
+                        }
+                    }
+                }
                 Ok(v)
             }
             _ => match self {
@@ -79,8 +91,19 @@ impl TypeCoercer for FieldType {
                 FieldType::Enum(e) => IrRef::Enum(e).coerce(ctx, target, value),
                 FieldType::Literal(l) => l.coerce(ctx, target, value),
                 FieldType::Class(c) => IrRef::Class(c).coerce(ctx, target, value),
+                FieldType::RecursiveTypeAlias(name) => {
+                    let alias_target = ctx
+                        .of
+                        .find_recursive_alias_target(name)
+                        .map_err(|e| ctx.error_internal(e))?;
+                    let ctx = ctx.visit_class_value_pair(name, value)?;
+                    match alias_target {
+                        FieldType::Union(_) => coerce_union(&ctx, alias_target, value, true),
+                        _ => alias_target.coerce(&ctx, alias_target, value),
+                    }
+                }
                 FieldType::List(_) => coerce_array(ctx, self, value),
-                FieldType::Union(_) => coerce_union(ctx, self, value),
+                FieldType::Union(_) => coerce_union(ctx, self, value, false),
                 FieldType::Optional(_) => coerce_optional(ctx, self, value),
                 FieldType::Map(_, _) => coerce_map(ctx, self, value),
                 FieldType::Tuple(_) => Err(ctx.error_internal("Tuple not supported")),
@@ -114,6 +137,7 @@ impl DefaultValue for FieldType {
             FieldType::Enum(e) => None,
             FieldType::Literal(_) => None,
             FieldType::Class(_) => None,
+            FieldType::RecursiveTypeAlias(_) => None,
             FieldType::List(_) => Some(BamlValueWithFlags::List(get_flags(), Vec::new())),
             FieldType::Union(items) => items.iter().find_map(|i| i.default_value(error)),
             FieldType::Primitive(TypeValue::Null) | FieldType::Optional(_) => {
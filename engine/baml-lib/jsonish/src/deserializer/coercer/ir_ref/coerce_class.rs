@@ -1,5 +1,5 @@
 use anyhow::Result;
-use baml_types::BamlMap;
+use baml_types::{BamlMap, BamlValue, CompletionState, StreamingBehavior, STREAM_STATE_CLASS};
 use internal_baml_core::ir::FieldType;
 use internal_baml_jinja::types::{Class, Name};
 
@@ -11,8 +11,14 @@ use crate::deserializer::{
 
 use super::ParsingContext;
 
-// Name, type, description.
-type FieldValue = (Name, FieldType, Option<String>);
+// Name, type, description, streaming behavior, default value.
+type FieldValue = (
+    Name,
+    FieldType,
+    Option<String>,
+    StreamingBehavior,
+    Option<BamlValue>,
+);
 
 impl TypeCoercer for Class {
     fn coerce(
@@ -142,9 +148,39 @@ impl TypeCoercer for Class {
             }
         }
 
+        // While streaming, `@stream.done` fields are left out until complete,
+        // and the class is held back until its `@stream.not_null` fields are in.
+        let mut held_back_fields = vec![];
+        if ctx.allow_partials {
+            for (field_name, t, _, streaming, _) in self.fields.iter() {
+                let values = if t.is_optional() {
+                    &mut optional_values
+                } else {
+                    &mut required_values
+                };
+                let key = field_name.real_name();
+                if streaming.done
+                    && matches!(values.get(key), Some(Some(Ok(v))) if v.conditions().is_unterminated())
+                {
+                    values.insert(key.to_string(), None);
+                }
+                if streaming.not_null && !has_streamed_value(values.get(key)) {
+                    held_back_fields.push(key.to_string());
+                }
+            }
+        }
+
         // Check what we have / what we need
         {
-            self.fields.iter().for_each(|(field_name, t, ..)| {
+            self.fields.iter().for_each(|(field_name, t, .., default)| {
+                // Fields left out of a complete response take their `@default`.
+                // While streaming they may still arrive, so they stay pending.
+                let from_default = || match default {
+                    Some(d) if !ctx.allow_partials => {
+                        coerce_default(&ctx.enter_scope(field_name.real_name()), t, d)
+                    }
+                    _ => None,
+                };
                 if t.is_optional() {
                     if let Some(v) = optional_values.get(field_name.real_name()) {
                         let next = match v {
@@ -158,10 +194,12 @@ impl TypeCoercer for Class {
                                 t.default_value(Some(e))
                             }
                             // If we're missing a field, thats ok!
-                            None => Some(BamlValueWithFlags::Null(
-                                DeserializerConditions::new()
-                                    .with_flag(Flag::OptionalDefaultFromNoValue),
-                            )),
+                            None => from_default().or_else(|| {
+                                Some(BamlValueWithFlags::Null(
+                                    DeserializerConditions::new()
+                                        .with_flag(Flag::OptionalDefaultFromNoValue),
+                                ))
+                            }),
                         };
 
                         if let Some(next) = next {
@@ -183,16 +221,20 @@ impl TypeCoercer for Class {
                                     None
                                 }
                             }),
-                            None => t.default_value(None).or_else(|| {
-                                if ctx.allow_partials {
-                                    Some(BamlValueWithFlags::Null(
-                                        DeserializerConditions::new()
-                                            .with_flag(Flag::OptionalDefaultFromNoValue),
-                                    ))
-                                } else {
-                                    None
-                                }
-                            }),
+                            None => {
+                                from_default()
+                                    .or_else(|| t.default_value(None))
+                                    .or_else(|| {
+                                        if ctx.allow_partials {
+                                            Some(BamlValueWithFlags::Null(
+                                                DeserializerConditions::new()
+                                                    .with_flag(Flag::OptionalDefaultFromNoValue),
+                                            ))
+                                        } else {
+                                            None
+                                        }
+                                    })
+                            }
                         };
 
                         if let Some(next) = next {
@@ -230,7 +272,7 @@ impl TypeCoercer for Class {
                     None => None,
                 })
                 .collect::<Vec<_>>();
-            let missing_required_fields = required_values
+            let mut missing_required_fields = required_values
                 .iter()
                 .filter_map(|(k, v)| match v {
                     Some(Ok(_)) => None,
@@ -238,6 +280,7 @@ impl TypeCoercer for Class {
                     None => Some(k.clone()),
                 })
                 .collect::<Vec<_>>();
+            missing_required_fields.extend(held_back_fields);
 
             if !missing_required_fields.is_empty() || !unparsed_required_fields.is_empty() {
                 if completed_cls.is_empty() {
@@ -287,7 +330,12 @@ impl TypeCoercer for Class {
                 for field in self.fields.iter() {
                     let key = field.0.real_name();
                     if let Some(value) = valid_fields.get(key) {
-                        ordered_valid_fields.insert(key.to_string(), value.clone());
+                        let value = if ctx.allow_partials && field.3.with_state {
+                            with_stream_state(value.clone())
+                        } else {
+                            value.clone()
+                        };
+                        ordered_valid_fields.insert(key.to_string(), value);
                     }
                 }
 
@@ -308,6 +356,60 @@ impl TypeCoercer for Class {
     }
 }
 
+/// Coerces a field's `@default(...)` into the field's type.
+fn coerce_default(
+    ctx: &ParsingContext,
+    target: &FieldType,
+    default: &BamlValue,
+) -> Option<BamlValueWithFlags> {
+    let value = serde_json::to_value(default)
+        .and_then(serde_json::from_value::<crate::jsonish::Value>)
+        .ok()?;
+    let mut coerced = target.coerce(ctx, target, Some(&value)).ok()?;
+    coerced.add_flag(Flag::DefaultFromAttribute);
+    Some(coerced)
+}
+
+/// Whether a field has a value that exists, if maybe still incomplete.
+fn has_streamed_value(value: Option<&Option<Result<BamlValueWithFlags, ParsingError>>>) -> bool {
+    match value {
+        Some(Some(Ok(BamlValueWithFlags::Null(conditions)))) => !conditions.is_unterminated(),
+        Some(Some(Ok(_))) => true,
+        _ => false,
+    }
+}
+
+/// Wraps a streamed `@stream.with_state` field value with how far along it is.
+fn with_stream_state(value: BamlValueWithFlags) -> BamlValueWithFlags {
+    let conditions = value.conditions();
+    let state = if conditions.is_unterminated() {
+        CompletionState::Incomplete
+    } else if conditions.flags().iter().any(|f| {
+        matches!(
+            f,
+            Flag::OptionalDefaultFromNoValue
+                | Flag::DefaultFromNoValue
+                | Flag::DefaultButHadUnparseableValue(_)
+        )
+    }) {
+        CompletionState::Pending
+    } else {
+        CompletionState::Complete
+    };
+
+    let mut fields = BamlMap::new();
+    fields.insert("value".to_string(), value);
+    fields.insert(
+        "state".to_string(),
+        BamlValueWithFlags::String(state.to_string().into()),
+    );
+    BamlValueWithFlags::Class(
+        STREAM_STATE_CLASS.into(),
+        DeserializerConditions::new(),
+        fields,
+    )
+}
+
 fn update_map<'a>(
     required_values: &'a mut BamlMap<String, Option<Result<BamlValueWithFlags, ParsingError>>>,
     optional_values: &'a mut BamlMap<String, Option<Result<BamlValueWithFlags, ParsingError>>>,
@@ -26,7 +26,10 @@ impl TypeCoercer for IrRef<'_> {
                 Err(e) => Err(ctx.error_internal(e.to_string())),
             },
             IrRef::Class(c) => match ctx.of.find_class(c.as_str()) {
-                Ok(c) => c.coerce(ctx, target, value),
+                Ok(c) => {
+                    let ctx = ctx.visit_class_value_pair(c.name.real_name(), value)?;
+                    c.coerce(&ctx, target, value)
+                }
                 Err(e) => Err(ctx.error_internal(e.to_string())),
             },
         }
@@ -9,6 +9,8 @@ mod field_type;
 mod ir_ref;
 mod match_string;
 
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
 use anyhow::Result;
 
 use baml_types::{BamlValue, Constraint};
@@ -22,6 +24,58 @@ pub struct ParsingContext<'a> {
     scope: Vec<String>,
     of: &'a OutputFormatContent,
     allow_partials: bool,
+    cache: Option<&'a CoercionCache>,
+    /// The recursive types being coerced further up the stack, with the value
+    /// each was given. Seeing the same pair again means coercion would loop.
+    visited: Vec<Rc<(String, crate::jsonish::Value)>>,
+}
+
+type CachedCoercion = (
+    FieldType,
+    crate::jsonish::Value,
+    Result<BamlValueWithFlags, ParsingError>,
+);
+
+/// Coercions of values that the rest of a streamed response can't change, so
+/// that the next parse of the stream doesn't have to do them again.
+#[derive(Default)]
+pub struct CoercionCache {
+    by_scope: RefCell<HashMap<Vec<String>, Vec<CachedCoercion>>>,
+}
+
+impl CoercionCache {
+    /// How many coercions to keep for a scope. A scope is coerced to more than
+    /// one type with unions.
+    const MAX_PER_SCOPE: usize = 8;
+
+    fn get(
+        &self,
+        scope: &[String],
+        target: &FieldType,
+        value: &crate::jsonish::Value,
+    ) -> Option<Result<BamlValueWithFlags, ParsingError>> {
+        self.by_scope.borrow().get(scope).and_then(|cached| {
+            cached
+                .iter()
+                .find(|(t, v, _)| t == target && v == value)
+                .map(|(_, _, res)| res.clone())
+        })
+    }
+
+    fn put(
+        &self,
+        scope: &[String],
+        target: &FieldType,
+        value: &crate::jsonish::Value,
+        res: &Result<BamlValueWithFlags, ParsingError>,
+    ) {
+        let mut by_scope = self.by_scope.borrow_mut();
+        let cached = by_scope.entry(scope.to_vec()).or_default();
+        if cached.len() >= Self::MAX_PER_SCOPE {
+            cached.remove(0);
+        }
+        cached.push((target.clone(), value.clone(), res.clone()));
+    }
 }
 
 impl ParsingContext<'_> {
@@ -37,6 +91,22 @@ impl ParsingContext<'_> {
             scope: Vec::new(),
             of,
             allow_partials,
+            cache: None,
+            visited: Vec::new(),
+        }
+    }
+
+    pub(crate) fn with_cache<'a>(
+        of: &'a OutputFormatContent,
+        allow_partials: bool,
+        cache: &'a CoercionCache,
+    ) -> ParsingContext<'a> {
+        ParsingContext {
+            scope: Vec::new(),
+            of,
+            allow_partials,
+            cache: Some(cache),
+            visited: Vec::new(),
         }
     }
 
@@ -47,9 +117,57 @@ impl ParsingContext<'_> {
             scope: new_scope,
             of: self.of,
             allow_partials: self.allow_partials,
+            cache: self.cache,
+            visited: self.visited.clone(),
         }
     }
 
+    /// Marks `name` as being coerced from `value`, failing if it already is,
+    /// which can only happen through recursive classes and aliases.
+    pub(crate) fn visit_class_value_pair(
+        &self,
+        name: &str,
+        value: Option<&crate::jsonish::Value>,
+    ) -> Result<ParsingContext, ParsingError> {
+        let mut visited = self.visited.clone();
+        if let Some(value) = value {
+            if visited.iter().any(|v| v.0 == name && &v.1 == value) {
+                return Err(ParsingError {
+                    reason: format!("Circular reference detected for type {}", name),
+                    scope: self.scope.clone(),
+                    causes: vec![],
+                });
+            }
+            visited.push(Rc::new((name.to_string(), value.clone())));
+        }
+        Ok(ParsingContext {
+            scope: self.scope.clone(),
+            of: self.of,
+            allow_partials: self.allow_partials,
+            cache: self.cache,
+            visited,
+        })
+    }
+
+    /// Coerces a value that won't change as the stream it comes from goes on,
+    /// reusing the coercion from an earlier parse of the stream if there is one.
+    pub(crate) fn coerce_complete(
+        &self,
+        target: &FieldType,
+        value: &crate::jsonish::Value,
+        coerce: impl FnOnce() -> Result<BamlValueWithFlags, ParsingError>,
+    ) -> Result<BamlValueWithFlags, ParsingError> {
+        let Some(cache) = self.cache else {
+            return coerce();
+        };
+        if let Some(res) = cache.get(&self.scope, target, value) {
+            return res;
+        }
+        let res = coerce();
+        cache.put(&self.scope, target, value, &res);
+        res
+    }
+
     pub(crate) fn error_too_many_matches<T: std::fmt::Display>(
         &self,
         target: &FieldType,
@@ -172,6 +290,14 @@ impl ParsingContext<'_> {
         }
     }
 
+    pub(crate) fn error_incomplete_value(&self, target: &FieldType) -> ParsingError {
+        ParsingError {
+            reason: format!("{} is only streamed once it is complete", target),
+            scope: self.scope.clone(),
+            causes: vec![],
+        }
+    }
+
     pub(crate) fn error_internal<T: std::fmt::Display>(&self, error: T) -> ParsingError {
         ParsingError {
             reason: format!("Internal error: {}", error),
@@ -32,11 +32,15 @@ pub enum Flag {
     DefaultFromNoValue,
     DefaultButHadValue(crate::jsonish::Value),
     OptionalDefaultFromNoValue,
+    // A missing field was filled in with its `@default(...)`.
+    DefaultFromAttribute,
 
     // String -> X convertions.
     StringToBool(String),
     StringToNull(String),
     StringToChar(String),
+    // A date, datetime or duration written in something other than ISO 8601.
+    NonIsoTemporal(String),
 
     // Number -> X convertions.
     FloatToInt(f64),
@@ -87,9 +91,11 @@ impl DeserializerConditions {
                 Flag::DefaultFromNoValue => None,
                 Flag::DefaultButHadValue(_) => None,
                 Flag::OptionalDefaultFromNoValue => None,
+                Flag::DefaultFromAttribute => None,
                 Flag::StringToBool(_) => None,
                 Flag::StringToNull(_) => None,
                 Flag::StringToChar(_) => None,
+                Flag::NonIsoTemporal(_) => None,
                 Flag::FloatToInt(_) => None,
                 Flag::NoFields(_) => None,
                 Flag::UnionMatch(_idx, _) => None,
@@ -99,6 +105,16 @@ impl DeserializerConditions {
             .collect::<Vec<_>>()
     }
 
+    /// Whether the value was cut off by the end of a streamed response.
+    pub fn is_unterminated(&self) -> bool {
+        self.flags.iter().any(|flag| match flag {
+            Flag::ObjectFromFixedJson(fixes) => fixes
+                .iter()
+                .any(|f| matches!(f, crate::jsonish::Fixes::Unterminated(_))),
+            _ => false,
+        })
+    }
+
     pub fn constraint_results(&self) -> Vec<(Constraint, bool)> {
         self.flags.iter().filter_map(|flag| match flag {
             Flag::ConstraintResults(cs) => Some(cs.clone()),
@@ -141,6 +157,9 @@ impl std::fmt::Display for Flag {
             Flag::DefaultFromNoValue => {
                 write!(f, "Default value")?;
             }
+            Flag::DefaultFromAttribute => {
+                write!(f, "Default value from @default")?;
+            }
             Flag::ObjectFromFixedJson(fixes) => {
                 write!(f, "JSON (Fixed {} mistakes)", fixes.len())?;
             }
@@ -231,6 +250,9 @@ impl std::fmt::Display for Flag {
             Flag::StringToChar(value) => {
                 write!(f, "String to char: {}", value)?;
             }
+            Flag::NonIsoTemporal(value) => {
+                write!(f, "Date in a non-ISO format: {}", value)?;
+            }
             Flag::FloatToInt(value) => {
                 write!(f, "Float to int: {}", value)?;
             }
@@ -243,7 +265,7 @@ impl std::fmt::Display for Flag {
                 }
             }
             Flag::ConstraintResults(cs) => {
-                for (Constraint{ label, level, expression }, succeeded) in cs.iter() {
+                for (Constraint{ label, level, expression, .. }, succeeded) in cs.iter() {
                     let msg = label.as_ref().unwrap_or(&expression.0);
                     let f_result = if *succeeded { "Succeeded" } else { "Failed" };
                     writeln!(f, "{level:?} {msg} {f_result}")?;
@@ -17,6 +17,9 @@ impl WithScore for BamlValueWithFlags {
             BamlValueWithFlags::Int(s) => s.score(),
             BamlValueWithFlags::Float(s) => s.score(),
             BamlValueWithFlags::Bool(s) => s.score(),
+            BamlValueWithFlags::Date(s) => s.score(),
+            BamlValueWithFlags::DateTime(s) => s.score(),
+            BamlValueWithFlags::Duration(s) => s.score(),
             BamlValueWithFlags::List(s, items) => {
                 s.score() + 10 * items.iter().map(WithScore::score).sum::<i32>()
             }
@@ -36,6 +39,7 @@ impl WithScore for Flag {
         match self {
             Flag::InferedObject(_) => 0, // Dont penalize for this but instead handle it at the top level
             Flag::OptionalDefaultFromNoValue => 1,
+            Flag::DefaultFromAttribute => 1,
             Flag::DefaultFromNoValue => 100,
             Flag::DefaultButHadValue(_) => 110,
             Flag::ObjectFromFixedJson(_) => 0,
@@ -62,6 +66,7 @@ impl WithScore for Flag {
             Flag::StringToBool(_) => 1,
             Flag::StringToNull(_) => 1,
             Flag::StringToChar(_) => 1,
+            Flag::NonIsoTemporal(_) => 1,
             Flag::FloatToInt(_) => 1,
             Flag::NoFields(_) => 1,
             Flag::ConstraintResults(cs) => {
@@ -1,6 +1,7 @@
 use std::collections::HashSet;
 
 use baml_types::{BamlMap, BamlMedia, BamlValue, BamlValueWithMeta, Constraint};
+use chrono::{FixedOffset, NaiveDate};
 use serde_json::json;
 use strsim::jaro;
 
@@ -17,6 +18,9 @@ pub enum BamlValueWithFlags {
     Int(ValueWithFlags<i64>),
     Float(ValueWithFlags<f64>),
     Bool(ValueWithFlags<bool>),
+    Date(ValueWithFlags<NaiveDate>),
+    DateTime(ValueWithFlags<chrono::DateTime<FixedOffset>>),
+    Duration(ValueWithFlags<chrono::Duration>),
     List(DeserializerConditions, Vec<BamlValueWithFlags>),
     Map(
         DeserializerConditions,
@@ -39,6 +43,9 @@ impl BamlValueWithFlags {
             BamlValueWithFlags::Int(f) => f.score(),
             BamlValueWithFlags::Float(f) => f.score(),
             BamlValueWithFlags::Bool(f) => f.score(),
+            BamlValueWithFlags::Date(f) => f.score(),
+            BamlValueWithFlags::DateTime(f) => f.score(),
+            BamlValueWithFlags::Duration(f) => f.score(),
             BamlValueWithFlags::List(f, items) => {
                 f.score() + items.iter().map(|i| i.score()).sum::<i32>()
             }
@@ -63,6 +70,9 @@ impl BamlValueWithFlags {
             BamlValueWithFlags::Int(v) => &v.flags,
             BamlValueWithFlags::Float(v) => &v.flags,
             BamlValueWithFlags::Bool(v) => &v.flags,
+            BamlValueWithFlags::Date(v) => &v.flags,
+            BamlValueWithFlags::DateTime(v) => &v.flags,
+            BamlValueWithFlags::Duration(v) => &v.flags,
             BamlValueWithFlags::List(v, _) => &v,
             BamlValueWithFlags::Map(v, _) => &v,
             BamlValueWithFlags::Enum(_, v) => &v.flags,
@@ -139,6 +149,36 @@ impl BamlValueWithFlags {
                     });
                 }
             }
+            BamlValueWithFlags::Date(v) => {
+                let causes = v.flags.explanation();
+                if !causes.is_empty() {
+                    expls.push(ParsingError {
+                        scope: scope.clone(),
+                        reason: format!("error while parsing date"),
+                        causes,
+                    });
+                }
+            }
+            BamlValueWithFlags::DateTime(v) => {
+                let causes = v.flags.explanation();
+                if !causes.is_empty() {
+                    expls.push(ParsingError {
+                        scope: scope.clone(),
+                        reason: format!("error while parsing datetime"),
+                        causes,
+                    });
+                }
+            }
+            BamlValueWithFlags::Duration(v) => {
+                let causes = v.flags.explanation();
+                if !causes.is_empty() {
+                    expls.push(ParsingError {
+                        scope: scope.clone(),
+                        reason: format!("error while parsing duration"),
+                        causes,
+                    });
+                }
+            }
             BamlValueWithFlags::List(flags, values) => {
                 let causes = flags.explanation();
                 if !causes.is_empty() {
@@ -281,6 +321,9 @@ impl From<BamlValueWithFlags> for BamlValue {
             BamlValueWithFlags::Int(i) => BamlValue::Int(i.value),
             BamlValueWithFlags::Float(f) => BamlValue::Float(f.value),
             BamlValueWithFlags::Bool(b) => BamlValue::Bool(b.value),
+            BamlValueWithFlags::Date(d) => BamlValue::Date(d.value),
+            BamlValueWithFlags::DateTime(d) => BamlValue::DateTime(d.value),
+            BamlValueWithFlags::Duration(d) => BamlValue::Duration(d.value),
             BamlValueWithFlags::List(_, v) => {
                 BamlValue::List(v.into_iter().map(|x| x.into()).collect())
             }
@@ -304,6 +347,9 @@ impl From<&BamlValueWithFlags> for BamlValue {
             BamlValueWithFlags::Int(i) => BamlValue::Int(i.value),
             BamlValueWithFlags::Float(f) => BamlValue::Float(f.value),
             BamlValueWithFlags::Bool(b) => BamlValue::Bool(b.value),
+            BamlValueWithFlags::Date(d) => BamlValue::Date(d.value),
+            BamlValueWithFlags::DateTime(d) => BamlValue::DateTime(d.value),
+            BamlValueWithFlags::Duration(d) => BamlValue::Duration(d.value),
             BamlValueWithFlags::List(_, v) => {
                 BamlValue::List(v.into_iter().map(|x| x.into()).collect())
             }
@@ -330,6 +376,9 @@ impl BamlValueWithFlags {
             BamlValueWithFlags::Int(v) => v.flags.add_flag(flag),
             BamlValueWithFlags::Float(v) => v.flags.add_flag(flag),
             BamlValueWithFlags::Bool(v) => v.flags.add_flag(flag),
+            BamlValueWithFlags::Date(v) => v.flags.add_flag(flag),
+            BamlValueWithFlags::DateTime(v) => v.flags.add_flag(flag),
+            BamlValueWithFlags::Duration(v) => v.flags.add_flag(flag),
             BamlValueWithFlags::List(v, _) => v.add_flag(flag),
             BamlValueWithFlags::Map(v, _) => v.add_flag(flag),
             BamlValueWithFlags::Enum(_, v) => v.flags.add_flag(flag),
@@ -345,6 +394,9 @@ impl BamlValueWithFlags {
             BamlValueWithFlags::Int(_) => "Int".to_string(),
             BamlValueWithFlags::Float(_) => "Float".to_string(),
             BamlValueWithFlags::Bool(_) => "Bool".to_string(),
+            BamlValueWithFlags::Date(_) => "Date".to_string(),
+            BamlValueWithFlags::DateTime(_) => "DateTime".to_string(),
+            BamlValueWithFlags::Duration(_) => "Duration".to_string(),
             BamlValueWithFlags::List(_, i) => {
                 let inner = i
                     .iter()
@@ -392,6 +444,24 @@ impl std::fmt::Display for BamlValueWithFlags {
                     write!(f, "\n  {}", v.flags.to_string().replace("\n", "\n  "))?;
                 }
             }
+            BamlValueWithFlags::Date(v) => {
+                write!(f, "{}", baml_types::temporal::format_date(&v.value))?;
+                if !v.flags.flags.is_empty() {
+                    write!(f, "\n  {}", v.flags.to_string().replace("\n", "\n  "))?;
+                }
+            }
+            BamlValueWithFlags::DateTime(v) => {
+                write!(f, "{}", baml_types::temporal::format_datetime(&v.value))?;
+                if !v.flags.flags.is_empty() {
+                    write!(f, "\n  {}", v.flags.to_string().replace("\n", "\n  "))?;
+                }
+            }
+            BamlValueWithFlags::Duration(v) => {
+                write!(f, "{}", baml_types::temporal::format_duration(&v.value))?;
+                if !v.flags.flags.is_empty() {
+                    write!(f, "\n  {}", v.flags.to_string().replace("\n", "\n  "))?;
+                }
+            }
             BamlValueWithFlags::List(flags, v) => {
                 write!(f, "\n")?;
                 for (idx, item) in v.iter().enumerate() {
@@ -450,6 +520,9 @@ impl From<BamlValueWithFlags> for BamlValueWithMeta<Vec<(Constraint, bool)>> {
             Int(ValueWithFlags { value, .. }) => BamlValueWithMeta::Int(value, c),
             Float(ValueWithFlags { value, .. }) => BamlValueWithMeta::Float(value, c),
             Bool(ValueWithFlags { value, .. }) => BamlValueWithMeta::Bool(value, c),
+            Date(ValueWithFlags { value, .. }) => BamlValueWithMeta::Date(value, c),
+            DateTime(ValueWithFlags { value, .. }) => BamlValueWithMeta::DateTime(value, c),
+            Duration(ValueWithFlags { value, .. }) => BamlValueWithMeta::Duration(value, c),
             Map(_, values) => BamlValueWithMeta::Map(
                 values.into_iter().map(|(k, v)| (k, v.1.into())).collect(),
                 c,
@@ -10,9 +10,19 @@ use crate::jsonish::{
     Value,
 };
 
-use super::ParseOptions;
+use super::{ParseOptions, StreamState};
 
-pub fn parse<'a>(str: &'a str, mut options: ParseOptions) -> Result<Value> {
+pub fn parse<'a>(str: &'a str, options: ParseOptions) -> Result<Value> {
+    parse_resumable(str, options, None)
+}
+
+/// Parses `str`, resuming the fixing parses of an earlier parse of the start
+/// of `str` when there's a `state`.
+pub(super) fn parse_resumable<'a>(
+    str: &'a str,
+    mut options: ParseOptions,
+    mut state: Option<&mut StreamState>,
+) -> Result<Value> {
     log::debug!("Parsing:\n{:?}\n-------\n{}\n-------", options, str);
 
     options.depth += 1;
@@ -22,15 +32,19 @@ pub fn parse<'a>(str: &'a str, mut options: ParseOptions) -> Result<Value> {
         ));
     }
 
-    match serde_json::from_str(str) {
-        Ok(v) => return Ok(Value::AnyOf(vec![v], str.to_string())),
-        Err(e) => {
-            log::debug!("Invalid JSON: {:?}", e);
-        }
-    };
+    // Only text that ends like a JSON value can be JSON. Checking first saves
+    // reading through all of a partial response just to find that out.
+    if ends_like_json(str) {
+        match serde_json::from_str(str) {
+            Ok(v) => return Ok(Value::AnyOf(vec![v], str.to_string())),
+            Err(e) => {
+                log::debug!("Invalid JSON: {:?}", e);
+            }
+        };
+    }
 
     if options.allow_markdown_json {
-        match markdown_parser::parse(str, &options) {
+        match markdown_parser::parse(str, &options, state.as_deref_mut()) {
             Ok(items) => match items.len() {
                 0 => {}
                 1 => {
@@ -63,11 +77,12 @@ pub fn parse<'a>(str: &'a str, mut options: ParseOptions) -> Result<Value> {
                             _ => None,
                         })
                         .map(|v| {
-                            parse(
+                            parse_resumable(
                                 str,
                                 options.next_from_mode(
                                     crate::jsonish::parser::ParsingMode::JsonMarkdownString,
                                 ),
+                                state.as_deref_mut(),
                             )
                         })
                         .filter_map(|res| match res {
@@ -103,7 +118,7 @@ pub fn parse<'a>(str: &'a str, mut options: ParseOptions) -> Result<Value> {
     }
 
     if options.all_finding_all_json_objects {
-        match multi_json_parser::parse(str, &options) {
+        match multi_json_parser::parse(str, &options, state.as_deref_mut()) {
             Ok(items) => match items.len() {
                 0 => {}
                 1 => {
@@ -136,7 +151,11 @@ pub fn parse<'a>(str: &'a str, mut options: ParseOptions) -> Result<Value> {
     }
 
     if options.allow_fixes {
-        match fixing_parser::parse(str, &options) {
+        let fixed = match state {
+            Some(state) => state.fixing_parse(str, &options),
+            None => fixing_parser::parse(str, &options),
+        };
+        match fixed {
             Ok(items) => {
                 match items.len() {
                     0 => {}
@@ -185,3 +204,10 @@ pub fn parse<'a>(str: &'a str, mut options: ParseOptions) -> Result<Value> {
 
     Err(anyhow::anyhow!("Failed to parse JSON"))
 }
+
+fn ends_like_json(str: &str) -> bool {
+    matches!(
+        str.trim_end().chars().last(),
+        Some('}' | ']' | '"' | 'e' | 'l' | '0'..='9')
+    )
+}
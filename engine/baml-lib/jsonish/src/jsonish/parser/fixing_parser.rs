@@ -1,9 +1,11 @@
 mod json_collection;
 mod json_parse_state;
 
+use std::{cell::Cell, str::CharIndices};
+
 use crate::jsonish::{value::Fixes, Value};
 
-use self::json_parse_state::JsonParseState;
+use self::{json_collection::JsonCollection, json_parse_state::JsonParseState};
 
 use super::ParseOptions;
 use anyhow::Result;
@@ -28,20 +30,127 @@ pub fn parse<'a>(str: &'a str, _options: &ParseOptions) -> Result<Vec<(Value, Ve
     // - Unterminated strings
 
     let mut state = JsonParseState::new();
+    feed(&mut state, str, 0, str.len())?;
+    completed_values(state)
+}
+
+/// A fixing parse that picks up where it left off when its input grows, as
+/// the text of a streamed response does.
+///
+/// Processing a token only looks at the tokens after it, so the state it
+/// leaves behind holds for any text that starts with what was looked at. The
+/// one exception is a token whose look ahead ran into the end of the text:
+/// with more text, it may be processed differently. We keep the state from
+/// just before the first such token, and the next parse starts from there.
+pub struct IncrementalParser {
+    /// The text given to the last parse.
+    text: String,
+    /// Where the tokens that `state` hasn't processed yet start.
+    offset: usize,
+    state: JsonParseState,
+}
+
+impl IncrementalParser {
+    pub fn new() -> Self {
+        Self {
+            text: String::new(),
+            offset: 0,
+            state: JsonParseState::new(),
+        }
+    }
+
+    /// Whether `str` continues the text of the last parse.
+    pub fn resumes(&self, str: &str) -> bool {
+        str.starts_with(&self.text)
+    }
+
+    /// How long the text of the last parse was.
+    pub fn seen_len(&self) -> usize {
+        self.text.len()
+    }
+
+    /// Same as [`parse`], but only processes what's new since the last parse.
+    pub fn parse(
+        &mut self,
+        str: &str,
+        _options: &ParseOptions,
+    ) -> Result<Vec<(Value, Vec<Fixes>)>> {
+        if !self.resumes(str) {
+            *self = Self::new();
+        }
+
+        let mut state = self.state.clone();
+        let stable_until = feed(&mut state, str, self.offset, str.len())?;
+        feed(&mut self.state, str, self.offset, stable_until)?;
+        self.offset = stable_until;
+        self.text.push_str(&str[self.text.len()..]);
 
-    let mut chars = str.char_indices().peekable();
+        completed_values(state)
+    }
+}
+
+/// Processes the tokens of `str` that start in `from..until`.
+///
+/// Returns where the first of them whose processing looked past the end of
+/// `str` starts, or `until` if none did. The state up to there is the same
+/// for any text that `str` is the start of.
+fn feed(state: &mut JsonParseState, str: &str, from: usize, until: usize) -> Result<usize> {
+    let saw_end = Cell::new(false);
+    let mut stable_until = None;
+
+    let mut chars = str[from..]
+        .char_indices()
+        .map(|(idx, c)| (from + idx, c))
+        .peekable();
     while let Some((count, c)) = chars.next() {
-        let peekable = str[count + c.len_utf8()..].char_indices().peekable();
-        match state.process_token(c, peekable) {
-            Ok(increments) => {
-                for _ in 0..increments {
-                    chars.next();
-                }
-            }
-            Err(e) => {
-                return Err(e);
+        if count >= until {
+            break;
+        }
+        let peekable = Lookahead {
+            chars: str[count + c.len_utf8()..].char_indices(),
+            saw_end: &saw_end,
+        }
+        .peekable();
+        let increments = state.process_token(c, peekable)?;
+        for _ in 0..increments {
+            if chars.next().is_none() {
+                saw_end.set(true);
             }
         }
+        if saw_end.get() && stable_until.is_none() {
+            stable_until = Some(count);
+        }
+    }
+
+    Ok(stable_until.unwrap_or(until))
+}
+
+/// The characters after a token, noting whether anything asked for more than
+/// there are.
+struct Lookahead<'a, 'b> {
+    chars: CharIndices<'a>,
+    saw_end: &'b Cell<bool>,
+}
+
+impl Iterator for Lookahead<'_, '_> {
+    type Item = (usize, char);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.chars.next();
+        if next.is_none() {
+            self.saw_end.set(true);
+        }
+        next
+    }
+}
+
+fn completed_values(mut state: JsonParseState) -> Result<Vec<(Value, Vec<Fixes>)>> {
+    // Anything still open was cut off by the end of the input.
+    let open = open_values(&state.collection_stack) + usize::from(state.cut_off_value);
+    if let Some((_, fixes)) = state.collection_stack.first_mut() {
+        if open > 0 {
+            fixes.push(Fixes::Unterminated(open));
+        }
     }
 
     // If we still have a collection open, close it
@@ -93,6 +202,25 @@ pub fn parse<'a>(str: &'a str, _options: &ParseOptions) -> Result<Vec<(Value, Ve
     }
 }
 
+/// How many of the open collections are values, rather than object keys or
+/// comments.
+fn open_values(stack: &[(JsonCollection, Vec<Fixes>)]) -> usize {
+    stack
+        .iter()
+        .enumerate()
+        .filter(|(idx, (collection, _))| {
+            let is_key = idx.checked_sub(1).map_or(false, |parent| {
+                matches!(&stack[parent].0, JsonCollection::Object(keys, values) if keys.len() == values.len())
+            });
+            !is_key
+                && !matches!(
+                    collection,
+                    JsonCollection::BlockComment(_) | JsonCollection::TrailingComment(_)
+                )
+        })
+        .count()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -163,4 +291,65 @@ mod tests {
             _ => panic!("Expected object")
         }
     }
+
+    #[test]
+    fn test_unterminated_values() {
+        let opts = ParseOptions::default();
+        let unterminated = |text: &str| {
+            let vals = parse(text, &opts).unwrap();
+            vals[0].1.iter().find_map(|f| match f {
+                Fixes::Unterminated(open) => Some(*open),
+                _ => None,
+            })
+        };
+        assert_eq!(unterminated(r#"{"a": 11}"#), None);
+        assert_eq!(unterminated(r#"{"a": 11, "b": "x"#), Some(2));
+        assert_eq!(unterminated(r#"{"a": 11, "b"#), Some(1));
+        assert_eq!(unterminated(r#"{"a": [{"b": 1}, {"c": tr"#), Some(4));
+    }
+
+    fn assert_same_parse(incremental: &mut IncrementalParser, text: &str) {
+        let opts = ParseOptions::default();
+        match (incremental.parse(text, &opts), parse(text, &opts)) {
+            (Ok(a), Ok(b)) => assert_eq!(a, b, "Different parses of {:?}", text),
+            (Err(_), Err(_)) => {}
+            (a, b) => panic!("Different parses of {:?}: {:?} vs {:?}", text, a, b),
+        }
+    }
+
+    #[test]
+    fn test_incremental_parse_matches_full_parse() {
+        let text = r#"Here you go:
+{
+  "name": "Bob \"the builder\"",
+  'nickname': 'bobby',
+  "tags": ["a", "b", // a comment
+  ],
+  "notes": unquoted text, with a comma,
+  "score": 12.5,
+  "nested": {"ok": true, "items": [1, 2, {"x": null}]}
+}"#;
+        let mut incremental = IncrementalParser::new();
+        for end in (1..=text.len()).filter(|end| text.is_char_boundary(*end)) {
+            assert_same_parse(&mut incremental, &text[..end]);
+        }
+        assert!(incremental.offset > 0);
+    }
+
+    #[test]
+    fn test_incremental_parse_in_chunks() {
+        let text = r#"[{"a": 1, "b": "two"}, {"a": 3, "b": "four"}, {"a": 5"#;
+        let mut incremental = IncrementalParser::new();
+        for end in [4, 9, 21, 30, 47, text.len()] {
+            assert_same_parse(&mut incremental, &text[..end]);
+        }
+    }
+
+    #[test]
+    fn test_incremental_parse_restarts_on_new_text() {
+        let mut incremental = IncrementalParser::new();
+        assert_same_parse(&mut incremental, r#"{"a": [1, 2"#);
+        assert_same_parse(&mut incremental, r#"{"b": "c"}"#);
+        assert_same_parse(&mut incremental, r#"{"b": "c"}, {"d": 4}"#);
+    }
 }
@@ -2,7 +2,7 @@ use baml_types::BamlMap;
 
 use crate::jsonish::Value;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum JsonCollection {
     // Key, Value
     Object(Vec<String>, Vec<Value>),
@@ -5,11 +5,16 @@ use anyhow::Result;
 
 use super::json_collection::JsonCollection;
 
+#[derive(Clone)]
 pub struct JsonParseState {
     pub collection_stack: Vec<(JsonCollection, Vec<Fixes>)>,
 
     // Technically we may find multiple values in a single string
     pub completed_values: Vec<(&'static str, Value, Vec<Fixes>)>,
+
+    // Whether the end of the text closed an unquoted value, which may have
+    // had more to come
+    pub cut_off_value: bool,
 }
 
 impl JsonParseState {
@@ -17,6 +22,7 @@ impl JsonParseState {
         JsonParseState {
             collection_stack: vec![],
             completed_values: vec![],
+            cut_off_value: false,
         }
     }
 
@@ -239,6 +245,7 @@ impl JsonParseState {
                         }
                     }
                 }
+                self.cut_off_value = true;
                 Some(counter)
             }
             4 => {
@@ -255,6 +262,7 @@ impl JsonParseState {
                     }
                 }
                 counter += 1; // Indicate that we called next() one time after the final `Some`.
+                self.cut_off_value = true;
                 Some(counter)
             }
             _ => unreachable!("Invalid position"),
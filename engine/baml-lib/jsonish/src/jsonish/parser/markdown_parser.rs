@@ -3,7 +3,7 @@ use crate::jsonish::{
     Value,
 };
 
-use super::ParseOptions;
+use super::{ParseOptions, StreamState};
 use anyhow::Result;
 
 #[derive(Debug)]
@@ -12,7 +12,11 @@ pub enum MarkdownResult {
     String(String),
 }
 
-pub fn parse<'a>(str: &'a str, options: &ParseOptions) -> Result<Vec<MarkdownResult>> {
+pub fn parse<'a>(
+    str: &'a str,
+    options: &ParseOptions,
+    mut state: Option<&mut StreamState>,
+) -> Result<Vec<MarkdownResult>> {
     let mut values = vec![];
 
     let mut remaining = str;
@@ -40,9 +44,10 @@ pub fn parse<'a>(str: &'a str, options: &ParseOptions) -> Result<Vec<MarkdownRes
 
         log::trace!("Content:\n-----\n{}\n-----\n", md_content);
 
-        let res = entry::parse(
+        let res = entry::parse_resumable(
             md_content,
             options.next_from_mode(ParsingMode::JsonMarkdown),
+            state.as_deref_mut(),
         );
 
         match res {
@@ -102,6 +107,7 @@ print("Hello, world!")
 ```
 "#,
             &ParseOptions::default(),
+            None,
         );
 
         let res = res?;
@@ -159,6 +165,7 @@ lorem ipsum
 dolor sit amet
             "#,
             &ParseOptions::default(),
+            None,
         );
 
         let res = res?;
@@ -186,6 +193,7 @@ lorem ipsum
 dolor sit amet
             "#,
             &ParseOptions::default(),
+            None,
         );
 
         let res = res?;
@@ -5,6 +5,51 @@ mod multi_json_parser;
 
 pub use entry::parse;
 
+use anyhow::Result;
+
+use crate::jsonish::{value::Fixes, Value};
+
+use self::fixing_parser::IncrementalParser;
+
+/// Parsers kept between the parses of a streamed response, so that each parse
+/// only goes through the text that was added since the last one.
+#[derive(Default)]
+pub struct StreamState {
+    idle: Vec<IncrementalParser>,
+    used: Vec<IncrementalParser>,
+}
+
+impl StreamState {
+    /// Like [`parse`], where `str` continues the text of the last call.
+    pub fn parse(&mut self, str: &str, options: ParseOptions) -> Result<Value> {
+        let res = entry::parse_resumable(str, options, Some(self));
+        // Parsers that weren't used won't be resumed again.
+        self.idle = std::mem::take(&mut self.used);
+        res
+    }
+
+    fn fixing_parse(
+        &mut self,
+        str: &str,
+        options: &ParseOptions,
+    ) -> Result<Vec<(Value, Vec<Fixes>)>> {
+        let resumed = self
+            .idle
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| p.resumes(str))
+            .max_by_key(|(_, p)| p.seen_len())
+            .map(|(idx, _)| idx);
+        let mut parser = match resumed {
+            Some(idx) => self.idle.swap_remove(idx),
+            None => IncrementalParser::new(),
+        };
+        let res = parser.parse(str, options);
+        self.used.push(parser);
+        res
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct ParseOptions {
     all_finding_all_json_objects: bool,
@@ -1,9 +1,13 @@
 use crate::jsonish::Value;
 
-use super::{entry, ParseOptions};
+use super::{entry, ParseOptions, StreamState};
 use anyhow::Result;
 
-pub fn parse<'a>(str: &'a str, options: &ParseOptions) -> Result<Vec<Value>> {
+pub fn parse<'a>(
+    str: &'a str,
+    options: &ParseOptions,
+    mut state: Option<&mut StreamState>,
+) -> Result<Vec<Value>> {
     // Find all balanced JSON objects but w/o any fixes.
     let mut stack = Vec::new();
     let mut json_str_start = None;
@@ -34,9 +38,10 @@ pub fn parse<'a>(str: &'a str, options: &ParseOptions) -> Result<Vec<Value>> {
                     } else {
                         &str[..end_index]
                     };
-                    match entry::parse(
+                    match entry::parse_resumable(
                         json_str,
                         options.next_from_mode(super::ParsingMode::AllJsonObjects),
+                        state.as_deref_mut(),
                     ) {
                         Ok(json) => json_objects.push(json),
                         Err(e) => {
@@ -55,9 +60,10 @@ pub fn parse<'a>(str: &'a str, options: &ParseOptions) -> Result<Vec<Value>> {
         match json_str_start {
             Some(start) => {
                 let json_str = &str[start..];
-                match entry::parse(
+                match entry::parse_resumable(
                     json_str,
                     options.next_from_mode(super::ParsingMode::AllJsonObjects),
+                    state.as_deref_mut(),
                 ) {
                     Ok(json) => json_objects.push(json),
                     Err(e) => {
@@ -102,6 +108,7 @@ print("Hello, world!")
 ```
 "#,
             &ParseOptions::default(),
+            None,
         );
 
         let res = res?;
@@ -6,6 +6,9 @@ use baml_types::BamlMap;
 pub enum Fixes {
     GreppedForJSON,
     InferredArray,
+    /// The input ended while this value was still open, along with the given
+    /// number of values nested along its last element (this one included).
+    Unterminated(usize),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -6,38 +6,80 @@ pub mod deserializer;
 mod jsonish;
 
 use baml_types::FieldType;
-use deserializer::coercer::{ParsingContext, TypeCoercer};
+use deserializer::coercer::{CoercionCache, ParsingContext, TypeCoercer};
 
 pub use deserializer::types::BamlValueWithFlags;
 use internal_baml_core::ir::TypeValue;
 use internal_baml_jinja::types::OutputFormatContent;
 
 use deserializer::deserialize_flags::Flag;
-use jsonish::Value;
+use jsonish::{Fixes, Value};
+
+/// What the parses of a streamed response keep for the next one.
+///
+/// With it, a parse only goes through the text that was added since the last
+/// one, and reuses the coercion of the list items that were already complete.
+/// Use a new one for each stream, with the same output format and target type
+/// throughout.
+#[derive(Default)]
+pub struct StreamingState {
+    parser: jsonish::StreamState,
+    coercions: CoercionCache,
+}
 
 pub fn from_str(
     of: &OutputFormatContent,
     target: &FieldType,
     raw_string: &str,
     allow_partials: bool,
+) -> Result<BamlValueWithFlags> {
+    parse_and_coerce(of, target, raw_string, allow_partials, None)
+}
+
+/// Parses the response streamed so far, where `raw_string` continues the text
+/// of the last parse with the same `state`.
+pub fn from_str_streaming(
+    of: &OutputFormatContent,
+    target: &FieldType,
+    raw_string: &str,
+    state: &mut StreamingState,
+) -> Result<BamlValueWithFlags> {
+    parse_and_coerce(of, target, raw_string, true, Some(state))
+}
+
+fn parse_and_coerce(
+    of: &OutputFormatContent,
+    target: &FieldType,
+    raw_string: &str,
+    allow_partials: bool,
+    mut state: Option<&mut StreamingState>,
 ) -> Result<BamlValueWithFlags> {
     if matches!(target, FieldType::Primitive(TypeValue::String)) {
         return Ok(BamlValueWithFlags::String(raw_string.to_string().into()));
     }
 
     // When the schema is just a string, i should really just return the raw_string w/o parsing it.
-    let mut value = jsonish::parse(raw_string, jsonish::ParseOptions::default())?;
+    let mut value = match state {
+        Some(ref mut state) => state
+            .parser
+            .parse(raw_string, jsonish::ParseOptions::default())?,
+        None => jsonish::parse(raw_string, jsonish::ParseOptions::default())?,
+    };
     // let schema = deserializer::schema::from_jsonish_value(&value, None);
 
     // See Note [Streaming Number Invalidation]
     if allow_partials {
         invalidate_numbers_in_progress(&mut value, raw_string);
+        mark_unterminated_values(&mut value);
     }
 
     // Pick the schema that is the most specific.
     // log::info!("Parsed: {}", schema);
     log::debug!("Parsed JSONish (step 1 of parsing): {:#?}", value);
-    let ctx = ParsingContext::new(of, allow_partials);
+    let ctx = match state {
+        Some(state) => ParsingContext::with_cache(of, allow_partials, &state.coercions),
+        None => ParsingContext::new(of, allow_partials),
+    };
     // let res = schema.cast_to(target);
     // log::info!("Casted: {:?}", res);
 
@@ -125,6 +167,51 @@ fn last_value_as_number(value: &mut Value) -> Vec<&mut Value> {
     }
 }
 
+/// Mark every value that the end of the response cut off, not just the
+/// outermost one, so that `@stream.*` fields can tell how far along they are.
+///
+/// The fixing parser only notes on the outermost value how many values were
+/// still open. Those are always the outermost value's last element, that
+/// element's own last element, and so on.
+fn mark_unterminated_values(value: &mut Value) {
+    match value {
+        Value::FixedJson(inner, fixes) => {
+            let open = fixes.iter().find_map(|f| match f {
+                Fixes::Unterminated(open) => Some(*open),
+                _ => None,
+            });
+            match open {
+                Some(open) => mark_last_values(inner, open.saturating_sub(1)),
+                None => mark_unterminated_values(inner),
+            }
+        }
+        Value::Markdown(_, inner) => mark_unterminated_values(inner),
+        Value::AnyOf(variants, _) => variants.iter_mut().for_each(mark_unterminated_values),
+        Value::Array(items) => {
+            if let Some(last) = items.last_mut() {
+                mark_unterminated_values(last);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn mark_last_values(value: &mut Value, open: usize) {
+    if open == 0 {
+        return;
+    }
+    let last = match value {
+        Value::Array(items) => items.last_mut(),
+        Value::Object(fields) => fields.last_mut().map(|(_, v)| v),
+        _ => None,
+    };
+    if let Some(last) = last {
+        let mut inner = Box::new(std::mem::replace(last, Value::Null));
+        mark_last_values(&mut inner, open - 1);
+        *last = Value::FixedJson(inner, vec![Fixes::Unterminated(open)]);
+    }
+}
+
 /*
  * Note: Streaming Number Invalidation
  *
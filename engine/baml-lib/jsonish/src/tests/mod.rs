@@ -7,11 +7,15 @@ pub mod macros;
 mod test_basics;
 mod test_class;
 mod test_constraints;
+mod test_dates;
+mod test_defaults;
 mod test_enum;
 mod test_lists;
 mod test_literals;
 mod test_maps;
 mod test_partials;
+mod test_recursive_types;
+mod test_streaming;
 mod test_unions;
 
 use std::{
@@ -19,7 +23,8 @@ use std::{
     path::PathBuf,
 };
 
-use baml_types::{BamlValue, Constraint, ConstraintLevel, JinjaExpression};
+use baml_types::{BamlValue, Constraint, ConstraintLevel, JinjaExpression, StreamingBehavior};
+use indexmap::IndexMap;
 use internal_baml_core::{
     internal_baml_diagnostics::SourceFile,
     ir::{repr::IntermediateRepr, ClassWalker, EnumWalker, FieldType, IRHelper, TypeValue},
@@ -52,8 +57,10 @@ fn render_output_format(
     output: &FieldType,
     env_values: &HashMap<String, String>,
 ) -> Result<OutputFormatContent> {
-    let (enums, classes) = relevant_data_models(ir, output, env_values)?;
-    return Ok(OutputFormatContent::new(enums, classes, output.clone()));
+    let (enums, classes, aliases) = relevant_data_models(ir, output, env_values)?;
+    return Ok(
+        OutputFormatContent::new(enums, classes, output.clone()).with_recursive_aliases(aliases)
+    );
 }
 
 fn find_existing_class_field<'a>(
@@ -61,7 +68,13 @@ fn find_existing_class_field<'a>(
     field_name: &str,
     class_walker: &Result<ClassWalker<'a>>,
     env_values: &HashMap<String, String>,
-) -> Result<(Name, FieldType, Option<String>)> {
+) -> Result<(
+    Name,
+    FieldType,
+    Option<String>,
+    StreamingBehavior,
+    Option<BamlValue>,
+)> {
     let Ok(class_walker) = class_walker else {
         anyhow::bail!("Class {} does not exist", class_name);
     };
@@ -73,7 +86,13 @@ fn find_existing_class_field<'a>(
     let name = Name::new_with_alias(field_name.to_string(), field_walker.alias(env_values)?);
     let desc = field_walker.description(env_values)?;
     let r#type = field_walker.r#type();
-    Ok((name, r#type.clone(), desc))
+    Ok((
+        name,
+        r#type.clone(),
+        desc,
+        field_walker.streaming_behavior(),
+        field_walker.default_value(env_values)?,
+    ))
 }
 
 fn find_enum_value(
@@ -112,10 +131,11 @@ fn relevant_data_models<'a>(
     ir: &'a IntermediateRepr,
     output: &'a FieldType,
     env_values: &HashMap<String, String>,
-) -> Result<(Vec<Enum>, Vec<Class>)> {
+) -> Result<(Vec<Enum>, Vec<Class>, IndexMap<String, FieldType>)> {
     let mut checked_types: HashSet<String> = HashSet::new();
     let mut enums = Vec::new();
     let mut classes: Vec<Class> = Vec::new();
+    let mut aliases = IndexMap::new();
     let mut start: Vec<baml_types::FieldType> = vec![output.clone()];
 
     while !start.is_empty() {
@@ -187,19 +207,28 @@ fn relevant_data_models<'a>(
 
                     let fields = fields.collect::<Result<Vec<_>>>()?;
 
-                    for (_, t, _) in fields.iter().as_ref() {
+                    for (_, t, ..) in fields.iter().as_ref() {
                         if !checked_types.contains(&t.to_string()) {
                             start.push(t.clone());
                         }
                     }
 
+                    let walker = walker?;
                     classes.push(Class {
-                        name: Name::new_with_alias(cls.to_string(), walker?.alias(env_values)?),
+                        name: Name::new_with_alias(cls.to_string(), walker.alias(env_values)?),
                         fields,
                         constraints,
+                        streaming_behavior: walker.streaming_behavior(),
                     });
                 }
             }
+            (FieldType::RecursiveTypeAlias(name), _) => {
+                if checked_types.insert(output.to_string()) {
+                    let target = ir.find_type_alias(name)?.target();
+                    aliases.insert(name.to_string(), target.clone());
+                    start.push(target.clone());
+                }
+            }
             (FieldType::Literal(_), _) => {}
             (FieldType::Primitive(_), _constraints) => {}
             (FieldType::Constrained{..}, _) => {
@@ -208,7 +237,7 @@ fn relevant_data_models<'a>(
         }
     }
 
-    Ok((enums, classes))
+    Ok((enums, classes, aliases))
 }
 
 const EMPTY_FILE: &str = r#"
@@ -127,3 +127,35 @@ class Foo {
   foo int @description("hi") @check(hi, {{this == 1}})
 }
 "#;
+
+const CLASS_WITH_REFINEMENTS: &str = r#"
+class Review {
+  score int @range(1, 5)
+  title string @length(1, 20)
+  summary string? @length(null, 10)
+}
+"#;
+
+test_deserializer_with_expected_score!(
+    test_refinements_within_bounds,
+    CLASS_WITH_REFINEMENTS,
+    r#"{"score": 4, "title": "Great", "summary": null}"#,
+    FieldType::Class("Review".to_string()),
+    0
+);
+
+test_deserializer_with_expected_score!(
+    test_refinement_out_of_range,
+    CLASS_WITH_REFINEMENTS,
+    r#"{"score": 7, "title": "Great", "summary": "Loved it"}"#,
+    FieldType::Class("Review".to_string()),
+    50
+);
+
+test_deserializer_with_expected_score!(
+    test_refinement_too_long,
+    CLASS_WITH_REFINEMENTS,
+    r#"{"score": 4, "title": "Great", "summary": "Loved every minute of it"}"#,
+    FieldType::Class("Review".to_string()),
+    50
+);
@@ -0,0 +1,74 @@
+use super::*;
+
+const EVENT_FILE: &str = r#"
+class Event {
+  name string
+  day date
+  starts_at datetime
+  length duration
+}
+"#;
+
+test_deserializer!(
+    test_iso_dates,
+    EVENT_FILE,
+    r#"{"name": "Standup", "day": "2024-01-31", "starts_at": "2024-01-31T09:30:00Z", "length": "PT15M"}"#,
+    FieldType::Class("Event".to_string()),
+    {
+      "name": "Standup",
+      "day": "2024-01-31",
+      "starts_at": "2024-01-31T09:30:00+00:00",
+      "length": "PT15M"
+    }
+);
+
+test_deserializer!(
+    test_natural_dates,
+    EVENT_FILE,
+    r#"{"name": "Standup", "day": "Jan 31st, 2024", "starts_at": "01/31/2024 9:30 AM", "length": "15 minutes"}"#,
+    FieldType::Class("Event".to_string()),
+    {
+      "name": "Standup",
+      "day": "2024-01-31",
+      "starts_at": "2024-01-31T09:30:00+00:00",
+      "length": "PT15M"
+    }
+);
+
+test_deserializer!(
+    test_datetime_keeps_offset,
+    EMPTY_FILE,
+    r#""2024-01-31T09:30:00-05:00""#,
+    FieldType::Primitive(TypeValue::DateTime),
+    "2024-01-31T09:30:00-05:00"
+);
+
+test_deserializer!(
+    test_date_from_datetime,
+    EMPTY_FILE,
+    "2024-01-31T23:30:00+09:00",
+    FieldType::Primitive(TypeValue::Date),
+    "2024-01-31"
+);
+
+test_deserializer!(
+    test_duration_from_clock,
+    EMPTY_FILE,
+    "01:30:00",
+    FieldType::Primitive(TypeValue::Duration),
+    "PT1H30M"
+);
+
+test_failing_deserializer!(
+    test_unparseable_date,
+    EMPTY_FILE,
+    r#""sometime next week""#,
+    FieldType::Primitive(TypeValue::Date)
+);
+
+test_failing_deserializer!(
+    test_duration_without_unit,
+    EMPTY_FILE,
+    "90",
+    FieldType::Primitive(TypeValue::Duration)
+);
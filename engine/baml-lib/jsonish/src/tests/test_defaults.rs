@@ -0,0 +1,73 @@
+use super::*;
+
+const SETTINGS_FILE: &str = r#"
+enum Level {
+  Low
+  High
+}
+
+class Settings {
+  theme string @default("dark")
+  retries int @default(3)
+  ratio float @default(0.5)
+  tags string[] @default(["a", "b"])
+  level Level @default(Low)
+  note string? @default("none")
+  name string
+}
+"#;
+
+test_deserializer!(
+    test_missing_fields_take_defaults,
+    SETTINGS_FILE,
+    r#"{"name": "main"}"#,
+    FieldType::Class("Settings".to_string()),
+    {
+      "theme": "dark",
+      "retries": 3,
+      "ratio": 0.5,
+      "tags": ["a", "b"],
+      "level": "Low",
+      "note": "none",
+      "name": "main"
+    }
+);
+
+test_deserializer!(
+    test_present_fields_override_defaults,
+    SETTINGS_FILE,
+    r#"{"name": "main", "theme": "light", "retries": 5, "level": "High", "note": null}"#,
+    FieldType::Class("Settings".to_string()),
+    {
+      "theme": "light",
+      "retries": 5,
+      "ratio": 0.5,
+      "tags": ["a", "b"],
+      "level": "High",
+      "note": null,
+      "name": "main"
+    }
+);
+
+test_failing_deserializer!(
+    test_fields_without_defaults_are_still_required,
+    SETTINGS_FILE,
+    r#"{"theme": "light"}"#,
+    FieldType::Class("Settings".to_string())
+);
+
+test_partial_deserializer!(
+    test_defaults_are_not_applied_while_streaming,
+    SETTINGS_FILE,
+    r#"{"name": "main", "theme": "li"#,
+    FieldType::Class("Settings".to_string()),
+    {
+      "theme": "li",
+      "retries": null,
+      "ratio": null,
+      "tags": [],
+      "level": null,
+      "note": null,
+      "name": "main"
+    }
+);
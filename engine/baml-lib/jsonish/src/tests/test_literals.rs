@@ -26,6 +26,29 @@ test_deserializer!(
     0
 );
 
+test_deserializer!(
+    test_literal_float,
+    EMPTY_FILE,
+    "0.5",
+    FieldType::Literal(LiteralValue::Float(0.5)),
+    0.5
+);
+
+test_deserializer!(
+    test_literal_float_from_integer,
+    EMPTY_FILE,
+    "2",
+    FieldType::Literal(LiteralValue::Float(2.0)),
+    2.0
+);
+
+test_failing_deserializer!(
+    test_literal_float_mismatch,
+    EMPTY_FILE,
+    "0.25",
+    FieldType::Literal(LiteralValue::Float(0.5))
+);
+
 test_deserializer!(
     test_literal_boolean_true,
     EMPTY_FILE,
@@ -1,4 +1,5 @@
 use super::*;
+use crate::{from_str_streaming, StreamingState};
 
 const BAML_FILE: &str = r###"
 class Score {
@@ -357,3 +358,56 @@ test_partial_deserializer!(
     ]
   }
 );
+
+const STREAMED_GRAPH: &str = r#"Here is the graph:
+```json
+{
+  "vertices": [
+    {"id": "stephanie_morales", "metadata": {"name": "Stephanie Morales", "affiliation": "Made Space"}},
+    {"id": "peter", "metadata": {"name": "Peter", "age": "42"}},
+    {"id": 'ada', "metadata": {"name": unquoted Ada}}
+  ],
+  "edges": [
+    {"source_id": "stephanie_morales", "target_id": "peter", "relationship": "knows"},
+    {"source_id": "peter", "target_id": "ada", "relationship": "works_with"}
+  ]
+}
+```
+"#;
+
+#[test_log::test]
+fn test_streaming_matches_partial_parse() {
+    let ir = load_test_ir(CHOPPY_BAML_FILE);
+    for target_type in [
+        FieldType::Class("GraphJson".to_string()),
+        FieldType::union(vec![
+            FieldType::Class("GraphJson".to_string()),
+            FieldType::Class("ErrorBasic".to_string()),
+        ]),
+    ] {
+        let target = render_output_format(&ir, &target_type, &Default::default()).unwrap();
+        let mut state = StreamingState::default();
+        let ends = (1..STREAMED_GRAPH.len())
+            .step_by(3)
+            .chain(std::iter::once(STREAMED_GRAPH.len()));
+        for end in ends {
+            let raw_string = &STREAMED_GRAPH[..end];
+            let streamed = from_str_streaming(&target, &target_type, raw_string, &mut state);
+            let parsed = from_str(&target, &target_type, raw_string, true);
+            match (streamed, parsed) {
+                (Ok(streamed), Ok(parsed)) => {
+                    assert_eq!(streamed.score(), parsed.score(), "{raw_string}");
+                    assert_eq!(
+                        json!(BamlValue::from(streamed)),
+                        json!(BamlValue::from(parsed)),
+                        "{raw_string}"
+                    );
+                }
+                (Err(_), Err(_)) => {}
+                (streamed, parsed) => {
+                    panic!("{raw_string}\nstreamed: {streamed:?}\nparsed: {parsed:?}")
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use super::*;
+
+const LINKED_LIST_FILE: &str = r#"
+class LinkedList {
+  head Node?
+  len int
+}
+
+class Node {
+  data int
+  next Node?
+}
+"#;
+
+test_deserializer!(
+    test_linked_list,
+    LINKED_LIST_FILE,
+    r#"{"head": {"data": 1, "next": {"data": 2, "next": {"data": 3, "next": null}}}, "len": 3}"#,
+    FieldType::Class("LinkedList".to_string()),
+    {"head": {"data": 1, "next": {"data": 2, "next": {"data": 3, "next": null}}}, "len": 3}
+);
+
+test_deserializer!(
+    test_linked_list_missing_tail,
+    LINKED_LIST_FILE,
+    r#"{"head": {"data": 1, "next": {"data": 2}}, "len": 2}"#,
+    FieldType::Class("LinkedList".to_string()),
+    {"head": {"data": 1, "next": {"data": 2, "next": null}}, "len": 2}
+);
+
+const TREE_FILE: &str = r#"
+class Tree {
+  value int
+  children Tree[]
+}
+"#;
+
+test_deserializer!(
+    test_tree,
+    TREE_FILE,
+    r#"
+    The tree is:
+    ```json
+    {
+      "value": 1,
+      "children": [
+        {"value": 2, "children": []},
+        {"value": 3, "children": [{"value": 4, "children": []}]}
+      ]
+    }
+    ```
+    "#,
+    FieldType::Class("Tree".to_string()),
+    {
+      "value": 1,
+      "children": [
+        {"value": 2, "children": []},
+        {"value": 3, "children": [{"value": 4, "children": []}]}
+      ]
+    }
+);
+
+test_deserializer!(
+    test_tree_missing_children,
+    TREE_FILE,
+    r#"{"value": 1, "children": [{"value": 2}]}"#,
+    FieldType::Class("Tree".to_string()),
+    {"value": 1, "children": [{"value": 2, "children": []}]}
+);
+
+const JSON_FILE: &str = r#"
+type Json = int | bool | string | Json[] | map<string, Json>
+
+class Document {
+  title string
+  data Json
+}
+"#;
+
+test_deserializer!(
+    test_recursive_alias,
+    JSON_FILE,
+    r#"{"a": [1, {"b": true}], "c": "d"}"#,
+    FieldType::RecursiveTypeAlias("Json".to_string()),
+    {"a": [1, {"b": true}], "c": "d"}
+);
+
+test_deserializer!(
+    test_recursive_alias_in_class,
+    JSON_FILE,
+    r#"{"title": "doc", "data": [[1, 2], [3]]}"#,
+    FieldType::Class("Document".to_string()),
+    {"title": "doc", "data": [[1, 2], [3]]}
+);
+
+const MUTUALLY_RECURSIVE_FILE: &str = r#"
+class Folder {
+  name string
+  entries Entry[]
+}
+
+class File {
+  name string
+  size int
+}
+
+type Entry = Folder | File
+"#;
+
+test_deserializer!(
+    test_recursion_through_alias,
+    MUTUALLY_RECURSIVE_FILE,
+    r#"{"name": "src", "entries": [{"name": "lib.rs", "size": 10}, {"name": "tests", "entries": []}]}"#,
+    FieldType::Class("Folder".to_string()),
+    {"name": "src", "entries": [{"name": "lib.rs", "size": 10}, {"name": "tests", "entries": []}]}
+);
@@ -0,0 +1,68 @@
+use super::*;
+
+const STREAMING_BAML_FILE: &str = r#"
+class Item {
+  id int @stream.not_null
+  name string @stream.done
+  tags string[] @stream.with_state
+}
+
+class Address {
+  street string
+  city string
+  @@stream.done
+}
+
+class Person {
+  name string
+  address Address?
+}
+"#;
+
+test_partial_deserializer!(
+    test_stream_done_field_is_null_until_complete,
+    STREAMING_BAML_FILE,
+    r#"{"id": 1, "name": "appl"#,
+    FieldType::Class("Item".to_string()),
+    {"id": 1, "name": null, "tags": {"value": [], "state": "Pending"}}
+);
+
+test_partial_deserializer!(
+    test_stream_done_field_once_complete,
+    STREAMING_BAML_FILE,
+    r#"{"id": 1, "name": "apple", "tags": ["a", "b"#,
+    FieldType::Class("Item".to_string()),
+    {"id": 1, "name": "apple", "tags": {"value": ["a", "b"], "state": "Incomplete"}}
+);
+
+test_partial_deserializer!(
+    test_stream_not_null_holds_back_item,
+    STREAMING_BAML_FILE,
+    r#"[{"id": 1, "name": "apple", "tags": []}, {"name": "pear""#,
+    FieldType::List(FieldType::Class("Item".to_string()).into()),
+    [{"id": 1, "name": "apple", "tags": {"value": [], "state": "Complete"}}]
+);
+
+test_partial_deserializer!(
+    test_stream_done_class_is_null_until_complete,
+    STREAMING_BAML_FILE,
+    r#"{"name": "Jane", "address": {"street": "1 Main St", "city": "Spring"#,
+    FieldType::Class("Person".to_string()),
+    {"name": "Jane", "address": null}
+);
+
+test_partial_deserializer!(
+    test_stream_done_class_once_complete,
+    STREAMING_BAML_FILE,
+    r#"{"name": "Jane", "address": {"street": "1 Main St", "city": "Springfield"}"#,
+    FieldType::Class("Person".to_string()),
+    {"name": "Jane", "address": {"street": "1 Main St", "city": "Springfield"}}
+);
+
+test_deserializer!(
+    test_stream_attributes_ignored_when_complete,
+    STREAMING_BAML_FILE,
+    r#"{"id": 1, "name": "apple", "tags": ["a", "b"]}"#,
+    FieldType::Class("Item".to_string()),
+    {"id": 1, "name": "apple", "tags": ["a", "b"]}
+);
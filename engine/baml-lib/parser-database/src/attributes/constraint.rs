@@ -29,6 +29,7 @@ pub(super) fn visit_constraint_attributes(
                 level,
                 expression: expression.clone(),
                 label,
+                refinement: None,
             });
         }
         _ => panic!(
@@ -0,0 +1,19 @@
+use crate::{context::Context, types::Attributes};
+
+/// Visits `@default(...)` on a class field, merging it into `attributes`.
+pub(super) fn visit(ctx: &mut Context<'_>, attributes: &mut Option<Attributes>) {
+    if !ctx.visit_optional_single_attr("default") {
+        return;
+    }
+
+    match ctx
+        .visit_default_arg_with_idx("default")
+        .map(|(_, value)| value)
+    {
+        Ok(value) => attributes
+            .get_or_insert_with(Default::default)
+            .add_default_value(value.clone()),
+        Err(err) => ctx.push_error(err), // not flattened for error handing legacy reasons
+    };
+    ctx.validate_visited_arguments();
+}
@@ -2,7 +2,9 @@ use internal_baml_schema_ast::ast::{Top, TopId, TypeExpId, TypeExpressionBlock};
 
 mod alias;
 mod constraint;
+mod default_value;
 mod description;
+mod stream;
 mod to_string_attribute;
 use crate::interner::StringId;
 use crate::{context::Context, types::ClassAttributes, types::EnumAttributes};
@@ -26,6 +28,18 @@ pub struct Attributes {
 
     /// @check and @assert attributes attached to the node.
     pub constraints: Vec<Constraint>,
+
+    /// Value used for the field when the LLM leaves it out.
+    pub default_value: Option<Expression>,
+
+    /// Whether the node should only be streamed once it is complete.
+    pub stream_done: Option<bool>,
+
+    /// Whether the parent should be held back while streaming until this node is present.
+    pub stream_not_null: Option<bool>,
+
+    /// Whether the streamed value should be wrapped with its completion state.
+    pub stream_with_state: Option<bool>,
 }
 
 impl Attributes {
@@ -68,6 +82,46 @@ impl Attributes {
     pub fn set_skip(&mut self) {
         self.skip.replace(true);
     }
+
+    /// Set a default value.
+    pub fn add_default_value(&mut self, value: Expression) {
+        self.default_value.replace(value);
+    }
+
+    /// Get the default value.
+    pub fn default_value(&self) -> &Option<Expression> {
+        &self.default_value
+    }
+
+    /// Get stream.done.
+    pub fn stream_done(&self) -> &Option<bool> {
+        &self.stream_done
+    }
+
+    /// Set stream.done.
+    pub fn set_stream_done(&mut self) {
+        self.stream_done.replace(true);
+    }
+
+    /// Get stream.not_null.
+    pub fn stream_not_null(&self) -> &Option<bool> {
+        &self.stream_not_null
+    }
+
+    /// Set stream.not_null.
+    pub fn set_stream_not_null(&mut self) {
+        self.stream_not_null.replace(true);
+    }
+
+    /// Get stream.with_state.
+    pub fn stream_with_state(&self) -> &Option<bool> {
+        &self.stream_with_state
+    }
+
+    /// Set stream.with_state.
+    pub fn set_stream_with_state(&mut self) {
+        self.stream_with_state.replace(true);
+    }
 }
 pub(super) fn resolve_attributes(ctx: &mut Context<'_>) {
     for top in ctx.ast.iter_tops() {
@@ -113,7 +167,10 @@ fn resolve_type_exp_block_attributes<'db>(
 
             for (field_idx, _field) in ast_typexpr.iter_fields() {
                 ctx.assert_all_attributes_processed((type_id, field_idx).into());
-                if let Some(attrs) = to_string_attribute::visit(ctx, false) {
+                let mut attrs = to_string_attribute::visit(ctx, false);
+                stream::visit(ctx, &mut attrs, false);
+                default_value::visit(ctx, &mut attrs);
+                if let Some(attrs) = attrs {
                     class_attributes.field_serilizers.insert(field_idx, attrs);
                 }
                 ctx.validate_visited_attributes();
@@ -122,6 +179,7 @@ fn resolve_type_exp_block_attributes<'db>(
             // Now validate the class attributes.
             ctx.assert_all_attributes_processed(type_id.into());
             class_attributes.serilizer = to_string_attribute::visit(ctx, true);
+            stream::visit(ctx, &mut class_attributes.serilizer, true);
             ctx.validate_visited_attributes();
 
             ctx.types.class_attributes.insert(type_id, class_attributes);
@@ -37,7 +37,10 @@ pub(super) fn resolve_names(ctx: &mut Context<'_>) {
     let mut names = Names::default();
 
     for (top_id, top) in ctx.ast.iter_tops() {
-        assert_is_not_a_reserved_scalar_type(top.identifier(), ctx);
+        // Aliases may take these names, e.g. `type Json = ...`.
+        if !matches!(top, ast::Top::TypeAlias(_)) {
+            assert_is_not_a_reserved_scalar_type(top.identifier(), ctx);
+        }
 
         let namespace = match (top_id, top) {
             (_, ast::Top::Enum(ast_enum)) => {
@@ -100,6 +103,15 @@ pub(super) fn resolve_names(ctx: &mut Context<'_>) {
                 unreachable!("Encountered impossible template_string declaration during parsing")
             }
 
+            (ast::TopId::TypeAlias(_), ast::Top::TypeAlias(type_alias)) => {
+                validate_type_alias_name(type_alias, ctx.diagnostics);
+
+                Some(either::Left(&mut names.tops))
+            }
+            (_, ast::Top::TypeAlias(_)) => {
+                unreachable!("Encountered impossible type alias declaration during parsing")
+            }
+
             (ast::TopId::Function(_function_id), ast::Top::Function(ast_function)) => {
                 validate_function_name(ast_function, ctx.diagnostics);
                 validate_attribute_identifiers(ast_function, ctx);
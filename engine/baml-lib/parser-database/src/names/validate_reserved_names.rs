@@ -6,6 +6,11 @@ use crate::{
 };
 
 pub(crate) fn validate_attribute_name(ast_attr: &ast::Attribute, diagnostics: &mut Diagnostics) {
+    // Dotted attribute names like `@stream.done` are namespaces, not imports.
+    // Unknown ones are reported when the attributes are resolved.
+    if let ast::Identifier::Ref(..) = ast_attr.identifier() {
+        return;
+    }
     validate_name("attribute", ast_attr.identifier(), diagnostics, false);
 }
 
@@ -68,6 +73,13 @@ pub(crate) fn validate_template_string_name(
     );
 }
 
+pub(crate) fn validate_type_alias_name(
+    ast_type_alias: &ast::TypeAlias,
+    diagnostics: &mut Diagnostics,
+) {
+    validate_name("type alias", ast_type_alias.identifier(), diagnostics, true);
+}
+
 pub(crate) fn validate_function_name(
     ast_func: &ast::ValueExprBlock,
     diagnostics: &mut Diagnostics,
@@ -142,4 +154,6 @@ fn validate_name(
 
 // The source of the following list is from prisma-client-js. Any edit should be done in both places.
 // https://github.com/prisma/prisma/blob/master/src/packages/client/src/generation/generateClient.ts#L443
-const RESERVED_NAMES: &[&str] = &["BamlClient"];
+// `StreamState` wraps the values of `@stream.with_state` fields in the
+// generated clients.
+const RESERVED_NAMES: &[&str] = &["BamlClient", baml_types::STREAM_STATE_CLASS];
@@ -0,0 +1,117 @@
+//! Cycles in dependency graphs, found with Tarjan's strongly connected
+//! components algorithm.
+
+use std::{
+    cmp,
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+/// The state of Tarjan's algorithm while it walks a graph.
+pub struct Tarjan<'g, V> {
+    graph: &'g HashMap<V, Vec<V>>,
+    next_index: usize,
+    stack: Vec<V>,
+    on_stack: HashSet<V>,
+    // The discovery index of each visited vertex and the lowest index it reaches.
+    indices: HashMap<V, (usize, usize)>,
+    components: Vec<Vec<V>>,
+}
+
+impl<'g, V: Copy + Eq + Hash + Ord> Tarjan<'g, V> {
+    /// The strongly connected components of `graph` that form a cycle: more
+    /// than one vertex, or a single vertex that depends on itself. Each
+    /// component is sorted, and the result doesn't depend on the order of the
+    /// graph's keys.
+    pub fn components(graph: &'g HashMap<V, Vec<V>>) -> Vec<Vec<V>> {
+        let mut tarjan = Tarjan {
+            graph,
+            next_index: 0,
+            stack: Vec::new(),
+            on_stack: HashSet::new(),
+            indices: HashMap::new(),
+            components: Vec::new(),
+        };
+
+        let mut vertices = graph.keys().copied().collect::<Vec<_>>();
+        vertices.sort();
+        for v in vertices {
+            if !tarjan.indices.contains_key(&v) {
+                tarjan.strong_connect(v);
+            }
+        }
+
+        tarjan.components.sort();
+        tarjan.components
+    }
+
+    fn strong_connect(&mut self, v: V) {
+        self.indices.insert(v, (self.next_index, self.next_index));
+        self.next_index += 1;
+        self.stack.push(v);
+        self.on_stack.insert(v);
+
+        let graph = self.graph;
+        for &w in graph.get(&v).into_iter().flatten() {
+            let reached = match self.indices.get(&w) {
+                None => {
+                    self.strong_connect(w);
+                    self.indices[&w].1
+                }
+                Some(&(index, _)) if self.on_stack.contains(&w) => index,
+                Some(_) => continue,
+            };
+            if let Some((_, low)) = self.indices.get_mut(&v) {
+                *low = cmp::min(*low, reached);
+            }
+        }
+
+        let (index, low) = self.indices[&v];
+        if index != low {
+            return;
+        }
+
+        let mut component = Vec::new();
+        while let Some(w) = self.stack.pop() {
+            self.on_stack.remove(&w);
+            component.push(w);
+            if w == v {
+                break;
+            }
+        }
+
+        let is_cycle = component.len() > 1 || graph.get(&v).map_or(false, |deps| deps.contains(&v));
+        if is_cycle {
+            component.sort();
+            self.components.push(component);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(edges: &[(u32, &[u32])]) -> HashMap<u32, Vec<u32>> {
+        edges.iter().map(|(v, deps)| (*v, deps.to_vec())).collect()
+    }
+
+    #[test]
+    fn finds_only_cycles() {
+        let g = graph(&[
+            (1, &[2]),
+            (2, &[3]),
+            (3, &[1]),
+            (4, &[4]),
+            (5, &[1]),
+            (6, &[]),
+        ]);
+        assert_eq!(Tarjan::components(&g), vec![vec![1, 2, 3], vec![4]]);
+    }
+
+    #[test]
+    fn separates_cycles_that_touch() {
+        let g = graph(&[(1, &[2]), (2, &[1, 3]), (3, &[4]), (4, &[3])]);
+        assert_eq!(Tarjan::components(&g), vec![vec![1, 2], vec![3, 4]]);
+    }
+}
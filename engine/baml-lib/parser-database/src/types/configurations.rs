@@ -5,7 +5,10 @@ use std::collections::HashSet;
 
 use crate::{coerce, coerce_array, coerce_expression::coerce_map, context::Context};
 
-use super::{ContantDelayStrategy, ExponentialBackoffStrategy, RetryPolicy, RetryPolicyStrategy};
+use super::{
+    CircuitBreakerConfig, ContantDelayStrategy, ExpectedMatch, ExponentialBackoffStrategy,
+    RetryJitter, RetryPolicy, RetryPolicyStrategy, RETRY_POLICY_ERROR_CODES,
+};
 
 fn dedent(s: &str) -> String {
     // Find the shortest indentation in the string (that's not an empty line).
@@ -35,9 +38,16 @@ pub(crate) fn visit_retry_policy<'db>(
     ctx: &mut Context<'db>,
 ) {
     let mut max_reties = None;
+    let mut retry_on_parse_failure = false;
+    let mut circuit_breaker = None;
+    let mut retry_on: Option<Vec<String>> = None;
+    let mut error_strategies: Vec<((String, Span), RetryPolicyStrategy)> = Vec::new();
 
     let mut strategy = Some(RetryPolicyStrategy::ConstantDelay(
-        super::ContantDelayStrategy { delay_ms: 200 },
+        super::ContantDelayStrategy {
+            delay_ms: 200,
+            jitter: RetryJitter::None,
+        },
     ));
     let mut options = None;
 
@@ -56,6 +66,10 @@ pub(crate) fn visit_retry_policy<'db>(
                 Some(val) => max_reties = Some(val as u32),
                 None => {}
             },
+            ("retry_on_parse_failure", Some(val)) => match coerce::boolean(val, ctx.diagnostics) {
+                Some(val) => retry_on_parse_failure = val,
+                None => {}
+            },
             ("strategy", Some(val)) => {
                 match coerce_map(val, &coerce::string_with_span, ctx.diagnostics) {
                     Some(val) => match visit_strategy(f.span(), val, ctx.diagnostics) {
@@ -65,6 +79,52 @@ pub(crate) fn visit_retry_policy<'db>(
                     None => {}
                 }
             }
+            ("circuit_breaker", Some(val)) => {
+                match coerce_map(val, &coerce::string_with_span, ctx.diagnostics) {
+                    Some(val) => circuit_breaker = visit_circuit_breaker(val, ctx.diagnostics),
+                    None => {}
+                }
+            }
+            ("retry_on", Some(val)) => {
+                match coerce_array(val, &coerce::string_with_span, ctx.diagnostics) {
+                    Some(val) => {
+                        retry_on = Some(
+                            val.into_iter()
+                                .filter_map(|(code, span)| {
+                                    visit_error_code(code, span, ctx.diagnostics)
+                                })
+                                .collect(),
+                        )
+                    }
+                    None => {}
+                }
+            }
+            ("error_strategies", Some(val)) => {
+                match coerce_map(val, &coerce::string_with_span, ctx.diagnostics) {
+                    Some(val) => val.into_iter().for_each(|((code, span), expr)| {
+                        let Some(code) = visit_error_code(code, span, ctx.diagnostics) else {
+                            return;
+                        };
+                        if error_strategies.iter().any(|((c, _), _)| *c == code) {
+                            ctx.push_error(DatamodelError::new_validation_error(
+                                &format!("Duplicate strategy for error code `{}`", code),
+                                span.clone(),
+                            ));
+                            return;
+                        }
+                        match coerce_map(expr, &coerce::string_with_span, ctx.diagnostics) {
+                            Some(val) => match visit_strategy(span, val, ctx.diagnostics) {
+                                Some(strategy) => {
+                                    error_strategies.push(((code, span.clone()), strategy))
+                                }
+                                None => {}
+                            },
+                            None => {}
+                        }
+                    }),
+                    None => {}
+                }
+            }
             ("options", Some(val)) => {
                 match coerce_map(val, &coerce::string_with_span, ctx.diagnostics) {
                     Some(val) => {
@@ -80,9 +140,34 @@ pub(crate) fn visit_retry_policy<'db>(
             (name, Some(_)) => ctx.push_error(DatamodelError::new_property_not_known_error(
                 name,
                 f.identifier().span().clone(),
-                ["max_retries", "strategy", "options"].to_vec(),
+                [
+                    "max_retries",
+                    "strategy",
+                    "retry_on_parse_failure",
+                    "circuit_breaker",
+                    "retry_on",
+                    "error_strategies",
+                    "options",
+                ]
+                .to_vec(),
             )),
         });
+
+    // A strategy for an error code that is never retried would never be used.
+    if let Some(retry_on) = &retry_on {
+        for ((code, span), _) in &error_strategies {
+            if !retry_on.contains(code) {
+                ctx.push_error(DatamodelError::new_validation_error(
+                    &format!(
+                        "Error code `{}` has a strategy but is not listed in `retry_on`",
+                        code
+                    ),
+                    span.clone(),
+                ));
+            }
+        }
+    }
+
     match (max_reties, strategy) {
         (Some(max_retries), Some(strategy)) => {
             ctx.types.retry_policies.insert(
@@ -90,6 +175,13 @@ pub(crate) fn visit_retry_policy<'db>(
                 RetryPolicy {
                     max_retries,
                     strategy,
+                    retry_on_parse_failure,
+                    circuit_breaker,
+                    retry_on,
+                    error_strategies: error_strategies
+                        .into_iter()
+                        .map(|((code, _), strategy)| (code, strategy))
+                        .collect(),
                     options,
                 },
             );
@@ -117,6 +209,7 @@ fn visit_strategy(
     let mut delay_ms = None;
     let mut max_delay_ms = None;
     let mut multiplier = None;
+    let mut jitter = None;
 
     val.iter()
         .for_each(|(name_and_span, val)| match name_and_span.0 {
@@ -136,6 +229,21 @@ fn visit_strategy(
                 Some(_val) => multiplier = Some((_val, val.span())),
                 None => {}
             },
+            "jitter" => match coerce::string_with_span(val, diagnostics) {
+                Some(("none", span)) => jitter = Some((RetryJitter::None, span)),
+                Some(("full", span)) => jitter = Some((RetryJitter::Full, span)),
+                Some(("decorrelated", span)) => jitter = Some((RetryJitter::Decorrelated, span)),
+                Some((name, span)) => diagnostics.push_error(
+                    internal_baml_diagnostics::DatamodelError::new_validation_error(
+                        &format!(
+                            "Unknown jitter: {}. Options are `none`, `full` or `decorrelated`",
+                            name
+                        ),
+                        span.clone(),
+                    ),
+                ),
+                None => {}
+            },
             _ => {}
         });
 
@@ -161,8 +269,18 @@ fn visit_strategy(
                   ),
                   None => {}
               }
+            match jitter {
+                Some((RetryJitter::Decorrelated, span)) => diagnostics.push_error(
+                    internal_baml_diagnostics::DatamodelError::new_validation_error(
+                        "The `decorrelated` jitter is not supported for the `constant_delay` strategy",
+                        span.clone(),
+                    ),
+                ),
+                _ => {}
+            }
             Some(RetryPolicyStrategy::ConstantDelay(ContantDelayStrategy {
                 delay_ms: delay_ms.unwrap_or(200) as u32,
+                jitter: jitter.map(|(j, _)| j).unwrap_or_default(),
             }))
         }
         Some(("exponential_backoff", _)) => Some(RetryPolicyStrategy::ExponentialBackoff(
@@ -170,6 +288,7 @@ fn visit_strategy(
                 delay_ms: delay_ms.unwrap_or(200) as u32,
                 multiplier: multiplier.map(|(v, _)| v as f32).unwrap_or(1.5),
                 max_delay_ms: max_delay_ms.map(|(v, _)| v as u32).unwrap_or(10000),
+                jitter: jitter.map(|(j, _)| j).unwrap_or_default(),
             },
         )),
         Some((name, span)) => {
@@ -194,6 +313,67 @@ fn visit_strategy(
     }
 }
 
+fn visit_error_code(
+    code: &str,
+    span: &Span,
+    diagnostics: &mut internal_baml_diagnostics::Diagnostics,
+) -> Option<String> {
+    if RETRY_POLICY_ERROR_CODES.contains(&code) {
+        return Some(code.to_string());
+    }
+    diagnostics.push_error(DatamodelError::new_validation_error(
+        &format!(
+            "Unknown error code: {}. Options are {}",
+            code,
+            RETRY_POLICY_ERROR_CODES
+                .iter()
+                .map(|c| format!("`{}`", c))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        span.clone(),
+    ));
+    None
+}
+
+fn visit_circuit_breaker(
+    val: Vec<((&str, &Span), &internal_baml_schema_ast::ast::Expression)>,
+    diagnostics: &mut internal_baml_diagnostics::Diagnostics,
+) -> Option<CircuitBreakerConfig> {
+    let mut failure_threshold = None;
+    let mut reset_timeout_ms = None;
+
+    val.iter()
+        .for_each(|(name_and_span, val)| match name_and_span.0 {
+            "failure_threshold" => match coerce::integer(val, diagnostics) {
+                Some(v) if v > 0 => failure_threshold = Some(v as u32),
+                Some(_) => diagnostics.push_error(DatamodelError::new_validation_error(
+                    "`failure_threshold` must be greater than 0",
+                    val.span().clone(),
+                )),
+                None => {}
+            },
+            "reset_timeout_ms" => match coerce::integer(val, diagnostics) {
+                Some(v) if v >= 0 => reset_timeout_ms = Some(v as u32),
+                Some(_) => diagnostics.push_error(DatamodelError::new_validation_error(
+                    "`reset_timeout_ms` cannot be negative",
+                    val.span().clone(),
+                )),
+                None => {}
+            },
+            name => diagnostics.push_error(DatamodelError::new_property_not_known_error(
+                name,
+                name_and_span.1.clone(),
+                ["failure_threshold", "reset_timeout_ms"].to_vec(),
+            )),
+        });
+
+    Some(CircuitBreakerConfig {
+        failure_threshold: failure_threshold.unwrap_or(5),
+        reset_timeout_ms: reset_timeout_ms.unwrap_or(30000),
+    })
+}
+
 pub(crate) fn visit_test_case<'db>(
     idx: ValExpId,
     config: &'db ValueExprBlock,
@@ -201,6 +381,8 @@ pub(crate) fn visit_test_case<'db>(
 ) {
     let mut functions = None;
     let mut args = None;
+    let mut expected = None;
+    let mut expected_match = ExpectedMatch::default();
 
     config
         .iter_fields()
@@ -281,13 +463,38 @@ pub(crate) fn visit_test_case<'db>(
                     None => {}
                 }
             }
+            ("expected", Some(val)) => expected = Some((f.span().clone(), val.clone())),
+            ("expected_match", Some(val)) => match coerce::string_with_span(val, ctx.diagnostics) {
+                Some(("exact", _)) => expected_match = ExpectedMatch::Exact,
+                Some(("fuzzy", _)) => expected_match = ExpectedMatch::Fuzzy,
+                Some((other, span)) => ctx.push_error(DatamodelError::new_validation_error(
+                    &format!("Unknown `expected_match` value `{other}`. Use `exact` or `fuzzy`."),
+                    span.clone(),
+                )),
+                None => {}
+            },
             (name, Some(_)) => ctx.push_error(DatamodelError::new_property_not_known_error(
                 name,
                 f.identifier().span().clone(),
-                ["functions", "args"].to_vec(),
+                ["functions", "args", "expected", "expected_match"].to_vec(),
             )),
         });
 
+    let mut constraints = Vec::new();
+    for attribute in &config.attributes {
+        match attribute.constraint() {
+            Some(Ok(constraint)) => constraints.push(constraint),
+            Some(Err(message)) => ctx.push_error(DatamodelError::new_validation_error(
+                &message,
+                attribute.span.clone(),
+            )),
+            None => ctx.push_error(DatamodelError::new_attribute_not_known_error(
+                &format!("@{}", attribute.name.name()),
+                attribute.span.clone(),
+            )),
+        }
+    }
+
     match (functions, args) {
         (None, _) => ctx.push_error(DatamodelError::new_validation_error(
             "Missing `functions` property",
@@ -304,6 +511,9 @@ pub(crate) fn visit_test_case<'db>(
                     functions,
                     args,
                     args_field_span: args_field_span.clone(),
+                    constraints,
+                    expected,
+                    expected_match,
                 },
             );
         }
@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+
+use internal_baml_schema_ast::ast::{
+    self, FieldId, TopId, TypeExpId, WithIdentifier, WithName, WithSpan,
+};
+
+use crate::{context::Context, DatamodelError, Tarjan};
+
+/// A class field, identified by the class whose body declares it.
+type FieldRef = (TypeExpId, FieldId);
+
+/// Resolves the classes named in `extends` and `...` spreads, and records the
+/// fields of every class in order: the inherited ones first, then the ones
+/// declared in its own body. A field declared in the body replaces an
+/// inherited field with the same name, keeping its position.
+pub(super) fn resolve_class_fields(ctx: &mut Context<'_>) {
+    let ast = ctx.ast;
+    let classes = ast
+        .iter_tops()
+        .filter_map(|(top_id, top)| match (top_id, top) {
+            (TopId::Class(id), ast::Top::Class(class)) => Some((id, class)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let class_names = classes
+        .iter()
+        .map(|(_, class)| class.name().to_string())
+        .collect::<Vec<_>>();
+
+    // The classes each class takes fields from, in order.
+    let mut sources: HashMap<TypeExpId, Vec<(TypeExpId, &ast::Identifier)>> = HashMap::new();
+    for (class_id, class) in &classes {
+        let mut resolved = Vec::new();
+        for (idx, source) in class.extends.iter().chain(class.spreads.iter()).enumerate() {
+            let top = ctx
+                .interner
+                .lookup(source.name())
+                .and_then(|name| ctx.names.tops.get(&name))
+                .copied();
+            match top {
+                Some(TopId::Class(source_id)) => {
+                    if idx == 0 && class.extends.is_some() {
+                        ctx.types.class_bases.insert(*class_id, source_id);
+                    }
+                    resolved.push((source_id, source));
+                }
+                Some(_) => ctx.push_error(DatamodelError::new_validation_error(
+                    &format!(
+                        "`{}` is not a class. Only the fields of a class can be inherited.",
+                        source.name()
+                    ),
+                    source.span().clone(),
+                )),
+                None => ctx.push_error(DatamodelError::new_type_not_found_error(
+                    source.name(),
+                    class_names
+                        .iter()
+                        .filter(|name| *name != class.name())
+                        .cloned()
+                        .collect(),
+                    source.span().clone(),
+                )),
+            }
+        }
+        sources.insert(*class_id, resolved);
+    }
+
+    let graph = sources
+        .iter()
+        .map(|(id, sources)| {
+            let mut edges = sources
+                .iter()
+                .map(|(source, _)| *source)
+                .collect::<Vec<_>>();
+            edges.sort();
+            (*id, edges)
+        })
+        .collect::<HashMap<_, _>>();
+    for cycle in Tarjan::components(&graph) {
+        let names = cycle
+            .iter()
+            .map(|id| ast[*id].name())
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        for id in cycle {
+            ctx.push_error(DatamodelError::new_validation_error(
+                &format!("These classes inherit from each other in a cycle: {names}"),
+                ast[id].identifier().span().clone(),
+            ));
+            // Leave only the class's own fields, so flattening terminates.
+            sources.insert(id, Vec::new());
+        }
+    }
+
+    let mut class_fields = HashMap::new();
+    let mut errors = Vec::new();
+    for (class_id, _) in &classes {
+        flatten(ast, *class_id, &sources, &mut class_fields, &mut errors);
+    }
+    for error in errors {
+        ctx.push_error(error);
+    }
+    ctx.types.class_fields = class_fields;
+}
+
+fn flatten(
+    ast: &ast::SchemaAst,
+    class_id: TypeExpId,
+    sources: &HashMap<TypeExpId, Vec<(TypeExpId, &ast::Identifier)>>,
+    class_fields: &mut HashMap<TypeExpId, Vec<FieldRef>>,
+    errors: &mut Vec<DatamodelError>,
+) {
+    if class_fields.contains_key(&class_id) {
+        return;
+    }
+
+    let class = &ast[class_id];
+    let field_name = |(class_id, field_id): FieldRef| ast[class_id][field_id].name();
+
+    // Each inherited field, and the class it was inherited from.
+    let mut inherited: Vec<(FieldRef, TypeExpId)> = Vec::new();
+    for (source_id, source) in sources.get(&class_id).into_iter().flatten() {
+        flatten(ast, *source_id, sources, class_fields, errors);
+        for field in class_fields[source_id].iter().copied() {
+            let name = field_name(field);
+            match inherited.iter().find(|(f, _)| field_name(*f) == name) {
+                None => inherited.push((field, *source_id)),
+                // The same field, reached through two different classes.
+                Some((existing, _)) if *existing == field => {}
+                Some(_) if class.fields.iter().any(|f| f.name() == name) => {}
+                Some((_, first_source)) => errors.push(DatamodelError::new_validation_error(
+                    &format!(
+                        "Field `{}` is inherited from both `{}` and `{}`. Declare it in `{}` to choose which one to use.",
+                        name,
+                        ast[*first_source].name(),
+                        source.name(),
+                        class.name()
+                    ),
+                    source.span().clone(),
+                )),
+            }
+        }
+    }
+
+    let mut fields = inherited.into_iter().map(|(f, _)| f).collect::<Vec<_>>();
+    for (field_id, field) in class.iter_fields() {
+        match fields.iter().position(|f| field_name(*f) == field.name()) {
+            Some(idx) => fields[idx] = (class_id, field_id),
+            None => fields.push((class_id, field_id)),
+        }
+    }
+    class_fields.insert(class_id, fields);
+}
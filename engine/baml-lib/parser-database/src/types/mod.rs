@@ -1,10 +1,11 @@
 use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 
-use crate::coerce;
 use crate::types::configurations::visit_test_case;
+use crate::{coerce, coerce_array};
 use crate::{context::Context, DatamodelError};
 
+use baml_types::Constraint;
 use indexmap::IndexMap;
 use internal_baml_diagnostics::Span;
 use internal_baml_prompt_parser::ast::{ChatBlock, PrinterBlock, Variable};
@@ -13,6 +14,7 @@ use internal_baml_schema_ast::ast::{
 };
 
 mod configurations;
+mod inheritance;
 mod prompt;
 mod types;
 
@@ -54,10 +56,17 @@ pub(super) fn resolve_types(ctx: &mut Context<'_>) {
                 visit_test_case(idx, config, ctx);
             }
             (_, ast::Top::TestCase(_)) => unreachable!("TestCase misconfigured"),
+            (ast::TopId::TypeAlias(idx), ast::Top::TypeAlias(alias)) => {
+                visit_type_alias(idx, alias, ctx);
+            }
+            (_, ast::Top::TypeAlias(_)) => unreachable!("TypeAlias misconfigured"),
 
             _ => {}
         }
     }
+
+    inheritance::resolve_class_fields(ctx);
+    resolve_type_alias_dependencies(ctx);
 }
 #[derive(Debug, Clone)]
 /// Variables used inside of raw strings.
@@ -138,6 +147,22 @@ pub struct TestCase {
     // The span is the span of the argument (the expression has its own span)
     pub args: IndexMap<String, (Span, Expression)>,
     pub args_field_span: Span,
+    /// The `@@assert`s and `@@check`s to run over the output.
+    pub constraints: Vec<Constraint>,
+    /// The output the function should produce, if the test names one.
+    pub expected: Option<(Span, Expression)>,
+    pub expected_match: ExpectedMatch,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+/// How the output of a test is compared with its `expected` value.
+pub enum ExpectedMatch {
+    /// The output must equal the expected value.
+    #[default]
+    Exact,
+    /// Strings may differ in case and whitespace, numbers may differ by
+    /// rounding, and fields missing from the expected value are ignored.
+    Fuzzy,
 }
 
 #[derive(Debug, Clone)]
@@ -164,6 +189,19 @@ impl PrinterType {
     }
 }
 
+/// The error codes a retry policy can refer to in `retry_on` and
+/// `error_strategies`.
+pub const RETRY_POLICY_ERROR_CODES: [&str; 8] = [
+    "InvalidAuthentication",
+    "NotSupported",
+    "RateLimited",
+    "ServerError",
+    "ServiceUnavailable",
+    "Timeout",
+    "UnsupportedResponse",
+    "Other",
+];
+
 /// How to retry a request.
 #[derive(Debug, Clone)]
 pub struct RetryPolicy {
@@ -171,6 +209,15 @@ pub struct RetryPolicy {
     pub max_retries: u32,
     /// The strategy to use.
     pub strategy: RetryPolicyStrategy,
+    /// Whether a response that fails to parse (or fails an `@assert`) should
+    /// be retried, instead of failing the call.
+    pub retry_on_parse_failure: bool,
+    /// Circuit breaker to put in front of the client, if any.
+    pub circuit_breaker: Option<CircuitBreakerConfig>,
+    /// The error codes that should be retried. `None` retries every error.
+    pub retry_on: Option<Vec<String>>,
+    /// Strategies that replace `strategy` for specific error codes.
+    pub error_strategies: Vec<(String, RetryPolicyStrategy)>,
     /// Any additional options.
     pub options: Option<Vec<((String, Span), Expression)>>,
 }
@@ -189,6 +236,8 @@ pub enum RetryPolicyStrategy {
 pub struct ContantDelayStrategy {
     /// The delay in milliseconds.
     pub delay_ms: u32,
+    /// Randomization applied to each delay.
+    pub jitter: RetryJitter,
 }
 
 #[derive(Debug, Clone, Copy, serde::Serialize)]
@@ -200,6 +249,30 @@ pub struct ExponentialBackoffStrategy {
     pub multiplier: f32,
     /// The maximum delay in milliseconds.
     pub max_delay_ms: u32,
+    /// Randomization applied to each delay.
+    pub jitter: RetryJitter,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+/// How to randomize retry delays, so that clients don't retry in lockstep.
+pub enum RetryJitter {
+    /// Use the delay as is.
+    #[default]
+    None,
+    /// Wait a random time between 0 and the delay.
+    Full,
+    /// Wait a random time between the initial delay and 3x the previous delay.
+    Decorrelated,
+}
+
+#[derive(Debug, Clone, Copy, serde::Serialize)]
+/// When to stop sending requests to a client that keeps failing.
+pub struct CircuitBreakerConfig {
+    /// Consecutive failures after which the circuit opens.
+    pub failure_threshold: u32,
+    /// How long the circuit stays open before letting a trial request through.
+    pub reset_timeout_ms: u32,
 }
 
 #[derive(Debug, Clone)]
@@ -207,6 +280,8 @@ pub struct FunctionType {
     pub dependencies: (HashSet<String>, HashSet<String>),
     pub prompt: Option<RawString>,
     pub client: Option<(String, Span)>,
+    /// Classes the model may call as tools, instead of answering in text.
+    pub tools: Vec<(String, Span)>,
 }
 
 #[derive(Debug, Clone)]
@@ -223,7 +298,18 @@ pub(super) struct Types {
     pub(super) enum_attributes: HashMap<ast::TypeExpId, EnumAttributes>,
     pub(super) class_attributes: HashMap<ast::TypeExpId, ClassAttributes>,
     pub(super) class_dependencies: HashMap<ast::TypeExpId, HashSet<String>>,
+    /// Fields of each class, including the ones it inherits through `extends`
+    /// and `...` spreads, identified by the class that declares them.
+    pub(super) class_fields: HashMap<ast::TypeExpId, Vec<(ast::TypeExpId, FieldId)>>,
+    /// The class each class `extends`, if any.
+    pub(super) class_bases: HashMap<ast::TypeExpId, ast::TypeExpId>,
     pub(super) enum_dependencies: HashMap<ast::TypeExpId, HashSet<String>>,
+    /// Names referenced by the target of each type alias.
+    pub(super) type_alias_dependencies: HashMap<ast::TypeAliasId, HashSet<String>>,
+    /// Groups of aliases that refer to each other, e.g.
+    /// `type Json = string | Json[]`. These stay named in the IR instead of
+    /// being expanded in place.
+    pub(super) structural_recursive_alias_cycles: Vec<Vec<ast::TypeAliasId>>,
 
     pub(super) function: HashMap<ast::ValExpId, FunctionType>,
 
@@ -281,6 +367,17 @@ fn visit_enum<'db>(
             ));
         });
 
+    for source in enm.extends.iter().chain(enm.spreads.iter()) {
+        ctx.push_error(DatamodelError::new_validation_error(
+            &format!(
+                "Enum `{}` can't inherit from `{}`. Only classes can be extended or spread.",
+                enm.name(),
+                source.name()
+            ),
+            source.span().clone(),
+        ));
+    }
+
     let input_deps = enm.input().map(|f| f.flat_idns()).unwrap_or_default();
     ctx.types.enum_dependencies.insert(
         enm_id,
@@ -316,6 +413,14 @@ fn visit_class<'db>(
         .flat_map(|(_, f)| f.expr.iter().flat_map(|e| e.flat_idns()))
         .map(|id| id.name().to_string())
         .collect::<HashSet<_>>();
+    // Inherited fields are resolved later, but their classes are dependencies.
+    used_types.extend(
+        class
+            .extends
+            .iter()
+            .chain(class.spreads.iter())
+            .map(|id| id.name().to_string()),
+    );
     let input_deps = class.input().map(|f| f.flat_idns()).unwrap_or_default();
 
     ctx.types.class_dependencies.insert(class_id, {
@@ -324,6 +429,133 @@ fn visit_class<'db>(
     });
 }
 
+fn visit_type_alias<'db>(
+    alias_id: ast::TypeAliasId,
+    alias: &'db ast::TypeAlias,
+    ctx: &mut Context<'db>,
+) {
+    // Only constraints make sense on an alias, they apply wherever it's used.
+    for attr in alias.value().attributes() {
+        if !matches!(attr.name.name(), "assert" | "check" | "range" | "length") {
+            ctx.push_error(DatamodelError::new_validation_error(
+                &format!(
+                    "Type alias `{}` can't have the attribute `@{}`. Only `@assert`, `@check`, `@range` and `@length` are allowed.",
+                    alias.name(),
+                    attr.name.name()
+                ),
+                attr.span.clone(),
+            ));
+        }
+    }
+
+    ctx.types.type_alias_dependencies.insert(
+        alias_id,
+        alias
+            .value()
+            .flat_idns()
+            .iter()
+            .map(|id| id.name().to_string())
+            .collect(),
+    );
+}
+
+/// Replaces references to aliases in the dependencies of every other type with
+/// the classes and enums the aliases expand to, and records which aliases are
+/// recursive.
+fn resolve_type_alias_dependencies(ctx: &mut Context<'_>) {
+    if ctx.types.type_alias_dependencies.is_empty() {
+        return;
+    }
+
+    let alias_ids = ctx
+        .ast
+        .iter_tops()
+        .filter_map(|(top_id, top)| match (top_id, top) {
+            (ast::TopId::TypeAlias(idx), ast::Top::TypeAlias(alias)) => {
+                Some((alias.name().to_string(), idx))
+            }
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+
+    let graph = ctx
+        .types
+        .type_alias_dependencies
+        .iter()
+        .map(|(id, deps)| {
+            let mut edges = deps
+                .iter()
+                .filter_map(|dep| alias_ids.get(dep).copied())
+                .collect::<Vec<_>>();
+            edges.sort();
+            (*id, edges)
+        })
+        .collect::<HashMap<_, _>>();
+    ctx.types.structural_recursive_alias_cycles = crate::Tarjan::components(&graph);
+
+    let resolve = |deps: &HashSet<String>| -> HashSet<String> {
+        let mut resolved = HashSet::new();
+        let mut visited = HashSet::new();
+        let mut stack = deps.iter().collect::<Vec<_>>();
+        while let Some(dep) = stack.pop() {
+            match alias_ids.get(dep) {
+                Some(alias_id) => {
+                    if visited.insert(*alias_id) {
+                        stack.extend(ctx.types.type_alias_dependencies[alias_id].iter());
+                    }
+                }
+                None => {
+                    resolved.insert(dep.clone());
+                }
+            }
+        }
+        resolved
+    };
+
+    let class_dependencies = ctx
+        .types
+        .class_dependencies
+        .iter()
+        .map(|(id, deps)| (*id, resolve(deps)))
+        .collect();
+    let enum_dependencies = ctx
+        .types
+        .enum_dependencies
+        .iter()
+        .map(|(id, deps)| (*id, resolve(deps)))
+        .collect();
+    let function_dependencies = ctx
+        .types
+        .function
+        .iter()
+        .map(|(id, f)| {
+            (
+                *id,
+                (resolve(&f.dependencies.0), resolve(&f.dependencies.1)),
+            )
+        })
+        .collect::<Vec<_>>();
+    let template_string_dependencies = ctx
+        .types
+        .template_strings
+        .iter()
+        .map(|(id, t)| (*id, resolve(&t.type_dependencies)))
+        .collect::<Vec<_>>();
+
+    ctx.types.class_dependencies = class_dependencies;
+    ctx.types.enum_dependencies = enum_dependencies;
+    for (id, deps) in function_dependencies {
+        if let Some(f) = ctx.types.function.get_mut(&id) {
+            f.dependencies = deps;
+        }
+    }
+    for (id, deps) in template_string_dependencies {
+        if let Some(t) = ctx.types.template_strings.get_mut(&id) {
+            t.type_dependencies = deps;
+        }
+    }
+}
+
 fn visit_function<'db>(idx: ValExpId, function: &'db ast::ValueExprBlock, ctx: &mut Context<'db>) {
     let input_deps = function
         .input()
@@ -342,6 +574,7 @@ fn visit_function<'db>(idx: ValExpId, function: &'db ast::ValueExprBlock, ctx: &
 
     let mut prompt = None;
     let mut client = None;
+    let mut tools = Vec::new();
     function
         .iter_fields()
         .for_each(|(_idx, field)| match field.name() {
@@ -358,6 +591,23 @@ fn visit_function<'db>(idx: ValExpId, function: &'db ast::ValueExprBlock, ctx: &
                     None => None,
                 }
             }
+            "tools" => {
+                if let Some(val) = &field.expr {
+                    match coerce_array(val, &coerce::string_with_span, ctx.diagnostics) {
+                        Some(val) => val.into_iter().for_each(|(tool, span)| {
+                            if tools.iter().any(|(t, _)| t == tool) {
+                                ctx.push_error(DatamodelError::new_validation_error(
+                                    &format!("Duplicate tool `{}`", tool),
+                                    span.clone(),
+                                ));
+                            } else {
+                                tools.push((tool.to_string(), span.clone()));
+                            }
+                        }),
+                        None => {}
+                    }
+                }
+            }
             config => ctx.push_error(DatamodelError::new_validation_error(
                 &format!("Unknown field `{}` in function", config),
                 field.span().clone(),
@@ -372,6 +622,7 @@ fn visit_function<'db>(idx: ValExpId, function: &'db ast::ValueExprBlock, ctx: &
                     dependencies: (input_deps.clone(), output_deps),
                     prompt: Some(prompt.clone()),
                     client: Some(client),
+                    tools,
                 },
             );
 
@@ -0,0 +1,40 @@
+use internal_baml_schema_ast::ast::{self, WithIdentifier, WithSpan};
+
+use super::Walker;
+
+/// A `type` alias declaration in the schema.
+pub type TypeAliasWalker<'db> = Walker<'db, ast::TypeAliasId>;
+
+impl<'db> TypeAliasWalker<'db> {
+    /// The AST node.
+    pub fn ast_type_alias(self) -> &'db ast::TypeAlias {
+        &self.db.ast[self.id]
+    }
+
+    /// The type the alias stands for.
+    pub fn target(self) -> &'db ast::FieldType {
+        self.ast_type_alias().value()
+    }
+
+    /// Whether the alias refers back to itself, directly or through other
+    /// aliases. These can't be expanded in place like other aliases.
+    pub fn is_recursive(self) -> bool {
+        self.db
+            .types
+            .structural_recursive_alias_cycles
+            .iter()
+            .any(|cycle| cycle.contains(&self.id))
+    }
+}
+
+impl<'db> WithIdentifier for TypeAliasWalker<'db> {
+    fn identifier(&self) -> &ast::Identifier {
+        self.ast_type_alias().identifier()
+    }
+}
+
+impl<'db> WithSpan for TypeAliasWalker<'db> {
+    fn span(&self) -> &internal_baml_diagnostics::Span {
+        self.ast_type_alias().span()
+    }
+}
@@ -22,8 +22,21 @@ impl<'db> ClassWalker<'db> {
         &self.db.ast[self.id]
     }
 
-    /// Iterate all the scalar fields in a given class in the order they were defined.
+    /// Iterate all the scalar fields in a given class in the order they were defined,
+    /// including the ones inherited through `extends` and `...` spreads.
     pub fn static_fields(self) -> impl ExactSizeIterator<Item = FieldWalker<'db>> {
+        match self.db.types.class_fields.get(&self.id) {
+            Some(fields) => fields
+                .iter()
+                .map(move |(class_id, field_id)| self.walk((*class_id, *field_id, false)))
+                .collect::<Vec<_>>()
+                .into_iter(),
+            None => self.declared_fields().collect::<Vec<_>>().into_iter(),
+        }
+    }
+
+    /// Iterate the fields written in the body of the class, without the inherited ones.
+    pub fn declared_fields(self) -> impl ExactSizeIterator<Item = FieldWalker<'db>> {
         self.ast_type_block()
             .iter_fields()
             .map(move |(field_id, _)| self.walk((self.id, field_id.into(), false)))
@@ -31,6 +44,21 @@ impl<'db> ClassWalker<'db> {
             .into_iter()
     }
 
+    /// The class named in `extends`, if any.
+    pub fn base_class(self) -> Option<ClassWalker<'db>> {
+        self.db
+            .types
+            .class_bases
+            .get(&self.id)
+            .map(|base| self.walk(*base))
+    }
+
+    /// Whether `field` comes unchanged from the class named in `extends`.
+    pub fn inherits_from_base(self, field: FieldWalker<'db>) -> bool {
+        self.base_class()
+            .map_or(false, |base| base.static_fields().any(|f| f.id == field.id))
+    }
+
     /// Iterate all the scalar fields in a given class in the order they were defined.
     pub fn dependencies(self) -> &'db HashSet<String> {
         &self.db.types.class_dependencies[&self.id]
@@ -6,6 +6,7 @@
 //! - Know about relations.
 //! - Do not know anything about connectors, they are generic.
 
+mod alias;
 mod r#class;
 mod client;
 mod configuration;
@@ -14,6 +15,7 @@ mod field;
 mod function;
 mod template_string;
 
+pub use alias::*;
 use baml_types::TypeValue;
 pub use client::*;
 pub use configuration::*;
@@ -89,6 +91,21 @@ impl<'db> crate::ParserDatabase {
         }
     }
 
+    /// Find a type alias by name.
+    pub fn find_type_alias(&'db self, idn: &Identifier) -> Option<TypeAliasWalker<'db>> {
+        match idn {
+            Identifier::Local(local, _) => self.find_type_alias_by_str(local),
+            _ => None,
+        }
+    }
+
+    /// Find a type alias by name.
+    pub fn find_type_alias_by_str(&'db self, name: &str) -> Option<TypeAliasWalker<'db>> {
+        self.find_top_by_str(name)
+            .and_then(|top_id| top_id.as_type_alias_id())
+            .map(|alias_id| self.walk(alias_id))
+    }
+
     /// Find a model by name.
     pub fn find_class(&'db self, idn: &Identifier) -> Option<ClassWalker<'db>> {
         self.find_type(idn).and_then(|either| match either {
@@ -138,6 +155,7 @@ impl<'db> crate::ParserDatabase {
     pub fn valid_type_names(&'db self) -> Vec<String> {
         let mut names: Vec<String> = self.walk_classes().map(|c| c.name().to_string()).collect();
         names.extend(self.walk_enums().map(|e| e.name().to_string()));
+        names.extend(self.walk_type_aliases().map(|a| a.name().to_string()));
         // Add primitive types
         names.extend(
             vec!["string", "int", "float", "bool", "true", "false"]
@@ -188,6 +206,17 @@ impl<'db> crate::ParserDatabase {
             })
     }
 
+    /// Walk all type aliases in the schema.
+    pub fn walk_type_aliases(&self) -> impl Iterator<Item = TypeAliasWalker<'_>> {
+        self.ast()
+            .iter_tops()
+            .filter_map(|(top_id, _)| top_id.as_type_alias_id())
+            .map(move |top_id| Walker {
+                db: self,
+                id: top_id,
+            })
+    }
+
     /// Walk all template strings in the schema.
     pub fn walk_templates(&self) -> impl Iterator<Item = TemplateStringWalker<'_>> {
         self.ast()
@@ -250,7 +279,12 @@ impl<'db> crate::ParserDatabase {
         let r = match ft {
             FieldType::Symbol(arity, idn, ..) => {
                 let mut t = match self.find_type(idn) {
-                    None => Type::Undefined,
+                    None => match self.find_type_alias(idn) {
+                        // Recursive aliases would expand forever.
+                        Some(alias) if alias.is_recursive() => Type::Unknown,
+                        Some(alias) => self.to_jinja_type(alias.target()),
+                        None => Type::Undefined,
+                    },
                     Some(Either::Left(_)) => Type::ClassRef(idn.to_string()),
                     Some(Either::Right(_)) => Type::String,
                 };
@@ -301,6 +335,8 @@ impl<'db> crate::ParserDatabase {
                     TypeValue::Bool => Type::Bool,
                     TypeValue::Null => Type::None,
                     TypeValue::Media(_) => Type::Unknown,
+                    // Rendered in prompts as ISO 8601 strings.
+                    TypeValue::Date | TypeValue::DateTime | TypeValue::Duration => Type::String,
                 };
                 if arity.is_optional() || matches!(t, Type::None) {
                     t = Type::None | t;
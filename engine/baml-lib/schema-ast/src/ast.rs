@@ -14,12 +14,14 @@ mod newline_type;
 mod template_string;
 mod top;
 mod traits;
+mod type_alias;
 mod type_expression_block;
 mod value_expression_block;
 pub(crate) use self::comment::Comment;
+pub(crate) use self::expression::dedent;
 
 pub use argument::{ArgumentId, Argument, ArgumentsList};
-pub use attribute::{Attribute, AttributeContainer, AttributeId};
+pub use attribute::{Attribute, AttributeContainer, AttributeId, REFINEMENT_ATTRIBUTES};
 pub use config::ConfigBlockProperty;
 pub use expression::{Expression, RawString};
 pub use field::{Field, FieldArity, FieldType};
@@ -30,6 +32,7 @@ pub use newline_type::NewlineType;
 pub use template_string::TemplateString;
 pub use top::Top;
 pub use traits::{WithAttributes, WithDocumentation, WithIdentifier, WithName, WithSpan};
+pub use type_alias::TypeAlias;
 pub use type_expression_block::{FieldId, SubType, TypeExpressionBlock};
 pub use value_expression_block::{
     BlockArg, BlockArgs, ValueExprBlock, ValueExprBlockType,
@@ -121,6 +124,17 @@ impl std::ops::Index<TemplateStringId> for SchemaAst {
     }
 }
 
+/// An opaque identifier for a type alias in a schema AST.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TypeAliasId(u32);
+impl std::ops::Index<TypeAliasId> for SchemaAst {
+    type Output = TypeAlias;
+
+    fn index(&self, index: TypeAliasId) -> &Self::Output {
+        self.tops[index.0 as usize].as_type_alias().unwrap()
+    }
+}
+
 /// An identifier for a top-level item in a schema AST. Use the `schema[top_id]`
 /// syntax to resolve the id to an `ast::Top`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -147,6 +161,9 @@ pub enum TopId {
     TestCase(ValExpId),
 
     RetryPolicy(ValExpId),
+
+    // A type alias declaration
+    TypeAlias(TypeAliasId),
 }
 
 impl TopId {
@@ -201,6 +218,13 @@ impl TopId {
             _ => None,
         }
     }
+
+    pub fn as_type_alias_id(self) -> Option<TypeAliasId> {
+        match self {
+            TopId::TypeAlias(id) => Some(id),
+            _ => None,
+        }
+    }
 }
 
 impl std::ops::Index<TopId> for SchemaAst {
@@ -216,6 +240,7 @@ impl std::ops::Index<TopId> for SchemaAst {
             TopId::Generator(ValExpId(idx)) => idx,
             TopId::TestCase(ValExpId(idx)) => idx,
             TopId::RetryPolicy(ValExpId(idx)) => idx,
+            TopId::TypeAlias(TypeAliasId(idx)) => idx,
         };
 
         &self.tops[idx as usize]
@@ -232,5 +257,6 @@ fn top_idx_to_top_id(top_idx: usize, top: &Top) -> TopId {
         Top::Generator(_) => TopId::Generator(ValExpId(top_idx as u32)),
         Top::TestCase(_) => TopId::TestCase(ValExpId(top_idx as u32)),
         Top::RetryPolicy(_) => TopId::RetryPolicy(ValExpId(top_idx as u32)),
+        Top::TypeAlias(_) => TopId::TypeAlias(TypeAliasId(top_idx as u32)),
     }
 }
@@ -1,6 +1,12 @@
-use super::{ArgumentId, ArgumentsList, Identifier, Span, WithIdentifier, WithSpan};
+use super::{
+    ArgumentId, ArgumentsList, Expression, Identifier, Span, WithIdentifier, WithName, WithSpan,
+};
+use baml_types::{Constraint, ConstraintLevel, Refinement};
 use std::ops::Index;
 
+/// The attributes that compile to a [`Refinement`].
+pub const REFINEMENT_ATTRIBUTES: [&str; 2] = ["range", "length"];
+
 /// An attribute (following `@` or `@@``) on a model, model field, enum, enum value or composite
 /// type field.
 #[derive(Debug, Clone)]
@@ -33,6 +39,110 @@ impl Attribute {
         self.arguments[argument].span.clone()
     }
 
+    /// Reads a `@range(min, max)` or `@length(min, max)` attribute, where
+    /// either bound can be `null`. Returns `None` for any other attribute.
+    pub fn refinement(&self) -> Option<Result<Refinement, String>> {
+        let name = self.name.name();
+        if !REFINEMENT_ATTRIBUTES.contains(&name) {
+            return None;
+        }
+        let usage = format!(
+            "`@{name}` takes a minimum and a maximum, e.g. `@{name}(1, 10)`. Use `null` to leave either side open."
+        );
+
+        let bounds = match self.arguments.arguments.as_slice() {
+            [min, max] => [&min.value, &max.value],
+            _ => return Some(Err(usage)),
+        };
+        let mut parsed = [None, None];
+        for (bound, expr) in parsed.iter_mut().zip(bounds) {
+            *bound = match expr {
+                Expression::NumericValue(n, _) => Some(n.as_str()),
+                Expression::Identifier(Identifier::Local(s, _)) if s == "null" => None,
+                _ => return Some(Err(usage)),
+            };
+        }
+
+        let refinement = match name {
+            "range" => {
+                let [min, max] = parsed.map(|b| b.map(|n| n.parse::<f64>()));
+                match (min.transpose(), max.transpose()) {
+                    (Ok(min), Ok(max)) => Refinement::Range { min, max },
+                    _ => return Some(Err(usage)),
+                }
+            }
+            _ => {
+                let [min, max] = parsed.map(|b| b.map(|n| n.parse::<u64>()));
+                match (min.transpose(), max.transpose()) {
+                    (Ok(min), Ok(max)) => Refinement::Length { min, max },
+                    _ => {
+                        return Some(Err(
+                            "The bounds of `@length` must be whole numbers of at least 0."
+                                .to_string(),
+                        ))
+                    }
+                }
+            }
+        };
+
+        let (unbounded, out_of_order) = match &refinement {
+            Refinement::Range { min, max } => (
+                min.is_none() && max.is_none(),
+                matches!((min, max), (Some(min), Some(max)) if min > max),
+            ),
+            Refinement::Length { min, max } => (
+                min.is_none() && max.is_none(),
+                matches!((min, max), (Some(min), Some(max)) if min > max),
+            ),
+        };
+        if unbounded {
+            return Some(Err(format!("`@{name}` needs at least one bound.")));
+        }
+        if out_of_order {
+            return Some(Err(format!(
+                "The minimum of `@{name}` is greater than its maximum."
+            )));
+        }
+        Some(Ok(refinement))
+    }
+
+    /// Reads an `@assert(name, {{ expr }})` or `@check(name, {{ expr }})`
+    /// attribute, where only asserts may leave out the name. Returns `None`
+    /// for any other attribute.
+    pub fn constraint(&self) -> Option<Result<Constraint, String>> {
+        let name = self.name.name();
+        let level = match name {
+            "assert" => ConstraintLevel::Assert,
+            "check" => ConstraintLevel::Check,
+            _ => return None,
+        };
+        let usage = "A constraint must have one Jinja argument such as {{ expr }}, and optionally one String label".to_string();
+
+        let (label, expression) = match self.arguments.arguments.as_slice() {
+            [label, expression] => match (&label.value, &expression.value) {
+                (
+                    Expression::Identifier(Identifier::Local(label, _)),
+                    Expression::JinjaExpressionValue(expression, _),
+                ) => (Some(label.clone()), expression.clone()),
+                _ => return Some(Err(usage)),
+            },
+            [expression] => match &expression.value {
+                Expression::JinjaExpressionValue(_, _) if level == ConstraintLevel::Check => {
+                    return Some(Err("Check constraints must have a name.".to_string()))
+                }
+                Expression::JinjaExpressionValue(expression, _) => (None, expression.clone()),
+                _ => return Some(Err(usage)),
+            },
+            _ => return Some(Err(usage)),
+        };
+        Some(Ok(Constraint {
+            level,
+            expression,
+            label,
+            refinement: None,
+        }))
+    }
+
     pub fn assert_eq_up_to_span(&self, other: &Attribute) {
         assert_eq!(self.name.to_string(), other.name.to_string());
         assert_eq!(self.parenthesized, other.parenthesized);
@@ -1,6 +1,6 @@
 use super::{
-    traits::WithSpan, Identifier, Span, TemplateString, TypeExpressionBlock, ValueExprBlock,
-    WithIdentifier,
+    traits::WithSpan, Identifier, Span, TemplateString, TypeAlias, TypeExpressionBlock,
+    ValueExprBlock, WithIdentifier,
 };
 
 /// Enum for distinguishing between top-level entries
@@ -24,6 +24,9 @@ pub enum Top {
     TestCase(ValueExprBlock),
 
     RetryPolicy(ValueExprBlock),
+
+    // A type alias declaration
+    TypeAlias(TypeAlias),
 }
 
 impl Top {
@@ -39,6 +42,7 @@ impl Top {
             Top::Generator(_) => "generator",
             Top::TestCase(_) => "test_case",
             Top::RetryPolicy(_) => "retry_policy",
+            Top::TypeAlias(_) => "type_alias",
         }
     }
 
@@ -68,6 +72,13 @@ impl Top {
             _ => None,
         }
     }
+
+    pub fn as_type_alias(&self) -> Option<&TypeAlias> {
+        match self {
+            Top::TypeAlias(alias) => Some(alias),
+            _ => None,
+        }
+    }
 }
 
 impl WithIdentifier for Top {
@@ -83,6 +94,7 @@ impl WithIdentifier for Top {
             Top::Generator(x) => x.identifier(),
             Top::TestCase(x) => x.identifier(),
             Top::RetryPolicy(x) => x.identifier(),
+            Top::TypeAlias(x) => x.identifier(),
         }
     }
 }
@@ -98,6 +110,7 @@ impl WithSpan for Top {
             Top::Generator(gen) => gen.span(),
             Top::TestCase(test) => test.span(),
             Top::RetryPolicy(retry) => retry.span(),
+            Top::TypeAlias(alias) => alias.span(),
         }
     }
 }
@@ -56,6 +56,24 @@ pub struct TypeExpressionBlock {
     ///
     pub fields: Vec<Field<FieldType>>, // needs to support field as well
 
+    /// The class this class extends.
+    ///
+    /// ```ignore
+    /// class Bar extends Foo { ... }
+    ///                   ^^^
+    /// ```
+    pub extends: Option<Identifier>,
+
+    /// Classes whose fields are spread into this one.
+    ///
+    /// ```ignore
+    /// class Bar {
+    ///   ...Foo
+    ///      ^^^
+    /// }
+    /// ```
+    pub spreads: Vec<Identifier>,
+
     /// The attributes of this enum.
     ///
     /// ```ignore
@@ -0,0 +1,911 @@
+//! Prints BAML source in its canonical layout.
+//!
+//! The formatter works on the pest parse tree rather than the
+//! [`SchemaAst`](crate::ast::SchemaAst): the AST only keeps doc comments, and
+//! doesn't remember how values were written.
+
+use std::path::PathBuf;
+
+use internal_baml_diagnostics::{Diagnostics, SourceFile};
+use pest::Parser;
+
+use crate::{
+    ast::dedent,
+    parse_schema,
+    parser::{BAMLParser, Rule},
+};
+
+type Pair<'a> = pest::iterators::Pair<'a, Rule>;
+
+const INDENT: &str = "  ";
+/// Arrays and argument lists longer than this are split over several lines.
+const MAX_WIDTH: usize = 100;
+
+/// Formats a BAML file. A file with parse errors is left alone, and the errors
+/// returned.
+pub fn format_schema(root_path: &PathBuf, source: &SourceFile) -> Result<String, Diagnostics> {
+    let (_, mut diagnostics) = parse_schema(root_path, source)?;
+    diagnostics.to_result()?;
+
+    let schema = BAMLParser::parse(Rule::schema, source.as_str())
+        .ok()
+        .and_then(|mut pairs| pairs.next())
+        .expect("parse_schema already accepted this file");
+    Ok(Formatter {
+        src: source.as_str(),
+    }
+    .schema(schema))
+}
+
+/// Something the formatter doesn't know how to lay out, such as a comment
+/// between a function's signature and its body. The top-level item holding it
+/// is kept as it was written.
+struct Unformattable;
+
+type FmtResult<T> = Result<T, Unformattable>;
+
+/// One line, or one multi-line construct, inside braces or brackets.
+enum Item {
+    /// A class or enum field. Fields next to each other line up their types
+    /// and attributes.
+    Field {
+        name: String,
+        ty: String,
+        rest: String,
+    },
+    Line(String),
+    Comment(String),
+    /// A comment written on the same line as whatever came before it.
+    Trailing(String),
+    Blank,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Type,
+    Attribute,
+    /// A comment after something else on its line.
+    Comment,
+    /// A comment on a line of its own.
+    LineComment,
+}
+
+struct Token {
+    kind: Kind,
+    text: String,
+    space_before: bool,
+}
+
+/// The pieces of a type, with its attributes and comments, in order.
+#[derive(Default)]
+struct Tokens {
+    tokens: Vec<Token>,
+    glue: bool,
+}
+
+impl Tokens {
+    fn push(&mut self, kind: Kind, text: impl Into<String>) {
+        let space_before = !std::mem::take(&mut self.glue);
+        self.tokens.push(Token {
+            kind,
+            text: text.into(),
+            space_before,
+        });
+    }
+
+    /// Pushes a token with no space before it.
+    fn glued(&mut self, text: &str) {
+        self.glue = true;
+        self.push(Kind::Type, text);
+    }
+
+    /// Pushes a token with no space after it.
+    fn open(&mut self, text: &str) {
+        self.push(Kind::Type, text);
+        self.glue = true;
+    }
+}
+
+/// Lays tokens out on one line, breaking after comments. `indent` is the
+/// indentation of the line they start on.
+fn join(tokens: &[Token], indent: usize) -> String {
+    let mut out = String::new();
+    let mut break_line = false;
+    for token in tokens {
+        if !out.is_empty() && (break_line || token.kind == Kind::LineComment) {
+            out.push('\n');
+            out.push_str(&INDENT.repeat(indent + 1));
+        } else if !out.is_empty() && token.space_before {
+            out.push(' ');
+        }
+        out.push_str(&token.text);
+        break_line = matches!(token.kind, Kind::Comment | Kind::LineComment);
+    }
+    out
+}
+
+struct Formatter<'a> {
+    src: &'a str,
+}
+
+impl Formatter<'_> {
+    fn schema(&self, schema: Pair<'_>) -> String {
+        let mut out = String::new();
+        let mut blank = false;
+        let mut after_block = false;
+        let line = |out: &mut String, blank: &mut bool, text: &str| {
+            if std::mem::take(blank) && !out.is_empty() {
+                out.push('\n');
+            }
+            out.push_str(text);
+            out.push('\n');
+        };
+
+        for item in schema.into_inner() {
+            match item.as_rule() {
+                Rule::type_expression_block
+                | Rule::value_expression_block
+                | Rule::template_declaration
+                | Rule::type_alias => {
+                    let text = self
+                        .top_level(item.clone())
+                        .unwrap_or_else(|Unformattable| item.as_str().trim_end().to_string());
+                    blank |= after_block;
+                    line(&mut out, &mut blank, &text);
+                    after_block = true;
+                }
+                Rule::comment_block => {
+                    for (start, comment) in self.comments(item) {
+                        if !self.starts_line(start) && out.ends_with('\n') {
+                            out.pop();
+                            out.push(' ');
+                            out.push_str(&comment);
+                            out.push('\n');
+                        } else {
+                            line(&mut out, &mut blank, &comment);
+                            after_block = false;
+                        }
+                    }
+                }
+                Rule::empty_lines => blank |= self.has_blank_line(&item),
+                Rule::EOI => {}
+                _ => {
+                    line(&mut out, &mut blank, item.as_str().trim_end());
+                    after_block = false;
+                }
+            }
+        }
+        out
+    }
+
+    fn top_level(&self, item: Pair<'_>) -> FmtResult<String> {
+        match item.as_rule() {
+            Rule::type_expression_block | Rule::value_expression_block => self.block(item),
+            Rule::template_declaration => self.template_string(item),
+            Rule::type_alias => self.type_alias(item),
+            _ => Err(Unformattable),
+        }
+    }
+
+    /// Classes, enums, functions, tests, clients, generators and retry
+    /// policies.
+    fn block(&self, block: Pair<'_>) -> FmtResult<String> {
+        let mut head = Vec::new();
+        let mut arguments = None;
+        let mut tail = String::new();
+        let mut items = Vec::new();
+
+        for part in block.into_inner() {
+            match part.as_rule() {
+                Rule::value_expression_keyword | Rule::identifier => {
+                    head.push(part.as_str().trim_end())
+                }
+                Rule::named_argument_list => arguments = Some(self.named_arguments(part)?),
+                Rule::extends_clause => {
+                    for word in part.into_inner() {
+                        tail.push(' ');
+                        tail.push_str(word.as_str());
+                    }
+                }
+                Rule::ARROW => {
+                    self.no_comments(&part)?;
+                    tail.push_str(" ->");
+                }
+                Rule::field_type_chain => {
+                    tail.push(' ');
+                    tail.push_str(&self.type_string(part, 0)?);
+                }
+                Rule::SPACER_TEXT => self.no_comments(&part)?,
+                Rule::type_expression_contents | Rule::value_expression_contents => {
+                    items = self.block_items(part, 0)?;
+                }
+                Rule::BLOCK_OPEN | Rule::BLOCK_CLOSE => {}
+                _ => return Err(Unformattable),
+            }
+        }
+
+        let head = head.join(" ");
+        let header = match arguments {
+            None => format!("{head}{tail} {{"),
+            Some(arguments) => {
+                let header = format!("{head}({}){tail} {{", arguments.join(", "));
+                if header.chars().count() <= MAX_WIDTH || arguments.is_empty() {
+                    header
+                } else {
+                    let arguments = arguments
+                        .iter()
+                        .map(|argument| format!("{INDENT}{argument},\n"))
+                        .collect::<String>();
+                    format!("{head}(\n{arguments}){tail} {{")
+                }
+            }
+        };
+        Ok(render_items(header, items, 0, "}"))
+    }
+
+    fn template_string(&self, template: Pair<'_>) -> FmtResult<String> {
+        let mut out = String::new();
+        for part in template.into_inner() {
+            match part.as_rule() {
+                Rule::TEMPLATE_KEYWORD => out.push_str(part.as_str()),
+                Rule::identifier => {
+                    out.push(' ');
+                    out.push_str(part.as_str());
+                }
+                Rule::assignment => out.push_str(" ="),
+                Rule::named_argument_list => {
+                    out.push('(');
+                    out.push_str(&self.named_arguments(part)?.join(", "));
+                    out.push(')');
+                }
+                Rule::raw_string_literal => {
+                    out.push(' ');
+                    out.push_str(&self.raw_string(part, 0)?);
+                }
+                _ => return Err(Unformattable),
+            }
+        }
+        Ok(out)
+    }
+
+    fn type_alias(&self, alias: Pair<'_>) -> FmtResult<String> {
+        let mut out = String::new();
+        for part in alias.into_inner() {
+            match part.as_rule() {
+                Rule::TYPE_KEYWORD => out.push_str(part.as_str()),
+                Rule::identifier => {
+                    out.push(' ');
+                    out.push_str(part.as_str());
+                }
+                Rule::assignment => out.push_str(" ="),
+                Rule::field_type_chain => {
+                    out.push(' ');
+                    out.push_str(&self.type_string(part, 0)?);
+                }
+                _ => return Err(Unformattable),
+            }
+        }
+        Ok(out)
+    }
+
+    fn named_arguments(&self, list: Pair<'_>) -> FmtResult<Vec<String>> {
+        let mut arguments = Vec::new();
+        for part in list.into_inner() {
+            match part.as_rule() {
+                Rule::openParan | Rule::closeParan => {}
+                Rule::SPACER_TEXT => self.no_comments(&part)?,
+                Rule::named_argument => {
+                    let mut argument = String::new();
+                    for piece in part.into_inner() {
+                        match piece.as_rule() {
+                            Rule::identifier => argument.push_str(piece.as_str().trim_end()),
+                            Rule::colon => argument.push(':'),
+                            Rule::field_type_chain => {
+                                argument.push_str(": ");
+                                argument.push_str(&self.type_string(piece, 1)?);
+                            }
+                            _ => return Err(Unformattable),
+                        }
+                    }
+                    arguments.push(argument);
+                }
+                _ => return Err(Unformattable),
+            }
+        }
+        Ok(arguments)
+    }
+
+    /// The contents of a block whose braces sit at `indent`.
+    fn block_items(&self, contents: Pair<'_>, indent: usize) -> FmtResult<Vec<Item>> {
+        let mut items = Vec::new();
+        for part in contents.into_inner() {
+            match part.as_rule() {
+                Rule::type_expression => self.field(part, indent + 1, &mut items)?,
+                Rule::value_expression => self.property(part, indent + 1, &mut items)?,
+                Rule::type_spread => {
+                    for piece in part.into_inner() {
+                        match piece.as_rule() {
+                            Rule::identifier => {
+                                items.push(Item::Line(format!("...{}", piece.as_str().trim_end())))
+                            }
+                            Rule::trailing_comment => self.push_comments(piece, &mut items),
+                            _ => return Err(Unformattable),
+                        }
+                    }
+                }
+                Rule::block_attribute => items.push(Item::Line(self.attribute(part, indent + 1)?)),
+                Rule::comment_block => self.push_comments(part, &mut items),
+                Rule::empty_lines => {
+                    if self.has_blank_line(&part) {
+                        items.push(Item::Blank);
+                    }
+                }
+                _ => return Err(Unformattable),
+            }
+        }
+        Ok(items)
+    }
+
+    /// A class field or enum value, on a line at `indent`.
+    fn field(&self, field: Pair<'_>, indent: usize, items: &mut Vec<Item>) -> FmtResult<()> {
+        let mut parts = field.into_inner();
+        let name = parts
+            .next()
+            .ok_or(Unformattable)?
+            .as_str()
+            .trim_end()
+            .to_string();
+        let mut tokens = Tokens::default();
+        for part in parts {
+            self.type_tokens(part, &mut tokens, indent)?;
+        }
+
+        // Comments on the lines after the field are really about whatever
+        // comes next.
+        let mut after = Vec::new();
+        while tokens
+            .tokens
+            .last()
+            .is_some_and(|token| token.kind == Kind::LineComment)
+        {
+            after.extend(tokens.tokens.pop().map(|token| Item::Comment(token.text)));
+        }
+
+        let split = tokens
+            .tokens
+            .iter()
+            .rposition(|token| token.kind == Kind::Type)
+            .map_or(0, |i| i + 1);
+        items.push(Item::Field {
+            name,
+            ty: join(&tokens.tokens[..split], indent),
+            rest: join(&tokens.tokens[split..], indent),
+        });
+        items.extend(after.into_iter().rev());
+        Ok(())
+    }
+
+    /// A `key value` line in a function, test, client, generator or retry
+    /// policy, at `indent`.
+    fn property(&self, property: Pair<'_>, indent: usize, items: &mut Vec<Item>) -> FmtResult<()> {
+        let mut line = String::new();
+        let mut after = Vec::new();
+        for part in property.into_inner() {
+            match part.as_rule() {
+                Rule::identifier => line.push_str(part.as_str().trim_end()),
+                Rule::expression => {
+                    line.push(' ');
+                    line.push_str(&self.expression(part, indent)?);
+                }
+                Rule::field_attribute => {
+                    line.push(' ');
+                    line.push_str(&self.attribute(part, indent)?);
+                }
+                Rule::trailing_comment => self.push_comments(part, &mut after),
+                _ => return Err(Unformattable),
+            }
+        }
+        items.push(Item::Line(line));
+        items.extend(after);
+        Ok(())
+    }
+
+    fn type_string(&self, chain: Pair<'_>, indent: usize) -> FmtResult<String> {
+        let mut tokens = Tokens::default();
+        self.type_tokens(chain, &mut tokens, indent)?;
+        Ok(join(&tokens.tokens, indent))
+    }
+
+    fn type_tokens(&self, pair: Pair<'_>, tokens: &mut Tokens, indent: usize) -> FmtResult<()> {
+        match pair.as_rule() {
+            Rule::field_type_chain
+            | Rule::field_type_with_attr
+            | Rule::field_type
+            | Rule::union
+            | Rule::base_type_with_attr
+            | Rule::base_type
+            | Rule::non_union
+            | Rule::base_type_without_array
+            | Rule::array_notation
+            | Rule::group
+            | Rule::parenthesized_type => {
+                for part in pair.into_inner() {
+                    self.type_tokens(part, tokens, indent)?;
+                }
+            }
+            Rule::tuple => {
+                let mut elements = 0;
+                for part in pair.into_inner() {
+                    if part.as_rule() == Rule::field_type_with_attr {
+                        if elements > 0 {
+                            tokens.glued(",");
+                        }
+                        elements += 1;
+                    }
+                    self.type_tokens(part, tokens, indent)?;
+                }
+            }
+            Rule::map => {
+                tokens.open("map<");
+                for (i, part) in pair.into_inner().enumerate() {
+                    if i > 0 {
+                        tokens.glued(",");
+                    }
+                    self.type_tokens(part, tokens, indent)?;
+                }
+                tokens.glued(">");
+            }
+            Rule::identifier | Rule::literal_type => {
+                tokens.push(Kind::Type, pair.as_str().trim_end())
+            }
+            Rule::field_operator => tokens.push(Kind::Type, "|"),
+            Rule::optional_token => tokens.glued("?"),
+            Rule::array_suffix => tokens.glued("[]"),
+            Rule::openParan => tokens.open("("),
+            Rule::closeParan => tokens.glued(")"),
+            Rule::field_attribute => tokens.push(Kind::Attribute, self.attribute(pair, indent)?),
+            Rule::trailing_comment => {
+                for (start, comment) in self.comments(pair) {
+                    let kind = if self.starts_line(start) {
+                        Kind::LineComment
+                    } else {
+                        Kind::Comment
+                    };
+                    tokens.push(kind, comment);
+                }
+            }
+            _ => return Err(Unformattable),
+        }
+        Ok(())
+    }
+
+    fn attribute(&self, attribute: Pair<'_>, indent: usize) -> FmtResult<String> {
+        let mut out = match attribute.as_rule() {
+            Rule::block_attribute => "@@".to_string(),
+            _ => "@".to_string(),
+        };
+        for part in attribute.into_inner() {
+            match part.as_rule() {
+                Rule::identifier => out.push_str(part.as_str().trim_end()),
+                Rule::arguments_list => {
+                    let arguments = part
+                        .into_inner()
+                        .map(|argument| self.expression(argument, indent))
+                        .collect::<FmtResult<Vec<_>>>()?;
+                    out.push('(');
+                    out.push_str(&arguments.join(", "));
+                    out.push(')');
+                }
+                _ => return Err(Unformattable),
+            }
+        }
+        Ok(out)
+    }
+
+    /// An expression starting on a line at `indent`.
+    fn expression(&self, expression: Pair<'_>, indent: usize) -> FmtResult<String> {
+        let inner = expression.into_inner().next().ok_or(Unformattable)?;
+        match inner.as_rule() {
+            Rule::jinja_expression | Rule::numeric_literal | Rule::identifier => {
+                Ok(inner.as_str().trim_end().to_string())
+            }
+            Rule::string_literal => {
+                let literal = inner.into_inner().next().ok_or(Unformattable)?;
+                match literal.as_rule() {
+                    Rule::raw_string_literal => self.raw_string(literal, indent),
+                    Rule::quoted_string_literal => Ok(literal.as_str().to_string()),
+                    Rule::unquoted_string_literal => Ok(literal.as_str().trim().to_string()),
+                    _ => Err(Unformattable),
+                }
+            }
+            Rule::map_expression => self.map(inner, indent),
+            Rule::array_expression => self.array(inner, indent),
+            _ => Err(Unformattable),
+        }
+    }
+
+    /// Multi-line raw strings get their contents indented one level past
+    /// `indent`, and their closing quote on a line of its own. Their value,
+    /// which is dedented, stays the same.
+    fn raw_string(&self, literal: Pair<'_>, indent: usize) -> FmtResult<String> {
+        let mut language = "";
+        let mut content = None;
+        for part in literal.clone().into_inner() {
+            let hashes = match part.as_rule() {
+                Rule::single_word => {
+                    language = part.as_str();
+                    continue;
+                }
+                Rule::raw_string_literal_content_1 => 1,
+                Rule::raw_string_literal_content_2 => 2,
+                Rule::raw_string_literal_content_3 => 3,
+                Rule::raw_string_literal_content_4 => 4,
+                Rule::raw_string_literal_content_5 => 5,
+                _ => return Err(Unformattable),
+            };
+            content = Some((part.as_str(), "#".repeat(hashes)));
+        }
+        let (content, hashes) = content.ok_or(Unformattable)?;
+
+        if !content.contains('\n') {
+            return Ok(literal.as_str().to_string());
+        }
+        let (value, _) = dedent(
+            content
+                .trim_start_matches(|c| c == '\n' || c == '\r')
+                .trim_end(),
+        );
+        if value.is_empty() {
+            return Ok(format!("{language}{hashes}\"\"{hashes}"));
+        }
+
+        let mut out = format!("{language}{hashes}\"\n");
+        for line in value.lines() {
+            if !line.is_empty() {
+                out.push_str(&INDENT.repeat(indent + 1));
+                out.push_str(line);
+            }
+            out.push('\n');
+        }
+        out.push_str(&INDENT.repeat(indent));
+        out.push('"');
+        out.push_str(&hashes);
+        Ok(out)
+    }
+
+    fn map(&self, map: Pair<'_>, indent: usize) -> FmtResult<String> {
+        let mut items = Vec::new();
+        for part in map.into_inner() {
+            match part.as_rule() {
+                Rule::map_entry => {
+                    let mut line = String::new();
+                    let mut after = Vec::new();
+                    for piece in part.into_inner() {
+                        match piece.as_rule() {
+                            Rule::comment_block => self.push_comments(piece, &mut items),
+                            Rule::empty_lines => {
+                                if self.has_blank_line(&piece) {
+                                    items.push(Item::Blank);
+                                }
+                            }
+                            Rule::map_key => line.push_str(piece.as_str().trim_end()),
+                            Rule::expression => {
+                                line.push(' ');
+                                line.push_str(&self.expression(piece, indent + 1)?);
+                            }
+                            Rule::trailing_comment => self.push_comments(piece, &mut after),
+                            _ => return Err(Unformattable),
+                        }
+                    }
+                    items.push(Item::Line(line));
+                    items.extend(after);
+                }
+                Rule::comment_block => self.push_comments(part, &mut items),
+                Rule::empty_lines => {
+                    if self.has_blank_line(&part) {
+                        items.push(Item::Blank);
+                    }
+                }
+                _ => return Err(Unformattable),
+            }
+        }
+        Ok(render_items("{".to_string(), items, indent, "}"))
+    }
+
+    /// Short arrays of short values go on one line. Otherwise every element
+    /// gets its own, with commas between elements but not after the last one,
+    /// where a comment couldn't follow it.
+    fn array(&self, array: Pair<'_>, indent: usize) -> FmtResult<String> {
+        let mut items = Vec::new();
+        let mut one_line = true;
+        for part in array.into_inner() {
+            match part.as_rule() {
+                Rule::expression => {
+                    let element = self.expression(part, indent + 1)?;
+                    one_line &= !element.contains('\n');
+                    items.push(Item::Line(element));
+                }
+                Rule::trailing_comment | Rule::comment_block => {
+                    one_line = false;
+                    self.push_comments(part, &mut items);
+                }
+                Rule::empty_lines => {
+                    if self.has_blank_line(&part) {
+                        items.push(Item::Blank);
+                    }
+                }
+                _ => return Err(Unformattable),
+            }
+        }
+
+        if one_line {
+            let elements = items
+                .iter()
+                .filter_map(|item| match item {
+                    Item::Line(element) => Some(element.as_str()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            let array = format!("[{}]", elements.join(", "));
+            if INDENT.len() * indent + array.chars().count() <= MAX_WIDTH {
+                return Ok(array);
+            }
+        }
+
+        let last = items.iter().rposition(|item| matches!(item, Item::Line(_)));
+        for (i, item) in items.iter_mut().enumerate() {
+            if let Item::Line(element) = item {
+                if Some(i) != last {
+                    element.push(',');
+                }
+            }
+        }
+        Ok(render_items("[".to_string(), items, indent, "]"))
+    }
+
+    /// The comments in a `comment_block` or `trailing_comment`, with where
+    /// each starts.
+    fn comments(&self, comments: Pair<'_>) -> Vec<(usize, String)> {
+        comments
+            .into_inner()
+            .map(|comment| {
+                (
+                    comment.as_span().start(),
+                    comment.as_str().trim().to_string(),
+                )
+            })
+            .collect()
+    }
+
+    fn push_comments(&self, comments: Pair<'_>, items: &mut Vec<Item>) {
+        for (start, comment) in self.comments(comments) {
+            items.push(if self.starts_line(start) {
+                Item::Comment(comment)
+            } else {
+                Item::Trailing(comment)
+            });
+        }
+    }
+
+    fn no_comments(&self, pair: &Pair<'_>) -> FmtResult<()> {
+        if pair
+            .clone()
+            .into_inner()
+            .flatten()
+            .any(|part| part.as_rule() == Rule::comment_block)
+        {
+            Err(Unformattable)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Whether nothing but whitespace comes before `pos` on its line.
+    fn starts_line(&self, pos: usize) -> bool {
+        let before = self.src[..pos].trim_end_matches(|c| c == ' ' || c == '\t');
+        before.is_empty() || before.ends_with(|c| c == '\n' || c == '\r')
+    }
+
+    /// Whether `empty_lines` holds a blank line, rather than just the end of
+    /// the line before it.
+    fn has_blank_line(&self, empty_lines: &Pair<'_>) -> bool {
+        let newlines = empty_lines.as_str().matches('\n').count();
+        let ends_previous_line = !self.starts_line(empty_lines.as_span().start());
+        newlines > usize::from(ends_previous_line)
+    }
+}
+
+/// Lays `items` out one per line between `opener` and `closer`, which sit at
+/// `indent`. Blank lines are kept, but never more than one in a row.
+fn render_items(mut opener: String, items: Vec<Item>, indent: usize, closer: &str) -> String {
+    let prefix = INDENT.repeat(indent + 1);
+    let mut lines: Vec<String> = Vec::new();
+    let mut opener_has_comment = false;
+    let mut i = 0;
+    while i < items.len() {
+        match &items[i] {
+            Item::Field { .. } => {
+                let end = items[i..]
+                    .iter()
+                    .position(|item| matches!(item, Item::Blank | Item::Line(_)))
+                    .map_or(items.len(), |n| i + n);
+                align_fields(&items[i..end], &prefix, &mut lines);
+                i = end;
+                continue;
+            }
+            Item::Line(text) | Item::Comment(text) => lines.push(format!("{prefix}{text}")),
+            Item::Trailing(comment) => {
+                opener_has_comment |= lines.is_empty();
+                let line = lines.last_mut().unwrap_or(&mut opener);
+                line.push(' ');
+                line.push_str(comment);
+            }
+            Item::Blank => {
+                if lines.last().is_some_and(|line| !line.is_empty()) {
+                    lines.push(String::new());
+                }
+            }
+        }
+        i += 1;
+    }
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+
+    let closer_indent = INDENT.repeat(indent);
+    if lines.is_empty() && !opener_has_comment {
+        format!("{opener}{closer}")
+    } else if lines.is_empty() {
+        format!("{opener}\n{closer_indent}{closer}")
+    } else {
+        format!("{opener}\n{}\n{closer_indent}{closer}", lines.join("\n"))
+    }
+}
+
+/// Lines up the types and attributes of a run of fields.
+fn align_fields(items: &[Item], prefix: &str, lines: &mut Vec<String>) {
+    let width = |s: &str| s.chars().count();
+    let fields = items.iter().filter_map(|item| match item {
+        Item::Field { name, ty, rest } => Some((name, ty, rest)),
+        _ => None,
+    });
+    let name_width = fields
+        .clone()
+        .filter(|(_, ty, rest)| !ty.is_empty() || !rest.is_empty())
+        .map(|(name, _, _)| width(name))
+        .max()
+        .unwrap_or(0);
+    let ty_width = fields
+        .filter(|(_, ty, rest)| !rest.is_empty() && !ty.contains('\n'))
+        .map(|(_, ty, _)| width(ty))
+        .max()
+        .unwrap_or(0);
+    let rest_column = prefix.len() + name_width + if ty_width > 0 { ty_width + 1 } else { 0 };
+    let pad = |line: &mut String, column: usize| {
+        let missing = column.saturating_sub(width(line));
+        line.extend(std::iter::repeat(' ').take(missing));
+    };
+
+    for item in items {
+        match item {
+            Item::Field { name, ty, rest } => {
+                let mut line = format!("{prefix}{name}");
+                if !ty.is_empty() {
+                    pad(&mut line, prefix.len() + name_width);
+                    line.push(' ');
+                    line.push_str(ty);
+                }
+                if !rest.is_empty() {
+                    if !line.contains('\n') {
+                        pad(&mut line, rest_column);
+                    }
+                    line.push(' ');
+                    line.push_str(rest);
+                }
+                lines.push(line);
+            }
+            Item::Comment(comment) => lines.push(format!("{prefix}{comment}")),
+            Item::Trailing(comment) => {
+                if let Some(line) = lines.last_mut() {
+                    line.push(' ');
+                    line.push_str(comment);
+                }
+            }
+            Item::Line(_) | Item::Blank => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use internal_baml_diagnostics::SourceFile;
+
+    use super::format_schema;
+
+    fn format(input: &str) -> String {
+        let source = SourceFile::from(("test.baml".into(), input.to_string()));
+        format_schema(&"test.baml".into(), &source).unwrap()
+    }
+
+    #[test]
+    fn aligns_fields_and_keeps_comments() {
+        let input = r##"
+// People.
+class Person {
+    name string @description("Their name") // full name
+  age    int?
+    // Not always known.
+    address Address? @alias("addr")
+}
+enum Color {
+  RED @alias("r")
+    GREEN
+}
+"##;
+        let expected = r##"// People.
+class Person {
+  name    string   @description("Their name") // full name
+  age     int?
+  // Not always known.
+  address Address? @alias("addr")
+}
+
+enum Color {
+  RED @alias("r")
+  GREEN
+}
+"##;
+        assert_eq!(format(input), expected);
+    }
+
+    #[test]
+    fn normalizes_prompts_and_test_args() {
+        let input = r##"
+function Greet(name: string) -> string {
+client GPT4
+  prompt #"
+        Say hello to {{ name }}.
+          Be nice.
+  "#
+}
+test Hello {
+  functions [Greet]
+  args { name "Alice" }
+}
+"##;
+        let expected = r##"function Greet(name: string) -> string {
+  client GPT4
+  prompt #"
+    Say hello to {{ name }}.
+      Be nice.
+  "#
+}
+
+test Hello {
+  functions [Greet]
+  args {
+    name "Alice"
+  }
+}
+"##;
+        assert_eq!(format(input), expected);
+    }
+
+    #[test]
+    fn is_idempotent() {
+        let input = r##"
+class Resume {
+  name string
+  skills   string[] | map<string, int> @description(#"
+    What they can do.
+  "#)
+}
+type Skills = map<string, Resume | null>
+template_string Header(resume: Resume) #"
+  {{ resume.name }}
+"#
+"##;
+        let once = format(input);
+        assert_eq!(format(&once), once);
+    }
+}
@@ -3,12 +3,15 @@
 #![deny(rust_2018_idioms, unsafe_code)]
 #![allow(clippy::derive_partial_eq_without_eq)]
 
+pub use self::formatter::format_schema;
 pub use self::parser::parse_schema;
 
 /// The AST data structure. It aims to faithfully represent the syntax of a Prisma Schema, with
 /// source span information.
 pub mod ast;
 
+/// Pretty-prints BAML source.
+mod formatter;
 mod parser;
 
 /// Transform the input string into a valid (quoted and escaped) PSL string literal.
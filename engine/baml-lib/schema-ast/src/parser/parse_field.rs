@@ -67,7 +67,10 @@ fn reassociate_type_attributes(
     let mut all_attrs = field_type.attributes().to_owned();
     all_attrs.append(field_attributes);
     let (attrs_for_type, attrs_for_field): (Vec<Attribute>, Vec<Attribute>) =
-        all_attrs.into_iter().partition(|attr| ["assert", "check"].contains(&attr.name()));
+        all_attrs.into_iter().partition(|attr| {
+            ["assert", "check"].contains(&attr.name())
+                || REFINEMENT_ATTRIBUTES.contains(&attr.name())
+        });
     field_type.set_attributes(attrs_for_type);
     *field_attributes = attrs_for_field;
 }
@@ -1,7 +1,7 @@
 use std::path::PathBuf;
 
 use super::{
-    parse_template_string::parse_template_string,
+    parse_template_string::parse_template_string, parse_type_alias::parse_type_alias,
     parse_type_expression_block::parse_type_expression_block,
     parse_value_expression_block::parse_value_expression_block, BAMLParser, Rule,
 };
@@ -105,6 +105,17 @@ pub fn parse_schema(
                         }
                     }
 
+                    Rule::type_alias => {
+                        match parse_type_alias(
+                            current,
+                            pending_block_comment.take(),
+                            &mut diagnostics,
+                        ) {
+                            Ok(alias) => top_level_definitions.push(Top::TypeAlias(alias)),
+                            Err(e) => diagnostics.push_error(e),
+                        }
+                    }
+
                     Rule::EOI => {}
                     Rule::CATCH_ALL => {
                         diagnostics.push_error(DatamodelError::new_validation_error(
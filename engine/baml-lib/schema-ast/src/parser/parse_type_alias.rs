@@ -0,0 +1,44 @@
+use super::{
+    helpers::{parsing_catch_all, Pair},
+    parse_comments::*,
+    parse_field::parse_field_type_chain,
+    parse_identifier::parse_identifier,
+    Rule,
+};
+use crate::ast::*;
+use internal_baml_diagnostics::{DatamodelError, Diagnostics};
+
+pub(crate) fn parse_type_alias(
+    pair: Pair<'_>,
+    doc_comment: Option<Pair<'_>>,
+    diagnostics: &mut Diagnostics,
+) -> Result<TypeAlias, DatamodelError> {
+    let pair_span = pair.as_span();
+    let mut name: Option<Identifier> = None;
+    let mut value = None;
+
+    for current in pair.into_inner() {
+        match current.as_rule() {
+            Rule::TYPE_KEYWORD => {}
+            Rule::identifier => name = Some(parse_identifier(current, diagnostics)),
+            Rule::assignment => {}
+            Rule::field_type_chain => value = parse_field_type_chain(current, diagnostics),
+            _ => parsing_catch_all(current, "type_alias"),
+        }
+    }
+
+    match (name, value) {
+        (Some(name), Some(value)) => Ok(TypeAlias {
+            name,
+            value,
+            documentation: doc_comment.and_then(parse_comment_block),
+            span: diagnostics.span(pair_span),
+        }),
+        (name, _) => Err(DatamodelError::new_model_validation_error(
+            "This type alias is invalid. Valid type alias syntax is `type Name = string | int`.",
+            "type_alias",
+            name.as_ref().map_or("<unknown>", |n| n.name()),
+            diagnostics.span(pair_span),
+        )),
+    }
+}
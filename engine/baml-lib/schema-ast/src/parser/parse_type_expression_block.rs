@@ -23,6 +23,8 @@ pub(crate) fn parse_type_expression_block(
     let mut name: Option<Identifier> = None;
     let mut attributes: Vec<Attribute> = Vec::new();
     let mut fields: Vec<Field<FieldType>> = Vec::new();
+    let mut extends: Option<Identifier> = None;
+    let mut spreads: Vec<Identifier> = Vec::new();
     let mut sub_type: Option<SubType> = None;
     let mut input = None;
 
@@ -51,6 +53,14 @@ pub(crate) fn parse_type_expression_block(
                 Ok(arg) => input = Some(arg),
                 Err(err) => diagnostics.push_error(err),
             },
+            Rule::extends_clause => {
+                let base = current
+                    .into_inner()
+                    .find(|p| p.as_rule() == Rule::identifier);
+                if let Some(base) = base {
+                    extends = Some(parse_identifier(base, diagnostics));
+                }
+            }
             Rule::type_expression_contents => {
                 let mut pending_field_comment: Option<Pair<'_>> = None;
 
@@ -59,6 +69,15 @@ pub(crate) fn parse_type_expression_block(
                         Rule::block_attribute => {
                             attributes.push(parse_attribute(item, false, diagnostics));
                         }
+                        Rule::type_spread => {
+                            pending_field_comment = None;
+                            let source = item
+                                .into_inner()
+                                .find(|p| p.as_rule() == Rule::identifier);
+                            if let Some(source) = source {
+                                spreads.push(parse_identifier(source, diagnostics));
+                            }
+                        }
                         Rule::type_expression =>{
                             let sub_type_is_enum = matches!(sub_type, Some(SubType::Enum));
                             let sub_type_expression = parse_type_expr(
@@ -103,6 +122,8 @@ pub(crate) fn parse_type_expression_block(
         Some(name) => TypeExpressionBlock {
             name,
             fields,
+            extends,
+            spreads,
             input,
             attributes,
             documentation: doc_comment.and_then(parse_comment_block),
@@ -174,4 +195,25 @@ mod tests {
         assert_eq!(name.to_string(), "Test");
         assert!(fields[0].expr.is_some());
     }
+
+    #[test]
+    fn class_extends_and_spreads() {
+        let root_path = "test_file.baml";
+
+        let input = "class Child extends Parent {\n  ...Mixin\n  name string\n}";
+        let source = SourceFile::new_static(root_path.into(), input);
+        let mut diagnostics = Diagnostics::new(root_path.into());
+        diagnostics.set_source(&source);
+        let parsed = BAMLParser::parse(Rule::type_expression_block, input)
+            .unwrap()
+            .next()
+            .unwrap();
+        let result = parse_type_expression_block(parsed, None, &mut diagnostics);
+        assert!(!diagnostics.has_errors());
+        assert_eq!(result.extends.unwrap().to_string(), "Parent");
+        assert_eq!(result.spreads.len(), 1);
+        assert_eq!(result.spreads[0].to_string(), "Mixin");
+        assert_eq!(result.fields.len(), 1);
+        assert_eq!(result.fields[0].name(), "name");
+    }
 }
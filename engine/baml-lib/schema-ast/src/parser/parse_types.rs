@@ -127,14 +127,13 @@ fn parse_base_type(pair: Pair<'_>, diagnostics: &mut Diagnostics) -> Option<Fiel
             Rule::identifier => {
                 let identifier = parse_identifier(current.clone(), diagnostics);
                 let field_type = match current.as_str() {
-                    "string" | "int" | "float" | "bool" | "image" | "audio" => {
-                        FieldType::Primitive(
-                            FieldArity::Required,
-                            TypeValue::from_str(identifier.name()).expect("Invalid type value"),
-                            diagnostics.span(current.as_span()),
-                            None,
-                        )
-                    }
+                    "string" | "int" | "float" | "bool" | "image" | "audio" | "date"
+                    | "datetime" | "duration" => FieldType::Primitive(
+                        FieldArity::Required,
+                        TypeValue::from_str(identifier.name()).expect("Invalid type value"),
+                        diagnostics.span(current.as_span()),
+                        None,
+                    ),
                     "null" => FieldType::Primitive(
                         FieldArity::Optional,
                         TypeValue::Null,
@@ -208,23 +207,22 @@ fn parse_literal_type(pair: Pair<'_>, diagnostics: &mut Diagnostics) -> Option<F
             None => unreachable!("quoted string literal has no string content"),
         },
 
-        Rule::numeric_literal => match literal_type.as_str().parse::<i64>() {
-            Ok(int) => LiteralValue::Int(int),
-
-            // This should only be a float because of how the pest grammar is defined.
-            Err(_e) => {
+        Rule::numeric_literal => {
+            let numeric = literal_type.as_str();
+            if let Ok(int) = numeric.parse::<i64>() {
+                LiteralValue::Int(int)
+            } else if numeric.contains('.') {
+                // The grammar only allows plain decimals, which always parse.
+                LiteralValue::Float(numeric.parse().expect("Invalid float literal"))
+            } else {
                 diagnostics.push_error(DatamodelError::new_validation_error(
-                    format!(
-                        "Float literal values are not supported: {}",
-                        literal_type.as_str()
-                    )
-                    .as_str(),
+                    &format!("Int literal value is out of range: {numeric}"),
                     span,
                 ));
 
                 return None;
             }
-        },
+        }
         _ => unreachable_rule!(literal_type, Rule::literal_type),
     };
 
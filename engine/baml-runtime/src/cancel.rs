@@ -0,0 +1,283 @@
+// Lets callers stop function calls that are in flight.
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::{pin, Pin},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll, Waker},
+};
+
+use anyhow::Result;
+use futures::future::{self, Either};
+
+use crate::{errors::ExposedError, FunctionResult};
+
+/// Cancels the calls it's passed to, like a `Collector` or a `ClientRegistry`
+/// is. Cancelling drops the request in flight, so the provider stops
+/// generating, and skips any retries and fallbacks that are left.
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    waiters: Mutex<Waiters>,
+}
+
+/// The wakers of the [`Cancelled`] futures that are waiting, by their id.
+#[derive(Debug, Default)]
+struct Waiters {
+    next_id: u64,
+    wakers: HashMap<u64, Waker>,
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+        for (_, waker) in self.inner.waiters.lock().unwrap().wakers.drain() {
+            waker.wake();
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Resolves once the token is cancelled.
+    pub fn cancelled(&self) -> Cancelled<'_> {
+        Cancelled {
+            token: self,
+            id: None,
+        }
+    }
+
+    /// Runs `fut` until it finishes, or until the token is cancelled, in which
+    /// case `fut` is dropped and `None` returned.
+    pub async fn run<F: Future>(&self, fut: F) -> Option<F::Output> {
+        if self.is_cancelled() {
+            return None;
+        }
+        match future::select(pin!(fut), pin!(self.cancelled())).await {
+            Either::Left((output, _)) => Some(output),
+            Either::Right(_) => None,
+        }
+    }
+
+    /// A call that hadn't succeeded by the time it was cancelled fails with
+    /// [`ExposedError::Cancelled`], whatever its last attempt ended with.
+    pub(crate) fn check(&self, result: Result<FunctionResult>) -> Result<FunctionResult> {
+        let succeeded =
+            matches!(&result, Ok(r) if matches!(r.result_with_constraints(), Some(Ok(_))));
+        if self.is_cancelled() && !succeeded {
+            Err(ExposedError::Cancelled.into())
+        } else {
+            result
+        }
+    }
+}
+
+/// Waits for a [`CancelToken`] to be cancelled. The token only keeps its waker
+/// until it's dropped, so a long-lived token doesn't pile up the wakers of
+/// every call it was passed to.
+#[derive(Debug)]
+pub struct Cancelled<'a> {
+    token: &'a CancelToken,
+    id: Option<u64>,
+}
+
+impl Future for Cancelled<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = self.get_mut();
+        if this.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+        let mut waiters = this.token.inner.waiters.lock().unwrap();
+        // `cancel` may have run since the check above
+        if this.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+        let id = *this.id.get_or_insert_with(|| {
+            waiters.next_id += 1;
+            waiters.next_id
+        });
+        if !waiters
+            .wakers
+            .get(&id)
+            .is_some_and(|waker| waker.will_wake(cx.waker()))
+        {
+            waiters.wakers.insert(id, cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for Cancelled<'_> {
+    fn drop(&mut self) {
+        if let Some(id) = self.id {
+            self.token.inner.waiters.lock().unwrap().wakers.remove(&id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cancel_drops_the_future() {
+        let token = CancelToken::new();
+        let pending = token.run(future::pending::<()>());
+        let cancel = async {
+            token.cancel();
+        };
+        let (output, _) = futures::executor::block_on(future::join(pending, cancel));
+        assert_eq!(output, None);
+        assert!(token.is_cancelled());
+
+        let done = futures::executor::block_on(CancelToken::new().run(async { 1 }));
+        assert_eq!(done, Some(1));
+        assert_eq!(futures::executor::block_on(token.run(async { 1 })), None);
+    }
+
+    #[test]
+    fn finished_waits_dont_keep_their_wakers() {
+        let token = CancelToken::new();
+        let waker = futures::task::noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        for _ in 0..100 {
+            let mut cancelled = pin!(token.cancelled());
+            assert!(cancelled.as_mut().poll(&mut cx).is_pending());
+            assert!(cancelled.as_mut().poll(&mut cx).is_pending());
+            assert_eq!(token.inner.waiters.lock().unwrap().wakers.len(), 1);
+        }
+        assert!(token.inner.waiters.lock().unwrap().wakers.is_empty());
+
+        // Only ready once `run` has waited on the token too
+        let mut yielded = false;
+        let yields_once = future::poll_fn(move |cx| {
+            if yielded {
+                return Poll::Ready(1);
+            }
+            yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        });
+        assert_eq!(futures::executor::block_on(token.run(yields_once)), Some(1));
+        assert!(token.inner.waiters.lock().unwrap().wakers.is_empty());
+
+        let mut cancelled = pin!(token.cancelled());
+        assert!(cancelled.as_mut().poll(&mut cx).is_pending());
+        token.cancel();
+        assert!(cancelled.as_mut().poll(&mut cx).is_ready());
+    }
+
+    #[test]
+    fn cancel_skips_retries_and_fallbacks() {
+        use std::{
+            collections::HashMap, net::TcpListener, sync::atomic::AtomicUsize, time::Duration,
+        };
+
+        use baml_types::{BamlMap, BamlValue};
+
+        use crate::BamlRuntime;
+
+        // Accepts requests but never answers them, so every attempt hangs until
+        // it's cancelled.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let requests = Arc::new(AtomicUsize::new(0));
+        let token = CancelToken::new();
+        {
+            let (requests, token) = (requests.clone(), token.clone());
+            std::thread::spawn(move || {
+                let mut open = Vec::new();
+                for conn in listener.incoming() {
+                    open.push(conn);
+                    if requests.fetch_add(1, Ordering::SeqCst) == 0 {
+                        std::thread::sleep(Duration::from_millis(100));
+                        token.cancel();
+                    }
+                }
+            });
+        }
+
+        let files = HashMap::from([(
+            "main.baml".to_string(),
+            format!(
+                r##"
+                retry_policy Thrice {{
+                  max_retries 3
+                  strategy {{
+                    type constant_delay
+                    delay_ms 10
+                  }}
+                }}
+
+                client<llm> Hangs {{
+                  provider openai-generic
+                  retry_policy Thrice
+                  options {{
+                    base_url "http://127.0.0.1:{port}"
+                    model "gpt-4o"
+                  }}
+                }}
+
+                client<llm> AlsoHangs {{
+                  provider openai-generic
+                  options {{
+                    base_url "http://127.0.0.1:{port}"
+                    model "gpt-4o-mini"
+                  }}
+                }}
+
+                client<llm> Resilient {{
+                  provider fallback
+                  options {{
+                    strategy [Hangs, AlsoHangs]
+                  }}
+                }}
+
+                function Echo(input: string) -> string {{
+                  client Resilient
+                  prompt #"{{{{ input }}}}"#
+                }}
+                "##
+            ),
+        )]);
+        let runtime =
+            BamlRuntime::from_file_content(".", &files, HashMap::<&str, &str>::new()).unwrap();
+        let ctx = runtime.create_ctx_manager(BamlValue::String("test".into()), None);
+        let params = BamlMap::from([("input".to_string(), BamlValue::String("hi".into()))]);
+
+        let (result, _) = runtime.call_function_sync(
+            "Echo".into(),
+            &params,
+            &ctx,
+            None,
+            None,
+            None,
+            Some(&token),
+        );
+        let Err(error) = result else {
+            panic!("A cancelled call shouldn't succeed");
+        };
+        assert!(matches!(
+            error.downcast_ref::<ExposedError>(),
+            Some(ExposedError::Cancelled)
+        ));
+        // Give any retry, or fallback, that wasn't skipped the time to show up.
+        std::thread::sleep(Duration::from_millis(200));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,68 @@
+use crate::runtime::runtime_interface::baml_src_files;
+use anyhow::{Context, Result};
+use internal_baml_core::{
+    internal_baml_diagnostics::SourceFile, internal_baml_schema_ast::format_schema,
+};
+use std::path::PathBuf;
+
+#[derive(clap::Args, Debug)]
+pub struct FormatArgs {
+    #[arg(long, help = "path/to/baml_src", default_value = "./baml_src")]
+    pub(super) from: PathBuf,
+    #[arg(
+        long,
+        help = "List the files that need formatting instead of rewriting them, and fail if there are any",
+        default_value_t = false
+    )]
+    check: bool,
+    #[arg(help = "Only format these files, instead of everything in baml_src")]
+    paths: Vec<PathBuf>,
+}
+
+impl FormatArgs {
+    pub fn run(&self) -> Result<()> {
+        let paths = if self.paths.is_empty() {
+            baml_src_files(&self.from)
+                .context("Failed while searching for .baml files in baml_src/")?
+                .into_iter()
+                .filter(|path| path.extension().is_some_and(|ext| ext == "baml"))
+                .collect()
+        } else {
+            self.paths.clone()
+        };
+
+        let mut unformatted = Vec::new();
+        for path in &paths {
+            let contents = std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            let source = SourceFile::from((path.clone(), contents.clone()));
+            let formatted = format_schema(&self.from, &source).map_err(|diagnostics| {
+                anyhow::anyhow!(
+                    "Failed to parse {}:\n{}",
+                    path.display(),
+                    diagnostics.to_pretty_string()
+                )
+            })?;
+            if formatted == contents {
+                continue;
+            }
+            if self.check {
+                println!("{}", path.display());
+            } else {
+                std::fs::write(path, formatted)
+                    .with_context(|| format!("Failed to write {}", path.display()))?;
+                log::info!("Formatted {}", path.display());
+            }
+            unformatted.push(path);
+        }
+
+        if self.check && !unformatted.is_empty() {
+            anyhow::bail!(
+                "{} of {} files need formatting; run `baml-cli fmt` to fix them",
+                unformatted.len(),
+                paths.len()
+            );
+        }
+        Ok(())
+    }
+}
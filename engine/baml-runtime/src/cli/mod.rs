@@ -1,7 +1,10 @@
 mod dev;
+mod format;
 pub mod generate;
 mod init;
 mod serve;
+mod test;
+mod traces;
 
 use anyhow::Result;
 use clap::{Parser, Subcommand};
@@ -28,6 +31,12 @@ enum Commands {
     Serve(serve::ServeArgs),
     #[command(about = "Starts a development server")]
     Dev(dev::DevArgs),
+    #[command(about = "Runs the tests in the baml_src directory")]
+    Test(test::TestArgs),
+    #[command(about = "Formats the BAML files in the baml_src directory")]
+    Fmt(format::FormatArgs),
+    #[command(about = "Lists and shows the traces written to BAML_TRACE_DIR")]
+    Traces(traces::TracesArgs),
 }
 
 /// Default values for the CLI to use.
@@ -61,6 +70,15 @@ impl RuntimeCli {
                 args.from = BamlRuntime::parse_baml_src_path(&args.from)?;
                 args.run(defaults)
             }
+            Commands::Test(args) => {
+                args.from = BamlRuntime::parse_baml_src_path(&args.from)?;
+                args.run()
+            }
+            Commands::Fmt(args) => {
+                args.from = BamlRuntime::parse_baml_src_path(&args.from)?;
+                args.run()
+            }
+            Commands::Traces(args) => args.run(),
         }
     }
 }
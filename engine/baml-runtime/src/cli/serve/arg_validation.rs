@@ -38,6 +38,9 @@ impl BamlServeValidate for BamlValue {
             Ok(())
           }
           BamlValue::Bool(_) |
+          BamlValue::Date(_) |
+          BamlValue::DateTime(_) |
+          BamlValue::Duration(_) |
           BamlValue::Enum(_, _) |
           BamlValue::Float(_) |
           BamlValue::Int(_) |
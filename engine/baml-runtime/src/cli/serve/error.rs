@@ -27,6 +27,10 @@ pub enum BamlError {
         raw_output: String,
         message: String,
     },
+    /// The call was cancelled before it succeeded, usually because the
+    /// client disconnected.
+    #[serde(rename_all = "snake_case")]
+    Cancelled { message: String },
     /// This is the only variant not documented at the aforementioned link:
     /// this is the catch-all for unclassified errors.
     #[serde(rename_all = "snake_case")]
@@ -46,6 +50,9 @@ impl BamlError {
                     raw_output: raw_output.to_string(),
                     message: message.to_string(),
                 },
+                ExposedError::Cancelled => Self::Cancelled {
+                    message: er.to_string(),
+                },
             }
         } else if let Some(er) = err.downcast_ref::<ScopeStack>() {
             Self::InvalidArgument {
@@ -57,7 +64,8 @@ impl BamlError {
                     message: format!("Unexpected error from BAML: {:?}", err),
                 },
                 LLMResponse::LLMFailure(failed) => match &failed.code {
-                    crate::internal::llm_client::ErrorCode::Other(2) => Self::InternalError {
+                    crate::internal::llm_client::ErrorCode::Other(2)
+                    | crate::internal::llm_client::ErrorCode::Timeout => Self::InternalError {
                         message: format!("Something went wrong with the LLM client: {:?}", err),
                     },
                     crate::internal::llm_client::ErrorCode::Other(_)
@@ -94,6 +102,8 @@ impl IntoResponse for BamlError {
                 BamlError::InvalidArgument { .. } => StatusCode::BAD_REQUEST,
                 BamlError::ClientError { .. } => StatusCode::BAD_GATEWAY,
                 BamlError::ValidationFailure { .. } => StatusCode::INTERNAL_SERVER_ERROR, // ??? - FIXME
+                // "Client Closed Request", as nginx calls it
+                BamlError::Cancelled { .. } => StatusCode::from_u16(499).unwrap(),
                 BamlError::InternalError { .. } => StatusCode::INTERNAL_SERVER_ERROR,
             },
             Json(match serde_json::to_value(&self) {
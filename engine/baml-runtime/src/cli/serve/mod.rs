@@ -33,6 +33,7 @@ use tokio::{net::TcpListener, sync::RwLock};
 use tokio_stream::StreamExt;
 
 use crate::{
+    cancel::CancelToken,
     client_registry::ClientRegistry,
     errors::ExposedError,
     internal::llm_client::{LLMResponse, ResponseBamlValue},
@@ -158,9 +159,11 @@ impl Header for XBamlApiKey {
 }
 
 async fn status_handler(
+    server: Arc<Server>,
     basic_creds: Option<TypedHeader<Authorization<Basic>>>,
     baml_api_key: Option<TypedHeader<XBamlApiKey>>,
 ) -> Response {
+    let circuit_breakers = server.b.read().await.circuit_breaker_statuses();
     match Server::enforce_auth(basic_creds.as_deref(), baml_api_key.as_deref()) {
         AuthEnforcementMode::EnforceAndFail(e) => (
             StatusCode::FORBIDDEN,
@@ -179,6 +182,7 @@ async fn status_handler(
                     "enforcement": "active",
                     "outcome": "pass"
                 },
+                "circuit_breakers": circuit_breakers,
             })),
         ),
         AuthEnforcementMode::NoEnforcement => (
@@ -187,12 +191,23 @@ async fn status_handler(
                 "authz": {
                     "enforcement": "none",
                 },
+                "circuit_breakers": circuit_breakers,
             })),
         ),
     }
     .into_response()
 }
 
+/// Tokens used and their estimated cost since the server started, or since the
+/// last `DELETE /_debug/usage`.
+async fn usage_handler(server: Arc<Server>) -> Response {
+    Json(server.b.read().await.usage()).into_response()
+}
+
+async fn reset_usage_handler(server: Arc<Server>) -> Response {
+    Json(server.b.read().await.reset_usage()).into_response()
+}
+
 enum AuthEnforcementMode {
     NoEnforcement,
     EnforceAndPass,
@@ -287,7 +302,21 @@ impl Server {
         let app = axum::Router::new();
 
         let app = app.route("/_debug/ping", any(ping::ping_handler));
-        let app = app.route("/_debug/status", any(status_handler));
+
+        let s = self.clone();
+        let app = app.route(
+            "/_debug/status",
+            any(move |basic_creds, baml_api_key| {
+                status_handler(s.clone(), basic_creds, baml_api_key)
+            }),
+        );
+
+        let s = self.clone();
+        let r = self.clone();
+        let app = app.route(
+            "/_debug/usage",
+            get(move || usage_handler(s.clone())).delete(move || reset_usage_handler(r.clone())),
+        );
 
         let s = self.clone();
         let app = app.route(
@@ -361,7 +390,15 @@ Tip: test that the server is up using `curl http://localhost:{}/_debug/ping`
 
         let locked = self.b.read().await;
         let (result, _trace_id) = locked
-            .call_function(b_fn, &args, &ctx_mgr, None, client_registry.as_ref())
+            .call_function(
+                b_fn,
+                &args,
+                &ctx_mgr,
+                None,
+                client_registry.as_ref(),
+                None,
+                None,
+            )
             .await;
 
         match result {
@@ -443,6 +480,7 @@ Tip: test that the server is up using `curl http://localhost:{}/_debug/ping`
         };
 
         let client_registry = b_options.and_then(|options| options.client_registry);
+        let cancel = CancelToken::new();
 
         tokio::spawn(async move {
             let ctx_mgr =
@@ -454,6 +492,8 @@ Tip: test that the server is up using `curl http://localhost:{}/_debug/ping`
                 &ctx_mgr,
                 None,
                 client_registry.as_ref(),
+                None,
+                Some(&cancel),
             );
 
             match result_stream {
@@ -462,11 +502,12 @@ Tip: test that the server is up using `curl http://localhost:{}/_debug/ping`
                         .run(
                             Some(move |result| {
                                 // If the receiver is closed (either because it called close or it was dropped),
-                                // we can't really do anything
+                                // the client has gone away, so there's no point in paying for the rest
                                 match sender.send(result) {
                                     Ok(_) => (),
                                     Err(e) => {
                                         log::error!("Error sending result to receiver: {:?}", e);
+                                        cancel.cancel();
                                     }
                                 }
                             }),
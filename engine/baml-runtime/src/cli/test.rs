@@ -0,0 +1,479 @@
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use baml_types::BamlValue;
+use colored::Colorize;
+use futures::StreamExt;
+use indexmap::IndexMap;
+use serde::Serialize;
+
+use crate::{
+    internal::llm_client::LLMResponse, runtime_interface::ExperimentalTracingInterface,
+    BamlRuntime, FunctionResult, InternalRuntimeInterface, TestFailReason, TestStatus,
+};
+
+#[derive(clap::Args, Clone, Debug)]
+pub struct TestArgs {
+    #[arg(long, help = "path/to/baml_src", default_value = "./baml_src")]
+    pub(super) from: PathBuf,
+    #[arg(
+        long,
+        short,
+        help = r#"Only run tests matching this pattern. Patterns look like "Function::Test" and may use "*" wildcards; a pattern without "::" matches either name. Can be repeated."#
+    )]
+    include: Vec<String>,
+    #[arg(
+        long,
+        short,
+        help = "Skip tests matching this pattern, as for --include. Can be repeated."
+    )]
+    exclude: Vec<String>,
+    #[arg(long, help = "How many tests to run at once", default_value = "10")]
+    parallel: usize,
+    #[arg(long, help = "Write a JUnit XML report to this path")]
+    junit: Option<PathBuf>,
+    #[arg(long, help = "Write a JSON report to this path")]
+    json: Option<PathBuf>,
+    #[arg(
+        long,
+        help = "List the selected tests without running them",
+        default_value_t = false
+    )]
+    list: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Outcome {
+    Passed,
+    /// Every assert passed, but some checks need a human to look at them.
+    NeedsHumanEval,
+    /// The output was wrong: it didn't parse, failed an assert or didn't
+    /// match the expected value.
+    Failed,
+    /// The test couldn't produce an output at all.
+    Error,
+}
+
+#[derive(Debug, Serialize)]
+struct TestResult {
+    function: String,
+    test: String,
+    status: Outcome,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+    #[serde(rename = "duration_ms", serialize_with = "serialize_millis")]
+    duration: Duration,
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Serialize)]
+struct Summary {
+    total: usize,
+    passed: usize,
+    needs_human_eval: usize,
+    failed: usize,
+    errors: usize,
+}
+
+impl Summary {
+    fn of<'a>(results: impl IntoIterator<Item = &'a TestResult>) -> Self {
+        let mut summary = Summary::default();
+        for result in results {
+            summary.total += 1;
+            match result.status {
+                Outcome::Passed => summary.passed += 1,
+                Outcome::NeedsHumanEval => summary.needs_human_eval += 1,
+                Outcome::Failed => summary.failed += 1,
+                Outcome::Error => summary.errors += 1,
+            }
+        }
+        summary
+    }
+}
+
+impl TestArgs {
+    pub fn run(&self) -> Result<()> {
+        let runtime = BamlRuntime::from_directory(&self.from, std::env::vars().collect())?;
+
+        let selected = runtime
+            .inner
+            .ir()
+            .walk_tests()
+            .map(|t| (t.item.0.elem.name().to_string(), t.item.1.elem.name.clone()))
+            .filter(|(function, test)| self.selects(function, test))
+            .collect::<Vec<_>>();
+
+        if selected.is_empty() {
+            log::warn!("No tests matched in {}", self.from.display());
+            return Ok(());
+        }
+
+        if self.list {
+            for (function, test) in &selected {
+                println!("{function}::{test}");
+            }
+            return Ok(());
+        }
+
+        let t = BamlRuntime::get_tokio_singleton()?;
+        let start = Instant::now();
+        let mut results = t.block_on(
+            futures::stream::iter(selected.iter().enumerate())
+                .map(|(i, (function, test))| {
+                    let runtime = &runtime;
+                    async move { (i, run_test(runtime, function, test).await) }
+                })
+                .buffer_unordered(self.parallel.max(1))
+                .inspect(|(_, result)| print_result(result))
+                .collect::<Vec<_>>(),
+        );
+        let elapsed = start.elapsed();
+        runtime.flush()?;
+
+        // Report in the order the tests were declared, not the order they finished.
+        results.sort_by_key(|(i, _)| *i);
+        let results = results
+            .into_iter()
+            .map(|(_, result)| result)
+            .collect::<Vec<_>>();
+
+        let summary = Summary::of(&results);
+        print_summary(&summary, elapsed);
+
+        if let Some(path) = &self.junit {
+            write_report(path, &render_junit(&results, elapsed))?;
+        }
+        if let Some(path) = &self.json {
+            let report = serde_json::json!({
+                "summary": summary,
+                "duration_ms": elapsed.as_millis(),
+                "tests": results,
+            });
+            write_report(path, &serde_json::to_string_pretty(&report)?)?;
+        }
+
+        let unsuccessful = summary.failed + summary.errors;
+        if unsuccessful > 0 {
+            anyhow::bail!("{} of {} tests failed", unsuccessful, summary.total);
+        }
+        Ok(())
+    }
+
+    fn selects(&self, function: &str, test: &str) -> bool {
+        let included = self.include.is_empty()
+            || self
+                .include
+                .iter()
+                .any(|pattern| pattern_matches(pattern, function, test));
+        included
+            && !self
+                .exclude
+                .iter()
+                .any(|pattern| pattern_matches(pattern, function, test))
+    }
+}
+
+async fn run_test(runtime: &BamlRuntime, function: &str, test: &str) -> TestResult {
+    let ctx = runtime.create_ctx_manager(BamlValue::String("cli".to_string()), None);
+    let start = Instant::now();
+    let (response, _) = runtime
+        .run_test(function, test, &ctx, None::<fn(FunctionResult)>)
+        .await;
+    let duration = start.elapsed();
+
+    let (status, message) = match &response {
+        Ok(response) => match response.status() {
+            TestStatus::Pass => (Outcome::Passed, None),
+            TestStatus::NeedsHumanEval(checks) => (
+                Outcome::NeedsHumanEval,
+                Some(format!("Failed checks: {}", checks.join(", "))),
+            ),
+            TestStatus::Fail(reason) => {
+                let status = match reason {
+                    TestFailReason::TestUnspecified(_) | TestFailReason::TestLLMFailure(_) => {
+                        Outcome::Error
+                    }
+                    _ => Outcome::Failed,
+                };
+                (status, Some(failure_message(&reason)))
+            }
+        },
+        Err(e) => (Outcome::Error, Some(format!("{e:#}"))),
+    };
+
+    TestResult {
+        function: function.to_string(),
+        test: test.to_string(),
+        status,
+        message,
+        duration,
+    }
+}
+
+fn failure_message(reason: &TestFailReason) -> String {
+    match reason {
+        TestFailReason::TestUnspecified(e) | TestFailReason::TestParseFailure(e) => {
+            format!("{e:#}")
+        }
+        TestFailReason::TestLLMFailure(LLMResponse::LLMFailure(failure)) => {
+            format!(
+                "LLM call failed ({}): {}",
+                failure.code.to_string(),
+                failure.message
+            )
+        }
+        TestFailReason::TestLLMFailure(response) => response.to_string(),
+        TestFailReason::TestConstraintsFailure { failed_assert, .. } => {
+            format!("Failed assertion: {failed_assert}")
+        }
+        TestFailReason::TestExpectationFailure { expected, actual } => format!(
+            "Expected {:#}\nbut got {:#}",
+            serde_json::json!(expected),
+            serde_json::json!(actual)
+        ),
+    }
+}
+
+fn print_result(result: &TestResult) {
+    let label = match result.status {
+        Outcome::Passed => "PASSED".green(),
+        Outcome::NeedsHumanEval => "CHECK ".yellow(),
+        Outcome::Failed => "FAILED".red(),
+        Outcome::Error => "ERROR ".red(),
+    };
+    println!(
+        "{} {}::{} {}",
+        label.bold(),
+        result.function,
+        result.test,
+        format!("({:.2}s)", result.duration.as_secs_f64()).dimmed()
+    );
+    if let Some(message) = &result.message {
+        for line in message.lines() {
+            println!("       {line}");
+        }
+    }
+}
+
+fn print_summary(summary: &Summary, elapsed: Duration) {
+    let mut parts = vec![format!("{} passed", summary.passed).green().to_string()];
+    if summary.needs_human_eval > 0 {
+        parts.push(
+            format!("{} need human eval", summary.needs_human_eval)
+                .yellow()
+                .to_string(),
+        );
+    }
+    if summary.failed > 0 {
+        parts.push(format!("{} failed", summary.failed).red().to_string());
+    }
+    if summary.errors > 0 {
+        parts.push(format!("{} errored", summary.errors).red().to_string());
+    }
+    println!(
+        "\n{} tests: {} ({:.2}s)",
+        summary.total,
+        parts.join(", "),
+        elapsed.as_secs_f64()
+    );
+}
+
+fn write_report(path: &Path, contents: &str) -> Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    std::fs::write(path, contents)
+        .with_context(|| format!("Failed to write report to {}", path.display()))
+}
+
+/// One `<testsuite>` per function, one `<testcase>` per test. Tests that need
+/// human eval pass, with the failed checks in `<system-out>`.
+fn render_junit(results: &[TestResult], elapsed: Duration) -> String {
+    let mut suites = IndexMap::<&str, Vec<&TestResult>>::new();
+    for result in results {
+        suites.entry(&result.function).or_default().push(result);
+    }
+
+    let total = Summary::of(results);
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        r#"<testsuites name="baml" tests="{}" failures="{}" errors="{}" time="{:.3}">"#,
+        total.total,
+        total.failed,
+        total.errors,
+        elapsed.as_secs_f64()
+    );
+    for (function, tests) in suites {
+        let summary = Summary::of(tests.iter().copied());
+        let time: Duration = tests.iter().map(|t| t.duration).sum();
+        let _ = writeln!(
+            xml,
+            r#"  <testsuite name="{}" tests="{}" failures="{}" errors="{}" time="{:.3}">"#,
+            xml_escape(function),
+            summary.total,
+            summary.failed,
+            summary.errors,
+            time.as_secs_f64()
+        );
+        for test in tests {
+            let _ = write!(
+                xml,
+                r#"    <testcase classname="{}" name="{}" time="{:.3}""#,
+                xml_escape(&test.function),
+                xml_escape(&test.test),
+                test.duration.as_secs_f64()
+            );
+            let message = test.message.as_deref().unwrap_or_default();
+            let body = match test.status {
+                Outcome::Passed => None,
+                Outcome::NeedsHumanEval => {
+                    Some(format!("<system-out>{}</system-out>", xml_escape(message)))
+                }
+                Outcome::Failed | Outcome::Error => {
+                    let tag = if test.status == Outcome::Failed {
+                        "failure"
+                    } else {
+                        "error"
+                    };
+                    let summary = message.lines().next().unwrap_or_default();
+                    Some(format!(
+                        r#"<{tag} message="{}">{}</{tag}>"#,
+                        xml_escape(summary),
+                        xml_escape(message)
+                    ))
+                }
+            };
+            match body {
+                Some(body) => {
+                    let _ = writeln!(xml, ">\n      {body}\n    </testcase>");
+                }
+                None => {
+                    let _ = writeln!(xml, " />");
+                }
+            }
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+fn xml_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // XML 1.0 can't carry most control characters, even escaped.
+            c if c.is_control() && !matches!(c, '\n' | '\r' | '\t') => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn serialize_millis<S: serde::Serializer>(duration: &Duration, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_u128(duration.as_millis())
+}
+
+/// Patterns look like `Function::Test`, where either half may use `*`
+/// wildcards. A pattern without `::` matches either the function or the test.
+fn pattern_matches(pattern: &str, function: &str, test: &str) -> bool {
+    match pattern.split_once("::") {
+        Some((function_pattern, test_pattern)) => {
+            wildcard_matches(function_pattern, function) && wildcard_matches(test_pattern, test)
+        }
+        None => wildcard_matches(pattern, function) || wildcard_matches(pattern, test),
+    }
+}
+
+pub(super) fn wildcard_matches(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let Some(mut rest) = text.strip_prefix(parts.next().unwrap_or_default()) else {
+        return false;
+    };
+    let parts = parts.collect::<Vec<_>>();
+    let Some((last, middle)) = parts.split_last() else {
+        // No wildcard at all.
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(i) => rest = &rest[i + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn wildcards() {
+        assert!(wildcard_matches("ExtractResume", "ExtractResume"));
+        assert!(!wildcard_matches("ExtractResume", "ExtractResumes"));
+        assert!(wildcard_matches("*", ""));
+        assert!(wildcard_matches("Extract*", "ExtractResume"));
+        assert!(wildcard_matches("*Resume", "ExtractResume"));
+        assert!(wildcard_matches("E*t*e", "ExtractResume"));
+        assert!(!wildcard_matches("E*x*x", "ExtractResume"));
+        assert!(!wildcard_matches("ab*b", "ab"));
+    }
+
+    #[test]
+    fn patterns() {
+        assert!(pattern_matches("Extract*::*", "ExtractResume", "vaibhav"));
+        assert!(!pattern_matches(
+            "Extract*::sam",
+            "ExtractResume",
+            "vaibhav"
+        ));
+        assert!(pattern_matches("vaib*", "ExtractResume", "vaibhav"));
+        assert!(pattern_matches("ExtractResume", "ExtractResume", "vaibhav"));
+        assert!(!pattern_matches("Classify", "ExtractResume", "vaibhav"));
+    }
+
+    #[test]
+    fn junit_report() {
+        let result = |test: &str, status, message: Option<&str>| TestResult {
+            function: "ExtractResume".to_string(),
+            test: test.to_string(),
+            status,
+            message: message.map(str::to_string),
+            duration: Duration::from_millis(1500),
+        };
+        let results = vec![
+            result("vaibhav", Outcome::Passed, None),
+            result(
+                "sam",
+                Outcome::Failed,
+                Some("Failed assertion: this.name|length < 3"),
+            ),
+        ];
+
+        assert_eq!(
+            render_junit(&results, Duration::from_secs(2)),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="baml" tests="2" failures="1" errors="0" time="2.000">
+  <testsuite name="ExtractResume" tests="2" failures="1" errors="0" time="3.000">
+    <testcase classname="ExtractResume" name="vaibhav" time="1.500" />
+    <testcase classname="ExtractResume" name="sam" time="1.500">
+      <failure message="Failed assertion: this.name|length &lt; 3">Failed assertion: this.name|length &lt; 3</failure>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#
+        );
+    }
+}
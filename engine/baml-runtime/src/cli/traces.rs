@@ -0,0 +1,196 @@
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+
+use crate::{
+    internal::llm_client::LLMResponse,
+    tracing::{
+        api_wrapper::core_types::{IOValue, LogSchema, TypeSchemaName, ValueType},
+        trace_files::{self, llm_requests},
+        Visualize,
+    },
+};
+
+#[derive(clap::Args, Debug)]
+pub struct TracesArgs {
+    #[arg(
+        long,
+        help = "The directory the traces were written to. Defaults to $BAML_TRACE_DIR, then ./.baml_traces"
+    )]
+    dir: Option<PathBuf>,
+    #[arg(
+        long,
+        short,
+        help = r#"Only show calls to functions matching this pattern, which may use "*" wildcards. Can be repeated."#
+    )]
+    function: Vec<String>,
+    #[arg(
+        long,
+        help = "Only show events with this tag, as key=value. Can be repeated."
+    )]
+    tag: Vec<String>,
+    #[arg(long, help = "Only show events that failed", default_value_t = false)]
+    errors: bool,
+    #[arg(
+        long,
+        help = "Only show the event with this id, or the events under it. A prefix of the id is enough."
+    )]
+    id: Option<String>,
+    #[arg(
+        long,
+        short = 'n',
+        help = "How many of the most recent events to show",
+        default_value = "20"
+    )]
+    limit: usize,
+    #[arg(
+        long,
+        help = "Print the prompts, raw responses and parsed outputs of each event",
+        default_value_t = false
+    )]
+    full: bool,
+}
+
+impl TracesArgs {
+    pub fn run(&self) -> Result<()> {
+        let dir = self
+            .dir
+            .clone()
+            .or_else(|| std::env::var_os("BAML_TRACE_DIR").map(PathBuf::from))
+            .unwrap_or_else(|| PathBuf::from(trace_files::DEFAULT_DIR));
+        if !dir.exists() {
+            anyhow::bail!(
+                "No traces found in {}. Set BAML_TRACE_DIR when running your app to write them.",
+                dir.display()
+            );
+        }
+
+        let tags = self
+            .tag
+            .iter()
+            .map(|tag| {
+                tag.split_once('=')
+                    .with_context(|| format!("Expected --tag key=value, got {tag:?}"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let events = trace_files::read_events(&dir)?
+            .into_iter()
+            .filter(|event| {
+                let function = event
+                    .context
+                    .event_chain
+                    .last()
+                    .map_or("", |chain| chain.function_name.as_str());
+                (self.function.is_empty()
+                    || self
+                        .function
+                        .iter()
+                        .any(|pattern| super::test::wildcard_matches(pattern, function)))
+                    && tags.iter().all(|(key, value)| {
+                        event.context.tags.get(*key).map(String::as_str) == Some(*value)
+                    })
+                    && (!self.errors || event.error.is_some())
+                    && match &self.id {
+                        Some(id) => {
+                            event.event_id.starts_with(id.as_str())
+                                || event.root_event_id.starts_with(id.as_str())
+                        }
+                        None => true,
+                    }
+            })
+            .collect::<Vec<_>>();
+
+        for event in &events[events.len().saturating_sub(self.limit)..] {
+            if self.full {
+                print_full(event);
+            } else {
+                print_summary(event);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn print_summary(event: &LogSchema) {
+    let status = match event.error {
+        Some(_) => "ERROR".red(),
+        None => "OK   ".green(),
+    };
+    let chain = event
+        .context
+        .event_chain
+        .iter()
+        .map(|chain| chain.function_name.as_str())
+        .collect::<Vec<_>>()
+        .join(" > ");
+    let tokens = llm_requests(event)
+        .iter()
+        .filter_map(|request| request.output.as_ref()?.metadata.total_tokens)
+        .reduce(|a, b| a + b)
+        .map(|tokens| format!(" {tokens} tokens"))
+        .unwrap_or_default();
+    println!(
+        "{} {} {} {} {}",
+        event.context.start_time.dimmed(),
+        status.bold(),
+        chain,
+        format!("({}ms{})", event.context.latency_ms, tokens).dimmed(),
+        event.event_id.dimmed()
+    );
+}
+
+fn print_full(event: &LogSchema) {
+    let name = event
+        .context
+        .event_chain
+        .last()
+        .map_or("<unknown>", |chain| chain.function_name.as_str());
+    println!(
+        "{} {}",
+        format!("Function {name}:").purple(),
+        format!("({}, {})", event.context.start_time, event.event_id).dimmed()
+    );
+    for request in llm_requests(event) {
+        println!("{}", LLMResponse::from(request).visualize(0));
+    }
+    match (&event.error, &event.io.output) {
+        (Some(error), _) => {
+            println!(
+                "{}",
+                format!("---Parsed Response ({})---", "Error".red()).blue()
+            );
+            println!("{}", error.message.red());
+        }
+        (None, Some(output)) => {
+            println!("{}", "---Parsed Response---".blue());
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&output_json(output)).unwrap_or_default()
+            );
+        }
+        (None, None) => {}
+    }
+    println!();
+}
+
+/// The logged output as JSON. Each value is logged as its own JSON string.
+fn output_json(output: &IOValue) -> serde_json::Value {
+    let parse = |value: &String| {
+        serde_json::from_str(value).unwrap_or_else(|_| serde_json::Value::String(value.clone()))
+    };
+    match (&output.r#type.name, &output.value) {
+        (TypeSchemaName::Multi, ValueType::List(values)) => serde_json::Value::Object(
+            output
+                .r#type
+                .fields
+                .keys()
+                .cloned()
+                .zip(values.iter().map(parse))
+                .collect(),
+        ),
+        (_, ValueType::String(value)) => parse(value),
+        (_, ValueType::List(values)) => values.iter().map(parse).collect(),
+    }
+}
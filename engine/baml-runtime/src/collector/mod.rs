@@ -0,0 +1,421 @@
+// Records everything that happens while a function is called, for debugging.
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::Result;
+use serde::Serialize;
+use web_time::{Duration, SystemTime};
+
+use crate::{
+    internal::llm_client::{
+        cassette::{recorded_headers, RecordedRequest, RecordedResponse},
+        orchestrator::OrchestrationScope,
+        LLMResponse,
+    },
+    FunctionResult,
+};
+
+/// Keeps a log of every call it's passed to, like a `TypeBuilder` or a
+/// `ClientRegistry` is: each attempt with its HTTP request and response, the
+/// chunks of streamed responses, parse errors and the time spent waiting
+/// between retries.
+#[derive(Clone, Debug, Default)]
+pub struct Collector {
+    calls: Arc<Mutex<Vec<Arc<Mutex<FunctionLog>>>>>,
+}
+
+impl Collector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The calls made with this collector, oldest first. Calls that haven't
+    /// finished are included as far as they got.
+    pub fn calls(&self) -> Vec<FunctionLog> {
+        self.calls
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|call| call.lock().unwrap().clone())
+            .collect()
+    }
+
+    pub fn last(&self) -> Option<FunctionLog> {
+        let calls = self.calls.lock().unwrap();
+        let last = calls.last()?.lock().unwrap().clone();
+        Some(last)
+    }
+
+    pub fn clear(&self) {
+        self.calls.lock().unwrap().clear();
+    }
+
+    pub(crate) fn start_call(&self, function_name: &str) -> CallLog {
+        let log = Arc::new(Mutex::new(FunctionLog {
+            function_name: function_name.to_string(),
+            start_time_ms: unix_ms(SystemTime::now()),
+            latency_ms: None,
+            attempts: vec![],
+            error: None,
+        }));
+        self.calls.lock().unwrap().push(log.clone());
+        CallLog { log }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct FunctionLog {
+    pub function_name: String,
+    pub start_time_ms: u64,
+    /// `None` until the call finishes.
+    pub latency_ms: Option<u64>,
+    pub attempts: Vec<AttemptLog>,
+    /// Why the call failed, if it did.
+    pub error: Option<String>,
+}
+
+/// One request to a client, whether it's the first one or a retry or fallback.
+#[derive(Clone, Debug, Serialize)]
+pub struct AttemptLog {
+    pub client: String,
+    /// The strategies and retries this attempt was made under.
+    pub scope: Vec<String>,
+    pub start_time_ms: u64,
+    pub latency_ms: Option<u64>,
+    /// What was sent to the provider. Headers aren't kept, as they hold API
+    /// keys. `None` when the response came from the client's cache, and for
+    /// AWS Bedrock, which is called through its SDK.
+    pub request: Option<RecordedRequest>,
+    pub response: Option<HttpResponseLog>,
+    /// The body of a streamed response, as it arrived.
+    pub chunks: Vec<StreamChunk>,
+    pub raw_output: Option<String>,
+    pub error: Option<String>,
+    /// Why `raw_output` couldn't be parsed into the function's return type.
+    pub parse_error: Option<String>,
+    /// How long the runtime waited after this attempt before making the next.
+    pub retry_sleep_ms: Option<u64>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct HttpResponseLog {
+    pub status: u16,
+    pub headers: BTreeMap<String, String>,
+    /// `None` for streams, whose body is in `chunks`.
+    pub body: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct StreamChunk {
+    pub time_ms: u64,
+    pub data: String,
+}
+
+/// The log of one call, shared by everything taking part in it. Attempts are
+/// made one after the other, so what's recorded belongs to the latest one.
+#[derive(Clone, Debug)]
+pub(crate) struct CallLog {
+    log: Arc<Mutex<FunctionLog>>,
+}
+
+impl CallLog {
+    pub fn start_attempt(&self, client: &str, scope: &OrchestrationScope) {
+        self.log.lock().unwrap().attempts.push(AttemptLog {
+            client: client.to_string(),
+            scope: scope.scope.iter().map(ToString::to_string).collect(),
+            start_time_ms: unix_ms(SystemTime::now()),
+            latency_ms: None,
+            request: None,
+            response: None,
+            chunks: vec![],
+            raw_output: None,
+            error: None,
+            parse_error: None,
+            retry_sleep_ms: None,
+        });
+    }
+
+    fn with_attempt(&self, f: impl FnOnce(&mut AttemptLog)) {
+        if let Some(attempt) = self.log.lock().unwrap().attempts.last_mut() {
+            f(attempt);
+        }
+    }
+
+    pub fn record_request(&self, request: &reqwest::Request) {
+        let request = RecordedRequest::from_reqwest(request);
+        self.with_attempt(|attempt| attempt.request = Some(request));
+    }
+
+    /// Records the status and headers of `response`, and its body. Complete
+    /// bodies are read before being handed back; streamed ones are recorded
+    /// chunk by chunk as the caller reads them.
+    pub async fn record_response(
+        &self,
+        response: reqwest::Response,
+        stream: bool,
+    ) -> Result<reqwest::Response> {
+        // Bodies can't be handed back once read in the browser
+        if stream || cfg!(target_arch = "wasm32") {
+            let log = HttpResponseLog {
+                status: response.status().as_u16(),
+                headers: recorded_headers(response.headers()),
+                body: None,
+            };
+            self.with_attempt(|attempt| attempt.response = Some(log));
+            return self.record_chunks(response);
+        }
+
+        let response = RecordedResponse::read(response).await?;
+        let log = HttpResponseLog {
+            status: response.status,
+            headers: response.headers.clone(),
+            body: Some(String::from_utf8_lossy(&response.body.bytes()?).into_owned()),
+        };
+        self.with_attempt(|attempt| attempt.response = Some(log));
+        response.into_reqwest()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn record_chunks(&self, response: reqwest::Response) -> Result<reqwest::Response> {
+        use futures::StreamExt;
+
+        let mut http = http::Response::builder().status(response.status());
+        if let Some(headers) = http.headers_mut() {
+            *headers = response.headers().clone();
+        }
+        let log = self.clone();
+        let body = response.bytes_stream().inspect(move |chunk| {
+            if let Ok(chunk) = chunk {
+                let chunk = StreamChunk {
+                    time_ms: unix_ms(SystemTime::now()),
+                    data: String::from_utf8_lossy(chunk).into_owned(),
+                };
+                log.with_attempt(|attempt| attempt.chunks.push(chunk));
+            }
+        });
+        Ok(http.body(reqwest::Body::wrap_stream(body))?.into())
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn record_chunks(&self, response: reqwest::Response) -> Result<reqwest::Response> {
+        Ok(response)
+    }
+
+    pub fn finish_attempt(&self, response: &LLMResponse, parse_error: Option<String>) {
+        self.with_attempt(|attempt| {
+            match response {
+                LLMResponse::Success(s) => {
+                    attempt.latency_ms = Some(s.latency.as_millis() as u64);
+                    attempt.raw_output = Some(s.content.clone());
+                }
+                LLMResponse::LLMFailure(e) => {
+                    attempt.latency_ms = Some(e.latency.as_millis() as u64);
+                    attempt.error = Some(e.message.clone());
+                }
+                LLMResponse::UserFailure(e) | LLMResponse::InternalFailure(e) => {
+                    attempt.error = Some(e.clone());
+                }
+            }
+            attempt.parse_error = parse_error;
+        });
+    }
+
+    pub fn cancel_attempt(&self, latency: Duration) {
+        self.with_attempt(|attempt| {
+            attempt.latency_ms = Some(latency.as_millis() as u64);
+            attempt.error = Some(crate::errors::ExposedError::Cancelled.to_string());
+        });
+    }
+
+    pub fn record_retry_sleep(&self, duration: Duration) {
+        self.with_attempt(|attempt| attempt.retry_sleep_ms = Some(duration.as_millis() as u64));
+    }
+
+    pub fn finish(&self, result: &Result<FunctionResult>) {
+        let mut log = self.log.lock().unwrap();
+        log.latency_ms = Some(unix_ms(SystemTime::now()).saturating_sub(log.start_time_ms));
+        log.error = match result {
+            Ok(result) => result
+                .result_with_constraints_content()
+                .err()
+                .map(|e| format!("{:#}", e)),
+            Err(e) => Some(format!("{:#}", e)),
+        };
+    }
+}
+
+fn unix_ms(time: SystemTime) -> u64 {
+    time.duration_since(web_time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use baml_types::{BamlMap, BamlValue};
+
+    use super::*;
+    use crate::{
+        test_server::{completion, http, serve},
+        BamlRuntime,
+    };
+
+    fn runtime(port: u16) -> BamlRuntime {
+        let files = HashMap::from([(
+            "main.baml".to_string(),
+            format!(
+                r##"
+                retry_policy Once {{
+                  max_retries 1
+                  strategy {{
+                    type constant_delay
+                    delay_ms 10
+                  }}
+                }}
+
+                client<llm> Local {{
+                  provider openai-generic
+                  retry_policy Once
+                  options {{
+                    base_url "http://127.0.0.1:{port}"
+                    model "gpt-4o"
+                  }}
+                }}
+
+                function Count(input: string) -> int {{
+                  client Local
+                  prompt #"{{{{ input }}}}"#
+                }}
+
+                function Echo(input: string) -> string {{
+                  client Local
+                  prompt #"{{{{ input }}}}"#
+                }}
+                "##
+            ),
+        )]);
+        BamlRuntime::from_file_content(".", &files, HashMap::<&str, &str>::new()).unwrap()
+    }
+
+    fn params() -> BamlMap<String, BamlValue> {
+        BamlMap::from([("input".to_string(), BamlValue::String("hi".into()))])
+    }
+
+    #[test]
+    fn retries_and_parse_errors_are_logged() {
+        let port = serve(vec![
+            vec![http(
+                "500 Internal Server Error",
+                "application/json",
+                r#"{"error": {"message": "overloaded"}}"#,
+            )],
+            vec![http(
+                "200 OK",
+                "application/json",
+                &completion("not a number"),
+            )],
+        ]);
+        let runtime = runtime(port);
+        let ctx = runtime.create_ctx_manager(BamlValue::String("test".into()), None);
+        let collector = Collector::new();
+
+        let (result, _) = runtime.call_function_sync(
+            "Count".into(),
+            &params(),
+            &ctx,
+            None,
+            None,
+            Some(&collector),
+            None,
+        );
+        assert!(result.is_ok_and(|r| r.result_with_constraints_content().is_err()));
+
+        let call = collector.last().unwrap();
+        assert_eq!(call.function_name, "Count");
+        assert!(call.error.is_some());
+        assert!(call.latency_ms.is_some());
+        let [failed, parsed] = call.attempts.as_slice() else {
+            panic!("Expected two attempts, got {}", call.attempts.len());
+        };
+
+        assert_eq!(failed.client, "Local");
+        let request = failed.request.as_ref().unwrap();
+        assert_eq!(request.method, "POST");
+        assert!(request.url.ends_with("/chat/completions"));
+        assert_eq!(failed.response.as_ref().unwrap().status, 500);
+        assert!(failed.error.is_some());
+        assert_eq!(failed.retry_sleep_ms, Some(10));
+
+        let response = parsed.response.as_ref().unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(
+            response.body.as_deref(),
+            Some(completion("not a number").as_str())
+        );
+        assert_eq!(parsed.raw_output.as_deref(), Some("not a number"));
+        assert!(parsed.parse_error.is_some());
+        assert_eq!(parsed.retry_sleep_ms, None);
+    }
+
+    #[test]
+    fn stream_chunks_are_logged() {
+        let event = |content: &str| {
+            let delta = serde_json::json!({
+                "id": "chatcmpl-1",
+                "object": "chat.completion.chunk",
+                "created": 0,
+                "model": "gpt-4o",
+                "choices": [{ "index": 0, "delta": { "content": content } }],
+            });
+            format!("data: {delta}\n\n")
+        };
+        let parts = vec![
+            "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nConnection: close\r\n\r\n"
+                .to_string(),
+            event("Hello"),
+            event(" world"),
+            "data: [DONE]\n\n".to_string(),
+        ];
+        let body = parts[1..].concat();
+        let runtime = runtime(serve(vec![parts]));
+        let ctx = runtime.create_ctx_manager(BamlValue::String("test".into()), None);
+        let collector = Collector::new();
+
+        let mut stream = runtime
+            .stream_function(
+                "Echo".into(),
+                &params(),
+                &ctx,
+                None,
+                None,
+                Some(&collector),
+                None,
+            )
+            .unwrap();
+        let (result, _) = stream.run_sync(None::<fn(FunctionResult)>, &ctx, None, None);
+        assert!(result.unwrap().result_with_constraints_content().is_ok());
+
+        let call = collector.last().unwrap();
+        assert_eq!(call.error, None);
+        let [attempt] = call.attempts.as_slice() else {
+            panic!("Expected one attempt, got {}", call.attempts.len());
+        };
+        let response = attempt.response.as_ref().unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, None);
+        assert!(attempt.chunks.len() > 1);
+        assert_eq!(
+            attempt
+                .chunks
+                .iter()
+                .map(|c| c.data.as_str())
+                .collect::<String>(),
+            body
+        );
+        assert_eq!(attempt.raw_output.as_deref(), Some("Hello world"));
+    }
+}
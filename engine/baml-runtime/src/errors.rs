@@ -5,6 +5,8 @@ pub enum ExposedError {
         raw_output: String,
         message: String,
     },
+    /// The caller cancelled the call before it finished
+    Cancelled,
 }
 
 impl std::error::Error for ExposedError {}
@@ -23,6 +25,7 @@ impl std::fmt::Display for ExposedError {
                     message, prompt, raw_output
                 )
             }
+            ExposedError::Cancelled => write!(f, "Cancelled by the caller"),
         }
     }
 }
@@ -0,0 +1,479 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::Result;
+use internal_baml_jinja::RenderedPrompt;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::{
+    tools::{ToolCall, ToolDefinition},
+    unquoted_bool, LLMCompleteResponse, LLMCompleteResponseMetadata,
+};
+
+const DEFAULT_MAX_ENTRIES: usize = 1000;
+const DEFAULT_DIR: &str = ".baml_cache";
+
+/// Where a client keeps the responses it got, as set through the `cache` client
+/// option.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ResponseCacheConfig {
+    /// Kept in memory for as long as the runtime lives, evicting the least
+    /// recently used response once `max_entries` are stored.
+    Memory { max_entries: usize },
+    /// One JSON file per response in `dir`, shared across runs.
+    Disk { dir: PathBuf },
+}
+
+impl ResponseCacheConfig {
+    /// Takes the `cache` option out of the client's options. It may be `true`
+    /// (in memory), `"memory"`, `"disk"`, or a map with a `type` and either
+    /// `max_entries` or `dir`.
+    pub fn from_properties(
+        properties: &mut HashMap<String, serde_json::Value>,
+    ) -> Result<Option<Self>> {
+        let config = match properties.remove("cache").map(unquoted_bool) {
+            None | Some(serde_json::Value::Bool(false)) => return Ok(None),
+            Some(serde_json::Value::Bool(true)) => serde_json::Map::new(),
+            Some(serde_json::Value::String(kind)) => {
+                [("type".to_string(), serde_json::Value::String(kind))]
+                    .into_iter()
+                    .collect()
+            }
+            Some(serde_json::Value::Object(config)) => config,
+            Some(_) => anyhow::bail!("cache must be true, false, \"memory\", \"disk\" or a map"),
+        };
+
+        for key in config.keys() {
+            if !matches!(key.as_str(), "type" | "max_entries" | "dir") {
+                anyhow::bail!(
+                    "Unknown cache option: {key}. Expected one of: type, max_entries, dir"
+                );
+            }
+        }
+
+        match config.get("type").map(|t| t.as_str()) {
+            None | Some(Some("memory")) => {
+                if config.contains_key("dir") {
+                    anyhow::bail!("cache.dir only applies to a \"disk\" cache");
+                }
+                let max_entries = match config.get("max_entries") {
+                    None => DEFAULT_MAX_ENTRIES,
+                    Some(v) => match v.as_u64() {
+                        Some(n) if n > 0 => n as usize,
+                        _ => anyhow::bail!("cache.max_entries must be a positive number"),
+                    },
+                };
+                Ok(Some(Self::Memory { max_entries }))
+            }
+            Some(Some("disk")) => {
+                if config.contains_key("max_entries") {
+                    anyhow::bail!("cache.max_entries only applies to a \"memory\" cache");
+                }
+                let dir = match config.get("dir") {
+                    None => DEFAULT_DIR,
+                    Some(v) => match v.as_str() {
+                        Some(dir) if !dir.is_empty() => dir,
+                        _ => anyhow::bail!("cache.dir must be a path"),
+                    },
+                };
+                Ok(Some(Self::Disk { dir: dir.into() }))
+            }
+            Some(_) => anyhow::bail!("cache.type must be \"memory\" or \"disk\""),
+        }
+    }
+
+    pub fn build(&self) -> Arc<dyn ResponseCache> {
+        match self {
+            Self::Memory { max_entries } => Arc::new(MemoryCache::new(*max_entries)),
+            Self::Disk { dir } => Arc::new(DirectoryCache::new(dir.clone())),
+        }
+    }
+}
+
+/// How calls made with a [`crate::RuntimeContext`] use the cache of clients
+/// that have one. Clients without a `cache` option are never cached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CacheMode {
+    /// Serve hits and store new responses.
+    #[default]
+    ReadWrite,
+    /// Serve hits, but don't store anything new.
+    ReadOnly,
+    /// Always call the provider, and overwrite what was stored.
+    Refresh,
+    /// Ignore the cache entirely.
+    Off,
+}
+
+impl CacheMode {
+    pub fn reads(&self) -> bool {
+        matches!(self, CacheMode::ReadWrite | CacheMode::ReadOnly)
+    }
+
+    pub fn writes(&self) -> bool {
+        matches!(self, CacheMode::ReadWrite | CacheMode::Refresh)
+    }
+}
+
+impl std::str::FromStr for CacheMode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "read_write" | "on" | "true" => Ok(CacheMode::ReadWrite),
+            "read_only" => Ok(CacheMode::ReadOnly),
+            "refresh" => Ok(CacheMode::Refresh),
+            "off" | "false" => Ok(CacheMode::Off),
+            _ => anyhow::bail!(
+                "Unknown cache mode: {s}. Expected one of: read_write, read_only, refresh, off"
+            ),
+        }
+    }
+}
+
+/// The parts of a response worth keeping. Everything else is either part of
+/// the key or describes the call that served it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedResponse {
+    pub model: String,
+    pub content: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tool_calls: Vec<ToolCall>,
+    pub metadata: LLMCompleteResponseMetadata,
+}
+
+impl From<&LLMCompleteResponse> for CachedResponse {
+    fn from(response: &LLMCompleteResponse) -> Self {
+        Self {
+            model: response.model.clone(),
+            content: response.content.clone(),
+            tool_calls: response.tool_calls.clone(),
+            metadata: response.metadata.clone(),
+        }
+    }
+}
+
+pub trait ResponseCache: Send + Sync {
+    fn get(&self, key: &str) -> Option<CachedResponse>;
+
+    fn put(&self, key: &str, response: CachedResponse);
+}
+
+/// Identifies a request: the same prompt sent to the same provider with the same
+/// options, tools and output schema gets the same key, across runs too.
+pub fn cache_key(
+    provider: &str,
+    request_options: &HashMap<String, serde_json::Value>,
+    prompt: &RenderedPrompt,
+    tools: &[ToolDefinition],
+    output_schema: Option<&serde_json::Value>,
+) -> String {
+    let request = serde_json::json!({
+        "provider": provider,
+        "request_options": request_options,
+        "prompt": prompt,
+        "tools": tools,
+        "output_schema": output_schema,
+    });
+    let digest = Sha256::digest(canonical(request).to_string().as_bytes());
+    digest.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Sorts the keys of every object, so that the key doesn't depend on the order
+/// options happen to be in.
+fn canonical(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => map
+            .into_iter()
+            .map(|(k, v)| (k, canonical(v)))
+            .collect::<BTreeMap<_, _>>()
+            .into_iter()
+            .collect(),
+        serde_json::Value::Array(items) => items.into_iter().map(canonical).collect(),
+        other => other,
+    }
+}
+
+/// An in-memory LRU cache.
+pub struct MemoryCache {
+    max_entries: usize,
+    inner: Mutex<MemoryCacheInner>,
+}
+
+#[derive(Default)]
+struct MemoryCacheInner {
+    // Ticks when each entry was last used, so the oldest one can be evicted.
+    tick: u64,
+    entries: HashMap<String, (u64, CachedResponse)>,
+    by_use: BTreeMap<u64, String>,
+}
+
+impl MemoryCache {
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            max_entries,
+            inner: Default::default(),
+        }
+    }
+}
+
+impl MemoryCacheInner {
+    fn touch(&mut self, key: &str) -> Option<&CachedResponse> {
+        self.tick += 1;
+        let tick = self.tick;
+        let (last_used, response) = self.entries.get_mut(key)?;
+        self.by_use.remove(last_used);
+        self.by_use.insert(tick, key.to_string());
+        *last_used = tick;
+        Some(response)
+    }
+}
+
+impl ResponseCache for MemoryCache {
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        self.inner.lock().unwrap().touch(key).cloned()
+    }
+
+    fn put(&self, key: &str, response: CachedResponse) {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.touch(key).is_some() {
+            inner
+                .entries
+                .entry(key.to_string())
+                .and_modify(|(_, r)| *r = response);
+            return;
+        }
+        while inner.entries.len() >= self.max_entries {
+            let Some((_, oldest)) = inner.by_use.pop_first() else {
+                break;
+            };
+            inner.entries.remove(&oldest);
+        }
+        let tick = inner.tick;
+        inner.by_use.insert(tick, key.to_string());
+        inner.entries.insert(key.to_string(), (tick, response));
+    }
+}
+
+/// Stores each response as `<dir>/<key>.json`, so that they can be checked in or
+/// shared between CI runs.
+pub struct DirectoryCache {
+    dir: PathBuf,
+}
+
+impl DirectoryCache {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.json"))
+    }
+
+    fn write(&self, key: &str, response: &CachedResponse) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        // Write next to the final file and rename, so that concurrent readers
+        // never see half a response.
+        let tmp = self.dir.join(format!("{key}.{}.tmp", uuid::Uuid::new_v4()));
+        std::fs::write(&tmp, serde_json::to_vec_pretty(response)?)?;
+        std::fs::rename(&tmp, self.path(key))?;
+        Ok(())
+    }
+}
+
+impl ResponseCache for DirectoryCache {
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        let content = std::fs::read(self.path(key)).ok()?;
+        match serde_json::from_slice(&content) {
+            Ok(response) => Some(response),
+            Err(e) => {
+                log::warn!(
+                    "Ignoring unreadable cached response {}: {}",
+                    self.path(key).display(),
+                    e
+                );
+                None
+            }
+        }
+    }
+
+    fn put(&self, key: &str, response: CachedResponse) {
+        if let Err(e) = self.write(key, &response) {
+            log::warn!("Failed to cache response in {}: {}", self.dir.display(), e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn response(content: &str) -> CachedResponse {
+        CachedResponse {
+            model: "gpt-4o".into(),
+            content: content.into(),
+            tool_calls: vec![],
+            metadata: LLMCompleteResponseMetadata {
+                baml_is_complete: true,
+                finish_reason: Some("stop".into()),
+                prompt_tokens: None,
+                output_tokens: None,
+                total_tokens: None,
+                cost: None,
+            },
+        }
+    }
+
+    fn config(value: serde_json::Value) -> Result<Option<ResponseCacheConfig>> {
+        let mut properties = HashMap::from([("cache".to_string(), value)]);
+        let config = ResponseCacheConfig::from_properties(&mut properties);
+        assert!(properties.is_empty());
+        config
+    }
+
+    #[test]
+    fn cache_option_forms() {
+        assert_eq!(config(json!(false)).unwrap(), None);
+        assert_eq!(config(json!("false")).unwrap(), None);
+        assert_eq!(
+            config(json!("true")).unwrap(),
+            Some(ResponseCacheConfig::Memory { max_entries: 1000 })
+        );
+        assert_eq!(
+            config(json!(true)).unwrap(),
+            Some(ResponseCacheConfig::Memory { max_entries: 1000 })
+        );
+        assert_eq!(
+            config(json!("disk")).unwrap(),
+            Some(ResponseCacheConfig::Disk {
+                dir: ".baml_cache".into()
+            })
+        );
+        assert_eq!(
+            config(json!({"type": "memory", "max_entries": 5})).unwrap(),
+            Some(ResponseCacheConfig::Memory { max_entries: 5 })
+        );
+        assert!(config(json!("redis")).is_err());
+        assert!(config(json!({"type": "memory", "dir": "x"})).is_err());
+        assert!(config(json!({"max_entries": 0})).is_err());
+    }
+
+    #[test]
+    fn least_recently_used_is_evicted() {
+        let cache = MemoryCache::new(2);
+        cache.put("a", response("a"));
+        cache.put("b", response("b"));
+        assert!(cache.get("a").is_some());
+        cache.put("c", response("c"));
+
+        assert!(cache.get("b").is_none());
+        assert_eq!(cache.get("a").unwrap().content, "a");
+        assert_eq!(cache.get("c").unwrap().content, "c");
+    }
+
+    #[test]
+    fn key_ignores_option_order() {
+        let prompt = RenderedPrompt::Completion("Hello".into());
+        let options = |pairs: &[(&str, serde_json::Value)]| {
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.clone()))
+                .collect::<HashMap<_, _>>()
+        };
+        let a = options(&[
+            ("model", json!("gpt-4o")),
+            ("extra", json!({"a": 1, "b": 2})),
+        ]);
+        let b = options(&[
+            ("extra", json!({"b": 2, "a": 1})),
+            ("model", json!("gpt-4o")),
+        ]);
+        assert_eq!(
+            cache_key("openai", &a, &prompt, &[], None),
+            cache_key("openai", &b, &prompt, &[], None)
+        );
+        assert_ne!(
+            cache_key("openai", &a, &prompt, &[], None),
+            cache_key("anthropic", &a, &prompt, &[], None)
+        );
+    }
+
+    #[test]
+    fn directory_cache_round_trips() {
+        let dir = std::env::temp_dir().join(format!("baml-cache-{}", uuid::Uuid::new_v4()));
+        let cache = DirectoryCache::new(dir.clone());
+        assert!(cache.get("key").is_none());
+        cache.put("key", response("hi"));
+        assert_eq!(cache.get("key").unwrap().content, "hi");
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn only_parsed_responses_are_cached() {
+        use crate::{
+            test_server::{completion, http, serve},
+            BamlRuntime,
+        };
+        use baml_types::{BamlMap, BamlValue};
+
+        // Once both are answered, a request that isn't served from the cache
+        // can't connect.
+        let port = serve(vec![
+            vec![http(
+                "200 OK",
+                "application/json",
+                &completion("not a number"),
+            )],
+            vec![http("200 OK", "application/json", &completion("42"))],
+        ]);
+        let files = HashMap::from([(
+            "main.baml".to_string(),
+            format!(
+                r##"
+                retry_policy OnParseFailure {{
+                  max_retries 1
+                  retry_on_parse_failure true
+                }}
+
+                client<llm> Local {{
+                  provider openai-generic
+                  retry_policy OnParseFailure
+                  options {{
+                    base_url "http://127.0.0.1:{port}"
+                    model "gpt-4o"
+                    cache true
+                  }}
+                }}
+
+                function Count(input: string) -> int {{
+                  client Local
+                  prompt #"{{{{ input }}}}"#
+                }}
+                "##
+            ),
+        )]);
+        let runtime =
+            BamlRuntime::from_file_content(".", &files, HashMap::<&str, &str>::new()).unwrap();
+        let ctx = runtime.create_ctx_manager(BamlValue::String("test".into()), None);
+        let params = BamlMap::from([("input".to_string(), BamlValue::String("hi".into()))]);
+
+        for _ in 0..2 {
+            let (result, _) =
+                runtime.call_function_sync("Count".into(), &params, &ctx, None, None, None, None);
+            let result = result.unwrap();
+            assert_eq!(
+                result
+                    .result_with_constraints_content()
+                    .unwrap()
+                    .clone()
+                    .value(),
+                BamlValue::Int(42)
+            );
+        }
+    }
+}
@@ -0,0 +1,310 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use anyhow::{Context, Result};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+const DEFAULT_DIR: &str = ".baml_cassettes";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Call providers as usual, and write every exchange to the cassette.
+    Record,
+    /// Answer every request from the cassette, without touching the network.
+    Replay,
+}
+
+/// Where provider HTTP exchanges are recorded to, or replayed from.
+///
+/// Each exchange is stored as `<dir>/<hash>.json`, where the hash covers the
+/// method, URL and body of the request. Request headers (and with them, API
+/// keys) are never written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cassette {
+    pub mode: CassetteMode,
+    pub dir: PathBuf,
+}
+
+impl Cassette {
+    pub fn new(mode: CassetteMode, dir: impl Into<PathBuf>) -> Self {
+        Self {
+            mode,
+            dir: dir.into(),
+        }
+    }
+
+    /// Reads `BAML_CASSETTE_MODE` (`record`, `replay` or `off`) and
+    /// `BAML_CASSETTE_DIR` (defaults to `.baml_cassettes`).
+    pub fn from_env(env: &std::collections::HashMap<String, String>) -> Result<Option<Self>> {
+        let mode = match env.get("BAML_CASSETTE_MODE").map(|m| m.to_lowercase()) {
+            None => return Ok(None),
+            Some(mode) => match mode.as_str() {
+                "" | "off" => return Ok(None),
+                "record" => CassetteMode::Record,
+                "replay" => CassetteMode::Replay,
+                _ => anyhow::bail!(
+                    "Unknown BAML_CASSETTE_MODE: {mode}. Expected one of: record, replay, off"
+                ),
+            },
+        };
+        if cfg!(target_arch = "wasm32") {
+            anyhow::bail!("BAML_CASSETTE_MODE is not supported in the browser");
+        }
+        let dir = env
+            .get("BAML_CASSETTE_DIR")
+            .filter(|d| !d.is_empty())
+            .map(String::as_str)
+            .unwrap_or(DEFAULT_DIR);
+        Ok(Some(Self::new(mode, dir)))
+    }
+
+    pub fn replaying(&self) -> bool {
+        self.mode == CassetteMode::Replay
+    }
+
+    fn path(&self, request: &RecordedRequest) -> PathBuf {
+        self.dir.join(format!("{}.json", request.key()))
+    }
+
+    /// Finds the recorded response to `request`.
+    pub fn replay(&self, request: &RecordedRequest) -> Result<RecordedResponse> {
+        let path = self.path(request);
+        let content = std::fs::read(&path).with_context(|| {
+            format!(
+                "No recording of {} {} in {} (looked for {}). Run with BAML_CASSETTE_MODE=record to record it",
+                request.method,
+                request.url,
+                self.dir.display(),
+                path.display()
+            )
+        })?;
+        let exchange: Exchange = serde_json::from_slice(&content)
+            .with_context(|| format!("Failed to read recording {}", path.display()))?;
+        Ok(exchange.response)
+    }
+
+    pub fn record(&self, request: RecordedRequest, response: RecordedResponse) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        let path = self.path(&request);
+        let exchange = Exchange { request, response };
+        std::fs::write(&path, serde_json::to_vec_pretty(&exchange)?)
+            .with_context(|| format!("Failed to write recording {}", path.display()))
+    }
+
+    /// The recorded response to a request made with `reqwest`.
+    pub fn replay_reqwest(&self, request: &RecordedRequest) -> Result<reqwest::Response> {
+        self.replay(request)?.into_reqwest()
+    }
+
+    /// Records the response to a request made with `reqwest`. Streams are read to
+    /// the end first, so the caller only gets the response once it's complete.
+    pub async fn record_reqwest(
+        &self,
+        request: RecordedRequest,
+        response: reqwest::Response,
+    ) -> Result<reqwest::Response> {
+        let response = RecordedResponse::read(response).await?;
+        self.record(request, response.clone())?;
+        response.into_reqwest()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Exchange {
+    request: RecordedRequest,
+    response: RecordedResponse,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub url: String,
+    #[serde(flatten)]
+    pub body: RecordedBody,
+}
+
+impl RecordedRequest {
+    pub fn new(method: &str, url: &str, body: &[u8]) -> Self {
+        Self {
+            method: method.to_string(),
+            url: url.to_string(),
+            body: RecordedBody::new(body),
+        }
+    }
+
+    /// Describes a request built for a provider. When the request goes through
+    /// the BAML proxy, the provider's own URL is used instead of the proxy's.
+    pub fn from_reqwest(req: &reqwest::Request) -> Self {
+        let url = req
+            .headers()
+            .get("baml-original-url")
+            .and_then(|u| u.to_str().ok())
+            .map(ToString::to_string)
+            .unwrap_or_else(|| req.url().to_string());
+        let body = req.body().and_then(|b| b.as_bytes()).unwrap_or_default();
+        Self::new(req.method().as_str(), &url, body)
+    }
+
+    pub fn key(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.method.as_bytes());
+        hasher.update(b" ");
+        hasher.update(self.url.as_bytes());
+        hasher.update(b"\n");
+        hasher.update(self.body.bytes().unwrap_or_default());
+        hasher
+            .finalize()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    pub headers: BTreeMap<String, String>,
+    #[serde(flatten)]
+    pub body: RecordedBody,
+}
+
+impl RecordedResponse {
+    pub async fn read(response: reqwest::Response) -> Result<Self> {
+        let status = response.status().as_u16();
+        let headers = recorded_headers(response.headers());
+        let body = response.bytes().await?;
+        Ok(Self {
+            status,
+            headers,
+            body: RecordedBody::new(&body),
+        })
+    }
+
+    pub fn into_http(self) -> Result<http::Response<Vec<u8>>> {
+        let mut response = http::Response::builder().status(self.status);
+        for (name, value) in &self.headers {
+            response = response.header(name, value);
+        }
+        Ok(response.body(self.body.bytes()?)?)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn into_reqwest(self) -> Result<reqwest::Response> {
+        Ok(reqwest::Response::from(self.into_http()?))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub(crate) fn into_reqwest(self) -> Result<reqwest::Response> {
+        anyhow::bail!("Cassettes are not supported in the browser")
+    }
+}
+
+/// Keeps the headers worth replaying, like `content-type` and rate limit hints.
+pub(crate) fn recorded_headers(headers: &http::HeaderMap) -> BTreeMap<String, String> {
+    headers
+        .iter()
+        .filter(|(name, _)| {
+            !matches!(
+                name.as_str(),
+                "set-cookie" | "content-length" | "transfer-encoding" | "connection"
+            )
+        })
+        .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+        .collect()
+}
+
+/// Bodies are kept as text when they are, so that recordings can be read and
+/// edited by hand, and as base64 otherwise (AWS event streams).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordedBody {
+    Body(String),
+    BodyBase64(String),
+}
+
+impl RecordedBody {
+    fn new(body: &[u8]) -> Self {
+        match std::str::from_utf8(body) {
+            Ok(text) => Self::Body(text.to_string()),
+            Err(_) => Self::BodyBase64(BASE64.encode(body)),
+        }
+    }
+
+    pub fn bytes(&self) -> Result<Vec<u8>> {
+        match self {
+            Self::Body(text) => Ok(text.as_bytes().to_vec()),
+            Self::BodyBase64(encoded) => Ok(BASE64.decode(encoded)?),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn env(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn mode_comes_from_env() {
+        assert_eq!(Cassette::from_env(&env(&[])).unwrap(), None);
+        assert_eq!(
+            Cassette::from_env(&env(&[("BAML_CASSETTE_MODE", "off")])).unwrap(),
+            None
+        );
+        assert_eq!(
+            Cassette::from_env(&env(&[
+                ("BAML_CASSETTE_MODE", "Replay"),
+                ("BAML_CASSETTE_DIR", "tests/cassettes")
+            ]))
+            .unwrap(),
+            Some(Cassette::new(CassetteMode::Replay, "tests/cassettes"))
+        );
+        assert!(Cassette::from_env(&env(&[("BAML_CASSETTE_MODE", "rewind")])).is_err());
+    }
+
+    #[test]
+    fn key_depends_on_request_only() {
+        let a = RecordedRequest::new("POST", "https://api.openai.com/v1/chat", b"{}");
+        let b = RecordedRequest::new("POST", "https://api.openai.com/v1/chat", b"{}");
+        let c = RecordedRequest::new("POST", "https://api.openai.com/v1/chat", b"{ }");
+        assert_eq!(a.key(), b.key());
+        assert_ne!(a.key(), c.key());
+    }
+
+    #[test]
+    fn binary_bodies_round_trip() {
+        let body = RecordedBody::new(&[0, 159, 146, 150]);
+        assert!(matches!(body, RecordedBody::BodyBase64(_)));
+        assert_eq!(body.bytes().unwrap(), vec![0, 159, 146, 150]);
+    }
+
+    #[test]
+    fn recordings_replay() {
+        let dir = std::env::temp_dir().join(format!("baml-cassette-{}", uuid::Uuid::new_v4()));
+        let cassette = Cassette::new(CassetteMode::Replay, &dir);
+        let request = RecordedRequest::new("POST", "https://example.com", b"hello");
+        assert!(cassette.replay(&request).is_err());
+
+        cassette
+            .record(
+                request.clone(),
+                RecordedResponse {
+                    status: 200,
+                    headers: [("content-type".to_string(), "text/plain".to_string())].into(),
+                    body: RecordedBody::new(b"world"),
+                },
+            )
+            .unwrap();
+        let response = cassette.replay(&request).unwrap().into_http().unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.body(), b"world");
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,234 @@
+use std::sync::Mutex;
+
+use internal_baml_core::internal_baml_parser_database::CircuitBreakerConfig;
+use serde::Serialize;
+use web_time::{Duration, Instant};
+
+use super::{ErrorCode, LLMResponse};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+    /// Requests go through as usual.
+    Closed,
+    /// Requests are skipped until `reset_timeout_ms` has passed.
+    Open,
+    /// A single trial request is let through to decide whether to close again.
+    HalfOpen,
+}
+
+#[derive(Debug)]
+struct BreakerState {
+    state: CircuitState,
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    trial_started_at: Option<Instant>,
+}
+
+/// Tracks the health of a single client across every call made by the runtime.
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    pub client_name: String,
+    config: CircuitBreakerConfig,
+    state: Mutex<BreakerState>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CircuitBreakerStatus {
+    pub client: String,
+    pub state: CircuitState,
+    pub consecutive_failures: u32,
+    pub failure_threshold: u32,
+    pub reset_timeout_ms: u32,
+    /// How long until an open circuit lets a trial request through.
+    pub retry_in_ms: Option<u128>,
+}
+
+impl CircuitBreaker {
+    pub fn new(client_name: impl Into<String>, config: CircuitBreakerConfig) -> Self {
+        Self {
+            client_name: client_name.into(),
+            config,
+            state: Mutex::new(BreakerState {
+                state: CircuitState::Closed,
+                consecutive_failures: 0,
+                opened_at: None,
+                trial_started_at: None,
+            }),
+        }
+    }
+
+    fn reset_timeout(&self) -> Duration {
+        Duration::from_millis(self.config.reset_timeout_ms as u64)
+    }
+
+    /// Whether a request to this client should be made right now.
+    ///
+    /// Once the reset timeout has passed, an open circuit moves to half-open and
+    /// lets exactly one trial request through.
+    pub fn allow_request(&self) -> bool {
+        let mut inner = self.state.lock().unwrap();
+        if !self.admits(&inner) {
+            return false;
+        }
+        if inner.state != CircuitState::Closed {
+            inner.state = CircuitState::HalfOpen;
+            inner.trial_started_at = Some(Instant::now());
+        }
+        true
+    }
+
+    /// Whether [`allow_request`](Self::allow_request) would let a request
+    /// through, without using up the trial request of a half-open circuit.
+    pub fn would_allow(&self) -> bool {
+        self.admits(&self.state.lock().unwrap())
+    }
+
+    fn admits(&self, inner: &BreakerState) -> bool {
+        match inner.state {
+            CircuitState::Closed => true,
+            CircuitState::Open => inner
+                .opened_at
+                .map_or(true, |t| t.elapsed() >= self.reset_timeout()),
+            // If the trial request never reported back (e.g. the prompt failed to
+            // render), don't stay stuck in half-open forever.
+            CircuitState::HalfOpen => inner
+                .trial_started_at
+                .map_or(true, |t| t.elapsed() >= self.reset_timeout()),
+        }
+    }
+
+    pub fn record_response(&self, response: &LLMResponse) {
+        let mut inner = self.state.lock().unwrap();
+        match response {
+            LLMResponse::Success(_) => {
+                inner.state = CircuitState::Closed;
+                inner.consecutive_failures = 0;
+                inner.opened_at = None;
+                inner.trial_started_at = None;
+            }
+            LLMResponse::LLMFailure(e) if is_breaker_failure(&e.code) => {
+                inner.consecutive_failures += 1;
+                if inner.state == CircuitState::HalfOpen
+                    || inner.consecutive_failures >= self.config.failure_threshold
+                {
+                    inner.state = CircuitState::Open;
+                    inner.opened_at = Some(Instant::now());
+                    inner.trial_started_at = None;
+                }
+            }
+            // Anything else says nothing about the health of the provider.
+            LLMResponse::LLMFailure(_)
+            | LLMResponse::UserFailure(_)
+            | LLMResponse::InternalFailure(_) => {}
+        }
+    }
+
+    pub fn status(&self) -> CircuitBreakerStatus {
+        let inner = self.state.lock().unwrap();
+        CircuitBreakerStatus {
+            client: self.client_name.clone(),
+            state: inner.state,
+            consecutive_failures: inner.consecutive_failures,
+            failure_threshold: self.config.failure_threshold,
+            reset_timeout_ms: self.config.reset_timeout_ms,
+            retry_in_ms: match (inner.state, inner.opened_at) {
+                (CircuitState::Open, Some(t)) => {
+                    Some(self.reset_timeout().saturating_sub(t.elapsed()).as_millis())
+                }
+                _ => None,
+            },
+        }
+    }
+}
+
+/// Errors that mean the provider itself is unhealthy or overloaded.
+fn is_breaker_failure(code: &ErrorCode) -> bool {
+    match code {
+        ErrorCode::RateLimited
+        | ErrorCode::ServerError
+        | ErrorCode::ServiceUnavailable
+        | ErrorCode::Timeout => true,
+        // 2 is what we use when the request couldn't be sent at all.
+        ErrorCode::Other(code) => *code == 2 || *code >= 500,
+        ErrorCode::InvalidAuthentication
+        | ErrorCode::NotSupported
+        | ErrorCode::UnsupportedResponse(_) => false,
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::internal::llm_client::LLMErrorResponse;
+    use std::collections::HashMap;
+
+    pub(crate) fn failure(code: ErrorCode) -> LLMResponse {
+        LLMResponse::LLMFailure(LLMErrorResponse {
+            client: "MyClient".into(),
+            model: None,
+            prompt: internal_baml_jinja::RenderedPrompt::Completion(String::new()),
+            request_options: HashMap::new(),
+            start_time: web_time::SystemTime::now(),
+            latency: Duration::from_millis(0),
+            message: "boom".into(),
+            code,
+            retry_after: None,
+        })
+    }
+
+    fn breaker(reset_timeout_ms: u32) -> CircuitBreaker {
+        CircuitBreaker::new(
+            "MyClient",
+            CircuitBreakerConfig {
+                failure_threshold: 2,
+                reset_timeout_ms,
+            },
+        )
+    }
+
+    #[test]
+    fn opens_after_threshold() {
+        let b = breaker(60_000);
+        b.record_response(&failure(ErrorCode::ServerError));
+        assert!(b.allow_request());
+        b.record_response(&failure(ErrorCode::RateLimited));
+        assert_eq!(b.status().state, CircuitState::Open);
+        assert!(!b.allow_request());
+    }
+
+    #[test]
+    fn ignores_client_errors() {
+        let b = breaker(60_000);
+        b.record_response(&failure(ErrorCode::InvalidAuthentication));
+        b.record_response(&failure(ErrorCode::Other(400)));
+        assert_eq!(b.status().state, CircuitState::Closed);
+        assert_eq!(b.status().consecutive_failures, 0);
+    }
+
+    #[test]
+    fn half_open_trial_reopens_on_failure() {
+        let b = breaker(0);
+        b.record_response(&failure(ErrorCode::ServerError));
+        b.record_response(&failure(ErrorCode::ServerError));
+        assert!(b.allow_request());
+        assert_eq!(b.status().state, CircuitState::HalfOpen);
+        b.record_response(&failure(ErrorCode::ServiceUnavailable));
+        assert_eq!(b.status().state, CircuitState::Open);
+    }
+
+    #[test]
+    fn would_allow_leaves_the_trial_alone() {
+        let b = breaker(0);
+        b.record_response(&failure(ErrorCode::ServerError));
+        b.record_response(&failure(ErrorCode::ServerError));
+        assert!(b.would_allow());
+        assert!(b.would_allow());
+        assert_eq!(b.status().state, CircuitState::Open);
+
+        let b = breaker(60_000);
+        b.record_response(&failure(ErrorCode::ServerError));
+        b.record_response(&failure(ErrorCode::ServerError));
+        assert!(!b.would_allow());
+    }
+}
@@ -31,6 +31,15 @@ impl std::fmt::Debug for LLMProvider {
     }
 }
 
+impl LLMProvider {
+    pub fn name(&self) -> &str {
+        match self {
+            LLMProvider::Primitive(provider) => provider.name(),
+            LLMProvider::Strategy(provider) => provider.name(),
+        }
+    }
+}
+
 impl WithRetryPolicy for LLMProvider {
     fn retry_policy_name(&self) -> Option<&str> {
         match self {
@@ -45,7 +54,9 @@ impl TryFrom<(&ClientWalker<'_>, &RuntimeContext)> for LLMProvider {
 
     fn try_from((client, ctx): (&ClientWalker, &RuntimeContext)) -> Result<Self> {
         match client.elem().provider.as_str() {
-            "baml-fallback" | "fallback" | "baml-round-robin" | "round-robin" => {
+            "baml-fallback" | "fallback" | "baml-round-robin" | "round-robin" | "baml-weighted"
+            | "weighted" | "baml-least-latency" | "least-latency" | "baml-least-errors"
+            | "least-errors" => {
                 LLMStrategyProvider::try_from((client, ctx)).map(LLMProvider::Strategy)
             }
             _ => LLMPrimitiveProvider::try_from((client, ctx))
@@ -60,9 +71,9 @@ impl TryFrom<(&ClientProperty, &RuntimeContext)> for LLMProvider {
 
     fn try_from(value: (&ClientProperty, &RuntimeContext)) -> Result<Self> {
         match value.0.provider.as_str() {
-            "baml-fallback" | "fallback" | "baml-round-robin" | "round-robin" => {
-                LLMStrategyProvider::try_from(value).map(LLMProvider::Strategy)
-            }
+            "baml-fallback" | "fallback" | "baml-round-robin" | "round-robin" | "baml-weighted"
+            | "weighted" | "baml-least-latency" | "least-latency" | "baml-least-errors"
+            | "least-errors" => LLMStrategyProvider::try_from(value).map(LLMProvider::Strategy),
             _ => LLMPrimitiveProvider::try_from(value)
                 .map(Arc::new)
                 .map(LLMProvider::Primitive),
@@ -80,6 +91,11 @@ impl IterOrchestrator for Arc<LLMProvider> {
     ) -> Result<OrchestratorNodeIterator> {
         if let Some(retry_policy) = self.retry_policy_name() {
             let policy = client_lookup.get_retry_policy(retry_policy, ctx)?;
+            let retry_on_parse_failure = policy.retry_on_parse_failure();
+            let callable_policy = Arc::new(policy.clone());
+            let circuit_breaker = policy
+                .circuit_breaker()
+                .map(|config| client_lookup.get_circuit_breaker(self.name(), config));
             Ok(policy
                 .into_iter()
                 .enumerate()
@@ -89,6 +105,8 @@ impl IterOrchestrator for Arc<LLMProvider> {
                         .extend(ExecutionScope::Retry(retry_policy.into(), idx, node))
                 })
                 .map(|scope| {
+                    let circuit_breaker = circuit_breaker.clone();
+                    let callable_policy = callable_policy.clone();
                     // repeat the same provider for each retry policy
 
                     // We can pass in empty previous.
@@ -109,7 +127,12 @@ impl IterOrchestrator for Arc<LLMProvider> {
                     .map(|nodes| {
                         nodes
                             .into_iter()
-                            .map(move |node| node.prefix(scope.clone()))
+                            .map(move |node| {
+                                node.prefix(scope.clone())
+                                    .with_retry_on_parse_failure(retry_on_parse_failure)
+                                    .with_circuit_breaker(circuit_breaker.clone())
+                                    .with_retry_policy(callable_policy.clone())
+                            })
                             .collect::<Vec<_>>()
                     })
                 })
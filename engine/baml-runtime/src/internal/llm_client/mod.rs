@@ -1,18 +1,26 @@
 use std::collections::{HashMap, HashSet};
 
 use colored::*;
+pub mod cache;
+pub mod cassette;
+pub mod circuit_breaker;
 pub mod llm_provider;
 pub mod orchestrator;
 pub mod primitive;
 
 pub mod retry_policy;
 mod strategy;
+pub mod structured_output;
+pub mod tools;
 pub mod traits;
+pub mod usage;
 
 use anyhow::Result;
 
 use baml_types::{BamlValueWithMeta, Constraint, ConstraintLevel, ResponseCheck};
-use internal_baml_core::ir::ClientWalker;
+use internal_baml_core::{
+    internal_baml_parser_database::RETRY_POLICY_ERROR_CODES, ir::ClientWalker,
+};
 use internal_baml_jinja::RenderedPrompt;
 use jsonish::BamlValueWithFlags;
 use serde::{Deserialize, Serialize};
@@ -188,6 +196,9 @@ pub struct LLMErrorResponse {
     // Short error message
     pub message: String,
     pub code: ErrorCode,
+    // How long the provider asked us to wait before retrying (Retry-After and
+    // friends), if it said so
+    pub retry_after: Option<web_time::Duration>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -198,6 +209,9 @@ pub enum ErrorCode {
     ServerError,           // 500
     ServiceUnavailable,    // 503
 
+    // The provider didn't respond in time (see the `*_timeout_ms` client options)
+    Timeout,
+
     // We failed to parse the response
     UnsupportedResponse(u16),
 
@@ -213,11 +227,27 @@ impl ErrorCode {
             ErrorCode::RateLimited => "RateLimited (429)".into(),
             ErrorCode::ServerError => "ServerError (500)".into(),
             ErrorCode::ServiceUnavailable => "ServiceUnavailable (503)".into(),
+            ErrorCode::Timeout => "Timeout".into(),
             ErrorCode::UnsupportedResponse(code) => format!("BadResponse {}", code),
             ErrorCode::Other(code) => format!("Unspecified error code: {}", code),
         }
     }
 
+    /// How this error is referred to in a retry policy. The names are listed in
+    /// [`RETRY_POLICY_ERROR_CODES`], in the order of the variants.
+    pub fn name(&self) -> &'static str {
+        RETRY_POLICY_ERROR_CODES[match self {
+            ErrorCode::InvalidAuthentication => 0,
+            ErrorCode::NotSupported => 1,
+            ErrorCode::RateLimited => 2,
+            ErrorCode::ServerError => 3,
+            ErrorCode::ServiceUnavailable => 4,
+            ErrorCode::Timeout => 5,
+            ErrorCode::UnsupportedResponse(_) => 6,
+            ErrorCode::Other(_) => 7,
+        }]
+    }
+
     pub fn from_status(status: StatusCode) -> Self {
         match status.as_u16() {
             401 => ErrorCode::InvalidAuthentication,
@@ -247,6 +277,7 @@ impl ErrorCode {
             ErrorCode::RateLimited => 429,
             ErrorCode::ServerError => 500,
             ErrorCode::ServiceUnavailable => 503,
+            ErrorCode::Timeout => 408,
             ErrorCode::UnsupportedResponse(code) => *code,
             ErrorCode::Other(code) => *code,
         }
@@ -260,19 +291,28 @@ pub struct LLMCompleteResponse {
     pub prompt: RenderedPrompt,
     pub request_options: HashMap<String, serde_json::Value>,
     pub content: String,
+    /// The tool calls in the provider's response. `content` lists them too,
+    /// but only these are parsed as calls.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tool_calls: Vec<tools::ToolCall>,
     #[cfg_attr(target_arch = "wasm32", serde(skip_serializing))]
     pub start_time: web_time::SystemTime,
     pub latency: web_time::Duration,
     pub metadata: LLMCompleteResponseMetadata,
 }
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct LLMCompleteResponseMetadata {
     pub baml_is_complete: bool,
     pub finish_reason: Option<String>,
     pub prompt_tokens: Option<u64>,
     pub output_tokens: Option<u64>,
     pub total_tokens: Option<u64>,
+    /// Estimated cost in dollars, from the client's `pricing` or the price
+    /// table. `None` when the model has no known price or the provider didn't
+    /// report token counts.
+    #[serde(default)]
+    pub cost: Option<f64>,
 }
 
 impl std::fmt::Display for LLMCompleteResponse {
@@ -385,3 +425,14 @@ fn resolve_properties_walker(
         })
         .collect::<Result<std::collections::HashMap<_, _>>>()
 }
+
+/// Unquoted `true` and `false` in a client's options reach us as strings, so
+/// options that take a bool read them back as one.
+pub(crate) fn unquoted_bool(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::String(s) if s == "true" || s == "false" => {
+            serde_json::Value::Bool(s == "true")
+        }
+        value => value,
+    }
+}
@@ -7,7 +7,9 @@ use web_time::Duration;
 use crate::{
     internal::{
         llm_client::{
-            parsed_value_to_response, traits::{WithPrompt, WithSingleCallable}, LLMResponse, ResponseBamlValue
+            parsed_value_to_response,
+            traits::{WithPrompt, WithSingleCallable},
+            LLMCompleteResponse, LLMResponse, ResponseBamlValue,
         },
         prompt_renderer::PromptRenderer,
     },
@@ -22,7 +24,7 @@ pub async fn orchestrate(
     ctx: &RuntimeContext,
     prompt: &PromptRenderer,
     params: &BamlValue,
-    parse_fn: impl Fn(&str) -> Result<BamlValueWithFlags>,
+    parse_fn: impl Fn(&LLMCompleteResponse) -> Result<BamlValueWithFlags>,
 ) -> (
     Vec<(
         OrchestrationScope,
@@ -34,8 +36,34 @@ pub async fn orchestrate(
 ) {
     let mut results = Vec::new();
     let mut total_sleep_duration = std::time::Duration::from_secs(0);
+    // Set once a retry policy gives up on an error it doesn't retry.
+    let mut abandoned: Option<OrchestrationScope> = None;
+
+    let tools = prompt.tools();
+    let output_schema = prompt.output_schema();
 
     for node in iter {
+        if abandoned
+            .as_ref()
+            .map_or(false, |scope| node.scope.is_later_retry_of(scope))
+        {
+            continue;
+        }
+
+        if let Some(breaker) = node.open_circuit_breaker() {
+            let message = format!(
+                "Skipped: circuit breaker for client {} is open",
+                breaker.client_name
+            );
+            results.push((
+                node.scope,
+                LLMResponse::InternalFailure(message),
+                None,
+                None,
+            ));
+            continue;
+        }
+
         let prompt = match node.render_prompt(ir, prompt, ctx, params).await {
             Ok(p) => p,
             Err(e) => {
@@ -43,30 +71,83 @@ pub async fn orchestrate(
                 continue;
             }
         };
-        let response = node.single_call(&ctx, &prompt).await;
+        if let Some(log) = &ctx.collector {
+            log.start_attempt(node.provider.name(), &node.scope);
+        }
+        let (system_start, instant_start) = (web_time::SystemTime::now(), web_time::Instant::now());
+        let call = async {
+            match node.provider.timeouts().total {
+                Some(total) => {
+                    let call = node.single_call(&ctx, &prompt, tools, output_schema);
+                    match async_std::future::timeout(total, call).await {
+                        Ok(response) => response,
+                        Err(_) => node.timed_out(
+                            &prompt,
+                            system_start,
+                            instant_start.elapsed(),
+                            format!(
+                                "No response after {}ms (total_timeout_ms)",
+                                total.as_millis()
+                            ),
+                        ),
+                    }
+                }
+                None => node.single_call(&ctx, &prompt, tools, output_schema).await,
+            }
+        };
+        // Dropping the call closes its connection, so the provider stops generating.
+        let Some(mut response) = ctx.cancel.run(call).await else {
+            if let Some(log) = &ctx.collector {
+                log.cancel_attempt(instant_start.elapsed());
+            }
+            break;
+        };
+        node.price_response(ctx, &mut response);
+        node.record_response(&response);
+        if let Some(scope) = node.abandoned_retries(&response) {
+            abandoned = Some(scope);
+        }
         let parsed_response = match &response {
-            LLMResponse::Success(s) => Some(parse_fn(&s.content)),
+            LLMResponse::Success(s) => Some(parse_fn(s)),
             _ => None,
         };
 
-        let sleep_duration = node.error_sleep_duration().cloned();
+        let sleep_duration = node.error_sleep_duration(&response);
+        let retry_on_parse_failure = node.retry_on_parse_failure;
         let (parsed_response, response_with_constraints) = match parsed_response {
                 Some(Ok(v)) => (Some(Ok(v.clone())), Some(parsed_value_to_response(&v))),
                 Some(Err(e)) => (None, Some(Err(e))),
                 None => (None, None),
             };
+        if let Some(Ok(_)) = &response_with_constraints {
+            node.cache_response(ctx, &prompt, tools, output_schema, &response);
+        }
+        if let Some(log) = &ctx.collector {
+            let parse_error = match &response_with_constraints {
+                Some(Err(e)) => Some(format!("{:#}", e)),
+                _ => None,
+            };
+            log.finish_attempt(&response, parse_error);
+        }
         results.push((node.scope, response, parsed_response, response_with_constraints));
 
-        // Currently, we break out of the loop if an LLM responded, even if we couldn't parse the result.
-        if results
-            .last()
-            .map_or(false, |(_, r, _, _)| matches!(r, LLMResponse::Success(_)))
-        {
+        // We break out of the loop if an LLM responded, unless the retry policy asks us to
+        // keep going when the result couldn't be parsed (or failed an assert).
+        if results.last().map_or(false, |(_, r, _, v)| {
+            matches!(r, LLMResponse::Success(_))
+                && !(retry_on_parse_failure && matches!(v, Some(Err(_))))
+        }) {
             break;
         } else {
             if let Some(duration) = sleep_duration {
+                if let Some(log) = &ctx.collector {
+                    log.record_retry_sleep(duration);
+                }
                 total_sleep_duration += duration;
-                async_std::task::sleep(duration).await;
+                let slept = ctx.cancel.run(async_std::task::sleep(duration)).await;
+                if slept.is_none() {
+                    break;
+                }
             }
         }
     }
@@ -9,11 +9,15 @@ use crate::{
     RuntimeContext,
 };
 
+use super::cache::{cache_key, CacheMode, CachedResponse, ResponseCache};
+use super::circuit_breaker::CircuitBreaker;
+use super::retry_policy::CallablePolicy;
 use super::traits::WithRenderRawCurl;
 use super::{
-    strategy::roundrobin::RoundRobinStrategy,
+    strategy::{load_balance::LoadBalanceStrategy, roundrobin::RoundRobinStrategy},
+    tools::ToolDefinition,
     traits::{StreamResponse, WithPrompt, WithSingleCallable, WithStreamable},
-    LLMResponse,
+    ErrorCode, LLMCompleteResponse, LLMCompleteResponseMetadata, LLMErrorResponse, LLMResponse,
 };
 
 pub use super::primitive::LLMPrimitiveProvider;
@@ -30,6 +34,17 @@ use std::{collections::HashMap, sync::Arc};
 pub struct OrchestratorNode {
     pub scope: OrchestrationScope,
     pub provider: Arc<LLMPrimitiveProvider>,
+    /// Set when a retry policy in scope asks for responses that fail to parse
+    /// (or fail an `@assert`) to be treated like any other failed call.
+    pub retry_on_parse_failure: bool,
+    /// Circuit breakers of every client in scope; all of them must be closed
+    /// (or ready for a trial request) for this node to run.
+    pub circuit_breakers: Vec<Arc<CircuitBreaker>>,
+    /// The innermost retry policy in scope. It decides which errors are worth
+    /// retrying and how long to wait before doing so.
+    pub retry_policy: Option<Arc<CallablePolicy>>,
+    /// Where responses of this node's client are cached, if it has a `cache`.
+    pub cache: Option<Arc<dyn ResponseCache>>,
 }
 
 impl std::fmt::Display for ExecutionScope {
@@ -45,6 +60,9 @@ impl std::fmt::Display for ExecutionScope {
             ExecutionScope::Fallback(strategy, index) => {
                 write!(f, "Fallback({}, {})", strategy, index)
             }
+            ExecutionScope::LoadBalance(strategy, index) => {
+                write!(f, "{}({}, {})", strategy.kind, strategy.name, index)
+            }
         }
     }
 }
@@ -61,9 +79,15 @@ impl std::fmt::Display for OrchestratorNode {
 
 impl OrchestratorNode {
     pub fn new(scope: impl Into<OrchestrationScope>, provider: Arc<LLMPrimitiveProvider>) -> Self {
+        let mut scope = scope.into();
+        scope.provider = Some(provider.provider().to_string());
         OrchestratorNode {
-            scope: scope.into(),
+            scope,
             provider,
+            retry_on_parse_failure: false,
+            circuit_breakers: vec![],
+            retry_policy: None,
+            cache: None,
         }
     }
 
@@ -71,32 +95,251 @@ impl OrchestratorNode {
         OrchestratorNode {
             scope: self.scope.prefix_scopes(scope.into().scope),
             provider: self.provider.clone(),
+            retry_on_parse_failure: self.retry_on_parse_failure,
+            circuit_breakers: self.circuit_breakers.clone(),
+            retry_policy: self.retry_policy.clone(),
+            cache: self.cache.clone(),
         }
     }
 
-    pub fn error_sleep_duration(&self) -> Option<&Duration> {
-        // in reverse find the first retry scope, and return the delay
-        self.scope.scope.iter().rev().find_map(|scope| match scope {
-            ExecutionScope::Retry(_, _, delay) if !delay.is_zero() => Some(delay),
-            _ => None,
+    pub fn with_retry_on_parse_failure(mut self, retry_on_parse_failure: bool) -> OrchestratorNode {
+        self.retry_on_parse_failure |= retry_on_parse_failure;
+        self
+    }
+
+    pub fn with_circuit_breaker(
+        mut self,
+        breaker: Option<Arc<CircuitBreaker>>,
+    ) -> OrchestratorNode {
+        self.circuit_breakers.extend(breaker);
+        self
+    }
+
+    /// Only the innermost retry policy is kept, as it's the one whose retries
+    /// we're in the middle of.
+    pub fn with_retry_policy(mut self, policy: Arc<CallablePolicy>) -> OrchestratorNode {
+        self.retry_policy.get_or_insert(policy);
+        self
+    }
+
+    pub fn with_response_cache(
+        mut self,
+        cache: Option<Arc<dyn ResponseCache>>,
+    ) -> OrchestratorNode {
+        self.cache = cache;
+        self
+    }
+
+    /// Returns the first circuit breaker in scope that won't let this node run.
+    /// The breakers are only asked to let the request through, which takes the
+    /// trial request of a half-open one, once none of them would block it.
+    pub fn open_circuit_breaker(&self) -> Option<&Arc<CircuitBreaker>> {
+        if let Some(open) = self.circuit_breakers.iter().find(|b| !b.would_allow()) {
+            return Some(open);
+        }
+        self.circuit_breakers.iter().find(|b| !b.allow_request())
+    }
+
+    /// Lets any load balancing strategy or circuit breaker in scope learn from the
+    /// outcome of this node.
+    pub fn record_response(&self, response: &LLMResponse) {
+        self.scope
+            .scope
+            .iter()
+            .filter_map(|scope| match scope {
+                ExecutionScope::LoadBalance(a, index) => Some((a, *index)),
+                _ => None,
+            })
+            .for_each(|(a, index)| a.record_response(index, response));
+        self.circuit_breakers
+            .iter()
+            .for_each(|b| b.record_response(response));
+    }
+
+    /// Fills in the estimated cost of a successful response, from the client's
+    /// `pricing` option or else the runtime's price table.
+    pub fn price_response(&self, ctx: &RuntimeContext, response: &mut LLMResponse) {
+        let LLMResponse::Success(response) = response else {
+            return;
+        };
+        if response.metadata.cost.is_some() {
+            return;
+        }
+        let pricing = self
+            .provider
+            .pricing()
+            .or_else(|| ctx.price_table.get(&response.model));
+        response.metadata.cost = pricing.and_then(|p| p.cost(&response.metadata));
+    }
+
+    /// The response to report when this node ran out of time.
+    pub fn timed_out(
+        &self,
+        prompt: &RenderedPrompt,
+        start_time: web_time::SystemTime,
+        latency: Duration,
+        message: String,
+    ) -> LLMResponse {
+        LLMResponse::LLMFailure(LLMErrorResponse {
+            client: self.provider.name().into(),
+            model: None,
+            prompt: prompt.clone(),
+            start_time,
+            latency,
+            request_options: self.provider.request_options().clone(),
+            message,
+            code: ErrorCode::Timeout,
+            retry_after: None,
         })
     }
+
+    /// The client's cache and the key of the request, unless caching is off.
+    fn cache_entry(
+        &self,
+        ctx: &RuntimeContext,
+        prompt: &RenderedPrompt,
+        tools: &[ToolDefinition],
+        output_schema: Option<&serde_json::Value>,
+    ) -> Option<(&Arc<dyn ResponseCache>, String)> {
+        let cache = self
+            .cache
+            .as_ref()
+            .filter(|_| ctx.cache_mode != CacheMode::Off)?;
+        let key = cache_key(
+            self.provider.provider(),
+            self.provider.request_options(),
+            prompt,
+            tools,
+            output_schema,
+        );
+        Some((cache, key))
+    }
+
+    /// Stores a response that was parsed and passed its asserts. Responses that
+    /// didn't are left out, so that a retry asks the provider again, and a bad
+    /// answer isn't replayed on later runs.
+    pub fn cache_response(
+        &self,
+        ctx: &RuntimeContext,
+        prompt: &RenderedPrompt,
+        tools: &[ToolDefinition],
+        output_schema: Option<&serde_json::Value>,
+        response: &LLMResponse,
+    ) {
+        let LLMResponse::Success(s) = response else {
+            return;
+        };
+        if let Some((cache, key)) = self
+            .cache_entry(ctx, prompt, tools, output_schema)
+            .filter(|_| ctx.cache_mode.writes())
+        {
+            cache.put(&key, s.into());
+        }
+    }
+
+    /// The response to report when this node's answer came from its cache.
+    fn cached_response(&self, prompt: &RenderedPrompt, cached: CachedResponse) -> LLMResponse {
+        LLMResponse::Success(LLMCompleteResponse {
+            client: self.provider.name().into(),
+            model: cached.model,
+            prompt: prompt.clone(),
+            request_options: self.provider.request_options().clone(),
+            content: cached.content,
+            tool_calls: cached.tool_calls,
+            start_time: web_time::SystemTime::now(),
+            latency: Duration::ZERO,
+            // Nothing was spent on a cached answer
+            metadata: LLMCompleteResponseMetadata {
+                cost: Some(0.0),
+                ..cached.metadata
+            },
+        })
+    }
+
+    /// If the innermost retry policy doesn't retry the error in `response`, returns
+    /// the scope of this attempt, so that its remaining retries can be skipped.
+    pub fn abandoned_retries(&self, response: &LLMResponse) -> Option<OrchestrationScope> {
+        let (LLMResponse::LLMFailure(e), Some(policy)) = (response, &self.retry_policy) else {
+            return None;
+        };
+        if policy.should_retry(&e.code) {
+            return None;
+        }
+        let position = self
+            .scope
+            .scope
+            .iter()
+            .rposition(|scope| matches!(scope, ExecutionScope::Retry(..)))?;
+        Some(self.scope.scope[..=position].to_vec().into())
+    }
+
+    pub fn error_sleep_duration(&self, response: &LLMResponse) -> Option<Duration> {
+        // in reverse find the first retry scope with attempts left, and return the delay
+        let (_, (attempt, delay)) = self
+            .scope
+            .scope
+            .iter()
+            .rev()
+            .filter_map(|scope| match scope {
+                ExecutionScope::Retry(_, attempt, delay) => Some((*attempt, *delay)),
+                _ => None,
+            })
+            .enumerate()
+            .find(|(depth, (attempt, delay))| match &self.retry_policy {
+                // The innermost policy knows whether an attempt was its last. Outer
+                // ones only have their delay to go by, which is zero after the last
+                // attempt, but may also be a zero drawn by jitter.
+                Some(policy) if *depth == 0 => (*attempt as u32) < policy.max_retries(),
+                _ => !delay.is_zero(),
+            })?;
+
+        let (LLMResponse::LLMFailure(e), Some(policy)) = (response, &self.retry_policy) else {
+            return Some(delay);
+        };
+        if !policy.should_retry(&e.code) {
+            return None;
+        }
+        let mut policy = policy.for_error(&e.code);
+
+        // If the provider told us how long to back off, trust it over the policy.
+        if let Some(retry_after) = e.retry_after {
+            return Some(retry_after.min(policy.max_delay()));
+        }
+
+        // Errors with a strategy of their own follow it instead of the one the
+        // retries were planned with.
+        if policy.error_strategy(&e.code).is_some() {
+            policy.nth(attempt)
+        } else {
+            Some(delay)
+        }
+    }
 }
 
 #[derive(Debug, Default, Clone, Serialize)]
 pub struct OrchestrationScope {
     pub scope: Vec<ExecutionScope>,
+    /// The provider (e.g. `openai`) of the client the scope ends at, for
+    /// tracing.
+    #[serde(skip)]
+    pub provider: Option<String>,
 }
 
 impl From<ExecutionScope> for OrchestrationScope {
     fn from(scope: ExecutionScope) -> Self {
-        OrchestrationScope { scope: vec![scope] }
+        OrchestrationScope {
+            scope: vec![scope],
+            provider: None,
+        }
     }
 }
 
 impl From<Vec<ExecutionScope>> for OrchestrationScope {
     fn from(scope: Vec<ExecutionScope>) -> Self {
-        OrchestrationScope { scope }
+        OrchestrationScope {
+            scope,
+            provider: None,
+        }
     }
 }
 
@@ -118,6 +361,7 @@ impl OrchestrationScope {
                 .into_iter()
                 .chain(std::iter::once(scope))
                 .collect(),
+            provider: self.provider.clone(),
         }
     }
 
@@ -127,6 +371,22 @@ impl OrchestrationScope {
                 .into_iter()
                 .chain(self.scope.clone().into_iter())
                 .collect(),
+            provider: self.provider.clone(),
+        }
+    }
+
+    /// Whether this is a later attempt of the retry that ended `abandoned`.
+    pub fn is_later_retry_of(&self, abandoned: &OrchestrationScope) -> bool {
+        let Some((ExecutionScope::Retry(policy, attempt, _), prefix)) =
+            abandoned.scope.split_last()
+        else {
+            return false;
+        };
+        match self.scope.get(prefix.len()) {
+            Some(ExecutionScope::Retry(p, a, _)) => {
+                p == policy && a > attempt && self.scope.starts_with(prefix)
+            }
+            _ => false,
         }
     }
 
@@ -147,6 +407,27 @@ pub enum ExecutionScope {
     RoundRobin(Arc<RoundRobinStrategy>, usize),
     // StrategyName, ClientIndex
     Fallback(String, usize),
+    // Strategy, ClientIndex
+    LoadBalance(Arc<LoadBalanceStrategy>, usize),
+}
+
+impl PartialEq for ExecutionScope {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ExecutionScope::Direct(a), ExecutionScope::Direct(b)) => a == b,
+            (ExecutionScope::Retry(a, i, d), ExecutionScope::Retry(b, j, e)) => {
+                a == b && i == j && d == e
+            }
+            (ExecutionScope::RoundRobin(a, i), ExecutionScope::RoundRobin(b, j)) => {
+                Arc::ptr_eq(a, b) && i == j
+            }
+            (ExecutionScope::Fallback(a, i), ExecutionScope::Fallback(b, j)) => a == b && i == j,
+            (ExecutionScope::LoadBalance(a, i), ExecutionScope::LoadBalance(b, j)) => {
+                Arc::ptr_eq(a, b) && i == j
+            }
+            _ => false,
+        }
+    }
 }
 
 pub type OrchestratorNodeIterator = Vec<OrchestratorNode>;
@@ -184,16 +465,24 @@ impl WithRenderRawCurl for OrchestratorNode {
         &self,
         ctx: &RuntimeContext,
         prompt: &Vec<RenderedChatMessage>,
+        tools: &[ToolDefinition],
+        output_schema: Option<&serde_json::Value>,
         render_settings: RenderCurlSettings,
     ) -> Result<String> {
         self.provider
-            .render_raw_curl(ctx, prompt, render_settings)
+            .render_raw_curl(ctx, prompt, tools, output_schema, render_settings)
             .await
     }
 }
 
 impl WithSingleCallable for OrchestratorNode {
-    async fn single_call(&self, ctx: &RuntimeContext, prompt: &RenderedPrompt) -> LLMResponse {
+    async fn single_call(
+        &self,
+        ctx: &RuntimeContext,
+        prompt: &RenderedPrompt,
+        tools: &[ToolDefinition],
+        output_schema: Option<&serde_json::Value>,
+    ) -> LLMResponse {
         self.scope
             .scope
             .iter()
@@ -203,12 +492,32 @@ impl WithSingleCallable for OrchestratorNode {
             })
             .map(|a| a.increment_index())
             .for_each(drop);
-        self.provider.single_call(ctx, prompt).await
+
+        // Streams aren't cached, only complete calls.
+        let cache = self
+            .cache_entry(ctx, prompt, tools, output_schema)
+            .filter(|_| ctx.cache_mode.reads());
+        if let Some((cache, key)) = cache {
+            if let Some(cached) = cache.get(&key) {
+                log::debug!("Using cached response {} for {}", key, self.provider.name());
+                return self.cached_response(prompt, cached);
+            }
+        }
+
+        self.provider
+            .single_call(ctx, prompt, tools, output_schema)
+            .await
     }
 }
 
 impl WithStreamable for OrchestratorNode {
-    async fn stream(&self, ctx: &RuntimeContext, prompt: &RenderedPrompt) -> StreamResponse {
+    async fn stream(
+        &self,
+        ctx: &RuntimeContext,
+        prompt: &RenderedPrompt,
+        tools: &[ToolDefinition],
+        output_schema: Option<&serde_json::Value>,
+    ) -> StreamResponse {
         self.scope
             .scope
             .iter()
@@ -218,6 +527,203 @@ impl WithStreamable for OrchestratorNode {
             })
             .map(|a| a.increment_index())
             .for_each(drop);
-        self.provider.stream(ctx, prompt).await
+        self.provider
+            .stream(ctx, prompt, tools, output_schema)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use anyhow::Result;
+    use baml_types::{BamlMap, BamlValue};
+    use web_time::{Duration, Instant};
+
+    use super::{ExecutionScope, OrchestrationScope};
+    use crate::{
+        internal::llm_client::LLMResponse,
+        test_server::{completion, http, serve},
+        BamlRuntime, FunctionResult,
+    };
+
+    /// Calls `Count`, which `baml` must declare.
+    fn count(baml: String) -> Result<FunctionResult> {
+        let files = HashMap::from([("main.baml".to_string(), baml)]);
+        let runtime =
+            BamlRuntime::from_file_content(".", &files, HashMap::<&str, &str>::new()).unwrap();
+        let ctx = runtime.create_ctx_manager(BamlValue::String("test".into()), None);
+        let params = BamlMap::from([("input".to_string(), BamlValue::String("hi".into()))]);
+        let (result, _) =
+            runtime.call_function_sync("Count".into(), &params, &ctx, None, None, None, None);
+        result
+    }
+
+    #[test]
+    fn retry_after_is_waited_without_a_delay() {
+        let rate_limited = "HTTP/1.1 429 Too Many Requests\r\n\
+            Retry-After: 0.5\r\n\
+            Content-Length: 0\r\n\
+            Connection: close\r\n\r\n";
+        // A zero delay is what full jitter draws at worst
+        let port = serve(vec![
+            vec![rate_limited.to_string()],
+            vec![http("200 OK", "application/json", &completion("42"))],
+        ]);
+        let start = Instant::now();
+        let result = count(format!(
+            r##"
+            retry_policy NoDelay {{
+              max_retries 1
+              strategy {{
+                type constant_delay
+                delay_ms 0
+              }}
+            }}
+
+            client<llm> Local {{
+              provider openai-generic
+              retry_policy NoDelay
+              options {{
+                base_url "http://127.0.0.1:{port}"
+                model "gpt-4o"
+              }}
+            }}
+
+            function Count(input: string) -> int {{
+              client Local
+              prompt #"{{{{ input }}}}"#
+            }}
+            "##
+        ));
+        assert!(result.unwrap().result_with_constraints_content().is_ok());
+        assert!(start.elapsed() >= Duration::from_millis(500));
+    }
+
+    #[test]
+    fn errors_left_out_of_retry_on_skip_to_the_fallback() {
+        let port = serve(vec![
+            vec![http("401 Unauthorized", "application/json", "{}")],
+            vec![http("200 OK", "application/json", &completion("42"))],
+        ]);
+        let result = count(format!(
+            r##"
+            retry_policy ServerErrorsOnly {{
+              max_retries 2
+              retry_on [ServerError]
+              strategy {{
+                type constant_delay
+                delay_ms 10
+              }}
+            }}
+
+            client<llm> Local {{
+              provider openai-generic
+              retry_policy ServerErrorsOnly
+              options {{
+                base_url "http://127.0.0.1:{port}"
+                model "gpt-4o"
+              }}
+            }}
+
+            client<llm> Backup {{
+              provider openai-generic
+              options {{
+                base_url "http://127.0.0.1:{port}"
+                model "gpt-4o-mini"
+              }}
+            }}
+
+            client<llm> Resilient {{
+              provider fallback
+              options {{
+                strategy [Local, Backup]
+              }}
+            }}
+
+            function Count(input: string) -> int {{
+              client Resilient
+              prompt #"{{{{ input }}}}"#
+            }}
+            "##
+        ))
+        .unwrap();
+        match result.llm_response() {
+            LLMResponse::Success(response) => assert_eq!(response.client, "Backup"),
+            response => panic!("Expected Backup to answer, got {}", response),
+        }
+    }
+
+    #[test]
+    fn fallbacks_can_retry_on_parse_failure() {
+        let port = serve(vec![
+            vec![http(
+                "200 OK",
+                "application/json",
+                &completion("not a number"),
+            )],
+            vec![http("200 OK", "application/json", &completion("42"))],
+        ]);
+        let result = count(format!(
+            r##"
+            client<llm> Local {{
+              provider openai-generic
+              options {{
+                base_url "http://127.0.0.1:{port}"
+                model "gpt-4o"
+              }}
+            }}
+
+            client<llm> Backup {{
+              provider openai-generic
+              options {{
+                base_url "http://127.0.0.1:{port}"
+                model "gpt-4o-mini"
+              }}
+            }}
+
+            client<llm> Resilient {{
+              provider fallback
+              options {{
+                strategy [Local, Backup]
+                retry_on_parse_failure true
+              }}
+            }}
+
+            function Count(input: string) -> int {{
+              client Resilient
+              prompt #"{{{{ input }}}}"#
+            }}
+            "##
+        ))
+        .unwrap();
+        match result.llm_response() {
+            LLMResponse::Success(response) => assert_eq!(response.client, "Backup"),
+            response => panic!("Expected Backup to answer, got {}", response),
+        }
+        let value = result
+            .result_with_constraints_content()
+            .unwrap()
+            .clone()
+            .value();
+        assert_eq!(value, BamlValue::Int(42));
+    }
+
+    #[test]
+    fn later_retries_of_an_abandoned_attempt() {
+        let retry =
+            |attempt| ExecutionScope::Retry("Policy".into(), attempt, Duration::from_millis(10));
+        let fallback = |index| ExecutionScope::Fallback("Resilient".into(), index);
+        let direct = |client: &str| ExecutionScope::Direct(client.into());
+        let scope = |scopes: Vec<ExecutionScope>| OrchestrationScope::from(scopes);
+
+        let abandoned = scope(vec![fallback(0), retry(1)]);
+        assert!(scope(vec![fallback(0), retry(2), direct("Local")]).is_later_retry_of(&abandoned));
+        assert!(!scope(vec![fallback(0), retry(1), direct("Local")]).is_later_retry_of(&abandoned));
+        assert!(!scope(vec![fallback(0), retry(0), direct("Local")]).is_later_retry_of(&abandoned));
+        assert!(!scope(vec![fallback(1), retry(2), direct("Other")]).is_later_retry_of(&abandoned));
+        assert!(!scope(vec![fallback(1), direct("Backup")]).is_later_retry_of(&abandoned));
+        assert!(!scope(vec![fallback(0), retry(2)]).is_later_retry_of(&direct("Local").into()));
     }
 }
@@ -2,13 +2,15 @@ use anyhow::Result;
 use async_std::stream::StreamExt;
 use baml_types::BamlValue;
 use internal_baml_core::ir::repr::IntermediateRepr;
-use jsonish::BamlValueWithFlags;
+use jsonish::{BamlValueWithFlags, StreamingState};
 use web_time::Duration;
 
 use crate::{
     internal::{
         llm_client::{
-            parsed_value_to_response, traits::{WithPrompt, WithStreamable}, LLMErrorResponse, LLMResponse, ResponseBamlValue
+            parsed_value_to_response,
+            traits::{WithPrompt, WithStreamable},
+            LLMCompleteResponse, LLMErrorResponse, LLMResponse, ResponseBamlValue,
         },
         prompt_renderer::PromptRenderer,
     },
@@ -23,8 +25,8 @@ pub async fn orchestrate_stream<F>(
     ctx: &RuntimeContext,
     prompt: &PromptRenderer,
     params: &BamlValue,
-    partial_parse_fn: impl Fn(&str) -> Result<BamlValueWithFlags>,
-    parse_fn: impl Fn(&str) -> Result<BamlValueWithFlags>,
+    partial_parse_fn: impl Fn(&LLMCompleteResponse, &mut StreamingState) -> Result<BamlValueWithFlags>,
+    parse_fn: impl Fn(&LLMCompleteResponse) -> Result<BamlValueWithFlags>,
     on_event: Option<F>,
 ) -> (
     Vec<(
@@ -40,9 +42,35 @@ where
 {
     let mut results = Vec::new();
     let mut total_sleep_duration = std::time::Duration::from_secs(0);
+    // Set once a retry policy gives up on an error it doesn't retry.
+    let mut abandoned: Option<OrchestrationScope> = None;
 
     //advanced curl viewing, use render_raw_curl on each node. TODO
+    let tools = prompt.tools();
+    let output_schema = prompt.output_schema();
+
     for node in iter {
+        if abandoned
+            .as_ref()
+            .map_or(false, |scope| node.scope.is_later_retry_of(scope))
+        {
+            continue;
+        }
+
+        if let Some(breaker) = node.open_circuit_breaker() {
+            let message = format!(
+                "Skipped: circuit breaker for client {} is open",
+                breaker.client_name
+            );
+            results.push((
+                node.scope,
+                LLMResponse::InternalFailure(message),
+                None,
+                None,
+            ));
+            continue;
+        }
+
         let prompt = match node.render_prompt(ir, prompt, ctx, params).await {
             Ok(p) => p,
             Err(e) => {
@@ -51,50 +79,108 @@ where
             }
         };
 
+        if let Some(log) = &ctx.collector {
+            log.start_attempt(node.provider.name(), &node.scope);
+        }
         let (system_start, instant_start) = (web_time::SystemTime::now(), web_time::Instant::now());
-        let stream_res = node.stream(ctx, &prompt).await;
-        let final_response = match stream_res {
-            Ok(response) => response
-                .map(|stream_part| {
-                    if let Some(on_event) = on_event.as_ref() {
-                        match &stream_part {
-                            LLMResponse::Success(s) => {
-                                let parsed = partial_parse_fn(&s.content);
-                                let (parsed, response_value) = match parsed {
-                                    Ok(v) => (Some(Ok(v.clone())), Some(parsed_value_to_response(&v))),
-                                    Err(e) => (None, Some(Err(e))),
-                                };
-                                on_event(FunctionResult::new(
-                                    node.scope.clone(),
-                                    LLMResponse::Success(s.clone()),
-                                    parsed,
-                                    response_value,
-                                ));
-                            }
-                            _ => {}
+        let timeouts = *node.provider.timeouts();
+        let stream_call = async {
+            let mut response = match node.stream(ctx, &prompt, tools, output_schema).await {
+                Ok(response) => response,
+                Err(response) => return response,
+            };
+
+            let mut last = None;
+            let mut parse_state = StreamingState::default();
+            loop {
+                let stream_part = match timeouts.idle_stream {
+                    Some(idle) => match async_std::future::timeout(idle, response.next()).await {
+                        Ok(stream_part) => stream_part,
+                        Err(_) => {
+                            return node.timed_out(
+                                &prompt,
+                                system_start,
+                                instant_start.elapsed(),
+                                format!(
+                                    "Stream sent nothing for {}ms (idle_stream_timeout_ms)",
+                                    idle.as_millis()
+                                ),
+                            )
+                        }
+                    },
+                    None => response.next().await,
+                };
+                let Some(stream_part) = stream_part else {
+                    break;
+                };
+
+                if let Some(on_event) = on_event.as_ref() {
+                    match &stream_part {
+                        LLMResponse::Success(s) => {
+                            let parsed = partial_parse_fn(s, &mut parse_state);
+                            let (parsed, response_value) = match parsed {
+                                Ok(v) => (Some(Ok(v.clone())), Some(parsed_value_to_response(&v))),
+                                Err(e) => (None, Some(Err(e))),
+                            };
+                            on_event(FunctionResult::new(
+                                node.scope.clone(),
+                                LLMResponse::Success(s.clone()),
+                                parsed,
+                                response_value,
+                            ));
                         }
+                        _ => {}
                     }
-                    stream_part
+                }
+                last = Some(stream_part);
+            }
+
+            last.unwrap_or_else(|| {
+                LLMResponse::LLMFailure(LLMErrorResponse {
+                    client: node.provider.name().into(),
+                    model: None,
+                    prompt: prompt.clone(),
+                    start_time: system_start,
+                    latency: instant_start.elapsed(),
+                    request_options: node.provider.request_options().clone(),
+                    message: "Stream ended without response".to_string(),
+                    code: crate::internal::llm_client::ErrorCode::from_u16(2),
+                    retry_after: None,
                 })
-                .fold(None, |_, current| Some(current))
-                .await
-                .unwrap_or_else(|| {
-                    LLMResponse::LLMFailure(LLMErrorResponse {
-                        client: node.provider.name().into(),
-                        model: None,
-                        prompt,
-                        start_time: system_start,
-                        latency: instant_start.elapsed(),
-                        request_options: node.provider.request_options().clone(),
-                        message: "Stream ended without response".to_string(),
-                        code: crate::internal::llm_client::ErrorCode::from_u16(2),
-                    })
-                }),
-            Err(response) => response,
+            })
+        };
+        let stream_call = async {
+            match timeouts.total {
+                Some(total) => match async_std::future::timeout(total, stream_call).await {
+                    Ok(response) => response,
+                    Err(_) => node.timed_out(
+                        &prompt,
+                        system_start,
+                        instant_start.elapsed(),
+                        format!(
+                            "No response after {}ms (total_timeout_ms)",
+                            total.as_millis()
+                        ),
+                    ),
+                },
+                None => stream_call.await,
+            }
+        };
+        // Dropping the stream closes its connection, so the provider stops generating.
+        let Some(mut final_response) = ctx.cancel.run(stream_call).await else {
+            if let Some(log) = &ctx.collector {
+                log.cancel_attempt(instant_start.elapsed());
+            }
+            break;
         };
+        node.price_response(ctx, &mut final_response);
+        node.record_response(&final_response);
+        if let Some(scope) = node.abandoned_retries(&final_response) {
+            abandoned = Some(scope);
+        }
 
         let parsed_response = match &final_response {
-            LLMResponse::Success(s) => Some(parse_fn(&s.content)),
+            LLMResponse::Success(s) => Some(parse_fn(s)),
             _ => None,
         };
         let (parsed_response, response_value) = match parsed_response {
@@ -103,19 +189,34 @@ where
             None => (None, None),
         };
             // parsed_response.map(|r| r.and_then(|v| parsed_value_to_response(v)));
-        let sleep_duration = node.error_sleep_duration().cloned();
+        let sleep_duration = node.error_sleep_duration(&final_response);
+        let retry_on_parse_failure = node.retry_on_parse_failure;
+        if let Some(log) = &ctx.collector {
+            let parse_error = match &response_value {
+                Some(Err(e)) => Some(format!("{:#}", e)),
+                _ => None,
+            };
+            log.finish_attempt(&final_response, parse_error);
+        }
         results.push((node.scope, final_response, parsed_response, response_value));
 
-        // Currently, we break out of the loop if an LLM responded, even if we couldn't parse the result.
-        if results
-            .last()
-            .map_or(false, |(_, r, _, _)| matches!(r, LLMResponse::Success(_)))
-        {
+        // We break out of the loop if an LLM responded, unless the retry policy asks us to
+        // keep going when the result couldn't be parsed (or failed an assert).
+        if results.last().map_or(false, |(_, r, _, v)| {
+            matches!(r, LLMResponse::Success(_))
+                && !(retry_on_parse_failure && matches!(v, Some(Err(_))))
+        }) {
             break;
         } else {
             if let Some(duration) = sleep_duration {
+                if let Some(log) = &ctx.collector {
+                    log.record_retry_sleep(duration);
+                }
                 total_sleep_duration += duration;
-                async_std::task::sleep(duration).await;
+                let slept = ctx.cancel.run(async_std::task::sleep(duration)).await;
+                if slept.is_none() {
+                    break;
+                }
             }
         }
     }
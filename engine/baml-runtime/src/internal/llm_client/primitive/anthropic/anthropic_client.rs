@@ -16,14 +16,21 @@ use internal_baml_jinja::{
 use crate::{
     client_registry::ClientProperty,
     internal::llm_client::{
+        cache::ResponseCacheConfig,
         primitive::{
             anthropic::types::{AnthropicMessageResponse, StopReason},
-            request::{make_parsed_request, make_request, RequestBuilder},
+            request::{
+                make_parsed_request, make_request, structured_output_from_properties,
+                RequestBuilder, RequestTimeouts,
+            },
         },
+        structured_output::{object_schema, ANTHROPIC_OUTPUT_TOOL},
+        tools::{tool_calls_to_content, ToolCall, ToolDefinition},
         traits::{
             SseResponseTrait, StreamResponse, WithChat, WithClient, WithNoCompletion,
             WithRetryPolicy, WithStreamChat,
         },
+        usage::ModelPricing,
         ErrorCode, LLMCompleteResponse, LLMCompleteResponseMetadata, LLMErrorResponse, LLMResponse,
         ModelFeatures,
     },
@@ -45,6 +52,10 @@ struct PostRequestProperities {
     allowed_metadata: AllowedMetadata,
     // These are passed directly to the Anthropic API.
     properties: HashMap<String, serde_json::Value>,
+    timeouts: RequestTimeouts,
+    structured_output: bool,
+    cache: Option<ResponseCacheConfig>,
+    pricing: Option<ModelPricing>,
 }
 
 // represents client that interacts with the Anthropic API
@@ -85,6 +96,11 @@ fn resolve_properties(
         .and_then(|v| v.as_str().map(|s| s.to_string()))
         .or_else(|| ctx.env.get("ANTHROPIC_API_KEY").map(|s| s.to_string()));
 
+    let timeouts = RequestTimeouts::from_properties(&mut properties)?;
+    let structured_output = structured_output_from_properties(&mut properties)?;
+    let cache = ResponseCacheConfig::from_properties(&mut properties)?;
+    let pricing = ModelPricing::from_properties(&mut properties)?;
+
     let allowed_metadata = match properties.remove("allowed_role_metadata") {
         Some(allowed_metadata) => serde_json::from_value(allowed_metadata).context(
             "allowed_role_metadata must be an array of keys. For example: ['key1', 'key2']",
@@ -121,6 +137,10 @@ fn resolve_properties(
         allowed_metadata,
         properties,
         proxy_url: ctx.env.get("BOUNDARY_PROXY_URL").map(|s| s.to_string()),
+        timeouts,
+        structured_output,
+        cache,
+        pricing,
     })
 }
 
@@ -164,6 +184,8 @@ impl SseResponseTrait for AnthropicClient {
         let prompt = prompt.clone();
         let client_name = self.context.name.clone();
         let params = self.properties.properties.clone();
+        // Each tool call, by content block index.
+        let mut tool_calls: Vec<(u32, ToolCall)> = Vec::new();
 
         Ok(Box::pin(
             resp.bytes_stream()
@@ -176,6 +198,7 @@ impl SseResponseTrait for AnthropicClient {
                         client: client_name.clone(),
                         prompt: RenderedPrompt::Chat(prompt.clone()),
                         content: "".to_string(),
+                        tool_calls: vec![],
                         start_time: system_start,
                         latency: instant_start.elapsed(),
                         model: "".to_string(),
@@ -186,6 +209,7 @@ impl SseResponseTrait for AnthropicClient {
                             prompt_tokens: None,
                             output_tokens: None,
                             total_tokens: None,
+                            cost: None,
                         },
                     }),
                     move |accumulated: &mut Result<LLMCompleteResponse>, event| {
@@ -211,6 +235,7 @@ impl SseResponseTrait for AnthropicClient {
                                         latency: instant_start.elapsed(),
                                         message: format!("Failed to parse event: {:#?}", e),
                                         code: ErrorCode::UnsupportedResponse(2),
+                                        retry_after: None,
                                     },
                                 )));
                             }
@@ -221,9 +246,9 @@ impl SseResponseTrait for AnthropicClient {
                                 inner.model = body.model;
                                 let ref mut inner = inner.metadata;
                                 inner.baml_is_complete = match body.stop_reason {
-                                    Some(StopReason::StopSequence) | Some(StopReason::EndTurn) => {
-                                        true
-                                    }
+                                    Some(StopReason::StopSequence)
+                                    | Some(StopReason::EndTurn)
+                                    | Some(StopReason::ToolUse) => true,
                                     _ => false,
                                 };
                                 inner.finish_reason =
@@ -234,18 +259,56 @@ impl SseResponseTrait for AnthropicClient {
                                     Some(body.usage.input_tokens + body.usage.output_tokens);
                             }
                             MessageChunk::ContentBlockDelta(event) => {
-                                inner.content += &event.delta.text;
+                                match event.delta.partial_json {
+                                    Some(partial_json) => {
+                                        match tool_calls.iter_mut().find(|(i, _)| *i == event.index)
+                                        {
+                                            Some((_, call)) => call.arguments += &partial_json,
+                                            None => tool_calls.push((
+                                                event.index,
+                                                ToolCall {
+                                                    name: String::new(),
+                                                    arguments: partial_json,
+                                                },
+                                            )),
+                                        }
+                                        let calls = tool_calls
+                                            .iter()
+                                            .map(|(_, call)| call.clone())
+                                            .collect::<Vec<_>>();
+                                        if let Some(content) = response_content(&calls) {
+                                            inner.content = content;
+                                        }
+                                        inner.tool_calls = function_tool_calls(&calls);
+                                    }
+                                    None if tool_calls.is_empty() => {
+                                        inner.content += &event.delta.text;
+                                    }
+                                    // Text next to tool calls is not part of the result.
+                                    None => (),
+                                }
+                            }
+                            MessageChunk::ContentBlockStart(event) => {
+                                // The tool's name comes before any of its arguments.
+                                if let Some(name) = event.content_block.name {
+                                    tool_calls.push((
+                                        event.index,
+                                        ToolCall {
+                                            name,
+                                            arguments: String::new(),
+                                        },
+                                    ));
+                                }
                             }
-                            MessageChunk::ContentBlockStart(_) => (),
                             MessageChunk::ContentBlockStop(_) => (),
                             MessageChunk::Ping => (),
                             MessageChunk::MessageDelta(body) => {
                                 let ref mut inner = inner.metadata;
 
                                 inner.baml_is_complete = match body.delta.stop_reason {
-                                    Some(StopReason::StopSequence) | Some(StopReason::EndTurn) => {
-                                        true
-                                    }
+                                    Some(StopReason::StopSequence)
+                                    | Some(StopReason::EndTurn)
+                                    | Some(StopReason::ToolUse) => true,
                                     _ => false,
                                 };
                                 inner.finish_reason = body
@@ -276,6 +339,7 @@ impl SseResponseTrait for AnthropicClient {
                                         latency: instant_start.elapsed(),
                                         message: err.message,
                                         code: ErrorCode::Other(2),
+                                        retry_after: None,
                                     },
                                 )));
                             }
@@ -293,14 +357,24 @@ impl SseResponseTrait for AnthropicClient {
 impl WithStreamChat for AnthropicClient {
     async fn stream_chat(
         &self,
-        _ctx: &RuntimeContext,
+        ctx: &RuntimeContext,
         prompt: &Vec<RenderedChatMessage>,
+        tools: &[ToolDefinition],
+        output_schema: Option<&serde_json::Value>,
     ) -> StreamResponse {
-        let (response, system_now, instant_now) =
-            match make_request(self, either::Either::Right(prompt), true).await {
-                Ok(v) => v,
-                Err(e) => return Err(e),
-            };
+        let (response, system_now, instant_now) = match make_request(
+            self,
+            ctx,
+            either::Either::Right(prompt),
+            tools,
+            output_schema,
+            true,
+        )
+        .await
+        {
+            Ok(v) => v,
+            Err(e) => return Err(e),
+        };
         self.response_stream(response, prompt, system_now, instant_now)
     }
 }
@@ -332,7 +406,7 @@ impl AnthropicClient {
                 allowed_metadata: properties.allowed_metadata.clone(),
             },
             retry_policy: client.retry_policy.clone(),
-            client: create_client()?,
+            client: create_client(properties.timeouts.connect)?,
             properties,
         })
     }
@@ -360,7 +434,7 @@ impl AnthropicClient {
                 .retry_policy_id
                 .as_ref()
                 .map(|s| s.to_string()),
-            client: create_client()?,
+            client: create_client(properties.timeouts.connect)?,
             properties,
         })
     }
@@ -372,9 +446,23 @@ impl RequestBuilder for AnthropicClient {
         &self.client
     }
 
+    fn timeouts(&self) -> &RequestTimeouts {
+        &self.properties.timeouts
+    }
+
+    fn response_cache(&self) -> Option<&ResponseCacheConfig> {
+        self.properties.cache.as_ref()
+    }
+
+    fn pricing(&self) -> Option<&ModelPricing> {
+        self.properties.pricing.as_ref()
+    }
+
     async fn build_request(
         &self,
         prompt: either::Either<&String, &Vec<RenderedChatMessage>>,
+        tools: &[ToolDefinition],
+        output_schema: Option<&serde_json::Value>,
         allow_proxy: bool,
         stream: bool,
     ) -> Result<reqwest::RequestBuilder> {
@@ -414,6 +502,48 @@ impl RequestBuilder for AnthropicClient {
             }
         }
 
+        if !tools.is_empty() {
+            body_obj.insert(
+                "tools".into(),
+                tools
+                    .iter()
+                    .map(|tool| {
+                        let mut tool_json = json!({
+                            "name": tool.name,
+                            "input_schema": tool.parameters,
+                        });
+                        if let Some(description) = &tool.description {
+                            tool_json["description"] = json!(description);
+                        }
+                        tool_json
+                    })
+                    .collect(),
+            );
+        }
+
+        // Anthropic has no schema for answers, so it is made to answer through
+        // a tool that takes the schema as its input.
+        if let Some(schema) = object_schema(output_schema) {
+            if self.properties.structured_output
+                && prompt.is_right()
+                && tools.is_empty()
+                && !body_obj.contains_key("tools")
+            {
+                body_obj.insert(
+                    "tools".into(),
+                    json!([{
+                        "name": ANTHROPIC_OUTPUT_TOOL,
+                        "description": "Respond to the user with this.",
+                        "input_schema": schema,
+                    }]),
+                );
+                body_obj.insert(
+                    "tool_choice".into(),
+                    json!({ "type": "tool", "name": ANTHROPIC_OUTPUT_TOOL }),
+                );
+            }
+        }
+
         if stream {
             body_obj.insert("stream".into(), true.into());
         }
@@ -434,19 +564,41 @@ impl WithChat for AnthropicClient {
         ))
     }
 
-    async fn chat(&self, _ctx: &RuntimeContext, prompt: &Vec<RenderedChatMessage>) -> LLMResponse {
-        let (response, system_now, instant_now) = match make_parsed_request::<
-            AnthropicMessageResponse,
-        >(
-            self, either::Either::Right(prompt), false
-        )
-        .await
-        {
-            Ok(v) => v,
-            Err(e) => return e,
-        };
+    async fn chat(
+        &self,
+        ctx: &RuntimeContext,
+        prompt: &Vec<RenderedChatMessage>,
+        tools: &[ToolDefinition],
+        output_schema: Option<&serde_json::Value>,
+    ) -> LLMResponse {
+        let (response, system_now, instant_now) =
+            match make_parsed_request::<AnthropicMessageResponse>(
+                self,
+                ctx,
+                either::Either::Right(prompt),
+                tools,
+                output_schema,
+                false,
+            )
+            .await
+            {
+                Ok(v) => v,
+                Err(e) => return e,
+            };
+
+        let tool_calls = response
+            .content
+            .iter()
+            .filter(|c| c.r#type == "tool_use")
+            .filter_map(|c| {
+                Some(ToolCall {
+                    name: c.name.clone()?,
+                    arguments: c.input.as_ref()?.to_string(),
+                })
+            })
+            .collect::<Vec<_>>();
 
-        if response.content.len() != 1 {
+        if tool_calls.is_empty() && response.content.len() != 1 {
             return LLMResponse::LLMFailure(LLMErrorResponse {
                 client: self.context.name.to_string(),
                 model: None,
@@ -459,20 +611,25 @@ impl WithChat for AnthropicClient {
                     response.content.len()
                 ),
                 code: ErrorCode::Other(200),
+                retry_after: None,
             });
         }
 
         LLMResponse::Success(LLMCompleteResponse {
             client: self.context.name.to_string(),
             prompt: internal_baml_jinja::RenderedPrompt::Chat(prompt.clone()),
-            content: response.content[0].text.clone(),
+            content: response_content(&tool_calls)
+                .unwrap_or_else(|| response.content[0].text.clone()),
+            tool_calls: function_tool_calls(&tool_calls),
             start_time: system_now,
             latency: instant_now.elapsed(),
             request_options: self.properties.properties.clone(),
             model: response.model,
             metadata: LLMCompleteResponseMetadata {
                 baml_is_complete: match response.stop_reason {
-                    Some(StopReason::StopSequence) | Some(StopReason::EndTurn) => true,
+                    Some(StopReason::StopSequence)
+                    | Some(StopReason::EndTurn)
+                    | Some(StopReason::ToolUse) => true,
                     _ => false,
                 },
                 finish_reason: response
@@ -482,6 +639,7 @@ impl WithChat for AnthropicClient {
                 prompt_tokens: Some(response.usage.input_tokens),
                 output_tokens: Some(response.usage.output_tokens),
                 total_tokens: Some(response.usage.input_tokens + response.usage.output_tokens),
+                cost: None,
             },
         })
     }
@@ -584,3 +742,22 @@ fn convert_completion_prompt_to_body(prompt: &String) -> HashMap<String, serde_j
     map.insert("prompt".into(), json!(prompt));
     map
 }
+
+// The answer given through the output tool is the response itself, while calls
+// to the function's tools are kept with their names.
+fn response_content(calls: &[ToolCall]) -> Option<String> {
+    match calls {
+        [call] if call.name == ANTHROPIC_OUTPUT_TOOL => Some(call.arguments.clone()),
+        calls => tool_calls_to_content(calls),
+    }
+}
+
+// The calls to the function's tools, without the answer given through the
+// output tool.
+fn function_tool_calls(calls: &[ToolCall]) -> Vec<ToolCall> {
+    calls
+        .iter()
+        .filter(|call| call.name != ANTHROPIC_OUTPUT_TOOL)
+        .cloned()
+        .collect()
+}
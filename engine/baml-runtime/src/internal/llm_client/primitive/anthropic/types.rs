@@ -4,7 +4,12 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct AnthropicMessageContent {
     pub r#type: String,
+    #[serde(default)]
     pub text: String,
+    /// Set on `tool_use` blocks: the tool that was called.
+    pub name: Option<String>,
+    /// Set on `tool_use` blocks: the arguments of the tool call.
+    pub input: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
@@ -32,6 +37,7 @@ pub enum StopReason {
     MaxTokens,
     StopSequence,
     EndTurn,
+    ToolUse,
     #[serde(other)]
     Unknown,
 }
@@ -52,6 +58,8 @@ pub enum ContentType {
     Image,
     /// text_delta
     TextDelta,
+    /// input_json_delta
+    InputJsonDelta,
     /// tool_use
     ToolUse,
     /// tool_result
@@ -60,11 +68,14 @@ pub enum ContentType {
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct TextContentBlock {
-    /// The content type. It is always `text`.
+    /// The content type: `text` or `tool_use`.
     #[serde(rename = "type")]
     pub _type: ContentType,
     /// The text content.
+    #[serde(default)]
     pub text: String,
+    /// Set on `tool_use` blocks: the tool that is being called.
+    pub name: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
@@ -144,11 +155,14 @@ pub struct MessageDeltaChunk {
 /// The text delta content block.
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
 pub struct TextDeltaContentBlock {
-    /// The content type. It is always `text_delta`.
+    /// The content type: `text_delta`, or `input_json_delta` in a `tool_use` block.
     #[serde(rename = "type")]
     pub _type: ContentType,
     /// The text delta content.
+    #[serde(default)]
     pub text: String,
+    /// The next piece of the tool call's arguments, as JSON.
+    pub partial_json: Option<String>,
 }
 
 /// The stream stop information.
@@ -181,6 +195,7 @@ mod tests {
             delta: TextDeltaContentBlock {
                 _type: ContentType::TextDelta,
                 text: "Hello".to_string(),
+                partial_json: None,
             },
         });
         println!("serialized = {}", serde_json::to_string(&chunk)?);
@@ -1,12 +1,15 @@
 use std::collections::HashMap;
 
-use aws_config::{identity::IdentityCache, retry::RetryConfig, BehaviorVersion, ConfigLoader};
+use aws_config::{
+    identity::IdentityCache, retry::RetryConfig, timeout::TimeoutConfig, BehaviorVersion,
+    ConfigLoader,
+};
 use aws_sdk_bedrockruntime::{self as bedrock, operation::converse::ConverseOutput};
 
 use anyhow::{Context, Result};
 use aws_smithy_json::serialize::JsonObjectWriter;
 use aws_smithy_runtime_api::client::result::SdkError;
-use aws_smithy_types::Blob;
+use aws_smithy_types::{Blob, Document, Number};
 use baml_types::BamlMediaContent;
 use baml_types::{BamlMedia, BamlMediaType};
 use futures::stream;
@@ -20,11 +23,14 @@ use web_time::SystemTime;
 use crate::internal::llm_client::traits::{ToProviderMessageExt, WithClientProperties};
 use crate::internal::llm_client::AllowedMetadata;
 use crate::internal::llm_client::{
-    primitive::request::RequestBuilder,
+    cache::ResponseCacheConfig,
+    primitive::request::{RequestBuilder, RequestTimeouts},
+    tools::{tool_calls_to_content, ToolCall, ToolDefinition},
     traits::{
         StreamResponse, WithChat, WithClient, WithNoCompletion, WithRenderRawCurl, WithRetryPolicy,
         WithStreamChat,
     },
+    usage::ModelPricing,
     ErrorCode, LLMCompleteResponse, LLMCompleteResponseMetadata, LLMErrorResponse, LLMResponse,
     ModelFeatures, ResolveMediaUrls,
 };
@@ -38,6 +44,9 @@ struct RequestProperties {
     default_role: String,
     inference_config: Option<bedrock::types::InferenceConfiguration>,
     allowed_metadata: AllowedMetadata,
+    timeouts: RequestTimeouts,
+    cache: Option<ResponseCacheConfig>,
+    pricing: Option<ModelPricing>,
 
     request_options: HashMap<String, serde_json::Value>,
     ctx_env: HashMap<String, String>,
@@ -79,6 +88,9 @@ fn resolve_properties(client: &ClientWalker, ctx: &RuntimeContext) -> Result<Req
         .remove("default_role")
         .and_then(|v| v.as_str().map(|s| s.to_string()))
         .unwrap_or_else(|| "user".to_string());
+    let timeouts = RequestTimeouts::from_properties(&mut properties)?;
+    let cache = ResponseCacheConfig::from_properties(&mut properties)?;
+    let pricing = ModelPricing::from_properties(&mut properties)?;
     let allowed_metadata = match properties.remove("allowed_role_metadata") {
         Some(allowed_metadata) => serde_json::from_value(allowed_metadata)
             .context("allowed_role_metadata must be an array of keys. For example: ['key1', 'key2']")?,
@@ -92,12 +104,22 @@ fn resolve_properties(client: &ClientWalker, ctx: &RuntimeContext) -> Result<Req
         ),
         None => None,
     };
+    // Bedrock's tool settings are built from the function's `tools` alone.
+    if properties.contains_key("tool_choice") {
+        anyhow::bail!(
+            "client {}: aws-bedrock doesn't support the tool_choice option",
+            client.name()
+        );
+    }
 
     Ok(RequestProperties {
         model_id,
         default_role,
         inference_config,
         allowed_metadata,
+        timeouts,
+        cache,
+        pricing,
         request_options: properties,
         ctx_env: ctx.env.clone(),
     })
@@ -135,15 +157,31 @@ impl AwsClient {
         &self.properties.request_options
     }
 
+    pub fn timeouts(&self) -> &RequestTimeouts {
+        &self.properties.timeouts
+    }
+
+    pub fn response_cache(&self) -> Option<&ResponseCacheConfig> {
+        self.properties.cache.as_ref()
+    }
+
+    pub fn pricing(&self) -> Option<&ModelPricing> {
+        self.properties.pricing.as_ref()
+    }
+
     // TODO: this should be memoized on client construction, but because config loading is async,
     // we can't do this in AwsClient::new (which is called from LLMPRimitiveProvider::try_from)
-    async fn client_anyhow(&self) -> Result<bedrock::Client> {
+    async fn client_anyhow(&self, ctx: &RuntimeContext) -> Result<bedrock::Client> {
         let loader: ConfigLoader = {
             cfg_if::cfg_if! {
                 if #[cfg(target_arch = "wasm32")] {
                     use aws_config::Region;
                     use aws_credential_types::Credentials;
 
+                    if ctx.cassette.is_some() {
+                        anyhow::bail!("Cassettes are not supported in the browser");
+                    }
+
                     let (aws_region, aws_access_key_id, aws_secret_access_key) = match (
                         self.properties.ctx_env.get("AWS_REGION"),
                         self.properties.ctx_env.get("AWS_ACCESS_KEY_ID"),
@@ -171,13 +209,39 @@ impl AwsClient {
 
                     loader
                 } else {
-                    aws_config::defaults(BehaviorVersion::latest())
+                    use aws_config::meta::region::RegionProviderChain;
+                    use aws_credential_types::Credentials;
+
+                    let loader = aws_config::defaults(BehaviorVersion::latest());
+                    match &ctx.cassette {
+                        // Requests are still signed when replaying, so any
+                        // credentials will do.
+                        Some(cassette) if cassette.replaying() => loader
+                            .http_client(super::cassette::CassetteHttp::new(cassette.clone()))
+                            .credentials_provider(Credentials::new(
+                                "replay",
+                                "replay",
+                                None,
+                                None,
+                                "baml-runtime/cassette",
+                            ))
+                            .region(RegionProviderChain::default_provider().or_else("us-east-1")),
+                        Some(cassette) => loader
+                            .http_client(super::cassette::CassetteHttp::new(cassette.clone())),
+                        None => loader,
+                    }
                 }
             }
         };
 
+        let mut timeouts = TimeoutConfig::builder();
+        timeouts
+            .set_connect_timeout(self.properties.timeouts.connect)
+            .set_operation_attempt_timeout(self.properties.timeouts.request);
+
         let config = loader
             .retry_config(RetryConfig::disabled())
+            .timeout_config(timeouts.build())
             .identity_cache(IdentityCache::no_cache())
             .load()
             .await;
@@ -185,13 +249,28 @@ impl AwsClient {
         Ok(bedrock::Client::new(&config))
     }
 
-    async fn chat_anyhow<'r>(&self, response: &'r ConverseOutput) -> Result<&'r String> {
+    /// The content of the response, and the tool calls in it.
+    async fn chat_anyhow(&self, response: &ConverseOutput) -> Result<(String, Vec<ToolCall>)> {
         let Some(bedrock::types::ConverseOutput::Message(ref message)) = response.output else {
             anyhow::bail!(
                 "Expected message output in response, but is type {}",
                 "unknown"
             );
         };
+        let tool_calls = message
+            .content
+            .iter()
+            .filter_map(|block| match block {
+                bedrock::types::ContentBlock::ToolUse(tool_use) => Some(ToolCall {
+                    name: tool_use.name().to_string(),
+                    arguments: document_to_json(tool_use.input()).to_string(),
+                }),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if let Some(content) = tool_calls_to_content(&tool_calls) {
+            return Ok((content, tool_calls));
+        }
         let content = message
             .content
             .get(0)
@@ -210,13 +289,14 @@ impl AwsClient {
             );
         };
 
-        Ok(content)
+        Ok((content.clone(), tool_calls))
     }
 
     fn build_request(
         &self,
         ctx: &RuntimeContext,
         chat_messages: &Vec<RenderedChatMessage>,
+        tools: &[ToolDefinition],
     ) -> Result<bedrock::operation::converse::ConverseInput> {
         let mut system_message = None;
         let mut chat_slice = chat_messages.as_slice();
@@ -239,11 +319,37 @@ impl AwsClient {
             .map(|m| self.role_to_message(m))
             .collect::<Result<Vec<_>>>()?;
 
+        let tool_config = match tools {
+            [] => None,
+            tools => Some(
+                bedrock::types::ToolConfiguration::builder()
+                    .set_tools(Some(
+                        tools
+                            .iter()
+                            .map(|tool| {
+                                bedrock::types::ToolSpecification::builder()
+                                    .name(tool.name.clone())
+                                    .set_description(tool.description.clone())
+                                    .input_schema(bedrock::types::ToolInputSchema::Json(
+                                        json_to_document(&tool.parameters),
+                                    ))
+                                    .build()
+                                    .map(bedrock::types::Tool::ToolSpec)
+                                    .context(format!("Invalid tool `{}`", tool.name))
+                            })
+                            .collect::<Result<Vec<_>>>()?,
+                    ))
+                    .build()
+                    .context("Failed to build AWS Bedrock tool configuration")?,
+            ),
+        };
+
         bedrock::operation::converse::ConverseInput::builder()
             .set_inference_config(self.properties.inference_config.clone())
             .set_model_id(Some(self.properties.model_id.clone()))
             .set_system(system_message)
             .set_messages(Some(converse_messages))
+            .set_tool_config(tool_config)
             .build()
             .context("Failed to convert BAML prompt to AWS Bedrock request")
     }
@@ -267,14 +373,54 @@ fn try_to_json<
     Ok(out)
 }
 
+fn json_to_document(value: &serde_json::Value) -> Document {
+    match value {
+        serde_json::Value::Null => Document::Null,
+        serde_json::Value::Bool(b) => Document::Bool(*b),
+        serde_json::Value::Number(n) => match (n.as_u64(), n.as_i64()) {
+            (Some(u), _) => Document::Number(Number::PosInt(u)),
+            (None, Some(i)) => Document::Number(Number::NegInt(i)),
+            (None, None) => Document::Number(Number::Float(n.as_f64().unwrap_or_default())),
+        },
+        serde_json::Value::String(s) => Document::String(s.clone()),
+        serde_json::Value::Array(items) => {
+            Document::Array(items.iter().map(json_to_document).collect())
+        }
+        serde_json::Value::Object(map) => Document::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), json_to_document(v)))
+                .collect(),
+        ),
+    }
+}
+
+fn document_to_json(document: &Document) -> serde_json::Value {
+    match document {
+        Document::Null => serde_json::Value::Null,
+        Document::Bool(b) => serde_json::Value::Bool(*b),
+        Document::Number(Number::PosInt(u)) => serde_json::Value::from(*u),
+        Document::Number(Number::NegInt(i)) => serde_json::Value::from(*i),
+        Document::Number(Number::Float(f)) => serde_json::Value::from(*f),
+        Document::String(s) => serde_json::Value::String(s.clone()),
+        Document::Array(items) => items.iter().map(document_to_json).collect(),
+        Document::Object(map) => serde_json::Value::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), document_to_json(v)))
+                .collect(),
+        ),
+    }
+}
+
 impl WithRenderRawCurl for AwsClient {
     async fn render_raw_curl(
         &self,
         ctx: &RuntimeContext,
         prompt: &Vec<internal_baml_jinja::RenderedChatMessage>,
+        tools: &[ToolDefinition],
+        _output_schema: Option<&serde_json::Value>,
         _render_settings: RenderCurlSettings,
     ) -> Result<String> {
-        let converse_input = self.build_request(ctx, prompt)?;
+        let converse_input = self.build_request(ctx, prompt, tools)?;
 
         // TODO(sam): this is fucked up. The SDK actually hides all the serializers inside the crate and doesn't let the user access them.
 
@@ -320,13 +466,15 @@ impl WithStreamChat for AwsClient {
         &self,
         ctx: &RuntimeContext,
         chat_messages: &Vec<RenderedChatMessage>,
+        tools: &[ToolDefinition],
+        _output_schema: Option<&serde_json::Value>,
     ) -> StreamResponse {
         let client = self.context.name.to_string();
         let model = Some(self.properties.model_id.clone());
         let request_options = self.properties.request_options.clone();
         let prompt = internal_baml_jinja::RenderedPrompt::Chat(chat_messages.clone());
 
-        let aws_client = match self.client_anyhow().await {
+        let aws_client = match self.client_anyhow(ctx).await {
             Ok(c) => c,
             Err(e) => {
                 return Err(LLMResponse::LLMFailure(LLMErrorResponse {
@@ -338,11 +486,12 @@ impl WithStreamChat for AwsClient {
                     latency: web_time::Duration::ZERO,
                     message: format!("{:#?}", e),
                     code: ErrorCode::Other(2),
+                    retry_after: None,
                 }));
             }
         };
 
-        let request = match self.build_request(ctx, chat_messages) {
+        let request = match self.build_request(ctx, chat_messages, tools) {
             Ok(r) => r,
             Err(e) => {
                 return Err(LLMResponse::LLMFailure(LLMErrorResponse {
@@ -354,6 +503,7 @@ impl WithStreamChat for AwsClient {
                     latency: web_time::Duration::ZERO,
                     message: format!("{:#?}", e),
                     code: ErrorCode::Other(2),
+                    retry_after: None,
                 }))
             }
         };
@@ -363,7 +513,8 @@ impl WithStreamChat for AwsClient {
             .set_model_id(request.model_id)
             .set_inference_config(request.inference_config)
             .set_system(request.system)
-            .set_messages(request.messages);
+            .set_messages(request.messages)
+            .set_tool_config(request.tool_config);
 
         let system_start = SystemTime::now();
         let instant_start = Instant::now();
@@ -381,7 +532,7 @@ impl WithStreamChat for AwsClient {
                     message: format!("{:#?}", e),
                     code: match e {
                         SdkError::ConstructionFailure(_) => ErrorCode::Other(2),
-                        SdkError::TimeoutError(_) => ErrorCode::Other(2),
+                        SdkError::TimeoutError(_) => ErrorCode::Timeout,
                         SdkError::DispatchFailure(_) => ErrorCode::Other(2),
                         SdkError::ResponseError(e) => {
                             ErrorCode::UnsupportedResponse(e.raw().status().as_u16())
@@ -405,6 +556,7 @@ impl WithStreamChat for AwsClient {
                         }
                         _ => ErrorCode::Other(2),
                     },
+                    retry_after: None,
                 }));
             }
         };
@@ -415,6 +567,7 @@ impl WithStreamChat for AwsClient {
                     client,
                     prompt,
                     content: "".to_string(),
+                    tool_calls: vec![],
                     start_time: system_start,
                     latency: instant_start.elapsed(),
                     model: self.properties.model_id.clone(),
@@ -425,11 +578,13 @@ impl WithStreamChat for AwsClient {
                         prompt_tokens: None,
                         output_tokens: None,
                         total_tokens: None,
+                        cost: None,
                     },
                 }),
                 response,
+                Vec::<(i32, ToolCall)>::new(),
             ),
-            move |(initial_state, mut response)| {
+            move |(initial_state, mut response, mut tool_calls)| {
                 async move {
                     let Some(mut new_state) = initial_state else {
                         return None;
@@ -441,17 +596,58 @@ impl WithStreamChat for AwsClient {
                                 bedrock::types::ConverseStreamOutput::ContentBlockDelta(
                                     content_block_delta,
                                 ) => {
-                                    if let Some(bedrock::types::ContentBlockDelta::Text(
-                                        ref delta,
-                                    )) = content_block_delta.delta
-                                    {
-                                        new_state.content += delta;
+                                    match content_block_delta.delta {
+                                        Some(bedrock::types::ContentBlockDelta::Text(
+                                            ref delta,
+                                        )) if tool_calls.is_empty() => {
+                                            new_state.content += delta;
+                                        }
+                                        Some(bedrock::types::ContentBlockDelta::ToolUse(
+                                            ref delta,
+                                        )) => {
+                                            let index = content_block_delta.content_block_index;
+                                            match tool_calls.iter_mut().find(|(i, _)| *i == index) {
+                                                Some((_, call)) => {
+                                                    call.arguments.push_str(delta.input())
+                                                }
+                                                None => tool_calls.push((
+                                                    index,
+                                                    ToolCall {
+                                                        name: String::new(),
+                                                        arguments: delta.input().to_string(),
+                                                    },
+                                                )),
+                                            }
+                                            new_state.tool_calls = tool_calls
+                                                .iter()
+                                                .map(|(_, call)| call.clone())
+                                                .collect();
+                                            if let Some(content) =
+                                                tool_calls_to_content(&new_state.tool_calls)
+                                            {
+                                                new_state.content = content;
+                                            }
+                                        }
                                         // TODO- handle
+                                        _ => {}
                                     }
-                                    // TODO- handle
                                 }
-                                bedrock::types::ConverseStreamOutput::ContentBlockStart(_) => {
-                                    // TODO- handle
+                                bedrock::types::ConverseStreamOutput::ContentBlockStart(
+                                    content_block_start,
+                                ) => {
+                                    // The tool's name comes before any of its arguments.
+                                    if let Some(bedrock::types::ContentBlockStart::ToolUse(
+                                        ref start,
+                                    )) = content_block_start.start
+                                    {
+                                        tool_calls.push((
+                                            content_block_start.content_block_index,
+                                            ToolCall {
+                                                name: start.name().to_string(),
+                                                arguments: String::new(),
+                                            },
+                                        ));
+                                    }
                                 }
                                 bedrock::types::ConverseStreamOutput::ContentBlockStop(_) => {
                                     // TODO- handle
@@ -462,7 +658,8 @@ impl WithStreamChat for AwsClient {
                                 bedrock::types::ConverseStreamOutput::MessageStop(stop) => {
                                     new_state.metadata.baml_is_complete = match stop.stop_reason {
                                         bedrock::types::StopReason::StopSequence
-                                        | bedrock::types::StopReason::EndTurn => true,
+                                        | bedrock::types::StopReason::EndTurn
+                                        | bedrock::types::StopReason::ToolUse => true,
                                         _ => false,
                                     };
                                     // TODO- handle
@@ -484,7 +681,7 @@ impl WithStreamChat for AwsClient {
                             new_state.latency = instant_start.elapsed();
                             Some((
                                 LLMResponse::Success(new_state.clone()),
-                                (Some(new_state), response),
+                                (Some(new_state), response, tool_calls),
                             ))
                         }
                         Ok(None) => None,
@@ -498,8 +695,9 @@ impl WithStreamChat for AwsClient {
                                 latency: instant_start.elapsed(),
                                 message: format!("Failed to parse event: {:#?}", e),
                                 code: ErrorCode::Other(2),
+                                retry_after: None,
                             }),
-                            (None, response),
+                            (None, response, tool_calls),
                         )),
                     }
                 }
@@ -618,15 +816,17 @@ impl WithChat for AwsClient {
 
     async fn chat(
         &self,
-        _ctx: &RuntimeContext,
+        ctx: &RuntimeContext,
         chat_messages: &Vec<RenderedChatMessage>,
+        tools: &[ToolDefinition],
+        _output_schema: Option<&serde_json::Value>,
     ) -> LLMResponse {
         let client = self.context.name.to_string();
         let model = Some(self.properties.model_id.clone());
         let request_options = self.properties.request_options.clone();
         let prompt = internal_baml_jinja::RenderedPrompt::Chat(chat_messages.clone());
 
-        let aws_client = match self.client_anyhow().await {
+        let aws_client = match self.client_anyhow(ctx).await {
             Ok(c) => c,
             Err(e) => {
                 return LLMResponse::LLMFailure(LLMErrorResponse {
@@ -638,11 +838,12 @@ impl WithChat for AwsClient {
                     latency: web_time::Duration::ZERO,
                     message: format!("{:#?}", e),
                     code: ErrorCode::Other(2),
+                    retry_after: None,
                 })
             }
         };
 
-        let request = match self.build_request(_ctx, chat_messages) {
+        let request = match self.build_request(ctx, chat_messages, tools) {
             Ok(r) => r,
             Err(e) => {
                 return LLMResponse::LLMFailure(LLMErrorResponse {
@@ -654,6 +855,7 @@ impl WithChat for AwsClient {
                     latency: web_time::Duration::ZERO,
                     message: format!("{:#?}", e),
                     code: ErrorCode::Other(2),
+                    retry_after: None,
                 })
             }
         };
@@ -662,7 +864,8 @@ impl WithChat for AwsClient {
             .set_model_id(request.model_id)
             .set_inference_config(request.inference_config)
             .set_system(request.system)
-            .set_messages(request.messages);
+            .set_messages(request.messages)
+            .set_tool_config(request.tool_config);
 
         let system_start = SystemTime::now();
         let instant_start = Instant::now();
@@ -679,16 +882,21 @@ impl WithChat for AwsClient {
                     latency: instant_start.elapsed(),
                     message: format!("{:#?}", e),
                     // TODO: derive this from the aws-returned error
-                    code: ErrorCode::Other(2),
+                    code: match e {
+                        SdkError::TimeoutError(_) => ErrorCode::Timeout,
+                        _ => ErrorCode::Other(2),
+                    },
+                    retry_after: None,
                 });
             }
         };
 
         match self.chat_anyhow(&response).await {
-            Ok(content) => LLMResponse::Success(LLMCompleteResponse {
+            Ok((content, tool_calls)) => LLMResponse::Success(LLMCompleteResponse {
                 client,
                 prompt,
-                content: content.clone(),
+                content,
+                tool_calls,
                 start_time: system_start.clone(),
                 latency: instant_start.elapsed(),
                 request_options,
@@ -696,7 +904,8 @@ impl WithChat for AwsClient {
                 metadata: LLMCompleteResponseMetadata {
                     baml_is_complete: match response.stop_reason {
                         bedrock::types::StopReason::StopSequence
-                        | bedrock::types::StopReason::EndTurn => true,
+                        | bedrock::types::StopReason::EndTurn
+                        | bedrock::types::StopReason::ToolUse => true,
                         _ => false,
                     },
                     finish_reason: Some(response.stop_reason().as_str().into()),
@@ -715,6 +924,7 @@ impl WithChat for AwsClient {
                         .as_ref()
                         .map(|i| i.total_tokens.try_into().ok())
                         .flatten(),
+                    cost: None,
                 },
             }),
             Err(e) => LLMResponse::LLMFailure(LLMErrorResponse {
@@ -726,6 +936,7 @@ impl WithChat for AwsClient {
                 latency: instant_start.elapsed(),
                 message: format!("{:#?}", e),
                 code: ErrorCode::Other(200),
+                retry_after: None,
             }),
         }
     }
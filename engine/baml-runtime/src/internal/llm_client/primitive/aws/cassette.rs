@@ -0,0 +1,93 @@
+use aws_smithy_runtime_api::client::{
+    http::{
+        HttpClient, HttpConnector, HttpConnectorFuture, HttpConnectorSettings, SharedHttpConnector,
+    },
+    orchestrator::HttpRequest,
+    result::ConnectorError,
+    runtime_components::RuntimeComponents,
+};
+use aws_smithy_runtime_api::http::{Response, StatusCode};
+use aws_smithy_runtime_api::shared::IntoShared;
+use aws_smithy_types::body::SdkBody;
+
+use crate::internal::llm_client::cassette::{
+    Cassette, CassetteMode, RecordedRequest, RecordedResponse,
+};
+
+/// Sends Bedrock requests through `reqwest` so that they can be recorded to a
+/// cassette, or answers them from one.
+#[derive(Debug, Clone)]
+pub(super) struct CassetteHttp {
+    cassette: Cassette,
+    client: reqwest::Client,
+}
+
+impl CassetteHttp {
+    pub fn new(cassette: Cassette) -> Self {
+        Self {
+            cassette,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    async fn send(&self, req: HttpRequest) -> Result<Response<SdkBody>, ConnectorError> {
+        let body = req.body().bytes().unwrap_or_default();
+        let request = RecordedRequest::new(req.method(), req.uri(), body);
+
+        let response = match self.cassette.mode {
+            CassetteMode::Replay => self
+                .cassette
+                .replay(&request)
+                .map_err(|e| ConnectorError::user(e.into()))?,
+            CassetteMode::Record => {
+                let method = reqwest::Method::from_bytes(req.method().as_bytes())
+                    .map_err(|e| ConnectorError::user(Box::new(e)))?;
+                let mut builder = self.client.request(method, req.uri());
+                for (k, v) in req.headers() {
+                    builder = builder.header(k, v);
+                }
+                let response = builder
+                    .body(body.to_vec())
+                    .send()
+                    .await
+                    .map_err(|e| ConnectorError::io(Box::new(e)))?;
+                let response = RecordedResponse::read(response)
+                    .await
+                    .map_err(|e| ConnectorError::io(e.into()))?;
+                self.cassette
+                    .record(request, response.clone())
+                    .map_err(|e| ConnectorError::other(e.into(), None))?;
+                response
+            }
+        };
+
+        let status = StatusCode::try_from(response.status)
+            .map_err(|e| ConnectorError::other(Box::new(e), None))?;
+        let body = response
+            .body
+            .bytes()
+            .map_err(|e| ConnectorError::other(e.into(), None))?;
+        let mut sdk_response = Response::new(status, SdkBody::from(body));
+        for (name, value) in response.headers {
+            sdk_response.headers_mut().insert(name, value);
+        }
+        Ok(sdk_response)
+    }
+}
+
+impl HttpConnector for CassetteHttp {
+    fn call(&self, req: HttpRequest) -> HttpConnectorFuture {
+        let clone = self.clone();
+        HttpConnectorFuture::new(async move { clone.send(req).await })
+    }
+}
+
+impl HttpClient for CassetteHttp {
+    fn http_connector(
+        &self,
+        _settings: &HttpConnectorSettings,
+        _components: &RuntimeComponents,
+    ) -> SharedHttpConnector {
+        self.clone().into_shared()
+    }
+}
@@ -1,4 +1,6 @@
 mod aws_client;
+#[cfg(not(target_arch = "wasm32"))]
+mod cassette;
 pub(super) mod types;
 #[cfg(target_arch = "wasm32")]
 pub(super) mod wasm;
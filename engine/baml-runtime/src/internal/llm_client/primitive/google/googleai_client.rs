@@ -6,14 +6,23 @@ use crate::internal::llm_client::{AllowedMetadata, ResolveMediaUrls};
 use crate::RuntimeContext;
 use crate::{
     internal::llm_client::{
+        cache::ResponseCacheConfig,
         primitive::{
-            google::types::{FinishReason, GoogleResponse},
-            request::{make_parsed_request, make_request, RequestBuilder},
+            google::types::{
+                FinishReason, FunctionCall, FunctionDeclaration, GoogleResponse, Tool,
+            },
+            request::{
+                make_parsed_request, make_request, structured_output_from_properties,
+                RequestBuilder, RequestTimeouts,
+            },
         },
+        structured_output::to_gemini_schema,
+        tools::{inline_definitions, tool_calls_to_content, ToolCall, ToolDefinition},
         traits::{
             SseResponseTrait, StreamResponse, WithChat, WithClient, WithNoCompletion,
             WithRetryPolicy, WithStreamChat,
         },
+        usage::ModelPricing,
         ErrorCode, LLMCompleteResponse, LLMCompleteResponseMetadata, LLMErrorResponse, LLMResponse,
         ModelFeatures,
     },
@@ -36,6 +45,10 @@ struct PostRequestProperities {
     model_id: Option<String>,
     properties: HashMap<String, serde_json::Value>,
     allowed_metadata: AllowedMetadata,
+    timeouts: RequestTimeouts,
+    structured_output: bool,
+    cache: Option<ResponseCacheConfig>,
+    pricing: Option<ModelPricing>,
 }
 
 pub struct GoogleAIClient {
@@ -70,6 +83,11 @@ fn resolve_properties(
         .remove("base_url")
         .and_then(|v| v.as_str().map(|s| s.to_string()))
         .unwrap_or_else(|| "https://generativelanguage.googleapis.com/v1beta".to_string());
+    let timeouts = RequestTimeouts::from_properties(&mut properties)?;
+    let structured_output = structured_output_from_properties(&mut properties)?;
+    let cache = ResponseCacheConfig::from_properties(&mut properties)?;
+    let pricing = ModelPricing::from_properties(&mut properties)?;
+
     let allowed_metadata = match properties.remove("allowed_role_metadata") {
         Some(allowed_metadata) => serde_json::from_value(allowed_metadata).context(
             "allowed_role_metadata must be an array of keys. For example: ['key1', 'key2']",
@@ -109,6 +127,10 @@ fn resolve_properties(
         model_id,
         proxy_url: ctx.env.get("BOUNDARY_PROXY_URL").map(|s| s.to_string()),
         allowed_metadata,
+        timeouts,
+        structured_output,
+        cache,
+        pricing,
     })
 }
 
@@ -166,6 +188,7 @@ impl SseResponseTrait for GoogleAIClient {
                         client: client_name.clone(),
                         prompt: internal_baml_jinja::RenderedPrompt::Chat(prompt.clone()),
                         content: "".to_string(),
+                        tool_calls: vec![],
                         start_time: system_start,
                         latency: instant_start.elapsed(),
                         model: model_id,
@@ -176,6 +199,7 @@ impl SseResponseTrait for GoogleAIClient {
                             prompt_tokens: None,
                             output_tokens: None,
                             total_tokens: None,
+                            cost: None,
                         },
                     }),
                     move |accumulated: &mut Result<LLMCompleteResponse>, event| {
@@ -202,6 +226,7 @@ impl SseResponseTrait for GoogleAIClient {
                                         latency: instant_start.elapsed(),
                                         message: format!("Failed to parse event: {:#?}", e),
                                         code: ErrorCode::UnsupportedResponse(2),
+                                        retry_after: None,
                                     },
                                 )));
                             }
@@ -211,6 +236,18 @@ impl SseResponseTrait for GoogleAIClient {
                             if let Some(content) = choice.content.parts.get(0) {
                                 inner.content += &content.text;
                             }
+                            // Gemini sends each tool call whole, in a single event.
+                            inner.tool_calls.extend(
+                                choice
+                                    .content
+                                    .parts
+                                    .iter()
+                                    .filter_map(|p| p.function_call.as_ref())
+                                    .map(to_tool_call),
+                            );
+                            if let Some(content) = tool_calls_to_content(&inner.tool_calls) {
+                                inner.content = content;
+                            }
                             match choice.finish_reason.as_ref() {
                                 Some(FinishReason::Stop) => {
                                     inner.metadata.baml_is_complete = true;
@@ -232,15 +269,25 @@ impl SseResponseTrait for GoogleAIClient {
 impl WithStreamChat for GoogleAIClient {
     async fn stream_chat(
         &self,
-        _ctx: &RuntimeContext,
+        ctx: &RuntimeContext,
         prompt: &Vec<RenderedChatMessage>,
+        tools: &[ToolDefinition],
+        output_schema: Option<&serde_json::Value>,
     ) -> StreamResponse {
         //incomplete, streaming response object is returned
-        let (response, system_now, instant_now) =
-            match make_request(self, either::Either::Right(prompt), true).await {
-                Ok(v) => v,
-                Err(e) => return Err(e),
-            };
+        let (response, system_now, instant_now) = match make_request(
+            self,
+            ctx,
+            either::Either::Right(prompt),
+            tools,
+            output_schema,
+            true,
+        )
+        .await
+        {
+            Ok(v) => v,
+            Err(e) => return Err(e),
+        };
         self.response_stream(response, prompt, system_now, instant_now)
     }
 }
@@ -269,7 +316,7 @@ impl GoogleAIClient {
                 .retry_policy_id
                 .as_ref()
                 .map(|s| s.to_string()),
-            client: create_client()?,
+            client: create_client(properties.timeouts.connect)?,
             properties,
         })
     }
@@ -300,7 +347,7 @@ impl GoogleAIClient {
                 allowed_metadata: properties.allowed_metadata.clone(),
             },
             retry_policy: client.retry_policy.clone(),
-            client: create_client()?,
+            client: create_client(properties.timeouts.connect)?,
             properties,
         })
     }
@@ -311,9 +358,23 @@ impl RequestBuilder for GoogleAIClient {
         &self.client
     }
 
+    fn timeouts(&self) -> &RequestTimeouts {
+        &self.properties.timeouts
+    }
+
+    fn response_cache(&self) -> Option<&ResponseCacheConfig> {
+        self.properties.cache.as_ref()
+    }
+
+    fn pricing(&self) -> Option<&ModelPricing> {
+        self.properties.pricing.as_ref()
+    }
+
     async fn build_request(
         &self,
         prompt: either::Either<&String, &Vec<RenderedChatMessage>>,
+        tools: &[ToolDefinition],
+        output_schema: Option<&serde_json::Value>,
         allow_proxy: bool,
         stream: bool,
     ) -> Result<reqwest::RequestBuilder> {
@@ -360,6 +421,51 @@ impl RequestBuilder for GoogleAIClient {
             }
         }
 
+        if !tools.is_empty() {
+            let declarations = tools
+                .iter()
+                .map(|tool| {
+                    Ok(FunctionDeclaration {
+                        name: tool.name.clone(),
+                        description: tool.description.clone(),
+                        parameters: Some(
+                            inline_definitions(&tool.parameters)
+                                .context(format!("Invalid tool `{}`", tool.name))?,
+                        ),
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            body_obj.insert(
+                "tools".into(),
+                json!([Tool {
+                    function_declarations: Some(declarations),
+                    retrieval: None,
+                }]),
+            );
+        }
+
+        // Gemini can't answer with a schema and call functions at once.
+        if let Some(schema) = output_schema {
+            if self.properties.structured_output && prompt.is_right() && tools.is_empty() {
+                let key = match body_obj.contains_key("generation_config") {
+                    true => "generation_config",
+                    false => "generationConfig",
+                };
+                let config = body_obj.entry(key).or_insert_with(|| json!({}));
+                if let Some(config) = config.as_object_mut() {
+                    if !config.contains_key("responseSchema")
+                        && !config.contains_key("response_schema")
+                    {
+                        config.insert("responseMimeType".into(), json!("application/json"));
+                        config.insert(
+                            "responseSchema".into(),
+                            to_gemini_schema(schema).context("Invalid output schema")?,
+                        );
+                    }
+                }
+            }
+        }
+
         Ok(req.json(&body))
     }
 
@@ -376,15 +482,27 @@ impl WithChat for GoogleAIClient {
         ))
     }
 
-    async fn chat(&self, _ctx: &RuntimeContext, prompt: &Vec<RenderedChatMessage>) -> LLMResponse {
+    async fn chat(
+        &self,
+        ctx: &RuntimeContext,
+        prompt: &Vec<RenderedChatMessage>,
+        tools: &[ToolDefinition],
+        output_schema: Option<&serde_json::Value>,
+    ) -> LLMResponse {
         //non-streaming, complete response is returned
-        let (response, system_now, instant_now) =
-            match make_parsed_request::<GoogleResponse>(self, either::Either::Right(prompt), false)
-                .await
-            {
-                Ok(v) => v,
-                Err(e) => return e,
-            };
+        let (response, system_now, instant_now) = match make_parsed_request::<GoogleResponse>(
+            self,
+            ctx,
+            either::Either::Right(prompt),
+            tools,
+            output_schema,
+            false,
+        )
+        .await
+        {
+            Ok(v) => v,
+            Err(e) => return e,
+        };
 
         if response.candidates.len() != 1 {
             return LLMResponse::LLMFailure(LLMErrorResponse {
@@ -399,13 +517,24 @@ impl WithChat for GoogleAIClient {
                     response.candidates.len()
                 ),
                 code: ErrorCode::Other(200),
+                retry_after: None,
             });
         }
 
+        let tool_calls = response.candidates[0]
+            .content
+            .parts
+            .iter()
+            .filter_map(|p| p.function_call.as_ref())
+            .map(to_tool_call)
+            .collect::<Vec<_>>();
+
         LLMResponse::Success(LLMCompleteResponse {
             client: self.context.name.to_string(),
             prompt: internal_baml_jinja::RenderedPrompt::Chat(prompt.clone()),
-            content: response.candidates[0].content.parts[0].text.clone(),
+            content: tool_calls_to_content(&tool_calls)
+                .unwrap_or_else(|| response.candidates[0].content.parts[0].text.clone()),
+            tool_calls,
             start_time: system_now,
             latency: instant_now.elapsed(),
             request_options: self.properties.properties.clone(),
@@ -414,7 +543,7 @@ impl WithChat for GoogleAIClient {
                 .properties
                 .get("model")
                 .and_then(|v| v.as_str().map(|s| s.to_string()))
-                .or_else(|| _ctx.env.get("default model").map(|s| s.to_string()))
+                .or_else(|| ctx.env.get("default model").map(|s| s.to_string()))
                 .unwrap_or_else(|| "".to_string()),
             metadata: LLMCompleteResponseMetadata {
                 baml_is_complete: match response.candidates[0].finish_reason {
@@ -428,6 +557,7 @@ impl WithChat for GoogleAIClient {
                 prompt_tokens: response.usage_metadata.prompt_token_count,
                 output_tokens: response.usage_metadata.candidates_token_count,
                 total_tokens: response.usage_metadata.total_token_count,
+                cost: None,
             },
         })
     }
@@ -511,3 +641,10 @@ impl ToProviderMessage for GoogleAIClient {
         Ok(message)
     }
 }
+
+fn to_tool_call(call: &FunctionCall) -> ToolCall {
+    ToolCall {
+        name: call.name.clone(),
+        arguments: call.args.as_ref().map_or("{}".into(), |a| a.to_string()),
+    }
+}
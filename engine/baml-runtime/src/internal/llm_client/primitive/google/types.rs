@@ -14,7 +14,9 @@ pub struct GoogleRequestBody {
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct Tool {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub function_declarations: Option<Vec<FunctionDeclaration>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub retrieval: Option<Retrieval>,
 }
 
@@ -22,8 +24,11 @@ pub struct Tool {
 #[serde(rename_all = "camelCase")]
 pub struct FunctionDeclaration {
     pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
-    pub parameters: Option<Schema>,
+    /// JSON schema of the arguments, without `$ref`s.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -239,6 +244,7 @@ pub struct Content {
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct Part {
+    #[serde(default)]
     pub text: String,
     pub inline_data: Option<Blob>,
     pub file_data: Option<FileData>,
@@ -264,7 +270,7 @@ pub struct FileData {
 #[derive(Serialize, Deserialize, Debug)]
 pub struct FunctionCall {
     pub name: String,
-    pub args: Option<Vec<Struct>>,
+    pub args: Option<serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
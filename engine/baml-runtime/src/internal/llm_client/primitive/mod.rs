@@ -15,14 +15,17 @@ use self::{
 };
 
 use super::{
+    cache::ResponseCacheConfig,
     orchestrator::{
         ExecutionScope, IterOrchestrator, OrchestrationScope, OrchestrationState, OrchestratorNode,
         OrchestratorNodeIterator,
     },
+    tools::ToolDefinition,
     traits::{
         WithClient, WithClientProperties, WithPrompt, WithRenderRawCurl, WithRetryPolicy,
         WithSingleCallable, WithStreamable,
     },
+    usage::ModelPricing,
     LLMResponse,
 };
 
@@ -118,6 +121,9 @@ impl TryFrom<(&ClientProperty, &RuntimeContext)> for LLMPrimitiveProvider {
                     "vertex-ai",
                     "fallback",
                     "round-robin",
+                    "weighted",
+                    "least-latency",
+                    "least-errors",
                 ];
                 anyhow::bail!(
                     "Unsupported provider: {}. Available ones are: {}",
@@ -157,6 +163,9 @@ impl TryFrom<(&ClientWalker<'_>, &RuntimeContext)> for LLMPrimitiveProvider {
                     "vertex-ai",
                     "fallback",
                     "round-robin",
+                    "weighted",
+                    "least-latency",
+                    "least-errors",
                 ];
                 anyhow::bail!(
                     "Unsupported provider: {}. Available ones are: {}",
@@ -186,9 +195,20 @@ impl WithRenderRawCurl for LLMPrimitiveProvider {
         &self,
         ctx: &RuntimeContext,
         prompt: &Vec<internal_baml_jinja::RenderedChatMessage>,
+        tools: &[ToolDefinition],
+        output_schema: Option<&serde_json::Value>,
         render_settings: RenderCurlSettings,
     ) -> Result<String> {
-        match_llm_provider!(self, render_raw_curl, async, ctx, prompt, render_settings)
+        match_llm_provider!(
+            self,
+            render_raw_curl,
+            async,
+            ctx,
+            prompt,
+            tools,
+            output_schema,
+            render_settings
+        )
     }
 }
 
@@ -197,8 +217,10 @@ impl WithSingleCallable for LLMPrimitiveProvider {
         &self,
         ctx: &RuntimeContext,
         prompt: &internal_baml_jinja::RenderedPrompt,
+        tools: &[ToolDefinition],
+        output_schema: Option<&serde_json::Value>,
     ) -> LLMResponse {
-        match_llm_provider!(self, single_call, async, ctx, prompt)
+        match_llm_provider!(self, single_call, async, ctx, prompt, tools, output_schema)
     }
 }
 
@@ -207,8 +229,10 @@ impl WithStreamable for LLMPrimitiveProvider {
         &self,
         ctx: &RuntimeContext,
         prompt: &internal_baml_jinja::RenderedPrompt,
+        tools: &[ToolDefinition],
+        output_schema: Option<&serde_json::Value>,
     ) -> super::traits::StreamResponse {
-        match_llm_provider!(self, stream, async, ctx, prompt)
+        match_llm_provider!(self, stream, async, ctx, prompt, tools, output_schema)
     }
 }
 
@@ -218,12 +242,16 @@ impl IterOrchestrator for Arc<LLMPrimitiveProvider> {
         _state: &mut OrchestrationState,
         _previous: OrchestrationScope,
         _ctx: &RuntimeContext,
-        _client_lookup: &'a dyn InternalClientLookup,
+        client_lookup: &'a dyn InternalClientLookup,
     ) -> Result<OrchestratorNodeIterator> {
+        let cache = self
+            .response_cache()
+            .map(|config| client_lookup.get_response_cache(config));
         Ok(vec![OrchestratorNode::new(
             ExecutionScope::Direct(self.name().to_string()),
             self.clone(),
-        )])
+        )
+        .with_response_cache(cache)])
     }
 }
 
@@ -244,9 +272,25 @@ impl LLMPrimitiveProvider {
         &match_llm_provider!(self, context).name
     }
 
+    pub fn provider(&self) -> &str {
+        &match_llm_provider!(self, context).provider
+    }
+
     pub fn request_options(&self) -> &std::collections::HashMap<String, serde_json::Value> {
         match_llm_provider!(self, request_options)
     }
+
+    pub fn timeouts(&self) -> &request::RequestTimeouts {
+        match_llm_provider!(self, timeouts)
+    }
+
+    pub fn response_cache(&self) -> Option<&ResponseCacheConfig> {
+        match_llm_provider!(self, response_cache)
+    }
+
+    pub fn pricing(&self) -> Option<&ModelPricing> {
+        match_llm_provider!(self, pricing)
+    }
 }
 
 use super::resolve_properties_walker;
@@ -16,14 +16,18 @@ use super::types::{ChatCompletionResponse, ChatCompletionResponseDelta, FinishRe
 
 use crate::client_registry::ClientProperty;
 use crate::internal::llm_client::primitive::request::{
-    make_parsed_request, make_request, RequestBuilder,
+    make_parsed_request, make_request, RequestBuilder, RequestTimeouts,
 };
 use crate::internal::llm_client::traits::{
     SseResponseTrait, StreamResponse, ToProviderMessage, ToProviderMessageExt,
     WithClientProperties, WithStreamChat,
 };
 use crate::internal::llm_client::{
+    cache::ResponseCacheConfig,
+    structured_output::object_schema,
+    tools::{tool_calls_to_content, ToolCall, ToolDefinition},
     traits::{WithChat, WithClient, WithNoCompletion, WithRetryPolicy},
+    usage::ModelPricing,
     LLMResponse, ModelFeatures,
 };
 
@@ -149,11 +153,20 @@ impl WithChat for OpenAIClient {
         ))
     }
 
-    async fn chat(&self, _ctx: &RuntimeContext, prompt: &Vec<RenderedChatMessage>) -> LLMResponse {
+    async fn chat(
+        &self,
+        ctx: &RuntimeContext,
+        prompt: &Vec<RenderedChatMessage>,
+        tools: &[ToolDefinition],
+        output_schema: Option<&serde_json::Value>,
+    ) -> LLMResponse {
         let (response, system_start, instant_start) =
             match make_parsed_request::<ChatCompletionResponse>(
                 self,
+                ctx,
                 either::Either::Right(prompt),
+                tools,
+                output_schema,
                 false,
             )
             .await
@@ -175,20 +188,33 @@ impl WithChat for OpenAIClient {
                     response.choices.len()
                 ),
                 code: ErrorCode::Other(200),
+                retry_after: None,
             });
         }
 
         let usage = response.usage.as_ref();
+        let message = &response.choices[0].message;
+        let tool_calls = message
+            .tool_calls
+            .iter()
+            .flatten()
+            .map(|call| ToolCall {
+                name: call.function.name.clone(),
+                arguments: call.function.arguments.clone(),
+            })
+            .collect::<Vec<_>>();
 
         LLMResponse::Success(LLMCompleteResponse {
             client: self.context.name.to_string(),
             prompt: internal_baml_jinja::RenderedPrompt::Chat(prompt.clone()),
-            content: response.choices[0]
-                .message
-                .content
-                .as_ref()
-                .map_or("", |s| s.as_str())
-                .to_string(),
+            content: tool_calls_to_content(&tool_calls).unwrap_or_else(|| {
+                message
+                    .content
+                    .as_ref()
+                    .map_or("", |s| s.as_str())
+                    .to_string()
+            }),
+            tool_calls,
             start_time: system_start,
             latency: instant_start.elapsed(),
             model: response.model,
@@ -196,14 +222,16 @@ impl WithChat for OpenAIClient {
             metadata: LLMCompleteResponseMetadata {
                 baml_is_complete: match response.choices.get(0) {
                     Some(c) => match c.finish_reason {
-                        Some(FinishReason::Stop) => true,
+                        Some(FinishReason::Stop) | Some(FinishReason::ToolCalls) => true,
                         _ => false,
                     },
                     None => false,
                 },
                 finish_reason: match response.choices.get(0) {
                     Some(c) => match c.finish_reason {
-                        Some(FinishReason::Stop) => Some(FinishReason::Stop.to_string()),
+                        Some(r @ (FinishReason::Stop | FinishReason::ToolCalls)) => {
+                            Some(r.to_string())
+                        }
                         _ => None,
                     },
                     None => None,
@@ -211,6 +239,7 @@ impl WithChat for OpenAIClient {
                 prompt_tokens: usage.map(|u| u.prompt_tokens),
                 output_tokens: usage.map(|u| u.completion_tokens),
                 total_tokens: usage.map(|u| u.total_tokens),
+                cost: None,
             },
         })
     }
@@ -221,9 +250,23 @@ impl RequestBuilder for OpenAIClient {
         &self.client
     }
 
+    fn timeouts(&self) -> &RequestTimeouts {
+        &self.properties.timeouts
+    }
+
+    fn response_cache(&self) -> Option<&ResponseCacheConfig> {
+        self.properties.cache.as_ref()
+    }
+
+    fn pricing(&self) -> Option<&ModelPricing> {
+        self.properties.pricing.as_ref()
+    }
+
     async fn build_request(
         &self,
         prompt: either::Either<&String, &Vec<RenderedChatMessage>>,
+        tools: &[ToolDefinition],
+        output_schema: Option<&serde_json::Value>,
         allow_proxy: bool,
         stream: bool,
     ) -> Result<reqwest::RequestBuilder> {
@@ -275,6 +318,50 @@ impl RequestBuilder for OpenAIClient {
             }
         }
 
+        if !tools.is_empty() {
+            body_obj.insert(
+                "tools".into(),
+                tools
+                    .iter()
+                    .map(|tool| {
+                        let mut function = json!({
+                            "name": tool.name,
+                            "parameters": tool.parameters,
+                        });
+                        if let Some(description) = &tool.description {
+                            function["description"] = json!(description);
+                        }
+                        json!({
+                            "type": "function",
+                            "function": function,
+                        })
+                    })
+                    .collect(),
+            );
+        }
+
+        // Only chat completions take a schema, and tool calls are already
+        // checked against theirs.
+        if let Some(schema) = object_schema(output_schema) {
+            if self.properties.structured_output
+                && prompt.is_right()
+                && tools.is_empty()
+                && !body_obj.contains_key("response_format")
+            {
+                body_obj.insert(
+                    "response_format".into(),
+                    json!({
+                        "type": "json_schema",
+                        "json_schema": {
+                            "name": "output",
+                            "strict": true,
+                            "schema": schema,
+                        },
+                    }),
+                );
+            }
+        }
+
         if stream {
             body_obj.insert("stream".into(), json!(true));
             if self.provider == "openai" {
@@ -323,6 +410,7 @@ impl SseResponseTrait for OpenAIClient {
                         client: client_name.clone(),
                         prompt: internal_baml_jinja::RenderedPrompt::Chat(prompt.clone()),
                         content: "".to_string(),
+                        tool_calls: vec![],
                         start_time: system_start,
                         latency: instant_start.elapsed(),
                         model: "".to_string(),
@@ -333,6 +421,7 @@ impl SseResponseTrait for OpenAIClient {
                             prompt_tokens: None,
                             output_tokens: None,
                             total_tokens: None,
+                            cost: None,
                         },
                     }),
                     move |accumulated: &mut Result<LLMCompleteResponse>, event| {
@@ -359,6 +448,7 @@ impl SseResponseTrait for OpenAIClient {
                                         latency: instant_start.elapsed(),
                                         message: format!("Failed to parse event: {:#?}", e),
                                         code: ErrorCode::UnsupportedResponse(2),
+                                        retry_after: None,
                                     },
                                 )));
                             }
@@ -367,12 +457,30 @@ impl SseResponseTrait for OpenAIClient {
                             if let Some(content) = choice.delta.content.as_ref() {
                                 inner.content += content.as_str();
                             }
+                            // Arguments of each tool call, indexed like the model's tool calls.
+                            let tool_calls = &mut inner.tool_calls;
+                            for call in choice.delta.tool_calls.iter().flatten() {
+                                if tool_calls.len() <= call.index {
+                                    tool_calls.resize(call.index + 1, ToolCall::default());
+                                }
+                                // The name comes with the first fragment of a call.
+                                if let Some(function) = call.function.as_ref() {
+                                    if let Some(name) = function.name.as_ref() {
+                                        tool_calls[call.index].name += name;
+                                    }
+                                    if let Some(arguments) = function.arguments.as_ref() {
+                                        tool_calls[call.index].arguments += arguments;
+                                    }
+                                }
+                            }
+                            if let Some(content) = tool_calls_to_content(&inner.tool_calls) {
+                                inner.content = content;
+                            }
                             inner.model = event.model;
                             match choice.finish_reason.as_ref() {
-                                Some(FinishReason::Stop) => {
+                                Some(r @ (FinishReason::Stop | FinishReason::ToolCalls)) => {
                                     inner.metadata.baml_is_complete = true;
-                                    inner.metadata.finish_reason =
-                                        Some(FinishReason::Stop.to_string());
+                                    inner.metadata.finish_reason = Some(r.to_string());
                                 }
                                 finish_reason => {
                                     inner.metadata.baml_is_complete = false;
@@ -398,14 +506,24 @@ impl SseResponseTrait for OpenAIClient {
 impl WithStreamChat for OpenAIClient {
     async fn stream_chat(
         &self,
-        _ctx: &RuntimeContext,
+        ctx: &RuntimeContext,
         prompt: &Vec<RenderedChatMessage>,
+        tools: &[ToolDefinition],
+        output_schema: Option<&serde_json::Value>,
     ) -> StreamResponse {
-        let (resp, system_start, instant_start) =
-            match make_request(self, either::Either::Right(prompt), true).await {
-                Ok(v) => v,
-                Err(e) => return Err(e),
-            };
+        let (resp, system_start, instant_start) = match make_request(
+            self,
+            ctx,
+            either::Either::Right(prompt),
+            tools,
+            output_schema,
+            true,
+        )
+        .await
+        {
+            Ok(v) => v,
+            Err(e) => return Err(e),
+        };
         self.response_stream(resp, prompt, system_start, instant_start)
     }
 }
@@ -427,9 +545,9 @@ macro_rules! make_openai_client {
                 resolve_media_urls: ResolveMediaUrls::Never,
                 allowed_metadata: $properties.allowed_metadata.clone(),
             },
+            client: create_client($properties.timeouts.connect)?,
             properties: $properties,
             retry_policy: $client.retry_policy.clone(),
-            client: create_client()?,
         })
     };
     ($client:ident, $properties:ident, $provider:expr) => {
@@ -448,13 +566,13 @@ macro_rules! make_openai_client {
                 resolve_media_urls: ResolveMediaUrls::Never,
                 allowed_metadata: $properties.allowed_metadata.clone(),
             },
+            client: create_client($properties.timeouts.connect)?,
             properties: $properties,
             retry_policy: $client
                 .elem()
                 .retry_policy_id
                 .as_ref()
                 .map(|s| s.to_string()),
-            client: create_client()?,
         })
     };
 }
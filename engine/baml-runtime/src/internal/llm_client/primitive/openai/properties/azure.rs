@@ -2,7 +2,15 @@ use std::collections::HashMap;
 
 use anyhow::{Context, Result};
 
-use crate::{internal::llm_client::AllowedMetadata, RuntimeContext};
+use crate::{
+    internal::llm_client::{
+        cache::ResponseCacheConfig,
+        primitive::request::{structured_output_from_properties, RequestTimeouts},
+        usage::ModelPricing,
+        AllowedMetadata,
+    },
+    RuntimeContext,
+};
 
 use super::PostRequestProperties;
 
@@ -16,6 +24,11 @@ pub fn resolve_properties(
         .remove("default_role")
         .and_then(|v| v.as_str().map(|s| s.to_string()))
         .unwrap_or_else(|| "system".to_string());
+    let timeouts = RequestTimeouts::from_properties(&mut properties)?;
+    let structured_output = structured_output_from_properties(&mut properties)?;
+    let cache = ResponseCacheConfig::from_properties(&mut properties)?;
+    let pricing = ModelPricing::from_properties(&mut properties)?;
+
     let allowed_metadata = match properties.remove("allowed_role_metadata") {
         Some(allowed_metadata) => serde_json::from_value(allowed_metadata)
             .context("allowed_role_metadata must be an array of keys. For example: ['key1', 'key2']")?,
@@ -101,5 +114,9 @@ pub fn resolve_properties(
         // proxy_url: None,
         proxy_url: ctx.env.get("BOUNDARY_PROXY_URL").map(|s| s.to_string()),
         query_params,
+        timeouts,
+        structured_output,
+        cache,
+        pricing,
     })
 }
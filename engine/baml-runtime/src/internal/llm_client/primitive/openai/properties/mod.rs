@@ -5,7 +5,10 @@ pub(crate) mod openai;
 
 use std::collections::HashMap;
 
-use crate::internal::llm_client::AllowedMetadata;
+use crate::internal::llm_client::{
+    cache::ResponseCacheConfig, primitive::request::RequestTimeouts, usage::ModelPricing,
+    AllowedMetadata,
+};
 
 pub struct PostRequestProperties {
     pub default_role: String,
@@ -17,4 +20,8 @@ pub struct PostRequestProperties {
     // These are passed directly to the OpenAI API.
     pub properties: HashMap<String, serde_json::Value>,
     pub allowed_metadata: AllowedMetadata,
+    pub timeouts: RequestTimeouts,
+    pub structured_output: bool,
+    pub cache: Option<ResponseCacheConfig>,
+    pub pricing: Option<ModelPricing>,
 }
@@ -2,7 +2,15 @@ use std::collections::HashMap;
 
 use anyhow::{Context, Result};
 
-use crate::{internal::llm_client::AllowedMetadata, RuntimeContext};
+use crate::{
+    internal::llm_client::{
+        cache::ResponseCacheConfig,
+        primitive::request::{structured_output_from_properties, RequestTimeouts},
+        usage::ModelPricing,
+        AllowedMetadata,
+    },
+    RuntimeContext,
+};
 
 use super::PostRequestProperties;
 
@@ -19,6 +27,11 @@ pub fn resolve_properties(
         .remove("base_url")
         .and_then(|v| v.as_str().map(|s| s.to_string()))
         .unwrap_or_else(|| "http://localhost:11434/v1".to_string());
+    let timeouts = RequestTimeouts::from_properties(&mut properties)?;
+    let structured_output = structured_output_from_properties(&mut properties)?;
+    let cache = ResponseCacheConfig::from_properties(&mut properties)?;
+    let pricing = ModelPricing::from_properties(&mut properties)?;
+
     let allowed_metadata = match properties.remove("allowed_role_metadata") {
         Some(allowed_metadata) => serde_json::from_value(allowed_metadata)
             .context("allowed_role_metadata must be an array of keys. For example: ['key1', 'key2']")?,
@@ -60,5 +73,9 @@ pub fn resolve_properties(
             .map(|s| Some(s.to_string()))
             .unwrap_or(None),
         query_params: Default::default(),
+        timeouts,
+        structured_output,
+        cache,
+        pricing,
     })
 }
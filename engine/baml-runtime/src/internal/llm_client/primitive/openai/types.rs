@@ -65,7 +65,7 @@ pub struct ChatCompletionResponseMessage {
     pub content: Option<String>,
 
     /// The tool calls generated by the model, such as function calls.
-    // pub tool_calls: Option<Vec<ChatCompletionMessageToolCall>>,
+    pub tool_calls: Option<Vec<ChatCompletionMessageToolCall>>,
 
     /// The role of the author of this message.
     pub role: ChatCompletionMessageRole,
@@ -89,6 +89,8 @@ pub struct ChatCompletionMessageDelta {
     pub role: Option<ChatCompletionMessageRole>,
     /// The contents of the message
     pub content: Option<String>,
+    /// Fragments of the tool calls generated by the model.
+    pub tool_calls: Option<Vec<ChatCompletionMessageToolCallDelta>>,
     // The name of the user in a multi-user chat
     // #[serde(skip_serializing_if = "Option::is_none")]
     // pub name: Option<String>,
@@ -99,6 +101,33 @@ pub struct ChatCompletionMessageDelta {
     // pub function_call: Option<ChatCompletionFunctionCallDelta>,
 }
 
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct ChatCompletionMessageToolCall {
+    pub id: String,
+    pub function: FunctionCall,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct FunctionCall {
+    pub name: String,
+    /// The arguments, as a JSON string.
+    pub arguments: String,
+}
+
+/// Same as ChatCompletionMessageToolCall, but received during a response stream.
+#[derive(Deserialize, Clone, Debug)]
+pub struct ChatCompletionMessageToolCallDelta {
+    /// Which tool call this fragment belongs to.
+    pub index: usize,
+    pub function: Option<FunctionCallDelta>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct FunctionCallDelta {
+    pub name: Option<String>,
+    pub arguments: Option<String>,
+}
+
 #[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum ChatCompletionMessageRole {
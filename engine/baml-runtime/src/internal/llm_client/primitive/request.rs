@@ -5,13 +5,22 @@ use internal_baml_jinja::RenderedChatMessage;
 use reqwest::Response;
 use serde::de::DeserializeOwned;
 
-use crate::internal::llm_client::{traits::WithClient, ErrorCode, LLMErrorResponse, LLMResponse};
+use crate::{
+    internal::llm_client::{
+        cache::ResponseCacheConfig, cassette::RecordedRequest, tools::ToolDefinition,
+        traits::WithClient, unquoted_bool, usage::ModelPricing, ErrorCode, LLMErrorResponse,
+        LLMResponse,
+    },
+    RuntimeContext,
+};
 
 pub trait RequestBuilder {
     #[allow(async_fn_in_trait)]
     async fn build_request(
         &self,
         prompt: either::Either<&String, &Vec<RenderedChatMessage>>,
+        tools: &[ToolDefinition],
+        output_schema: Option<&serde_json::Value>,
         allow_proxy: bool,
         stream: bool,
     ) -> Result<reqwest::RequestBuilder>;
@@ -19,6 +28,60 @@ pub trait RequestBuilder {
     fn request_options(&self) -> &HashMap<String, serde_json::Value>;
 
     fn http_client(&self) -> &reqwest::Client;
+
+    fn timeouts(&self) -> &RequestTimeouts;
+
+    fn response_cache(&self) -> Option<&ResponseCacheConfig>;
+
+    fn pricing(&self) -> Option<&ModelPricing>;
+}
+
+/// Timeouts set through the `*_timeout_ms` client options.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RequestTimeouts {
+    /// How long to wait for a connection to the provider.
+    pub connect: Option<web_time::Duration>,
+    /// How long to wait for the provider to start responding.
+    pub request: Option<web_time::Duration>,
+    /// How long a stream may go without sending anything.
+    pub idle_stream: Option<web_time::Duration>,
+    /// How long a call may take from start to finish, streaming included.
+    pub total: Option<web_time::Duration>,
+}
+
+impl RequestTimeouts {
+    /// Takes the timeout options out of the client's options, so that they don't
+    /// end up in the request body.
+    pub fn from_properties(properties: &mut HashMap<String, serde_json::Value>) -> Result<Self> {
+        let mut take = |key: &str| {
+            properties
+                .remove(key)
+                .map(|v| match v.as_u64() {
+                    Some(ms) if ms > 0 => Ok(web_time::Duration::from_millis(ms)),
+                    _ => anyhow::bail!("{key} must be a positive number of milliseconds"),
+                })
+                .transpose()
+        };
+
+        Ok(Self {
+            connect: take("connect_timeout_ms")?,
+            request: take("request_timeout_ms")?,
+            idle_stream: take("idle_stream_timeout_ms")?,
+            total: take("total_timeout_ms")?,
+        })
+    }
+}
+
+/// Takes the `structured_output` option out of the client's options. When set,
+/// the function's return type is also sent to the provider as a strict schema.
+pub fn structured_output_from_properties(
+    properties: &mut HashMap<String, serde_json::Value>,
+) -> Result<bool> {
+    match properties.remove("structured_output").map(unquoted_bool) {
+        None => Ok(false),
+        Some(serde_json::Value::Bool(enabled)) => Ok(enabled),
+        Some(_) => anyhow::bail!("structured_output must be true or false"),
+    }
 }
 
 fn to_prompt(
@@ -30,15 +93,94 @@ fn to_prompt(
     }
 }
 
+/// Reads how long the provider wants us to wait before trying again.
+///
+/// Understands `retry-after-ms` (Azure), `retry-after` (seconds or an HTTP date),
+/// the `x-ratelimit-reset-*` family, whose values are either seconds, durations
+/// like `6m0s` (OpenAI) or RFC 3339 timestamps, and Anthropic's
+/// `anthropic-ratelimit-*-reset` timestamps. When several are present we go
+/// with the longest wait.
+pub(crate) fn retry_after_from_headers(
+    headers: &reqwest::header::HeaderMap,
+) -> Option<web_time::Duration> {
+    let now = chrono::Utc::now();
+    let until = |date: chrono::DateTime<chrono::FixedOffset>| {
+        (date.with_timezone(&chrono::Utc) - now)
+            .to_std()
+            .unwrap_or_default()
+    };
+
+    headers
+        .iter()
+        .filter_map(|(name, value)| {
+            let value = value.to_str().ok()?.trim();
+            match name.as_str() {
+                "retry-after-ms" => value
+                    .parse::<f64>()
+                    .ok()
+                    .and_then(|ms| web_time::Duration::try_from_secs_f64(ms / 1000.0).ok()),
+                "retry-after" => parse_seconds(value)
+                    .or_else(|| chrono::DateTime::parse_from_rfc2822(value).ok().map(until)),
+                name if name.starts_with("x-ratelimit-reset") => parse_seconds(value)
+                    .or_else(|| parse_go_duration(value))
+                    .or_else(|| chrono::DateTime::parse_from_rfc3339(value).ok().map(until)),
+                name if name.starts_with("anthropic-ratelimit-") && name.ends_with("-reset") => {
+                    chrono::DateTime::parse_from_rfc3339(value).ok().map(until)
+                }
+                _ => None,
+            }
+        })
+        .max()
+}
+
+fn parse_seconds(value: &str) -> Option<web_time::Duration> {
+    value
+        .parse::<f64>()
+        .ok()
+        .and_then(|s| web_time::Duration::try_from_secs_f64(s).ok())
+}
+
+/// Parses durations like `1h2m3.5s` or `250ms`.
+fn parse_go_duration(value: &str) -> Option<web_time::Duration> {
+    if value.is_empty() {
+        return None;
+    }
+    let mut total = 0.0;
+    let mut rest = value;
+    while !rest.is_empty() {
+        let number_end = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let number = rest[..number_end].parse::<f64>().ok()?;
+        rest = &rest[number_end..];
+        let unit_end = rest
+            .find(|c: char| c.is_ascii_digit() || c == '.')
+            .unwrap_or(rest.len());
+        total += number
+            * match &rest[..unit_end] {
+                "h" => 3600.0,
+                "m" => 60.0,
+                "s" => 1.0,
+                "ms" => 0.001,
+                _ => return None,
+            };
+        rest = &rest[unit_end..];
+    }
+    web_time::Duration::try_from_secs_f64(total).ok()
+}
+
 pub async fn make_request(
     client: &(impl WithClient + RequestBuilder),
+    ctx: &RuntimeContext,
     prompt: either::Either<&String, &Vec<RenderedChatMessage>>,
+    tools: &[ToolDefinition],
+    output_schema: Option<&serde_json::Value>,
     stream: bool,
 ) -> Result<(Response, web_time::SystemTime, web_time::Instant), LLMResponse> {
     let (system_now, instant_now) = (web_time::SystemTime::now(), web_time::Instant::now());
 
     let req = match client
-        .build_request(prompt, true, stream)
+        .build_request(prompt, tools, output_schema, true, stream)
         .await
         .context("Failed to build request")
     {
@@ -53,6 +195,7 @@ pub async fn make_request(
                 latency: instant_now.elapsed(),
                 message: format!("{:#?}", e),
                 code: ErrorCode::Other(2),
+                retry_after: None,
             }));
         }
     };
@@ -69,11 +212,64 @@ pub async fn make_request(
                 latency: instant_now.elapsed(),
                 message: format!("{:#?}", e),
                 code: ErrorCode::Other(2),
+                retry_after: None,
             }));
         }
     };
 
-    let response = match client.http_client().execute(req).await {
+    if let Some(log) = &ctx.collector {
+        log.record_request(&req);
+    }
+
+    // When replaying, the provider is never called.
+    let recording = ctx
+        .cassette
+        .as_ref()
+        .map(|cassette| (cassette, RecordedRequest::from_reqwest(&req)));
+    let response = match recording.as_ref().filter(|(c, _)| c.replaying()) {
+        Some((cassette, request)) => match cassette.replay_reqwest(request) {
+            Ok(response) => Ok(response),
+            Err(e) => {
+                return Err(LLMResponse::LLMFailure(LLMErrorResponse {
+                    client: client.context().name.to_string(),
+                    model: None,
+                    prompt: to_prompt(prompt),
+                    start_time: system_now,
+                    request_options: client.request_options().clone(),
+                    latency: instant_now.elapsed(),
+                    message: format!("{:#}", e),
+                    code: ErrorCode::Other(2),
+                    retry_after: None,
+                }));
+            }
+        },
+        None => match client.timeouts().request {
+            Some(timeout) => {
+                match async_std::future::timeout(timeout, client.http_client().execute(req)).await {
+                    Ok(response) => response,
+                    Err(_) => {
+                        return Err(LLMResponse::LLMFailure(LLMErrorResponse {
+                            client: client.context().name.to_string(),
+                            model: None,
+                            prompt: to_prompt(prompt),
+                            start_time: system_now,
+                            request_options: client.request_options().clone(),
+                            latency: instant_now.elapsed(),
+                            message: format!(
+                                "No response after {}ms (request_timeout_ms)",
+                                timeout.as_millis()
+                            ),
+                            code: ErrorCode::Timeout,
+                            retry_after: None,
+                        }));
+                    }
+                }
+            }
+            None => client.http_client().execute(req).await,
+        },
+    };
+
+    let response = match response {
         Ok(response) => response,
         Err(e) => {
             return Err(LLMResponse::LLMFailure(LLMErrorResponse {
@@ -84,13 +280,61 @@ pub async fn make_request(
                 request_options: client.request_options().clone(),
                 latency: instant_now.elapsed(),
                 message: format!("{:?}", e),
-                code: ErrorCode::Other(2),
+                code: if e.is_timeout() {
+                    ErrorCode::Timeout
+                } else {
+                    ErrorCode::Other(2)
+                },
+                retry_after: None,
             }));
         }
     };
 
+    let response = match recording {
+        Some((cassette, request)) if !cassette.replaying() => {
+            match cassette.record_reqwest(request, response).await {
+                Ok(response) => response,
+                Err(e) => {
+                    return Err(LLMResponse::LLMFailure(LLMErrorResponse {
+                        client: client.context().name.to_string(),
+                        model: None,
+                        prompt: to_prompt(prompt),
+                        start_time: system_now,
+                        request_options: client.request_options().clone(),
+                        latency: instant_now.elapsed(),
+                        message: format!("Failed to record the response: {:#}", e),
+                        code: ErrorCode::Other(2),
+                        retry_after: None,
+                    }));
+                }
+            }
+        }
+        _ => response,
+    };
+
+    let response = match &ctx.collector {
+        Some(log) => match log.record_response(response, stream).await {
+            Ok(response) => response,
+            Err(e) => {
+                return Err(LLMResponse::LLMFailure(LLMErrorResponse {
+                    client: client.context().name.to_string(),
+                    model: None,
+                    prompt: to_prompt(prompt),
+                    start_time: system_now,
+                    request_options: client.request_options().clone(),
+                    latency: instant_now.elapsed(),
+                    message: format!("Failed to read the response: {:#}", e),
+                    code: ErrorCode::Other(2),
+                    retry_after: None,
+                }));
+            }
+        },
+        None => response,
+    };
+
     let status = response.status();
     if !status.is_success() {
+        let retry_after = retry_after_from_headers(response.headers());
         return Err(LLMResponse::LLMFailure(LLMErrorResponse {
             client: client.context().name.to_string(),
             model: None,
@@ -103,6 +347,7 @@ pub async fn make_request(
                 response.text().await.unwrap_or("<no response>".into())
             ),
             code: ErrorCode::from_status(status),
+            retry_after,
         }));
     }
 
@@ -111,10 +356,14 @@ pub async fn make_request(
 
 pub async fn make_parsed_request<T: DeserializeOwned>(
     client: &(impl WithClient + RequestBuilder),
+    ctx: &RuntimeContext,
     prompt: either::Either<&String, &Vec<RenderedChatMessage>>,
+    tools: &[ToolDefinition],
+    output_schema: Option<&serde_json::Value>,
     stream: bool,
 ) -> Result<(T, web_time::SystemTime, web_time::Instant), LLMResponse> {
-    let (response, system_now, instant_now) = make_request(client, prompt, stream).await?;
+    let (response, system_now, instant_now) =
+        make_request(client, ctx, prompt, tools, output_schema, stream).await?;
     let j = match response.json::<serde_json::Value>().await {
         Ok(response) => response,
         Err(e) => {
@@ -127,6 +376,7 @@ pub async fn make_parsed_request<T: DeserializeOwned>(
                 latency: instant_now.elapsed(),
                 message: e.to_string(),
                 code: ErrorCode::Other(2),
+                retry_after: None,
             }))
         }
     };
@@ -146,6 +396,119 @@ pub async fn make_parsed_request<T: DeserializeOwned>(
             latency: instant_now.elapsed(),
             message: format!("{:?}", e),
             code: ErrorCode::Other(2),
+            retry_after: None,
         })),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::{HeaderMap, HeaderValue};
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.parse().unwrap(), HeaderValue::from_static(v)))
+            .collect()
+    }
+
+    #[test]
+    fn retry_after_seconds() {
+        let h = headers(&[("retry-after", "20")]);
+        assert_eq!(
+            retry_after_from_headers(&h),
+            Some(web_time::Duration::from_secs(20))
+        );
+    }
+
+    #[test]
+    fn retry_after_ms_and_openai_reset() {
+        let h = headers(&[
+            ("retry-after-ms", "1500"),
+            ("x-ratelimit-reset-requests", "1m2s"),
+            ("x-ratelimit-reset-tokens", "250ms"),
+        ]);
+        assert_eq!(
+            retry_after_from_headers(&h),
+            Some(web_time::Duration::from_secs(62))
+        );
+    }
+
+    #[test]
+    fn retry_after_anthropic_reset() {
+        let reset = |secs| {
+            let at = chrono::Utc::now() + chrono::Duration::seconds(secs);
+            HeaderValue::from_str(&at.to_rfc3339()).unwrap()
+        };
+        let h = HeaderMap::from_iter([
+            (
+                "anthropic-ratelimit-requests-reset".parse().unwrap(),
+                reset(30),
+            ),
+            (
+                "anthropic-ratelimit-tokens-reset".parse().unwrap(),
+                reset(5),
+            ),
+            (
+                "anthropic-ratelimit-tokens-limit".parse().unwrap(),
+                HeaderValue::from_static("60"),
+            ),
+        ]);
+        let wait = retry_after_from_headers(&h).unwrap();
+        assert!((29..=30).contains(&wait.as_secs()), "waited {:?}", wait);
+    }
+
+    #[test]
+    fn timeouts_are_removed_from_properties() {
+        let mut properties = HashMap::from([
+            ("model".to_string(), serde_json::json!("gpt-4o")),
+            ("request_timeout_ms".to_string(), serde_json::json!(30000)),
+            (
+                "idle_stream_timeout_ms".to_string(),
+                serde_json::json!(5000),
+            ),
+        ]);
+        let timeouts = RequestTimeouts::from_properties(&mut properties).unwrap();
+        assert_eq!(
+            timeouts.request,
+            Some(web_time::Duration::from_millis(30000))
+        );
+        assert_eq!(
+            timeouts.idle_stream,
+            Some(web_time::Duration::from_millis(5000))
+        );
+        assert_eq!(timeouts.connect, None);
+        assert_eq!(properties.len(), 1);
+    }
+
+    #[test]
+    fn timeouts_must_be_positive() {
+        let mut properties =
+            HashMap::from([("total_timeout_ms".to_string(), serde_json::json!(-1))]);
+        assert!(RequestTimeouts::from_properties(&mut properties).is_err());
+    }
+
+    #[test]
+    fn structured_output_is_removed_from_properties() {
+        let mut properties =
+            HashMap::from([("structured_output".to_string(), serde_json::json!(true))]);
+        assert!(structured_output_from_properties(&mut properties).unwrap());
+        assert!(properties.is_empty());
+
+        // Unquoted `true` in BAML arrives as a string.
+        let mut properties =
+            HashMap::from([("structured_output".to_string(), serde_json::json!("true"))]);
+        assert!(structured_output_from_properties(&mut properties).unwrap());
+
+        let mut properties =
+            HashMap::from([("structured_output".to_string(), serde_json::json!("yes"))]);
+        assert!(structured_output_from_properties(&mut properties).is_err());
+    }
+
+    #[test]
+    fn retry_after_ignores_garbage() {
+        let h = headers(&[("retry-after", "soon"), ("x-ratelimit-reset-tokens", "5x")]);
+        assert_eq!(retry_after_from_headers(&h), None);
+    }
+}
@@ -1,12 +1,25 @@
 use internal_baml_core::{
-    internal_baml_parser_database::RetryPolicyStrategy, ir::RetryPolicyWalker,
+    internal_baml_parser_database::{CircuitBreakerConfig, RetryJitter, RetryPolicyStrategy},
+    ir::RetryPolicyWalker,
 };
 
+use super::ErrorCode;
+
+/// How long a `constant_delay` policy waits, at most, when the provider asks
+/// for a longer delay. It has no `max_delay_ms` of its own.
+const CONSTANT_DELAY_MAX_DELAY_MS: u32 = 10_000;
+
 #[derive(Clone)]
 pub struct CallablePolicy {
     max_retries: u32,
     strategy: RetryPolicyStrategy,
+    retry_on_parse_failure: bool,
+    circuit_breaker: Option<CircuitBreakerConfig>,
+    retry_on: Option<Vec<String>>,
+    error_strategies: Vec<(String, RetryPolicyStrategy)>,
     current: std::time::Duration,
+    // Last delay handed out, after jitter
+    last_delay: std::time::Duration,
     counter: u32,
 }
 
@@ -15,15 +28,106 @@ impl From<RetryPolicyWalker<'_>> for CallablePolicy {
         CallablePolicy {
             max_retries: policy.max_retries(),
             strategy: policy.strategy().clone(),
-            current: match policy.strategy() {
-                RetryPolicyStrategy::ExponentialBackoff(strategy) => {
-                    std::time::Duration::from_millis(strategy.delay_ms as u64)
-                }
-                RetryPolicyStrategy::ConstantDelay(strategy) => {
-                    std::time::Duration::from_millis(strategy.delay_ms as u64)
-                }
-            },
+            retry_on_parse_failure: policy.retry_on_parse_failure(),
+            circuit_breaker: policy.circuit_breaker().copied(),
+            retry_on: policy.retry_on().map(|codes| codes.to_vec()),
+            error_strategies: policy.error_strategies().to_vec(),
+            current: base_delay(policy.strategy()),
+            last_delay: base_delay(policy.strategy()),
+            counter: 0,
+        }
+    }
+}
+
+fn base_delay(strategy: &RetryPolicyStrategy) -> std::time::Duration {
+    match strategy {
+        RetryPolicyStrategy::ExponentialBackoff(strategy) => {
+            std::time::Duration::from_millis(strategy.delay_ms as u64)
+        }
+        RetryPolicyStrategy::ConstantDelay(strategy) => {
+            std::time::Duration::from_millis(strategy.delay_ms as u64)
+        }
+    }
+}
+
+impl CallablePolicy {
+    pub fn retry_on_parse_failure(&self) -> bool {
+        self.retry_on_parse_failure
+    }
+
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    pub fn circuit_breaker(&self) -> Option<&CircuitBreakerConfig> {
+        self.circuit_breaker.as_ref()
+    }
+
+    /// Whether a request that failed with `code` should be tried again.
+    pub fn should_retry(&self, code: &ErrorCode) -> bool {
+        match &self.retry_on {
+            Some(codes) => codes.iter().any(|c| c == code.name()),
+            None => true,
+        }
+    }
+
+    /// The strategy configured for `code`, if it has one of its own.
+    pub fn error_strategy(&self, code: &ErrorCode) -> Option<&RetryPolicyStrategy> {
+        self.error_strategies
+            .iter()
+            .find(|(c, _)| c == code.name())
+            .map(|(_, strategy)| strategy)
+    }
+
+    /// A fresh copy of this policy that follows the strategy configured for `code`.
+    pub fn for_error(&self, code: &ErrorCode) -> CallablePolicy {
+        let strategy = *self.error_strategy(code).unwrap_or(&self.strategy);
+        CallablePolicy {
+            strategy,
+            current: base_delay(&strategy),
+            last_delay: base_delay(&strategy),
             counter: 0,
+            ..self.clone()
+        }
+    }
+
+    /// Upper bound for any delay, including the ones requested by the provider.
+    pub fn max_delay(&self) -> std::time::Duration {
+        let max_delay_ms = match &self.strategy {
+            RetryPolicyStrategy::ExponentialBackoff(strategy) => strategy.max_delay_ms,
+            RetryPolicyStrategy::ConstantDelay(_) => CONSTANT_DELAY_MAX_DELAY_MS,
+        };
+        std::time::Duration::from_millis(max_delay_ms as u64)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn jitter(&self) -> RetryJitter {
+        match &self.strategy {
+            RetryPolicyStrategy::ExponentialBackoff(strategy) => strategy.jitter,
+            RetryPolicyStrategy::ConstantDelay(strategy) => strategy.jitter,
+        }
+    }
+
+    fn apply_jitter(&self, delay: u32) -> u32 {
+        // For VSCode, we don't want random delays,
+        // as it can make rendering inconsistent
+        #[cfg(target_arch = "wasm32")]
+        {
+            delay
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        match self.jitter() {
+            RetryJitter::None => delay,
+            RetryJitter::Full => fastrand::u32(0..=delay),
+            RetryJitter::Decorrelated => {
+                let base = base_delay(&self.strategy).as_millis() as u32;
+                let upper = (self.last_delay.as_millis() as u32)
+                    .saturating_mul(3)
+                    .max(base);
+                let max_delay = self.max_delay().as_millis() as u32;
+                fastrand::u32(base..=upper).min(max_delay)
+            }
         }
     }
 }
@@ -51,11 +155,70 @@ impl Iterator for CallablePolicy {
         if self.counter == self.max_retries {
             // Last element has no delay
             self.current = std::time::Duration::from_millis(0);
+            self.last_delay = self.current;
         } else {
             self.current = std::time::Duration::from_millis(delay as u64);
+            self.last_delay = std::time::Duration::from_millis(self.apply_jitter(delay) as u64);
         }
         self.counter += 1;
 
-        Some(self.current)
+        Some(self.last_delay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use internal_baml_core::internal_baml_parser_database::{
+        ContantDelayStrategy, RETRY_POLICY_ERROR_CODES,
+    };
+
+    use super::*;
+
+    fn policy(retry_on: Option<&[&str]>) -> CallablePolicy {
+        let strategy = RetryPolicyStrategy::ConstantDelay(ContantDelayStrategy {
+            delay_ms: 100,
+            jitter: RetryJitter::None,
+        });
+        CallablePolicy {
+            max_retries: 2,
+            strategy,
+            retry_on_parse_failure: false,
+            circuit_breaker: None,
+            retry_on: retry_on.map(|codes| codes.iter().map(|c| c.to_string()).collect()),
+            error_strategies: vec![],
+            current: base_delay(&strategy),
+            last_delay: base_delay(&strategy),
+            counter: 0,
+        }
+    }
+
+    #[test]
+    fn error_codes_are_named_after_their_variant() {
+        let codes = [
+            ErrorCode::InvalidAuthentication,
+            ErrorCode::NotSupported,
+            ErrorCode::RateLimited,
+            ErrorCode::ServerError,
+            ErrorCode::ServiceUnavailable,
+            ErrorCode::Timeout,
+            ErrorCode::UnsupportedResponse(200),
+            ErrorCode::Other(418),
+        ];
+        for code in &codes {
+            assert!(format!("{:?}", code).starts_with(code.name()));
+        }
+        let names = codes.iter().map(|c| c.name()).collect::<Vec<_>>();
+        assert_eq!(names, RETRY_POLICY_ERROR_CODES);
+    }
+
+    #[test]
+    fn should_retry_follows_retry_on() {
+        assert!(policy(None).should_retry(&ErrorCode::InvalidAuthentication));
+
+        let policy = policy(Some(&["RateLimited", "Other"]));
+        assert!(policy.should_retry(&ErrorCode::RateLimited));
+        assert!(policy.should_retry(&ErrorCode::Other(418)));
+        assert!(!policy.should_retry(&ErrorCode::ServerError));
+        assert!(!policy.should_retry(&ErrorCode::InvalidAuthentication));
     }
 }
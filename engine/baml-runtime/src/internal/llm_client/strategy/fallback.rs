@@ -16,6 +16,7 @@ use crate::{
 pub struct FallbackStrategy {
     pub name: String,
     pub(super) retry_policy: Option<String>,
+    pub(super) retry_on_parse_failure: bool,
     // TODO: We can add conditions to each client
     client_specs: Vec<ClientSpec>,
 }
@@ -40,7 +41,7 @@ fn resolve_strategy(
     };
 
     if !properties.is_empty() {
-        let supported_keys = ["strategy"];
+        let supported_keys = ["strategy", "retry_on_parse_failure"];
         let unknown_keys = properties.keys().map(String::from).collect::<Vec<_>>();
         anyhow::bail!(
             "Unknown keys: {}. Supported keys are: {}",
@@ -58,17 +59,18 @@ impl TryFrom<(&ClientProperty, &RuntimeContext)> for FallbackStrategy {
     fn try_from(
         (client, ctx): (&ClientProperty, &RuntimeContext),
     ) -> std::result::Result<Self, Self::Error> {
-        let strategy = resolve_strategy(
-            client
-                .options
-                .iter()
-                .map(|(k, v)| Ok((k.clone(), serde_json::json!(v))))
-                .collect::<Result<HashMap<_, _>>>()?,
-            ctx,
-        )?;
+        let mut properties = client
+            .options
+            .iter()
+            .map(|(k, v)| Ok((k.clone(), serde_json::json!(v))))
+            .collect::<Result<HashMap<_, _>>>()?;
+        let retry_on_parse_failure =
+            super::retry_on_parse_failure_from_properties(&mut properties)?;
+        let strategy = resolve_strategy(properties, ctx)?;
         Ok(Self {
             name: client.name.clone(),
             retry_policy: client.retry_policy.clone(),
+            retry_on_parse_failure,
             client_specs: strategy,
         })
     }
@@ -78,11 +80,14 @@ impl TryFrom<(&ClientWalker<'_>, &RuntimeContext)> for FallbackStrategy {
     type Error = anyhow::Error;
 
     fn try_from((client, ctx): (&ClientWalker, &RuntimeContext)) -> Result<Self> {
-        let properties = super::super::resolve_properties_walker(client, ctx)?;
+        let mut properties = super::super::resolve_properties_walker(client, ctx)?;
+        let retry_on_parse_failure =
+            super::retry_on_parse_failure_from_properties(&mut properties)?;
         let strategy = resolve_strategy(properties, ctx)?;
         Ok(Self {
             name: client.item.elem.name.clone(),
             retry_policy: client.retry_policy().as_ref().map(String::from),
+            retry_on_parse_failure,
             client_specs: strategy,
         })
     }
@@ -0,0 +1,485 @@
+use anyhow::{Context, Result};
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    sync::{Arc, Mutex},
+};
+
+use internal_baml_core::ir::{repr::ClientSpec, ClientWalker};
+
+use crate::{
+    client_registry::ClientProperty,
+    internal::llm_client::{
+        orchestrator::{
+            ExecutionScope, IterOrchestrator, OrchestrationScope, OrchestrationState,
+            OrchestratorNodeIterator,
+        },
+        LLMResponse,
+    },
+    runtime_interface::InternalClientLookup,
+    RuntimeContext,
+};
+use serde::Serialize;
+
+const DEFAULT_EWMA_ALPHA: f64 = 0.3;
+/// The latency we rank a client at before it has answered at all, so that a
+/// client that only fails sorts after every client that has answered.
+const FAILED_LATENCY_MS: f64 = 10.0 * 60.0 * 1000.0;
+/// The success rate a client is ranked at, at worst, so that clients that
+/// keep failing still rank by their latency instead of all being infinitely slow.
+const MIN_SUCCESS_RATE: f64 = 0.01;
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+pub enum LoadBalanceKind {
+    /// Picks a client at random, proportionally to its weight.
+    Weighted,
+    /// Picks the client with the lowest moving average latency, counting
+    /// failed calls against it.
+    LeastLatency,
+    /// Picks the client with the lowest moving average error rate.
+    LeastErrors,
+}
+
+impl LoadBalanceKind {
+    pub fn from_provider(provider: &str) -> Option<Self> {
+        match provider {
+            "baml-weighted" | "weighted" => Some(Self::Weighted),
+            "baml-least-latency" | "least-latency" => Some(Self::LeastLatency),
+            "baml-least-errors" | "least-errors" => Some(Self::LeastErrors),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for LoadBalanceKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadBalanceKind::Weighted => write!(f, "Weighted"),
+            LoadBalanceKind::LeastLatency => write!(f, "LeastLatency"),
+            LoadBalanceKind::LeastErrors => write!(f, "LeastErrors"),
+        }
+    }
+}
+
+/// What we've observed so far from a single client in the strategy.
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct ClientStats {
+    /// EWMA of the latency of successful calls, in milliseconds.
+    pub latency_ms: Option<f64>,
+    /// EWMA of failures (1.0) vs successes (0.0).
+    pub error_rate: f64,
+    pub calls: u64,
+}
+
+impl ClientStats {
+    /// The latency to rank this client by: how long a call takes to succeed,
+    /// counting the calls that fail before it does.
+    fn effective_latency_ms(&self) -> f64 {
+        self.latency_ms.unwrap_or(FAILED_LATENCY_MS) / (1.0 - self.error_rate).max(MIN_SUCCESS_RATE)
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct LoadBalanceStrategy {
+    pub name: String,
+    pub kind: LoadBalanceKind,
+    pub(super) retry_policy: Option<String>,
+    pub(super) retry_on_parse_failure: bool,
+    client_specs: Vec<ClientSpec>,
+    weights: Vec<f64>,
+    ewma_alpha: f64,
+    stats: Mutex<Vec<ClientStats>>,
+}
+
+impl LoadBalanceStrategy {
+    pub fn stats(&self) -> Vec<ClientStats> {
+        self.stats.lock().unwrap().clone()
+    }
+
+    /// Feeds the outcome of a call made through this strategy back into its stats.
+    pub fn record_response(&self, index: usize, response: &LLMResponse) {
+        let alpha = self.ewma_alpha;
+        let mut stats = self.stats.lock().unwrap();
+        let Some(stat) = stats.get_mut(index) else {
+            return;
+        };
+        match response {
+            LLMResponse::Success(s) => {
+                let latency = s.latency.as_millis() as f64;
+                stat.latency_ms = Some(match stat.latency_ms {
+                    Some(prev) => alpha * latency + (1.0 - alpha) * prev,
+                    None => latency,
+                });
+                stat.error_rate *= 1.0 - alpha;
+            }
+            LLMResponse::LLMFailure(_) => {
+                stat.error_rate = alpha + (1.0 - alpha) * stat.error_rate;
+            }
+            // Not the client's fault.
+            LLMResponse::UserFailure(_) | LLMResponse::InternalFailure(_) => return,
+        }
+        stat.calls += 1;
+    }
+
+    /// Client indices ordered from most to least preferred.
+    fn ranked(&self) -> Vec<usize> {
+        let stats = self.stats.lock().unwrap();
+        let mut indices = (0..self.client_specs.len()).collect::<Vec<_>>();
+        if self.kind == LoadBalanceKind::Weighted {
+            return indices;
+        }
+        // Clients we haven't called yet sort first, so that every client gets
+        // a chance to report how it does.
+        indices.sort_by(|a, b| {
+            let (a, b) = (&stats[*a], &stats[*b]);
+            let untried = (b.calls == 0).cmp(&(a.calls == 0));
+            untried.then_with(|| match self.kind {
+                LoadBalanceKind::LeastLatency => a
+                    .effective_latency_ms()
+                    .total_cmp(&b.effective_latency_ms()),
+                _ => a.error_rate.total_cmp(&b.error_rate),
+            })
+        });
+        indices
+    }
+
+    fn pick_weighted(&self) -> usize {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let total = self.weights.iter().sum::<f64>();
+            let mut target = fastrand::f64() * total;
+            for (idx, weight) in self.weights.iter().enumerate() {
+                if target < *weight {
+                    return idx;
+                }
+                target -= weight;
+            }
+            self.weights.len() - 1
+        }
+
+        // For VSCode, we don't want a random pick,
+        // as it can make rendering inconsistent
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.weights
+                .iter()
+                .enumerate()
+                .max_by(|a, b| a.1.total_cmp(b.1))
+                .map(|(idx, _)| idx)
+                .unwrap_or(0)
+        }
+    }
+}
+
+fn resolve_strategy(
+    kind: LoadBalanceKind,
+    mut properties: HashMap<String, serde_json::Value>,
+    _ctx: &RuntimeContext,
+) -> Result<(Vec<ClientSpec>, Vec<f64>, f64)> {
+    let strategy = properties
+        .remove("strategy")
+        .map(|v| serde_json::from_value::<Vec<String>>(v))
+        .transpose()
+        .context("Failed to resolve strategy into string[]")?;
+
+    let strategy = if let Some(strategy) = strategy {
+        if strategy.is_empty() {
+            anyhow::bail!("Empty strategy array, at least one client is required");
+        }
+        strategy
+    } else {
+        anyhow::bail!("Missing a strategy field");
+    };
+
+    let supported_keys = match kind {
+        LoadBalanceKind::Weighted => vec!["strategy", "weights", "retry_on_parse_failure"],
+        LoadBalanceKind::LeastLatency | LoadBalanceKind::LeastErrors => {
+            vec!["strategy", "ewma_alpha", "retry_on_parse_failure"]
+        }
+    };
+
+    let weights = match kind {
+        LoadBalanceKind::Weighted => {
+            let weights = properties
+                .remove("weights")
+                .map(|v| serde_json::from_value::<Vec<f64>>(v))
+                .transpose()
+                .context("Failed to resolve weights into float[]")?;
+            match weights {
+                Some(weights) => {
+                    if weights.len() != strategy.len() {
+                        anyhow::bail!(
+                            "Expected {} weights (one per client in strategy), got {}",
+                            strategy.len(),
+                            weights.len()
+                        );
+                    }
+                    if weights.iter().any(|w| !w.is_finite() || *w < 0.0) {
+                        anyhow::bail!("Weights must be non-negative numbers");
+                    }
+                    if weights.iter().sum::<f64>() <= 0.0 {
+                        anyhow::bail!("At least one weight must be greater than 0");
+                    }
+                    weights
+                }
+                None => vec![1.0; strategy.len()],
+            }
+        }
+        _ => vec![1.0; strategy.len()],
+    };
+
+    let ewma_alpha = match kind {
+        LoadBalanceKind::Weighted => DEFAULT_EWMA_ALPHA,
+        _ => {
+            let alpha = properties
+                .remove("ewma_alpha")
+                .map(|v| serde_json::from_value::<f64>(v))
+                .transpose()
+                .context("Invalid ewma_alpha (not a number)")?
+                .unwrap_or(DEFAULT_EWMA_ALPHA);
+            if !(alpha > 0.0 && alpha <= 1.0) {
+                anyhow::bail!("ewma_alpha must be greater than 0 and at most 1");
+            }
+            alpha
+        }
+    };
+
+    if !properties.is_empty() {
+        let unknown_keys = properties.keys().map(String::from).collect::<Vec<_>>();
+        anyhow::bail!(
+            "Unknown keys: {}. Supported keys are: {}",
+            unknown_keys.join(", "),
+            supported_keys.join(", ")
+        );
+    }
+
+    Ok((
+        strategy.into_iter().map(ClientSpec::new_from_id).collect(),
+        weights,
+        ewma_alpha,
+    ))
+}
+
+impl LoadBalanceStrategy {
+    fn new(
+        name: String,
+        kind: LoadBalanceKind,
+        retry_policy: Option<String>,
+        (client_specs, weights, ewma_alpha): (Vec<ClientSpec>, Vec<f64>, f64),
+    ) -> Self {
+        let stats = Mutex::new(vec![ClientStats::default(); client_specs.len()]);
+        Self {
+            name,
+            kind,
+            retry_policy,
+            retry_on_parse_failure: false,
+            client_specs,
+            weights,
+            ewma_alpha,
+            stats,
+        }
+    }
+}
+
+impl TryFrom<(&ClientProperty, &RuntimeContext)> for LoadBalanceStrategy {
+    type Error = anyhow::Error;
+
+    fn try_from(
+        (client, ctx): (&ClientProperty, &RuntimeContext),
+    ) -> std::result::Result<Self, Self::Error> {
+        let kind = LoadBalanceKind::from_provider(&client.provider)
+            .with_context(|| format!("Unsupported strategy provider: {}", client.provider))?;
+        let mut properties = client
+            .options
+            .iter()
+            .map(|(k, v)| Ok((k.clone(), serde_json::json!(v))))
+            .collect::<Result<HashMap<_, _>>>()?;
+        let retry_on_parse_failure =
+            super::retry_on_parse_failure_from_properties(&mut properties)?;
+        let resolved = resolve_strategy(kind, properties, ctx)?;
+
+        Ok(Self {
+            retry_on_parse_failure,
+            ..Self::new(
+                client.name.clone(),
+                kind,
+                client.retry_policy.clone(),
+                resolved,
+            )
+        })
+    }
+}
+
+impl TryFrom<(&ClientWalker<'_>, &RuntimeContext)> for LoadBalanceStrategy {
+    type Error = anyhow::Error;
+
+    fn try_from((client, ctx): (&ClientWalker, &RuntimeContext)) -> Result<Self> {
+        let provider = client.elem().provider.as_str();
+        let kind = LoadBalanceKind::from_provider(provider)
+            .with_context(|| format!("Unsupported strategy provider: {}", provider))?;
+        let mut properties = super::super::resolve_properties_walker(client, ctx)?;
+        let retry_on_parse_failure =
+            super::retry_on_parse_failure_from_properties(&mut properties)?;
+        let resolved = resolve_strategy(kind, properties, ctx)?;
+        Ok(Self {
+            retry_on_parse_failure,
+            ..Self::new(
+                client.item.elem.name.clone(),
+                kind,
+                client.retry_policy().as_ref().map(String::from),
+                resolved,
+            )
+        })
+    }
+}
+
+impl IterOrchestrator for Arc<LoadBalanceStrategy> {
+    fn iter_orchestrator<'a>(
+        &self,
+        state: &mut OrchestrationState,
+        _previous: OrchestrationScope,
+        ctx: &RuntimeContext,
+        client_lookup: &'a dyn InternalClientLookup<'a>,
+    ) -> Result<OrchestratorNodeIterator> {
+        let offset = state.client_to_usage.entry(self.name.clone()).or_insert(0);
+        // If we get expanded more than once in a call (e.g. by a retry policy),
+        // move on to the next best client each time.
+        let next = match self.kind {
+            LoadBalanceKind::Weighted => self.pick_weighted(),
+            LoadBalanceKind::LeastLatency | LoadBalanceKind::LeastErrors => {
+                let ranked = self.ranked();
+                ranked[*offset % ranked.len()]
+            }
+        };
+
+        // Update the usage count
+        *offset += 1;
+
+        let client_spec = &self.client_specs[next];
+        let client = client_lookup.get_llm_provider(client_spec, ctx)?;
+        let client = client.clone();
+        client.iter_orchestrator(
+            state,
+            ExecutionScope::LoadBalance(self.clone(), next).into(),
+            ctx,
+            client_lookup,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::internal::llm_client::{
+        circuit_breaker::tests::failure, ErrorCode, LLMCompleteResponse,
+        LLMCompleteResponseMetadata,
+    };
+    use web_time::Duration;
+
+    fn strategy(kind: LoadBalanceKind, weights: Vec<f64>) -> LoadBalanceStrategy {
+        let client_specs = (0..weights.len())
+            .map(|i| ClientSpec::new_from_id(format!("Client{}", i)))
+            .collect();
+        LoadBalanceStrategy::new(
+            "MyStrategy".into(),
+            kind,
+            None,
+            (client_specs, weights, DEFAULT_EWMA_ALPHA),
+        )
+    }
+
+    fn success(latency_ms: u64) -> LLMResponse {
+        LLMResponse::Success(LLMCompleteResponse {
+            client: "MyClient".into(),
+            model: "my-model".into(),
+            prompt: internal_baml_jinja::RenderedPrompt::Completion(String::new()),
+            request_options: HashMap::new(),
+            content: String::new(),
+            tool_calls: vec![],
+            start_time: web_time::SystemTime::now(),
+            latency: Duration::from_millis(latency_ms),
+            metadata: LLMCompleteResponseMetadata {
+                baml_is_complete: true,
+                finish_reason: None,
+                prompt_tokens: None,
+                output_tokens: None,
+                total_tokens: None,
+                cost: None,
+            },
+        })
+    }
+
+    #[test]
+    fn untried_clients_rank_first() {
+        let s = strategy(LoadBalanceKind::LeastLatency, vec![1.0; 3]);
+        s.record_response(0, &success(100));
+        s.record_response(2, &success(50));
+        assert_eq!(s.ranked(), vec![1, 2, 0]);
+
+        let s = strategy(LoadBalanceKind::LeastErrors, vec![1.0; 3]);
+        s.record_response(0, &success(100));
+        s.record_response(1, &failure(ErrorCode::ServerError));
+        assert_eq!(s.ranked(), vec![2, 0, 1]);
+    }
+
+    #[test]
+    fn always_failing_client_ranks_last() {
+        let s = strategy(LoadBalanceKind::LeastLatency, vec![1.0; 2]);
+        for _ in 0..3 {
+            s.record_response(0, &failure(ErrorCode::ServerError));
+        }
+        s.record_response(1, &success(2_000));
+        assert_eq!(s.ranked(), vec![1, 0]);
+    }
+
+    #[test]
+    fn failures_count_against_latency() {
+        let s = strategy(LoadBalanceKind::LeastLatency, vec![1.0; 2]);
+        s.record_response(0, &success(100));
+        s.record_response(1, &success(150));
+        assert_eq!(s.ranked(), vec![0, 1]);
+        s.record_response(0, &failure(ErrorCode::ServerError));
+        s.record_response(0, &failure(ErrorCode::ServerError));
+        assert_eq!(s.ranked(), vec![1, 0]);
+    }
+
+    #[test]
+    fn clients_that_keep_failing_still_rank_by_latency() {
+        // Only the last call counts, so a single failure takes the error rate
+        // all the way to 1.
+        let client_specs = (0..3)
+            .map(|i| ClientSpec::new_from_id(format!("Client{}", i)))
+            .collect();
+        let s = LoadBalanceStrategy::new(
+            "MyStrategy".into(),
+            LoadBalanceKind::LeastLatency,
+            None,
+            (client_specs, vec![1.0; 3], 1.0),
+        );
+        s.record_response(0, &success(100));
+        s.record_response(1, &success(200));
+        for client in 0..3 {
+            s.record_response(client, &failure(ErrorCode::ServerError));
+        }
+        let stats = s.stats();
+        assert_eq!(stats[0].error_rate, 1.0);
+        assert!(stats.iter().all(|s| s.effective_latency_ms().is_finite()));
+        assert_eq!(s.ranked(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn weighted_picks_skip_zero_weights() {
+        let s = strategy(LoadBalanceKind::Weighted, vec![0.0, 1.0, 0.0]);
+        assert!((0..100).all(|_| s.pick_weighted() == 1));
+    }
+
+    #[test]
+    fn weighted_picks_follow_weights() {
+        fastrand::seed(7);
+        let s = strategy(LoadBalanceKind::Weighted, vec![1.0, 3.0]);
+        let picks = 10_000;
+        let second = (0..picks).filter(|_| s.pick_weighted() == 1).count();
+        let share = second as f64 / picks as f64;
+        assert!((0.72..0.78).contains(&share), "share was {}", share);
+    }
+}
@@ -1,7 +1,8 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use anyhow::Result;
 mod fallback;
+pub mod load_balance;
 pub mod roundrobin;
 
 use internal_baml_core::ir::ClientWalker;
@@ -10,7 +11,11 @@ use crate::{
     client_registry::ClientProperty, runtime_interface::InternalClientLookup, RuntimeContext,
 };
 
-use self::{fallback::FallbackStrategy, roundrobin::RoundRobinStrategy};
+use self::{
+    fallback::FallbackStrategy,
+    load_balance::{LoadBalanceKind, LoadBalanceStrategy},
+    roundrobin::RoundRobinStrategy,
+};
 
 use super::{
     orchestrator::{
@@ -22,6 +27,7 @@ use super::{
 pub enum LLMStrategyProvider {
     RoundRobin(Arc<RoundRobinStrategy>),
     Fallback(FallbackStrategy),
+    LoadBalance(Arc<LoadBalanceStrategy>),
 }
 
 impl std::fmt::Display for LLMStrategyProvider {
@@ -33,6 +39,9 @@ impl std::fmt::Display for LLMStrategyProvider {
             LLMStrategyProvider::Fallback(strategy) => {
                 write!(f, "Fallback({})", strategy.name)
             }
+            LLMStrategyProvider::LoadBalance(strategy) => {
+                write!(f, "{}({})", strategy.kind, strategy.name)
+            }
         }
     }
 }
@@ -48,8 +57,19 @@ impl TryFrom<(&ClientWalker<'_>, &RuntimeContext)> for LLMStrategyProvider {
             "baml-fallback" | "fallback" => {
                 FallbackStrategy::try_from((client, ctx)).map(LLMStrategyProvider::Fallback)
             }
+            other if LoadBalanceKind::from_provider(other).is_some() => {
+                LoadBalanceStrategy::try_from((client, ctx))
+                    .map(Arc::new)
+                    .map(LLMStrategyProvider::LoadBalance)
+            }
             other => {
-                let options = ["round-robin", "fallback"];
+                let options = [
+                    "round-robin",
+                    "fallback",
+                    "weighted",
+                    "least-latency",
+                    "least-errors",
+                ];
                 anyhow::bail!(
                     "Unsupported strategy provider: {}. Available ones are: {}",
                     other,
@@ -71,8 +91,19 @@ impl TryFrom<(&ClientProperty, &RuntimeContext)> for LLMStrategyProvider {
             "baml-fallback" | "fallback" => {
                 FallbackStrategy::try_from((client, ctx)).map(LLMStrategyProvider::Fallback)
             }
+            other if LoadBalanceKind::from_provider(other).is_some() => {
+                LoadBalanceStrategy::try_from((client, ctx))
+                    .map(Arc::new)
+                    .map(LLMStrategyProvider::LoadBalance)
+            }
             other => {
-                let options = ["round-robin", "fallback"];
+                let options = [
+                    "round-robin",
+                    "fallback",
+                    "weighted",
+                    "least-latency",
+                    "least-errors",
+                ];
                 anyhow::bail!(
                     "Unsupported strategy provider: {}. Available ones are: {}",
                     other,
@@ -83,11 +114,45 @@ impl TryFrom<(&ClientProperty, &RuntimeContext)> for LLMStrategyProvider {
     }
 }
 
+impl LLMStrategyProvider {
+    pub fn name(&self) -> &str {
+        match self {
+            LLMStrategyProvider::RoundRobin(strategy) => &strategy.name,
+            LLMStrategyProvider::Fallback(strategy) => &strategy.name,
+            LLMStrategyProvider::LoadBalance(strategy) => &strategy.name,
+        }
+    }
+
+    fn retry_on_parse_failure(&self) -> bool {
+        match self {
+            LLMStrategyProvider::RoundRobin(strategy) => strategy.retry_on_parse_failure,
+            LLMStrategyProvider::Fallback(strategy) => strategy.retry_on_parse_failure,
+            LLMStrategyProvider::LoadBalance(strategy) => strategy.retry_on_parse_failure,
+        }
+    }
+}
+
+/// Takes the `retry_on_parse_failure` option out of a strategy's options. When
+/// set, a response that can't be parsed moves on to the strategy's next client.
+fn retry_on_parse_failure_from_properties(
+    properties: &mut HashMap<String, serde_json::Value>,
+) -> Result<bool> {
+    match properties
+        .remove("retry_on_parse_failure")
+        .map(super::unquoted_bool)
+    {
+        None => Ok(false),
+        Some(serde_json::Value::Bool(enabled)) => Ok(enabled),
+        Some(_) => anyhow::bail!("retry_on_parse_failure must be true or false"),
+    }
+}
+
 impl WithRetryPolicy for LLMStrategyProvider {
     fn retry_policy_name(&self) -> Option<&str> {
         match self {
             LLMStrategyProvider::RoundRobin(strategy) => strategy.retry_policy.as_deref(),
             LLMStrategyProvider::Fallback(strategy) => strategy.retry_policy.as_deref(),
+            LLMStrategyProvider::LoadBalance(strategy) => strategy.retry_policy.as_deref(),
         }
     }
 }
@@ -100,13 +165,21 @@ impl IterOrchestrator for LLMStrategyProvider {
         ctx: &RuntimeContext,
         client_lookup: &'a dyn InternalClientLookup<'a>,
     ) -> Result<OrchestratorNodeIterator> {
-        match self {
+        let nodes = match self {
             LLMStrategyProvider::Fallback(f) => {
                 f.iter_orchestrator(state, previous, ctx, client_lookup)
             }
             LLMStrategyProvider::RoundRobin(r) => {
                 r.iter_orchestrator(state, previous, ctx, client_lookup)
             }
-        }
+            LLMStrategyProvider::LoadBalance(l) => {
+                l.iter_orchestrator(state, previous, ctx, client_lookup)
+            }
+        }?;
+        let retry_on_parse_failure = self.retry_on_parse_failure();
+        Ok(nodes
+            .into_iter()
+            .map(|node| node.with_retry_on_parse_failure(retry_on_parse_failure))
+            .collect())
     }
 }
@@ -25,6 +25,7 @@ use serde::Serializer;
 pub struct RoundRobinStrategy {
     pub name: String,
     pub(super) retry_policy: Option<String>,
+    pub(super) retry_on_parse_failure: bool,
     // TODO: We can add conditions to each client
     client_specs: Vec<ClientSpec>,
     #[serde(serialize_with = "serialize_atomic")]
@@ -76,7 +77,7 @@ fn resolve_strategy(
         .context("Invalid start index (not a number)")?;
 
     if !properties.is_empty() {
-        let supported_keys = ["strategy", "start"];
+        let supported_keys = ["strategy", "start", "retry_on_parse_failure"];
         let unknown_keys = properties.keys().map(String::from).collect::<Vec<_>>();
         anyhow::bail!(
             "Unknown keys: {}. Supported keys are: {}",
@@ -114,18 +115,19 @@ impl TryFrom<(&ClientProperty, &RuntimeContext)> for RoundRobinStrategy {
     fn try_from(
         (client, ctx): (&ClientProperty, &RuntimeContext),
     ) -> std::result::Result<Self, Self::Error> {
-        let (strategy, start) = resolve_strategy(
-            client
-                .options
-                .iter()
-                .map(|(k, v)| Ok((k.clone(), serde_json::json!(v))))
-                .collect::<Result<HashMap<_, _>>>()?,
-            ctx,
-        )?;
+        let mut properties = client
+            .options
+            .iter()
+            .map(|(k, v)| Ok((k.clone(), serde_json::json!(v))))
+            .collect::<Result<HashMap<_, _>>>()?;
+        let retry_on_parse_failure =
+            super::retry_on_parse_failure_from_properties(&mut properties)?;
+        let (strategy, start) = resolve_strategy(properties, ctx)?;
 
         Ok(RoundRobinStrategy {
             name: client.name.clone(),
             retry_policy: client.retry_policy.clone(),
+            retry_on_parse_failure,
             client_specs: strategy,
             current_index: AtomicUsize::new(start),
         })
@@ -136,11 +138,14 @@ impl TryFrom<(&ClientWalker<'_>, &RuntimeContext)> for RoundRobinStrategy {
     type Error = anyhow::Error;
 
     fn try_from((client, ctx): (&ClientWalker, &RuntimeContext)) -> Result<Self> {
-        let properties = super::super::resolve_properties_walker(client, ctx)?;
+        let mut properties = super::super::resolve_properties_walker(client, ctx)?;
+        let retry_on_parse_failure =
+            super::retry_on_parse_failure_from_properties(&mut properties)?;
         let (strategy, start) = resolve_strategy(properties, ctx)?;
         Ok(Self {
             name: client.item.elem.name.clone(),
             retry_policy: client.retry_policy().as_ref().map(String::from),
+            retry_on_parse_failure,
             client_specs: strategy,
             current_index: AtomicUsize::new(start),
         })
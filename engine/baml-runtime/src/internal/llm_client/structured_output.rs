@@ -0,0 +1,133 @@
+use anyhow::Result;
+use serde_json::{json, Value};
+
+use super::tools::inline_definitions;
+
+/// The tool Anthropic is made to call so that it answers with the schema.
+/// BAML names can't start with `_`, so it can't clash with a function's tools.
+pub const ANTHROPIC_OUTPUT_TOOL: &str = "__baml_output";
+
+/// OpenAI and Anthropic only take an object at the top of a schema, so
+/// functions returning anything else rely on the prompt alone.
+pub fn object_schema(schema: Option<&Value>) -> Option<&Value> {
+    schema.filter(|s| s.get("type").and_then(Value::as_str) == Some("object"))
+}
+
+/// Rewrites a strict schema into the OpenAPI subset Gemini's `responseSchema`
+/// accepts: no references, `nullable` instead of null types, upper case type
+/// names and only string enums.
+pub fn to_gemini_schema(schema: &Value) -> Result<Value> {
+    let mut schema = inline_definitions(schema)?;
+    to_gemini(&mut schema);
+    Ok(schema)
+}
+
+fn is_null(schema: &Value) -> bool {
+    schema.get("type").and_then(Value::as_str) == Some("null")
+}
+
+fn to_gemini(schema: &mut Value) {
+    let Value::Object(map) = schema else {
+        return;
+    };
+
+    let mut nullable = false;
+    if let Some(Value::Array(options)) = map.remove("anyOf") {
+        nullable = options.iter().any(is_null);
+        let mut options = options
+            .into_iter()
+            .filter(|o| !is_null(o))
+            .collect::<Vec<_>>();
+        match (options.len(), options.pop()) {
+            (1, Some(Value::Object(only))) => map.extend(only),
+            (_, last) => {
+                options.extend(last);
+                map.insert("anyOf".to_string(), Value::Array(options));
+            }
+        }
+    }
+    if let Some(Value::Array(types)) = map.get("type").cloned() {
+        nullable |= types.iter().any(|t| t == "null");
+        if let Some(t) = types.into_iter().find(|t| t != "null") {
+            map.insert("type".to_string(), t);
+        }
+    }
+    if nullable {
+        map.insert("nullable".to_string(), json!(true));
+    }
+
+    map.retain(|k, _| !matches!(k.as_str(), "title" | "default" | "additionalProperties"));
+    if let Some(Value::String(t)) = map.get_mut("type") {
+        *t = t.to_uppercase();
+    }
+    if let Some(Value::Array(values)) = map.get("enum") {
+        if !values.iter().all(Value::is_string) {
+            map.remove("enum");
+        }
+    }
+
+    for (key, value) in map.iter_mut() {
+        match (key.as_str(), value) {
+            ("properties", Value::Object(schemas)) => schemas.values_mut().for_each(to_gemini),
+            ("anyOf", Value::Array(schemas)) => schemas.iter_mut().for_each(to_gemini),
+            ("items", schema) => to_gemini(schema),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_objects_are_sent_to_openai() {
+        let class = json!({ "type": "object", "properties": {} });
+        assert!(object_schema(Some(&class)).is_some());
+        assert!(object_schema(Some(&json!({ "type": "array" }))).is_none());
+        assert!(object_schema(None).is_none());
+    }
+
+    #[test]
+    fn gemini_schema_uses_nullable() {
+        let schema = json!({
+            "title": "Person",
+            "type": "object",
+            "additionalProperties": false,
+            "required": ["address", "age", "mood"],
+            "properties": {
+                "address": {
+                    "anyOf": [
+                        { "$ref": "#/definitions/Address" },
+                        { "type": "null", "title": "null" },
+                    ],
+                },
+                "age": { "type": ["integer", "null"] },
+                "mood": { "type": "integer", "enum": [1] },
+            },
+            "definitions": {
+                "Address": {
+                    "title": "Address",
+                    "type": "object",
+                    "properties": { "city": { "type": "string" } },
+                },
+            },
+        });
+        assert_eq!(
+            to_gemini_schema(&schema).unwrap(),
+            json!({
+                "type": "OBJECT",
+                "required": ["address", "age", "mood"],
+                "properties": {
+                    "address": {
+                        "type": "OBJECT",
+                        "nullable": true,
+                        "properties": { "city": { "type": "STRING" } },
+                    },
+                    "age": { "type": "INTEGER", "nullable": true },
+                    "mood": { "type": "INTEGER" },
+                },
+            })
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// A class listed in a function's `tools`, which the model may call instead of
+/// answering in text.
+#[derive(Clone, Debug, Serialize)]
+pub struct ToolDefinition {
+    pub name: String,
+    pub description: Option<String>,
+    /// JSON schema of the class. The model's arguments are parsed into it.
+    pub parameters: serde_json::Value,
+}
+
+/// A call the model made to one of the tools.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ToolCall {
+    /// The tool, i.e. the class the arguments are parsed into.
+    pub name: String,
+    /// The arguments as JSON, which may be partial while streaming.
+    pub arguments: String,
+}
+
+/// Turns the tool calls made by the model into the content of the response, a
+/// list of `{"name", "arguments"}`, so that logs show them like any other
+/// reply. The calls themselves are kept in the response's `tool_calls`.
+pub fn tool_calls_to_content(calls: &[ToolCall]) -> Option<String> {
+    match calls {
+        [] => None,
+        calls => serde_json::to_string(calls).ok(),
+    }
+}
+
+/// Replaces every `$ref` in `schema` with the definition it points to, for
+/// providers whose tool schemas can't hold references (Gemini). Recursive
+/// classes can't be written out this way, so they are an error.
+pub fn inline_definitions(schema: &serde_json::Value) -> Result<serde_json::Value> {
+    let mut schema = schema.clone();
+    let definitions = match schema.as_object_mut().and_then(|s| s.remove("definitions")) {
+        Some(serde_json::Value::Object(definitions)) => definitions,
+        _ => return Ok(schema),
+    };
+
+    fn inline(
+        value: &mut serde_json::Value,
+        definitions: &serde_json::Map<String, serde_json::Value>,
+        seen: &mut Vec<String>,
+    ) -> Result<()> {
+        let reference = value
+            .get("$ref")
+            .and_then(|r| r.as_str())
+            .and_then(|r| r.strip_prefix("#/definitions/"))
+            .map(ToString::to_string);
+        if let Some(name) = reference {
+            if seen.contains(&name) {
+                anyhow::bail!(
+                    "`{}` refers to itself, which can't be described to this provider",
+                    name
+                );
+            }
+            let mut definition = definitions
+                .get(&name)
+                .cloned()
+                .context(format!("Missing definition for `{}`", name))?;
+            seen.push(name);
+            inline(&mut definition, definitions, seen)?;
+            seen.pop();
+            *value = definition;
+            return Ok(());
+        }
+
+        match value {
+            serde_json::Value::Object(map) => map
+                .values_mut()
+                .try_for_each(|v| inline(v, definitions, seen)),
+            serde_json::Value::Array(items) => items
+                .iter_mut()
+                .try_for_each(|v| inline(v, definitions, seen)),
+            _ => Ok(()),
+        }
+    }
+
+    inline(&mut schema, &definitions, &mut Vec::new())?;
+    Ok(schema)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn call(name: &str, arguments: &str) -> ToolCall {
+        ToolCall {
+            name: name.into(),
+            arguments: arguments.into(),
+        }
+    }
+
+    #[test]
+    fn tool_calls_keep_their_names() {
+        let calls = vec![
+            call("GetWeather", r#"{"city": "Paris"}"#),
+            call("SendEmail", r#"{"to": "a@b.c", "bo"#),
+        ];
+        let content = tool_calls_to_content(&calls).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Vec<ToolCall>>(&content).unwrap(),
+            calls
+        );
+        assert_eq!(tool_calls_to_content(&[]), None);
+    }
+
+    #[test]
+    fn definitions_are_inlined() {
+        let schema = json!({
+            "type": "object",
+            "properties": { "address": { "$ref": "#/definitions/Address" } },
+            "definitions": {
+                "Address": { "type": "object", "properties": { "city": { "type": "string" } } },
+            },
+        });
+        assert_eq!(
+            inline_definitions(&schema).unwrap(),
+            json!({
+                "type": "object",
+                "properties": {
+                    "address": { "type": "object", "properties": { "city": { "type": "string" } } },
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn recursive_definitions_are_an_error() {
+        let schema = json!({
+            "type": "object",
+            "properties": { "next": { "$ref": "#/definitions/Node" } },
+            "definitions": {
+                "Node": { "type": "object", "properties": { "next": { "$ref": "#/definitions/Node" } } },
+            },
+        });
+        assert!(inline_definitions(&schema).is_err());
+    }
+}
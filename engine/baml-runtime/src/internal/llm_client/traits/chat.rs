@@ -1,7 +1,10 @@
 use anyhow::Result;
 use internal_baml_jinja::{ChatOptions, RenderedChatMessage};
 
-use crate::{internal::llm_client::LLMResponse, RuntimeContext};
+use crate::{
+    internal::llm_client::{tools::ToolDefinition, LLMResponse},
+    RuntimeContext,
+};
 
 use super::StreamResponse;
 
@@ -9,7 +12,13 @@ pub trait WithChat: Sync + Send {
     fn chat_options(&self, ctx: &RuntimeContext) -> Result<ChatOptions>;
 
     #[allow(async_fn_in_trait)]
-    async fn chat(&self, ctx: &RuntimeContext, prompt: &Vec<RenderedChatMessage>) -> LLMResponse;
+    async fn chat(
+        &self,
+        ctx: &RuntimeContext,
+        prompt: &Vec<RenderedChatMessage>,
+        tools: &[ToolDefinition],
+        output_schema: Option<&serde_json::Value>,
+    ) -> LLMResponse;
 }
 
 pub trait WithStreamChat: Sync + Send {
@@ -18,6 +27,8 @@ pub trait WithStreamChat: Sync + Send {
         &self,
         ctx: &RuntimeContext,
         prompt: &Vec<RenderedChatMessage>,
+        tools: &[ToolDefinition],
+        output_schema: Option<&serde_json::Value>,
     ) -> StreamResponse;
 }
 
@@ -32,7 +43,13 @@ where
     }
 
     #[allow(async_fn_in_trait)]
-    async fn chat(&self, _: &RuntimeContext, _: &Vec<RenderedChatMessage>) -> LLMResponse {
+    async fn chat(
+        &self,
+        _: &RuntimeContext,
+        _: &Vec<RenderedChatMessage>,
+        _: &[ToolDefinition],
+        _: Option<&serde_json::Value>,
+    ) -> LLMResponse {
         LLMResponse::InternalFailure("Chat prompts are not supported by this provider".to_string())
     }
 }
@@ -46,6 +63,8 @@ where
         &self,
         _: &RuntimeContext,
         _: &Vec<RenderedChatMessage>,
+        _: &[ToolDefinition],
+        _: Option<&serde_json::Value>,
     ) -> StreamResponse {
         Err(LLMResponse::InternalFailure(
             "Chat prompts are not supported by this provider".to_string(),
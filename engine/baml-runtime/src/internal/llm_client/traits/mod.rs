@@ -10,7 +10,9 @@ pub use self::{
     chat::{WithChat, WithStreamChat},
     completion::{WithCompletion, WithNoCompletion, WithStreamCompletion},
 };
-use super::{primitive::request::RequestBuilder, LLMResponse, ModelFeatures};
+use super::{
+    primitive::request::RequestBuilder, tools::ToolDefinition, LLMResponse, ModelFeatures,
+};
 use crate::{internal::llm_client::ResolveMediaUrls, RenderCurlSettings};
 use crate::{internal::prompt_renderer::PromptRenderer, RuntimeContext};
 use baml_types::{BamlMedia, BamlMediaContent, BamlMediaType, BamlValue, MediaBase64, MediaUrl};
@@ -39,7 +41,13 @@ pub trait WithClientProperties {
 
 pub trait WithSingleCallable {
     #[allow(async_fn_in_trait)]
-    async fn single_call(&self, ctx: &RuntimeContext, prompt: &RenderedPrompt) -> LLMResponse;
+    async fn single_call(
+        &self,
+        ctx: &RuntimeContext,
+        prompt: &RenderedPrompt,
+        tools: &[ToolDefinition],
+        output_schema: Option<&serde_json::Value>,
+    ) -> LLMResponse;
 }
 
 pub trait WithClient {
@@ -136,6 +144,8 @@ pub trait WithRenderRawCurl {
         &self,
         ctx: &RuntimeContext,
         prompt: &Vec<RenderedChatMessage>,
+        tools: &[ToolDefinition],
+        output_schema: Option<&serde_json::Value>,
         render_settings: RenderCurlSettings,
     ) -> Result<String>;
 }
@@ -145,7 +155,13 @@ where
     T: WithClient + WithChat + WithCompletion,
 {
     #[allow(async_fn_in_trait)]
-    async fn single_call(&self, ctx: &RuntimeContext, prompt: &RenderedPrompt) -> LLMResponse {
+    async fn single_call(
+        &self,
+        ctx: &RuntimeContext,
+        prompt: &RenderedPrompt,
+        tools: &[ToolDefinition],
+        output_schema: Option<&serde_json::Value>,
+    ) -> LLMResponse {
         if let RenderedPrompt::Chat(chat) = &prompt {
             match process_media_urls(
                 self.model_features().resolve_media_urls,
@@ -156,7 +172,7 @@ where
             )
             .await
             {
-                Ok(messages) => return self.chat(ctx, &messages).await,
+                Ok(messages) => return self.chat(ctx, &messages, tools, output_schema).await,
                 Err(e) => {
                     return LLMResponse::InternalFailure(format!("Error occurred:\n\n{:?}", e))
                 }
@@ -164,7 +180,10 @@ where
         }
 
         match prompt {
-            RenderedPrompt::Chat(p) => self.chat(ctx, p).await,
+            RenderedPrompt::Chat(p) => self.chat(ctx, p, tools, output_schema).await,
+            RenderedPrompt::Completion(_) if !tools.is_empty() => LLMResponse::InternalFailure(
+                "Tools are only supported with chat prompts".to_string(),
+            ),
             RenderedPrompt::Completion(p) => self.completion(ctx, p).await,
         }
     }
@@ -270,6 +289,8 @@ where
         &self,
         ctx: &RuntimeContext,
         prompt: &Vec<internal_baml_jinja::RenderedChatMessage>,
+        tools: &[ToolDefinition],
+        output_schema: Option<&serde_json::Value>,
         render_settings: RenderCurlSettings,
     ) -> Result<String> {
         let chat_messages = process_media_urls(
@@ -282,7 +303,13 @@ where
         .await?;
 
         let request_builder = self
-            .build_request(either::Right(&chat_messages), false, render_settings.stream)
+            .build_request(
+                either::Right(&chat_messages),
+                tools,
+                output_schema,
+                false,
+                render_settings.stream,
+            )
             .await?;
         let mut request = request_builder.build()?;
         let url_header_value = {
@@ -328,7 +355,13 @@ pub type StreamResponse =
 pub trait WithStreamable {
     /// Retries are not supported for streaming calls.
     #[allow(async_fn_in_trait)]
-    async fn stream(&self, ctx: &RuntimeContext, prompt: &RenderedPrompt) -> StreamResponse;
+    async fn stream(
+        &self,
+        ctx: &RuntimeContext,
+        prompt: &RenderedPrompt,
+        tools: &[ToolDefinition],
+        output_schema: Option<&serde_json::Value>,
+    ) -> StreamResponse;
 }
 
 impl<T> WithStreamable for T
@@ -336,7 +369,13 @@ where
     T: WithClient + WithStreamChat + WithStreamCompletion,
 {
     #[allow(async_fn_in_trait)]
-    async fn stream(&self, ctx: &RuntimeContext, prompt: &RenderedPrompt) -> StreamResponse {
+    async fn stream(
+        &self,
+        ctx: &RuntimeContext,
+        prompt: &RenderedPrompt,
+        tools: &[ToolDefinition],
+        output_schema: Option<&serde_json::Value>,
+    ) -> StreamResponse {
         if let RenderedPrompt::Chat(ref chat) = prompt {
             match process_media_urls(
                 self.model_features().resolve_media_urls,
@@ -347,7 +386,9 @@ where
             )
             .await
             {
-                Ok(messages) => return self.stream_chat(ctx, &messages).await,
+                Ok(messages) => {
+                    return self.stream_chat(ctx, &messages, tools, output_schema).await
+                }
                 Err(e) => {
                     return Err(LLMResponse::InternalFailure(format!(
                         "Error occurred:\n\n{:?}",
@@ -358,7 +399,12 @@ where
         }
 
         match prompt {
-            RenderedPrompt::Chat(p) => self.stream_chat(ctx, p).await,
+            RenderedPrompt::Chat(p) => self.stream_chat(ctx, p, tools, output_schema).await,
+            RenderedPrompt::Completion(_) if !tools.is_empty() => {
+                Err(LLMResponse::InternalFailure(
+                    "Tools are only supported with chat prompts".to_string(),
+                ))
+            }
             RenderedPrompt::Completion(p) => self.stream_completion(ctx, p).await,
         }
     }
@@ -0,0 +1,302 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{FunctionResult, SpanCtx};
+
+use super::{LLMCompleteResponse, LLMCompleteResponseMetadata, LLMResponse};
+
+/// What a model charges, in dollars per million tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ModelPricing {
+    pub input_per_million: f64,
+    pub output_per_million: f64,
+}
+
+impl ModelPricing {
+    /// Takes the `pricing` option out of the client's options. It's a map with
+    /// `input_per_million` and `output_per_million`, both in dollars.
+    pub fn from_properties(
+        properties: &mut HashMap<String, serde_json::Value>,
+    ) -> Result<Option<Self>> {
+        let Some(pricing) = properties.remove("pricing") else {
+            return Ok(None);
+        };
+        let pricing: Self = serde_json::from_value(pricing).context(
+            "pricing must be a map with input_per_million and output_per_million, in dollars",
+        )?;
+        if pricing.input_per_million < 0.0 || pricing.output_per_million < 0.0 {
+            anyhow::bail!("pricing can't be negative");
+        }
+        Ok(Some(pricing))
+    }
+
+    /// The cost of a response, if the provider said how many tokens it used.
+    pub fn cost(&self, metadata: &LLMCompleteResponseMetadata) -> Option<f64> {
+        let input = metadata.prompt_tokens? as f64;
+        let output = metadata.output_tokens? as f64;
+        Some((input * self.input_per_million + output * self.output_per_million) / 1_000_000.0)
+    }
+}
+
+/// Prices by model name, for clients without a `pricing` option. Read from the
+/// JSON file that `BAML_PRICING_FILE` points to, which maps model names to
+/// their [`ModelPricing`].
+#[derive(Debug, Default)]
+pub struct PriceTable {
+    models: HashMap<String, ModelPricing>,
+}
+
+impl PriceTable {
+    pub fn from_env(env_vars: &HashMap<String, String>) -> Result<Arc<Self>> {
+        match env_vars.get("BAML_PRICING_FILE") {
+            Some(path) if !path.is_empty() => Ok(Arc::new(Self::from_file(Path::new(path))?)),
+            _ => Ok(Default::default()),
+        }
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let models = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse price table {}", path.display()))?;
+        Ok(Self { models })
+    }
+
+    /// Providers often answer with a dated version of the model that was
+    /// asked for, like `gpt-4o-2024-08-06` for `gpt-4o`, so when there's no
+    /// exact match, `model` is priced as the model it's a version of. Other
+    /// models sharing a prefix, like `gpt-4o` and `gpt-4`, aren't confused.
+    pub fn get(&self, model: &str) -> Option<&ModelPricing> {
+        self.models.get(model).or_else(|| {
+            self.models
+                .iter()
+                .filter(|(name, _)| {
+                    model
+                        .strip_prefix(name.as_str())
+                        .is_some_and(is_version_suffix)
+                })
+                .max_by_key(|(name, _)| name.len())
+                .map(|(_, pricing)| pricing)
+        })
+    }
+}
+
+/// Whether `suffix` names a version, like `-2024-08-06`, `-0613` or
+/// `-20240229-v1:0`.
+fn is_version_suffix(suffix: &str) -> bool {
+    let Some(version) = suffix.strip_prefix('-') else {
+        return false;
+    };
+    version.split('-').all(|part| {
+        let part = part.strip_prefix('v').unwrap_or(part);
+        part.starts_with(|c: char| c.is_ascii_digit())
+            && part
+                .chars()
+                .all(|c| c.is_ascii_digit() || c == '.' || c == ':')
+    })
+}
+
+/// Tokens used, and what they cost, by one or more LLM requests.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Usage {
+    /// Requests the provider answered.
+    pub requests: u64,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    /// Estimated cost in dollars of the requests with a known price.
+    pub cost: f64,
+    /// Requests whose cost is unknown, because their model has no price or
+    /// the provider didn't report token counts. They aren't part of `cost`.
+    pub unpriced_requests: u64,
+}
+
+impl Usage {
+    pub fn add_response(&mut self, response: &LLMCompleteResponse) {
+        let metadata = &response.metadata;
+        self.requests += 1;
+        self.input_tokens += metadata.prompt_tokens.unwrap_or_default();
+        self.output_tokens += metadata.output_tokens.unwrap_or_default();
+        match metadata.cost {
+            Some(cost) => self.cost += cost,
+            None => self.unpriced_requests += 1,
+        }
+    }
+
+    pub fn add(&mut self, other: &Usage) {
+        self.requests += other.requests;
+        self.input_tokens += other.input_tokens;
+        self.output_tokens += other.output_tokens;
+        self.cost += other.cost;
+        self.unpriced_requests += other.unpriced_requests;
+    }
+}
+
+impl FunctionResult {
+    /// Usage of every request made for this call, including retries and
+    /// fallbacks.
+    pub fn usage(&self) -> Usage {
+        let mut usage = Usage::default();
+        for (_, response, ..) in self.event_chain() {
+            if let LLMResponse::Success(response) = response {
+                usage.add_response(response);
+            }
+        }
+        usage
+    }
+}
+
+/// Usage since the runtime was created or last reset, broken down by function,
+/// by client, and by the spans the functions were called in.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct UsageReport {
+    pub total: Usage,
+    pub functions: BTreeMap<String, Usage>,
+    pub clients: BTreeMap<String, Usage>,
+    pub spans: BTreeMap<String, Usage>,
+}
+
+/// Adds up the usage of every function called with a runtime.
+#[derive(Debug, Default)]
+pub struct UsageCollector {
+    report: Mutex<UsageReport>,
+}
+
+impl UsageCollector {
+    /// `event_chain` is every span the call was made in, ending with the
+    /// function's own span.
+    pub fn record(&self, event_chain: &[SpanCtx], result: &FunctionResult) {
+        let Some((function, spans)) = event_chain.split_last() else {
+            return;
+        };
+
+        let mut report = self.report.lock().unwrap();
+        let mut function_usage = Usage::default();
+        for (_, response, ..) in result.event_chain() {
+            if let LLMResponse::Success(response) = response {
+                let mut usage = Usage::default();
+                usage.add_response(response);
+                report
+                    .clients
+                    .entry(response.client.clone())
+                    .or_default()
+                    .add(&usage);
+                function_usage.add(&usage);
+            }
+        }
+
+        report.total.add(&function_usage);
+        report
+            .functions
+            .entry(function.name.clone())
+            .or_default()
+            .add(&function_usage);
+        // A span that's entered recursively only counts the call once
+        let mut names = spans.iter().map(|s| s.name.as_str()).collect::<Vec<_>>();
+        names.sort();
+        names.dedup();
+        for name in names {
+            report
+                .spans
+                .entry(name.to_string())
+                .or_default()
+                .add(&function_usage);
+        }
+    }
+
+    pub fn report(&self) -> UsageReport {
+        self.report.lock().unwrap().clone()
+    }
+
+    /// Starts counting from zero, returning what was counted so far.
+    pub fn reset(&self) -> UsageReport {
+        std::mem::take(&mut *self.report.lock().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn metadata(
+        prompt_tokens: Option<u64>,
+        output_tokens: Option<u64>,
+    ) -> LLMCompleteResponseMetadata {
+        LLMCompleteResponseMetadata {
+            baml_is_complete: true,
+            finish_reason: Some("stop".into()),
+            prompt_tokens,
+            output_tokens,
+            total_tokens: None,
+            cost: None,
+        }
+    }
+
+    #[test]
+    fn pricing_option() {
+        let pricing = |value: serde_json::Value| {
+            let mut properties = HashMap::from([("pricing".to_string(), value)]);
+            let pricing = ModelPricing::from_properties(&mut properties);
+            assert!(properties.is_empty());
+            pricing
+        };
+        let gpt = pricing(json!({"input_per_million": 2.5, "output_per_million": 10}))
+            .unwrap()
+            .unwrap();
+        assert_eq!(gpt.cost(&metadata(Some(1000), Some(500))), Some(0.0075));
+        assert_eq!(gpt.cost(&metadata(None, Some(500))), None);
+
+        assert!(pricing(json!({"input_per_million": 2.5})).is_err());
+        assert!(pricing(json!({"input_per_million": 1, "output_per_million": -1})).is_err());
+        assert!(ModelPricing::from_properties(&mut HashMap::new())
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn price_table_matches_dated_models() {
+        let pricing = |input_per_million| ModelPricing {
+            input_per_million,
+            output_per_million: 0.0,
+        };
+        let table = PriceTable {
+            models: HashMap::from([
+                ("gpt-4o".to_string(), pricing(2.5)),
+                ("gpt-4o-mini".to_string(), pricing(0.15)),
+            ]),
+        };
+        assert_eq!(table.get("gpt-4o"), Some(&pricing(2.5)));
+        assert_eq!(table.get("gpt-4o-2024-08-06"), Some(&pricing(2.5)));
+        assert_eq!(table.get("gpt-4o-mini-2024-07-18"), Some(&pricing(0.15)));
+        assert_eq!(table.get("claude-3-5-sonnet"), None);
+    }
+
+    #[test]
+    fn price_table_keeps_models_sharing_a_prefix_apart() {
+        let pricing = |input_per_million| ModelPricing {
+            input_per_million,
+            output_per_million: 0.0,
+        };
+        let table = PriceTable {
+            models: HashMap::from([
+                ("gpt-4".to_string(), pricing(30.0)),
+                ("anthropic.claude-3-sonnet".to_string(), pricing(3.0)),
+            ]),
+        };
+        assert_eq!(table.get("gpt-4-0613"), Some(&pricing(30.0)));
+        assert_eq!(table.get("gpt-4o"), None);
+        assert_eq!(table.get("gpt-4o-mini"), None);
+        assert_eq!(table.get("gpt-4-turbo"), None);
+        assert_eq!(
+            table.get("anthropic.claude-3-sonnet-20240229-v1:0"),
+            Some(&pricing(3.0))
+        );
+    }
+}
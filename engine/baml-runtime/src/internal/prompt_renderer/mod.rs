@@ -1,14 +1,14 @@
 mod render_output_format;
-use jsonish::BamlValueWithFlags;
+use jsonish::{deserializer::deserialize_flags::DeserializerConditions, BamlValueWithFlags};
 use render_output_format::render_output_format;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use baml_types::{BamlValue, FieldType};
 use internal_baml_core::{
     error_unsupported,
     ir::{
-        repr::{ClientSpec, IntermediateRepr},
-        FunctionWalker, IRHelper,
+        repr::{ClientSpec, Expression, IntermediateRepr},
+        FunctionWalker, IRHelper, WithJsonSchema,
     },
 };
 use internal_baml_jinja::{
@@ -16,13 +16,18 @@ use internal_baml_jinja::{
     TemplateStringMacro,
 };
 
-use crate::RuntimeContext;
+use crate::{
+    internal::llm_client::tools::{ToolCall, ToolDefinition},
+    RuntimeContext,
+};
 
 pub struct PromptRenderer {
     function_name: String,
     client_spec: ClientSpec,
     output_defs: OutputFormatContent,
     output_type: FieldType,
+    tools: Vec<ToolDefinition>,
+    output_schema: Option<serde_json::Value>,
 }
 
 impl PromptRenderer {
@@ -44,6 +49,27 @@ impl PromptRenderer {
             },
             output_defs: render_output_format(ir, ctx, &func_v2.output)?,
             output_type: func_v2.output.clone(),
+            tools: config
+                .tools
+                .iter()
+                .map(|name| {
+                    let class = ir.find_class(name)?;
+                    Ok(ToolDefinition {
+                        name: name.clone(),
+                        description: match class.item.attributes.get("description") {
+                            Some(Expression::String(description)) => Some(description.clone()),
+                            _ => None,
+                        },
+                        parameters: (ir, &FieldType::Class(name.clone())).json_schema(),
+                    })
+                })
+                .collect::<Result<_>>()?,
+            // Types changed with a TypeBuilder aren't in the IR, so a schema
+            // built from it would reject their fields.
+            output_schema: match ctx.class_override.is_empty() && ctx.enum_overrides.is_empty() {
+                true => (ir, &func_v2.output).strict_json_schema(),
+                false => None,
+            },
         })
     }
 
@@ -51,7 +77,25 @@ impl PromptRenderer {
         &self.client_spec
     }
 
-    pub fn parse(&self, raw_string: &str, allow_partials: bool) -> Result<BamlValueWithFlags> {
+    pub fn tools(&self) -> &[ToolDefinition] {
+        &self.tools
+    }
+
+    pub fn output_schema(&self) -> Option<&serde_json::Value> {
+        self.output_schema.as_ref()
+    }
+
+    /// Parses a response. When the provider answered with tool calls, those
+    /// are parsed instead of `raw_string`.
+    pub fn parse(
+        &self,
+        raw_string: &str,
+        tool_calls: &[ToolCall],
+        allow_partials: bool,
+    ) -> Result<BamlValueWithFlags> {
+        if !tool_calls.is_empty() {
+            return self.parse_tool_calls(tool_calls, allow_partials);
+        }
         jsonish::from_str(
             &self.output_defs,
             &self.output_type,
@@ -60,6 +104,58 @@ impl PromptRenderer {
         )
     }
 
+    /// Parses the response streamed so far, continuing the parse of the last
+    /// chunk kept in `state`.
+    pub fn parse_streaming(
+        &self,
+        raw_string: &str,
+        tool_calls: &[ToolCall],
+        state: &mut jsonish::StreamingState,
+    ) -> Result<BamlValueWithFlags> {
+        if !tool_calls.is_empty() {
+            return self.parse_tool_calls(tool_calls, true);
+        }
+        jsonish::from_str_streaming(&self.output_defs, &self.output_type, raw_string, state)
+    }
+
+    /// Parses the arguments of each call into the class named by its tool.
+    /// Functions returning a list get every call, the others only the first.
+    fn parse_tool_calls(
+        &self,
+        calls: &[ToolCall],
+        allow_partials: bool,
+    ) -> Result<BamlValueWithFlags> {
+        let mut values = calls
+            .iter()
+            // While streaming, a call may not have any arguments yet.
+            .filter(|call| !(allow_partials && call.arguments.trim().is_empty()))
+            .map(|call| {
+                jsonish::from_str(
+                    &self.output_defs,
+                    &FieldType::Class(call.name.clone()),
+                    &call.arguments,
+                    allow_partials,
+                )
+                .with_context(|| format!("Failed to parse the arguments of tool `{}`", call.name))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut output_type = &self.output_type;
+        while let FieldType::Optional(inner) | FieldType::Constrained { base: inner, .. } =
+            output_type
+        {
+            output_type = inner;
+        }
+        match output_type {
+            FieldType::List(_) => Ok(BamlValueWithFlags::List(
+                DeserializerConditions::new(),
+                values,
+            )),
+            _ if values.is_empty() => anyhow::bail!("No tool call arguments to parse yet"),
+            _ => Ok(values.swap_remove(0)),
+        }
+    }
+
     pub fn render_prompt(
         &self,
         ir: &IntermediateRepr,
@@ -99,3 +195,89 @@ impl PromptRenderer {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::{internal::llm_client::tools::tool_calls_to_content, BamlRuntime};
+
+    #[test]
+    fn tool_calls_are_parsed_into_their_tools() {
+        let files = vec![(
+            "test-file.baml",
+            r##"
+          client<llm> GPT4 {
+            provider openai
+            options {
+              model gpt-4o
+            }
+          }
+
+          class GetWeather {
+            city string
+          }
+
+          class GetForecast {
+            city string
+          }
+
+          function Assistant(request: string) -> (GetWeather | GetForecast)[] {
+            client GPT4
+            tools [GetWeather, GetForecast]
+            prompt #"{{ request }}"#
+          }
+
+          function Chat(request: string) -> string | GetWeather {
+            client GPT4
+            tools [GetWeather]
+            prompt #"{{ request }}"#
+          }"##,
+        )]
+        .into_iter()
+        .collect();
+        let env_vars: HashMap<&str, &str> = HashMap::new();
+        let baml_runtime = BamlRuntime::from_file_content(".", &files, env_vars).unwrap();
+        let ctx_manager = baml_runtime.create_ctx_manager(BamlValue::Null, None);
+        let ctx: RuntimeContext = ctx_manager.create_ctx(None, None).unwrap();
+        let ir = baml_runtime.inner.ir.as_ref();
+        let renderer =
+            PromptRenderer::from_function(&ir.find_function("Assistant").unwrap(), ir, &ctx)
+                .unwrap();
+
+        // Both tools take the same arguments, so only the name tells them apart.
+        let tool_calls = [
+            ToolCall {
+                name: "GetForecast".into(),
+                arguments: r#"{"city": "Paris"}"#.into(),
+            },
+            ToolCall {
+                name: "GetWeather".into(),
+                arguments: r#"{"city": "Rome"}"#.into(),
+            },
+        ];
+        let content = tool_calls_to_content(&tool_calls).unwrap();
+        let BamlValueWithFlags::List(_, calls) =
+            renderer.parse(&content, &tool_calls, false).unwrap()
+        else {
+            panic!("Expected a list of tool calls");
+        };
+        let classes = calls
+            .iter()
+            .map(|call| match call {
+                BamlValueWithFlags::Class(name, ..) => name.as_str(),
+                _ => panic!("Expected a class, got {:?}", call),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(classes, vec!["GetForecast", "GetWeather"]);
+
+        // A reply that only looks like tool calls is still text.
+        let renderer =
+            PromptRenderer::from_function(&ir.find_function("Chat").unwrap(), ir, &ctx).unwrap();
+        match renderer.parse(&content, &[], false).unwrap() {
+            BamlValueWithFlags::String(_) => (),
+            reply => panic!("Expected a string, got {:?}", reply),
+        }
+    }
+}
@@ -1,8 +1,8 @@
 use std::collections::HashSet;
 
 use anyhow::Result;
-use baml_types::{BamlValue, Constraint};
-use indexmap::IndexSet;
+use baml_types::{BamlValue, Constraint, StreamingBehavior};
+use indexmap::{IndexMap, IndexSet};
 use internal_baml_core::ir::{
     repr::IntermediateRepr, ClassWalker, EnumWalker, FieldType, IRHelper,
 };
@@ -18,8 +18,10 @@ pub fn render_output_format(
     ctx: &RuntimeContext,
     output: &FieldType,
 ) -> Result<OutputFormatContent> {
-    let (enums, classes) = relevant_data_models(ir, output, ctx)?;
-    return Ok(OutputFormatContent::new(enums, classes, output.clone()));
+    let (enums, classes, aliases) = relevant_data_models(ir, output, ctx)?;
+    return Ok(
+        OutputFormatContent::new(enums, classes, output.clone()).with_recursive_aliases(aliases)
+    );
 }
 
 enum OverridableValue<T> {
@@ -67,7 +69,13 @@ fn find_new_class_field<'a>(
     class_walker: &Result<ClassWalker<'_>>,
     overrides: &'a RuntimeClassOverride,
     _ctx: &RuntimeContext,
-) -> Result<(Name, FieldType, Option<String>)> {
+) -> Result<(
+    Name,
+    FieldType,
+    Option<String>,
+    StreamingBehavior,
+    Option<BamlValue>,
+)> {
     let Some(field_overrides) = overrides.new_fields.get(field_name) else {
         anyhow::bail!("Class {} does not have a field: {}", class_name, field_name);
     };
@@ -89,7 +97,13 @@ fn find_new_class_field<'a>(
     let name = Name::new_with_alias(field_name.to_string(), alias.value());
     let desc = desc.value();
 
-    Ok((name, field_overrides.0.clone(), desc))
+    Ok((
+        name,
+        field_overrides.0.clone(),
+        desc,
+        StreamingBehavior::default(),
+        None,
+    ))
 }
 
 fn find_existing_class_field<'a>(
@@ -98,7 +112,13 @@ fn find_existing_class_field<'a>(
     class_walker: &Result<ClassWalker<'a>>,
     overrides: &Option<&RuntimeClassOverride>,
     ctx: &RuntimeContext,
-) -> Result<(Name, FieldType, Option<String>)> {
+) -> Result<(
+    Name,
+    FieldType,
+    Option<String>,
+    StreamingBehavior,
+    Option<BamlValue>,
+)> {
     let Ok(class_walker) = class_walker else {
         anyhow::bail!("Class {} does not exist", class_name);
     };
@@ -132,7 +152,13 @@ fn find_existing_class_field<'a>(
     let name = Name::new_with_alias(field_name.to_string(), alias.value());
     let desc = desc.value();
     let r#type = field_walker.r#type();
-    Ok((name, r#type.clone(), desc))
+    Ok((
+        name,
+        r#type.clone(),
+        desc,
+        field_walker.streaming_behavior(),
+        field_walker.default_value(&ctx.env)?,
+    ))
 }
 
 fn find_enum_value(
@@ -198,10 +224,11 @@ fn relevant_data_models<'a>(
     ir: &'a IntermediateRepr,
     output: &'a FieldType,
     ctx: &RuntimeContext,
-) -> Result<(Vec<Enum>, Vec<Class>)> {
+) -> Result<(Vec<Enum>, Vec<Class>, IndexMap<String, FieldType>)> {
     let mut checked_types = HashSet::new();
     let mut enums = Vec::new();
     let mut classes = Vec::new();
+    let mut aliases = IndexMap::new();
     let mut start: Vec<baml_types::FieldType> = vec![output.clone()];
 
     while let Some(output) = start.pop() {
@@ -322,7 +349,7 @@ fn relevant_data_models<'a>(
 
                     let fields = fields.chain(new_fields).collect::<Result<Vec<_>>>()?;
 
-                    for (_, t, _) in fields.iter().as_ref() {
+                    for (_, t, ..) in fields.iter().as_ref() {
                         if !checked_types.contains(&t.to_string()) {
                             start.push(t.clone());
                         }
@@ -332,9 +359,20 @@ fn relevant_data_models<'a>(
                         name: Name::new_with_alias(cls.to_string(), alias.value()),
                         fields,
                         constraints,
+                        streaming_behavior: walker
+                            .as_ref()
+                            .map(|w| w.streaming_behavior())
+                            .unwrap_or_default(),
                     });
                 }
             }
+            (FieldType::RecursiveTypeAlias(name), _) => {
+                if checked_types.insert(output.to_string()) {
+                    let target = ir.find_type_alias(name)?.target();
+                    aliases.insert(name.to_string(), target.clone());
+                    start.push(target.clone());
+                }
+            }
             (FieldType::Literal(_), _) => {}
             (FieldType::Primitive(_), _) => {}
             (FieldType::Constrained{..}, _)=> {
@@ -343,7 +381,7 @@ fn relevant_data_models<'a>(
         }
     }
 
-    Ok((enums, classes))
+    Ok((enums, classes, aliases))
 }
 
 #[cfg(test)]
@@ -379,4 +417,31 @@ mod tests {
         assert_eq!(foo_enum.values.len(), 1);
     }
 
+    #[test]
+    fn recursive_types_are_hoisted() {
+        let files = vec![(
+            "test-file.baml",
+            r#"
+          type Json = string | Json[]
+
+          class Node {
+            value Json
+            next Node?
+          }"#,
+        )]
+        .into_iter()
+        .collect();
+        let env_vars: HashMap<&str, &str> = HashMap::new();
+        let baml_runtime = BamlRuntime::from_file_content(".", &files, env_vars).unwrap();
+        let ctx_manager = baml_runtime.create_ctx_manager(BamlValue::Null, None);
+        let ctx: RuntimeContext = ctx_manager.create_ctx(None, None).unwrap();
+
+        let field_type = FieldType::Class("Node".to_string());
+        let render_output =
+            render_output_format(baml_runtime.inner.ir.as_ref(), &ctx, &field_type).unwrap();
+
+        assert!(render_output.find_class("Node").is_ok());
+        assert!(render_output.find_recursive_alias_target("Json").is_ok());
+    }
+
 }
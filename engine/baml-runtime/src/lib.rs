@@ -11,14 +11,18 @@ pub mod internal;
 #[cfg(not(feature = "internal"))]
 pub(crate) mod internal;
 
+pub mod cancel;
 #[cfg(not(target_arch = "wasm32"))]
 mod cli;
 pub mod client_registry;
+pub mod collector;
 pub mod errors;
 mod macros;
 pub mod request;
 mod runtime;
 pub mod runtime_interface;
+#[cfg(test)]
+mod test_server;
 pub mod tracing;
 pub mod type_builder;
 mod types;
@@ -32,8 +36,10 @@ use anyhow::Result;
 
 use baml_types::BamlMap;
 use baml_types::BamlValue;
+use cancel::CancelToken;
 use cfg_if::cfg_if;
 use client_registry::ClientRegistry;
+use collector::Collector;
 use indexmap::IndexMap;
 use internal_baml_core::configuration::Generator;
 use internal_baml_core::configuration::GeneratorOutputType;
@@ -43,6 +49,9 @@ use std::sync::OnceLock;
 
 #[cfg(not(target_arch = "wasm32"))]
 pub use cli::RuntimeCliDefaults;
+pub use internal::llm_client::cache::CacheMode;
+pub use internal::llm_client::cassette::{Cassette, CassetteMode};
+pub use internal::llm_client::usage::{Usage, UsageReport};
 pub use runtime_context::BamlSrcReader;
 use runtime_interface::ExperimentalTracingInterface;
 use runtime_interface::RuntimeConstructor;
@@ -74,6 +83,7 @@ pub struct BamlRuntime {
     pub(crate) inner: InternalBamlRuntime,
     tracer: Arc<BamlTracer>,
     env_vars: HashMap<String, String>,
+    cassette: Option<Cassette>,
     #[cfg(not(target_arch = "wasm32"))]
     pub async_runtime: Arc<tokio::runtime::Runtime>,
 }
@@ -83,6 +93,13 @@ impl BamlRuntime {
         &self.env_vars
     }
 
+    /// Records every provider request to `cassette`, or replays them from it,
+    /// overriding `BAML_CASSETTE_MODE`. Applies to context managers created
+    /// from now on.
+    pub fn set_cassette(&mut self, cassette: Option<Cassette>) {
+        self.cassette = cassette;
+    }
+
     #[cfg(not(target_arch = "wasm32"))]
     fn get_tokio_singleton() -> Result<Arc<tokio::runtime::Runtime>> {
         match TOKIO_SINGLETON.get_or_init(|| tokio::runtime::Runtime::new().map(Arc::new)) {
@@ -139,6 +156,7 @@ impl BamlRuntime {
         Ok(BamlRuntime {
             inner: InternalBamlRuntime::from_directory(&path)?,
             tracer: BamlTracer::new(None, env_vars.into_iter())?.into(),
+            cassette: Cassette::from_env(&copy)?,
             env_vars: copy,
             #[cfg(not(target_arch = "wasm32"))]
             async_runtime: Self::get_tokio_singleton()?,
@@ -157,6 +175,7 @@ impl BamlRuntime {
         Ok(BamlRuntime {
             inner: InternalBamlRuntime::from_file_content(root_path, files)?,
             tracer: BamlTracer::new(None, env_vars.into_iter())?.into(),
+            cassette: Cassette::from_env(&copy)?,
             env_vars: copy,
             #[cfg(not(target_arch = "wasm32"))]
             async_runtime: Self::get_tokio_singleton()?,
@@ -168,6 +187,25 @@ impl BamlRuntime {
         &self.inner
     }
 
+    /// State of every circuit breaker this runtime has tripped over so far.
+    pub(crate) fn circuit_breaker_statuses(
+        &self,
+    ) -> Vec<internal::llm_client::circuit_breaker::CircuitBreakerStatus> {
+        self.inner.circuit_breaker_statuses()
+    }
+
+    /// Tokens used and their estimated cost, by every function called with this
+    /// runtime since it was created or [`reset_usage`](Self::reset_usage) was
+    /// last called.
+    pub fn usage(&self) -> UsageReport {
+        self.tracer.usage()
+    }
+
+    /// Starts counting usage from zero, returning what was counted so far.
+    pub fn reset_usage(&self) -> UsageReport {
+        self.tracer.reset_usage()
+    }
+
     #[cfg(not(target_arch = "wasm32"))]
     pub fn run_cli(argv: Vec<String>, caller_type: cli::RuntimeCliDefaults) -> Result<()> {
         cli::RuntimeCli::parse_from(argv.into_iter()).run(caller_type)
@@ -178,7 +216,8 @@ impl BamlRuntime {
         language: BamlValue,
         baml_src_reader: BamlSrcReader,
     ) -> RuntimeContextManager {
-        let ctx = RuntimeContextManager::new_from_env_vars(self.env_vars.clone(), baml_src_reader);
+        let ctx = RuntimeContextManager::new_from_env_vars(self.env_vars.clone(), baml_src_reader)
+            .with_cassette(self.cassette.clone());
         let tags: HashMap<String, BamlValue> = [("baml.language", language)]
             .into_iter()
             .map(|(k, v)| (k.to_string(), v))
@@ -198,6 +237,16 @@ impl BamlRuntime {
         self.inner.get_test_params(function_name, test_name, ctx)
     }
 
+    pub fn get_test_expectations(
+        &self,
+        function_name: &str,
+        test_name: &str,
+        ctx: &RuntimeContext,
+    ) -> Result<TestExpectations> {
+        self.inner
+            .get_test_expectations(function_name, test_name, ctx)
+    }
+
     pub async fn run_test<F>(
         &self,
         function_name: &str,
@@ -212,9 +261,15 @@ impl BamlRuntime {
 
         let response = match ctx.create_ctx(None, None) {
             Ok(rctx) => {
-                let params = self.get_test_params(function_name, test_name, &rctx);
+                let params = self
+                    .get_test_params(function_name, test_name, &rctx)
+                    .and_then(|params| {
+                        let expectations =
+                            self.get_test_expectations(function_name, test_name, &rctx)?;
+                        Ok((params, expectations))
+                    });
                 match params {
-                    Ok(params) => match ctx.create_ctx(None, None) {
+                    Ok((params, expectations)) => match ctx.create_ctx(None, None) {
                         Ok(rctx_stream) => {
                             let stream = self.inner.stream_function_impl(
                                 function_name.into(),
@@ -231,6 +286,7 @@ impl BamlRuntime {
                                     response.map(|res| TestResponse {
                                         function_response: res,
                                         function_span: span,
+                                        expectations,
                                     })
                                 }
                                 Err(e) => Err(e),
@@ -269,8 +325,10 @@ impl BamlRuntime {
         ctx: &RuntimeContextManager,
         tb: Option<&TypeBuilder>,
         cb: Option<&ClientRegistry>,
+        collector: Option<&Collector>,
+        cancel: Option<&CancelToken>,
     ) -> (Result<FunctionResult>, Option<uuid::Uuid>) {
-        let fut = self.call_function(function_name, params, ctx, tb, cb);
+        let fut = self.call_function(function_name, params, ctx, tb, cb, collector, cancel);
         self.async_runtime.block_on(fut)
     }
 
@@ -281,17 +339,27 @@ impl BamlRuntime {
         ctx: &RuntimeContextManager,
         tb: Option<&TypeBuilder>,
         cb: Option<&ClientRegistry>,
+        collector: Option<&Collector>,
+        cancel: Option<&CancelToken>,
     ) -> (Result<FunctionResult>, Option<uuid::Uuid>) {
         log::trace!("Calling function: {}", function_name);
         let span = self.tracer.start_span(&function_name, ctx, &params);
+        let log = collector.map(|c| c.start_call(&function_name));
+        let cancel = cancel.cloned().unwrap_or_default();
         let response = match ctx.create_ctx(tb, cb) {
-            Ok(rctx) => {
+            Ok(mut rctx) => {
+                rctx.collector = log.clone();
+                rctx.cancel = cancel.clone();
                 self.inner
                     .call_function_impl(function_name, params, rctx)
                     .await
             }
             Err(e) => Err(e),
         };
+        let response = cancel.check(response);
+        if let Some(log) = log {
+            log.finish(&response);
+        }
 
         let mut target_id = None;
         if let Some(span) = span {
@@ -316,15 +384,20 @@ impl BamlRuntime {
         ctx: &RuntimeContextManager,
         tb: Option<&TypeBuilder>,
         cb: Option<&ClientRegistry>,
+        collector: Option<&Collector>,
+        cancel: Option<&CancelToken>,
     ) -> Result<FunctionResultStream> {
-        self.inner.stream_function_impl(
+        let mut stream = self.inner.stream_function_impl(
             function_name,
             params,
             self.tracer.clone(),
             ctx.create_ctx(tb, cb)?,
             #[cfg(not(target_arch = "wasm32"))]
             self.async_runtime.clone(),
-        )
+        )?;
+        stream.collector = collector.cloned();
+        stream.cancel = cancel.cloned().unwrap_or_default();
+        Ok(stream)
     }
 
     #[cfg(not(target_arch = "wasm32"))]
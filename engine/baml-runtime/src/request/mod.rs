@@ -23,8 +23,22 @@ fn builder() -> reqwest::ClientBuilder {
     }
 }
 
-pub(crate) fn create_client() -> Result<reqwest::Client> {
-    builder().build().context("Failed to create reqwest client")
+/// `connect_timeout` replaces the default connect timeout, if set.
+pub(crate) fn create_client(connect_timeout: Option<Duration>) -> Result<reqwest::Client> {
+    cfg_if::cfg_if! {
+        if #[cfg(target_arch = "wasm32")] {
+            // The browser decides how long connecting may take.
+            let _ = connect_timeout;
+            let cb = builder();
+        } else {
+            let cb = match connect_timeout {
+                Some(connect_timeout) => builder().connect_timeout(connect_timeout),
+                None => builder(),
+            };
+        }
+    }
+
+    cb.build().context("Failed to create reqwest client")
 }
 
 pub(crate) fn create_tracing_client() -> Result<reqwest::Client> {
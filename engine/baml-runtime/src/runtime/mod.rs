@@ -19,13 +19,20 @@ use internal_baml_core::{
 };
 use std::sync::Arc;
 
-use crate::internal::llm_client::{llm_provider::LLMProvider, retry_policy::CallablePolicy};
+use crate::internal::llm_client::{
+    cache::{ResponseCache, ResponseCacheConfig},
+    circuit_breaker::{CircuitBreaker, CircuitBreakerStatus},
+    llm_provider::LLMProvider,
+    retry_policy::CallablePolicy,
+};
 
 pub struct InternalBamlRuntime {
     pub(crate) ir: Arc<IntermediateRepr>,
     diagnostics: Diagnostics,
     clients: DashMap<String, Arc<LLMProvider>>,
     retry_policies: DashMap<String, CallablePolicy>,
+    circuit_breakers: DashMap<String, Arc<CircuitBreaker>>,
+    response_caches: DashMap<ResponseCacheConfig, Arc<dyn ResponseCache>>,
 }
 
 impl InternalBamlRuntime {
@@ -51,6 +58,8 @@ impl InternalBamlRuntime {
             diagnostics: schema.diagnostics,
             clients: Default::default(),
             retry_policies: Default::default(),
+            circuit_breakers: Default::default(),
+            response_caches: Default::default(),
         })
     }
 
@@ -73,6 +82,30 @@ impl InternalBamlRuntime {
             diagnostics: schema.diagnostics,
             clients: Default::default(),
             retry_policies: Default::default(),
+            circuit_breakers: Default::default(),
+            response_caches: Default::default(),
         })
     }
 }
+
+impl InternalBamlRuntime {
+    pub(crate) fn circuit_breaker_statuses(&self) -> Vec<CircuitBreakerStatus> {
+        #[cfg(target_arch = "wasm32")]
+        let mut statuses = self
+            .circuit_breakers
+            .lock()
+            .unwrap()
+            .values()
+            .map(|b| b.status())
+            .collect::<Vec<_>>();
+        #[cfg(not(target_arch = "wasm32"))]
+        let mut statuses = self
+            .circuit_breakers
+            .iter()
+            .map(|b| b.value().status())
+            .collect::<Vec<_>>();
+
+        statuses.sort_by(|a, b| a.client.cmp(&b.client));
+        statuses
+    }
+}
@@ -8,6 +8,8 @@ use crate::{
     internal::{
         ir_features::{IrFeatures, WithInternal},
         llm_client::{
+            cache::{ResponseCache, ResponseCacheConfig},
+            circuit_breaker::CircuitBreaker,
             llm_provider::LLMProvider,
             orchestrator::{
                 orchestrate_call, IterOrchestrator, OrchestrationScope, OrchestratorNode,
@@ -21,14 +23,16 @@ use crate::{
     runtime_interface::{InternalClientLookup, RuntimeConstructor},
     tracing::BamlTracer,
     FunctionResult, FunctionResultStream, InternalRuntimeInterface, RenderCurlSettings,
-    RuntimeContext, RuntimeInterface,
+    RuntimeContext, RuntimeInterface, TestExpectations,
 };
 use anyhow::{Context, Result};
 use baml_types::{BamlMap, BamlValue};
 use internal_baml_core::{
     internal_baml_diagnostics::SourceFile,
+    internal_baml_parser_database::{CircuitBreakerConfig, ExpectedMatch},
     ir::{
         repr::{ClientSpec, IntermediateRepr},
+        scope_diagnostics::ScopeStack,
         ArgCoercer, FunctionWalker, IRHelper,
     },
     validate,
@@ -129,6 +133,55 @@ impl<'a> InternalClientLookup<'a> for InternalBamlRuntime {
             Ok(policy_ref.value().clone())
         }
     }
+
+    fn get_circuit_breaker(
+        &self,
+        client_name: &str,
+        config: &CircuitBreakerConfig,
+    ) -> Arc<CircuitBreaker> {
+        #[cfg(target_arch = "wasm32")]
+        let mut circuit_breakers = self.circuit_breakers.lock().unwrap();
+        #[cfg(not(target_arch = "wasm32"))]
+        let circuit_breakers = &self.circuit_breakers;
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            circuit_breakers
+                .entry(client_name.into())
+                .or_insert_with(|| Arc::new(CircuitBreaker::new(client_name, *config)))
+                .clone()
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            circuit_breakers
+                .entry(client_name.into())
+                .or_insert_with(|| Arc::new(CircuitBreaker::new(client_name, *config)))
+                .value()
+                .clone()
+        }
+    }
+
+    fn get_response_cache(&self, config: &ResponseCacheConfig) -> Arc<dyn ResponseCache> {
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.response_caches
+                .lock()
+                .unwrap()
+                .entry(config.clone())
+                .or_insert_with(|| config.build())
+                .clone()
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.response_caches
+                .entry(config.clone())
+                .or_insert_with(|| config.build())
+                .value()
+                .clone()
+        }
+    }
 }
 
 impl InternalRuntimeInterface for InternalBamlRuntime {
@@ -222,7 +275,13 @@ impl InternalRuntimeInterface for InternalBamlRuntime {
         let node = selected.swap_remove(node_index);
         return node
             .provider
-            .render_raw_curl(ctx, prompt, render_settings)
+            .render_raw_curl(
+                ctx,
+                prompt,
+                renderer.tools(),
+                renderer.output_schema(),
+                render_settings,
+            )
             .await;
     }
 
@@ -286,6 +345,44 @@ impl InternalRuntimeInterface for InternalBamlRuntime {
             Err(e) => return Err(anyhow::anyhow!("Unable to resolve test params: {:?}", e)),
         }
     }
+
+    fn get_test_expectations(
+        &self,
+        function_name: &str,
+        test_name: &str,
+        ctx: &RuntimeContext,
+    ) -> Result<TestExpectations> {
+        let func = self.get_function(function_name, ctx)?;
+        let test = self.ir().find_test(&func, test_name)?;
+
+        let expected = match test.expected(&ctx.env) {
+            // A fuzzy match may leave out fields, so only an exact match is
+            // held to the output type.
+            Some(expected) if test.expected_match() == ExpectedMatch::Fuzzy => {
+                Some(expected.context("Unable to resolve the expected value")?)
+            }
+            Some(expected) => {
+                let expected = expected.context("Unable to resolve the expected value")?;
+                let mut scope = ScopeStack::new();
+                let coerced = ArgCoercer {
+                    span_path: test.span().map(|s| s.file.path_buf().clone()),
+                    allow_implicit_cast_to_string: false,
+                }
+                .coerce_arg(self.ir(), func.output(), &expected, &mut scope);
+                match coerced {
+                    Ok(expected) if !scope.has_errors() => Some(expected),
+                    _ => anyhow::bail!("Invalid expected value: {}", scope),
+                }
+            }
+            None => None,
+        };
+
+        Ok(TestExpectations {
+            constraints: test.constraints().clone(),
+            expected,
+            expected_match: test.expected_match(),
+        })
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -367,6 +464,8 @@ impl RuntimeConstructor for InternalBamlRuntime {
             diagnostics: schema.diagnostics,
             clients: Default::default(),
             retry_policies: Default::default(),
+            circuit_breakers: Default::default(),
+            response_caches: Default::default(),
         })
     }
 
@@ -430,11 +529,15 @@ impl RuntimeInterface for InternalBamlRuntime {
         let orchestrator = self.orchestration_graph(renderer.client_spec(), &ctx)?;
 
         // Now actually execute the code.
-        let (history, _) =
-            orchestrate_call(orchestrator, self.ir(), &ctx, &renderer, &baml_args, |s| {
-                renderer.parse(s, false)
-            })
-            .await;
+        let (history, _) = orchestrate_call(
+            orchestrator,
+            self.ir(),
+            &ctx,
+            &renderer,
+            &baml_args,
+            |response| renderer.parse(&response.content, &response.tool_calls, false),
+        )
+        .await;
 
         FunctionResult::new_chain(history)
     }
@@ -471,6 +574,8 @@ impl RuntimeInterface for InternalBamlRuntime {
             orchestrator,
             tracer,
             renderer,
+            collector: None,
+            cancel: Default::default(),
             #[cfg(not(target_arch = "wasm32"))]
             tokio_runtime,
         })
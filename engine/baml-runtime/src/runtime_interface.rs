@@ -1,11 +1,14 @@
 use anyhow::Result;
 use baml_types::{BamlMap, BamlValue};
 use internal_baml_core::internal_baml_diagnostics::Diagnostics;
+use internal_baml_core::internal_baml_parser_database::CircuitBreakerConfig;
 use internal_baml_core::ir::repr::ClientSpec;
 use internal_baml_core::ir::{repr::IntermediateRepr, FunctionWalker};
 use internal_baml_jinja::RenderedPrompt;
 use std::{collections::HashMap, sync::Arc};
 
+use crate::internal::llm_client::cache::{ResponseCache, ResponseCacheConfig};
+use crate::internal::llm_client::circuit_breaker::CircuitBreaker;
 use crate::internal::llm_client::llm_provider::LLMProvider;
 use crate::internal::llm_client::orchestrator::{OrchestrationScope, OrchestratorNode};
 use crate::internal::llm_client::AllowedMetadata;
@@ -14,7 +17,7 @@ use crate::types::on_log_event::LogEventCallbackSync;
 use crate::{
     internal::{ir_features::IrFeatures, llm_client::retry_policy::CallablePolicy},
     runtime::InternalBamlRuntime,
-    types::FunctionResultStream,
+    types::{FunctionResultStream, TestExpectations},
     FunctionResult, RuntimeContext,
 };
 use crate::{RenderCurlSettings, RuntimeContextManager};
@@ -111,6 +114,16 @@ pub trait InternalClientLookup<'a> {
     ) -> Result<Arc<LLMProvider>>;
 
     fn get_retry_policy(&self, policy_name: &str, ctx: &RuntimeContext) -> Result<CallablePolicy>;
+
+    // Gets the circuit breaker shared by every call to this client
+    fn get_circuit_breaker(
+        &self,
+        client_name: &str,
+        config: &CircuitBreakerConfig,
+    ) -> Arc<CircuitBreaker>;
+
+    // Gets the response cache shared by every client configured with this `cache`
+    fn get_response_cache(&self, config: &ResponseCacheConfig) -> Arc<dyn ResponseCache>;
 }
 
 // Define your composite trait with a generic parameter that must implement all the required traits.
@@ -159,4 +172,11 @@ pub trait InternalRuntimeInterface {
         test_name: &str,
         ctx: &RuntimeContext,
     ) -> Result<BamlMap<String, BamlValue>>;
+
+    fn get_test_expectations(
+        &self,
+        function_name: &str,
+        test_name: &str,
+        ctx: &RuntimeContext,
+    ) -> Result<TestExpectations>;
 }
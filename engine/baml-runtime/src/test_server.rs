@@ -0,0 +1,73 @@
+//! A stand-in for an OpenAI-compatible provider, for tests that run whole
+//! function calls against an `openai-generic` client.
+
+use std::{
+    io::{Read, Write},
+    net::TcpListener,
+};
+
+/// Answers each request to a local port with the next of `responses`, and
+/// each response with the next of its parts, a little apart.
+pub(crate) fn serve(responses: Vec<Vec<String>>) -> u16 {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    std::thread::spawn(move || {
+        for (conn, parts) in listener.incoming().zip(responses) {
+            let mut conn = conn.unwrap();
+            read_request(&mut conn);
+            for part in parts {
+                conn.write_all(part.as_bytes()).unwrap();
+                conn.flush().unwrap();
+                std::thread::sleep(std::time::Duration::from_millis(50));
+            }
+        }
+    });
+    port
+}
+
+fn read_request(conn: &mut impl Read) {
+    let mut request = Vec::new();
+    let mut buf = [0; 4096];
+    loop {
+        let n = conn.read(&mut buf).unwrap();
+        request.extend_from_slice(&buf[..n]);
+        let text = String::from_utf8_lossy(&request);
+        if let Some(end) = text.find("\r\n\r\n") {
+            let length = text[..end]
+                .lines()
+                .find_map(|l| {
+                    l.to_lowercase()
+                        .strip_prefix("content-length:")
+                        .map(|v| v.trim().to_string())
+                })
+                .map_or(0, |v| v.parse::<usize>().unwrap());
+            if request.len() >= end + 4 + length {
+                return;
+            }
+        }
+    }
+}
+
+/// A whole HTTP response, closing the connection.
+pub(crate) fn http(status: &str, content_type: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+/// The body of a chat completion answering `content`.
+pub(crate) fn completion(content: &str) -> String {
+    serde_json::json!({
+        "id": "chatcmpl-1",
+        "object": "chat.completion",
+        "created": 0,
+        "model": "gpt-4o",
+        "choices": [{
+            "index": 0,
+            "message": { "role": "assistant", "content": content },
+            "finish_reason": "stop",
+        }],
+    })
+    .to_string()
+}
@@ -1,14 +1,12 @@
 use anyhow::Result;
-use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use super::core_types::{LLMOutputModel, LogSchema, Template, TestCaseStatus};
+use super::core_types::{LogSchema, TestCaseStatus};
 
 #[allow(dead_code)]
 pub(crate) trait BoundaryAPI {
-    async fn check_cache(&self, payload: &CacheRequest) -> Result<Option<CacheResponse>>;
     async fn log_schema(&self, payload: &LogSchema) -> Result<()>;
     async fn create_session(&self) -> Result<CreateSessionResponse>;
     async fn finish_session(&self) -> Result<()>;
@@ -46,20 +44,3 @@ pub(crate) struct UpdateTestCaseRequest {
     pub test_case: String,
     pub status: TestCaseStatus,
 }
-
-#[derive(Debug, Serialize)]
-#[allow(dead_code)]
-pub(crate) struct CacheRequest {
-    provider: String,
-    prompt: Template,
-    prompt_vars: HashMap<String, String>,
-    invovation_params: HashMap<String, Value>,
-}
-
-#[derive(Debug, Serialize)]
-#[allow(dead_code)]
-pub(crate) struct CacheResponse {
-    model_name: String,
-    llm_output: LLMOutputModel,
-    latency_ms: i32,
-}
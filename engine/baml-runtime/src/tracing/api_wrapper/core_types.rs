@@ -16,7 +16,7 @@ pub(crate) struct UpdateTestCase {
     pub error_data: Option<Value>, // Rust doesn't have a direct equivalent of Python's Any type, so we use serde_json::Value
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct LogSchema {
     pub project_id: Option<String>,
     pub event_type: EventType,
@@ -29,26 +29,26 @@ pub struct LogSchema {
     pub metadata: Option<MetadataType>,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct IO {
     pub(crate) input: Option<IOValue>,
     pub(crate) output: Option<IOValue>,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct IOValue {
     pub(crate) value: ValueType,
     pub(crate) r#override: Option<HashMap<String, Value>>,
     pub(crate) r#type: TypeSchema,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct TypeSchema {
     pub(crate) name: TypeSchemaName,
     pub(crate) fields: IndexMap<String, String>,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum TypeSchemaName {
     #[serde(rename = "single")]
     Single,
@@ -56,7 +56,7 @@ pub enum TypeSchemaName {
     Multi,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum ValueType {
     String(String),
@@ -85,7 +85,7 @@ pub enum TestCaseStatus {
     ExpectedFailure,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[allow(dead_code)]
 pub enum EventType {
     #[serde(rename = "log")]
@@ -98,7 +98,7 @@ pub enum EventType {
     FuncCode,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct LogSchemaContext {
     pub hostname: String,
     pub process_id: String,
@@ -109,13 +109,13 @@ pub struct LogSchemaContext {
     pub event_chain: Vec<EventChain>,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct EventChain {
     pub function_name: String,
     pub variant_name: Option<String>,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub(crate) struct Error {
     pub code: i32,
     pub message: String,
@@ -123,6 +123,12 @@ pub(crate) struct Error {
     pub r#override: Option<HashMap<String, Value>>,
 }
 
+impl Error {
+    /// The code of calls that were cancelled by their caller, rather than
+    /// failing.
+    pub const CANCELLED: i32 = 499;
+}
+
 #[derive(Serialize, Debug, Deserialize, Default, Clone)]
 pub struct LLMOutputModelMetadata {
     pub logprobs: Option<Value>,
@@ -132,20 +138,20 @@ pub struct LLMOutputModelMetadata {
     pub finish_reason: Option<String>,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct LLMOutputModel {
     pub raw_text: String,
     pub metadata: LLMOutputModelMetadata,
     pub r#override: Option<HashMap<String, Value>>,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub(crate) struct LLMChat {
     pub role: Role,
     pub content: Vec<ContentPart>,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum ContentPart {
     #[serde(rename = "text")]
     Text(String),
@@ -177,37 +183,47 @@ pub enum Role {
     Other(String),
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub(crate) struct LLMEventInput {
     pub prompt: LLMEventInputPrompt,
     #[serde(rename = "invocation_params")]
     pub request_options: HashMap<String, Value>,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct LLMEventSchema {
     pub model_name: String,
     pub provider: String,
     pub input: LLMEventInput,
     pub output: Option<LLMOutputModel>,
     pub error: Option<String>,
-}
-
-#[derive(Serialize, Debug, Clone)]
+    /// When the request was sent and how long it took. The Boundary API
+    /// doesn't take these; the OTLP exporter turns them into span times.
+    #[serde(skip)]
+    pub start_time: Option<web_time::SystemTime>,
+    #[serde(skip)]
+    pub latency: Option<web_time::Duration>,
+    /// The client's provider, e.g. `openai` (`provider` holds the client's
+    /// name). Only the OTLP exporter uses it.
+    #[serde(skip)]
+    pub client_provider: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(untagged)]
 pub(crate) enum MetadataType {
     #[allow(dead_code)]
     Single(LLMEventSchema),
     Multi(Vec<LLMEventSchema>),
 }
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct LLMEventInputPrompt {
     pub template: Template,
     pub template_args: HashMap<String, String>,
     pub r#override: Option<HashMap<String, Value>>,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(untagged)]
 #[allow(dead_code)]
 pub enum Template {
@@ -152,17 +152,6 @@ struct LogResponse {
 }
 
 impl BoundaryAPI for CompleteAPIConfig {
-    async fn check_cache(
-        &self,
-        _payload: &api_interface::CacheRequest,
-    ) -> Result<Option<api_interface::CacheResponse>> {
-        // TODO: @hellovai Implement this
-        Ok(None)
-        // let body = serde_json::to_value(payload)?;
-        // let response = self.post("cache", &body).await?;
-        // Ok(Some(serde_json::from_value(response)?))
-    }
-
     async fn log_schema(&self, payload: &core_types::LogSchema) -> Result<()> {
         let body = serde_json::to_value(payload)?;
         self.post::<LogResponse>("log/v2", &body).await?;
@@ -185,16 +174,6 @@ impl BoundaryAPI for CompleteAPIConfig {
 }
 
 impl BoundaryAPI for APIWrapper {
-    async fn check_cache(
-        &self,
-        payload: &api_interface::CacheRequest,
-    ) -> Result<Option<api_interface::CacheResponse>> {
-        match &self.config {
-            APIConfig::LocalOnly(_) => Ok(None),
-            APIConfig::Web(config) => config.check_cache(payload).await,
-        }
-    }
-
     async fn log_schema(&self, payload: &core_types::LogSchema) -> Result<()> {
         match &self.config {
             APIConfig::LocalOnly(_) => Ok(()),
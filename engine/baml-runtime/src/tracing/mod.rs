@@ -13,9 +13,15 @@ use std::sync::{Arc, Mutex};
 use uuid::Uuid;
 
 use crate::{
-    client_registry::ClientRegistry, internal::llm_client::LLMResponse,
-    tracing::api_wrapper::core_types::Role, type_builder::TypeBuilder, FunctionResult,
-    RuntimeContext, RuntimeContextManager, SpanCtx, TestResponse, TraceStats,
+    client_registry::ClientRegistry,
+    errors::ExposedError,
+    internal::llm_client::{
+        usage::{UsageCollector, UsageReport},
+        LLMResponse,
+    },
+    tracing::api_wrapper::core_types::Role,
+    type_builder::TypeBuilder,
+    FunctionResult, RuntimeContext, RuntimeContextManager, SpanCtx, TestResponse, TraceStats,
 };
 
 use self::api_wrapper::{
@@ -32,7 +38,9 @@ cfg_if! {
         mod wasm_tracer;
         use self::wasm_tracer::NonThreadedTracer as TracerImpl;
     } else {
+        mod otlp;
         mod threaded_tracer;
+        pub(crate) mod trace_files;
         use self::threaded_tracer::ThreadedTracer as TracerImpl;
     }
 }
@@ -48,6 +56,7 @@ pub struct BamlTracer {
     options: APIWrapper,
     tracer: Option<TracerImpl>,
     trace_stats: TraceStats,
+    usage: UsageCollector,
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -149,21 +158,45 @@ impl BamlTracer {
         options: Option<APIWrapper>,
         env_vars: impl Iterator<Item = (T, T)>,
     ) -> Result<Self> {
+        let env_vars = env_vars
+            .map(|(k, v)| (k.as_ref().to_string(), v.as_ref().to_string()))
+            .collect::<Vec<_>>();
         let options = match options {
             Some(wrapper) => wrapper,
-            None => APIWrapper::from_env_vars(env_vars)?,
+            None => APIWrapper::from_env_vars(env_vars.iter().map(|(k, v)| (k, v)))?,
         };
 
         let trace_stats = TraceStats::default();
 
-        let tracer = BamlTracer {
-            tracer: if options.enabled() {
-                Some(TracerImpl::new(&options, 20, trace_stats.clone()))
+        cfg_if! {
+            if #[cfg(target_arch = "wasm32")] {
+                let tracer = options
+                    .enabled()
+                    .then(|| TracerImpl::new(&options, 20, trace_stats.clone()));
             } else {
-                None
-            },
+                // Traces go to the Boundary API, an OTLP collector, local
+                // files, or any combination of them
+                // A bad telemetry setting shouldn't stop functions from running
+                let otlp = otlp::OtlpExporter::from_env_vars(
+                    env_vars.iter().map(|(k, v)| (k, v)),
+                    &options,
+                )
+                .unwrap_or_else(|e| {
+                    log::warn!("Not exporting BAML traces over OTLP: {e:#}");
+                    None
+                });
+                let files =
+                    trace_files::TraceFiles::from_env_vars(env_vars.iter().map(|(k, v)| (k, v)))?;
+                let tracer = (options.enabled() || otlp.is_some() || files.is_some())
+                    .then(|| TracerImpl::new(&options, otlp, files, 20, trace_stats.clone()));
+            }
+        }
+
+        let tracer = BamlTracer {
+            tracer,
             options,
             trace_stats,
+            usage: Default::default(),
         };
         Ok(tracer)
     }
@@ -187,6 +220,14 @@ impl BamlTracer {
         self.trace_stats.drain()
     }
 
+    pub(crate) fn usage(&self) -> UsageReport {
+        self.usage.report()
+    }
+
+    pub(crate) fn reset_usage(&self) -> UsageReport {
+        self.usage.reset()
+    }
+
     pub(crate) fn start_span(
         &self,
         function_name: &str,
@@ -291,6 +332,7 @@ impl BamlTracer {
         }
 
         if let Ok(response) = &response {
+            self.usage.record(&event_chain, response);
             let name = event_chain.last().map(|s| s.name.as_str());
             let is_ok = response.result_with_constraints().as_ref().is_some_and(|r| r.is_ok());
             log::log!(
@@ -338,6 +380,7 @@ impl BamlTracer {
         }
 
         if let Ok(response) = &response {
+            self.usage.record(&event_chain, response);
             let name = event_chain.last().map(|s| s.name.as_str());
             let is_ok = response.result_with_constraints().as_ref().is_some_and(|r| r.is_ok());
             log::log!(
@@ -526,7 +569,10 @@ impl<T: ToLogSchema> ToLogSchema for Result<T> {
                     output: None,
                 },
                 error: Some(api_wrapper::core_types::Error {
-                    code: 2,
+                    code: match e.downcast_ref::<ExposedError>() {
+                        Some(ExposedError::Cancelled) => api_wrapper::core_types::Error::CANCELLED,
+                        _ => 2,
+                    },
                     message: e.to_string(),
                     traceback: None,
                     r#override: None,
@@ -628,7 +674,10 @@ impl From<&FunctionResult> for MetadataType {
             result
                 .event_chain()
                 .iter()
-                .map(|(_, r, _, _)| r.into())
+                .map(|(scope, r, _, _)| LLMEventSchema {
+                    client_provider: scope.provider.clone(),
+                    ..r.into()
+                })
                 .collect::<Vec<_>>(),
         )
     }
@@ -650,6 +699,9 @@ impl From<&LLMResponse> for LLMEventSchema {
                 },
                 output: None,
                 error: Some(s.clone()),
+                start_time: None,
+                latency: None,
+                client_provider: None,
             },
             LLMResponse::InternalFailure(s) => LLMEventSchema {
                 model_name: "<unknown>".into(),
@@ -664,6 +716,9 @@ impl From<&LLMResponse> for LLMEventSchema {
                 },
                 output: None,
                 error: Some(s.clone()),
+                start_time: None,
+                latency: None,
+                client_provider: None,
             },
             LLMResponse::Success(s) => LLMEventSchema {
                 model_name: s.model.clone(),
@@ -684,6 +739,9 @@ impl From<&LLMResponse> for LLMEventSchema {
                     r#override: None,
                 }),
                 error: None,
+                start_time: Some(s.start_time),
+                latency: Some(s.latency),
+                client_provider: None,
             },
             LLMResponse::LLMFailure(s) => LLMEventSchema {
                 model_name: s
@@ -702,6 +760,9 @@ impl From<&LLMResponse> for LLMEventSchema {
                 },
                 output: None,
                 error: Some(s.message.clone()),
+                start_time: Some(s.start_time),
+                latency: Some(s.latency),
+                client_provider: None,
             },
         }
     }
@@ -0,0 +1,687 @@
+//! Exports BAML traces to an OpenTelemetry collector over OTLP.
+//!
+//! The exporter is configured with the standard `OTEL_EXPORTER_OTLP_*`
+//! environment variables and runs next to the Boundary API: every
+//! [`LogSchema`] the tracer submits becomes a span, and every LLM request made
+//! by a BAML function becomes a child span following the GenAI semantic
+//! conventions. Function inputs and outputs, prompts and completions are only
+//! attached to the spans when `OTEL_INSTRUMENTATION_GENAI_CAPTURE_MESSAGE_CONTENT`
+//! is `true`.
+
+use anyhow::{Context, Result};
+use opentelemetry_proto::tonic::{
+    collector::trace::v1::{trace_service_client::TraceServiceClient, ExportTraceServiceRequest},
+    common::v1::{any_value, AnyValue, ArrayValue, InstrumentationScope, KeyValue},
+    resource::v1::Resource,
+    trace::v1::{
+        span::{Event, SpanKind},
+        status::StatusCode,
+        ResourceSpans, ScopeSpans, Span, Status,
+    },
+};
+use prost::Message;
+use serde::Deserialize;
+use tonic::transport::{Channel, Endpoint};
+use uuid::Uuid;
+
+use super::api_wrapper::{
+    core_types::{LLMEventSchema, LogSchema, MetadataType, Template},
+    APIWrapper,
+};
+
+#[derive(Deserialize, Debug, Default)]
+struct Config {
+    exporter_otlp_endpoint: Option<String>,
+    exporter_otlp_traces_endpoint: Option<String>,
+    exporter_otlp_protocol: Option<String>,
+    exporter_otlp_traces_protocol: Option<String>,
+    exporter_otlp_headers: Option<String>,
+    exporter_otlp_traces_headers: Option<String>,
+    service_name: Option<String>,
+    instrumentation_genai_capture_message_content: Option<String>,
+}
+
+impl Config {
+    /// Where to send traces, following the OTLP exporter spec: a
+    /// traces-specific endpoint is used as is, while the generic one gets
+    /// `/v1/traces` appended when exporting over HTTP.
+    fn url(&self, protocol: Protocol) -> Option<String> {
+        if let Some(endpoint) = &self.exporter_otlp_traces_endpoint {
+            return Some(endpoint.clone());
+        }
+        let endpoint = self.exporter_otlp_endpoint.as_ref()?;
+        Some(match protocol {
+            Protocol::HttpProtobuf => format!("{}/v1/traces", endpoint.trim_end_matches('/')),
+            Protocol::Grpc => endpoint.clone(),
+        })
+    }
+
+    fn protocol(&self) -> Result<Protocol> {
+        match self
+            .exporter_otlp_traces_protocol
+            .as_deref()
+            .or(self.exporter_otlp_protocol.as_deref())
+        {
+            None | Some("http/protobuf") => Ok(Protocol::HttpProtobuf),
+            Some("grpc") => Ok(Protocol::Grpc),
+            Some(other) => anyhow::bail!(
+                "Unsupported OTLP protocol {other:?}: expected \"http/protobuf\" or \"grpc\""
+            ),
+        }
+    }
+
+    /// Whether to export what functions and LLMs were given and answered.
+    /// Off unless set to `true` (or `1`).
+    fn capture_content(&self) -> bool {
+        self.instrumentation_genai_capture_message_content
+            .as_deref()
+            .map_or(false, |value| {
+                value.trim().eq_ignore_ascii_case("true") || value.trim() == "1"
+            })
+    }
+
+    /// Parses `key1=value1,key2=value2`.
+    fn headers(&self) -> Result<http::HeaderMap> {
+        let mut headers = http::HeaderMap::new();
+        let specs = [
+            &self.exporter_otlp_headers,
+            &self.exporter_otlp_traces_headers,
+        ];
+        for spec in specs.into_iter().flatten() {
+            for pair in spec.split(',').filter(|pair| !pair.trim().is_empty()) {
+                let Some((name, value)) = pair.split_once('=') else {
+                    anyhow::bail!("Invalid OTLP header {pair:?}: expected key=value");
+                };
+                headers.insert(
+                    http::HeaderName::try_from(name.trim())
+                        .with_context(|| format!("Invalid OTLP header name {name:?}"))?,
+                    http::HeaderValue::try_from(value.trim())
+                        .with_context(|| format!("Invalid OTLP header value for {name:?}"))?,
+                );
+            }
+        }
+        Ok(headers)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Protocol {
+    HttpProtobuf,
+    Grpc,
+}
+
+enum Transport {
+    Http {
+        client: reqwest::Client,
+        url: String,
+    },
+    Grpc(Box<GrpcTransport>),
+}
+
+struct GrpcTransport {
+    endpoint: Endpoint,
+    // Connected on first export, since that happens on the delivery thread's runtime
+    client: tokio::sync::OnceCell<TraceServiceClient<Channel>>,
+}
+
+pub(super) struct OtlpExporter {
+    transport: Transport,
+    headers: http::HeaderMap,
+    resource: Resource,
+    capture_content: bool,
+}
+
+impl OtlpExporter {
+    /// Returns `None` unless an OTLP endpoint is configured.
+    pub fn from_env_vars<T: AsRef<str>>(
+        env_vars: impl Iterator<Item = (T, T)>,
+        api: &APIWrapper,
+    ) -> Result<Option<Self>> {
+        let config: Config = envy::prefixed("OTEL_")
+            .from_iter(env_vars.map(|(k, v)| (k.as_ref().to_string(), v.as_ref().to_string())))
+            .context("Failed to parse OTLP config from environment variables")?;
+
+        let protocol = config.protocol()?;
+        let Some(url) = config.url(protocol) else {
+            return Ok(None);
+        };
+        log::info!("Exporting BAML traces over OTLP to {url}");
+
+        let transport = match protocol {
+            Protocol::HttpProtobuf => Transport::Http {
+                client: crate::request::create_tracing_client()?,
+                url,
+            },
+            Protocol::Grpc => Transport::Grpc(Box::new(GrpcTransport {
+                endpoint: Endpoint::from_shared(url.clone())
+                    .with_context(|| format!("Invalid OTLP endpoint {url:?}"))?,
+                client: tokio::sync::OnceCell::new(),
+            })),
+        };
+
+        Ok(Some(Self {
+            transport,
+            headers: config.headers()?,
+            resource: Resource {
+                attributes: vec![
+                    string(
+                        "service.name",
+                        config.service_name.as_deref().unwrap_or("baml"),
+                    ),
+                    string("host.name", api.host_name()),
+                    string("deployment.environment", api.stage()),
+                    string("baml.session_id", api.session_id()),
+                ],
+                dropped_attributes_count: 0,
+            },
+            capture_content: config.capture_content(),
+        }))
+    }
+
+    pub async fn export(&self, batch: &[LogSchema]) -> Result<()> {
+        let request = ExportTraceServiceRequest {
+            resource_spans: vec![ResourceSpans {
+                resource: Some(self.resource.clone()),
+                scope_spans: vec![ScopeSpans {
+                    scope: Some(InstrumentationScope {
+                        name: "baml".to_string(),
+                        version: env!("CARGO_PKG_VERSION").to_string(),
+                        ..Default::default()
+                    }),
+                    spans: batch
+                        .iter()
+                        .flat_map(|event| spans(event, self.capture_content))
+                        .collect(),
+                    schema_url: String::new(),
+                }],
+                schema_url: String::new(),
+            }],
+        };
+
+        match &self.transport {
+            Transport::Http { client, url } => {
+                let response = client
+                    .post(url)
+                    .headers(self.headers.clone())
+                    .header(http::header::CONTENT_TYPE, "application/x-protobuf")
+                    .body(request.encode_to_vec())
+                    .send()
+                    .await
+                    .with_context(|| format!("Failed to send traces to {url}"))?;
+                let status = response.status();
+                if !status.is_success() {
+                    let body = response.text().await.unwrap_or_default();
+                    anyhow::bail!("OTLP collector at {url} returned {status}: {body}");
+                }
+            }
+            Transport::Grpc(grpc) => {
+                let GrpcTransport { endpoint, client } = grpc.as_ref();
+                let mut client = client
+                    .get_or_try_init(|| async {
+                        endpoint.connect().await.map(TraceServiceClient::new)
+                    })
+                    .await
+                    .with_context(|| format!("Failed to connect to {}", endpoint.uri()))?
+                    .clone();
+                let mut request = tonic::Request::new(request);
+                *request.metadata_mut() =
+                    tonic::metadata::MetadataMap::from_headers(self.headers.clone());
+                client
+                    .export(request)
+                    .await
+                    .with_context(|| format!("Failed to send traces to {}", endpoint.uri()))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The span for one BAML event, followed by one span per LLM request it made.
+/// Inputs and outputs are only included with `capture_content`.
+fn spans(event: &LogSchema, capture_content: bool) -> Vec<Span> {
+    let trace_id = Uuid::parse_str(&event.root_event_id)
+        .unwrap_or_else(|_| Uuid::new_v4())
+        .as_bytes()
+        .to_vec();
+    let span_id = span_id(&event.event_id);
+    let start = chrono::DateTime::parse_from_rfc3339(&event.context.start_time)
+        .ok()
+        .and_then(|time| time.timestamp_nanos_opt())
+        .unwrap_or_default() as u64;
+    let end = start + event.context.latency_ms.max(0) as u64 * 1_000_000;
+
+    let mut attributes = vec![
+        string(
+            "baml.function.name",
+            event
+                .context
+                .event_chain
+                .last()
+                .map_or("", |chain| chain.function_name.as_str()),
+        ),
+        string(
+            "baml.event_type",
+            serde_json::to_value(&event.event_type)
+                .ok()
+                .and_then(|value| value.as_str().map(str::to_string))
+                .unwrap_or_default(),
+        ),
+    ];
+    let io = [
+        ("baml.input", &event.io.input),
+        ("baml.output", &event.io.output),
+    ];
+    for (key, value) in io.into_iter().filter(|_| capture_content) {
+        if let Some(value) = value {
+            attributes.push(string(
+                key,
+                serde_json::to_string(&value.value).unwrap_or_default(),
+            ));
+        }
+    }
+    let mut tags = event.context.tags.iter().collect::<Vec<_>>();
+    tags.sort();
+    attributes.extend(
+        tags.into_iter()
+            .map(|(key, value)| string(key, value.as_str())),
+    );
+
+    let mut spans = vec![Span {
+        trace_id: trace_id.clone(),
+        span_id: span_id.clone(),
+        parent_span_id: event
+            .parent_event_id
+            .as_deref()
+            .map(self::span_id)
+            .unwrap_or_default(),
+        name: event
+            .context
+            .event_chain
+            .last()
+            .map_or_else(|| "baml".to_string(), |chain| chain.function_name.clone()),
+        kind: SpanKind::Internal as i32,
+        start_time_unix_nano: start,
+        end_time_unix_nano: end,
+        attributes,
+        status: event
+            .error
+            .as_ref()
+            .map(|error| error_status(&error.message)),
+        ..Default::default()
+    }];
+
+    let requests = match &event.metadata {
+        Some(MetadataType::Single(request)) => std::slice::from_ref(request),
+        Some(MetadataType::Multi(requests)) => requests.as_slice(),
+        None => &[],
+    };
+    for (attempt, request) in requests.iter().enumerate() {
+        let mut span = llm_span(request, attempt, start, end, capture_content);
+        span.trace_id = trace_id.clone();
+        span.parent_span_id = span_id.clone();
+        spans.push(span);
+    }
+    spans
+}
+
+/// A span for one LLM request, named and annotated per the GenAI semantic
+/// conventions. Requests that didn't record their own timing inherit the
+/// function's. The prompt and completion are added as events only when
+/// `capture_content` is set, since they may hold sensitive data.
+fn llm_span(
+    request: &LLMEventSchema,
+    attempt: usize,
+    start: u64,
+    end: u64,
+    capture_content: bool,
+) -> Span {
+    let nanos = |time: web_time::SystemTime| {
+        time.duration_since(web_time::UNIX_EPOCH)
+            .map_or(0, |since| since.as_nanos() as u64)
+    };
+    let (start, end) = match (request.start_time, request.latency) {
+        (Some(time), Some(latency)) => (nanos(time), nanos(time) + latency.as_nanos() as u64),
+        _ => (start, end),
+    };
+    let operation = match request.input.prompt.template {
+        Template::Single(_) => "text_completion",
+        Template::Multiple(_) => "chat",
+    };
+    let options = &request.input.request_options;
+    let request_model = options
+        .get("model")
+        .and_then(|model| model.as_str())
+        .unwrap_or(&request.model_name);
+
+    let mut attributes = vec![
+        string("gen_ai.operation.name", operation),
+        string("gen_ai.request.model", request_model),
+        string("baml.client", request.provider.as_str()),
+        int("baml.attempt", attempt as i64),
+    ];
+    if let Some(provider) = &request.client_provider {
+        attributes.push(string("gen_ai.system", gen_ai_system(provider)));
+    }
+    if request.model_name != "<unknown>" {
+        attributes.push(string("gen_ai.response.model", request.model_name.as_str()));
+    }
+    for (option, key) in [
+        ("temperature", "gen_ai.request.temperature"),
+        ("top_p", "gen_ai.request.top_p"),
+    ] {
+        if let Some(value) = options.get(option).and_then(|value| value.as_f64()) {
+            attributes.push(key_value(key, any_value::Value::DoubleValue(value)));
+        }
+    }
+    if let Some(max_tokens) = options.get("max_tokens").and_then(|value| value.as_i64()) {
+        attributes.push(int("gen_ai.request.max_tokens", max_tokens));
+    }
+
+    let mut events = vec![];
+    if capture_content {
+        events.push(Event {
+            time_unix_nano: start,
+            name: "gen_ai.content.prompt".to_string(),
+            attributes: vec![string(
+                "gen_ai.prompt",
+                serde_json::to_string(&request.input.prompt.template).unwrap_or_default(),
+            )],
+            dropped_attributes_count: 0,
+        });
+    }
+    if let Some(output) = &request.output {
+        let metadata = &output.metadata;
+        if let Some(tokens) = metadata.prompt_tokens {
+            attributes.push(int("gen_ai.usage.input_tokens", tokens));
+        }
+        if let Some(tokens) = metadata.output_tokens {
+            attributes.push(int("gen_ai.usage.output_tokens", tokens));
+        }
+        if let Some(reason) = &metadata.finish_reason {
+            attributes.push(key_value(
+                "gen_ai.response.finish_reasons",
+                any_value::Value::ArrayValue(ArrayValue {
+                    values: vec![AnyValue {
+                        value: Some(any_value::Value::StringValue(reason.clone())),
+                    }],
+                }),
+            ));
+        }
+        if capture_content {
+            events.push(Event {
+                time_unix_nano: end,
+                name: "gen_ai.content.completion".to_string(),
+                attributes: vec![string("gen_ai.completion", output.raw_text.as_str())],
+                dropped_attributes_count: 0,
+            });
+        }
+    }
+
+    Span {
+        span_id: fastrand::u64(..).to_be_bytes().to_vec(),
+        name: format!("{operation} {request_model}"),
+        kind: SpanKind::Client as i32,
+        start_time_unix_nano: start,
+        end_time_unix_nano: end,
+        attributes,
+        events,
+        status: request.error.as_deref().map(error_status),
+        ..Default::default()
+    }
+}
+
+/// The `gen_ai.system` value for a client's provider. Providers the
+/// conventions don't name are reported as is.
+fn gen_ai_system(provider: &str) -> &str {
+    match provider {
+        "openai" | "baml-openai-chat" => "openai",
+        "azure-openai" | "baml-azure-chat" => "az.ai.openai",
+        "anthropic" | "baml-anthropic-chat" => "anthropic",
+        "ollama" | "baml-ollama-chat" => "ollama",
+        "google-ai" => "gcp.gemini",
+        "vertex-ai" => "gcp.vertex_ai",
+        "aws-bedrock" => "aws.bedrock",
+        other => other,
+    }
+}
+
+/// OTel span ids are 8 bytes, so fold the event's UUID in half.
+fn span_id(event_id: &str) -> Vec<u8> {
+    let (high, low) = Uuid::parse_str(event_id)
+        .unwrap_or_else(|_| Uuid::new_v4())
+        .as_u64_pair();
+    (high ^ low).to_be_bytes().to_vec()
+}
+
+fn error_status(message: &str) -> Status {
+    Status {
+        message: message.to_string(),
+        code: StatusCode::Error as i32,
+    }
+}
+
+fn key_value(key: &str, value: any_value::Value) -> KeyValue {
+    KeyValue {
+        key: key.to_string(),
+        value: Some(AnyValue { value: Some(value) }),
+    }
+}
+
+fn string(key: &str, value: impl Into<String>) -> KeyValue {
+    key_value(key, any_value::Value::StringValue(value.into()))
+}
+
+fn int(key: &str, value: i64) -> KeyValue {
+    key_value(key, any_value::Value::IntValue(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::tracing::api_wrapper::core_types::{
+        EventChain, EventType, IOValue, LLMEventInput, LLMEventInputPrompt, LLMOutputModel,
+        LLMOutputModelMetadata, LogSchemaContext, TypeSchema, TypeSchemaName, ValueType, IO,
+    };
+
+    fn config(vars: &[(&str, &str)]) -> Config {
+        envy::prefixed("OTEL_")
+            .from_iter(vars.iter().map(|(k, v)| (k.to_string(), v.to_string())))
+            .unwrap()
+    }
+
+    #[test]
+    fn resolves_endpoints_per_protocol() {
+        let base = config(&[("OTEL_EXPORTER_OTLP_ENDPOINT", "http://localhost:4318/")]);
+        assert_eq!(base.protocol().unwrap(), Protocol::HttpProtobuf);
+        assert_eq!(
+            base.url(Protocol::HttpProtobuf).as_deref(),
+            Some("http://localhost:4318/v1/traces")
+        );
+
+        let grpc = config(&[
+            ("OTEL_EXPORTER_OTLP_ENDPOINT", "http://localhost:4317"),
+            ("OTEL_EXPORTER_OTLP_TRACES_PROTOCOL", "grpc"),
+        ]);
+        assert_eq!(grpc.protocol().unwrap(), Protocol::Grpc);
+        assert_eq!(
+            grpc.url(Protocol::Grpc).as_deref(),
+            Some("http://localhost:4317")
+        );
+
+        let traces = config(&[
+            ("OTEL_EXPORTER_OTLP_ENDPOINT", "http://localhost:4318"),
+            (
+                "OTEL_EXPORTER_OTLP_TRACES_ENDPOINT",
+                "http://collector/traces",
+            ),
+        ]);
+        assert_eq!(
+            traces.url(Protocol::HttpProtobuf).as_deref(),
+            Some("http://collector/traces")
+        );
+
+        assert_eq!(config(&[]).url(Protocol::HttpProtobuf), None);
+        assert!(config(&[("OTEL_EXPORTER_OTLP_PROTOCOL", "http/json")])
+            .protocol()
+            .is_err());
+    }
+
+    #[test]
+    fn parses_headers() {
+        let headers = config(&[("OTEL_EXPORTER_OTLP_HEADERS", "api-key=secret, x-team = ml")])
+            .headers()
+            .unwrap();
+        assert_eq!(headers["api-key"], "secret");
+        assert_eq!(headers["x-team"], "ml");
+
+        assert!(config(&[("OTEL_EXPORTER_OTLP_HEADERS", "api-key")])
+            .headers()
+            .is_err());
+    }
+
+    #[test]
+    fn message_content_is_opt_in() {
+        assert!(!config(&[]).capture_content());
+        for (value, capture) in [("true", true), ("TRUE", true), ("1", true), ("no", false)] {
+            assert_eq!(
+                config(&[("OTEL_INSTRUMENTATION_GENAI_CAPTURE_MESSAGE_CONTENT", value)])
+                    .capture_content(),
+                capture,
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn bad_config_doesnt_stop_the_runtime() {
+        let files = HashMap::from([("main.baml", "class Resume {\n  name string\n}\n")]);
+        let env_vars = HashMap::from([
+            ("OTEL_EXPORTER_OTLP_ENDPOINT", "http://localhost:4318"),
+            ("OTEL_EXPORTER_OTLP_PROTOCOL", "http/json"),
+        ]);
+        assert!(crate::BamlRuntime::from_file_content(".", &files, env_vars).is_ok());
+    }
+
+    fn attribute<'a>(attributes: &'a [KeyValue], key: &str) -> Option<&'a any_value::Value> {
+        attributes
+            .iter()
+            .find(|attribute| attribute.key == key)
+            .and_then(|attribute| attribute.value.as_ref()?.value.as_ref())
+    }
+
+    #[test]
+    fn maps_function_calls_and_llm_requests_to_spans() {
+        let root = Uuid::new_v4();
+        let parent = Uuid::new_v4();
+        let event = Uuid::new_v4();
+        let request = LLMEventSchema {
+            model_name: "gpt-4o-2024-08-06".to_string(),
+            provider: "GPT4o".to_string(),
+            input: LLMEventInput {
+                prompt: LLMEventInputPrompt {
+                    template: Template::Single("Extract the resume".to_string()),
+                    template_args: HashMap::new(),
+                    r#override: None,
+                },
+                request_options: [("model".to_string(), serde_json::json!("gpt-4o"))].into(),
+            },
+            output: Some(LLMOutputModel {
+                raw_text: "{}".to_string(),
+                metadata: LLMOutputModelMetadata {
+                    prompt_tokens: Some(12),
+                    output_tokens: Some(3),
+                    finish_reason: Some("stop".to_string()),
+                    ..Default::default()
+                },
+                r#override: None,
+            }),
+            error: None,
+            start_time: None,
+            latency: None,
+            client_provider: Some("baml-openai-chat".to_string()),
+        };
+        let schema = LogSchema {
+            project_id: None,
+            event_type: EventType::FuncLlm,
+            root_event_id: root.to_string(),
+            event_id: event.to_string(),
+            parent_event_id: Some(parent.to_string()),
+            context: LogSchemaContext {
+                hostname: "host".to_string(),
+                process_id: "session".to_string(),
+                stage: None,
+                latency_ms: 250,
+                start_time: "2024-10-01T12:00:00.000Z".to_string(),
+                tags: [("user".to_string(), "alice".to_string())].into(),
+                event_chain: vec![EventChain {
+                    function_name: "ExtractResume".to_string(),
+                    variant_name: None,
+                }],
+            },
+            io: IO {
+                input: Some(IOValue {
+                    value: ValueType::String("Jane Doe, engineer".to_string()),
+                    r#override: None,
+                    r#type: TypeSchema {
+                        name: TypeSchemaName::Single,
+                        fields: Default::default(),
+                    },
+                }),
+                output: None,
+            },
+            error: None,
+            metadata: Some(MetadataType::Multi(vec![request])),
+        };
+
+        let spans = super::spans(&schema, true);
+        assert_eq!(spans.len(), 2);
+        let (function, llm) = (&spans[0], &spans[1]);
+
+        assert_eq!(function.name, "ExtractResume");
+        assert_eq!(function.trace_id, root.as_bytes().to_vec());
+        assert_eq!(function.span_id, span_id(&event.to_string()));
+        assert_eq!(function.parent_span_id, span_id(&parent.to_string()));
+        assert_eq!(
+            function.end_time_unix_nano - function.start_time_unix_nano,
+            250_000_000
+        );
+        assert_eq!(
+            attribute(&function.attributes, "user"),
+            Some(&any_value::Value::StringValue("alice".to_string()))
+        );
+        assert!(function.status.is_none());
+        assert!(attribute(&function.attributes, "baml.input").is_some());
+
+        assert_eq!(llm.name, "text_completion gpt-4o");
+        assert_eq!(llm.kind, SpanKind::Client as i32);
+        assert_eq!(llm.trace_id, function.trace_id);
+        assert_eq!(llm.parent_span_id, function.span_id);
+        assert_eq!(
+            attribute(&llm.attributes, "gen_ai.system"),
+            Some(&any_value::Value::StringValue("openai".to_string()))
+        );
+        assert_eq!(
+            attribute(&llm.attributes, "gen_ai.response.model"),
+            Some(&any_value::Value::StringValue(
+                "gpt-4o-2024-08-06".to_string()
+            ))
+        );
+        assert_eq!(
+            attribute(&llm.attributes, "gen_ai.usage.input_tokens"),
+            Some(&any_value::Value::IntValue(12))
+        );
+        assert_eq!(
+            attribute(&llm.attributes, "gen_ai.usage.output_tokens"),
+            Some(&any_value::Value::IntValue(3))
+        );
+        assert_eq!(llm.events.len(), 2);
+
+        // Without the opt-in, what the function and the LLM were given and
+        // answered is left out.
+        let spans = super::spans(&schema, false);
+        assert!(attribute(&spans[0].attributes, "baml.input").is_none());
+        assert!(spans[1].events.is_empty());
+    }
+}
@@ -9,7 +9,11 @@ use crate::{
     TraceStats,
 };
 
-use super::api_wrapper::{core_types::LogSchema, APIConfig, APIWrapper, BoundaryAPI};
+use super::{
+    api_wrapper::{core_types::LogSchema, APIConfig, APIWrapper, BoundaryAPI},
+    otlp::OtlpExporter,
+    trace_files::TraceFiles,
+};
 
 const MAX_TRACE_SEND_CONCURRENCY: usize = 10;
 
@@ -27,6 +31,8 @@ enum ProcessorStatus {
 
 struct DeliveryThread {
     api_config: Arc<APIWrapper>,
+    otlp: Option<OtlpExporter>,
+    files: Option<TraceFiles>,
     span_rx: mpsc::Receiver<TxEventSignal>,
     stop_tx: watch::Sender<ProcessorStatus>,
     rt: tokio::runtime::Runtime,
@@ -38,6 +44,8 @@ struct DeliveryThread {
 impl DeliveryThread {
     fn new(
         api_config: APIWrapper,
+        otlp: Option<OtlpExporter>,
+        files: Option<TraceFiles>,
         span_rx: mpsc::Receiver<TxEventSignal>,
         stop_tx: watch::Sender<ProcessorStatus>,
         max_batch_size: usize,
@@ -47,6 +55,8 @@ impl DeliveryThread {
 
         Self {
             api_config: Arc::new(api_config),
+            otlp,
+            files,
             span_rx,
             stop_tx,
             rt,
@@ -57,6 +67,17 @@ impl DeliveryThread {
     }
 
     async fn process_batch(&self, batch: Vec<LogSchema>) {
+        if let Some(files) = &self.files {
+            if let Err(e) = files.write(&batch) {
+                log::warn!("Unable to write BAML traces to disk: {:#}", e);
+            }
+        }
+        if let Some(otlp) = &self.otlp {
+            if let Err(e) = otlp.export(&batch).await {
+                log::warn!("Unable to export BAML traces over OTLP: {:#}", e);
+            }
+        }
+
         let work = batch
             .into_iter()
             .map(|work| {
@@ -156,6 +177,8 @@ pub(super) struct ThreadedTracer {
 impl ThreadedTracer {
     fn start_worker(
         api_config: APIWrapper,
+        otlp: Option<OtlpExporter>,
+        files: Option<TraceFiles>,
         max_batch_size: usize,
         stats: TraceStats,
     ) -> (
@@ -166,15 +189,35 @@ impl ThreadedTracer {
         let (span_tx, span_rx) = mpsc::channel();
         let (stop_tx, stop_rx) = watch::channel(ProcessorStatus::Active);
         let join_handle = std::thread::spawn(move || {
-            DeliveryThread::new(api_config, span_rx, stop_tx, max_batch_size, stats).run();
+            DeliveryThread::new(
+                api_config,
+                otlp,
+                files,
+                span_rx,
+                stop_tx,
+                max_batch_size,
+                stats,
+            )
+            .run();
         });
 
         (span_tx, stop_rx, join_handle)
     }
 
-    pub fn new(api_config: &APIWrapper, max_batch_size: usize, stats: TraceStats) -> Self {
-        let (span_tx, stop_rx, join_handle) =
-            Self::start_worker(api_config.clone(), max_batch_size, stats.clone());
+    pub fn new(
+        api_config: &APIWrapper,
+        otlp: Option<OtlpExporter>,
+        files: Option<TraceFiles>,
+        max_batch_size: usize,
+        stats: TraceStats,
+    ) -> Self {
+        let (span_tx, stop_rx, join_handle) = Self::start_worker(
+            api_config.clone(),
+            otlp,
+            files,
+            max_batch_size,
+            stats.clone(),
+        );
 
         Self {
             api_config: Arc::new(api_config.clone()),
@@ -0,0 +1,468 @@
+//! Writes finished trace events to rotating JSONL files on local disk, for
+//! deployments that can't send them to the Boundary API, and reads them back
+//! for `baml-cli traces`.
+
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+};
+
+use anyhow::{Context, Result};
+use baml_types::{BamlMedia, BamlMediaType};
+use internal_baml_jinja::{ChatMessagePart, RenderedChatMessage, RenderedPrompt};
+use serde::{Deserialize, Serialize};
+
+use crate::internal::llm_client::{
+    ErrorCode, LLMCompleteResponse, LLMCompleteResponseMetadata, LLMErrorResponse, LLMResponse,
+};
+
+use super::api_wrapper::core_types::{
+    ContentPart, LLMEventSchema, LogSchema, MetadataType, Role, Template,
+};
+
+pub(crate) const DEFAULT_DIR: &str = ".baml_traces";
+const DEFAULT_MAX_FILE_BYTES: u64 = 10 * 1024 * 1024;
+const DEFAULT_MAX_FILES: usize = 10;
+
+#[derive(Deserialize, Debug, Default)]
+struct Config {
+    dir: Option<PathBuf>,
+    max_file_bytes: Option<u64>,
+    max_files: Option<usize>,
+}
+
+/// One line of a trace file. The event isn't flattened into the record
+/// because serde can't buffer its `i128` latency when flattening.
+#[derive(Serialize, Deserialize)]
+struct TraceRecord {
+    event: LogSchema,
+    /// When each LLM request in `event.metadata` started and how long it
+    /// took, which the event itself doesn't serialize.
+    #[serde(default)]
+    llm_timings: Vec<LLMTiming>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct LLMTiming {
+    start_time_ms: Option<u64>,
+    latency_ms: Option<u64>,
+}
+
+struct OpenFile {
+    file: std::fs::File,
+    size: u64,
+}
+
+/// Appends events to `<dir>/traces-<timestamp>-<pid>-<n>.jsonl`, starting a new
+/// file once the current one reaches `max_file_bytes` and deleting the oldest
+/// files beyond `max_files`. Only files written by this process are deleted,
+/// since other processes may still be writing theirs.
+pub(crate) struct TraceFiles {
+    dir: PathBuf,
+    max_file_bytes: u64,
+    max_files: usize,
+    current: Mutex<Option<OpenFile>>,
+    opened: AtomicUsize,
+}
+
+impl TraceFiles {
+    /// Reads `BAML_TRACE_DIR`, which turns the sink on, and optionally
+    /// `BAML_TRACE_MAX_FILE_BYTES` and `BAML_TRACE_MAX_FILES`.
+    pub fn from_env_vars<T: AsRef<str>>(
+        env_vars: impl Iterator<Item = (T, T)>,
+    ) -> Result<Option<Self>> {
+        let config: Config = envy::prefixed("BAML_TRACE_")
+            .from_iter(env_vars.map(|(k, v)| (k.as_ref().to_string(), v.as_ref().to_string())))
+            .context("Failed to parse BAML_TRACE_* environment variables")?;
+        let Some(dir) = config.dir.filter(|dir| !dir.as_os_str().is_empty()) else {
+            return Ok(None);
+        };
+        log::info!("Writing BAML traces to {}", dir.display());
+        Ok(Some(Self::new(
+            dir,
+            config.max_file_bytes.unwrap_or(DEFAULT_MAX_FILE_BYTES),
+            config.max_files.unwrap_or(DEFAULT_MAX_FILES),
+        )))
+    }
+
+    pub fn new(dir: impl Into<PathBuf>, max_file_bytes: u64, max_files: usize) -> Self {
+        Self {
+            dir: dir.into(),
+            max_file_bytes,
+            max_files: max_files.max(1),
+            current: Mutex::new(None),
+            opened: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn write(&self, batch: &[LogSchema]) -> Result<()> {
+        let mut current = self.current.lock().unwrap();
+        for event in batch {
+            let record = TraceRecord {
+                event: event.clone(),
+                llm_timings: llm_requests(event)
+                    .iter()
+                    .map(|request| LLMTiming {
+                        start_time_ms: request.start_time.and_then(|start_time| {
+                            start_time
+                                .duration_since(web_time::UNIX_EPOCH)
+                                .ok()
+                                .map(|since_epoch| since_epoch.as_millis() as u64)
+                        }),
+                        latency_ms: request.latency.map(|latency| latency.as_millis() as u64),
+                    })
+                    .collect(),
+            };
+            let mut line = serde_json::to_vec(&record)?;
+            line.push(b'\n');
+
+            let file = match current.take() {
+                Some(file)
+                    if file.size == 0 || file.size + line.len() as u64 <= self.max_file_bytes =>
+                {
+                    file
+                }
+                _ => self.open()?,
+            };
+            let file = current.insert(file);
+            // One write per line, so readers never see half an event
+            file.file.write_all(&line)?;
+            file.size += line.len() as u64;
+        }
+        Ok(())
+    }
+
+    fn open(&self) -> Result<OpenFile> {
+        std::fs::create_dir_all(&self.dir)
+            .with_context(|| format!("Failed to create {}", self.dir.display()))?;
+        let path = self.dir.join(format!(
+            "traces-{}-{}-{:04}.jsonl",
+            chrono::Utc::now().format("%Y%m%dT%H%M%S%.3f"),
+            std::process::id(),
+            self.opened.fetch_add(1, Ordering::Relaxed)
+        ));
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .with_context(|| format!("Failed to open {}", path.display()))?;
+
+        let pid = std::process::id();
+        let files = trace_files(&self.dir)?
+            .into_iter()
+            .filter(|path| writer_pid(path) == Some(pid))
+            .collect::<Vec<_>>();
+        for old in &files[..files.len().saturating_sub(self.max_files)] {
+            if let Err(e) = std::fs::remove_file(old) {
+                log::warn!("Failed to remove old trace file {}: {}", old.display(), e);
+            }
+        }
+
+        Ok(OpenFile {
+            size: file.metadata().map_or(0, |metadata| metadata.len()),
+            file,
+        })
+    }
+}
+
+/// The trace files in `dir`, oldest first.
+fn trace_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read {}", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("traces-") && name.ends_with(".jsonl"))
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+    Ok(files)
+}
+
+/// The pid of the process that wrote a trace file, from its name.
+fn writer_pid(path: &Path) -> Option<u32> {
+    path.file_name()?.to_str()?.split('-').nth(2)?.parse().ok()
+}
+
+/// Every event written to `dir`, oldest first. Lines that can't be read, like
+/// one a crashed process only wrote half of, are skipped.
+pub(crate) fn read_events(dir: &Path) -> Result<Vec<LogSchema>> {
+    let mut events = Vec::new();
+    for path in trace_files(dir)? {
+        let contents = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        for (i, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str::<TraceRecord>(line) {
+                Ok(TraceRecord {
+                    mut event,
+                    llm_timings,
+                }) => {
+                    let requests = match &mut event.metadata {
+                        Some(MetadataType::Single(request)) => std::slice::from_mut(request),
+                        Some(MetadataType::Multi(requests)) => requests.as_mut_slice(),
+                        None => &mut [],
+                    };
+                    for (request, timing) in requests.iter_mut().zip(llm_timings) {
+                        request.start_time = timing.start_time_ms.map(|start_time| {
+                            web_time::UNIX_EPOCH + web_time::Duration::from_millis(start_time)
+                        });
+                        request.latency = timing.latency_ms.map(web_time::Duration::from_millis);
+                    }
+                    events.push(event);
+                }
+                Err(e) => log::warn!("Skipping {}:{}: {}", path.display(), i + 1, e),
+            }
+        }
+    }
+    Ok(events)
+}
+
+pub(crate) fn llm_requests(event: &LogSchema) -> &[LLMEventSchema] {
+    match &event.metadata {
+        Some(MetadataType::Single(request)) => std::slice::from_ref(request),
+        Some(MetadataType::Multi(requests)) => requests,
+        None => &[],
+    }
+}
+
+/// Rebuilds the response an LLM request was logged from, as far as the log
+/// allows, so it can be shown with [`Visualize`](super::Visualize).
+impl From<&LLMEventSchema> for LLMResponse {
+    fn from(request: &LLMEventSchema) -> Self {
+        let prompt = RenderedPrompt::from(&request.input.prompt.template);
+        let start_time = request.start_time.unwrap_or(web_time::UNIX_EPOCH);
+        let latency = request.latency.unwrap_or_default();
+        match (&request.output, &request.error) {
+            (Some(output), _) => LLMResponse::Success(LLMCompleteResponse {
+                client: request.provider.clone(),
+                model: request.model_name.clone(),
+                prompt,
+                request_options: request.input.request_options.clone(),
+                content: output.raw_text.clone(),
+                tool_calls: vec![],
+                start_time,
+                latency,
+                metadata: LLMCompleteResponseMetadata {
+                    baml_is_complete: true,
+                    finish_reason: output.metadata.finish_reason.clone(),
+                    prompt_tokens: output.metadata.prompt_tokens.map(|t| t as u64),
+                    output_tokens: output.metadata.output_tokens.map(|t| t as u64),
+                    total_tokens: output.metadata.total_tokens.map(|t| t as u64),
+                    cost: None,
+                },
+            }),
+            // Failures before a request was made don't know their client
+            (None, Some(message)) if request.provider == "<unknown>" => {
+                LLMResponse::InternalFailure(message.clone())
+            }
+            (None, message) => LLMResponse::LLMFailure(LLMErrorResponse {
+                client: request.provider.clone(),
+                model: Some(request.model_name.clone()).filter(|model| model != "<unknown>"),
+                prompt,
+                request_options: request.input.request_options.clone(),
+                start_time,
+                latency,
+                message: message.clone().unwrap_or_default(),
+                // The log only keeps the message
+                code: ErrorCode::Other(2),
+                retry_after: None,
+            }),
+        }
+    }
+}
+
+impl From<&Template> for RenderedPrompt {
+    fn from(template: &Template) -> Self {
+        match template {
+            Template::Single(prompt) => RenderedPrompt::Completion(prompt.clone()),
+            Template::Multiple(chats) => RenderedPrompt::Chat(
+                chats
+                    .iter()
+                    .map(|chat| RenderedChatMessage {
+                        role: match &chat.role {
+                            Role::Assistant => "assistant".to_string(),
+                            Role::User => "user".to_string(),
+                            Role::System => "system".to_string(),
+                            Role::Other(role) => role.clone(),
+                        },
+                        allow_duplicate_role: false,
+                        parts: chat.content.iter().map(|part| part.into()).collect(),
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl From<&ContentPart> for ChatMessagePart {
+    fn from(part: &ContentPart) -> Self {
+        let file = |media_type, span_path: &String, relpath: &String| {
+            BamlMedia::file(media_type, span_path.into(), relpath.clone(), None)
+        };
+        match part {
+            ContentPart::Text(text) => ChatMessagePart::Text(text.clone()),
+            ContentPart::FileImage(span_path, relpath) => {
+                ChatMessagePart::Media(file(BamlMediaType::Image, span_path, relpath))
+            }
+            ContentPart::FileAudio(span_path, relpath) => {
+                ChatMessagePart::Media(file(BamlMediaType::Audio, span_path, relpath))
+            }
+            ContentPart::UrlImage(url) => {
+                ChatMessagePart::Media(BamlMedia::url(BamlMediaType::Image, url.clone(), None))
+            }
+            ContentPart::UrlAudio(url) => {
+                ChatMessagePart::Media(BamlMedia::url(BamlMediaType::Audio, url.clone(), None))
+            }
+            ContentPart::B64Image(data) => {
+                ChatMessagePart::Media(BamlMedia::base64(BamlMediaType::Image, data.clone(), None))
+            }
+            ContentPart::B64Audio(data) => {
+                ChatMessagePart::Media(BamlMedia::base64(BamlMediaType::Audio, data.clone(), None))
+            }
+            ContentPart::WithMeta(part, meta) => {
+                ChatMessagePart::WithMeta(Box::new(part.as_ref().into()), meta.clone())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::tracing::api_wrapper::core_types::{
+        EventChain, EventType, LLMEventInput, LLMEventInputPrompt, LLMOutputModel,
+        LLMOutputModelMetadata, LogSchemaContext, IO,
+    };
+
+    fn event(function_name: &str) -> LogSchema {
+        LogSchema {
+            project_id: None,
+            event_type: EventType::FuncLlm,
+            root_event_id: uuid::Uuid::new_v4().to_string(),
+            event_id: uuid::Uuid::new_v4().to_string(),
+            parent_event_id: None,
+            context: LogSchemaContext {
+                hostname: "localhost".into(),
+                process_id: "1".into(),
+                stage: None,
+                latency_ms: 250,
+                start_time: "2024-10-01T12:00:00.000Z".into(),
+                tags: [("env".to_string(), "test".to_string())].into(),
+                event_chain: vec![EventChain {
+                    function_name: function_name.into(),
+                    variant_name: None,
+                }],
+            },
+            io: IO {
+                input: None,
+                output: None,
+            },
+            error: None,
+            metadata: Some(MetadataType::Multi(vec![LLMEventSchema {
+                model_name: "gpt-4o".into(),
+                provider: "openai".into(),
+                input: LLMEventInput {
+                    prompt: LLMEventInputPrompt {
+                        template: Template::Single("Say hi".into()),
+                        template_args: HashMap::new(),
+                        r#override: None,
+                    },
+                    request_options: HashMap::new(),
+                },
+                output: Some(LLMOutputModel {
+                    raw_text: "hi".into(),
+                    metadata: LLMOutputModelMetadata {
+                        total_tokens: Some(12),
+                        ..Default::default()
+                    },
+                    r#override: None,
+                }),
+                error: None,
+                start_time: Some(web_time::UNIX_EPOCH + web_time::Duration::from_secs(1)),
+                latency: Some(web_time::Duration::from_millis(200)),
+                client_provider: None,
+            }])),
+        }
+    }
+
+    #[test]
+    fn events_round_trip() {
+        let dir = std::env::temp_dir().join(format!("baml-traces-{}", uuid::Uuid::new_v4()));
+        let files = TraceFiles::new(&dir, DEFAULT_MAX_FILE_BYTES, DEFAULT_MAX_FILES);
+        files.write(&[event("First"), event("Second")]).unwrap();
+
+        let events = read_events(&dir).unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[1].context.event_chain[0].function_name, "Second");
+        let request = &llm_requests(&events[0])[0];
+        assert_eq!(request.latency, Some(web_time::Duration::from_millis(200)));
+        match LLMResponse::from(request) {
+            LLMResponse::Success(response) => {
+                assert_eq!(response.content, "hi");
+                assert_eq!(response.metadata.total_tokens, Some(12));
+                assert_eq!(
+                    response.start_time,
+                    web_time::UNIX_EPOCH + web_time::Duration::from_secs(1)
+                );
+            }
+            other => panic!("Expected a successful response, got {:?}", other),
+        }
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn old_files_are_removed() {
+        let dir = std::env::temp_dir().join(format!("baml-traces-{}", uuid::Uuid::new_v4()));
+        // Small enough that every event starts a new file
+        let files = TraceFiles::new(&dir, 10, 3);
+        for i in 0..5 {
+            files.write(&[event(&format!("Function{i}"))]).unwrap();
+        }
+        // A line a crashed writer left behind is skipped
+        let last = trace_files(&dir).unwrap().pop().unwrap();
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(last)
+            .unwrap()
+            .write_all(b"{\"event\":{")
+            .unwrap();
+
+        assert_eq!(trace_files(&dir).unwrap().len(), 3);
+        let names = read_events(&dir)
+            .unwrap()
+            .into_iter()
+            .map(|event| event.context.event_chain[0].function_name.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["Function2", "Function3", "Function4"]);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn files_of_other_processes_are_kept() {
+        let dir = std::env::temp_dir().join(format!("baml-traces-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let other = dir.join(format!(
+            "traces-20240101T000000.000-{}-0000.jsonl",
+            std::process::id().wrapping_add(1)
+        ));
+        std::fs::write(&other, "").unwrap();
+
+        let files = TraceFiles::new(&dir, 10, 2);
+        for i in 0..4 {
+            files.write(&[event(&format!("Function{i}"))]).unwrap();
+        }
+        assert!(other.exists());
+        assert_eq!(trace_files(&dir).unwrap().len(), 3);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
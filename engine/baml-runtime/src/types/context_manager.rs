@@ -7,7 +7,12 @@ use anyhow::{Context, Result};
 use baml_types::BamlValue;
 use std::fmt;
 
-use crate::{client_registry::ClientRegistry, type_builder::TypeBuilder, RuntimeContext, SpanCtx};
+use crate::{
+    client_registry::ClientRegistry,
+    internal::llm_client::{cache::CacheMode, cassette::Cassette, usage::PriceTable},
+    type_builder::TypeBuilder,
+    RuntimeContext, SpanCtx,
+};
 
 use super::runtime_context::BamlSrcReader;
 
@@ -20,6 +25,9 @@ pub struct RuntimeContextManager {
     context: Arc<Mutex<Vec<BamlContext>>>,
     env_vars: HashMap<String, String>,
     global_tags: Arc<Mutex<HashMap<String, BamlValue>>>,
+    cache_mode: Arc<Mutex<Option<CacheMode>>>,
+    cassette: Option<Cassette>,
+    price_table: Arc<PriceTable>,
 }
 
 impl fmt::Debug for RuntimeContextManager {
@@ -39,6 +47,9 @@ impl RuntimeContextManager {
             context: Arc::new(Mutex::new(self.context.lock().unwrap().clone())),
             env_vars: self.env_vars.clone(),
             global_tags: Arc::new(Mutex::new(self.global_tags.lock().unwrap().clone())),
+            cache_mode: Arc::new(Mutex::new(*self.cache_mode.lock().unwrap())),
+            cassette: self.cassette.clone(),
+            price_table: self.price_table.clone(),
         }
     }
 
@@ -46,11 +57,49 @@ impl RuntimeContextManager {
         env_vars: HashMap<String, String>,
         baml_src_reader: BamlSrcReader,
     ) -> Self {
+        let cassette = Cassette::from_env(&env_vars).unwrap_or_else(|e| {
+            log::warn!("Not recording or replaying requests: {:#}", e);
+            None
+        });
+        let price_table = PriceTable::from_env(&env_vars).unwrap_or_else(|e| {
+            log::warn!("Not using BAML_PRICING_FILE: {:#}", e);
+            Default::default()
+        });
         Self {
             baml_src_reader: Arc::new(baml_src_reader),
             context: Default::default(),
             env_vars,
             global_tags: Default::default(),
+            cache_mode: Default::default(),
+            cassette,
+            price_table,
+        }
+    }
+
+    /// Records provider requests to, or replays them from, `cassette` instead of
+    /// whatever `BAML_CASSETTE_MODE` asked for.
+    pub fn with_cassette(mut self, cassette: Option<Cassette>) -> Self {
+        self.cassette = cassette;
+        self
+    }
+
+    /// Overrides how calls use the cache of clients that have one. `None` goes
+    /// back to `BAML_CACHE`, or reading and writing the cache if that isn't set.
+    pub fn set_cache_mode(&self, mode: Option<CacheMode>) {
+        *self.cache_mode.lock().unwrap() = mode;
+    }
+
+    fn cache_mode(&self) -> CacheMode {
+        if let Some(mode) = *self.cache_mode.lock().unwrap() {
+            return mode;
+        }
+        match self.env_vars.get("BAML_CACHE").map(|v| v.parse()) {
+            Some(Ok(mode)) => mode,
+            Some(Err(e)) => {
+                log::warn!("Ignoring BAML_CACHE: {}", e);
+                CacheMode::default()
+            }
+            None => CacheMode::default(),
         }
     }
 
@@ -136,6 +185,11 @@ impl RuntimeContextManager {
             client_overrides: Default::default(),
             class_override: cls,
             enum_overrides: enm,
+            cache_mode: self.cache_mode(),
+            cassette: self.cassette.clone(),
+            price_table: self.price_table.clone(),
+            collector: None,
+            cancel: Default::default(),
         };
 
         let client_overrides = match cb {
@@ -168,6 +222,11 @@ impl RuntimeContextManager {
             client_overrides: Default::default(),
             class_override: Default::default(),
             enum_overrides: Default::default(),
+            cache_mode: self.cache_mode(),
+            cassette: self.cassette.clone(),
+            price_table: self.price_table.clone(),
+            collector: None,
+            cancel: Default::default(),
         }
     }
 
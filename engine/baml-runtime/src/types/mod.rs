@@ -4,12 +4,14 @@ pub mod on_log_event;
 mod response;
 pub(crate) mod runtime_context;
 mod stream;
+mod test_expectations;
 mod trace_stats;
 
 pub use context_manager::RuntimeContextManager;
 pub use response::{FunctionResult, TestFailReason, TestResponse, TestStatus};
 pub use runtime_context::{RuntimeContext, SpanCtx};
 pub use stream::FunctionResultStream;
+pub use test_expectations::TestExpectations;
 pub use trace_stats::{InnerTraceStats, TraceStats};
 
 #[derive(Debug, Clone, Copy)]
@@ -6,6 +6,8 @@ use colored::*;
 use baml_types::BamlValue;
 use jsonish::BamlValueWithFlags;
 
+use super::test_expectations::TestExpectations;
+
 #[derive(Debug)]
 pub struct FunctionResult {
     event_chain: Vec<(
@@ -180,6 +182,7 @@ impl FunctionResult {
 pub struct TestResponse {
     pub function_response: FunctionResult,
     pub function_span: Option<uuid::Uuid>,
+    pub expectations: TestExpectations,
 }
 
 impl std::fmt::Display for TestResponse {
@@ -191,6 +194,8 @@ impl std::fmt::Display for TestResponse {
 #[derive(Debug, PartialEq, Eq)]
 pub enum TestStatus<'a> {
     Pass,
+    /// Every assert passed, but these checks failed.
+    NeedsHumanEval(Vec<String>),
     Fail(TestFailReason<'a>),
 }
 
@@ -198,6 +203,9 @@ impl From<TestStatus<'_>> for BamlValue {
     fn from(status: TestStatus) -> Self {
         match status {
             TestStatus::Pass => BamlValue::String("pass".to_string()),
+            TestStatus::NeedsHumanEval(checks) => {
+                BamlValue::String(format!("needs human eval! {:?}", checks))
+            }
             TestStatus::Fail(r) => BamlValue::String(format!("failed! {:?}", r)),
         }
     }
@@ -208,6 +216,16 @@ pub enum TestFailReason<'a> {
     TestUnspecified(&'a anyhow::Error),
     TestLLMFailure(&'a LLMResponse),
     TestParseFailure(&'a anyhow::Error),
+    /// An `@@assert` of the test failed.
+    TestConstraintsFailure {
+        checks: Vec<(String, bool)>,
+        failed_assert: String,
+    },
+    /// The output differs from the test's `expected` value.
+    TestExpectationFailure {
+        expected: &'a BamlValue,
+        actual: BamlValue,
+    },
 }
 
 impl PartialEq for TestFailReason<'_> {
@@ -218,6 +236,26 @@ impl PartialEq for TestFailReason<'_> {
             (Self::TestParseFailure(a), Self::TestParseFailure(b)) => {
                 a.to_string() == b.to_string()
             }
+            (
+                Self::TestConstraintsFailure {
+                    checks: a,
+                    failed_assert: a_assert,
+                },
+                Self::TestConstraintsFailure {
+                    checks: b,
+                    failed_assert: b_assert,
+                },
+            ) => a == b && a_assert == b_assert,
+            (
+                Self::TestExpectationFailure {
+                    expected: a,
+                    actual: a_actual,
+                },
+                Self::TestExpectationFailure {
+                    expected: b,
+                    actual: b_actual,
+                },
+            ) => a == b && a_actual == b_actual,
             _ => false,
         }
     }
@@ -229,17 +267,45 @@ impl TestResponse {
     pub fn status(&self) -> TestStatus {
         let func_res = &self.function_response;
         if let Some(parsed) = func_res.result_with_constraints() {
-            if parsed.is_ok() {
-                TestStatus::Pass
-            } else {
-                TestStatus::Fail(TestFailReason::TestParseFailure(
-                    parsed.as_ref().unwrap_err(),
-                ))
+            match parsed {
+                Ok(value) => self.expectations_status(BamlValue::from(value)),
+                Err(e) => TestStatus::Fail(TestFailReason::TestParseFailure(e)),
             }
         } else {
             TestStatus::Fail(TestFailReason::TestLLMFailure(func_res.llm_response()))
         }
     }
+
+    /// Holds a parsed output up against the test's asserts, checks and
+    /// expected value, in that order.
+    fn expectations_status(&self, output: BamlValue) -> TestStatus {
+        let outcome = self.expectations.evaluate(&output);
+        if let Some(failed_assert) = outcome.failed_assert {
+            return TestStatus::Fail(TestFailReason::TestConstraintsFailure {
+                checks: outcome.checks,
+                failed_assert,
+            });
+        }
+        if !outcome.expected_matches {
+            if let Some(expected) = &self.expectations.expected {
+                return TestStatus::Fail(TestFailReason::TestExpectationFailure {
+                    expected,
+                    actual: output,
+                });
+            }
+        }
+        let failed_checks = outcome
+            .checks
+            .into_iter()
+            .filter(|(_, passed)| !passed)
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+        if failed_checks.is_empty() {
+            TestStatus::Pass
+        } else {
+            TestStatus::NeedsHumanEval(failed_checks)
+        }
+    }
 }
 
 #[cfg(test)]
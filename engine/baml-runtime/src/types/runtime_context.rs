@@ -6,7 +6,11 @@ use serde;
 use serde_json;
 use std::{collections::HashMap, sync::Arc};
 
-use crate::internal::llm_client::llm_provider::LLMProvider;
+use crate::cancel::CancelToken;
+use crate::collector::CallLog;
+use crate::internal::llm_client::{
+    cache::CacheMode, cassette::Cassette, llm_provider::LLMProvider, usage::PriceTable,
+};
 
 #[derive(Debug)]
 pub struct SpanCtx {
@@ -58,6 +62,16 @@ pub struct RuntimeContext {
     pub client_overrides: Option<(Option<String>, HashMap<String, Arc<LLMProvider>>)>,
     pub class_override: IndexMap<String, RuntimeClassOverride>,
     pub enum_overrides: IndexMap<String, RuntimeEnumOverride>,
+    // how clients with a `cache` use it
+    pub cache_mode: CacheMode,
+    // where provider HTTP exchanges are recorded to or replayed from
+    pub cassette: Option<Cassette>,
+    // prices of models whose client has no `pricing`
+    pub price_table: Arc<PriceTable>,
+    // where this call is logged, if it was made with a `Collector`
+    pub(crate) collector: Option<CallLog>,
+    // stops the call when its caller gives up on it
+    pub(crate) cancel: CancelToken,
 }
 
 impl RuntimeContext {
@@ -5,7 +5,9 @@ use internal_baml_core::ir::repr::IntermediateRepr;
 use std::sync::Arc;
 
 use crate::{
+    cancel::CancelToken,
     client_registry::ClientRegistry,
+    collector::Collector,
     internal::{
         llm_client::orchestrator::{orchestrate_stream, OrchestratorNodeIterator},
         prompt_renderer::PromptRenderer,
@@ -27,6 +29,8 @@ pub struct FunctionResultStream {
     pub(crate) ir: Arc<IntermediateRepr>,
     pub(crate) orchestrator: OrchestratorNodeIterator,
     pub(crate) tracer: Arc<BamlTracer>,
+    pub(crate) collector: Option<Collector>,
+    pub(crate) cancel: CancelToken,
     #[cfg(not(target_arch = "wasm32"))]
     pub(crate) tokio_runtime: Arc<tokio::runtime::Runtime>,
 }
@@ -55,6 +59,12 @@ first.scope.clone();
 */
 
 impl FunctionResultStream {
+    /// The token that stops this stream, which can be cancelled while the
+    /// stream runs.
+    pub fn cancel_token(&self) -> CancelToken {
+        self.cancel.clone()
+    }
+
     #[cfg(not(target_arch = "wasm32"))]
     pub fn run_sync<F>(
         &mut self,
@@ -92,16 +102,31 @@ impl FunctionResultStream {
             .start_span(&self.function_name, ctx, &local_params);
 
         let rctx = ctx.create_ctx(tb, cb);
+        let log = self
+            .collector
+            .as_ref()
+            .map(|c| c.start_call(&self.function_name));
         let res = match rctx {
-            Ok(rctx) => {
+            Ok(mut rctx) => {
+                rctx.collector = log.clone();
+                rctx.cancel = self.cancel.clone();
                 let (history, _) = orchestrate_stream(
                     local_orchestrator,
                     self.ir.as_ref(),
                     &rctx,
                     &self.renderer,
                     &baml_types::BamlValue::Map(local_params),
-                    |content| self.renderer.parse(content, true),
-                    |content| self.renderer.parse(content, false),
+                    |response, state| {
+                        self.renderer.parse_streaming(
+                            &response.content,
+                            &response.tool_calls,
+                            state,
+                        )
+                    },
+                    |response| {
+                        self.renderer
+                            .parse(&response.content, &response.tool_calls, false)
+                    },
                     on_event,
                 )
                 .await;
@@ -110,6 +135,10 @@ impl FunctionResultStream {
             }
             Err(e) => Err(e),
         };
+        let res = self.cancel.check(res);
+        if let Some(log) = log {
+            log.finish(&res);
+        }
 
         let mut target_id = None;
         if let Some(span) = span {
@@ -0,0 +1,215 @@
+use baml_types::{BamlMap, BamlValue, Constraint, ConstraintLevel};
+use internal_baml_core::{
+    internal_baml_parser_database::ExpectedMatch, ir::jinja_helpers::evaluate_predicate,
+};
+
+/// What a test block asks of its function's output, beyond parsing.
+#[derive(Debug, Default)]
+pub struct TestExpectations {
+    /// The test's `@@assert`s and `@@check`s, run with `this` bound to the output.
+    pub constraints: Vec<Constraint>,
+    /// The `expected` value. For an exact match it has been coerced to the
+    /// function's output type, so maps are classes and strings enum values.
+    pub expected: Option<BamlValue>,
+    pub expected_match: ExpectedMatch,
+}
+
+/// The result of holding an output up against a [`TestExpectations`].
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct ExpectationsOutcome {
+    /// Every constraint by name, with whether it passed.
+    pub checks: Vec<(String, bool)>,
+    pub failed_assert: Option<String>,
+    pub expected_matches: bool,
+}
+
+impl TestExpectations {
+    pub(crate) fn evaluate(&self, output: &BamlValue) -> ExpectationsOutcome {
+        let mut outcome = ExpectationsOutcome {
+            expected_matches: self.expected.as_ref().map_or(true, |expected| {
+                values_match(expected, output, self.expected_match)
+            }),
+            ..Default::default()
+        };
+        for constraint in &self.constraints {
+            let name = constraint
+                .label
+                .clone()
+                .unwrap_or_else(|| constraint.expression.0.clone());
+            // An expression that can't be evaluated can't have passed.
+            let passed = evaluate_predicate(output, &constraint.expression).unwrap_or(false);
+            if !passed
+                && constraint.level == ConstraintLevel::Assert
+                && outcome.failed_assert.is_none()
+            {
+                outcome.failed_assert = Some(name.clone());
+            }
+            outcome.checks.push((name, passed));
+        }
+        outcome
+    }
+}
+
+fn values_match(expected: &BamlValue, actual: &BamlValue, mode: ExpectedMatch) -> bool {
+    let fuzzy = mode == ExpectedMatch::Fuzzy;
+    match (expected, actual) {
+        (
+            BamlValue::Class(_, expected) | BamlValue::Map(expected),
+            BamlValue::Class(_, actual) | BamlValue::Map(actual),
+        ) => fields_match(expected, actual, mode),
+        (BamlValue::List(expected), BamlValue::List(actual)) => {
+            expected.len() == actual.len()
+                && expected
+                    .iter()
+                    .zip(actual)
+                    .all(|(e, a)| values_match(e, a, mode))
+        }
+        (BamlValue::Int(_) | BamlValue::Float(_), BamlValue::Int(_) | BamlValue::Float(_)) => {
+            let (expected, actual) = (as_f64(expected), as_f64(actual));
+            if fuzzy {
+                (expected - actual).abs() <= 1e-6 * expected.abs().max(actual.abs()).max(1.0)
+            } else {
+                expected == actual
+            }
+        }
+        (BamlValue::String(expected), BamlValue::String(actual)) if fuzzy => {
+            normalize(expected) == normalize(actual)
+        }
+        (
+            BamlValue::Enum(_, expected) | BamlValue::String(expected),
+            BamlValue::Enum(_, actual),
+        ) if fuzzy => expected.eq_ignore_ascii_case(actual),
+        _ => expected == actual,
+    }
+}
+
+/// Fields left out on one side count as `null`. A fuzzy match also ignores
+/// fields the expected value doesn't mention.
+fn fields_match(
+    expected: &BamlMap<String, BamlValue>,
+    actual: &BamlMap<String, BamlValue>,
+    mode: ExpectedMatch,
+) -> bool {
+    let expected_fields_match = expected.iter().all(|(key, expected)| {
+        values_match(expected, actual.get(key).unwrap_or(&BamlValue::Null), mode)
+    });
+    let extra_fields_match = mode == ExpectedMatch::Fuzzy
+        || actual
+            .iter()
+            .filter(|(key, _)| !expected.contains_key(*key))
+            .all(|(_, actual)| matches!(actual, BamlValue::Null));
+    expected_fields_match && extra_fields_match
+}
+
+fn as_f64(value: &BamlValue) -> f64 {
+    match value {
+        BamlValue::Int(i) => *i as f64,
+        BamlValue::Float(f) => *f,
+        _ => f64::NAN,
+    }
+}
+
+fn normalize(s: &str) -> String {
+    s.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use baml_types::JinjaExpression;
+
+    use super::*;
+
+    fn person(name: &str, age: BamlValue) -> BamlValue {
+        BamlValue::Class(
+            "Person".to_string(),
+            [
+                ("name".to_string(), BamlValue::String(name.to_string())),
+                ("age".to_string(), age),
+            ]
+            .into_iter()
+            .collect(),
+        )
+    }
+
+    fn expecting(expected: BamlValue, expected_match: ExpectedMatch) -> TestExpectations {
+        TestExpectations {
+            expected: Some(expected),
+            expected_match,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn exact_match_is_structural() {
+        let output = person("Alice", BamlValue::Int(30));
+
+        let same = expecting(
+            person("Alice", BamlValue::Float(30.0)),
+            ExpectedMatch::Exact,
+        );
+        assert!(same.evaluate(&output).expected_matches);
+
+        let different = expecting(person("alice ", BamlValue::Int(30)), ExpectedMatch::Exact);
+        assert!(!different.evaluate(&output).expected_matches);
+    }
+
+    #[test]
+    fn fuzzy_match_forgives_case_whitespace_and_missing_fields() {
+        let output = person("Alice  Smith", BamlValue::Int(30));
+        let expected = BamlValue::Map(
+            [(
+                "name".to_string(),
+                BamlValue::String(" alice smith".to_string()),
+            )]
+            .into_iter()
+            .collect(),
+        );
+
+        assert!(
+            !expecting(expected.clone(), ExpectedMatch::Exact)
+                .evaluate(&output)
+                .expected_matches
+        );
+        assert!(
+            expecting(expected, ExpectedMatch::Fuzzy)
+                .evaluate(&output)
+                .expected_matches
+        );
+    }
+
+    #[test]
+    fn constraints_report_every_result_and_the_first_failed_assert() {
+        let constraint = |level, label: Option<&str>, expression: &str| Constraint {
+            level,
+            expression: JinjaExpression(expression.to_string()),
+            label: label.map(str::to_string),
+            refinement: None,
+        };
+        let expectations = TestExpectations {
+            constraints: vec![
+                constraint(ConstraintLevel::Check, Some("adult"), "this.age >= 18"),
+                constraint(ConstraintLevel::Assert, None, "this.name|length < 3"),
+                constraint(ConstraintLevel::Assert, Some("named"), "this.name"),
+            ],
+            ..Default::default()
+        };
+
+        let outcome = expectations.evaluate(&person("Alice", BamlValue::Int(30)));
+        assert_eq!(
+            outcome.checks,
+            vec![
+                ("adult".to_string(), true),
+                ("this.name|length < 3".to_string(), false),
+                ("named".to_string(), false),
+            ]
+        );
+        assert_eq!(
+            outcome.failed_assert.as_deref(),
+            Some("this.name|length < 3")
+        );
+        assert!(outcome.expected_matches);
+    }
+}
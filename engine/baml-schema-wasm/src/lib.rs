@@ -7,3 +7,15 @@ pub fn version() -> String {
     // register_panic_hook();
     env!("CARGO_PKG_VERSION").to_string()
 }
+
+/// Formats a single BAML file, failing if it doesn't parse.
+#[wasm_bindgen]
+pub fn format_baml(file_name: &str, source: &str) -> Result<String, JsError> {
+    use internal_baml_core::{
+        internal_baml_diagnostics::SourceFile, internal_baml_schema_ast::format_schema,
+    };
+
+    let source = SourceFile::from((file_name.into(), source.to_string()));
+    format_schema(&file_name.into(), &source)
+        .map_err(|diagnostics| JsError::new(&diagnostics.to_pretty_string()))
+}
@@ -411,6 +411,9 @@ pub enum TestStatus {
     LLMFailure,
     ParseFailure,
     UnableToRun,
+    ConstraintsFailed,
+    ExpectationFailed,
+    NeedsHumanEval,
 }
 
 #[wasm_bindgen(getter_with_clone, inspectable)]
@@ -499,10 +502,17 @@ impl WasmTestResponse {
         match &self.test_response {
             Ok(t) => match t.status() {
                 baml_runtime::TestStatus::Pass => TestStatus::Passed,
+                baml_runtime::TestStatus::NeedsHumanEval(_) => TestStatus::NeedsHumanEval,
                 baml_runtime::TestStatus::Fail(r) => match r {
                     baml_runtime::TestFailReason::TestUnspecified(_) => TestStatus::UnableToRun,
                     baml_runtime::TestFailReason::TestLLMFailure(_) => TestStatus::LLMFailure,
                     baml_runtime::TestFailReason::TestParseFailure(_) => TestStatus::ParseFailure,
+                    baml_runtime::TestFailReason::TestConstraintsFailure { .. } => {
+                        TestStatus::ConstraintsFailed
+                    }
+                    baml_runtime::TestFailReason::TestExpectationFailure { .. } => {
+                        TestStatus::ExpectationFailed
+                    }
                 },
             },
             Err(_) => TestStatus::UnableToRun,
@@ -561,6 +571,9 @@ impl WasmTestResponse {
         match self.test_response.as_ref() {
             Ok(r) => match r.status() {
                 baml_runtime::TestStatus::Pass => None,
+                baml_runtime::TestStatus::NeedsHumanEval(checks) => {
+                    Some(format!("Failed checks: {}", checks.join(", ")))
+                }
                 baml_runtime::TestStatus::Fail(r) => r.render_error(),
             },
             Err(e) => Some(format!("{e:#}")),
@@ -676,6 +689,16 @@ impl WithRenderError for baml_runtime::TestFailReason<'_> {
             baml_runtime::TestFailReason::TestUnspecified(e) => Some(format!("{e:#}")),
             baml_runtime::TestFailReason::TestLLMFailure(f) => f.render_error(),
             baml_runtime::TestFailReason::TestParseFailure(e) => Some(format!("{e:#}")),
+            baml_runtime::TestFailReason::TestConstraintsFailure { failed_assert, .. } => {
+                Some(format!("Failed assertion: {failed_assert}"))
+            }
+            baml_runtime::TestFailReason::TestExpectationFailure { expected, actual } => {
+                Some(format!(
+                    "Expected {:#}\nbut got {:#}",
+                    serde_json::json!(expected),
+                    serde_json::json!(actual)
+                ))
+            }
         }
     }
 }
@@ -720,6 +743,9 @@ fn get_dummy_value(
                 TypeValue::Float => "0.5".to_string(),
                 TypeValue::Bool => "true".to_string(),
                 TypeValue::Null => "null".to_string(),
+                TypeValue::Date => "\"2024-01-31\"".to_string(),
+                TypeValue::DateTime => "\"2024-01-31T09:30:00Z\"".to_string(),
+                TypeValue::Duration => "\"PT1H30M\"".to_string(),
                 TypeValue::Media(BamlMediaType::Image) => {
                     "{ url \"https://imgs.xkcd.com/comics/standards.png\"}".to_string()
                 }
@@ -733,6 +759,7 @@ fn get_dummy_value(
         baml_runtime::FieldType::Literal(_) => None,
         baml_runtime::FieldType::Enum(_) => None,
         baml_runtime::FieldType::Class(_) => None,
+        baml_runtime::FieldType::RecursiveTypeAlias(_) => None,
         baml_runtime::FieldType::List(item) => {
             let dummy = get_dummy_value(indent + 1, allow_multiline, item);
             // Repeat it 2 times
@@ -1582,6 +1609,11 @@ impl ToJsValue for ExecutionScope {
                 set_property(&obj, "name", JsValue::from_str(name));
                 set_property(&obj, "index", JsValue::from_f64(*index as f64));
             }
+            ExecutionScope::LoadBalance(strategy, index) => {
+                set_property(&obj, "type", JsValue::from_str(&strategy.kind.to_string()));
+                set_property(&obj, "strategy_name", JsValue::from_str(&strategy.name));
+                set_property(&obj, "index", JsValue::from_f64(*index as f64));
+            }
         }
         obj.into()
     }
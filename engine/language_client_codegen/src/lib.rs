@@ -1,9 +1,9 @@
 use anyhow::{Context, Result};
-use baml_types::{Constraint, ConstraintLevel, FieldType};
+use baml_types::{BamlValue, Constraint, ConstraintLevel, FieldType};
 use indexmap::IndexMap;
 use internal_baml_core::{
     configuration::{GeneratorDefaultClientMode, GeneratorOutputType},
-    ir::repr::IntermediateRepr,
+    ir::{repr::IntermediateRepr, ClassFieldWalker, ClassWalker, IRHelper},
 };
 use std::{collections::{BTreeMap, HashSet}, path::PathBuf};
 use version_check::{check_version, GeneratorType, VersionCheckMode};
@@ -303,6 +303,122 @@ pub fn type_check_attributes(
 
 }
 
+/// Whether a type refers to any class.
+fn references_classes(field_type: &FieldType) -> bool {
+    match field_type {
+        FieldType::Class(_) => true,
+        FieldType::List(inner) | FieldType::Optional(inner) => references_classes(inner),
+        FieldType::Map(_, value) => references_classes(value),
+        FieldType::Union(inner) | FieldType::Tuple(inner) => inner.iter().any(references_classes),
+        FieldType::Constrained { base, .. } => references_classes(base),
+        // Recursive aliases are the same in partial types.
+        FieldType::RecursiveTypeAlias(_)
+        | FieldType::Enum(_)
+        | FieldType::Literal(_)
+        | FieldType::Primitive(_) => false,
+    }
+}
+
+/// The classes in the IR, each one after the class it extends, for languages
+/// where a base class has to be defined before its subclasses.
+fn classes_with_bases_first(ir: &IntermediateRepr) -> Vec<ClassWalker<'_>> {
+    let mut ordered = Vec::new();
+    let mut placed = HashSet::new();
+    for class in ir.walk_classes() {
+        let mut chain = vec![class];
+        while let Some(base) = chain
+            .last()
+            .and_then(|c| c.base_class())
+            .and_then(|base| ir.find_class(base).ok())
+        {
+            if placed.contains(base.name()) || chain.iter().any(|c| c.name() == base.name()) {
+                break;
+            }
+            chain.push(base);
+        }
+        for class in chain.into_iter().rev() {
+            if placed.insert(class.name()) {
+                ordered.push(class);
+            }
+        }
+    }
+    ordered
+}
+
+/// How a generated client spells the values a `@default(...)` can hold.
+/// Numbers and lists are written the same in every language.
+trait LiteralSyntax {
+    const NULL: &'static str;
+
+    fn bool(b: bool) -> String {
+        b.to_string()
+    }
+
+    fn string(s: &str) -> String {
+        serde_json::to_string(s).unwrap_or_default()
+    }
+
+    fn enum_value(enum_name: &str, value: &str) -> String;
+
+    fn map_entry(key: &str, value: String) -> String {
+        format!("{}: {}", Self::string(key), value)
+    }
+}
+
+/// A field's `@default(...)` written in the client's language, if it has one.
+fn default_literal<S: LiteralSyntax>(field: &ClassFieldWalker) -> Result<Option<String>> {
+    let field_type = &field.item.elem.r#type.elem;
+    Ok(field
+        .default_value(&Default::default())
+        .with_context(|| format!("Invalid default value of field `{}`", field.name()))?
+        .map(|value| render_literal::<S>(&value, field_type)))
+}
+
+/// Renders `value` for a field of `field_type`. Strings become members of the
+/// field's enum, if it is one.
+fn render_literal<S: LiteralSyntax>(value: &BamlValue, field_type: &FieldType) -> String {
+    let field_type = match field_type {
+        FieldType::Optional(inner) => inner.as_ref(),
+        FieldType::Constrained { base, .. } => base.as_ref(),
+        other => other,
+    };
+    match value {
+        BamlValue::Null => S::NULL.to_string(),
+        BamlValue::Bool(b) => S::bool(*b),
+        BamlValue::Int(i) => i.to_string(),
+        BamlValue::Float(f) => format!("{f:?}"),
+        BamlValue::String(s) => match field_type {
+            FieldType::Enum(name) => S::enum_value(name, s),
+            _ => S::string(s),
+        },
+        BamlValue::List(items) => {
+            let item_type = match field_type {
+                FieldType::List(inner) => inner.as_ref(),
+                other => other,
+            };
+            let items = items.iter().map(|v| render_literal::<S>(v, item_type));
+            format!("[{}]", items.collect::<Vec<_>>().join(", "))
+        }
+        BamlValue::Map(entries) => {
+            let value_type = match field_type {
+                FieldType::Map(_, inner) => inner.as_ref(),
+                other => other,
+            };
+            let entries = entries
+                .iter()
+                .map(|(k, v)| S::map_entry(k, render_literal::<S>(v, value_type)));
+            format!("{{{}}}", entries.collect::<Vec<_>>().join(", "))
+        }
+        // Validation only allows literal defaults.
+        BamlValue::Date(_)
+        | BamlValue::DateTime(_)
+        | BamlValue::Duration(_)
+        | BamlValue::Media(_)
+        | BamlValue::Enum(..)
+        | BamlValue::Class(..) => S::NULL.to_string(),
+    }
+}
+
 /// The set of Check names associated with a type.
 fn field_type_attributes<'a>(field_type: &FieldType) -> Option<TypeCheckAttributes> {
     match field_type {
@@ -339,6 +339,11 @@ impl<'ir> TryFrom<(&'ir IntermediateRepr, &'_ crate::GeneratorArgs)> for OpenApi
                 .into_iter()
                 .chain(ir.walk_enums().map(|e| Ok((e.name(), e.try_into()?))))
                 .chain(ir.walk_classes().map(|c| Ok((c.name(), c.try_into()?))))
+                .chain(
+                    ir.walk_type_aliases()
+                        .filter(|a| a.is_recursive())
+                        .map(|a| Ok((a.name(), a.target().to_type_spec(ir)?))),
+                )
                 .collect::<Result<_>>()?,
         })
     }
@@ -523,7 +528,9 @@ trait ToTypeReferenceInTypeDefinition<'ir> {
 impl<'ir> ToTypeReferenceInTypeDefinition<'ir> for FieldType {
     fn to_type_spec(&self, ir: &'ir IntermediateRepr) -> Result<TypeSpecWithMeta> {
         Ok(match self {
-            FieldType::Enum(name) | FieldType::Class(name) => TypeSpecWithMeta {
+            FieldType::Enum(name)
+            | FieldType::Class(name)
+            | FieldType::RecursiveTypeAlias(name) => TypeSpecWithMeta {
                 meta: TypeMetadata {
                     title: None,
                     r#enum: None,
@@ -543,6 +550,7 @@ impl<'ir> ToTypeReferenceInTypeDefinition<'ir> for FieldType {
                 },
                 type_spec: match v {
                     LiteralValue::Int(_) => TypeSpec::Inline(TypeDef::Int),
+                    LiteralValue::Float(_) => TypeSpec::Inline(TypeDef::Float),
                     LiteralValue::Bool(_) => TypeSpec::Inline(TypeDef::Bool),
                     LiteralValue::String(_) => TypeSpec::Inline(TypeDef::String),
                 },
@@ -591,6 +599,15 @@ impl<'ir> ToTypeReferenceInTypeDefinition<'ir> for FieldType {
                         "BAML<->OpenAPI only allows nulls in unions, not as a literal"
                     ),
                     TypeValue::String => TypeSpec::Inline(TypeDef::String),
+                    TypeValue::Date => {
+                        TypeSpec::Inline(TypeDef::FormattedString { format: "date" })
+                    }
+                    TypeValue::DateTime => {
+                        TypeSpec::Inline(TypeDef::FormattedString { format: "date-time" })
+                    }
+                    TypeValue::Duration => {
+                        TypeSpec::Inline(TypeDef::FormattedString { format: "duration" })
+                    }
                     TypeValue::Media(BamlMediaType::Audio) => TypeSpec::Ref {
                         r#ref: format!("#/components/schemas/BamlAudio"),
                     },
@@ -720,6 +737,10 @@ enum TypeDef {
     #[serde(rename = "string")]
     String,
 
+    /// A string in a well-known format, like `date` or `date-time`.
+    #[serde(rename = "string")]
+    FormattedString { format: &'static str },
+
     #[serde(rename = "object")]
     #[serde(rename_all = "camelCase")]
     Class {
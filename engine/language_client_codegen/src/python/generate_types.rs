@@ -2,18 +2,23 @@ use anyhow::Result;
 use itertools::{Itertools, join};
 use std::borrow::Cow;
 
-use crate::{field_type_attributes, type_check_attributes, TypeCheckAttributes};
+use crate::{
+    classes_with_bases_first, default_literal, field_type_attributes, references_classes,
+    type_check_attributes, LiteralSyntax, TypeCheckAttributes,
+};
 
 use super::python_language_features::ToPython;
+use baml_types::{LiteralValue, Refinement, StreamingBehavior};
 use internal_baml_core::ir::{
-    repr::IntermediateRepr, ClassWalker, EnumWalker, FieldType, IRHelper,
+    repr::IntermediateRepr, ClassFieldWalker, ClassWalker, EnumWalker, FieldType, IRHelper,
 };
 
 #[derive(askama::Template)]
 #[template(path = "types.py.j2", escape = "none")]
 pub(crate) struct PythonTypes<'ir> {
     enums: Vec<PythonEnum<'ir>>,
-    classes: Vec<PythonClass<'ir>>
+    classes: Vec<PythonClass<'ir>>,
+    recursive_aliases: Vec<PythonTypeAlias<'ir>>,
 }
 
 #[derive(askama::Template)]
@@ -30,10 +35,18 @@ struct PythonEnum<'ir> {
     dynamic: bool,
 }
 
+/// A recursive `type` alias. The others are inlined wherever they're used.
+struct PythonTypeAlias<'ir> {
+    name: &'ir str,
+    target: String,
+}
+
 struct PythonClass<'ir> {
     name: Cow<'ir, str>,
-    // the name, and the type of the field
-    fields: Vec<(Cow<'ir, str>, String)>,
+    // the class it extends, if any
+    base_class: Option<&'ir str>,
+    // the name, the type of the field, and whether it's inherited from the base class
+    fields: Vec<(Cow<'ir, str>, String, bool)>,
     dynamic: bool,
 }
 
@@ -46,9 +59,17 @@ pub(crate) struct PythonStreamTypes<'ir> {
 /// The Python class corresponding to Partial<TypeDefinedInBaml>
 struct PartialPythonClass<'ir> {
     name: &'ir str,
+    // the class it extends, if any
+    base_class: Option<&'ir str>,
     dynamic: bool,
-    // the name, and the type of the field
-    fields: Vec<(&'ir str, String)>,
+    // the name, the type of the field, and whether it's inherited from the base class
+    fields: Vec<(&'ir str, String, bool)>,
+}
+
+/// Whether the body of a class with these fields has any, inherited ones
+/// aside. A body without any needs a `pass`.
+fn has_own_fields<N>(fields: &[(N, String, bool)]) -> bool {
+    fields.iter().any(|(_, _, inherited)| !inherited)
 }
 
 impl<'ir> TryFrom<(&'ir IntermediateRepr, &'_ crate::GeneratorArgs)> for PythonTypes<'ir> {
@@ -59,7 +80,18 @@ impl<'ir> TryFrom<(&'ir IntermediateRepr, &'_ crate::GeneratorArgs)> for PythonT
     ) -> Result<PythonTypes<'ir>> {
         Ok(PythonTypes {
             enums: ir.walk_enums().map(PythonEnum::from).collect::<Vec<_>>(),
-            classes: ir.walk_classes().map(PythonClass::from).collect::<Vec<_>>(),
+            classes: classes_with_bases_first(ir)
+                .into_iter()
+                .map(PythonClass::try_from)
+                .collect::<Result<Vec<_>>>()?,
+            recursive_aliases: ir
+                .walk_type_aliases()
+                .filter(|a| a.is_recursive())
+                .map(|a| PythonTypeAlias {
+                    name: a.name(),
+                    target: a.target().to_type_ref(ir),
+                })
+                .collect::<Vec<_>>(),
         })
     }
 }
@@ -77,7 +109,10 @@ impl<'ir> TryFrom<(&'ir IntermediateRepr, &'_ crate::GeneratorArgs)> for TypeBui
             .collect::<Vec<_>>();
         Ok(TypeBuilder {
             enums: ir.walk_enums().map(PythonEnum::from).collect::<Vec<_>>(),
-            classes: ir.walk_classes().map(PythonClass::from).collect::<Vec<_>>(),
+            classes: ir
+                .walk_classes()
+                .map(PythonClass::try_from)
+                .collect::<Result<Vec<_>>>()?,
             checks_classes,
         })
     }
@@ -99,10 +134,13 @@ impl<'ir> From<EnumWalker<'ir>> for PythonEnum<'ir> {
     }
 }
 
-impl<'ir> From<ClassWalker<'ir>> for PythonClass<'ir> {
-    fn from(c: ClassWalker<'ir>) -> Self {
-        PythonClass {
+impl<'ir> TryFrom<ClassWalker<'ir>> for PythonClass<'ir> {
+    type Error = anyhow::Error;
+
+    fn try_from(c: ClassWalker<'ir>) -> Result<Self> {
+        Ok(PythonClass {
             name: Cow::Borrowed(c.name()),
+            base_class: c.base_class(),
             dynamic: c.item.attributes.get("dynamic_type").is_some(),
             fields: c
                 .item
@@ -110,16 +148,31 @@ impl<'ir> From<ClassWalker<'ir>> for PythonClass<'ir> {
                 .static_fields
                 .iter()
                 .map(|f| {
-                    (
+                    let field = ClassFieldWalker { db: c.db, item: f };
+                    let type_ref = f.elem.r#type.elem.to_type_ref(&c.db);
+                    let bounds = pydantic_bounds(&f.elem.r#type.elem);
+                    let type_ref = match default_literal::<Python>(&field)? {
+                        Some(default) => {
+                            if bounds.is_empty() {
+                                format!("{type_ref} = {default}")
+                            } else {
+                                format!("{type_ref} = Field(default={default}, {bounds})")
+                            }
+                        }
+                        _ if bounds.is_empty() => add_default_value(&f.elem.r#type.elem, &type_ref),
+                        _ if type_ref.starts_with("Optional[") => {
+                            format!("{type_ref} = Field(default=None, {bounds})")
+                        }
+                        _ => format!("{type_ref} = Field({bounds})"),
+                    };
+                    Ok((
                         Cow::Borrowed(f.elem.name.as_str()),
-                        add_default_value(
-                            &f.elem.r#type.elem,
-                            &f.elem.r#type.elem.to_type_ref(&c.db),
-                        ),
-                    )
+                        type_ref,
+                        c.inherits_field(&f.elem.name),
+                    ))
                 })
-                .collect(),
-        }
+                .collect::<Result<_>>()?,
+        })
     }
 }
 
@@ -128,8 +181,8 @@ impl<'ir> TryFrom<(&'ir IntermediateRepr, &'_ crate::GeneratorArgs)> for PythonS
 
     fn try_from((ir, _): (&'ir IntermediateRepr, &'_ crate::GeneratorArgs)) -> Result<Self> {
         Ok(Self {
-            partial_classes: ir
-                .walk_classes()
+            partial_classes: classes_with_bases_first(ir)
+                .into_iter()
                 .map(PartialPythonClass::from)
                 .collect::<Vec<_>>(),
         })
@@ -140,6 +193,7 @@ impl<'ir> From<ClassWalker<'ir>> for PartialPythonClass<'ir> {
     fn from(c: ClassWalker<'ir>) -> PartialPythonClass<'ir> {
         PartialPythonClass {
             name: c.name(),
+            base_class: c.base_class(),
             dynamic: c.item.attributes.get("dynamic_type").is_some(),
             fields: c
                 .item
@@ -147,12 +201,18 @@ impl<'ir> From<ClassWalker<'ir>> for PartialPythonClass<'ir> {
                 .static_fields
                 .iter()
                 .map(|f| {
+                    let field = ClassFieldWalker { db: c.db, item: f };
                     (
                         f.elem.name.as_str(),
                         add_default_value(
                             &f.elem.r#type.elem,
-                            &f.elem.r#type.elem.to_partial_type_ref(&c.db, false),
+                            &partial_field_type_ref(
+                                &f.elem.r#type.elem,
+                                field.streaming_behavior(),
+                                &c.db,
+                            ),
                         ),
+                        c.inherits_field(&f.elem.name),
                     )
                 })
                 .collect(),
@@ -160,6 +220,36 @@ impl<'ir> From<ClassWalker<'ir>> for PartialPythonClass<'ir> {
     }
 }
 
+/// The type of a partial class field, given how the field is streamed.
+fn partial_field_type_ref(
+    r#type: &FieldType,
+    streaming: StreamingBehavior,
+    ir: &IntermediateRepr,
+) -> String {
+    // Streamed classes always come back in their partial form, complete or not.
+    let type_ref = if streaming.done && !references_classes(r#type) {
+        let complete = super::ToTypeReferenceInClientDefinition::to_type_ref(r#type, ir, true);
+        if r#type.is_optional() {
+            complete
+        } else {
+            format!("Optional[{complete}]")
+        }
+    } else {
+        r#type.to_partial_type_ref(ir, false)
+    };
+    let type_ref = match type_ref.strip_prefix("Optional[") {
+        Some(inner) if streaming.not_null && !r#type.is_optional() => {
+            inner.strip_suffix(']').unwrap_or(inner).to_string()
+        }
+        _ => type_ref,
+    };
+    if streaming.with_state {
+        format!("baml_py.StreamState[{type_ref}]")
+    } else {
+        type_ref
+    }
+}
+
 pub fn add_default_value(node: &FieldType, type_str: &String) -> String {
     if type_str.starts_with("Optional[") {
         return format!("{} = None", type_str);
@@ -168,6 +258,44 @@ pub fn add_default_value(node: &FieldType, type_str: &String) -> String {
     }
 }
 
+/// The `Field(...)` arguments that enforce a field's `@range` and `@length`,
+/// e.g. `ge=0, le=10`.
+fn pydantic_bounds(field_type: &FieldType) -> String {
+    let FieldType::Constrained { constraints, .. } = field_type else {
+        return String::new();
+    };
+    constraints
+        .iter()
+        .filter_map(|c| c.refinement.as_ref())
+        .flat_map(|refinement| match refinement {
+            Refinement::Range { min, max } => [
+                min.map(|n| format!("ge={n}")),
+                max.map(|n| format!("le={n}")),
+            ],
+            Refinement::Length { min, max } => [
+                min.map(|n| format!("min_length={n}")),
+                max.map(|n| format!("max_length={n}")),
+            ],
+        })
+        .flatten()
+        .join(", ")
+}
+
+/// Writes `@default(...)` values as Python expressions.
+struct Python;
+
+impl LiteralSyntax for Python {
+    const NULL: &'static str = "None";
+
+    fn bool(b: bool) -> String {
+        if b { "True" } else { "False" }.to_string()
+    }
+
+    fn enum_value(enum_name: &str, value: &str) -> String {
+        format!("{enum_name}.{value}")
+    }
+}
+
 pub fn type_name_for_checks(checks: &TypeCheckAttributes) -> String {
     let check_names = checks.0.iter().map(|check| format!("\"{check}\"")).sorted().join(", ");
     format!["Literal[{check_names}]"]
@@ -176,8 +304,13 @@ pub fn type_name_for_checks(checks: &TypeCheckAttributes) -> String {
 fn type_def_for_checks(checks: TypeCheckAttributes) -> PythonClass<'static> {
     PythonClass {
         name: Cow::Owned(type_name_for_checks(&checks)),
-        fields: checks.0.into_iter().map(|check_name| (Cow::Owned(check_name), "baml_py.Check".to_string())).collect(),
-        dynamic: false
+        base_class: None,
+        fields: checks
+            .0
+            .into_iter()
+            .map(|check_name| (Cow::Owned(check_name), "baml_py.Check".to_string(), false))
+            .collect(),
+        dynamic: false,
     }
 }
 
@@ -200,8 +333,12 @@ impl ToTypeReferenceInTypeDefinition for FieldType {
                     format!("\"{name}\"")
                 }
             }
+            // `Literal` can't hold floats, see PEP 586.
+            FieldType::Literal(LiteralValue::Float(_)) => "float".to_string(),
             FieldType::Literal(value) => format!("Literal[{}]", value),
-            FieldType::Class(name) => format!("\"{name}\""),
+            FieldType::Class(name) | FieldType::RecursiveTypeAlias(name) => {
+                format!("\"{name}\"")
+            }
             FieldType::List(inner) => format!("List[{}]", inner.to_type_ref(ir)),
             FieldType::Map(key, value) => {
                 format!("Dict[{}, {}]", key.to_type_ref(ir), value.to_type_ref(ir))
@@ -248,6 +385,13 @@ impl ToTypeReferenceInTypeDefinition for FieldType {
                     format!("Optional[\"{name}\"]")
                 }
             }
+            FieldType::RecursiveTypeAlias(name) => {
+                if wrapped {
+                    format!("types.{name}")
+                } else {
+                    format!("Optional[types.{name}]")
+                }
+            }
             FieldType::Enum(name) => {
                 if ir
                     .find_enum(name)
@@ -259,6 +403,7 @@ impl ToTypeReferenceInTypeDefinition for FieldType {
                     format!("Optional[types.{name}]")
                 }
             }
+            FieldType::Literal(LiteralValue::Float(_)) => "float".to_string(),
             FieldType::Literal(value) => format!("Literal[{}]", value),
             FieldType::List(inner) => format!("List[{}]", inner.to_partial_type_ref(ir, true)),
             FieldType::Map(key, value) => {
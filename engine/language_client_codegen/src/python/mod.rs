@@ -4,6 +4,7 @@ mod python_language_features;
 use std::path::PathBuf;
 
 use anyhow::Result;
+use baml_types::LiteralValue;
 use generate_types::type_name_for_checks;
 use indexmap::IndexMap;
 use internal_baml_core::{
@@ -198,8 +199,12 @@ impl ToTypeReferenceInClientDefinition for FieldType {
                     format!("types.{name}")
                 }
             }
+            // `Literal` can't hold floats, see PEP 586.
+            FieldType::Literal(LiteralValue::Float(_)) => "float".to_string(),
             FieldType::Literal(value) => format!("Literal[{}]", value),
-            FieldType::Class(name) => format!("types.{name}"),
+            FieldType::Class(name) | FieldType::RecursiveTypeAlias(name) => {
+                format!("types.{name}")
+            }
             FieldType::List(inner) => format!("List[{}]", inner.to_type_ref(ir, with_checked)),
             FieldType::Map(key, value) => {
                 format!("Dict[{}, {}]", key.to_type_ref(ir, with_checked), value.to_type_ref(ir, with_checked))
@@ -251,6 +256,8 @@ impl ToTypeReferenceInClientDefinition for FieldType {
                 }
             }
             FieldType::Class(name) => format!("partial_types.{name}"),
+            FieldType::RecursiveTypeAlias(name) => format!("Optional[types.{name}]"),
+            FieldType::Literal(LiteralValue::Float(_)) => "float".to_string(),
             FieldType::Literal(value) => format!("Literal[{}]", value),
             FieldType::List(inner) => format!("List[{}]", inner.to_partial_type_ref(ir, with_checked)),
             FieldType::Map(key, value) => {
@@ -36,6 +36,9 @@ impl ToPython for TypeValue {
             TypeValue::Int => "int",
             TypeValue::String => "str",
             TypeValue::Null => "None",
+            TypeValue::Date => "datetime.date",
+            TypeValue::DateTime => "datetime.datetime",
+            TypeValue::Duration => "datetime.timedelta",
             TypeValue::Media(BamlMediaType::Image) => "baml_py.Image",
             TypeValue::Media(BamlMediaType::Audio) => "baml_py.Audio",
         }
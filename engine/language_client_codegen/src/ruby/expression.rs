@@ -49,6 +49,9 @@ impl ToRuby for TypeValue {
             TypeValue::Int => "number",
             TypeValue::String => "string",
             TypeValue::Null => "null",
+            TypeValue::Date => "date",
+            TypeValue::DateTime => "datetime",
+            TypeValue::Duration => "duration",
             TypeValue::Media(BamlMediaType::Image) => "Image",
             TypeValue::Media(BamlMediaType::Audio) => "Audio",
         }
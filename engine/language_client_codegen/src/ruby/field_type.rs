@@ -10,6 +10,8 @@ impl ToRuby for FieldType {
         match self {
             FieldType::Class(name) => format!("Baml::Types::{}", name.clone()),
             FieldType::Enum(name) => format!("T.any(Baml::Types::{}, String)", name.clone()),
+            // Sorbet has no recursive type aliases.
+            FieldType::RecursiveTypeAlias(_) => "T.untyped".to_string(),
             // TODO: Temporary solution until we figure out Ruby literals.
             FieldType::Literal(value) => value.literal_base_type().to_ruby(),
             // https://sorbet.org/docs/stdlib-generics
@@ -24,6 +26,10 @@ impl ToRuby for FieldType {
                 TypeValue::Int => "Integer",
                 TypeValue::String => "String",
                 TypeValue::Null => "NilClass",
+                TypeValue::Date => "Date",
+                TypeValue::DateTime => "Time",
+                // Durations are passed as a number of seconds.
+                TypeValue::Duration => "Float",
                 // TODO: Create Baml::Types::Image
                 TypeValue::Media(BamlMediaType::Image) => "Baml::Image",
                 TypeValue::Media(BamlMediaType::Audio) => "Baml::Audio",
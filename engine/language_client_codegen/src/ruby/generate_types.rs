@@ -4,10 +4,16 @@ use std::collections::HashSet;
 use anyhow::Result;
 use itertools::Itertools;
 
-use crate::{field_type_attributes, type_check_attributes, TypeCheckAttributes};
+use crate::{
+    default_literal, field_type_attributes, references_classes, type_check_attributes,
+    LiteralSyntax, TypeCheckAttributes,
+};
 
 use super::ruby_language_features::ToRuby;
-use internal_baml_core::ir::{repr::IntermediateRepr, ClassWalker, EnumWalker, FieldType};
+use baml_types::StreamingBehavior;
+use internal_baml_core::ir::{
+    repr::IntermediateRepr, ClassFieldWalker, ClassWalker, EnumWalker, FieldType,
+};
 
 #[derive(askama::Template)]
 #[template(path = "types.rb.j2", escape = "none")]
@@ -24,7 +30,9 @@ struct RubyEnum<'ir> {
 
 struct RubyStruct<'ir> {
     name: Cow<'ir, str>,
-    fields: Vec<(Cow<'ir, str>, String)>,
+    // the name, the type and the `@default` value of the field. T::Struct
+    // can't be subclassed, so this includes the fields of any base class.
+    fields: Vec<(Cow<'ir, str>, String, Option<String>)>,
     dynamic: bool,
 }
 
@@ -54,7 +62,10 @@ impl<'ir> TryFrom<(&'ir IntermediateRepr, &'ir crate::GeneratorArgs)> for RubyTy
     fn try_from((ir, _): (&'ir IntermediateRepr, &'ir crate::GeneratorArgs)) -> Result<Self> {
         Ok(RubyTypes {
             enums: ir.walk_enums().map(|e| e.into()).collect(),
-            classes: ir.walk_classes().map(|c| c.into()).collect(),
+            classes: ir
+                .walk_classes()
+                .map(RubyStruct::try_from)
+                .collect::<Result<_>>()?,
         })
     }
 }
@@ -75,9 +86,11 @@ impl<'ir> From<EnumWalker<'ir>> for RubyEnum<'ir> {
     }
 }
 
-impl<'ir> From<ClassWalker<'ir>> for RubyStruct<'ir> {
-    fn from(c: ClassWalker<'ir>) -> RubyStruct<'ir> {
-        RubyStruct {
+impl<'ir> TryFrom<ClassWalker<'ir>> for RubyStruct<'ir> {
+    type Error = anyhow::Error;
+
+    fn try_from(c: ClassWalker<'ir>) -> Result<RubyStruct<'ir>> {
+        Ok(RubyStruct {
             name: Cow::Borrowed(c.name()),
             dynamic: c.item.attributes.get("dynamic_type").is_some(),
             fields: c
@@ -85,9 +98,16 @@ impl<'ir> From<ClassWalker<'ir>> for RubyStruct<'ir> {
                 .elem
                 .static_fields
                 .iter()
-                .map(|f| (Cow::Borrowed(f.elem.name.as_str()), f.elem.r#type.elem.to_type_ref()))
-                .collect(),
-        }
+                .map(|f| {
+                    let field = ClassFieldWalker { db: c.db, item: f };
+                    Ok((
+                        Cow::Borrowed(f.elem.name.as_str()),
+                        f.elem.r#type.elem.to_type_ref(),
+                        default_literal::<Ruby>(&field)?,
+                    ))
+                })
+                .collect::<Result<_>>()?,
+        })
     }
 }
 
@@ -111,9 +131,10 @@ impl<'ir> From<ClassWalker<'ir>> for PartialRubyStruct<'ir> {
                 .static_fields
                 .iter()
                 .map(|f| {
+                    let field = ClassFieldWalker { db: c.db, item: f };
                     (
                         f.elem.name.as_str(),
-                        f.elem.r#type.elem.to_partial_type_ref(),
+                        partial_field_type_ref(&f.elem.r#type.elem, field.streaming_behavior()),
                     )
                 })
                 .collect(),
@@ -121,6 +142,55 @@ impl<'ir> From<ClassWalker<'ir>> for PartialRubyStruct<'ir> {
     }
 }
 
+/// Writes `@default(...)` values as Ruby expressions.
+struct Ruby;
+
+impl LiteralSyntax for Ruby {
+    const NULL: &'static str = "nil";
+
+    fn string(s: &str) -> String {
+        ruby_string(s)
+    }
+
+    fn enum_value(enum_name: &str, value: &str) -> String {
+        format!("Baml::Types::{enum_name}::{value}")
+    }
+
+    fn map_entry(key: &str, value: String) -> String {
+        format!("{} => {}", ruby_string(key), value)
+    }
+}
+
+/// A single-quoted Ruby string, so nothing in it gets interpolated.
+fn ruby_string(s: &str) -> String {
+    format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+/// The type of a partial struct field, given how the field is streamed.
+fn partial_field_type_ref(r#type: &FieldType, streaming: StreamingBehavior) -> String {
+    // Streamed classes always come back in their partial form, complete or not.
+    let type_ref = if streaming.done && !references_classes(r#type) {
+        if r#type.is_optional() {
+            r#type.to_type_ref()
+        } else {
+            format!("T.nilable({})", r#type.to_type_ref())
+        }
+    } else {
+        r#type.to_partial_type_ref()
+    };
+    let type_ref = match type_ref.strip_prefix("T.nilable(") {
+        Some(inner) if streaming.not_null && !r#type.is_optional() => {
+            inner.strip_suffix(')').unwrap_or(inner).to_string()
+        }
+        _ => type_ref,
+    };
+    if streaming.with_state {
+        format!("Baml::StreamState[{type_ref}]")
+    } else {
+        type_ref
+    }
+}
+
 pub(super) trait ToTypeReferenceInTypeDefinition {
     fn to_type_ref(&self) -> String;
     fn to_partial_type_ref(&self) -> String;
@@ -136,6 +206,7 @@ impl ToTypeReferenceInTypeDefinition for FieldType {
         match self {
             FieldType::Class(name) => format!("Baml::PartialTypes::{}", name.clone()),
             FieldType::Enum(name) => format!("T.nilable(Baml::Types::{})", name.clone()),
+            FieldType::RecursiveTypeAlias(_) => self.to_type_ref(),
             // TODO: Temporary solution until we figure out Ruby literals.
             FieldType::Literal(value) => value.literal_base_type().to_partial_type_ref(),
             // https://sorbet.org/docs/stdlib-generics
@@ -190,7 +261,10 @@ impl<'ir> TryFrom<(&'ir IntermediateRepr, &'_ crate::GeneratorArgs)> for TypeReg
     ) -> Result<TypeRegistry<'ir>> {
         Ok(TypeRegistry {
             enums: ir.walk_enums().map(RubyEnum::from).collect::<Vec<_>>(),
-            classes: ir.walk_classes().map(RubyStruct::from).collect::<Vec<_>>(),
+            classes: ir
+                .walk_classes()
+                .map(RubyStruct::try_from)
+                .collect::<Result<Vec<_>>>()?,
         })
     }
 }
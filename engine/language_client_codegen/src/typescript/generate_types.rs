@@ -3,9 +3,15 @@ use std::borrow::Cow;
 use anyhow::Result;
 use itertools::Itertools;
 
-use internal_baml_core::ir::{repr::IntermediateRepr, ClassWalker, EnumWalker};
+use baml_types::StreamingBehavior;
+use internal_baml_core::ir::{
+    repr::IntermediateRepr, ClassFieldWalker, ClassWalker, EnumWalker, FieldType, IRHelper,
+};
 
-use crate::{type_check_attributes, GeneratorArgs, TypeCheckAttributes};
+use crate::{
+    default_literal, references_classes, type_check_attributes, GeneratorArgs, LiteralSyntax,
+    TypeCheckAttributes,
+};
 
 use super::ToTypeReferenceInClientDefinition;
 
@@ -21,6 +27,27 @@ pub(crate) struct TypeBuilder<'ir> {
 pub(crate) struct TypescriptTypes<'ir> {
     enums: Vec<TypescriptEnum<'ir>>,
     classes: Vec<TypescriptClass<'ir>>,
+    // the name and the aliased type of each recursive type alias
+    recursive_aliases: Vec<(&'ir str, String)>,
+}
+
+#[derive(askama::Template)]
+#[template(path = "partial_types.ts.j2", escape = "none")]
+pub(crate) struct TypescriptStreamTypes<'ir> {
+    // enums and recursive aliases, which are the same in partial types
+    type_imports: Vec<&'ir str>,
+    partial_classes: Vec<PartialTypescriptClass<'ir>>,
+}
+
+/// The interface for a class that is still being streamed.
+struct PartialTypescriptClass<'ir> {
+    name: &'ir str,
+    // the interface it extends, if any
+    extends: Option<String>,
+    dynamic: bool,
+    // the name, whether it may be missing, the type of the field, and whether
+    // it's inherited from the base class
+    fields: Vec<(&'ir str, bool, String, bool)>,
 }
 
 struct TypescriptEnum<'ir> {
@@ -31,7 +58,11 @@ struct TypescriptEnum<'ir> {
 
 pub struct TypescriptClass<'ir> {
     pub name: Cow<'ir, str>,
-    pub fields: Vec<(Cow<'ir, str>, bool, String)>,
+    // The interface it extends, if any.
+    pub extends: Option<String>,
+    // Name, optional, type, `@default` value and whether it's inherited from
+    // the base class.
+    pub fields: Vec<(Cow<'ir, str>, bool, String, Option<String>, bool)>,
     pub dynamic: bool,
 }
 
@@ -48,8 +79,37 @@ impl<'ir> TryFrom<(&'ir IntermediateRepr, &'ir GeneratorArgs)> for TypescriptTyp
                 .collect::<Vec<_>>(),
             classes: ir
                 .walk_classes()
-                .map(|e| Into::<TypescriptClass>::into(&e))
-                .collect::<Vec<_>>(),
+                .map(|e| TypescriptClass::try_from(&e))
+                .collect::<Result<Vec<_>>>()?,
+            recursive_aliases: ir
+                .walk_type_aliases()
+                .filter(|a| a.is_recursive())
+                .map(|a| (a.name(), a.target().to_type_ref(ir)))
+                .collect(),
+        })
+    }
+}
+
+impl<'ir> TryFrom<(&'ir IntermediateRepr, &'ir GeneratorArgs)> for TypescriptStreamTypes<'ir> {
+    type Error = anyhow::Error;
+
+    fn try_from(
+        (ir, _): (&'ir IntermediateRepr, &'ir GeneratorArgs),
+    ) -> Result<TypescriptStreamTypes<'ir>> {
+        Ok(TypescriptStreamTypes {
+            type_imports: ir
+                .walk_enums()
+                .map(|e| e.name())
+                .chain(
+                    ir.walk_type_aliases()
+                        .filter(|a| a.is_recursive())
+                        .map(|a| a.name()),
+                )
+                .collect(),
+            partial_classes: ir
+                .walk_classes()
+                .map(PartialTypescriptClass::from)
+                .collect(),
         })
     }
 }
@@ -65,8 +125,8 @@ impl<'ir> TryFrom<(&'ir IntermediateRepr, &'ir GeneratorArgs)> for TypeBuilder<'
                 .collect::<Vec<_>>(),
             classes: ir
                 .walk_classes()
-                .map(|e| Into::<TypescriptClass>::into(&e))
-                .collect::<Vec<_>>(),
+                .map(|e| TypescriptClass::try_from(&e))
+                .collect::<Result<Vec<_>>>()?,
         })
     }
 }
@@ -87,10 +147,13 @@ impl<'ir> From<&EnumWalker<'ir>> for TypescriptEnum<'ir> {
     }
 }
 
-impl<'ir> From<&ClassWalker<'ir>> for TypescriptClass<'ir> {
-    fn from(c: &ClassWalker<'ir>) -> TypescriptClass<'ir> {
-        TypescriptClass {
+impl<'ir> TryFrom<&ClassWalker<'ir>> for TypescriptClass<'ir> {
+    type Error = anyhow::Error;
+
+    fn try_from(c: &ClassWalker<'ir>) -> Result<TypescriptClass<'ir>> {
+        Ok(TypescriptClass {
             name: Cow::Borrowed(c.name()),
+            extends: extends_clause(c),
             dynamic: c.item.attributes.get("dynamic_type").is_some(),
             fields: c
                 .item
@@ -98,10 +161,39 @@ impl<'ir> From<&ClassWalker<'ir>> for TypescriptClass<'ir> {
                 .static_fields
                 .iter()
                 .map(|f| {
-                    (
+                    let field = ClassFieldWalker { db: c.db, item: f };
+                    Ok((
                         Cow::Borrowed(f.elem.name.as_str()),
                         f.elem.r#type.elem.is_optional(),
                         f.elem.r#type.elem.to_type_ref(&c.db),
+                        default_literal::<Typescript>(&field)?,
+                        c.inherits_field(&f.elem.name),
+                    ))
+                })
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+impl<'ir> From<ClassWalker<'ir>> for PartialTypescriptClass<'ir> {
+    fn from(c: ClassWalker<'ir>) -> PartialTypescriptClass<'ir> {
+        PartialTypescriptClass {
+            name: c.name(),
+            extends: extends_clause(&c),
+            dynamic: c.item.attributes.get("dynamic_type").is_some(),
+            fields: c
+                .item
+                .elem
+                .static_fields
+                .iter()
+                .map(|f| {
+                    let field = ClassFieldWalker { db: c.db, item: f };
+                    let streaming = field.streaming_behavior();
+                    (
+                        f.elem.name.as_str(),
+                        !streaming.not_null,
+                        partial_field_type_ref(&f.elem.r#type.elem, streaming, c.db),
+                        c.inherits_field(&f.elem.name),
                     )
                 })
                 .collect(),
@@ -109,6 +201,72 @@ impl<'ir> From<&ClassWalker<'ir>> for TypescriptClass<'ir> {
     }
 }
 
+/// The interface a class extends. Fields it redeclares are omitted from the
+/// base, since TypeScript only lets an interface narrow an inherited field.
+fn extends_clause(c: &ClassWalker) -> Option<String> {
+    let base = c.base_class()?;
+    let base_class = c.db.find_class(base).ok();
+    let in_base = |name: &str| {
+        base_class.as_ref().map_or(false, |b| {
+            b.elem().static_fields.iter().any(|f| f.elem.name == name)
+        })
+    };
+    let redeclared = c
+        .elem()
+        .static_fields
+        .iter()
+        .map(|f| f.elem.name.as_str())
+        .filter(|name| !c.inherits_field(name) && in_base(name))
+        .map(|name| format!("\"{name}\""))
+        .collect::<Vec<_>>();
+    if redeclared.is_empty() {
+        Some(base.to_string())
+    } else {
+        Some(format!("Omit<{}, {}>", base, redeclared.join(" | ")))
+    }
+}
+
+/// Writes `@default(...)` values as TypeScript expressions.
+struct Typescript;
+
+impl LiteralSyntax for Typescript {
+    const NULL: &'static str = "null";
+
+    fn enum_value(enum_name: &str, value: &str) -> String {
+        format!("{enum_name}.{value}")
+    }
+}
+
+/// The type of a partial class field, given how the field is streamed.
+fn partial_field_type_ref(
+    r#type: &FieldType,
+    streaming: StreamingBehavior,
+    ir: &IntermediateRepr,
+) -> String {
+    // Streamed classes always come back in their partial form, complete or not.
+    let type_ref = if streaming.done && !references_classes(r#type) {
+        if r#type.is_optional() {
+            r#type.to_type_ref(ir)
+        } else {
+            format!("({} | null)", r#type.to_type_ref(ir))
+        }
+    } else {
+        r#type.to_partial_type_ref(ir, "")
+    };
+    let type_ref = match type_ref
+        .strip_prefix('(')
+        .and_then(|t| t.strip_suffix(" | null)"))
+    {
+        Some(inner) if streaming.not_null && !r#type.is_optional() => inner.to_string(),
+        _ => type_ref,
+    };
+    if streaming.with_state {
+        format!("StreamState<{type_ref}>")
+    } else {
+        type_ref
+    }
+}
+
 pub fn type_name_for_checks(checks: &TypeCheckAttributes) -> String {
     checks.0.iter().map(|check| format!("\"{check}\"")).sorted().join(" | ")
 }
@@ -54,7 +54,7 @@ impl From<TypescriptClient> for SyncTypescriptClient {
 #[derive(Debug)]
 struct TypescriptFunction {
     name: String,
-    // partial_return_type: String,
+    partial_return_type: String,
     return_type: String,
     args: Vec<(String, bool, String)>,
 }
@@ -87,6 +87,10 @@ pub(crate) fn generate(
 ) -> Result<IndexMap<PathBuf, String>> {
     let mut collector = FileCollector::<TypescriptLanguageFeatures>::new();
     collector.add_template::<generate_types::TypescriptTypes>("types.ts", (ir, generator))?;
+    collector.add_template::<generate_types::TypescriptStreamTypes>(
+        "partial_types.ts",
+        (ir, generator),
+    )?;
     collector.add_template::<generate_types::TypeBuilder>("type_builder.ts", (ir, generator))?;
     collector.add_template::<AsyncTypescriptClient>("async_client.ts", (ir, generator))?;
     collector.add_template::<SyncTypescriptClient>("sync_client.ts", (ir, generator))?;
@@ -131,7 +135,10 @@ impl TryFrom<(&'_ IntermediateRepr, &'_ crate::GeneratorArgs)> for TypescriptCli
                         Ok(TypescriptFunction {
                             name: f.name().to_string(),
                             return_type: f.elem().output().to_type_ref(ir),
-                            // partial_return_type: f.elem().output().to_partial_type_ref(ir),
+                            partial_return_type: f
+                                .elem()
+                                .output()
+                                .to_partial_type_ref(ir, "partial_types."),
                             args: f
                                 .inputs()
                                 .iter()
@@ -157,6 +164,11 @@ impl TryFrom<(&'_ IntermediateRepr, &'_ crate::GeneratorArgs)> for TypescriptCli
             .walk_classes()
             .map(|c| c.name().to_string())
             .chain(ir.walk_enums().map(|e| e.name().to_string()))
+            .chain(
+                ir.walk_type_aliases()
+                    .filter(|a| a.is_recursive())
+                    .map(|a| a.name().to_string()),
+            )
             .collect();
         Ok(TypescriptClient {
             funcs: functions,
@@ -204,53 +216,64 @@ impl TryFrom<(&'_ IntermediateRepr, &'_ crate::GeneratorArgs)> for TypescriptIni
 trait ToTypeReferenceInClientDefinition {
     fn to_type_ref(&self, ir: &IntermediateRepr) -> String;
 
-    // fn to_partial_type_ref(&self, ir: &IntermediateRepr) -> String;
+    /// The type of the value while it's being streamed, with classes named
+    /// with `prefix`.
+    fn to_partial_type_ref(&self, ir: &IntermediateRepr, prefix: &str) -> String;
 }
 
 impl ToTypeReferenceInClientDefinition for FieldType {
-    // fn to_partial_type_ref(&self, ir: &IntermediateRepr) -> String {
-    //     match self {
-    //         FieldType::Enum(name) => {
-    //             if ir
-    //                 .find_enum(name)
-    //                 .map(|e| e.item.attributes.get("dynamic_type").is_some())
-    //                 .unwrap_or(false)
-    //             {
-    //                 format!("(string | {name} | null)")
-    //             } else {
-    //                 format!("({name} | null)")
-    //             }
-    //         }
-    //         FieldType::Class(name) => format!("(RecursivePartialNull<{name}>)"),
-    //         FieldType::List(inner) => format!("{}[]", inner.to_partial_type_ref(ir)),
-    //         FieldType::Map(key, value) => {
-    //             format!(
-    //                 "(Record<{}, {}> | null)",
-    //                 key.to_type_ref(ir),
-    //                 value.to_partial_type_ref(ir)
-    //             )
-    //         }
-    //         FieldType::Literal(value) => value.to_string(),
-    //         FieldType::Primitive(r#type) => format!("({} | null)", r#type.to_typescript()),
-    //         FieldType::Union(inner) => format!(
-    //             "({} | null)",
-    //             inner
-    //                 .iter()
-    //                 .map(|t| t.to_partial_type_ref(ir))
-    //                 .collect::<Vec<_>>()
-    //                 .join(" | ")
-    //         ),
-    //         FieldType::Tuple(inner) => format!(
-    //             "([{}] | null)",
-    //             inner
-    //                 .iter()
-    //                 .map(|t| t.to_partial_type_ref(ir))
-    //                 .collect::<Vec<_>>()
-    //                 .join(", ")
-    //         ),
-    //         FieldType::Optional(inner) => format!("({} | null)", inner.to_partial_type_ref(ir)),
-    //     }
-    // }
+    fn to_partial_type_ref(&self, ir: &IntermediateRepr, prefix: &str) -> String {
+        match self {
+            FieldType::Enum(name) => {
+                if ir
+                    .find_enum(name)
+                    .map(|e| e.item.attributes.get("dynamic_type").is_some())
+                    .unwrap_or(false)
+                {
+                    format!("(string | {name} | null)")
+                } else {
+                    format!("({name} | null)")
+                }
+            }
+            FieldType::Class(name) => format!("({prefix}{name} | null)"),
+            FieldType::RecursiveTypeAlias(name) => format!("({name} | null)"),
+            FieldType::List(inner) => format!("{}[]", inner.to_partial_type_ref(ir, prefix)),
+            FieldType::Map(key, value) => {
+                format!(
+                    "(Record<{}, {}> | null)",
+                    key.to_type_ref(ir),
+                    value.to_partial_type_ref(ir, prefix)
+                )
+            }
+            FieldType::Literal(value) => format!("({value} | null)"),
+            FieldType::Primitive(r#type) => format!("({} | null)", r#type.to_typescript()),
+            FieldType::Union(inner) => format!(
+                "({} | null)",
+                inner
+                    .iter()
+                    .map(|t| t.to_partial_type_ref(ir, prefix))
+                    .collect::<Vec<_>>()
+                    .join(" | ")
+            ),
+            FieldType::Tuple(inner) => format!(
+                "([{}] | null)",
+                inner
+                    .iter()
+                    .map(|t| t.to_partial_type_ref(ir, prefix))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            FieldType::Optional(inner) => inner.to_partial_type_ref(ir, prefix),
+            FieldType::Constrained { base, .. } => match field_type_attributes(self) {
+                Some(checks) => {
+                    let base_type_ref = base.to_partial_type_ref(ir, prefix);
+                    let checks_type_ref = type_name_for_checks(&checks);
+                    format!("Checked<{base_type_ref},{checks_type_ref}>")
+                }
+                None => base.to_partial_type_ref(ir, prefix),
+            },
+        }
+    }
 
     fn to_type_ref(&self, ir: &IntermediateRepr) -> String {
         match self {
@@ -265,7 +288,7 @@ impl ToTypeReferenceInClientDefinition for FieldType {
                     format!("{name}")
                 }
             }
-            FieldType::Class(name) => format!("{name}"),
+            FieldType::Class(name) | FieldType::RecursiveTypeAlias(name) => format!("{name}"),
             FieldType::List(inner) => match inner.as_ref() {
                 FieldType::Union(_) | FieldType::Optional(_) => {
                     format!("({})[]", inner.to_type_ref(ir))
@@ -38,6 +38,9 @@ impl ToTypescript for TypeValue {
             TypeValue::Int => "number",
             TypeValue::String => "string",
             TypeValue::Null => "null",
+            TypeValue::Date | TypeValue::DateTime => "Date",
+            // Durations are passed as a number of seconds.
+            TypeValue::Duration => "number",
             TypeValue::Media(BamlMediaType::Image) => "Image",
             TypeValue::Media(BamlMediaType::Audio) => "Audio",
         };
@@ -15,14 +15,17 @@ from .baml_py import (
     BamlAudioPy as Audio,
     invoke_runtime_cli,
     ClientRegistry,
+    Collector,
 )
 from .stream import BamlStream, BamlSyncStream
 from .ctx_manager import CtxManager as BamlCtxManager
 from .constraints import Check, Checked
+from .stream_state import StreamState
 
 __all__ = [
     "BamlRuntime",
     "ClientRegistry",
+    "Collector",
     "BamlStream",
     "BamlSyncStream",
     "BamlCtxManager",
@@ -1,4 +1,4 @@
-from typing import Any, Callable, Dict, Optional, Tuple
+from typing import Any, Callable, Dict, List, Optional, Tuple
 
 class FunctionResult:
     """The result of a BAML function call.
@@ -18,6 +18,8 @@ class FunctionResult:
     # Returns True if the function call was successful, False otherwise
     def is_ok(self) -> bool: ...
     def cast_to(self, enum_module: Any, class_module: Any) -> Any: ...
+    # Tokens used and their estimated cost, over every request made for this call
+    def usage(self) -> Usage: ...
 
     # This is a debug function that returns the internal representation of the response
     # This is not to be relied upon and is subject to change
@@ -40,6 +42,7 @@ class FunctionResultStream:
         self, on_event: Callable[[FunctionResult], None]
     ) -> FunctionResultStream: ...
     async def done(self, ctx: RuntimeContextManager) -> FunctionResult: ...
+    def cancel(self) -> None: ...
 
 class SyncFunctionResultStream:
     """The result of a BAML function stream.
@@ -54,6 +57,7 @@ class SyncFunctionResultStream:
         self, on_event: Callable[[FunctionResult], None]
     ) -> SyncFunctionResultStream: ...
     def done(self, ctx: RuntimeContextManager) -> FunctionResult: ...
+    def cancel(self) -> None: ...
 
 class BamlImagePy:
     @staticmethod
@@ -77,6 +81,7 @@ class BamlAudioPy:
 
 class RuntimeContextManager:
     def upsert_tags(self, tags: Dict[str, Any]) -> None: ...
+    def set_cache_mode(self, mode: Optional[str] = None) -> None: ...
     def deep_clone(self) -> RuntimeContextManager: ...
     def context_depth(self) -> int: ...
 
@@ -90,6 +95,7 @@ class BamlRuntime:
         ctx: RuntimeContextManager,
         tb: Optional[TypeBuilder],
         cr: Optional[ClientRegistry],
+        collector: Optional[Collector] = None,
     ) -> FunctionResult: ...
     @staticmethod
     def from_files(
@@ -106,6 +112,7 @@ class BamlRuntime:
         ctx: RuntimeContextManager,
         tb: Optional[TypeBuilder],
         cr: Optional[ClientRegistry],
+        collector: Optional[Collector] = None,
     ) -> FunctionResultStream: ...
     def stream_function_sync(
         self,
@@ -115,10 +122,13 @@ class BamlRuntime:
         ctx: RuntimeContextManager,
         tb: Optional[TypeBuilder],
         cr: Optional[ClientRegistry],
+        collector: Optional[Collector] = None,
     ) -> SyncFunctionResultStream: ...
     def create_context_manager(self) -> RuntimeContextManager: ...
     def flush(self) -> None: ...
     def drain_stats(self) -> TraceStats: ...
+    def usage(self) -> UsageReport: ...
+    def reset_usage(self) -> UsageReport: ...
     def set_log_event_callback(
         self, handler: Optional[Callable[[BamlLogEvent], None]]
     ) -> None: ...
@@ -162,6 +172,28 @@ class TraceStats:
     @property
     def done(self) -> int: ...
 
+class Usage:
+    @property
+    def requests(self) -> int: ...
+    @property
+    def input_tokens(self) -> int: ...
+    @property
+    def output_tokens(self) -> int: ...
+    @property
+    def cost(self) -> float: ...
+    @property
+    def unpriced_requests(self) -> int: ...
+
+class UsageReport:
+    @property
+    def total(self) -> Usage: ...
+    @property
+    def functions(self) -> Dict[str, Usage]: ...
+    @property
+    def clients(self) -> Dict[str, Usage]: ...
+    @property
+    def spans(self) -> Dict[str, Usage]: ...
+
 class BamlSpan:
     @staticmethod
     def new(
@@ -200,6 +232,17 @@ class ClientRegistry:
     ) -> None: ...
     def set_primary(self, name: str) -> None: ...
 
+class Collector:
+    """Logs every call it's passed to: each attempt's HTTP request and
+    response, streamed chunks, parse errors and retry sleeps."""
+
+    def __init__(self) -> None: ...
+    # Logs of the calls made with this collector, oldest first
+    def calls(self) -> List[Dict[str, Any]]: ...
+    # Log of the most recent call, if any
+    def last(self) -> Optional[Dict[str, Any]]: ...
+    def clear(self) -> None: ...
+
 class FieldType:
     def list(self) -> FieldType: ...
     def optional(self) -> FieldType: ...
@@ -244,3 +287,8 @@ class BamlClientHttpError(BamlClientError):
     """Raised for HTTP-related client errors."""
 
     ...
+
+class BamlCancelledError(BamlError):
+    """Raised when a stream is cancelled before it finishes."""
+
+    ...
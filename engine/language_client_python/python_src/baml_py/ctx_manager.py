@@ -66,6 +66,15 @@ class CtxManager:
         mngr = self.__ctx()
         mngr.upsert_tags(tags)
 
+    def set_cache_mode(
+        self,
+        mode: typing.Optional[
+            typing.Literal["read_write", "read_only", "refresh", "off"]
+        ] = None,
+    ) -> None:
+        mngr = self.__ctx()
+        mngr.set_cache_mode(mode)
+
     def get(self) -> RuntimeContextManager:
         return self.__ctx()
 
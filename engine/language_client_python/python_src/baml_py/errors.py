@@ -3,6 +3,7 @@ from .baml_py import (
     BamlClientError,
     BamlClientHttpError,
     BamlInvalidArgumentError,
+    BamlCancelledError,
 )
 from .internal_monkeypatch import BamlValidationError
 
@@ -13,4 +14,5 @@ __all__ = [
     "BamlClientHttpError",
     "BamlInvalidArgumentError",
     "BamlValidationError",
+    "BamlCancelledError",
 ]
@@ -69,16 +69,32 @@ class BamlStream(Generic[PartialOutputType, FinalOutputType]):
         # ensure that the caller is using an async for loop.
         # Eventually we do not want to create a new thread for each stream.
         self.__drive_to_completion_in_bg()
-        while True:
-            event = self.__event_queue.get()
-            if event is None:
-                break
-            if event.is_ok():
-                yield self.__partial_coerce(event)
+        try:
+            while True:
+                event = self.__event_queue.get()
+                if event is None:
+                    break
+                if event.is_ok():
+                    yield self.__partial_coerce(event)
+        finally:
+            # The consumer stopped early (break, cancellation, or the generator
+            # being closed), so nothing is left to read the rest of the stream.
+            if not self.__future.done():
+                self.cancel()
 
     async def get_final_response(self):
         final = self.__drive_to_completion_in_bg()
-        return self.__final_coerce((await asyncio.wrap_future(final)))
+        try:
+            return self.__final_coerce((await asyncio.wrap_future(final)))
+        except asyncio.CancelledError:
+            # The stream runs on its own thread, so it has to be told to stop.
+            self.cancel()
+            raise
+
+    def cancel(self) -> None:
+        """Stops the stream, closing its connection to the provider and
+        skipping any retries and fallbacks that are left."""
+        self.__ffi_stream.cancel()
 
 
 class BamlSyncStream(Generic[PartialOutputType, FinalOutputType]):
@@ -133,12 +149,17 @@ class BamlSyncStream(Generic[PartialOutputType, FinalOutputType]):
         # TODO: This is deliberately __iter__ and not __aiter__ because we want to
         # ensure that the caller is NOT using an async for loop.
         self.__drive_to_completion_in_bg()
-        while True:
-            event = self.__event_queue.get()
-            if event is None:
-                break
-            if event.is_ok():
-                yield self.__partial_coerce(event)
+        try:
+            while True:
+                event = self.__event_queue.get()
+                if event is None:
+                    break
+                if event.is_ok():
+                    yield self.__partial_coerce(event)
+        finally:
+            # Same as BamlStream: stop the stream if the consumer stopped early.
+            if self.__result is None and self.__exception is None:
+                self.cancel()
 
     def get_final_response(self):
         self.__drive_to_completion_in_bg()
@@ -154,3 +175,8 @@ class BamlSyncStream(Generic[PartialOutputType, FinalOutputType]):
             )
 
         return self.__final_coerce(self.__result)
+
+    def cancel(self) -> None:
+        """Stops the stream, closing its connection to the provider and
+        skipping any retries and fallbacks that are left."""
+        self.__ffi_stream.cancel()
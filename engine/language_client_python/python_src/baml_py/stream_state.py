@@ -0,0 +1,9 @@
+from typing import Generic, Literal, TypeVar
+from pydantic import BaseModel
+
+T = TypeVar('T')
+
+class StreamState(BaseModel, Generic[T]):
+    """A field marked @stream.with_state, as it stands in a partial result."""
+    value: T
+    state: Literal["Pending", "Incomplete", "Complete"]
@@ -10,6 +10,7 @@ create_exception!(baml_py, BamlError, pyo3::exceptions::PyException);
 create_exception!(baml_py, BamlInvalidArgumentError, BamlError);
 create_exception!(baml_py, BamlClientError, BamlError);
 create_exception!(baml_py, BamlClientHttpError, BamlClientError);
+create_exception!(baml_py, BamlCancelledError, BamlError);
 
 // Define the BamlValidationError exception with additional fields
 // can't use extends=PyException yet https://github.com/PyO3/pyo3/discussions/3838
@@ -47,6 +48,10 @@ pub fn errors(parent_module: &Bound<'_, PyModule>) -> PyResult<()> {
         "BamlClientHttpError",
         parent_module.py().get_type_bound::<BamlClientHttpError>(),
     )?;
+    parent_module.add(
+        "BamlCancelledError",
+        parent_module.py().get_type_bound::<BamlCancelledError>(),
+    )?;
 
     Ok(())
 }
@@ -64,6 +69,7 @@ impl BamlError {
                     // If not, you may need to adjust this part based on the actual structure of ValidationError
                     raise_baml_validation_error(prompt.clone(), message.clone(), raw_output.clone())
                 }
+                ExposedError::Cancelled => PyErr::new::<BamlCancelledError, _>(er.to_string()),
             }
         } else if let Some(er) = err.downcast_ref::<ScopeStack>() {
             PyErr::new::<BamlInvalidArgumentError, _>(format!("Invalid argument: {}", er))
@@ -73,7 +79,8 @@ impl BamlError {
                     PyErr::new::<BamlError, _>(format!("Unexpected error from BAML: {}", err))
                 }
                 LLMResponse::LLMFailure(failed) => match &failed.code {
-                    baml_runtime::internal::llm_client::ErrorCode::Other(2) => {
+                    baml_runtime::internal::llm_client::ErrorCode::Other(2)
+                    | baml_runtime::internal::llm_client::ErrorCode::Timeout => {
                         PyErr::new::<BamlClientError, _>(format!(
                             "Something went wrong with the LLM client: {}",
                             err
@@ -45,6 +45,9 @@ fn baml_py(m: Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<types::ClassPropertyBuilder>()?;
     m.add_class::<types::FieldType>()?;
     m.add_class::<types::ClientRegistry>()?;
+    m.add_class::<types::Collector>()?;
+    m.add_class::<types::Usage>()?;
+    m.add_class::<types::UsageReport>()?;
 
     m.add_class::<runtime::BamlLogEvent>()?;
     m.add_class::<runtime::LogEventMetadata>()?;
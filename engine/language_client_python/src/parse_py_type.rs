@@ -1,7 +1,8 @@
 use std::collections::HashMap;
 
 use anyhow::Result;
-use baml_types::{BamlMap, BamlValue};
+use baml_types::{temporal, BamlMap, BamlValue};
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate};
 use pyo3::{
     exceptions::{PyRuntimeError, PyTypeError},
     prelude::{PyAnyMethods, PyTypeMethods},
@@ -58,6 +59,9 @@ enum MappedPyType {
     Int(i64),
     Float(f64),
     Bool(bool),
+    Date(NaiveDate),
+    DateTime(DateTime<FixedOffset>),
+    Duration(Duration),
     None,
     BamlMedia(baml_types::BamlMedia),
     Unsupported(String),
@@ -191,6 +195,9 @@ where
         MappedPyType::Int(v) => BamlValue::Int(v),
         MappedPyType::Float(v) => BamlValue::Float(v),
         MappedPyType::Bool(v) => BamlValue::Bool(v),
+        MappedPyType::Date(v) => BamlValue::Date(v),
+        MappedPyType::DateTime(v) => BamlValue::DateTime(v),
+        MappedPyType::Duration(v) => BamlValue::Duration(v),
         MappedPyType::BamlMedia(media) => BamlValue::Media(media),
         MappedPyType::None => BamlValue::Null,
         MappedPyType::Unsupported(r#type) => {
@@ -215,6 +222,12 @@ pub fn parse_py_type(
         let base_model = py
             .import_bound("pydantic")
             .and_then(|m| m.getattr("BaseModel"))?;
+        let datetime_module = py.import_bound("datetime")?;
+        let (py_date, py_datetime, py_timedelta) = (
+            datetime_module.getattr("date")?,
+            datetime_module.getattr("datetime")?,
+            datetime_module.getattr("timedelta")?,
+        );
 
         let mut get_type = |py: Python<'_>,
                             any: PyObject,
@@ -286,6 +299,26 @@ pub fn parse_py_type(
                 // }
                 Ok(MappedPyType::Class(name, fields))
                 // use downcast only
+            } else if t.is_subclass(&py_datetime).unwrap_or(false) {
+                // Naive datetimes have no offset in their ISO form, and are
+                // taken to be UTC.
+                let iso = any.call_method0(py, "isoformat")?.extract::<String>(py)?;
+                match temporal::parse_datetime(&iso) {
+                    Some(datetime) => Ok(MappedPyType::DateTime(datetime)),
+                    None => Ok(MappedPyType::Unsupported(format!("datetime {iso}"))),
+                }
+            } else if t.is_subclass(&py_date).unwrap_or(false) {
+                // `datetime` subclasses `date`, so this has to come second.
+                let iso = any.call_method0(py, "isoformat")?.extract::<String>(py)?;
+                match temporal::parse_date(&iso) {
+                    Some(date) => Ok(MappedPyType::Date(date)),
+                    None => Ok(MappedPyType::Unsupported(format!("date {iso}"))),
+                }
+            } else if t.is_subclass(&py_timedelta).unwrap_or(false) {
+                let seconds = any.call_method0(py, "total_seconds")?.extract::<f64>(py)?;
+                Ok(MappedPyType::Duration(Duration::microseconds(
+                    (seconds * 1e6).round() as i64,
+                )))
             } else if let Ok(list) = any.downcast_bound::<PyList>(py) {
                 let mut items = vec![];
                 let len = list.len()?;
@@ -2,11 +2,13 @@ use crate::errors::{BamlError, BamlInvalidArgumentError};
 use crate::parse_py_type::parse_py_type;
 use crate::types::function_results::FunctionResult;
 use crate::types::trace_stats::TraceStats;
+use crate::types::usage::UsageReport;
 
 use crate::types::function_result_stream::{FunctionResultStream, SyncFunctionResultStream};
 use crate::types::runtime_ctx_manager::RuntimeContextManager;
 use crate::types::type_builder::TypeBuilder;
-use crate::types::ClientRegistry;
+use crate::types::{ClientRegistry, Collector};
+use baml_runtime::cancel::CancelToken;
 use baml_runtime::runtime_interface::ExperimentalTracingInterface;
 use baml_runtime::BamlRuntime as CoreBamlRuntime;
 use pyo3::prelude::{pymethods, PyResult};
@@ -113,7 +115,7 @@ impl BamlRuntime {
             .into()
     }
 
-    #[pyo3(signature = (function_name, args, ctx, tb, cb))]
+    #[pyo3(signature = (function_name, args, ctx, tb, cb, collector = None))]
     fn call_function(
         &self,
         py: Python<'_>,
@@ -122,6 +124,7 @@ impl BamlRuntime {
         ctx: &RuntimeContextManager,
         tb: Option<&TypeBuilder>,
         cb: Option<&ClientRegistry>,
+        collector: Option<&Collector>,
     ) -> PyResult<PyObject> {
         let Some(args) = parse_py_type(args.into_bound(py).to_object(py), false)? else {
             return Err(BamlInvalidArgumentError::new_err(
@@ -139,12 +142,31 @@ impl BamlRuntime {
         let ctx_mng = ctx.inner.clone();
         let tb = tb.map(|tb| tb.inner.clone());
         let cb = cb.map(|cb| cb.inner.clone());
+        let collector = collector.map(|c| c.inner.clone());
 
         pyo3_asyncio::tokio::future_into_py(py, async move {
-            let ctx_mng = ctx_mng;
-            let (result, _) = baml_runtime
-                .call_function(function_name, &args_map, &ctx_mng, tb.as_ref(), cb.as_ref())
-                .await;
+            // Cancelling the Python task drops this future. The call runs on a
+            // task of its own, so that it can wind down and end its span once
+            // the guard tells it to stop.
+            let cancel = CancelToken::new();
+            let _guard = CancelOnDrop(cancel.clone());
+            let call = tokio::spawn(async move {
+                let ctx_mng = ctx_mng;
+                baml_runtime
+                    .call_function(
+                        function_name,
+                        &args_map,
+                        &ctx_mng,
+                        tb.as_ref(),
+                        cb.as_ref(),
+                        collector.as_ref(),
+                        Some(&cancel),
+                    )
+                    .await
+            });
+            let (result, _) = call
+                .await
+                .map_err(|e| BamlError::new_err(format!("BAML call failed: {e}")))?;
 
             result
                 .map(FunctionResult::from)
@@ -153,7 +175,7 @@ impl BamlRuntime {
         .map(|f| f.into())
     }
 
-    #[pyo3(signature = (function_name, args, ctx, tb, cb))]
+    #[pyo3(signature = (function_name, args, ctx, tb, cb, collector = None))]
     fn call_function_sync(
         &self,
         function_name: String,
@@ -161,6 +183,7 @@ impl BamlRuntime {
         ctx: &RuntimeContextManager,
         tb: Option<&TypeBuilder>,
         cb: Option<&ClientRegistry>,
+        collector: Option<&Collector>,
     ) -> PyResult<FunctionResult> {
         let Some(args) = parse_py_type(args, false)? else {
             return Err(BamlInvalidArgumentError::new_err(
@@ -184,6 +207,8 @@ impl BamlRuntime {
             &ctx_mng,
             tb.as_ref(),
             cb.as_ref(),
+            collector.map(|c| &c.inner),
+            None,
         );
 
         result
@@ -191,7 +216,7 @@ impl BamlRuntime {
             .map_err(BamlError::from_anyhow)
     }
 
-    #[pyo3(signature = (function_name, args, on_event, ctx, tb, cb))]
+    #[pyo3(signature = (function_name, args, on_event, ctx, tb, cb, collector = None))]
     fn stream_function(
         &self,
         py: Python<'_>,
@@ -201,6 +226,7 @@ impl BamlRuntime {
         ctx: &RuntimeContextManager,
         tb: Option<&TypeBuilder>,
         cb: Option<&ClientRegistry>,
+        collector: Option<&Collector>,
     ) -> PyResult<FunctionResultStream> {
         let Some(args) = parse_py_type(args.into_bound(py).to_object(py), false)? else {
             return Err(BamlInvalidArgumentError::new_err(
@@ -221,6 +247,8 @@ impl BamlRuntime {
                 &ctx,
                 tb.map(|tb| tb.inner.clone()).as_ref(),
                 cb.map(|cb| cb.inner.clone()).as_ref(),
+                collector.map(|c| &c.inner),
+                None,
             )
             .map_err(BamlError::from_anyhow)?;
 
@@ -232,7 +260,7 @@ impl BamlRuntime {
         ))
     }
 
-    #[pyo3(signature = (function_name, args, on_event, ctx, tb, cb))]
+    #[pyo3(signature = (function_name, args, on_event, ctx, tb, cb, collector = None))]
     fn stream_function_sync(
         &self,
         py: Python<'_>,
@@ -242,6 +270,7 @@ impl BamlRuntime {
         ctx: &RuntimeContextManager,
         tb: Option<&TypeBuilder>,
         cb: Option<&ClientRegistry>,
+        collector: Option<&Collector>,
     ) -> PyResult<SyncFunctionResultStream> {
         let Some(args) = parse_py_type(args.into_bound(py).to_object(py), false)? else {
             return Err(BamlInvalidArgumentError::new_err(
@@ -262,6 +291,8 @@ impl BamlRuntime {
                 &ctx,
                 tb.map(|tb| tb.inner.clone()).as_ref(),
                 cb.map(|cb| cb.inner.clone()).as_ref(),
+                collector.map(|c| &c.inner),
+                None,
             )
             .map_err(BamlError::from_anyhow)?;
 
@@ -283,6 +314,16 @@ impl BamlRuntime {
         self.inner.drain_stats().into()
     }
 
+    #[pyo3()]
+    fn usage(&self) -> UsageReport {
+        self.inner.usage().into()
+    }
+
+    #[pyo3()]
+    fn reset_usage(&self) -> UsageReport {
+        self.inner.reset_usage().into()
+    }
+
     #[pyo3()]
     fn set_log_event_callback(&self, callback: Option<PyObject>) -> PyResult<()> {
         let callback = callback.clone();
@@ -324,3 +365,12 @@ impl BamlRuntime {
         }
     }
 }
+
+/// Cancels its token when dropped.
+struct CancelOnDrop(CancelToken);
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        self.0.cancel();
+    }
+}
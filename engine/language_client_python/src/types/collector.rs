@@ -0,0 +1,30 @@
+use baml_runtime::collector;
+use pyo3::prelude::{pymethods, PyResult};
+use pyo3::{PyObject, Python};
+use pythonize::pythonize;
+
+crate::lang_wrapper!(Collector, collector::Collector);
+
+#[pymethods]
+impl Collector {
+    #[new]
+    pub fn new() -> Self {
+        Self {
+            inner: collector::Collector::new(),
+        }
+    }
+
+    /// Logs of the calls made with this collector, oldest first
+    pub fn calls(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(pythonize(py, &self.inner.calls())?)
+    }
+
+    /// Log of the most recent call, if any
+    pub fn last(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(pythonize(py, &self.inner.last())?)
+    }
+
+    pub fn clear(&self) {
+        self.inner.clear()
+    }
+}
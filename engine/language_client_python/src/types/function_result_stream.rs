@@ -11,7 +11,8 @@ crate::lang_wrapper!(
     baml_runtime::FunctionResultStream, thread_safe,
     on_event: Option<PyObject>,
     tb: Option<baml_runtime::type_builder::TypeBuilder>,
-    cb: Option<baml_runtime::client_registry::ClientRegistry>
+    cb: Option<baml_runtime::client_registry::ClientRegistry>,
+    cancel: baml_runtime::cancel::CancelToken
 );
 
 crate::lang_wrapper!(
@@ -19,7 +20,8 @@ crate::lang_wrapper!(
     baml_runtime::FunctionResultStream, sync_thread_safe,
     on_event: Option<PyObject>,
     tb: Option<baml_runtime::type_builder::TypeBuilder>,
-    cb: Option<baml_runtime::client_registry::ClientRegistry>
+    cb: Option<baml_runtime::client_registry::ClientRegistry>,
+    cancel: baml_runtime::cancel::CancelToken
 );
 
 impl FunctionResultStream {
@@ -30,6 +32,7 @@ impl FunctionResultStream {
         cb: Option<baml_runtime::client_registry::ClientRegistry>,
     ) -> Self {
         Self {
+            cancel: inner.cancel_token(),
             inner: std::sync::Arc::new(tokio::sync::Mutex::new(inner)),
             on_event: event,
             tb,
@@ -46,6 +49,7 @@ impl SyncFunctionResultStream {
         cb: Option<baml_runtime::client_registry::ClientRegistry>,
     ) -> Self {
         Self {
+            cancel: inner.cancel_token(),
             inner: std::sync::Arc::new(std::sync::Mutex::new(inner)),
             on_event: event,
             tb,
@@ -101,6 +105,12 @@ impl FunctionResultStream {
         })
         .map(|f| f.into())
     }
+
+    /// Stops the stream, which makes `done` raise a `BamlCancelledError`
+    /// unless the stream had already finished.
+    fn cancel(&self) {
+        self.cancel.cancel();
+    }
 }
 
 #[pymethods]
@@ -147,4 +157,10 @@ impl SyncFunctionResultStream {
             .map_err(BamlError::from_anyhow)
             .map(|f| f.into())
     }
+
+    /// Stops the stream, which makes `done` raise a `BamlCancelledError`
+    /// unless the stream had already finished.
+    fn cancel(&self) {
+        self.cancel.cancel();
+    }
 }
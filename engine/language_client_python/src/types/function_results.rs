@@ -1,11 +1,12 @@
 use baml_types::{BamlValueWithMeta, ResponseCheck};
+use chrono::{Datelike, Duration, Timelike};
 use pyo3::prelude::{pymethods, PyResult};
 use pyo3::types::{PyAnyMethods, PyDict, PyModule, PyTuple, PyType};
 use pyo3::{Bound, IntoPy, PyObject, Python};
 
 use crate::errors::BamlError;
 
-use super::{BamlAudioPy, BamlImagePy};
+use super::{BamlAudioPy, BamlImagePy, Usage};
 
 crate::lang_wrapper!(FunctionResult, baml_runtime::FunctionResult);
 
@@ -19,6 +20,12 @@ impl FunctionResult {
         self.inner.result_with_constraints_content().is_ok()
     }
 
+    /// Tokens used and their estimated cost, over every request made for
+    /// this call
+    fn usage(&self) -> Usage {
+        self.inner.usage().into()
+    }
+
     /// This is a debug function that returns the internal representation of the response
     /// This is not to be relied upon and is subject to change
     fn unstable_internal_repr(&self) -> String {
@@ -77,6 +84,38 @@ fn pythonize_strict(
         BamlValueWithMeta::Int(val, _) => Ok(val.into_py(py)),
         BamlValueWithMeta::Float(val, _) => Ok(val.into_py(py)),
         BamlValueWithMeta::Bool(val, _) => Ok(val.into_py(py)),
+        BamlValueWithMeta::Date(val, _) => {
+            let date = py.import_bound("datetime")?.getattr("date")?;
+            Ok(date.call1((val.year(), val.month(), val.day()))?.into())
+        }
+        BamlValueWithMeta::DateTime(val, _) => {
+            let datetime = py.import_bound("datetime")?;
+            let offset = datetime
+                .getattr("timedelta")?
+                .call1((0, val.offset().local_minus_utc()))?;
+            let tzinfo = datetime.getattr("timezone")?.call1((offset,))?;
+            Ok(datetime
+                .getattr("datetime")?
+                .call1((
+                    val.year(),
+                    val.month(),
+                    val.day(),
+                    val.hour(),
+                    val.minute(),
+                    val.second(),
+                    val.timestamp_subsec_micros(),
+                    tzinfo,
+                ))?
+                .into())
+        }
+        BamlValueWithMeta::Duration(val, _) => {
+            let timedelta = py.import_bound("datetime")?.getattr("timedelta")?;
+            let seconds = val.num_seconds();
+            let micros = (val - Duration::seconds(seconds))
+                .num_microseconds()
+                .unwrap_or(0);
+            Ok(timedelta.call1((0, seconds, micros))?.into())
+        }
         BamlValueWithMeta::Map(index_map, _) => {
             let dict = pyo3::types::PyDict::new_bound(py);
             for (key, value) in index_map {
@@ -1,8 +1,10 @@
 mod lang_wrapper;
 
 pub use client_registry::ClientRegistry;
+pub use collector::Collector;
 pub(crate) mod audio;
 pub(crate) mod client_registry;
+pub(crate) mod collector;
 pub(crate) mod function_result_stream;
 pub(crate) mod function_results;
 pub(crate) mod image;
@@ -11,6 +13,7 @@ pub(crate) mod runtime_ctx_manager;
 pub(crate) mod span;
 pub(crate) mod trace_stats;
 pub(crate) mod type_builder;
+pub(crate) mod usage;
 
 pub use audio::BamlAudioPy;
 pub use function_result_stream::{FunctionResultStream, SyncFunctionResultStream};
@@ -20,3 +23,4 @@ pub use image::BamlImagePy;
 pub use runtime_ctx_manager::RuntimeContextManager;
 pub use span::BamlSpan;
 pub use type_builder::*;
+pub use usage::{Usage, UsageReport};
@@ -21,6 +21,16 @@ impl RuntimeContextManager {
         Ok(true)
     }
 
+    #[pyo3(signature = (mode = None))]
+    fn set_cache_mode(&self, mode: Option<String>) -> PyResult<()> {
+        let mode = mode
+            .map(|m| m.parse::<baml_runtime::CacheMode>())
+            .transpose()
+            .map_err(|e| BamlError::new_err(e.to_string()))?;
+        self.inner.set_cache_mode(mode);
+        Ok(())
+    }
+
     #[pyo3()]
     fn deep_clone(&self) -> Self {
         RuntimeContextManager {
@@ -0,0 +1,86 @@
+use std::collections::BTreeMap;
+
+use pyo3::pymethods;
+
+crate::lang_wrapper!(Usage, baml_runtime::Usage);
+
+#[pymethods]
+impl Usage {
+    #[getter]
+    pub fn requests(&self) -> u64 {
+        self.inner.requests
+    }
+
+    #[getter]
+    pub fn input_tokens(&self) -> u64 {
+        self.inner.input_tokens
+    }
+
+    #[getter]
+    pub fn output_tokens(&self) -> u64 {
+        self.inner.output_tokens
+    }
+
+    #[getter]
+    pub fn cost(&self) -> f64 {
+        self.inner.cost
+    }
+
+    #[getter]
+    pub fn unpriced_requests(&self) -> u64 {
+        self.inner.unpriced_requests
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "Usage(requests={}, input_tokens={}, output_tokens={}, cost={}, unpriced_requests={})",
+            self.requests(),
+            self.input_tokens(),
+            self.output_tokens(),
+            self.cost(),
+            self.unpriced_requests()
+        )
+    }
+}
+
+crate::lang_wrapper!(UsageReport, baml_runtime::UsageReport);
+
+fn wrap(usage: &BTreeMap<String, baml_runtime::Usage>) -> BTreeMap<String, Usage> {
+    usage
+        .iter()
+        .map(|(name, usage)| (name.clone(), usage.clone().into()))
+        .collect()
+}
+
+#[pymethods]
+impl UsageReport {
+    #[getter]
+    pub fn total(&self) -> Usage {
+        self.inner.total.clone().into()
+    }
+
+    #[getter]
+    pub fn functions(&self) -> BTreeMap<String, Usage> {
+        wrap(&self.inner.functions)
+    }
+
+    #[getter]
+    pub fn clients(&self) -> BTreeMap<String, Usage> {
+        wrap(&self.inner.clients)
+    }
+
+    #[getter]
+    pub fn spans(&self) -> BTreeMap<String, Usage> {
+        wrap(&self.inner.spans)
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "UsageReport(total={}, functions={}, clients={}, spans={})",
+            self.total().__repr__(),
+            self.inner.functions.len(),
+            self.inner.clients.len(),
+            self.inner.spans.len()
+        )
+    }
+}
@@ -53,6 +53,12 @@ impl FunctionResult {
         }
     }
 
+    /// Tokens used and their estimated cost, over every request made for this
+    /// call.
+    pub fn usage(&self) -> Result<Value> {
+        serde_magnus::serialize(&self.inner.usage())
+    }
+
     /// For usage in magnus::init
     ///
     /// TODO: use traits and macros to implement this
@@ -63,6 +69,7 @@ impl FunctionResult {
             "parsed_using_types",
             method!(FunctionResult::parsed_using_types, 1),
         )?;
+        cls.define_method("usage", method!(FunctionResult::usage, 0))?;
 
         Ok(())
     }
@@ -1,6 +1,8 @@
 use baml_runtime::BamlRuntime;
 use baml_types::BamlValue;
-use magnus::{class, function, method, prelude::*, Error, RHash, Ruby};
+use magnus::{
+    class, function, method, prelude::*, scan_args::scan_args, Error, RHash, Ruby, Value,
+};
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -33,6 +35,36 @@ impl Drop for BamlRuntimeFfi {
     }
 }
 
+/// The arguments of `call_function` and `stream_function`. The collector and
+/// the cancel token are optional, so that clients generated before they were
+/// added keep working.
+struct CallArgs<'a> {
+    function_name: String,
+    args: RHash,
+    ctx: &'a RuntimeContextManager,
+    type_registry: Option<&'a types::type_builder::TypeBuilder>,
+    client_registry: Option<&'a types::client_registry::ClientRegistry>,
+    collector: Option<&'a types::collector::Collector>,
+    cancel_token: Option<&'a types::cancel_token::CancelToken>,
+}
+
+impl CallArgs<'_> {
+    fn scan(args: &[Value]) -> Result<Self> {
+        let args = scan_args::<_, _, (), (), (), ()>(args)?;
+        let (function_name, args, ctx, type_registry, client_registry) = args.required;
+        let (collector, cancel_token): (Option<Option<_>>, Option<Option<_>>) = args.optional;
+        Ok(CallArgs {
+            function_name,
+            args,
+            ctx,
+            type_registry,
+            client_registry,
+            collector: collector.flatten(),
+            cancel_token: cancel_token.flatten(),
+        })
+    }
+}
+
 impl BamlRuntimeFfi {
     fn make_tokio_runtime(ruby: &Ruby) -> Result<tokio::runtime::Runtime> {
         // NB: libruby will panic if called from a non-Ruby thread, so we stick to the current thread
@@ -103,15 +135,30 @@ impl BamlRuntimeFfi {
         }
     }
 
+    /// Tokens used and their estimated cost, as a hash with `total`, and the
+    /// usage by `functions`, `clients` and `spans`.
+    pub fn usage(&self) -> Result<magnus::Value> {
+        serde_magnus::serialize(&self.inner.usage())
+    }
+
+    pub fn reset_usage(&self) -> Result<magnus::Value> {
+        serde_magnus::serialize(&self.inner.reset_usage())
+    }
+
     pub fn call_function(
         ruby: &Ruby,
         rb_self: &BamlRuntimeFfi,
-        function_name: String,
-        args: RHash,
-        ctx: &RuntimeContextManager,
-        type_registry: Option<&types::type_builder::TypeBuilder>,
-        client_registry: Option<&types::client_registry::ClientRegistry>,
+        args: &[Value],
     ) -> Result<FunctionResult> {
+        let CallArgs {
+            function_name,
+            args,
+            ctx,
+            type_registry,
+            client_registry,
+            collector,
+            cancel_token,
+        } = CallArgs::scan(args)?;
         let args = match ruby_to_json::RubyToJson::convert_hash_to_json(args) {
             Ok(args) => args.into_iter().collect(),
             Err(e) => {
@@ -128,6 +175,8 @@ impl BamlRuntimeFfi {
             &ctx.inner,
             type_registry.map(|t| &t.inner),
             client_registry.map(|c| c.inner.borrow_mut()).as_deref(),
+            collector.map(|c| &c.inner),
+            cancel_token.map(|c| &c.inner),
         )) {
             (Ok(res), _) => Ok(FunctionResult::new(res)),
             (Err(e), _) => Err(Error::new(
@@ -145,12 +194,17 @@ impl BamlRuntimeFfi {
     fn stream_function(
         ruby: &Ruby,
         rb_self: &BamlRuntimeFfi,
-        function_name: String,
-        args: RHash,
-        ctx: &RuntimeContextManager,
-        type_registry: Option<&types::type_builder::TypeBuilder>,
-        client_registry: Option<&types::client_registry::ClientRegistry>,
+        args: &[Value],
     ) -> Result<FunctionResultStream> {
+        let CallArgs {
+            function_name,
+            args,
+            ctx,
+            type_registry,
+            client_registry,
+            collector,
+            cancel_token,
+        } = CallArgs::scan(args)?;
         let args = match ruby_to_json::RubyToJson::convert_hash_to_json(args) {
             Ok(args) => args.into_iter().collect(),
             Err(e) => {
@@ -169,6 +223,8 @@ impl BamlRuntimeFfi {
             &ctx.inner,
             type_registry.map(|t| &t.inner),
             client_registry.map(|c| c.inner.borrow_mut()).as_deref(),
+            collector.map(|c| &c.inner),
+            cancel_token.map(|c| &c.inner),
         ) {
             Ok(res) => Ok(FunctionResultStream::new(res, rb_self.t.clone())),
             Err(e) => Err(Error::new(
@@ -225,10 +281,12 @@ fn init(ruby: &Ruby) -> Result<()> {
         "create_context_manager",
         method!(BamlRuntimeFfi::create_context_manager, 0),
     )?;
-    runtime_class.define_method("call_function", method!(BamlRuntimeFfi::call_function, 5))?;
+    runtime_class.define_method("call_function", method!(BamlRuntimeFfi::call_function, -1))?;
+    runtime_class.define_method("usage", method!(BamlRuntimeFfi::usage, 0))?;
+    runtime_class.define_method("reset_usage", method!(BamlRuntimeFfi::reset_usage, 0))?;
     runtime_class.define_method(
         "stream_function",
-        method!(BamlRuntimeFfi::stream_function, 5),
+        method!(BamlRuntimeFfi::stream_function, -1),
     )?;
 
     FunctionResult::define_in_ruby(&module)?;
@@ -244,6 +302,8 @@ fn init(ruby: &Ruby) -> Result<()> {
     types::type_builder::FieldType::define_in_ruby(&module)?;
 
     types::client_registry::ClientRegistry::define_in_ruby(&module)?;
+    types::collector::Collector::define_in_ruby(&module)?;
+    types::cancel_token::CancelToken::define_in_ruby(&module)?;
     types::media::Audio::define_in_ruby(&module)?;
     types::media::Image::define_in_ruby(&module)?;
 
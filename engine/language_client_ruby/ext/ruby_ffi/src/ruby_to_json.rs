@@ -1,4 +1,5 @@
-use baml_types::{BamlValue, BamlMap, BamlValueWithMeta, ResponseCheck};
+use baml_types::{temporal, BamlMap, BamlValue, BamlValueWithMeta, ResponseCheck, STREAM_STATE_CLASS};
+use chrono::{Datelike, Timelike};
 use indexmap::IndexMap;
 use magnus::{
     prelude::*, typed_data::Obj, value::Value, class, Error, Float, Integer, IntoValue, RArray, RClass,
@@ -77,6 +78,10 @@ impl<'rb> RubyToJson<'rb> {
                     }
                     match types.const_get::<_, RClass>(class_name.as_str()) {
                         Ok(class_type) => class_type.funcall("new", (hash,)),
+                        Err(_) if class_name == STREAM_STATE_CLASS => {
+                            let stream_state_type = ruby.eval::<RClass>("Baml::StreamState")?;
+                            stream_state_type.funcall("new", (hash,))
+                        }
                         Err(_) => {
                             let dynamic_class_type = ruby.eval::<RClass>("Baml::DynamicStruct")?;
                             dynamic_class_type.funcall("new", (hash,))
@@ -110,6 +115,32 @@ impl<'rb> RubyToJson<'rb> {
                     }
                     Ok(arr.into_value_with(ruby))
                 }
+                BamlValueWithMeta::Date(date, _) => {
+                    let date_class = ruby.eval::<RClass>("Date")?;
+                    date_class.funcall("iso8601", (temporal::format_date(&date),))
+                }
+                BamlValueWithMeta::DateTime(datetime, _) => {
+                    let seconds =
+                        datetime.second() as f64 + datetime.nanosecond() as f64 / 1_000_000_000.0;
+                    let offset = datetime.format("%:z").to_string();
+                    ruby.class_time().funcall(
+                        "new",
+                        (
+                            datetime.year(),
+                            datetime.month(),
+                            datetime.day(),
+                            datetime.hour(),
+                            datetime.minute(),
+                            seconds,
+                            offset,
+                        ),
+                    )
+                }
+                // Durations are a number of seconds, like the difference of two `Time`s.
+                BamlValueWithMeta::Duration(duration, _) => {
+                    let seconds = duration.num_milliseconds() as f64 / 1000.0;
+                    Ok(ruby.float_from_f64(seconds).into_value_with(ruby))
+                }
             _ => serde_magnus::serialize(&from),
             }
 
@@ -223,6 +254,21 @@ impl<'rb> RubyToJson<'rb> {
             }
         }
 
+        // Matched by name, since `date` may not be loaded and `DateTime` is a
+        // subclass of `Date`.
+        let class_name = unsafe { any.class().name() }.to_string();
+        if class_name == "Time" || class_name == "DateTime" {
+            return self.to_temporal(any, field_pos, |s| {
+                temporal::parse_datetime(s).map(BamlValue::DateTime)
+            });
+        }
+
+        if class_name == "Date" {
+            return self.to_temporal(any, field_pos, |s| {
+                temporal::parse_date(s).map(BamlValue::Date)
+            });
+        }
+
         if self.is_type::<Audio>(any) {
             return self.to_type::<Audio>(any, field_pos);
         }
@@ -547,6 +593,34 @@ impl<'rb> RubyToJson<'rb> {
         }
     }
 
+    /// Converts a `Date`, `DateTime` or `Time` through its `iso8601` form.
+    fn to_temporal(
+        &self,
+        any: Value,
+        field_pos: Vec<String>,
+        parse: impl FnOnce(&str) -> Option<BamlValue>,
+    ) -> Result<BamlValue, Vec<SerializationError>> {
+        let iso = match any.funcall::<_, _, String>("iso8601", (9,)) {
+            Ok(iso) => iso,
+            // `Date#iso8601` takes no arguments.
+            Err(_) => match any.funcall::<_, _, String>("iso8601", ()) {
+                Ok(iso) => iso,
+                Err(e) => {
+                    return Err(vec![SerializationError {
+                        position: field_pos,
+                        message: format!("failed to format {} as ISO 8601: {e}", any.class()),
+                    }]);
+                }
+            },
+        };
+        parse(&iso).ok_or_else(|| {
+            vec![SerializationError {
+                position: field_pos,
+                message: format!("failed to parse {iso} as ISO 8601"),
+            }]
+        })
+    }
+
     fn sorbet_to_json(
         &self,
         any: Value,
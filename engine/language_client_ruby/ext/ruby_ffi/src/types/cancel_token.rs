@@ -0,0 +1,36 @@
+use baml_runtime::cancel;
+use magnus::{class, function, method, Module, Object};
+
+use crate::Result;
+
+#[magnus::wrap(class = "Baml::Ffi::CancelToken", free_immediately, size)]
+pub(crate) struct CancelToken {
+    pub(crate) inner: cancel::CancelToken,
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self {
+            inner: cancel::CancelToken::new(),
+        }
+    }
+
+    /// Stops the calls and streams this token was passed to.
+    pub fn cancel(&self) {
+        self.inner.cancel();
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.is_cancelled()
+    }
+
+    pub fn define_in_ruby(module: &magnus::RModule) -> Result<()> {
+        let cls = module.define_class("CancelToken", class::object())?;
+
+        cls.define_singleton_method("new", function!(CancelToken::new, 0))?;
+        cls.define_method("cancel", method!(CancelToken::cancel, 0))?;
+        cls.define_method("cancelled?", method!(CancelToken::is_cancelled, 0))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use baml_runtime::collector;
+use magnus::{class, function, method, Module, Object};
+
+use crate::Result;
+
+#[magnus::wrap(class = "Baml::Ffi::Collector", free_immediately, size)]
+pub(crate) struct Collector {
+    pub(crate) inner: collector::Collector,
+}
+
+impl Collector {
+    pub fn new() -> Self {
+        Self {
+            inner: collector::Collector::new(),
+        }
+    }
+
+    /// Every call made with this collector, oldest first, as hashes.
+    pub fn calls(&self) -> Result<magnus::Value> {
+        serde_magnus::serialize(&self.inner.calls())
+    }
+
+    pub fn last(&self) -> Result<magnus::Value> {
+        serde_magnus::serialize(&self.inner.last())
+    }
+
+    pub fn clear(&self) {
+        self.inner.clear();
+    }
+
+    pub fn define_in_ruby(module: &magnus::RModule) -> Result<()> {
+        let cls = module.define_class("Collector", class::object())?;
+
+        cls.define_singleton_method("new", function!(Collector::new, 0))?;
+        cls.define_method("calls", method!(Collector::calls, 0))?;
+        cls.define_method("last", method!(Collector::last, 0))?;
+        cls.define_method("clear", method!(Collector::clear, 0))?;
+
+        Ok(())
+    }
+}
@@ -1,4 +1,6 @@
+pub(crate) mod cancel_token;
 pub(crate) mod client_registry;
+pub(crate) mod collector;
 mod lang_wrapper;
 pub(crate) mod media;
 pub(crate) mod runtime_ctx_manager;
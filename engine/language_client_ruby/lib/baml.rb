@@ -8,9 +8,12 @@ end
 require_relative "stream"
 require_relative "struct"
 require_relative "checked"
+require_relative "stream_state"
 
 module Baml
   ClientRegistry = Baml::Ffi::ClientRegistry
+  Collector = Baml::Ffi::Collector
+  CancelToken = Baml::Ffi::CancelToken
   Image = Baml::Ffi::Image
   Audio = Baml::Ffi::Audio
 
@@ -0,0 +1,20 @@
+require "sorbet-runtime"
+
+module Baml
+  # A field marked @stream.with_state, as it stands in a partial result.
+  class StreamState < T::Struct
+    extend T::Sig
+
+    extend T::Generic
+
+    Value = type_member
+
+    const :value, Value
+    # One of "Pending", "Incomplete" or "Complete".
+    const :state, String
+
+    def initialize(props)
+      super(value: props[:value], state: props[:state])
+    end
+  end
+end
@@ -6,6 +6,7 @@ export declare class BamlCtxManager {
     allowResets(): boolean;
     reset(): void;
     upsertTags(tags: Record<string, string>): void;
+    setCacheMode(mode?: 'read_write' | 'read_only' | 'refresh' | 'off'): void;
     cloneContext(): RuntimeContextManager;
     startTrace(name: string, args: Record<string, any>): [RuntimeContextManager, BamlSpan];
     endTrace(span: BamlSpan, response: any): void;
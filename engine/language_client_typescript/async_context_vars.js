@@ -32,6 +32,10 @@ class BamlCtxManager {
         const manager = this.ctx.getStore();
         manager.upsertTags(tags);
     }
+    setCacheMode(mode) {
+        const manager = this.ctx.getStore();
+        manager.setCacheMode(mode);
+    }
     cloneContext() {
         let store = this.ctx.getStore();
         if (store === undefined) {
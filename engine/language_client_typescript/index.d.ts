@@ -1,7 +1,8 @@
-export { BamlRuntime, FunctionResult, FunctionResultStream, BamlImage as Image, ClientBuilder, BamlAudio as Audio, invoke_runtime_cli, ClientRegistry, BamlLogEvent, } from './native';
+export { BamlRuntime, FunctionResult, FunctionResultStream, BamlImage as Image, ClientBuilder, BamlAudio as Audio, invoke_runtime_cli, ClientRegistry, Collector, CancelToken, BamlLogEvent, } from './native';
 export { BamlStream } from './stream';
 export { BamlCtxManager } from './async_context_vars';
 export { Checked } from './checked';
+export { StreamState } from './stream_state';
 export declare class BamlValidationError extends Error {
     prompt: string;
     raw_output: string;
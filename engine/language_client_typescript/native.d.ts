@@ -23,13 +23,15 @@ export declare class BamlRuntime {
   static fromFiles(rootPath: string, files: Record<string, string>, envVars: Record<string, string>): BamlRuntime
   reset(rootPath: string, files: Record<string, string>, envVars: Record<string, string>): void
   createContextManager(): RuntimeContextManager
-  callFunction(functionName: string, args: { [string]: any }, ctx: RuntimeContextManager, tb?: TypeBuilder | undefined | null, cb?: ClientRegistry | undefined | null): Promise<FunctionResult>
-  callFunctionSync(functionName: string, args: { [string]: any }, ctx: RuntimeContextManager, tb?: TypeBuilder | undefined | null, cb?: ClientRegistry | undefined | null): FunctionResult
-  streamFunction(functionName: string, args: { [string]: any }, cb: ((err: any, param: FunctionResult) => void) | undefined, ctx: RuntimeContextManager, tb?: TypeBuilder | undefined | null, clientRegistry?: ClientRegistry | undefined | null): FunctionResultStream
-  streamFunctionSync(functionName: string, args: { [string]: any }, cb: ((err: any, param: FunctionResult) => void) | undefined, ctx: RuntimeContextManager, tb?: TypeBuilder | undefined | null, clientRegistry?: ClientRegistry | undefined | null): FunctionResultStream
+  callFunction(functionName: string, args: { [string]: any }, ctx: RuntimeContextManager, tb?: TypeBuilder | undefined | null, cb?: ClientRegistry | undefined | null, collector?: Collector | undefined | null, cancelToken?: CancelToken | undefined | null): Promise<FunctionResult>
+  callFunctionSync(functionName: string, args: { [string]: any }, ctx: RuntimeContextManager, tb?: TypeBuilder | undefined | null, cb?: ClientRegistry | undefined | null, collector?: Collector | undefined | null): FunctionResult
+  streamFunction(functionName: string, args: { [string]: any }, cb: ((err: any, param: FunctionResult) => void) | undefined, ctx: RuntimeContextManager, tb?: TypeBuilder | undefined | null, clientRegistry?: ClientRegistry | undefined | null, collector?: Collector | undefined | null, cancelToken?: CancelToken | undefined | null): FunctionResultStream
+  streamFunctionSync(functionName: string, args: { [string]: any }, cb: ((err: any, param: FunctionResult) => void) | undefined, ctx: RuntimeContextManager, tb?: TypeBuilder | undefined | null, clientRegistry?: ClientRegistry | undefined | null, collector?: Collector | undefined | null, cancelToken?: CancelToken | undefined | null): FunctionResultStream
   setLogEventCallback(func?: undefined | ((err: any, param: BamlLogEvent) => void)): void
   flush(): void
   drainStats(): TraceStats
+  usage(): UsageReport
+  resetUsage(): UsageReport
 }
 
 export declare class BamlSpan {
@@ -37,6 +39,13 @@ export declare class BamlSpan {
   finish(result: any, ctx: RuntimeContextManager): any
 }
 
+export declare class CancelToken {
+  constructor()
+  /** Stops the calls and streams this token was passed to. */
+  cancel(): void
+  isCancelled(): boolean
+}
+
 export declare class ClassBuilder {
   field(): FieldType
   property(name: string): ClassPropertyBuilder
@@ -54,6 +63,14 @@ export declare class ClientRegistry {
   setPrimary(primary: string): void
 }
 
+export declare class Collector {
+  constructor()
+  /** Every call made with this collector, oldest first. */
+  calls(): any[]
+  last(): any | null
+  clear(): void
+}
+
 export declare class EnumBuilder {
   value(name: string): EnumValueBuilder
   alias(alias?: string | undefined | null): EnumBuilder
@@ -73,16 +90,27 @@ export declare class FieldType {
 
 export declare class FunctionResult {
   isOk(): boolean
+  /**
+   * Tokens used and their estimated cost, over every request made for
+   * this call
+   */
+  usage(): Usage
   parsed(): any
 }
 
 export declare class FunctionResultStream {
   onEvent(func: (err: any, param: FunctionResult) => void): void
   done(rctx: RuntimeContextManager): Promise<FunctionResult>
+  /**
+   * Stops the stream, which makes `done` reject unless the stream had
+   * already finished.
+   */
+  cancel(): void
 }
 
 export declare class RuntimeContextManager {
   upsertTags(tags: any): void
+  setCacheMode(mode?: string | undefined | null): void
   deepClone(): RuntimeContextManager
   contextDepth(): number
 }
@@ -131,3 +159,18 @@ export interface LogEventMetadata {
   rootEventId: string
 }
 
+export interface Usage {
+  requests: number
+  inputTokens: number
+  outputTokens: number
+  /** Estimated cost in dollars of the requests with a known price */
+  cost: number
+  unpricedRequests: number
+}
+
+export interface UsageReport {
+  total: Usage
+  functions: Record<string, Usage>
+  clients: Record<string, Usage>
+  spans: Record<string, Usage>
+}
@@ -365,9 +365,11 @@ module.exports.BamlAudio = nativeBinding.BamlAudio
 module.exports.BamlImage = nativeBinding.BamlImage
 module.exports.BamlRuntime = nativeBinding.BamlRuntime
 module.exports.BamlSpan = nativeBinding.BamlSpan
+module.exports.CancelToken = nativeBinding.CancelToken
 module.exports.ClassBuilder = nativeBinding.ClassBuilder
 module.exports.ClassPropertyBuilder = nativeBinding.ClassPropertyBuilder
 module.exports.ClientRegistry = nativeBinding.ClientRegistry
+module.exports.Collector = nativeBinding.Collector
 module.exports.EnumBuilder = nativeBinding.EnumBuilder
 module.exports.EnumValueBuilder = nativeBinding.EnumValueBuilder
 module.exports.FieldType = nativeBinding.FieldType
@@ -20,6 +20,10 @@ pub fn from_anyhow_error(err: anyhow::Error) -> napi::Error {
                 message,
                 raw_output: raw_response,
             } => throw_baml_validation_error(prompt, raw_response, message),
+            ExposedError::Cancelled => napi::Error::new(
+                napi::Status::Cancelled,
+                format!("BamlError: BamlCancelledError: {}", er),
+            ),
         }
     } else if let Some(er) = err.downcast_ref::<ScopeStack>() {
         invalid_argument_error(&format!("{}", er))
@@ -30,7 +34,8 @@ pub fn from_anyhow_error(err: anyhow::Error) -> napi::Error {
                 format!("BamlError: Unexpected error from BAML: {}", err),
             ),
             LLMResponse::LLMFailure(failed) => match &failed.code {
-                baml_runtime::internal::llm_client::ErrorCode::Other(2) => napi::Error::new(
+                baml_runtime::internal::llm_client::ErrorCode::Other(2)
+                | baml_runtime::internal::llm_client::ErrorCode::Timeout => napi::Error::new(
                     napi::Status::GenericFailure,
                     format!(
                         "BamlError: BamlClientError: Something went wrong with the LLM client: {}",
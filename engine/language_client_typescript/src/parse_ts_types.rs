@@ -106,8 +106,12 @@ pub fn js_object_to_baml_value(env: Env, kwargs: JsObject) -> napi::Result<BamlV
     } else if kwargs.is_date()? {
         let date: JsDate = unsafe { kwargs.into_unknown().cast() };
         let timestamp = date.value_of()?;
-        // TODO: Convert timestamp to a DateTime
-        Ok(BamlValue::Float(timestamp))
+        match chrono::DateTime::from_timestamp_millis(timestamp as i64) {
+            Some(datetime) => Ok(BamlValue::DateTime(datetime.into())),
+            None => Err(napi::Error::from_reason(format!(
+                "Date out of range: {timestamp}"
+            ))),
+        }
     } else {
         let mut args = BamlMap::new();
 
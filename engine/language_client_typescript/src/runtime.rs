@@ -1,11 +1,14 @@
 use crate::errors::{from_anyhow_error, invalid_argument_error};
 use crate::parse_ts_types;
+use crate::types::cancel_token::CancelToken;
 use crate::types::client_registry::ClientRegistry;
+use crate::types::collector::Collector;
 use crate::types::function_result_stream::FunctionResultStream;
 use crate::types::function_results::FunctionResult;
 use crate::types::runtime_ctx_manager::RuntimeContextManager;
 use crate::types::trace_stats::TraceStats;
 use crate::types::type_builder::TypeBuilder;
+use crate::types::usage::UsageReport;
 use baml_runtime::on_log_event::LogEvent;
 use baml_runtime::runtime_interface::ExperimentalTracingInterface;
 use baml_runtime::BamlRuntime as CoreRuntime;
@@ -99,6 +102,8 @@ impl BamlRuntime {
         ctx: &RuntimeContextManager,
         tb: Option<&TypeBuilder>,
         cb: Option<&ClientRegistry>,
+        collector: Option<&Collector>,
+        cancel_token: Option<&CancelToken>,
     ) -> napi::Result<JsObject> {
         let args = parse_ts_types::js_object_to_baml_value(env, args)?;
 
@@ -114,10 +119,20 @@ impl BamlRuntime {
         let ctx_mng = ctx.inner.clone();
         let tb = tb.map(|tb| tb.inner.clone());
         let cb = cb.map(|cb| cb.inner.clone());
+        let collector = collector.map(|c| c.inner.clone());
+        let cancel_token = cancel_token.map(|c| c.inner.clone());
 
         let fut = async move {
             let result = baml_runtime
-                .call_function(function_name, &args_map, &ctx_mng, tb.as_ref(), cb.as_ref())
+                .call_function(
+                    function_name,
+                    &args_map,
+                    &ctx_mng,
+                    tb.as_ref(),
+                    cb.as_ref(),
+                    collector.as_deref(),
+                    cancel_token.as_deref(),
+                )
                 .await;
 
             result
@@ -138,6 +153,7 @@ impl BamlRuntime {
         ctx: &RuntimeContextManager,
         tb: Option<&TypeBuilder>,
         cb: Option<&ClientRegistry>,
+        collector: Option<&Collector>,
     ) -> napi::Result<FunctionResult> {
         let args = parse_ts_types::js_object_to_baml_value(env, args)?;
 
@@ -158,6 +174,8 @@ impl BamlRuntime {
             &ctx_mng,
             tb.as_ref(),
             cb.as_ref(),
+            collector.map(|c| c.inner.as_ref()),
+            None,
         );
 
         result
@@ -177,6 +195,8 @@ impl BamlRuntime {
         ctx: &RuntimeContextManager,
         tb: Option<&TypeBuilder>,
         client_registry: Option<&ClientRegistry>,
+        collector: Option<&Collector>,
+        cancel_token: Option<&CancelToken>,
     ) -> napi::Result<FunctionResultStream> {
         let args: BamlValue = parse_ts_types::js_object_to_baml_value(env, args)?;
         if !args.is_map() {
@@ -198,6 +218,8 @@ impl BamlRuntime {
                 &ctx,
                 tb.as_ref(),
                 client_registry.as_ref(),
+                collector.map(|c| c.inner.as_ref()),
+                cancel_token.map(|c| c.inner.as_ref()),
             )
             .map_err(|e| from_anyhow_error(e))?;
 
@@ -221,6 +243,8 @@ impl BamlRuntime {
         ctx: &RuntimeContextManager,
         tb: Option<&TypeBuilder>,
         client_registry: Option<&ClientRegistry>,
+        collector: Option<&Collector>,
+        cancel_token: Option<&CancelToken>,
     ) -> napi::Result<FunctionResultStream> {
         let args: BamlValue = parse_ts_types::js_object_to_baml_value(env, args)?;
         if !args.is_map() {
@@ -242,6 +266,8 @@ impl BamlRuntime {
                 &ctx,
                 tb.as_ref(),
                 client_registry.as_ref(),
+                collector.map(|c| c.inner.as_ref()),
+                cancel_token.map(|c| c.inner.as_ref()),
             )
             .map_err(|e| from_anyhow_error(e))?;
 
@@ -354,6 +380,16 @@ impl BamlRuntime {
     pub fn drain_stats(&self) -> TraceStats {
         self.inner.drain_stats().into()
     }
+
+    #[napi]
+    pub fn usage(&self) -> UsageReport {
+        self.inner.usage().into()
+    }
+
+    #[napi]
+    pub fn reset_usage(&self) -> UsageReport {
+        self.inner.reset_usage().into()
+    }
 }
 
 impl ObjectFinalize for BamlRuntime {
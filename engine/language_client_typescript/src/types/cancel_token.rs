@@ -0,0 +1,25 @@
+use baml_runtime::cancel;
+use napi_derive::napi;
+
+crate::lang_wrapper!(CancelToken, cancel::CancelToken);
+
+#[napi]
+impl CancelToken {
+    #[napi(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: cancel::CancelToken::new().into(),
+        }
+    }
+
+    /// Stops the calls and streams this token was passed to.
+    #[napi]
+    pub fn cancel(&self) {
+        self.inner.cancel();
+    }
+
+    #[napi]
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.is_cancelled()
+    }
+}
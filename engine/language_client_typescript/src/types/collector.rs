@@ -0,0 +1,32 @@
+use baml_runtime::collector;
+use napi_derive::napi;
+
+use crate::errors::from_anyhow_error;
+
+crate::lang_wrapper!(Collector, collector::Collector);
+
+#[napi]
+impl Collector {
+    #[napi(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: collector::Collector::new().into(),
+        }
+    }
+
+    /// Every call made with this collector, oldest first.
+    #[napi(ts_return_type = "any[]")]
+    pub fn calls(&self) -> napi::Result<serde_json::Value> {
+        serde_json::to_value(self.inner.calls()).map_err(|e| from_anyhow_error(e.into()))
+    }
+
+    #[napi(ts_return_type = "any | null")]
+    pub fn last(&self) -> napi::Result<serde_json::Value> {
+        serde_json::to_value(self.inner.last()).map_err(|e| from_anyhow_error(e.into()))
+    }
+
+    #[napi]
+    pub fn clear(&self) {
+        self.inner.clear();
+    }
+}
@@ -17,7 +17,8 @@ crate::lang_wrapper!(
     thread_safe,
     callback: Option<napi::Ref<()>>,
     tb: Option<baml_runtime::type_builder::TypeBuilder>,
-    cb: Option<baml_runtime::client_registry::ClientRegistry>
+    cb: Option<baml_runtime::client_registry::ClientRegistry>,
+    cancel: baml_runtime::cancel::CancelToken
 );
 
 impl FunctionResultStream {
@@ -28,6 +29,7 @@ impl FunctionResultStream {
         cb: Option<baml_runtime::client_registry::ClientRegistry>,
     ) -> Self {
         Self {
+            cancel: inner.cancel_token(),
             inner: std::sync::Arc::new(tokio::sync::Mutex::new(inner)),
             callback: event,
             tb,
@@ -96,6 +98,13 @@ impl FunctionResultStream {
 
         env.execute_tokio_future(fut, |&mut _, data| Ok(data))
     }
+
+    /// Stops the stream, which makes `done` reject unless the stream had
+    /// already finished.
+    #[napi]
+    pub fn cancel(&self) {
+        self.cancel.cancel();
+    }
 }
 
 impl ObjectFinalize for FunctionResultStream {
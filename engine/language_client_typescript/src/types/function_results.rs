@@ -1,7 +1,14 @@
+use std::collections::HashMap;
+
+use baml_types::{BamlValueWithMeta, ResponseCheck};
+use chrono::NaiveTime;
+use napi::{Env, JsUnknown};
 use napi_derive::napi;
 
 use crate::errors::from_anyhow_error;
 
+use super::usage::Usage;
+
 crate::lang_wrapper!(FunctionResult, baml_runtime::FunctionResult);
 
 #[napi]
@@ -15,13 +22,75 @@ impl FunctionResult {
         self.inner.result_with_constraints_content().is_ok()
     }
 
+    /// Tokens used and their estimated cost, over every request made for
+    /// this call
+    #[napi]
+    pub fn usage(&self) -> Usage {
+        (&self.inner.usage()).into()
+    }
+
     #[napi]
-    pub fn parsed(&self) -> napi::Result<serde_json::Value> {
+    pub fn parsed(&self, env: Env) -> napi::Result<JsUnknown> {
         let parsed = self
             .inner
             .result_with_constraints_content()
             .map_err(|e| from_anyhow_error(e))?;
 
-        Ok(serde_json::to_value(parsed)?)
+        to_js_value(&env, parsed)
     }
 }
+
+/// Converts a parsed value the same way as its JSON form, except that dates
+/// and datetimes become `Date`s and durations a number of seconds.
+fn to_js_value(
+    env: &Env,
+    value: &BamlValueWithMeta<Vec<ResponseCheck>>,
+) -> napi::Result<JsUnknown> {
+    let js_value = match value {
+        BamlValueWithMeta::Date(date, _) => {
+            let millis = date.and_time(NaiveTime::MIN).and_utc().timestamp_millis();
+            env.create_date(millis as f64)?.into_unknown()
+        }
+        BamlValueWithMeta::DateTime(datetime, _) => env
+            .create_date(datetime.timestamp_millis() as f64)?
+            .into_unknown(),
+        BamlValueWithMeta::Duration(duration, _) => env
+            .create_double(duration.num_milliseconds() as f64 / 1000.0)?
+            .into_unknown(),
+        BamlValueWithMeta::List(items, _) => {
+            let mut array = env.create_array_with_length(items.len())?;
+            for (idx, item) in items.iter().enumerate() {
+                array.set_element(idx as u32, to_js_value(env, item)?)?;
+            }
+            array.into_unknown()
+        }
+        BamlValueWithMeta::Map(fields, checks) | BamlValueWithMeta::Class(_, fields, checks) => {
+            let mut object = env.create_object()?;
+            for (key, field) in fields {
+                object.set_named_property(key, to_js_value(env, field)?)?;
+            }
+            if !checks.is_empty() {
+                object.set_named_property("checks", checks_to_js_value(env, checks)?)?;
+            }
+            return Ok(object.into_unknown());
+        }
+        other => return env.to_js_value(other),
+    };
+
+    let checks = value.meta();
+    if checks.is_empty() {
+        return Ok(js_value);
+    }
+    let mut object = env.create_object()?;
+    object.set_named_property("value", js_value)?;
+    object.set_named_property("checks", checks_to_js_value(env, checks)?)?;
+    Ok(object.into_unknown())
+}
+
+fn checks_to_js_value(env: &Env, checks: &[ResponseCheck]) -> napi::Result<JsUnknown> {
+    let by_name = checks
+        .iter()
+        .map(|check| (check.name.as_str(), check))
+        .collect::<HashMap<_, _>>();
+    env.to_js_value(&by_name)
+}
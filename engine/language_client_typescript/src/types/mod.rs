@@ -1,7 +1,9 @@
 mod lang_wrappers;
 
 pub mod audio;
+pub(crate) mod cancel_token;
 pub(crate) mod client_registry;
+pub(crate) mod collector;
 pub(crate) mod function_result_stream;
 pub(crate) mod function_results;
 pub mod image;
@@ -9,3 +11,4 @@ pub(crate) mod runtime_ctx_manager;
 pub(crate) mod span;
 pub(crate) mod trace_stats;
 pub(crate) mod type_builder;
+pub(crate) mod usage;
@@ -23,6 +23,19 @@ impl RuntimeContextManager {
         Ok(())
     }
 
+    #[napi]
+    pub fn set_cache_mode(&self, mode: Option<String>) -> napi::Result<()> {
+        let mode = match mode
+            .map(|m| m.parse::<baml_runtime::CacheMode>())
+            .transpose()
+        {
+            Ok(mode) => mode,
+            Err(e) => return Err(invalid_argument_error(&e.to_string())),
+        };
+        self.inner.set_cache_mode(mode);
+        Ok(())
+    }
+
     #[napi]
     pub fn deep_clone(&self) -> Self {
         RuntimeContextManager {
@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+
+use napi_derive::napi;
+
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct Usage {
+    pub requests: i64,
+    pub input_tokens: i64,
+    pub output_tokens: i64,
+    /// Estimated cost in dollars of the requests with a known price
+    pub cost: f64,
+    pub unpriced_requests: i64,
+}
+
+impl From<&baml_runtime::Usage> for Usage {
+    fn from(usage: &baml_runtime::Usage) -> Self {
+        Self {
+            requests: usage.requests as i64,
+            input_tokens: usage.input_tokens as i64,
+            output_tokens: usage.output_tokens as i64,
+            cost: usage.cost,
+            unpriced_requests: usage.unpriced_requests as i64,
+        }
+    }
+}
+
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct UsageReport {
+    pub total: Usage,
+    pub functions: HashMap<String, Usage>,
+    pub clients: HashMap<String, Usage>,
+    pub spans: HashMap<String, Usage>,
+}
+
+impl From<baml_runtime::UsageReport> for UsageReport {
+    fn from(report: baml_runtime::UsageReport) -> Self {
+        let wrap = |usage: &std::collections::BTreeMap<String, baml_runtime::Usage>| {
+            usage
+                .iter()
+                .map(|(name, usage)| (name.clone(), usage.into()))
+                .collect()
+        };
+        Self {
+            total: (&report.total).into(),
+            functions: wrap(&report.functions),
+            clients: wrap(&report.clients),
+            spans: wrap(&report.spans),
+        }
+    }
+}
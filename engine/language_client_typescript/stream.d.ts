@@ -4,12 +4,18 @@ export declare class BamlStream<PartialOutputType, FinalOutputType> {
     private partialCoerce;
     private finalCoerce;
     private ctxManager;
+    private onDone;
     private task;
     private eventQueue;
-    constructor(ffiStream: FunctionResultStream, partialCoerce: (result: FunctionResult) => PartialOutputType, finalCoerce: (result: FunctionResult) => FinalOutputType, ctxManager: RuntimeContextManager);
+    constructor(ffiStream: FunctionResultStream, partialCoerce: (result: FunctionResult) => PartialOutputType, finalCoerce: (result: FunctionResult) => FinalOutputType, ctxManager: RuntimeContextManager, onDone?: () => void);
     private driveToCompletion;
     private driveToCompletionInBg;
     [Symbol.asyncIterator](): AsyncIterableIterator<PartialOutputType>;
     getFinalResponse(): Promise<FinalOutputType>;
+    /**
+     * Stops the stream, closing its connection to the provider and skipping
+     * any retries and fallbacks that are left.
+     */
+    cancel(): void;
 }
 //# sourceMappingURL=stream.d.ts.map
\ No newline at end of file
@@ -6,13 +6,17 @@ class BamlStream {
     partialCoerce;
     finalCoerce;
     ctxManager;
+    onDone;
     task = null;
     eventQueue = [];
-    constructor(ffiStream, partialCoerce, finalCoerce, ctxManager) {
+    constructor(ffiStream, partialCoerce, finalCoerce, ctxManager, 
+    // Called once the stream is over, however it ended.
+    onDone = () => { }) {
         this.ffiStream = ffiStream;
         this.partialCoerce = partialCoerce;
         this.finalCoerce = finalCoerce;
         this.ctxManager = ctxManager;
+        this.onDone = onDone;
     }
     async driveToCompletion() {
         try {
@@ -29,6 +33,7 @@ class BamlStream {
         }
         finally {
             this.eventQueue.push(null);
+            this.onDone();
         }
     }
     driveToCompletionInBg() {
@@ -57,5 +62,12 @@ class BamlStream {
         const final = await this.driveToCompletionInBg();
         return this.finalCoerce(final.parsed());
     }
+    /**
+     * Stops the stream, closing its connection to the provider and skipping
+     * any retries and fallbacks that are left.
+     */
+    cancel() {
+        this.ffiStream.cancel();
+    }
 }
 exports.BamlStream = BamlStream;
@@ -36,6 +36,11 @@ export class BamlCtxManager {
     manager.upsertTags(tags)
   }
 
+  setCacheMode(mode?: 'read_write' | 'read_only' | 'refresh' | 'off'): void {
+    const manager = this.ctx.getStore()!
+    manager.setCacheMode(mode)
+  }
+
   cloneContext(): RuntimeContextManager {
     let store = this.ctx.getStore()
     if (store === undefined) {
@@ -7,11 +7,14 @@ export {
   BamlAudio as Audio,
   invoke_runtime_cli,
   ClientRegistry,
+  Collector,
+  CancelToken,
   BamlLogEvent,
 } from './native'
 export { BamlStream } from './stream'
 export { BamlCtxManager } from './async_context_vars'
 export { Checked } from './checked'
+export { StreamState } from './stream_state'
 
 export class BamlValidationError extends Error {
   prompt: string
@@ -10,6 +10,8 @@ export class BamlStream<PartialOutputType, FinalOutputType> {
     private partialCoerce: (result: FunctionResult) => PartialOutputType,
     private finalCoerce: (result: FunctionResult) => FinalOutputType,
     private ctxManager: RuntimeContextManager,
+    // Called once the stream is over, however it ended.
+    private onDone: () => void = () => {},
   ) {}
 
   private async driveToCompletion(): Promise<FunctionResult> {
@@ -26,6 +28,7 @@ export class BamlStream<PartialOutputType, FinalOutputType> {
       return retval
     } finally {
       this.eventQueue.push(null)
+      this.onDone()
     }
   }
 
@@ -63,4 +66,12 @@ export class BamlStream<PartialOutputType, FinalOutputType> {
 
     return this.finalCoerce(final.parsed())
   }
+
+  /**
+   * Stops the stream, closing its connection to the provider and skipping
+   * any retries and fallbacks that are left.
+   */
+  cancel(): void {
+    this.ffiStream.cancel()
+  }
 }
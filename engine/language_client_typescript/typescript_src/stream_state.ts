@@ -0,0 +1,4 @@
+export interface StreamState<T> {
+    value: T,
+    state: "Pending" | "Incomplete" | "Complete"
+}
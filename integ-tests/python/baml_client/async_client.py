@@ -15,6 +15,7 @@
 # fmt: off
 from typing import Any, Dict, List, Optional, TypeVar, Union, TypedDict, Type, Literal, cast
 from typing_extensions import NotRequired
+import datetime
 import pprint
 
 import baml_py
@@ -32,6 +33,7 @@ OutputType = TypeVar('OutputType')
 class BamlCallOptions(TypedDict, total=False):
     tb: NotRequired[TypeBuilder]
     client_registry: NotRequired[baml_py.baml_py.ClientRegistry]
+    collector: NotRequired[baml_py.baml_py.Collector]
 
 class BamlAsyncClient:
     __runtime: baml_py.BamlRuntime
@@ -60,6 +62,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "AaaSamOutputFormat",
@@ -69,6 +72,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.Recipe, raw.cast_to(types, types))
     
@@ -83,6 +87,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "AliasedInputClass",
@@ -92,6 +97,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -106,6 +112,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "AliasedInputClass2",
@@ -115,6 +122,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -129,6 +137,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "AliasedInputClassNested",
@@ -138,6 +147,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -152,6 +162,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "AliasedInputEnum",
@@ -161,6 +172,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -175,6 +187,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "AliasedInputList",
@@ -184,6 +197,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -198,6 +212,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "AudioInput",
@@ -207,6 +222,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -221,6 +237,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "ClassifyDynEnumTwo",
@@ -230,6 +247,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(Union[types.DynEnumTwo, str], raw.cast_to(types, types))
     
@@ -244,6 +262,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "ClassifyMessage",
@@ -253,6 +272,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.Category, raw.cast_to(types, types))
     
@@ -267,6 +287,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "ClassifyMessage2",
@@ -276,6 +297,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.Category, raw.cast_to(types, types))
     
@@ -290,6 +312,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "ClassifyMessage3",
@@ -299,6 +322,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.Category, raw.cast_to(types, types))
     
@@ -313,6 +337,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "CustomTask",
@@ -322,6 +347,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(Union[types.BookOrder, types.FlightConfirmation, types.GroceryReceipt], raw.cast_to(types, types))
     
@@ -336,6 +362,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "DescribeImage",
@@ -345,6 +372,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -359,6 +387,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "DescribeImage2",
@@ -368,6 +397,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -382,6 +412,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "DescribeImage3",
@@ -391,6 +422,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -405,6 +437,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "DescribeImage4",
@@ -414,6 +447,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -428,6 +462,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "DifferentiateUnions",
@@ -437,6 +472,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(Union[types.OriginalA, types.OriginalB], raw.cast_to(types, types))
     
@@ -451,6 +487,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "DummyOutputFunction",
@@ -460,6 +497,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.DummyOutput, raw.cast_to(types, types))
     
@@ -474,6 +512,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "DynamicFunc",
@@ -483,6 +522,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.DynamicClassTwo, raw.cast_to(types, types))
     
@@ -497,6 +537,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "DynamicInputOutput",
@@ -506,6 +547,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.DynInputOutput, raw.cast_to(types, types))
     
@@ -520,6 +562,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "DynamicListInputOutput",
@@ -529,6 +572,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(List[types.DynInputOutput], raw.cast_to(types, types))
     
@@ -543,6 +587,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "ExpectFailure",
@@ -552,6 +597,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -566,6 +612,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "ExtractContactInfo",
@@ -575,6 +622,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.ContactInfo, raw.cast_to(types, types))
     
@@ -589,6 +637,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "ExtractHobby",
@@ -598,6 +647,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(List[Union[types.Hobby, str]], raw.cast_to(types, types))
     
@@ -612,6 +662,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "ExtractNames",
@@ -621,6 +672,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(List[str], raw.cast_to(types, types))
     
@@ -635,6 +687,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "ExtractPeople",
@@ -644,6 +697,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(List[types.Person], raw.cast_to(types, types))
     
@@ -658,6 +712,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "ExtractReceiptInfo",
@@ -667,6 +722,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.ReceiptInfo, raw.cast_to(types, types))
     
@@ -681,6 +737,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "ExtractResume",
@@ -690,6 +747,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.Resume, raw.cast_to(types, types))
     
@@ -704,6 +762,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "ExtractResume2",
@@ -713,6 +772,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.Resume, raw.cast_to(types, types))
     
@@ -727,6 +787,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "FnClassOptionalOutput",
@@ -736,6 +797,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(Optional[types.ClassOptionalOutput], raw.cast_to(types, types))
     
@@ -750,6 +812,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "FnClassOptionalOutput2",
@@ -759,6 +822,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(Optional[types.ClassOptionalOutput2], raw.cast_to(types, types))
     
@@ -773,6 +837,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "FnEnumListOutput",
@@ -782,6 +847,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(List[types.EnumOutput], raw.cast_to(types, types))
     
@@ -796,6 +862,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "FnEnumOutput",
@@ -805,6 +872,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.EnumOutput, raw.cast_to(types, types))
     
@@ -819,6 +887,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "FnNamedArgsSingleStringOptional",
@@ -828,6 +897,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -842,6 +912,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "FnOutputBool",
@@ -851,6 +922,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(bool, raw.cast_to(types, types))
     
@@ -865,6 +937,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "FnOutputClass",
@@ -874,6 +947,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.TestOutputClass, raw.cast_to(types, types))
     
@@ -888,6 +962,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "FnOutputClassList",
@@ -897,6 +972,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(List[types.TestOutputClass], raw.cast_to(types, types))
     
@@ -911,6 +987,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "FnOutputClassNested",
@@ -920,6 +997,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.TestClassNested, raw.cast_to(types, types))
     
@@ -934,6 +1012,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "FnOutputClassWithEnum",
@@ -943,6 +1022,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.TestClassWithEnum, raw.cast_to(types, types))
     
@@ -957,6 +1037,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "FnOutputStringList",
@@ -966,6 +1047,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(List[str], raw.cast_to(types, types))
     
@@ -980,6 +1062,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "FnTestAliasedEnumOutput",
@@ -989,6 +1072,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.TestEnum, raw.cast_to(types, types))
     
@@ -1003,6 +1087,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "FnTestClassAlias",
@@ -1012,6 +1097,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.TestClassAlias, raw.cast_to(types, types))
     
@@ -1026,6 +1112,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "FnTestNamedArgsSingleEnum",
@@ -1035,6 +1122,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1049,6 +1137,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "GetDataType",
@@ -1058,6 +1147,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.RaysData, raw.cast_to(types, types))
     
@@ -1072,6 +1162,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "GetOrderInfo",
@@ -1081,6 +1172,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.OrderInfo, raw.cast_to(types, types))
     
@@ -1095,6 +1187,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "GetQuery",
@@ -1104,6 +1197,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.SearchParams, raw.cast_to(types, types))
     
@@ -1118,6 +1212,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "MyFunc",
@@ -1127,6 +1222,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.DynamicOutput, raw.cast_to(types, types))
     
@@ -1141,6 +1237,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "OptionalTest_Function",
@@ -1150,6 +1247,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(List[Optional[types.OptionalTest_ReturnType]], raw.cast_to(types, types))
     
@@ -1164,6 +1262,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "PredictAge",
@@ -1173,6 +1272,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.FooAny, raw.cast_to(types, types))
     
@@ -1187,6 +1287,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "PredictAgeBare",
@@ -1196,6 +1297,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(baml_py.Checked[int,types.Literal["too_big"]], raw.cast_to(types, types))
     
@@ -1210,6 +1312,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "PromptTestClaude",
@@ -1219,6 +1322,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1233,6 +1337,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "PromptTestClaudeChat",
@@ -1242,6 +1347,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1256,6 +1362,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "PromptTestClaudeChatNoSystem",
@@ -1265,6 +1372,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1279,6 +1387,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "PromptTestOpenAI",
@@ -1288,6 +1397,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1302,6 +1412,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "PromptTestOpenAIChat",
@@ -1311,6 +1422,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1325,6 +1437,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "PromptTestOpenAIChatNoSystem",
@@ -1334,6 +1447,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1348,6 +1462,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "PromptTestStreaming",
@@ -1357,6 +1472,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1371,6 +1487,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "ReturnFailingAssert",
@@ -1380,6 +1497,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(int, raw.cast_to(types, types))
     
@@ -1394,6 +1512,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "ReturnMalformedConstraints",
@@ -1403,6 +1522,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.MalformedConstraints, raw.cast_to(types, types))
     
@@ -1417,6 +1537,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "SchemaDescriptions",
@@ -1426,6 +1547,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.Schema, raw.cast_to(types, types))
     
@@ -1440,6 +1562,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "StreamBigNumbers",
@@ -1449,6 +1572,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.BigNumbers, raw.cast_to(types, types))
     
@@ -1463,6 +1587,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "StreamFailingAssertion",
@@ -1472,6 +1597,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.TwoStoriesOneTitle, raw.cast_to(types, types))
     
@@ -1486,6 +1612,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "StreamOneBigNumber",
@@ -1495,6 +1622,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(int, raw.cast_to(types, types))
     
@@ -1509,6 +1637,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "StreamUnionIntegers",
@@ -1518,6 +1647,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(List[Union[int, str]], raw.cast_to(types, types))
     
@@ -1532,6 +1662,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "StreamingCompoundNumbers",
@@ -1541,6 +1672,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.CompoundBigNumbers, raw.cast_to(types, types))
     
@@ -1555,6 +1687,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestAnthropic",
@@ -1564,6 +1697,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1578,6 +1712,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestAnthropicShorthand",
@@ -1587,6 +1722,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1601,6 +1737,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestAws",
@@ -1610,6 +1747,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1624,6 +1762,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestAzure",
@@ -1633,6 +1772,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1647,6 +1787,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestCaching",
@@ -1656,6 +1797,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1670,6 +1812,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestFallbackClient",
@@ -1679,6 +1822,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1693,6 +1837,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestFallbackToShorthand",
@@ -1702,6 +1847,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1716,6 +1862,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestFnNamedArgsSingleBool",
@@ -1725,6 +1872,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1739,6 +1887,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestFnNamedArgsSingleClass",
@@ -1748,6 +1897,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1762,6 +1912,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestFnNamedArgsSingleEnumList",
@@ -1771,6 +1922,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1785,6 +1937,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestFnNamedArgsSingleFloat",
@@ -1794,6 +1947,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1808,6 +1962,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestFnNamedArgsSingleInt",
@@ -1817,6 +1972,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1831,6 +1987,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestFnNamedArgsSingleMapStringToClass",
@@ -1840,6 +1997,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(Dict[str, types.StringToClassEntry], raw.cast_to(types, types))
     
@@ -1854,6 +2012,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestFnNamedArgsSingleMapStringToMap",
@@ -1863,6 +2022,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(Dict[str, Dict[str, str]], raw.cast_to(types, types))
     
@@ -1877,6 +2037,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestFnNamedArgsSingleMapStringToString",
@@ -1886,6 +2047,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(Dict[str, str], raw.cast_to(types, types))
     
@@ -1900,6 +2062,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestFnNamedArgsSingleString",
@@ -1909,6 +2072,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1923,6 +2087,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestFnNamedArgsSingleStringArray",
@@ -1932,6 +2097,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1946,6 +2112,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestFnNamedArgsSingleStringList",
@@ -1955,6 +2122,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1969,6 +2137,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestGemini",
@@ -1978,6 +2147,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1992,6 +2162,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestImageInput",
@@ -2001,6 +2172,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -2015,6 +2187,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestImageInputAnthropic",
@@ -2024,6 +2197,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -2038,6 +2212,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestImageListInput",
@@ -2047,6 +2222,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -2061,6 +2237,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestMulticlassNamedArgs",
@@ -2070,6 +2247,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -2084,6 +2262,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestOllama",
@@ -2093,6 +2272,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -2107,6 +2287,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestOpenAILegacyProvider",
@@ -2116,6 +2297,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -2130,6 +2312,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestOpenAIShorthand",
@@ -2139,6 +2322,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -2153,6 +2337,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestRetryConstant",
@@ -2162,6 +2347,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -2176,6 +2362,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestRetryExponential",
@@ -2185,6 +2372,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -2199,6 +2387,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestSingleFallbackClient",
@@ -2208,6 +2397,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -2222,6 +2412,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "TestVertex",
@@ -2231,6 +2422,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -2245,6 +2437,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "UnionTest_Function",
@@ -2254,6 +2447,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.UnionTest_ReturnType, raw.cast_to(types, types))
     
@@ -2268,6 +2462,7 @@ class BamlAsyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = await self.__runtime.call_function(
         "UseMalformedConstraints",
@@ -2277,6 +2472,7 @@ class BamlAsyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(int, raw.cast_to(types, types))
     
@@ -2302,6 +2498,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "AaaSamOutputFormat",
@@ -2312,6 +2509,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[partial_types.Recipe, types.Recipe](
@@ -2332,6 +2530,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "AliasedInputClass",
@@ -2342,6 +2541,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -2362,6 +2562,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "AliasedInputClass2",
@@ -2372,6 +2573,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -2392,6 +2594,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "AliasedInputClassNested",
@@ -2402,6 +2605,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -2422,6 +2626,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "AliasedInputEnum",
@@ -2432,6 +2637,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -2452,6 +2658,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "AliasedInputList",
@@ -2462,6 +2669,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -2482,6 +2690,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "AudioInput",
@@ -2492,6 +2701,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -2512,6 +2722,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "ClassifyDynEnumTwo",
@@ -2522,6 +2733,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[Union[types.DynEnumTwo, str]], Union[types.DynEnumTwo, str]](
@@ -2542,6 +2754,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "ClassifyMessage",
@@ -2552,6 +2765,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[types.Category], types.Category](
@@ -2572,6 +2786,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "ClassifyMessage2",
@@ -2582,6 +2797,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[types.Category], types.Category](
@@ -2602,6 +2818,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "ClassifyMessage3",
@@ -2612,6 +2829,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[types.Category], types.Category](
@@ -2632,6 +2850,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "CustomTask",
@@ -2642,6 +2861,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[Union[partial_types.BookOrder, partial_types.FlightConfirmation, partial_types.GroceryReceipt]], Union[types.BookOrder, types.FlightConfirmation, types.GroceryReceipt]](
@@ -2662,6 +2882,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "DescribeImage",
@@ -2672,6 +2893,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -2692,6 +2914,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "DescribeImage2",
@@ -2703,6 +2926,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -2723,6 +2947,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "DescribeImage3",
@@ -2734,6 +2959,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -2754,6 +2980,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "DescribeImage4",
@@ -2765,6 +2992,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -2785,6 +3013,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "DifferentiateUnions",
@@ -2794,6 +3023,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[Union[partial_types.OriginalA, partial_types.OriginalB]], Union[types.OriginalA, types.OriginalB]](
@@ -2814,6 +3044,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "DummyOutputFunction",
@@ -2824,6 +3055,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[partial_types.DummyOutput, types.DummyOutput](
@@ -2844,6 +3076,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "DynamicFunc",
@@ -2854,6 +3087,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[partial_types.DynamicClassTwo, types.DynamicClassTwo](
@@ -2874,6 +3108,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "DynamicInputOutput",
@@ -2884,6 +3119,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[partial_types.DynInputOutput, types.DynInputOutput](
@@ -2904,6 +3140,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "DynamicListInputOutput",
@@ -2914,6 +3151,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[List[partial_types.DynInputOutput], List[types.DynInputOutput]](
@@ -2934,6 +3172,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "ExpectFailure",
@@ -2943,6 +3182,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -2963,6 +3203,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "ExtractContactInfo",
@@ -2973,6 +3214,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[partial_types.ContactInfo, types.ContactInfo](
@@ -2993,6 +3235,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "ExtractHobby",
@@ -3003,6 +3246,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[List[Optional[Union[types.Hobby, str]]], List[Union[types.Hobby, str]]](
@@ -3023,6 +3267,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "ExtractNames",
@@ -3033,6 +3278,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[List[Optional[str]], List[str]](
@@ -3053,6 +3299,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "ExtractPeople",
@@ -3063,6 +3310,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[List[partial_types.Person], List[types.Person]](
@@ -3083,6 +3331,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "ExtractReceiptInfo",
@@ -3094,6 +3343,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[partial_types.ReceiptInfo, types.ReceiptInfo](
@@ -3114,6 +3364,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "ExtractResume",
@@ -3125,6 +3376,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[partial_types.Resume, types.Resume](
@@ -3145,6 +3397,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "ExtractResume2",
@@ -3155,6 +3408,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[partial_types.Resume, types.Resume](
@@ -3175,6 +3429,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "FnClassOptionalOutput",
@@ -3185,6 +3440,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[partial_types.ClassOptionalOutput, Optional[types.ClassOptionalOutput]](
@@ -3205,6 +3461,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "FnClassOptionalOutput2",
@@ -3215,6 +3472,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[partial_types.ClassOptionalOutput2, Optional[types.ClassOptionalOutput2]](
@@ -3235,6 +3493,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "FnEnumListOutput",
@@ -3245,6 +3504,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[List[Optional[types.EnumOutput]], List[types.EnumOutput]](
@@ -3265,6 +3525,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "FnEnumOutput",
@@ -3275,6 +3536,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[types.EnumOutput], types.EnumOutput](
@@ -3295,6 +3557,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "FnNamedArgsSingleStringOptional",
@@ -3305,6 +3568,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -3325,6 +3589,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "FnOutputBool",
@@ -3335,6 +3600,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[bool], bool](
@@ -3355,6 +3621,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "FnOutputClass",
@@ -3365,6 +3632,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[partial_types.TestOutputClass, types.TestOutputClass](
@@ -3385,6 +3653,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "FnOutputClassList",
@@ -3395,6 +3664,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[List[partial_types.TestOutputClass], List[types.TestOutputClass]](
@@ -3415,6 +3685,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "FnOutputClassNested",
@@ -3425,6 +3696,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[partial_types.TestClassNested, types.TestClassNested](
@@ -3445,6 +3717,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "FnOutputClassWithEnum",
@@ -3455,6 +3728,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[partial_types.TestClassWithEnum, types.TestClassWithEnum](
@@ -3475,6 +3749,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "FnOutputStringList",
@@ -3485,6 +3760,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[List[Optional[str]], List[str]](
@@ -3505,6 +3781,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "FnTestAliasedEnumOutput",
@@ -3515,6 +3792,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[types.TestEnum], types.TestEnum](
@@ -3535,6 +3813,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "FnTestClassAlias",
@@ -3545,6 +3824,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[partial_types.TestClassAlias, types.TestClassAlias](
@@ -3565,6 +3845,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "FnTestNamedArgsSingleEnum",
@@ -3575,6 +3856,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -3595,6 +3877,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "GetDataType",
@@ -3605,6 +3888,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[partial_types.RaysData, types.RaysData](
@@ -3625,6 +3909,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "GetOrderInfo",
@@ -3635,6 +3920,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[partial_types.OrderInfo, types.OrderInfo](
@@ -3655,6 +3941,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "GetQuery",
@@ -3665,6 +3952,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[partial_types.SearchParams, types.SearchParams](
@@ -3685,6 +3973,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "MyFunc",
@@ -3695,6 +3984,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[partial_types.DynamicOutput, types.DynamicOutput](
@@ -3715,6 +4005,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "OptionalTest_Function",
@@ -3725,6 +4016,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[List[partial_types.OptionalTest_ReturnType], List[Optional[types.OptionalTest_ReturnType]]](
@@ -3745,6 +4037,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "PredictAge",
@@ -3755,6 +4048,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[partial_types.FooAny, types.FooAny](
@@ -3775,6 +4069,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "PredictAgeBare",
@@ -3785,6 +4080,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[baml_py.Checked[Optional[int],types.Literal["too_big"]], baml_py.Checked[int,types.Literal["too_big"]]](
@@ -3805,6 +4101,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "PromptTestClaude",
@@ -3815,6 +4112,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -3835,6 +4133,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "PromptTestClaudeChat",
@@ -3845,6 +4144,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -3865,6 +4165,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "PromptTestClaudeChatNoSystem",
@@ -3875,6 +4176,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -3895,6 +4197,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "PromptTestOpenAI",
@@ -3905,6 +4208,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -3925,6 +4229,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "PromptTestOpenAIChat",
@@ -3935,6 +4240,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -3955,6 +4261,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "PromptTestOpenAIChatNoSystem",
@@ -3965,6 +4272,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -3985,6 +4293,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "PromptTestStreaming",
@@ -3995,6 +4304,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -4015,6 +4325,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "ReturnFailingAssert",
@@ -4025,6 +4336,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[int], int](
@@ -4045,6 +4357,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "ReturnMalformedConstraints",
@@ -4055,6 +4368,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[partial_types.MalformedConstraints, types.MalformedConstraints](
@@ -4075,6 +4389,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "SchemaDescriptions",
@@ -4085,6 +4400,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[partial_types.Schema, types.Schema](
@@ -4105,6 +4421,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "StreamBigNumbers",
@@ -4115,6 +4432,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[partial_types.BigNumbers, types.BigNumbers](
@@ -4135,6 +4453,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "StreamFailingAssertion",
@@ -4146,6 +4465,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[partial_types.TwoStoriesOneTitle, types.TwoStoriesOneTitle](
@@ -4166,6 +4486,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "StreamOneBigNumber",
@@ -4176,6 +4497,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[int], int](
@@ -4196,6 +4518,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "StreamUnionIntegers",
@@ -4206,6 +4529,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[List[Optional[Union[Optional[int], Optional[str]]]], List[Union[int, str]]](
@@ -4226,6 +4550,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "StreamingCompoundNumbers",
@@ -4237,6 +4562,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[partial_types.CompoundBigNumbers, types.CompoundBigNumbers](
@@ -4257,6 +4583,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestAnthropic",
@@ -4267,6 +4594,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -4287,6 +4615,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestAnthropicShorthand",
@@ -4297,6 +4626,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -4317,6 +4647,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestAws",
@@ -4327,6 +4658,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -4347,6 +4679,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestAzure",
@@ -4357,6 +4690,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -4377,6 +4711,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestCaching",
@@ -4388,6 +4723,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -4408,6 +4744,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestFallbackClient",
@@ -4417,6 +4754,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -4437,6 +4775,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestFallbackToShorthand",
@@ -4447,6 +4786,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -4467,6 +4807,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestFnNamedArgsSingleBool",
@@ -4477,6 +4818,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -4497,6 +4839,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestFnNamedArgsSingleClass",
@@ -4507,6 +4850,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -4527,6 +4871,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestFnNamedArgsSingleEnumList",
@@ -4537,6 +4882,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -4557,6 +4903,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestFnNamedArgsSingleFloat",
@@ -4567,6 +4914,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -4587,6 +4935,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestFnNamedArgsSingleInt",
@@ -4597,6 +4946,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -4617,6 +4967,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestFnNamedArgsSingleMapStringToClass",
@@ -4627,6 +4978,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Dict[str, partial_types.StringToClassEntry], Dict[str, types.StringToClassEntry]](
@@ -4647,6 +4999,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestFnNamedArgsSingleMapStringToMap",
@@ -4657,6 +5010,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Dict[str, Dict[str, Optional[str]]], Dict[str, Dict[str, str]]](
@@ -4677,6 +5031,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestFnNamedArgsSingleMapStringToString",
@@ -4687,6 +5042,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Dict[str, Optional[str]], Dict[str, str]](
@@ -4707,6 +5063,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestFnNamedArgsSingleString",
@@ -4717,6 +5074,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -4737,6 +5095,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestFnNamedArgsSingleStringArray",
@@ -4747,6 +5106,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -4767,6 +5127,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestFnNamedArgsSingleStringList",
@@ -4777,6 +5138,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -4797,6 +5159,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestGemini",
@@ -4807,6 +5170,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -4827,6 +5191,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestImageInput",
@@ -4837,6 +5202,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -4857,6 +5223,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestImageInputAnthropic",
@@ -4867,6 +5234,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -4887,6 +5255,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestImageListInput",
@@ -4897,6 +5266,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -4917,6 +5287,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestMulticlassNamedArgs",
@@ -4928,6 +5299,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -4948,6 +5320,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestOllama",
@@ -4958,6 +5331,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -4978,6 +5352,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestOpenAILegacyProvider",
@@ -4988,6 +5363,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -5008,6 +5384,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestOpenAIShorthand",
@@ -5018,6 +5395,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -5038,6 +5416,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestRetryConstant",
@@ -5047,6 +5426,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -5067,6 +5447,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestRetryExponential",
@@ -5076,6 +5457,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -5096,6 +5478,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestSingleFallbackClient",
@@ -5105,6 +5488,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -5125,6 +5509,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "TestVertex",
@@ -5135,6 +5520,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[str], str](
@@ -5155,6 +5541,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "UnionTest_Function",
@@ -5165,6 +5552,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[partial_types.UnionTest_ReturnType, types.UnionTest_ReturnType](
@@ -5185,6 +5573,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function(
         "UseMalformedConstraints",
@@ -5195,6 +5584,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlStream[Optional[int], int](
@@ -14,6 +14,7 @@
 # pylint: disable=unused-import,line-too-long
 # fmt: off
 import baml_py
+import datetime
 from enum import Enum
 from pydantic import BaseModel, ConfigDict
 from typing import Dict, List, Optional, Union, Literal
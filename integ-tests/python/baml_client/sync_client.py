@@ -15,6 +15,7 @@
 # fmt: off
 from typing import Any, Dict, List, Optional, TypeVar, Union, TypedDict, Type, Literal, cast
 from typing_extensions import NotRequired
+import datetime
 import pprint
 
 import baml_py
@@ -30,6 +31,7 @@ OutputType = TypeVar('OutputType')
 class BamlCallOptions(TypedDict, total=False):
     tb: NotRequired[TypeBuilder]
     client_registry: NotRequired[baml_py.baml_py.ClientRegistry]
+    collector: NotRequired[baml_py.baml_py.Collector]
 
 class BamlSyncClient:
     __runtime: baml_py.BamlRuntime
@@ -57,6 +59,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "AaaSamOutputFormat",
@@ -66,6 +69,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.Recipe, raw.cast_to(types, types))
     
@@ -80,6 +84,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "AliasedInputClass",
@@ -89,6 +94,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -103,6 +109,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "AliasedInputClass2",
@@ -112,6 +119,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -126,6 +134,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "AliasedInputClassNested",
@@ -135,6 +144,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -149,6 +159,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "AliasedInputEnum",
@@ -158,6 +169,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -172,6 +184,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "AliasedInputList",
@@ -181,6 +194,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -195,6 +209,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "AudioInput",
@@ -204,6 +219,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -218,6 +234,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "ClassifyDynEnumTwo",
@@ -227,6 +244,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(Union[types.DynEnumTwo, str], raw.cast_to(types, types))
     
@@ -241,6 +259,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "ClassifyMessage",
@@ -250,6 +269,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.Category, raw.cast_to(types, types))
     
@@ -264,6 +284,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "ClassifyMessage2",
@@ -273,6 +294,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.Category, raw.cast_to(types, types))
     
@@ -287,6 +309,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "ClassifyMessage3",
@@ -296,6 +319,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.Category, raw.cast_to(types, types))
     
@@ -310,6 +334,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "CustomTask",
@@ -319,6 +344,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(Union[types.BookOrder, types.FlightConfirmation, types.GroceryReceipt], raw.cast_to(types, types))
     
@@ -333,6 +359,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "DescribeImage",
@@ -342,6 +369,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -356,6 +384,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "DescribeImage2",
@@ -365,6 +394,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -379,6 +409,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "DescribeImage3",
@@ -388,6 +419,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -402,6 +434,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "DescribeImage4",
@@ -411,6 +444,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -425,6 +459,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "DifferentiateUnions",
@@ -434,6 +469,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(Union[types.OriginalA, types.OriginalB], raw.cast_to(types, types))
     
@@ -448,6 +484,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "DummyOutputFunction",
@@ -457,6 +494,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.DummyOutput, raw.cast_to(types, types))
     
@@ -471,6 +509,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "DynamicFunc",
@@ -480,6 +519,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.DynamicClassTwo, raw.cast_to(types, types))
     
@@ -494,6 +534,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "DynamicInputOutput",
@@ -503,6 +544,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.DynInputOutput, raw.cast_to(types, types))
     
@@ -517,6 +559,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "DynamicListInputOutput",
@@ -526,6 +569,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(List[types.DynInputOutput], raw.cast_to(types, types))
     
@@ -540,6 +584,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "ExpectFailure",
@@ -549,6 +594,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -563,6 +609,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "ExtractContactInfo",
@@ -572,6 +619,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.ContactInfo, raw.cast_to(types, types))
     
@@ -586,6 +634,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "ExtractHobby",
@@ -595,6 +644,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(List[Union[types.Hobby, str]], raw.cast_to(types, types))
     
@@ -609,6 +659,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "ExtractNames",
@@ -618,6 +669,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(List[str], raw.cast_to(types, types))
     
@@ -632,6 +684,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "ExtractPeople",
@@ -641,6 +694,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(List[types.Person], raw.cast_to(types, types))
     
@@ -655,6 +709,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "ExtractReceiptInfo",
@@ -664,6 +719,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.ReceiptInfo, raw.cast_to(types, types))
     
@@ -678,6 +734,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "ExtractResume",
@@ -687,6 +744,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.Resume, raw.cast_to(types, types))
     
@@ -701,6 +759,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "ExtractResume2",
@@ -710,6 +769,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.Resume, raw.cast_to(types, types))
     
@@ -724,6 +784,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "FnClassOptionalOutput",
@@ -733,6 +794,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(Optional[types.ClassOptionalOutput], raw.cast_to(types, types))
     
@@ -747,6 +809,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "FnClassOptionalOutput2",
@@ -756,6 +819,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(Optional[types.ClassOptionalOutput2], raw.cast_to(types, types))
     
@@ -770,6 +834,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "FnEnumListOutput",
@@ -779,6 +844,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(List[types.EnumOutput], raw.cast_to(types, types))
     
@@ -793,6 +859,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "FnEnumOutput",
@@ -802,6 +869,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.EnumOutput, raw.cast_to(types, types))
     
@@ -816,6 +884,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "FnNamedArgsSingleStringOptional",
@@ -825,6 +894,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -839,6 +909,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "FnOutputBool",
@@ -848,6 +919,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(bool, raw.cast_to(types, types))
     
@@ -862,6 +934,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "FnOutputClass",
@@ -871,6 +944,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.TestOutputClass, raw.cast_to(types, types))
     
@@ -885,6 +959,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "FnOutputClassList",
@@ -894,6 +969,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(List[types.TestOutputClass], raw.cast_to(types, types))
     
@@ -908,6 +984,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "FnOutputClassNested",
@@ -917,6 +994,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.TestClassNested, raw.cast_to(types, types))
     
@@ -931,6 +1009,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "FnOutputClassWithEnum",
@@ -940,6 +1019,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.TestClassWithEnum, raw.cast_to(types, types))
     
@@ -954,6 +1034,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "FnOutputStringList",
@@ -963,6 +1044,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(List[str], raw.cast_to(types, types))
     
@@ -977,6 +1059,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "FnTestAliasedEnumOutput",
@@ -986,6 +1069,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.TestEnum, raw.cast_to(types, types))
     
@@ -1000,6 +1084,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "FnTestClassAlias",
@@ -1009,6 +1094,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.TestClassAlias, raw.cast_to(types, types))
     
@@ -1023,6 +1109,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "FnTestNamedArgsSingleEnum",
@@ -1032,6 +1119,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1046,6 +1134,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "GetDataType",
@@ -1055,6 +1144,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.RaysData, raw.cast_to(types, types))
     
@@ -1069,6 +1159,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "GetOrderInfo",
@@ -1078,6 +1169,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.OrderInfo, raw.cast_to(types, types))
     
@@ -1092,6 +1184,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "GetQuery",
@@ -1101,6 +1194,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.SearchParams, raw.cast_to(types, types))
     
@@ -1115,6 +1209,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "MyFunc",
@@ -1124,6 +1219,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.DynamicOutput, raw.cast_to(types, types))
     
@@ -1138,6 +1234,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "OptionalTest_Function",
@@ -1147,6 +1244,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(List[Optional[types.OptionalTest_ReturnType]], raw.cast_to(types, types))
     
@@ -1161,6 +1259,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "PredictAge",
@@ -1170,6 +1269,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.FooAny, raw.cast_to(types, types))
     
@@ -1184,6 +1284,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "PredictAgeBare",
@@ -1193,6 +1294,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(baml_py.Checked[int,types.Literal["too_big"]], raw.cast_to(types, types))
     
@@ -1207,6 +1309,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "PromptTestClaude",
@@ -1216,6 +1319,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1230,6 +1334,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "PromptTestClaudeChat",
@@ -1239,6 +1344,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1253,6 +1359,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "PromptTestClaudeChatNoSystem",
@@ -1262,6 +1369,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1276,6 +1384,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "PromptTestOpenAI",
@@ -1285,6 +1394,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1299,6 +1409,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "PromptTestOpenAIChat",
@@ -1308,6 +1419,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1322,6 +1434,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "PromptTestOpenAIChatNoSystem",
@@ -1331,6 +1444,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1345,6 +1459,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "PromptTestStreaming",
@@ -1354,6 +1469,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1368,6 +1484,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "ReturnFailingAssert",
@@ -1377,6 +1494,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(int, raw.cast_to(types, types))
     
@@ -1391,6 +1509,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "ReturnMalformedConstraints",
@@ -1400,6 +1519,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.MalformedConstraints, raw.cast_to(types, types))
     
@@ -1414,6 +1534,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "SchemaDescriptions",
@@ -1423,6 +1544,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.Schema, raw.cast_to(types, types))
     
@@ -1437,6 +1559,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "StreamBigNumbers",
@@ -1446,6 +1569,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.BigNumbers, raw.cast_to(types, types))
     
@@ -1460,6 +1584,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "StreamFailingAssertion",
@@ -1469,6 +1594,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.TwoStoriesOneTitle, raw.cast_to(types, types))
     
@@ -1483,6 +1609,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "StreamOneBigNumber",
@@ -1492,6 +1619,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(int, raw.cast_to(types, types))
     
@@ -1506,6 +1634,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "StreamUnionIntegers",
@@ -1515,6 +1644,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(List[Union[int, str]], raw.cast_to(types, types))
     
@@ -1529,6 +1659,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "StreamingCompoundNumbers",
@@ -1538,6 +1669,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.CompoundBigNumbers, raw.cast_to(types, types))
     
@@ -1552,6 +1684,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestAnthropic",
@@ -1561,6 +1694,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1575,6 +1709,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestAnthropicShorthand",
@@ -1584,6 +1719,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1598,6 +1734,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestAws",
@@ -1607,6 +1744,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1621,6 +1759,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestAzure",
@@ -1630,6 +1769,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1644,6 +1784,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestCaching",
@@ -1653,6 +1794,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1667,6 +1809,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestFallbackClient",
@@ -1676,6 +1819,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1690,6 +1834,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestFallbackToShorthand",
@@ -1699,6 +1844,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1713,6 +1859,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestFnNamedArgsSingleBool",
@@ -1722,6 +1869,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1736,6 +1884,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestFnNamedArgsSingleClass",
@@ -1745,6 +1894,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1759,6 +1909,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestFnNamedArgsSingleEnumList",
@@ -1768,6 +1919,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1782,6 +1934,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestFnNamedArgsSingleFloat",
@@ -1791,6 +1944,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1805,6 +1959,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestFnNamedArgsSingleInt",
@@ -1814,6 +1969,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1828,6 +1984,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestFnNamedArgsSingleMapStringToClass",
@@ -1837,6 +1994,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(Dict[str, types.StringToClassEntry], raw.cast_to(types, types))
     
@@ -1851,6 +2009,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestFnNamedArgsSingleMapStringToMap",
@@ -1860,6 +2019,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(Dict[str, Dict[str, str]], raw.cast_to(types, types))
     
@@ -1874,6 +2034,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestFnNamedArgsSingleMapStringToString",
@@ -1883,6 +2044,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(Dict[str, str], raw.cast_to(types, types))
     
@@ -1897,6 +2059,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestFnNamedArgsSingleString",
@@ -1906,6 +2069,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1920,6 +2084,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestFnNamedArgsSingleStringArray",
@@ -1929,6 +2094,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1943,6 +2109,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestFnNamedArgsSingleStringList",
@@ -1952,6 +2119,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1966,6 +2134,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestGemini",
@@ -1975,6 +2144,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -1989,6 +2159,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestImageInput",
@@ -1998,6 +2169,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -2012,6 +2184,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestImageInputAnthropic",
@@ -2021,6 +2194,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -2035,6 +2209,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestImageListInput",
@@ -2044,6 +2219,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -2058,6 +2234,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestMulticlassNamedArgs",
@@ -2067,6 +2244,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -2081,6 +2259,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestOllama",
@@ -2090,6 +2269,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -2104,6 +2284,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestOpenAILegacyProvider",
@@ -2113,6 +2294,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -2127,6 +2309,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestOpenAIShorthand",
@@ -2136,6 +2319,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -2150,6 +2334,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestRetryConstant",
@@ -2159,6 +2344,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -2173,6 +2359,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestRetryExponential",
@@ -2182,6 +2369,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -2196,6 +2384,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestSingleFallbackClient",
@@ -2205,6 +2394,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -2219,6 +2409,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "TestVertex",
@@ -2228,6 +2419,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(str, raw.cast_to(types, types))
     
@@ -2242,6 +2434,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "UnionTest_Function",
@@ -2251,6 +2444,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(types.UnionTest_ReturnType, raw.cast_to(types, types))
     
@@ -2265,6 +2459,7 @@ class BamlSyncClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.call_function_sync(
         "UseMalformedConstraints",
@@ -2274,6 +2469,7 @@ class BamlSyncClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
       return cast(int, raw.cast_to(types, types))
     
@@ -2300,6 +2496,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "AaaSamOutputFormat",
@@ -2310,6 +2507,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[partial_types.Recipe, types.Recipe](
@@ -2330,6 +2528,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "AliasedInputClass",
@@ -2340,6 +2539,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -2360,6 +2560,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "AliasedInputClass2",
@@ -2370,6 +2571,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -2390,6 +2592,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "AliasedInputClassNested",
@@ -2400,6 +2603,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -2420,6 +2624,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "AliasedInputEnum",
@@ -2430,6 +2635,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -2450,6 +2656,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "AliasedInputList",
@@ -2460,6 +2667,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -2480,6 +2688,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "AudioInput",
@@ -2490,6 +2699,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -2510,6 +2720,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "ClassifyDynEnumTwo",
@@ -2520,6 +2731,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[Union[types.DynEnumTwo, str]], Union[types.DynEnumTwo, str]](
@@ -2540,6 +2752,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "ClassifyMessage",
@@ -2550,6 +2763,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[types.Category], types.Category](
@@ -2570,6 +2784,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "ClassifyMessage2",
@@ -2580,6 +2795,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[types.Category], types.Category](
@@ -2600,6 +2816,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "ClassifyMessage3",
@@ -2610,6 +2827,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[types.Category], types.Category](
@@ -2630,6 +2848,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "CustomTask",
@@ -2640,6 +2859,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[Union[partial_types.BookOrder, partial_types.FlightConfirmation, partial_types.GroceryReceipt]], Union[types.BookOrder, types.FlightConfirmation, types.GroceryReceipt]](
@@ -2660,6 +2880,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "DescribeImage",
@@ -2670,6 +2891,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -2690,6 +2912,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "DescribeImage2",
@@ -2701,6 +2924,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -2721,6 +2945,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "DescribeImage3",
@@ -2732,6 +2957,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -2752,6 +2978,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "DescribeImage4",
@@ -2763,6 +2990,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -2783,6 +3011,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "DifferentiateUnions",
@@ -2792,6 +3021,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[Union[partial_types.OriginalA, partial_types.OriginalB]], Union[types.OriginalA, types.OriginalB]](
@@ -2812,6 +3042,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "DummyOutputFunction",
@@ -2822,6 +3053,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[partial_types.DummyOutput, types.DummyOutput](
@@ -2842,6 +3074,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "DynamicFunc",
@@ -2852,6 +3085,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[partial_types.DynamicClassTwo, types.DynamicClassTwo](
@@ -2872,6 +3106,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "DynamicInputOutput",
@@ -2882,6 +3117,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[partial_types.DynInputOutput, types.DynInputOutput](
@@ -2902,6 +3138,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "DynamicListInputOutput",
@@ -2912,6 +3149,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[List[partial_types.DynInputOutput], List[types.DynInputOutput]](
@@ -2932,6 +3170,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "ExpectFailure",
@@ -2941,6 +3180,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -2961,6 +3201,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "ExtractContactInfo",
@@ -2971,6 +3212,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[partial_types.ContactInfo, types.ContactInfo](
@@ -2991,6 +3233,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "ExtractHobby",
@@ -3001,6 +3244,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[List[Optional[Union[types.Hobby, str]]], List[Union[types.Hobby, str]]](
@@ -3021,6 +3265,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "ExtractNames",
@@ -3031,6 +3276,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[List[Optional[str]], List[str]](
@@ -3051,6 +3297,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "ExtractPeople",
@@ -3061,6 +3308,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[List[partial_types.Person], List[types.Person]](
@@ -3081,6 +3329,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "ExtractReceiptInfo",
@@ -3092,6 +3341,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[partial_types.ReceiptInfo, types.ReceiptInfo](
@@ -3112,6 +3362,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "ExtractResume",
@@ -3123,6 +3374,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[partial_types.Resume, types.Resume](
@@ -3143,6 +3395,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "ExtractResume2",
@@ -3153,6 +3406,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[partial_types.Resume, types.Resume](
@@ -3173,6 +3427,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "FnClassOptionalOutput",
@@ -3183,6 +3438,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[partial_types.ClassOptionalOutput, Optional[types.ClassOptionalOutput]](
@@ -3203,6 +3459,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "FnClassOptionalOutput2",
@@ -3213,6 +3470,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[partial_types.ClassOptionalOutput2, Optional[types.ClassOptionalOutput2]](
@@ -3233,6 +3491,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "FnEnumListOutput",
@@ -3243,6 +3502,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[List[Optional[types.EnumOutput]], List[types.EnumOutput]](
@@ -3263,6 +3523,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "FnEnumOutput",
@@ -3273,6 +3534,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[types.EnumOutput], types.EnumOutput](
@@ -3293,6 +3555,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "FnNamedArgsSingleStringOptional",
@@ -3303,6 +3566,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -3323,6 +3587,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "FnOutputBool",
@@ -3333,6 +3598,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[bool], bool](
@@ -3353,6 +3619,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "FnOutputClass",
@@ -3363,6 +3630,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[partial_types.TestOutputClass, types.TestOutputClass](
@@ -3383,6 +3651,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "FnOutputClassList",
@@ -3393,6 +3662,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[List[partial_types.TestOutputClass], List[types.TestOutputClass]](
@@ -3413,6 +3683,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "FnOutputClassNested",
@@ -3423,6 +3694,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[partial_types.TestClassNested, types.TestClassNested](
@@ -3443,6 +3715,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "FnOutputClassWithEnum",
@@ -3453,6 +3726,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[partial_types.TestClassWithEnum, types.TestClassWithEnum](
@@ -3473,6 +3747,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "FnOutputStringList",
@@ -3483,6 +3758,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[List[Optional[str]], List[str]](
@@ -3503,6 +3779,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "FnTestAliasedEnumOutput",
@@ -3513,6 +3790,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[types.TestEnum], types.TestEnum](
@@ -3533,6 +3811,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "FnTestClassAlias",
@@ -3543,6 +3822,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[partial_types.TestClassAlias, types.TestClassAlias](
@@ -3563,6 +3843,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "FnTestNamedArgsSingleEnum",
@@ -3573,6 +3854,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -3593,6 +3875,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "GetDataType",
@@ -3603,6 +3886,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[partial_types.RaysData, types.RaysData](
@@ -3623,6 +3907,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "GetOrderInfo",
@@ -3633,6 +3918,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[partial_types.OrderInfo, types.OrderInfo](
@@ -3653,6 +3939,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "GetQuery",
@@ -3663,6 +3950,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[partial_types.SearchParams, types.SearchParams](
@@ -3683,6 +3971,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "MyFunc",
@@ -3693,6 +3982,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[partial_types.DynamicOutput, types.DynamicOutput](
@@ -3713,6 +4003,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "OptionalTest_Function",
@@ -3723,6 +4014,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[List[partial_types.OptionalTest_ReturnType], List[Optional[types.OptionalTest_ReturnType]]](
@@ -3743,6 +4035,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "PredictAge",
@@ -3753,6 +4046,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[partial_types.FooAny, types.FooAny](
@@ -3773,6 +4067,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "PredictAgeBare",
@@ -3783,6 +4078,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[baml_py.Checked[Optional[int],types.Literal["too_big"]], baml_py.Checked[int,types.Literal["too_big"]]](
@@ -3803,6 +4099,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "PromptTestClaude",
@@ -3813,6 +4110,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -3833,6 +4131,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "PromptTestClaudeChat",
@@ -3843,6 +4142,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -3863,6 +4163,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "PromptTestClaudeChatNoSystem",
@@ -3873,6 +4174,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -3893,6 +4195,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "PromptTestOpenAI",
@@ -3903,6 +4206,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -3923,6 +4227,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "PromptTestOpenAIChat",
@@ -3933,6 +4238,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -3953,6 +4259,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "PromptTestOpenAIChatNoSystem",
@@ -3963,6 +4270,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -3983,6 +4291,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "PromptTestStreaming",
@@ -3993,6 +4302,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -4013,6 +4323,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "ReturnFailingAssert",
@@ -4023,6 +4334,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[int], int](
@@ -4043,6 +4355,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "ReturnMalformedConstraints",
@@ -4053,6 +4366,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[partial_types.MalformedConstraints, types.MalformedConstraints](
@@ -4073,6 +4387,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "SchemaDescriptions",
@@ -4083,6 +4398,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[partial_types.Schema, types.Schema](
@@ -4103,6 +4419,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "StreamBigNumbers",
@@ -4113,6 +4430,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[partial_types.BigNumbers, types.BigNumbers](
@@ -4133,6 +4451,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "StreamFailingAssertion",
@@ -4144,6 +4463,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[partial_types.TwoStoriesOneTitle, types.TwoStoriesOneTitle](
@@ -4164,6 +4484,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "StreamOneBigNumber",
@@ -4174,6 +4495,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[int], int](
@@ -4194,6 +4516,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "StreamUnionIntegers",
@@ -4204,6 +4527,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[List[Optional[Union[Optional[int], Optional[str]]]], List[Union[int, str]]](
@@ -4224,6 +4548,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "StreamingCompoundNumbers",
@@ -4235,6 +4560,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[partial_types.CompoundBigNumbers, types.CompoundBigNumbers](
@@ -4255,6 +4581,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestAnthropic",
@@ -4265,6 +4592,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -4285,6 +4613,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestAnthropicShorthand",
@@ -4295,6 +4624,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -4315,6 +4645,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestAws",
@@ -4325,6 +4656,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -4345,6 +4677,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestAzure",
@@ -4355,6 +4688,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -4375,6 +4709,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestCaching",
@@ -4386,6 +4721,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -4406,6 +4742,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestFallbackClient",
@@ -4415,6 +4752,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -4435,6 +4773,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestFallbackToShorthand",
@@ -4445,6 +4784,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -4465,6 +4805,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestFnNamedArgsSingleBool",
@@ -4475,6 +4816,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -4495,6 +4837,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestFnNamedArgsSingleClass",
@@ -4505,6 +4848,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -4525,6 +4869,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestFnNamedArgsSingleEnumList",
@@ -4535,6 +4880,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -4555,6 +4901,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestFnNamedArgsSingleFloat",
@@ -4565,6 +4912,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -4585,6 +4933,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestFnNamedArgsSingleInt",
@@ -4595,6 +4944,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -4615,6 +4965,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestFnNamedArgsSingleMapStringToClass",
@@ -4625,6 +4976,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Dict[str, partial_types.StringToClassEntry], Dict[str, types.StringToClassEntry]](
@@ -4645,6 +4997,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestFnNamedArgsSingleMapStringToMap",
@@ -4655,6 +5008,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Dict[str, Dict[str, Optional[str]]], Dict[str, Dict[str, str]]](
@@ -4675,6 +5029,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestFnNamedArgsSingleMapStringToString",
@@ -4685,6 +5040,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Dict[str, Optional[str]], Dict[str, str]](
@@ -4705,6 +5061,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestFnNamedArgsSingleString",
@@ -4715,6 +5072,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -4735,6 +5093,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestFnNamedArgsSingleStringArray",
@@ -4745,6 +5104,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -4765,6 +5125,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestFnNamedArgsSingleStringList",
@@ -4775,6 +5136,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -4795,6 +5157,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestGemini",
@@ -4805,6 +5168,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -4825,6 +5189,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestImageInput",
@@ -4835,6 +5200,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -4855,6 +5221,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestImageInputAnthropic",
@@ -4865,6 +5232,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -4885,6 +5253,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestImageListInput",
@@ -4895,6 +5264,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -4915,6 +5285,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestMulticlassNamedArgs",
@@ -4926,6 +5297,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -4946,6 +5318,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestOllama",
@@ -4956,6 +5329,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -4976,6 +5350,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestOpenAILegacyProvider",
@@ -4986,6 +5361,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -5006,6 +5382,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestOpenAIShorthand",
@@ -5016,6 +5393,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -5036,6 +5414,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestRetryConstant",
@@ -5045,6 +5424,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -5065,6 +5445,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestRetryExponential",
@@ -5074,6 +5455,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -5094,6 +5476,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestSingleFallbackClient",
@@ -5103,6 +5486,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -5123,6 +5507,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "TestVertex",
@@ -5133,6 +5518,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[str], str](
@@ -5153,6 +5539,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "UnionTest_Function",
@@ -5163,6 +5550,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[partial_types.UnionTest_ReturnType, types.UnionTest_ReturnType](
@@ -5183,6 +5571,7 @@ class BamlStreamClient:
       else:
         tb = None
       __cr__ = baml_options.get("client_registry", None)
+      __collector__ = baml_options.get("collector", None)
 
       raw = self.__runtime.stream_function_sync(
         "UseMalformedConstraints",
@@ -5193,6 +5582,7 @@ class BamlStreamClient:
         self.__ctx_manager.get(),
         tb,
         __cr__,
+        __collector__,
       )
 
       return baml_py.BamlSyncStream[Optional[int], int](
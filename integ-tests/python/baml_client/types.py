@@ -14,8 +14,9 @@
 # pylint: disable=unused-import,line-too-long
 # fmt: off
 import baml_py
+import datetime
 from enum import Enum
-from pydantic import BaseModel, ConfigDict
+from pydantic import BaseModel, ConfigDict, Field
 from typing import Dict, List, Optional, Union, Literal
 
 
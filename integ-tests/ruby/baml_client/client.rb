@@ -14,6 +14,7 @@
 # formatter:off
 # typed: false
 require "baml"
+require "date"
 require "sorbet-runtime"
 
 require_relative "inlined"
@@ -54,7 +55,7 @@ module Baml
       params(
         varargs: T.untyped,
         recipe: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::Types::Recipe)
     }
     def AaaSamOutputFormat(
@@ -66,8 +67,8 @@ module Baml
         
         raise ArgumentError.new("AaaSamOutputFormat may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -78,6 +79,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -86,7 +89,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: Baml::Types::InputClass,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def AliasedInputClass(
@@ -98,8 +101,8 @@ module Baml
         
         raise ArgumentError.new("AliasedInputClass may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -110,6 +113,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -118,7 +123,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: Baml::Types::InputClass,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def AliasedInputClass2(
@@ -130,8 +135,8 @@ module Baml
         
         raise ArgumentError.new("AliasedInputClass2 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -142,6 +147,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -150,7 +157,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: Baml::Types::InputClassNested,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def AliasedInputClassNested(
@@ -162,8 +169,8 @@ module Baml
         
         raise ArgumentError.new("AliasedInputClassNested may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -174,6 +181,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -182,7 +191,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: T.any(Baml::Types::AliasedEnum, String),
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def AliasedInputEnum(
@@ -194,8 +203,8 @@ module Baml
         
         raise ArgumentError.new("AliasedInputEnum may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -206,6 +215,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -214,7 +225,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: T::Array[T.any(Baml::Types::AliasedEnum, String)],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def AliasedInputList(
@@ -226,8 +237,8 @@ module Baml
         
         raise ArgumentError.new("AliasedInputList may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -238,6 +249,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -246,7 +259,7 @@ module Baml
       params(
         varargs: T.untyped,
         aud: Baml::Audio,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def AudioInput(
@@ -258,8 +271,8 @@ module Baml
         
         raise ArgumentError.new("AudioInput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -270,6 +283,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -278,7 +293,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(T.any(Baml::Types::DynEnumTwo, String))
     }
     def ClassifyDynEnumTwo(
@@ -290,8 +305,8 @@ module Baml
         
         raise ArgumentError.new("ClassifyDynEnumTwo may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -302,6 +317,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -310,7 +327,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(T.any(Baml::Types::Category, String))
     }
     def ClassifyMessage(
@@ -322,8 +339,8 @@ module Baml
         
         raise ArgumentError.new("ClassifyMessage may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -334,6 +351,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -342,7 +361,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(T.any(Baml::Types::Category, String))
     }
     def ClassifyMessage2(
@@ -354,8 +373,8 @@ module Baml
         
         raise ArgumentError.new("ClassifyMessage2 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -366,6 +385,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -374,7 +395,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(T.any(Baml::Types::Category, String))
     }
     def ClassifyMessage3(
@@ -386,8 +407,8 @@ module Baml
         
         raise ArgumentError.new("ClassifyMessage3 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -398,6 +419,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -406,7 +429,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(T.any(Baml::Types::BookOrder, Baml::Types::FlightConfirmation, Baml::Types::GroceryReceipt))
     }
     def CustomTask(
@@ -418,8 +441,8 @@ module Baml
         
         raise ArgumentError.new("CustomTask may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -430,6 +453,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -438,7 +463,7 @@ module Baml
       params(
         varargs: T.untyped,
         img: Baml::Image,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def DescribeImage(
@@ -450,8 +475,8 @@ module Baml
         
         raise ArgumentError.new("DescribeImage may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -462,6 +487,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -470,7 +497,7 @@ module Baml
       params(
         varargs: T.untyped,
         classWithImage: Baml::Types::ClassWithImage,img2: Baml::Image,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def DescribeImage2(
@@ -482,8 +509,8 @@ module Baml
         
         raise ArgumentError.new("DescribeImage2 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -494,6 +521,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -502,7 +531,7 @@ module Baml
       params(
         varargs: T.untyped,
         classWithImage: Baml::Types::ClassWithImage,img2: Baml::Image,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def DescribeImage3(
@@ -514,8 +543,8 @@ module Baml
         
         raise ArgumentError.new("DescribeImage3 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -526,6 +555,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -534,7 +565,7 @@ module Baml
       params(
         varargs: T.untyped,
         classWithImage: Baml::Types::ClassWithImage,img2: Baml::Image,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def DescribeImage4(
@@ -546,8 +577,8 @@ module Baml
         
         raise ArgumentError.new("DescribeImage4 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -558,6 +589,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -566,7 +599,7 @@ module Baml
       params(
         varargs: T.untyped,
         
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(T.any(Baml::Types::OriginalA, Baml::Types::OriginalB))
     }
     def DifferentiateUnions(
@@ -578,8 +611,8 @@ module Baml
         
         raise ArgumentError.new("DifferentiateUnions may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -590,6 +623,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -598,7 +633,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::Types::DummyOutput)
     }
     def DummyOutputFunction(
@@ -610,8 +645,8 @@ module Baml
         
         raise ArgumentError.new("DummyOutputFunction may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -622,6 +657,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -630,7 +667,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: Baml::Types::DynamicClassOne,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::Types::DynamicClassTwo)
     }
     def DynamicFunc(
@@ -642,8 +679,8 @@ module Baml
         
         raise ArgumentError.new("DynamicFunc may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -654,6 +691,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -662,7 +701,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: Baml::Types::DynInputOutput,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::Types::DynInputOutput)
     }
     def DynamicInputOutput(
@@ -674,8 +713,8 @@ module Baml
         
         raise ArgumentError.new("DynamicInputOutput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -686,6 +725,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -694,7 +735,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: T::Array[Baml::Types::DynInputOutput],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(T::Array[Baml::Types::DynInputOutput])
     }
     def DynamicListInputOutput(
@@ -706,8 +747,8 @@ module Baml
         
         raise ArgumentError.new("DynamicListInputOutput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -718,6 +759,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -726,7 +769,7 @@ module Baml
       params(
         varargs: T.untyped,
         
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def ExpectFailure(
@@ -738,8 +781,8 @@ module Baml
         
         raise ArgumentError.new("ExpectFailure may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -750,6 +793,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -758,7 +803,7 @@ module Baml
       params(
         varargs: T.untyped,
         document: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::Types::ContactInfo)
     }
     def ExtractContactInfo(
@@ -770,8 +815,8 @@ module Baml
         
         raise ArgumentError.new("ExtractContactInfo may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -782,6 +827,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -790,7 +837,7 @@ module Baml
       params(
         varargs: T.untyped,
         text: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(T::Array[T.any(Baml::Types::Hobby, String)])
     }
     def ExtractHobby(
@@ -802,8 +849,8 @@ module Baml
         
         raise ArgumentError.new("ExtractHobby may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -814,6 +861,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -822,7 +871,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(T::Array[String])
     }
     def ExtractNames(
@@ -834,8 +883,8 @@ module Baml
         
         raise ArgumentError.new("ExtractNames may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -846,6 +895,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -854,7 +905,7 @@ module Baml
       params(
         varargs: T.untyped,
         text: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(T::Array[Baml::Types::Person])
     }
     def ExtractPeople(
@@ -866,8 +917,8 @@ module Baml
         
         raise ArgumentError.new("ExtractPeople may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -878,6 +929,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -886,7 +939,7 @@ module Baml
       params(
         varargs: T.untyped,
         email: String,reason: T.any(String, String),
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::Types::ReceiptInfo)
     }
     def ExtractReceiptInfo(
@@ -898,8 +951,8 @@ module Baml
         
         raise ArgumentError.new("ExtractReceiptInfo may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -910,6 +963,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -918,7 +973,7 @@ module Baml
       params(
         varargs: T.untyped,
         resume: String,img: T.nilable(Baml::Image),
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::Types::Resume)
     }
     def ExtractResume(
@@ -930,8 +985,8 @@ module Baml
         
         raise ArgumentError.new("ExtractResume may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -942,6 +997,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -950,7 +1007,7 @@ module Baml
       params(
         varargs: T.untyped,
         resume: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::Types::Resume)
     }
     def ExtractResume2(
@@ -962,8 +1019,8 @@ module Baml
         
         raise ArgumentError.new("ExtractResume2 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -974,6 +1031,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -982,7 +1041,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(T.nilable(Baml::Types::ClassOptionalOutput))
     }
     def FnClassOptionalOutput(
@@ -994,8 +1053,8 @@ module Baml
         
         raise ArgumentError.new("FnClassOptionalOutput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1006,6 +1065,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1014,7 +1075,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(T.nilable(Baml::Types::ClassOptionalOutput2))
     }
     def FnClassOptionalOutput2(
@@ -1026,8 +1087,8 @@ module Baml
         
         raise ArgumentError.new("FnClassOptionalOutput2 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1038,6 +1099,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1046,7 +1109,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(T::Array[T.any(Baml::Types::EnumOutput, String)])
     }
     def FnEnumListOutput(
@@ -1058,8 +1121,8 @@ module Baml
         
         raise ArgumentError.new("FnEnumListOutput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1070,6 +1133,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1078,7 +1143,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(T.any(Baml::Types::EnumOutput, String))
     }
     def FnEnumOutput(
@@ -1090,8 +1155,8 @@ module Baml
         
         raise ArgumentError.new("FnEnumOutput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1102,6 +1167,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1110,7 +1177,7 @@ module Baml
       params(
         varargs: T.untyped,
         myString: T.nilable(String),
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def FnNamedArgsSingleStringOptional(
@@ -1122,8 +1189,8 @@ module Baml
         
         raise ArgumentError.new("FnNamedArgsSingleStringOptional may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1134,6 +1201,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1142,7 +1211,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(T::Boolean)
     }
     def FnOutputBool(
@@ -1154,8 +1223,8 @@ module Baml
         
         raise ArgumentError.new("FnOutputBool may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1166,6 +1235,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1174,7 +1245,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::Types::TestOutputClass)
     }
     def FnOutputClass(
@@ -1186,8 +1257,8 @@ module Baml
         
         raise ArgumentError.new("FnOutputClass may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1198,6 +1269,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1206,7 +1279,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(T::Array[Baml::Types::TestOutputClass])
     }
     def FnOutputClassList(
@@ -1218,8 +1291,8 @@ module Baml
         
         raise ArgumentError.new("FnOutputClassList may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1230,6 +1303,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1238,7 +1313,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::Types::TestClassNested)
     }
     def FnOutputClassNested(
@@ -1250,8 +1325,8 @@ module Baml
         
         raise ArgumentError.new("FnOutputClassNested may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1262,6 +1337,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1270,7 +1347,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::Types::TestClassWithEnum)
     }
     def FnOutputClassWithEnum(
@@ -1282,8 +1359,8 @@ module Baml
         
         raise ArgumentError.new("FnOutputClassWithEnum may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1294,6 +1371,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1302,7 +1381,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(T::Array[String])
     }
     def FnOutputStringList(
@@ -1314,8 +1393,8 @@ module Baml
         
         raise ArgumentError.new("FnOutputStringList may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1326,6 +1405,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1334,7 +1415,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(T.any(Baml::Types::TestEnum, String))
     }
     def FnTestAliasedEnumOutput(
@@ -1346,8 +1427,8 @@ module Baml
         
         raise ArgumentError.new("FnTestAliasedEnumOutput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1358,6 +1439,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1366,7 +1449,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::Types::TestClassAlias)
     }
     def FnTestClassAlias(
@@ -1378,8 +1461,8 @@ module Baml
         
         raise ArgumentError.new("FnTestClassAlias may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1390,6 +1473,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1398,7 +1483,7 @@ module Baml
       params(
         varargs: T.untyped,
         myArg: T.any(Baml::Types::NamedArgsSingleEnum, String),
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def FnTestNamedArgsSingleEnum(
@@ -1410,8 +1495,8 @@ module Baml
         
         raise ArgumentError.new("FnTestNamedArgsSingleEnum may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1422,6 +1507,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1430,7 +1517,7 @@ module Baml
       params(
         varargs: T.untyped,
         text: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::Types::RaysData)
     }
     def GetDataType(
@@ -1442,8 +1529,8 @@ module Baml
         
         raise ArgumentError.new("GetDataType may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1454,6 +1541,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1462,7 +1551,7 @@ module Baml
       params(
         varargs: T.untyped,
         email: Baml::Types::Email,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::Types::OrderInfo)
     }
     def GetOrderInfo(
@@ -1474,8 +1563,8 @@ module Baml
         
         raise ArgumentError.new("GetOrderInfo may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1486,6 +1575,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1494,7 +1585,7 @@ module Baml
       params(
         varargs: T.untyped,
         query: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::Types::SearchParams)
     }
     def GetQuery(
@@ -1506,8 +1597,8 @@ module Baml
         
         raise ArgumentError.new("GetQuery may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1518,6 +1609,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1526,7 +1619,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::Types::DynamicOutput)
     }
     def MyFunc(
@@ -1538,8 +1631,8 @@ module Baml
         
         raise ArgumentError.new("MyFunc may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1550,6 +1643,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1558,7 +1653,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(T::Array[T.nilable(Baml::Types::OptionalTest_ReturnType)])
     }
     def OptionalTest_Function(
@@ -1570,8 +1665,8 @@ module Baml
         
         raise ArgumentError.new("OptionalTest_Function may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1582,6 +1677,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1590,7 +1687,7 @@ module Baml
       params(
         varargs: T.untyped,
         name: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::Types::FooAny)
     }
     def PredictAge(
@@ -1602,8 +1699,8 @@ module Baml
         
         raise ArgumentError.new("PredictAge may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1614,6 +1711,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1622,7 +1721,7 @@ module Baml
       params(
         varargs: T.untyped,
         inp: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::Checked[Integer])
     }
     def PredictAgeBare(
@@ -1634,8 +1733,8 @@ module Baml
         
         raise ArgumentError.new("PredictAgeBare may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1646,6 +1745,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1654,7 +1755,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def PromptTestClaude(
@@ -1666,8 +1767,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestClaude may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1678,6 +1779,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1686,7 +1789,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def PromptTestClaudeChat(
@@ -1698,8 +1801,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestClaudeChat may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1710,6 +1813,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1718,7 +1823,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def PromptTestClaudeChatNoSystem(
@@ -1730,8 +1835,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestClaudeChatNoSystem may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1742,6 +1847,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1750,7 +1857,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def PromptTestOpenAI(
@@ -1762,8 +1869,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestOpenAI may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1774,6 +1881,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1782,7 +1891,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def PromptTestOpenAIChat(
@@ -1794,8 +1903,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestOpenAIChat may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1806,6 +1915,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1814,7 +1925,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def PromptTestOpenAIChatNoSystem(
@@ -1826,8 +1937,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestOpenAIChatNoSystem may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1838,6 +1949,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1846,7 +1959,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def PromptTestStreaming(
@@ -1858,8 +1971,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestStreaming may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1870,6 +1983,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1878,7 +1993,7 @@ module Baml
       params(
         varargs: T.untyped,
         inp: Integer,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Integer)
     }
     def ReturnFailingAssert(
@@ -1890,8 +2005,8 @@ module Baml
         
         raise ArgumentError.new("ReturnFailingAssert may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1902,6 +2017,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1910,7 +2027,7 @@ module Baml
       params(
         varargs: T.untyped,
         a: Integer,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::Types::MalformedConstraints)
     }
     def ReturnMalformedConstraints(
@@ -1922,8 +2039,8 @@ module Baml
         
         raise ArgumentError.new("ReturnMalformedConstraints may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1934,6 +2051,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1942,7 +2061,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::Types::Schema)
     }
     def SchemaDescriptions(
@@ -1954,8 +2073,8 @@ module Baml
         
         raise ArgumentError.new("SchemaDescriptions may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1966,6 +2085,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -1974,7 +2095,7 @@ module Baml
       params(
         varargs: T.untyped,
         digits: Integer,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::Types::BigNumbers)
     }
     def StreamBigNumbers(
@@ -1986,8 +2107,8 @@ module Baml
         
         raise ArgumentError.new("StreamBigNumbers may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -1998,6 +2119,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2006,7 +2129,7 @@ module Baml
       params(
         varargs: T.untyped,
         theme: String,length: Integer,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::Types::TwoStoriesOneTitle)
     }
     def StreamFailingAssertion(
@@ -2018,8 +2141,8 @@ module Baml
         
         raise ArgumentError.new("StreamFailingAssertion may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2030,6 +2153,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2038,7 +2163,7 @@ module Baml
       params(
         varargs: T.untyped,
         digits: Integer,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Integer)
     }
     def StreamOneBigNumber(
@@ -2050,8 +2175,8 @@ module Baml
         
         raise ArgumentError.new("StreamOneBigNumber may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2062,6 +2187,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2070,7 +2197,7 @@ module Baml
       params(
         varargs: T.untyped,
         digits: Integer,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(T::Array[T.any(Integer, String)])
     }
     def StreamUnionIntegers(
@@ -2082,8 +2209,8 @@ module Baml
         
         raise ArgumentError.new("StreamUnionIntegers may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2094,6 +2221,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2102,7 +2231,7 @@ module Baml
       params(
         varargs: T.untyped,
         digits: Integer,yapping: T::Boolean,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::Types::CompoundBigNumbers)
     }
     def StreamingCompoundNumbers(
@@ -2114,8 +2243,8 @@ module Baml
         
         raise ArgumentError.new("StreamingCompoundNumbers may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2126,6 +2255,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2134,7 +2265,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def TestAnthropic(
@@ -2146,8 +2277,8 @@ module Baml
         
         raise ArgumentError.new("TestAnthropic may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2158,6 +2289,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2166,7 +2299,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def TestAnthropicShorthand(
@@ -2178,8 +2311,8 @@ module Baml
         
         raise ArgumentError.new("TestAnthropicShorthand may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2190,6 +2323,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2198,7 +2333,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def TestAws(
@@ -2210,8 +2345,8 @@ module Baml
         
         raise ArgumentError.new("TestAws may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2222,6 +2357,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2230,7 +2367,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def TestAzure(
@@ -2242,8 +2379,8 @@ module Baml
         
         raise ArgumentError.new("TestAzure may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2254,6 +2391,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2262,7 +2401,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,not_cached: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def TestCaching(
@@ -2274,8 +2413,8 @@ module Baml
         
         raise ArgumentError.new("TestCaching may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2286,6 +2425,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2294,7 +2435,7 @@ module Baml
       params(
         varargs: T.untyped,
         
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def TestFallbackClient(
@@ -2306,8 +2447,8 @@ module Baml
         
         raise ArgumentError.new("TestFallbackClient may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2318,6 +2459,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2326,7 +2469,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def TestFallbackToShorthand(
@@ -2338,8 +2481,8 @@ module Baml
         
         raise ArgumentError.new("TestFallbackToShorthand may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2350,6 +2493,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2358,7 +2503,7 @@ module Baml
       params(
         varargs: T.untyped,
         myBool: T::Boolean,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def TestFnNamedArgsSingleBool(
@@ -2370,8 +2515,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleBool may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2382,6 +2527,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2390,7 +2537,7 @@ module Baml
       params(
         varargs: T.untyped,
         myArg: Baml::Types::NamedArgsSingleClass,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def TestFnNamedArgsSingleClass(
@@ -2402,8 +2549,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleClass may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2414,6 +2561,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2422,7 +2571,7 @@ module Baml
       params(
         varargs: T.untyped,
         myArg: T::Array[T.any(Baml::Types::NamedArgsSingleEnumList, String)],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def TestFnNamedArgsSingleEnumList(
@@ -2434,8 +2583,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleEnumList may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2446,6 +2595,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2454,7 +2605,7 @@ module Baml
       params(
         varargs: T.untyped,
         myFloat: Float,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def TestFnNamedArgsSingleFloat(
@@ -2466,8 +2617,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleFloat may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2478,6 +2629,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2486,7 +2639,7 @@ module Baml
       params(
         varargs: T.untyped,
         myInt: Integer,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def TestFnNamedArgsSingleInt(
@@ -2498,8 +2651,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleInt may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2510,6 +2663,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2518,7 +2673,7 @@ module Baml
       params(
         varargs: T.untyped,
         myMap: T::Hash[String, Baml::Types::StringToClassEntry],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(T::Hash[String, Baml::Types::StringToClassEntry])
     }
     def TestFnNamedArgsSingleMapStringToClass(
@@ -2530,8 +2685,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleMapStringToClass may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2542,6 +2697,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2550,7 +2707,7 @@ module Baml
       params(
         varargs: T.untyped,
         myMap: T::Hash[String, T::Hash[String, String]],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(T::Hash[String, T::Hash[String, String]])
     }
     def TestFnNamedArgsSingleMapStringToMap(
@@ -2562,8 +2719,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleMapStringToMap may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2574,6 +2731,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2582,7 +2741,7 @@ module Baml
       params(
         varargs: T.untyped,
         myMap: T::Hash[String, String],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(T::Hash[String, String])
     }
     def TestFnNamedArgsSingleMapStringToString(
@@ -2594,8 +2753,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleMapStringToString may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2606,6 +2765,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2614,7 +2775,7 @@ module Baml
       params(
         varargs: T.untyped,
         myString: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def TestFnNamedArgsSingleString(
@@ -2626,8 +2787,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleString may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2638,6 +2799,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2646,7 +2809,7 @@ module Baml
       params(
         varargs: T.untyped,
         myStringArray: T::Array[String],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def TestFnNamedArgsSingleStringArray(
@@ -2658,8 +2821,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleStringArray may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2670,6 +2833,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2678,7 +2843,7 @@ module Baml
       params(
         varargs: T.untyped,
         myArg: T::Array[String],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def TestFnNamedArgsSingleStringList(
@@ -2690,8 +2855,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleStringList may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2702,6 +2867,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2710,7 +2877,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def TestGemini(
@@ -2722,8 +2889,8 @@ module Baml
         
         raise ArgumentError.new("TestGemini may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2734,6 +2901,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2742,7 +2911,7 @@ module Baml
       params(
         varargs: T.untyped,
         img: Baml::Image,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def TestImageInput(
@@ -2754,8 +2923,8 @@ module Baml
         
         raise ArgumentError.new("TestImageInput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2766,6 +2935,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2774,7 +2945,7 @@ module Baml
       params(
         varargs: T.untyped,
         img: Baml::Image,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def TestImageInputAnthropic(
@@ -2786,8 +2957,8 @@ module Baml
         
         raise ArgumentError.new("TestImageInputAnthropic may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2798,6 +2969,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2806,7 +2979,7 @@ module Baml
       params(
         varargs: T.untyped,
         imgs: T::Array[Baml::Image],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def TestImageListInput(
@@ -2818,8 +2991,8 @@ module Baml
         
         raise ArgumentError.new("TestImageListInput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2830,6 +3003,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2838,7 +3013,7 @@ module Baml
       params(
         varargs: T.untyped,
         myArg: Baml::Types::NamedArgsSingleClass,myArg2: Baml::Types::NamedArgsSingleClass,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def TestMulticlassNamedArgs(
@@ -2850,8 +3025,8 @@ module Baml
         
         raise ArgumentError.new("TestMulticlassNamedArgs may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2862,6 +3037,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2870,7 +3047,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def TestOllama(
@@ -2882,8 +3059,8 @@ module Baml
         
         raise ArgumentError.new("TestOllama may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2894,6 +3071,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2902,7 +3081,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def TestOpenAILegacyProvider(
@@ -2914,8 +3093,8 @@ module Baml
         
         raise ArgumentError.new("TestOpenAILegacyProvider may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2926,6 +3105,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2934,7 +3115,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def TestOpenAIShorthand(
@@ -2946,8 +3127,8 @@ module Baml
         
         raise ArgumentError.new("TestOpenAIShorthand may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2958,6 +3139,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2966,7 +3149,7 @@ module Baml
       params(
         varargs: T.untyped,
         
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def TestRetryConstant(
@@ -2978,8 +3161,8 @@ module Baml
         
         raise ArgumentError.new("TestRetryConstant may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -2990,6 +3173,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -2998,7 +3183,7 @@ module Baml
       params(
         varargs: T.untyped,
         
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def TestRetryExponential(
@@ -3010,8 +3195,8 @@ module Baml
         
         raise ArgumentError.new("TestRetryExponential may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -3022,6 +3207,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -3030,7 +3217,7 @@ module Baml
       params(
         varargs: T.untyped,
         
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def TestSingleFallbackClient(
@@ -3042,8 +3229,8 @@ module Baml
         
         raise ArgumentError.new("TestSingleFallbackClient may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -3054,6 +3241,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -3062,7 +3251,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(String)
     }
     def TestVertex(
@@ -3074,8 +3263,8 @@ module Baml
         
         raise ArgumentError.new("TestVertex may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -3086,6 +3275,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -3094,7 +3285,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: T.any(String, T::Boolean),
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::Types::UnionTest_ReturnType)
     }
     def UnionTest_Function(
@@ -3106,8 +3297,8 @@ module Baml
         
         raise ArgumentError.new("UnionTest_Function may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -3118,6 +3309,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -3126,7 +3319,7 @@ module Baml
       params(
         varargs: T.untyped,
         a: Baml::Types::MalformedConstraints2,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Integer)
     }
     def UseMalformedConstraints(
@@ -3138,8 +3331,8 @@ module Baml
         
         raise ArgumentError.new("UseMalformedConstraints may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.call_function(
@@ -3150,6 +3343,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       (raw.parsed_using_types(Baml::Types))
     end
@@ -3171,7 +3366,7 @@ module Baml
       params(
         varargs: T.untyped,
         recipe: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[Baml::Types::Recipe])
     }
     def AaaSamOutputFormat(
@@ -3183,8 +3378,8 @@ module Baml
         
         raise ArgumentError.new("AaaSamOutputFormat may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -3195,6 +3390,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[Baml::PartialTypes::Recipe, Baml::Types::Recipe].new(
         ffi_stream: raw,
@@ -3206,7 +3403,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: Baml::Types::InputClass,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def AliasedInputClass(
@@ -3218,8 +3415,8 @@ module Baml
         
         raise ArgumentError.new("AliasedInputClass may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -3230,6 +3427,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -3241,7 +3440,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: Baml::Types::InputClass,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def AliasedInputClass2(
@@ -3253,8 +3452,8 @@ module Baml
         
         raise ArgumentError.new("AliasedInputClass2 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -3265,6 +3464,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -3276,7 +3477,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: Baml::Types::InputClassNested,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def AliasedInputClassNested(
@@ -3288,8 +3489,8 @@ module Baml
         
         raise ArgumentError.new("AliasedInputClassNested may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -3300,6 +3501,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -3311,7 +3514,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: T.any(Baml::Types::AliasedEnum, String),
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def AliasedInputEnum(
@@ -3323,8 +3526,8 @@ module Baml
         
         raise ArgumentError.new("AliasedInputEnum may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -3335,6 +3538,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -3346,7 +3551,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: T::Array[T.any(Baml::Types::AliasedEnum, String)],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def AliasedInputList(
@@ -3358,8 +3563,8 @@ module Baml
         
         raise ArgumentError.new("AliasedInputList may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -3370,6 +3575,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -3381,7 +3588,7 @@ module Baml
       params(
         varargs: T.untyped,
         aud: Baml::Audio,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def AudioInput(
@@ -3393,8 +3600,8 @@ module Baml
         
         raise ArgumentError.new("AudioInput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -3405,6 +3612,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -3416,7 +3625,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[T.any(Baml::Types::DynEnumTwo, String)])
     }
     def ClassifyDynEnumTwo(
@@ -3428,8 +3637,8 @@ module Baml
         
         raise ArgumentError.new("ClassifyDynEnumTwo may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -3440,6 +3649,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(Baml::Types::DynEnumTwo), T.any(Baml::Types::DynEnumTwo, String)].new(
         ffi_stream: raw,
@@ -3451,7 +3662,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[T.any(Baml::Types::Category, String)])
     }
     def ClassifyMessage(
@@ -3463,8 +3674,8 @@ module Baml
         
         raise ArgumentError.new("ClassifyMessage may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -3475,6 +3686,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(Baml::Types::Category), T.any(Baml::Types::Category, String)].new(
         ffi_stream: raw,
@@ -3486,7 +3699,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[T.any(Baml::Types::Category, String)])
     }
     def ClassifyMessage2(
@@ -3498,8 +3711,8 @@ module Baml
         
         raise ArgumentError.new("ClassifyMessage2 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -3510,6 +3723,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(Baml::Types::Category), T.any(Baml::Types::Category, String)].new(
         ffi_stream: raw,
@@ -3521,7 +3736,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[T.any(Baml::Types::Category, String)])
     }
     def ClassifyMessage3(
@@ -3533,8 +3748,8 @@ module Baml
         
         raise ArgumentError.new("ClassifyMessage3 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -3545,6 +3760,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(Baml::Types::Category), T.any(Baml::Types::Category, String)].new(
         ffi_stream: raw,
@@ -3556,7 +3773,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[T.any(Baml::Types::BookOrder, Baml::Types::FlightConfirmation, Baml::Types::GroceryReceipt)])
     }
     def CustomTask(
@@ -3568,8 +3785,8 @@ module Baml
         
         raise ArgumentError.new("CustomTask may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -3580,6 +3797,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(T.any(Baml::PartialTypes::BookOrder, Baml::PartialTypes::FlightConfirmation, Baml::PartialTypes::GroceryReceipt)), T.any(Baml::Types::BookOrder, Baml::Types::FlightConfirmation, Baml::Types::GroceryReceipt)].new(
         ffi_stream: raw,
@@ -3591,7 +3810,7 @@ module Baml
       params(
         varargs: T.untyped,
         img: Baml::Image,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def DescribeImage(
@@ -3603,8 +3822,8 @@ module Baml
         
         raise ArgumentError.new("DescribeImage may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -3615,6 +3834,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -3626,7 +3847,7 @@ module Baml
       params(
         varargs: T.untyped,
         classWithImage: Baml::Types::ClassWithImage,img2: Baml::Image,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def DescribeImage2(
@@ -3638,8 +3859,8 @@ module Baml
         
         raise ArgumentError.new("DescribeImage2 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -3650,6 +3871,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -3661,7 +3884,7 @@ module Baml
       params(
         varargs: T.untyped,
         classWithImage: Baml::Types::ClassWithImage,img2: Baml::Image,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def DescribeImage3(
@@ -3673,8 +3896,8 @@ module Baml
         
         raise ArgumentError.new("DescribeImage3 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -3685,6 +3908,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -3696,7 +3921,7 @@ module Baml
       params(
         varargs: T.untyped,
         classWithImage: Baml::Types::ClassWithImage,img2: Baml::Image,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def DescribeImage4(
@@ -3708,8 +3933,8 @@ module Baml
         
         raise ArgumentError.new("DescribeImage4 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -3720,6 +3945,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -3731,7 +3958,7 @@ module Baml
       params(
         varargs: T.untyped,
         
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[T.any(Baml::Types::OriginalA, Baml::Types::OriginalB)])
     }
     def DifferentiateUnions(
@@ -3743,8 +3970,8 @@ module Baml
         
         raise ArgumentError.new("DifferentiateUnions may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -3755,6 +3982,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(T.any(Baml::PartialTypes::OriginalA, Baml::PartialTypes::OriginalB)), T.any(Baml::Types::OriginalA, Baml::Types::OriginalB)].new(
         ffi_stream: raw,
@@ -3766,7 +3995,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[Baml::Types::DummyOutput])
     }
     def DummyOutputFunction(
@@ -3778,8 +4007,8 @@ module Baml
         
         raise ArgumentError.new("DummyOutputFunction may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -3790,6 +4019,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[Baml::PartialTypes::DummyOutput, Baml::Types::DummyOutput].new(
         ffi_stream: raw,
@@ -3801,7 +4032,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: Baml::Types::DynamicClassOne,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[Baml::Types::DynamicClassTwo])
     }
     def DynamicFunc(
@@ -3813,8 +4044,8 @@ module Baml
         
         raise ArgumentError.new("DynamicFunc may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -3825,6 +4056,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[Baml::PartialTypes::DynamicClassTwo, Baml::Types::DynamicClassTwo].new(
         ffi_stream: raw,
@@ -3836,7 +4069,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: Baml::Types::DynInputOutput,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[Baml::Types::DynInputOutput])
     }
     def DynamicInputOutput(
@@ -3848,8 +4081,8 @@ module Baml
         
         raise ArgumentError.new("DynamicInputOutput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -3860,6 +4093,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[Baml::PartialTypes::DynInputOutput, Baml::Types::DynInputOutput].new(
         ffi_stream: raw,
@@ -3871,7 +4106,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: T::Array[Baml::Types::DynInputOutput],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[T::Array[Baml::Types::DynInputOutput]])
     }
     def DynamicListInputOutput(
@@ -3883,8 +4118,8 @@ module Baml
         
         raise ArgumentError.new("DynamicListInputOutput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -3895,6 +4130,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T::Array[Baml::PartialTypes::DynInputOutput], T::Array[Baml::Types::DynInputOutput]].new(
         ffi_stream: raw,
@@ -3906,7 +4143,7 @@ module Baml
       params(
         varargs: T.untyped,
         
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def ExpectFailure(
@@ -3918,8 +4155,8 @@ module Baml
         
         raise ArgumentError.new("ExpectFailure may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -3930,6 +4167,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -3941,7 +4180,7 @@ module Baml
       params(
         varargs: T.untyped,
         document: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[Baml::Types::ContactInfo])
     }
     def ExtractContactInfo(
@@ -3953,8 +4192,8 @@ module Baml
         
         raise ArgumentError.new("ExtractContactInfo may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -3965,6 +4204,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[Baml::PartialTypes::ContactInfo, Baml::Types::ContactInfo].new(
         ffi_stream: raw,
@@ -3976,7 +4217,7 @@ module Baml
       params(
         varargs: T.untyped,
         text: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[T::Array[T.any(Baml::Types::Hobby, String)]])
     }
     def ExtractHobby(
@@ -3988,8 +4229,8 @@ module Baml
         
         raise ArgumentError.new("ExtractHobby may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4000,6 +4241,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T::Array[T.nilable(Baml::Types::Hobby)], T::Array[T.any(Baml::Types::Hobby, String)]].new(
         ffi_stream: raw,
@@ -4011,7 +4254,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[T::Array[String]])
     }
     def ExtractNames(
@@ -4023,8 +4266,8 @@ module Baml
         
         raise ArgumentError.new("ExtractNames may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4035,6 +4278,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T::Array[T.nilable(String)], T::Array[String]].new(
         ffi_stream: raw,
@@ -4046,7 +4291,7 @@ module Baml
       params(
         varargs: T.untyped,
         text: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[T::Array[Baml::Types::Person]])
     }
     def ExtractPeople(
@@ -4058,8 +4303,8 @@ module Baml
         
         raise ArgumentError.new("ExtractPeople may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4070,6 +4315,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T::Array[Baml::PartialTypes::Person], T::Array[Baml::Types::Person]].new(
         ffi_stream: raw,
@@ -4081,7 +4328,7 @@ module Baml
       params(
         varargs: T.untyped,
         email: String,reason: T.any(String, String),
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[Baml::Types::ReceiptInfo])
     }
     def ExtractReceiptInfo(
@@ -4093,8 +4340,8 @@ module Baml
         
         raise ArgumentError.new("ExtractReceiptInfo may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4105,6 +4352,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[Baml::PartialTypes::ReceiptInfo, Baml::Types::ReceiptInfo].new(
         ffi_stream: raw,
@@ -4116,7 +4365,7 @@ module Baml
       params(
         varargs: T.untyped,
         resume: String,img: T.nilable(Baml::Image),
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[Baml::Types::Resume])
     }
     def ExtractResume(
@@ -4128,8 +4377,8 @@ module Baml
         
         raise ArgumentError.new("ExtractResume may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4140,6 +4389,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[Baml::PartialTypes::Resume, Baml::Types::Resume].new(
         ffi_stream: raw,
@@ -4151,7 +4402,7 @@ module Baml
       params(
         varargs: T.untyped,
         resume: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[Baml::Types::Resume])
     }
     def ExtractResume2(
@@ -4163,8 +4414,8 @@ module Baml
         
         raise ArgumentError.new("ExtractResume2 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4175,6 +4426,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[Baml::PartialTypes::Resume, Baml::Types::Resume].new(
         ffi_stream: raw,
@@ -4186,7 +4439,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[T.nilable(Baml::Types::ClassOptionalOutput)])
     }
     def FnClassOptionalOutput(
@@ -4198,8 +4451,8 @@ module Baml
         
         raise ArgumentError.new("FnClassOptionalOutput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4210,6 +4463,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[Baml::PartialTypes::ClassOptionalOutput, T.nilable(Baml::Types::ClassOptionalOutput)].new(
         ffi_stream: raw,
@@ -4221,7 +4476,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[T.nilable(Baml::Types::ClassOptionalOutput2)])
     }
     def FnClassOptionalOutput2(
@@ -4233,8 +4488,8 @@ module Baml
         
         raise ArgumentError.new("FnClassOptionalOutput2 may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4245,6 +4500,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[Baml::PartialTypes::ClassOptionalOutput2, T.nilable(Baml::Types::ClassOptionalOutput2)].new(
         ffi_stream: raw,
@@ -4256,7 +4513,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[T::Array[T.any(Baml::Types::EnumOutput, String)]])
     }
     def FnEnumListOutput(
@@ -4268,8 +4525,8 @@ module Baml
         
         raise ArgumentError.new("FnEnumListOutput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4280,6 +4537,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T::Array[T.nilable(Baml::Types::EnumOutput)], T::Array[T.any(Baml::Types::EnumOutput, String)]].new(
         ffi_stream: raw,
@@ -4291,7 +4550,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[T.any(Baml::Types::EnumOutput, String)])
     }
     def FnEnumOutput(
@@ -4303,8 +4562,8 @@ module Baml
         
         raise ArgumentError.new("FnEnumOutput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4315,6 +4574,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(Baml::Types::EnumOutput), T.any(Baml::Types::EnumOutput, String)].new(
         ffi_stream: raw,
@@ -4326,7 +4587,7 @@ module Baml
       params(
         varargs: T.untyped,
         myString: T.nilable(String),
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def FnNamedArgsSingleStringOptional(
@@ -4338,8 +4599,8 @@ module Baml
         
         raise ArgumentError.new("FnNamedArgsSingleStringOptional may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4350,6 +4611,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -4361,7 +4624,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[T::Boolean])
     }
     def FnOutputBool(
@@ -4373,8 +4636,8 @@ module Baml
         
         raise ArgumentError.new("FnOutputBool may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4385,6 +4648,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(T::Boolean), T::Boolean].new(
         ffi_stream: raw,
@@ -4396,7 +4661,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[Baml::Types::TestOutputClass])
     }
     def FnOutputClass(
@@ -4408,8 +4673,8 @@ module Baml
         
         raise ArgumentError.new("FnOutputClass may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4420,6 +4685,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[Baml::PartialTypes::TestOutputClass, Baml::Types::TestOutputClass].new(
         ffi_stream: raw,
@@ -4431,7 +4698,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[T::Array[Baml::Types::TestOutputClass]])
     }
     def FnOutputClassList(
@@ -4443,8 +4710,8 @@ module Baml
         
         raise ArgumentError.new("FnOutputClassList may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4455,6 +4722,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T::Array[Baml::PartialTypes::TestOutputClass], T::Array[Baml::Types::TestOutputClass]].new(
         ffi_stream: raw,
@@ -4466,7 +4735,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[Baml::Types::TestClassNested])
     }
     def FnOutputClassNested(
@@ -4478,8 +4747,8 @@ module Baml
         
         raise ArgumentError.new("FnOutputClassNested may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4490,6 +4759,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[Baml::PartialTypes::TestClassNested, Baml::Types::TestClassNested].new(
         ffi_stream: raw,
@@ -4501,7 +4772,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[Baml::Types::TestClassWithEnum])
     }
     def FnOutputClassWithEnum(
@@ -4513,8 +4784,8 @@ module Baml
         
         raise ArgumentError.new("FnOutputClassWithEnum may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4525,6 +4796,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[Baml::PartialTypes::TestClassWithEnum, Baml::Types::TestClassWithEnum].new(
         ffi_stream: raw,
@@ -4536,7 +4809,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[T::Array[String]])
     }
     def FnOutputStringList(
@@ -4548,8 +4821,8 @@ module Baml
         
         raise ArgumentError.new("FnOutputStringList may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4560,6 +4833,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T::Array[T.nilable(String)], T::Array[String]].new(
         ffi_stream: raw,
@@ -4571,7 +4846,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[T.any(Baml::Types::TestEnum, String)])
     }
     def FnTestAliasedEnumOutput(
@@ -4583,8 +4858,8 @@ module Baml
         
         raise ArgumentError.new("FnTestAliasedEnumOutput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4595,6 +4870,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(Baml::Types::TestEnum), T.any(Baml::Types::TestEnum, String)].new(
         ffi_stream: raw,
@@ -4606,7 +4883,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[Baml::Types::TestClassAlias])
     }
     def FnTestClassAlias(
@@ -4618,8 +4895,8 @@ module Baml
         
         raise ArgumentError.new("FnTestClassAlias may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4630,6 +4907,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[Baml::PartialTypes::TestClassAlias, Baml::Types::TestClassAlias].new(
         ffi_stream: raw,
@@ -4641,7 +4920,7 @@ module Baml
       params(
         varargs: T.untyped,
         myArg: T.any(Baml::Types::NamedArgsSingleEnum, String),
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def FnTestNamedArgsSingleEnum(
@@ -4653,8 +4932,8 @@ module Baml
         
         raise ArgumentError.new("FnTestNamedArgsSingleEnum may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4665,6 +4944,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -4676,7 +4957,7 @@ module Baml
       params(
         varargs: T.untyped,
         text: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[Baml::Types::RaysData])
     }
     def GetDataType(
@@ -4688,8 +4969,8 @@ module Baml
         
         raise ArgumentError.new("GetDataType may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4700,6 +4981,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[Baml::PartialTypes::RaysData, Baml::Types::RaysData].new(
         ffi_stream: raw,
@@ -4711,7 +4994,7 @@ module Baml
       params(
         varargs: T.untyped,
         email: Baml::Types::Email,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[Baml::Types::OrderInfo])
     }
     def GetOrderInfo(
@@ -4723,8 +5006,8 @@ module Baml
         
         raise ArgumentError.new("GetOrderInfo may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4735,6 +5018,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[Baml::PartialTypes::OrderInfo, Baml::Types::OrderInfo].new(
         ffi_stream: raw,
@@ -4746,7 +5031,7 @@ module Baml
       params(
         varargs: T.untyped,
         query: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[Baml::Types::SearchParams])
     }
     def GetQuery(
@@ -4758,8 +5043,8 @@ module Baml
         
         raise ArgumentError.new("GetQuery may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4770,6 +5055,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[Baml::PartialTypes::SearchParams, Baml::Types::SearchParams].new(
         ffi_stream: raw,
@@ -4781,7 +5068,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[Baml::Types::DynamicOutput])
     }
     def MyFunc(
@@ -4793,8 +5080,8 @@ module Baml
         
         raise ArgumentError.new("MyFunc may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4805,6 +5092,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[Baml::PartialTypes::DynamicOutput, Baml::Types::DynamicOutput].new(
         ffi_stream: raw,
@@ -4816,7 +5105,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[T::Array[T.nilable(Baml::Types::OptionalTest_ReturnType)]])
     }
     def OptionalTest_Function(
@@ -4828,8 +5117,8 @@ module Baml
         
         raise ArgumentError.new("OptionalTest_Function may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4840,6 +5129,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T::Array[Baml::PartialTypes::OptionalTest_ReturnType], T::Array[T.nilable(Baml::Types::OptionalTest_ReturnType)]].new(
         ffi_stream: raw,
@@ -4851,7 +5142,7 @@ module Baml
       params(
         varargs: T.untyped,
         name: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[Baml::Types::FooAny])
     }
     def PredictAge(
@@ -4863,8 +5154,8 @@ module Baml
         
         raise ArgumentError.new("PredictAge may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4875,6 +5166,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[Baml::PartialTypes::FooAny, Baml::Types::FooAny].new(
         ffi_stream: raw,
@@ -4886,7 +5179,7 @@ module Baml
       params(
         varargs: T.untyped,
         inp: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[Baml::Checked[Integer]])
     }
     def PredictAgeBare(
@@ -4898,8 +5191,8 @@ module Baml
         
         raise ArgumentError.new("PredictAgeBare may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4910,6 +5203,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[Baml::Checked[T.nilable(Integer)], Baml::Checked[Integer]].new(
         ffi_stream: raw,
@@ -4921,7 +5216,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def PromptTestClaude(
@@ -4933,8 +5228,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestClaude may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4945,6 +5240,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -4956,7 +5253,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def PromptTestClaudeChat(
@@ -4968,8 +5265,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestClaudeChat may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -4980,6 +5277,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -4991,7 +5290,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def PromptTestClaudeChatNoSystem(
@@ -5003,8 +5302,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestClaudeChatNoSystem may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5015,6 +5314,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -5026,7 +5327,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def PromptTestOpenAI(
@@ -5038,8 +5339,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestOpenAI may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5050,6 +5351,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -5061,7 +5364,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def PromptTestOpenAIChat(
@@ -5073,8 +5376,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestOpenAIChat may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5085,6 +5388,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -5096,7 +5401,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def PromptTestOpenAIChatNoSystem(
@@ -5108,8 +5413,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestOpenAIChatNoSystem may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5120,6 +5425,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -5131,7 +5438,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def PromptTestStreaming(
@@ -5143,8 +5450,8 @@ module Baml
         
         raise ArgumentError.new("PromptTestStreaming may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5155,6 +5462,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -5166,7 +5475,7 @@ module Baml
       params(
         varargs: T.untyped,
         inp: Integer,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[Integer])
     }
     def ReturnFailingAssert(
@@ -5178,8 +5487,8 @@ module Baml
         
         raise ArgumentError.new("ReturnFailingAssert may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5190,6 +5499,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(Integer), Integer].new(
         ffi_stream: raw,
@@ -5201,7 +5512,7 @@ module Baml
       params(
         varargs: T.untyped,
         a: Integer,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[Baml::Types::MalformedConstraints])
     }
     def ReturnMalformedConstraints(
@@ -5213,8 +5524,8 @@ module Baml
         
         raise ArgumentError.new("ReturnMalformedConstraints may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5225,6 +5536,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[Baml::PartialTypes::MalformedConstraints, Baml::Types::MalformedConstraints].new(
         ffi_stream: raw,
@@ -5236,7 +5549,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[Baml::Types::Schema])
     }
     def SchemaDescriptions(
@@ -5248,8 +5561,8 @@ module Baml
         
         raise ArgumentError.new("SchemaDescriptions may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5260,6 +5573,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[Baml::PartialTypes::Schema, Baml::Types::Schema].new(
         ffi_stream: raw,
@@ -5271,7 +5586,7 @@ module Baml
       params(
         varargs: T.untyped,
         digits: Integer,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[Baml::Types::BigNumbers])
     }
     def StreamBigNumbers(
@@ -5283,8 +5598,8 @@ module Baml
         
         raise ArgumentError.new("StreamBigNumbers may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5295,6 +5610,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[Baml::PartialTypes::BigNumbers, Baml::Types::BigNumbers].new(
         ffi_stream: raw,
@@ -5306,7 +5623,7 @@ module Baml
       params(
         varargs: T.untyped,
         theme: String,length: Integer,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[Baml::Types::TwoStoriesOneTitle])
     }
     def StreamFailingAssertion(
@@ -5318,8 +5635,8 @@ module Baml
         
         raise ArgumentError.new("StreamFailingAssertion may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5330,6 +5647,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[Baml::PartialTypes::TwoStoriesOneTitle, Baml::Types::TwoStoriesOneTitle].new(
         ffi_stream: raw,
@@ -5341,7 +5660,7 @@ module Baml
       params(
         varargs: T.untyped,
         digits: Integer,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[Integer])
     }
     def StreamOneBigNumber(
@@ -5353,8 +5672,8 @@ module Baml
         
         raise ArgumentError.new("StreamOneBigNumber may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5365,6 +5684,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(Integer), Integer].new(
         ffi_stream: raw,
@@ -5376,7 +5697,7 @@ module Baml
       params(
         varargs: T.untyped,
         digits: Integer,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[T::Array[T.any(Integer, String)]])
     }
     def StreamUnionIntegers(
@@ -5388,8 +5709,8 @@ module Baml
         
         raise ArgumentError.new("StreamUnionIntegers may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5400,6 +5721,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T::Array[T.nilable(T.any(T.nilable(Integer), T.nilable(String)))], T::Array[T.any(Integer, String)]].new(
         ffi_stream: raw,
@@ -5411,7 +5734,7 @@ module Baml
       params(
         varargs: T.untyped,
         digits: Integer,yapping: T::Boolean,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[Baml::Types::CompoundBigNumbers])
     }
     def StreamingCompoundNumbers(
@@ -5423,8 +5746,8 @@ module Baml
         
         raise ArgumentError.new("StreamingCompoundNumbers may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5435,6 +5758,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[Baml::PartialTypes::CompoundBigNumbers, Baml::Types::CompoundBigNumbers].new(
         ffi_stream: raw,
@@ -5446,7 +5771,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestAnthropic(
@@ -5458,8 +5783,8 @@ module Baml
         
         raise ArgumentError.new("TestAnthropic may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5470,6 +5795,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -5481,7 +5808,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestAnthropicShorthand(
@@ -5493,8 +5820,8 @@ module Baml
         
         raise ArgumentError.new("TestAnthropicShorthand may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5505,6 +5832,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -5516,7 +5845,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestAws(
@@ -5528,8 +5857,8 @@ module Baml
         
         raise ArgumentError.new("TestAws may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5540,6 +5869,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -5551,7 +5882,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestAzure(
@@ -5563,8 +5894,8 @@ module Baml
         
         raise ArgumentError.new("TestAzure may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5575,6 +5906,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -5586,7 +5919,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,not_cached: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestCaching(
@@ -5598,8 +5931,8 @@ module Baml
         
         raise ArgumentError.new("TestCaching may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5610,6 +5943,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -5621,7 +5956,7 @@ module Baml
       params(
         varargs: T.untyped,
         
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestFallbackClient(
@@ -5633,8 +5968,8 @@ module Baml
         
         raise ArgumentError.new("TestFallbackClient may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5645,6 +5980,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -5656,7 +5993,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestFallbackToShorthand(
@@ -5668,8 +6005,8 @@ module Baml
         
         raise ArgumentError.new("TestFallbackToShorthand may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5680,6 +6017,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -5691,7 +6030,7 @@ module Baml
       params(
         varargs: T.untyped,
         myBool: T::Boolean,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestFnNamedArgsSingleBool(
@@ -5703,8 +6042,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleBool may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5715,6 +6054,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -5726,7 +6067,7 @@ module Baml
       params(
         varargs: T.untyped,
         myArg: Baml::Types::NamedArgsSingleClass,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestFnNamedArgsSingleClass(
@@ -5738,8 +6079,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleClass may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5750,6 +6091,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -5761,7 +6104,7 @@ module Baml
       params(
         varargs: T.untyped,
         myArg: T::Array[T.any(Baml::Types::NamedArgsSingleEnumList, String)],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestFnNamedArgsSingleEnumList(
@@ -5773,8 +6116,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleEnumList may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5785,6 +6128,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -5796,7 +6141,7 @@ module Baml
       params(
         varargs: T.untyped,
         myFloat: Float,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestFnNamedArgsSingleFloat(
@@ -5808,8 +6153,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleFloat may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5820,6 +6165,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -5831,7 +6178,7 @@ module Baml
       params(
         varargs: T.untyped,
         myInt: Integer,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestFnNamedArgsSingleInt(
@@ -5843,8 +6190,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleInt may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5855,6 +6202,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -5866,7 +6215,7 @@ module Baml
       params(
         varargs: T.untyped,
         myMap: T::Hash[String, Baml::Types::StringToClassEntry],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[T::Hash[String, Baml::Types::StringToClassEntry]])
     }
     def TestFnNamedArgsSingleMapStringToClass(
@@ -5878,8 +6227,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleMapStringToClass may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5890,6 +6239,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T::Hash[String, Baml::PartialTypes::StringToClassEntry], T::Hash[String, Baml::Types::StringToClassEntry]].new(
         ffi_stream: raw,
@@ -5901,7 +6252,7 @@ module Baml
       params(
         varargs: T.untyped,
         myMap: T::Hash[String, T::Hash[String, String]],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[T::Hash[String, T::Hash[String, String]]])
     }
     def TestFnNamedArgsSingleMapStringToMap(
@@ -5913,8 +6264,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleMapStringToMap may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5925,6 +6276,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T::Hash[String, T::Hash[String, T.nilable(String)]], T::Hash[String, T::Hash[String, String]]].new(
         ffi_stream: raw,
@@ -5936,7 +6289,7 @@ module Baml
       params(
         varargs: T.untyped,
         myMap: T::Hash[String, String],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[T::Hash[String, String]])
     }
     def TestFnNamedArgsSingleMapStringToString(
@@ -5948,8 +6301,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleMapStringToString may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5960,6 +6313,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T::Hash[String, T.nilable(String)], T::Hash[String, String]].new(
         ffi_stream: raw,
@@ -5971,7 +6326,7 @@ module Baml
       params(
         varargs: T.untyped,
         myString: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestFnNamedArgsSingleString(
@@ -5983,8 +6338,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleString may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -5995,6 +6350,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -6006,7 +6363,7 @@ module Baml
       params(
         varargs: T.untyped,
         myStringArray: T::Array[String],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestFnNamedArgsSingleStringArray(
@@ -6018,8 +6375,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleStringArray may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -6030,6 +6387,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -6041,7 +6400,7 @@ module Baml
       params(
         varargs: T.untyped,
         myArg: T::Array[String],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestFnNamedArgsSingleStringList(
@@ -6053,8 +6412,8 @@ module Baml
         
         raise ArgumentError.new("TestFnNamedArgsSingleStringList may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -6065,6 +6424,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -6076,7 +6437,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestGemini(
@@ -6088,8 +6449,8 @@ module Baml
         
         raise ArgumentError.new("TestGemini may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -6100,6 +6461,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -6111,7 +6474,7 @@ module Baml
       params(
         varargs: T.untyped,
         img: Baml::Image,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestImageInput(
@@ -6123,8 +6486,8 @@ module Baml
         
         raise ArgumentError.new("TestImageInput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -6135,6 +6498,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -6146,7 +6511,7 @@ module Baml
       params(
         varargs: T.untyped,
         img: Baml::Image,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestImageInputAnthropic(
@@ -6158,8 +6523,8 @@ module Baml
         
         raise ArgumentError.new("TestImageInputAnthropic may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -6170,6 +6535,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -6181,7 +6548,7 @@ module Baml
       params(
         varargs: T.untyped,
         imgs: T::Array[Baml::Image],
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestImageListInput(
@@ -6193,8 +6560,8 @@ module Baml
         
         raise ArgumentError.new("TestImageListInput may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -6205,6 +6572,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -6216,7 +6585,7 @@ module Baml
       params(
         varargs: T.untyped,
         myArg: Baml::Types::NamedArgsSingleClass,myArg2: Baml::Types::NamedArgsSingleClass,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestMulticlassNamedArgs(
@@ -6228,8 +6597,8 @@ module Baml
         
         raise ArgumentError.new("TestMulticlassNamedArgs may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -6240,6 +6609,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -6251,7 +6622,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestOllama(
@@ -6263,8 +6634,8 @@ module Baml
         
         raise ArgumentError.new("TestOllama may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -6275,6 +6646,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -6286,7 +6659,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestOpenAILegacyProvider(
@@ -6298,8 +6671,8 @@ module Baml
         
         raise ArgumentError.new("TestOpenAILegacyProvider may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -6310,6 +6683,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -6321,7 +6696,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestOpenAIShorthand(
@@ -6333,8 +6708,8 @@ module Baml
         
         raise ArgumentError.new("TestOpenAIShorthand may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -6345,6 +6720,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -6356,7 +6733,7 @@ module Baml
       params(
         varargs: T.untyped,
         
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestRetryConstant(
@@ -6368,8 +6745,8 @@ module Baml
         
         raise ArgumentError.new("TestRetryConstant may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -6380,6 +6757,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -6391,7 +6770,7 @@ module Baml
       params(
         varargs: T.untyped,
         
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestRetryExponential(
@@ -6403,8 +6782,8 @@ module Baml
         
         raise ArgumentError.new("TestRetryExponential may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -6415,6 +6794,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -6426,7 +6807,7 @@ module Baml
       params(
         varargs: T.untyped,
         
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestSingleFallbackClient(
@@ -6438,8 +6819,8 @@ module Baml
         
         raise ArgumentError.new("TestSingleFallbackClient may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -6450,6 +6831,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -6461,7 +6844,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: String,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[String])
     }
     def TestVertex(
@@ -6473,8 +6856,8 @@ module Baml
         
         raise ArgumentError.new("TestVertex may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -6485,6 +6868,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(String), String].new(
         ffi_stream: raw,
@@ -6496,7 +6881,7 @@ module Baml
       params(
         varargs: T.untyped,
         input: T.any(String, T::Boolean),
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[Baml::Types::UnionTest_ReturnType])
     }
     def UnionTest_Function(
@@ -6508,8 +6893,8 @@ module Baml
         
         raise ArgumentError.new("UnionTest_Function may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -6520,6 +6905,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[Baml::PartialTypes::UnionTest_ReturnType, Baml::Types::UnionTest_ReturnType].new(
         ffi_stream: raw,
@@ -6531,7 +6918,7 @@ module Baml
       params(
         varargs: T.untyped,
         a: Baml::Types::MalformedConstraints2,
-        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry)]
+        baml_options: T::Hash[Symbol, T.any(Baml::TypeBuilder, Baml::ClientRegistry, Baml::Collector, Baml::CancelToken)]
       ).returns(Baml::BamlStream[Integer])
     }
     def UseMalformedConstraints(
@@ -6543,8 +6930,8 @@ module Baml
         
         raise ArgumentError.new("UseMalformedConstraints may only be called with keyword arguments")
       end
-      if (baml_options.keys - [:client_registry, :tb]).any?
-        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :client_registry, :tb): #{baml_options.keys - [:client_registry, :tb]}")
+      if (baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]).any?
+        raise ArgumentError.new("Received unknown keys in baml_options (valid keys: :cancel_token, :client_registry, :collector, :tb): #{baml_options.keys - [:cancel_token, :client_registry, :collector, :tb]}")
       end
 
       raw = @runtime.stream_function(
@@ -6555,6 +6942,8 @@ module Baml
         @ctx_manager,
         baml_options[:tb]&.instance_variable_get(:@registry),
         baml_options[:client_registry],
+        baml_options[:collector],
+        baml_options[:cancel_token],
       )
       Baml::BamlStream[T.nilable(Integer), Integer].new(
         ffi_stream: raw,
@@ -13,6 +13,7 @@
 # rubocop: disable
 # formatter:off
 # typed: false
+require "date"
 require "sorbet-runtime"
 
 require_relative "types"
@@ -15,9 +15,10 @@ $ pnpm add @boundaryml/baml
 // tslint:disable
 // @ts-nocheck
 // biome-ignore format: autogenerated code
-import { BamlRuntime, FunctionResult, BamlCtxManager, BamlStream, Image, ClientRegistry, BamlValidationError, createBamlValidationError } from "@boundaryml/baml"
+import { BamlRuntime, FunctionResult, BamlCtxManager, BamlStream, Image, ClientRegistry, Collector, CancelToken, BamlValidationError, createBamlValidationError } from "@boundaryml/baml"
 import {BigNumbers, Blah, BookOrder, ClassOptionalOutput, ClassOptionalOutput2, ClassWithImage, CompoundBigNumbers, ContactInfo, CustomTaskResult, DummyOutput, DynInputOutput, DynamicClassOne, DynamicClassTwo, DynamicOutput, Earthling, Education, Email, EmailAddress, Event, FakeImage, FlightConfirmation, FooAny, GroceryReceipt, InnerClass, InnerClass2, InputClass, InputClassNested, MalformedConstraints, MalformedConstraints2, Martian, NamedArgsSingleClass, Nested, Nested2, OptionalTest_Prop1, OptionalTest_ReturnType, OrderInfo, OriginalA, OriginalB, Person, PhoneNumber, Quantity, RaysData, ReceiptInfo, ReceiptItem, Recipe, Resume, Schema, SearchParams, SomeClassNestedDynamic, StringToClassEntry, TestClassAlias, TestClassNested, TestClassWithEnum, TestOutputClass, TwoStoriesOneTitle, UnionTest_ReturnType, WithReasoning, AliasedEnum, Category, Category2, Category3, Color, DataType, DynEnumOne, DynEnumTwo, EnumInClass, EnumOutput, Hobby, NamedArgsSingleEnum, NamedArgsSingleEnumList, OptionalTest_CategoryType, OrderStatus, Tag, TestEnum} from "./types"
 import TypeBuilder from "./type_builder"
+import * as partial_types from "./partial_types"
 import { DO_NOT_USE_DIRECTLY_UNLESS_YOU_KNOW_WHAT_YOURE_DOING_CTX, DO_NOT_USE_DIRECTLY_UNLESS_YOU_KNOW_WHAT_YOURE_DOING_RUNTIME } from "./globals"
 
 export type RecursivePartialNull<T> = T extends object
@@ -26,6 +27,23 @@ export type RecursivePartialNull<T> = T extends object
     }
   : T | null;
 
+// Cancels the call, or stream, its token is passed to when `signal` aborts.
+// `release` must be called once the call is over, so that a long-lived signal
+// doesn't keep a listener for every call it was ever passed to.
+function cancelTokenFor(signal?: AbortSignal): { token?: CancelToken, release: () => void } {
+  if (signal === undefined) {
+    return { token: undefined, release: () => {} }
+  }
+  const token = new CancelToken()
+  const onAbort = () => token.cancel()
+  if (signal.aborted) {
+    token.cancel()
+  } else {
+    signal.addEventListener("abort", onAbort, { once: true })
+  }
+  return { token, release: () => signal.removeEventListener("abort", onAbort) }
+}
+
 export class BamlAsyncClient {
   private runtime: BamlRuntime
   private ctx_manager: BamlCtxManager
@@ -44,8 +62,9 @@ export class BamlAsyncClient {
   
   async AaaSamOutputFormat(
       recipe: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<Recipe> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "AaaSamOutputFormat",
@@ -55,22 +74,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as Recipe
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async AliasedInputClass(
       input: InputClass,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "AliasedInputClass",
@@ -80,22 +107,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async AliasedInputClass2(
       input: InputClass,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "AliasedInputClass2",
@@ -105,22 +140,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async AliasedInputClassNested(
       input: InputClassNested,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "AliasedInputClassNested",
@@ -130,22 +173,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async AliasedInputEnum(
       input: AliasedEnum,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "AliasedInputEnum",
@@ -155,22 +206,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async AliasedInputList(
       input: AliasedEnum[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "AliasedInputList",
@@ -180,22 +239,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async AudioInput(
       aud: Audio,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "AudioInput",
@@ -205,22 +272,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async ClassifyDynEnumTwo(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<(string | DynEnumTwo)> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "ClassifyDynEnumTwo",
@@ -230,22 +305,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as (string | DynEnumTwo)
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async ClassifyMessage(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<Category> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "ClassifyMessage",
@@ -255,22 +338,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as Category
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async ClassifyMessage2(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<Category> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "ClassifyMessage2",
@@ -280,22 +371,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as Category
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async ClassifyMessage3(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<Category> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "ClassifyMessage3",
@@ -305,22 +404,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as Category
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async CustomTask(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<BookOrder | FlightConfirmation | GroceryReceipt> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "CustomTask",
@@ -330,22 +437,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as BookOrder | FlightConfirmation | GroceryReceipt
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async DescribeImage(
       img: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "DescribeImage",
@@ -355,22 +470,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async DescribeImage2(
       classWithImage: ClassWithImage,img2: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "DescribeImage2",
@@ -380,22 +503,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async DescribeImage3(
       classWithImage: ClassWithImage,img2: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "DescribeImage3",
@@ -405,22 +536,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async DescribeImage4(
       classWithImage: ClassWithImage,img2: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "DescribeImage4",
@@ -430,22 +569,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async DifferentiateUnions(
       
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<OriginalA | OriginalB> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "DifferentiateUnions",
@@ -455,22 +602,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as OriginalA | OriginalB
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async DummyOutputFunction(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<DummyOutput> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "DummyOutputFunction",
@@ -480,22 +635,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as DummyOutput
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async DynamicFunc(
       input: DynamicClassOne,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<DynamicClassTwo> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "DynamicFunc",
@@ -505,22 +668,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as DynamicClassTwo
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async DynamicInputOutput(
       input: DynInputOutput,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<DynInputOutput> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "DynamicInputOutput",
@@ -530,22 +701,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as DynInputOutput
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async DynamicListInputOutput(
       input: DynInputOutput[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<DynInputOutput[]> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "DynamicListInputOutput",
@@ -555,22 +734,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as DynInputOutput[]
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async ExpectFailure(
       
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "ExpectFailure",
@@ -580,22 +767,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async ExtractContactInfo(
       document: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<ContactInfo> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "ExtractContactInfo",
@@ -605,22 +800,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as ContactInfo
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async ExtractHobby(
       text: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<(string | Hobby)[]> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "ExtractHobby",
@@ -630,22 +833,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as (string | Hobby)[]
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async ExtractNames(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string[]> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "ExtractNames",
@@ -655,22 +866,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string[]
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async ExtractPeople(
       text: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<Person[]> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "ExtractPeople",
@@ -680,22 +899,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as Person[]
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async ExtractReceiptInfo(
       email: string,reason: "curiosity" | "personal_finance",
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<ReceiptInfo> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "ExtractReceiptInfo",
@@ -705,22 +932,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as ReceiptInfo
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async ExtractResume(
       resume: string,img?: Image | null,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<Resume> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "ExtractResume",
@@ -730,22 +965,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as Resume
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async ExtractResume2(
       resume: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<Resume> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "ExtractResume2",
@@ -755,22 +998,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as Resume
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async FnClassOptionalOutput(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<ClassOptionalOutput | null> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "FnClassOptionalOutput",
@@ -780,22 +1031,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as ClassOptionalOutput | null
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async FnClassOptionalOutput2(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<ClassOptionalOutput2 | null> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "FnClassOptionalOutput2",
@@ -805,22 +1064,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as ClassOptionalOutput2 | null
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async FnEnumListOutput(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<EnumOutput[]> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "FnEnumListOutput",
@@ -830,22 +1097,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as EnumOutput[]
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async FnEnumOutput(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<EnumOutput> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "FnEnumOutput",
@@ -855,22 +1130,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as EnumOutput
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async FnNamedArgsSingleStringOptional(
       myString?: string | null,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "FnNamedArgsSingleStringOptional",
@@ -880,22 +1163,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async FnOutputBool(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<boolean> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "FnOutputBool",
@@ -905,22 +1196,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as boolean
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async FnOutputClass(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<TestOutputClass> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "FnOutputClass",
@@ -930,22 +1229,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as TestOutputClass
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async FnOutputClassList(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<TestOutputClass[]> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "FnOutputClassList",
@@ -955,22 +1262,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as TestOutputClass[]
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async FnOutputClassNested(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<TestClassNested> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "FnOutputClassNested",
@@ -980,22 +1295,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as TestClassNested
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async FnOutputClassWithEnum(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<TestClassWithEnum> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "FnOutputClassWithEnum",
@@ -1005,22 +1328,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as TestClassWithEnum
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async FnOutputStringList(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string[]> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "FnOutputStringList",
@@ -1030,22 +1361,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string[]
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async FnTestAliasedEnumOutput(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<TestEnum> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "FnTestAliasedEnumOutput",
@@ -1055,22 +1394,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as TestEnum
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async FnTestClassAlias(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<TestClassAlias> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "FnTestClassAlias",
@@ -1080,22 +1427,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as TestClassAlias
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async FnTestNamedArgsSingleEnum(
       myArg: NamedArgsSingleEnum,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "FnTestNamedArgsSingleEnum",
@@ -1105,22 +1460,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async GetDataType(
       text: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<RaysData> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "GetDataType",
@@ -1130,22 +1493,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as RaysData
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async GetOrderInfo(
       email: Email,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<OrderInfo> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "GetOrderInfo",
@@ -1155,22 +1526,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as OrderInfo
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async GetQuery(
       query: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<SearchParams> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "GetQuery",
@@ -1180,22 +1559,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as SearchParams
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async MyFunc(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<DynamicOutput> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "MyFunc",
@@ -1205,22 +1592,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as DynamicOutput
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async OptionalTest_Function(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<(OptionalTest_ReturnType | null)[]> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "OptionalTest_Function",
@@ -1230,22 +1625,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as (OptionalTest_ReturnType | null)[]
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async PredictAge(
       name: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<FooAny> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "PredictAge",
@@ -1255,22 +1658,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as FooAny
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async PredictAgeBare(
       inp: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<Checked<number,"too_big">> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "PredictAgeBare",
@@ -1280,22 +1691,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as Checked<number,"too_big">
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async PromptTestClaude(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "PromptTestClaude",
@@ -1305,22 +1724,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async PromptTestClaudeChat(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "PromptTestClaudeChat",
@@ -1330,22 +1757,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async PromptTestClaudeChatNoSystem(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "PromptTestClaudeChatNoSystem",
@@ -1355,22 +1790,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async PromptTestOpenAI(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "PromptTestOpenAI",
@@ -1380,22 +1823,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async PromptTestOpenAIChat(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "PromptTestOpenAIChat",
@@ -1405,22 +1856,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async PromptTestOpenAIChatNoSystem(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "PromptTestOpenAIChatNoSystem",
@@ -1430,22 +1889,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async PromptTestStreaming(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "PromptTestStreaming",
@@ -1455,22 +1922,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async ReturnFailingAssert(
       inp: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<number> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "ReturnFailingAssert",
@@ -1480,22 +1955,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as number
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async ReturnMalformedConstraints(
       a: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<MalformedConstraints> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "ReturnMalformedConstraints",
@@ -1505,22 +1988,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as MalformedConstraints
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async SchemaDescriptions(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<Schema> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "SchemaDescriptions",
@@ -1530,22 +2021,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as Schema
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async StreamBigNumbers(
       digits: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<BigNumbers> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "StreamBigNumbers",
@@ -1555,22 +2054,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as BigNumbers
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async StreamFailingAssertion(
       theme: string,length: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<TwoStoriesOneTitle> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "StreamFailingAssertion",
@@ -1580,22 +2087,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as TwoStoriesOneTitle
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async StreamOneBigNumber(
       digits: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<number> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "StreamOneBigNumber",
@@ -1605,22 +2120,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as number
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async StreamUnionIntegers(
       digits: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<(number | string)[]> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "StreamUnionIntegers",
@@ -1630,22 +2153,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as (number | string)[]
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async StreamingCompoundNumbers(
       digits: number,yapping: boolean,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<CompoundBigNumbers> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "StreamingCompoundNumbers",
@@ -1655,22 +2186,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as CompoundBigNumbers
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestAnthropic(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestAnthropic",
@@ -1680,22 +2219,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestAnthropicShorthand(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestAnthropicShorthand",
@@ -1705,22 +2252,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestAws(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestAws",
@@ -1730,22 +2285,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestAzure(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestAzure",
@@ -1755,22 +2318,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestCaching(
       input: string,not_cached: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestCaching",
@@ -1780,22 +2351,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestFallbackClient(
       
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestFallbackClient",
@@ -1805,22 +2384,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestFallbackToShorthand(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestFallbackToShorthand",
@@ -1830,22 +2417,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestFnNamedArgsSingleBool(
       myBool: boolean,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestFnNamedArgsSingleBool",
@@ -1855,22 +2450,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestFnNamedArgsSingleClass(
       myArg: NamedArgsSingleClass,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestFnNamedArgsSingleClass",
@@ -1880,22 +2483,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestFnNamedArgsSingleEnumList(
       myArg: NamedArgsSingleEnumList[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestFnNamedArgsSingleEnumList",
@@ -1905,22 +2516,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestFnNamedArgsSingleFloat(
       myFloat: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestFnNamedArgsSingleFloat",
@@ -1930,22 +2549,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestFnNamedArgsSingleInt(
       myInt: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestFnNamedArgsSingleInt",
@@ -1955,22 +2582,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestFnNamedArgsSingleMapStringToClass(
       myMap: Record<string, StringToClassEntry>,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<Record<string, StringToClassEntry>> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestFnNamedArgsSingleMapStringToClass",
@@ -1980,22 +2615,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as Record<string, StringToClassEntry>
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestFnNamedArgsSingleMapStringToMap(
       myMap: Record<string, Record<string, string>>,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<Record<string, Record<string, string>>> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestFnNamedArgsSingleMapStringToMap",
@@ -2005,22 +2648,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as Record<string, Record<string, string>>
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestFnNamedArgsSingleMapStringToString(
       myMap: Record<string, string>,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<Record<string, string>> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestFnNamedArgsSingleMapStringToString",
@@ -2030,22 +2681,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as Record<string, string>
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestFnNamedArgsSingleString(
       myString: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestFnNamedArgsSingleString",
@@ -2055,22 +2714,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestFnNamedArgsSingleStringArray(
       myStringArray: string[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestFnNamedArgsSingleStringArray",
@@ -2080,22 +2747,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestFnNamedArgsSingleStringList(
       myArg: string[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestFnNamedArgsSingleStringList",
@@ -2105,22 +2780,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestGemini(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestGemini",
@@ -2130,22 +2813,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestImageInput(
       img: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestImageInput",
@@ -2155,22 +2846,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestImageInputAnthropic(
       img: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestImageInputAnthropic",
@@ -2180,22 +2879,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestImageListInput(
       imgs: Image[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestImageListInput",
@@ -2205,22 +2912,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestMulticlassNamedArgs(
       myArg: NamedArgsSingleClass,myArg2: NamedArgsSingleClass,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestMulticlassNamedArgs",
@@ -2230,22 +2945,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestOllama(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestOllama",
@@ -2255,22 +2978,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestOpenAILegacyProvider(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestOpenAILegacyProvider",
@@ -2280,22 +3011,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestOpenAIShorthand(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestOpenAIShorthand",
@@ -2305,22 +3044,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestRetryConstant(
       
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestRetryConstant",
@@ -2330,22 +3077,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestRetryExponential(
       
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestRetryExponential",
@@ -2355,22 +3110,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestSingleFallbackClient(
       
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestSingleFallbackClient",
@@ -2380,22 +3143,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async TestVertex(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "TestVertex",
@@ -2405,22 +3176,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as string
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async UnionTest_Function(
       input: string | boolean,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<UnionTest_ReturnType> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "UnionTest_Function",
@@ -2430,22 +3209,30 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as UnionTest_ReturnType
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
   async UseMalformedConstraints(
       a: MalformedConstraints2,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
   ): Promise<number> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = await this.runtime.callFunction(
         "UseMalformedConstraints",
@@ -2455,15 +3242,22 @@ export class BamlAsyncClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
       return raw.parsed() as number
     } catch (error: any) {
+      if (__baml_options__?.signal?.aborted) {
+        throw __baml_options__.signal.reason
+      }
       const bamlError = createBamlValidationError(error);
       if (bamlError instanceof BamlValidationError) {
         throw bamlError;
       } else {
         throw error;
       }
+    } finally {
+      cancel.release()
     }
   }
   
@@ -2475,8 +3269,9 @@ class BamlStreamClient {
   
   AaaSamOutputFormat(
       recipe: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<Recipe>, Recipe> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.Recipe | null), Recipe> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "AaaSamOutputFormat",
@@ -2487,15 +3282,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<Recipe>, Recipe>(
+      return new BamlStream<(partial_types.Recipe | null), Recipe>(
         raw,
-        (a): a is RecursivePartialNull<Recipe> => a,
+        (a): a is (partial_types.Recipe | null) => a,
         (a): a is Recipe => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -2508,8 +3306,9 @@ class BamlStreamClient {
   
   AliasedInputClass(
       input: InputClass,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "AliasedInputClass",
@@ -2520,15 +3319,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -2541,8 +3343,9 @@ class BamlStreamClient {
   
   AliasedInputClass2(
       input: InputClass,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "AliasedInputClass2",
@@ -2553,15 +3356,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -2574,8 +3380,9 @@ class BamlStreamClient {
   
   AliasedInputClassNested(
       input: InputClassNested,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "AliasedInputClassNested",
@@ -2586,15 +3393,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -2607,8 +3417,9 @@ class BamlStreamClient {
   
   AliasedInputEnum(
       input: AliasedEnum,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "AliasedInputEnum",
@@ -2619,15 +3430,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -2640,8 +3454,9 @@ class BamlStreamClient {
   
   AliasedInputList(
       input: AliasedEnum[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "AliasedInputList",
@@ -2652,15 +3467,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -2673,8 +3491,9 @@ class BamlStreamClient {
   
   AudioInput(
       aud: Audio,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "AudioInput",
@@ -2685,15 +3504,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -2706,8 +3528,9 @@ class BamlStreamClient {
   
   ClassifyDynEnumTwo(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<(string | DynEnumTwo)>, (string | DynEnumTwo)> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | DynEnumTwo | null), (string | DynEnumTwo)> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "ClassifyDynEnumTwo",
@@ -2718,15 +3541,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<(string | DynEnumTwo)>, (string | DynEnumTwo)>(
+      return new BamlStream<(string | DynEnumTwo | null), (string | DynEnumTwo)>(
         raw,
-        (a): a is RecursivePartialNull<(string | DynEnumTwo)> => a,
+        (a): a is (string | DynEnumTwo | null) => a,
         (a): a is (string | DynEnumTwo) => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -2739,8 +3565,9 @@ class BamlStreamClient {
   
   ClassifyMessage(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<Category>, Category> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(Category | null), Category> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "ClassifyMessage",
@@ -2751,15 +3578,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<Category>, Category>(
+      return new BamlStream<(Category | null), Category>(
         raw,
-        (a): a is RecursivePartialNull<Category> => a,
+        (a): a is (Category | null) => a,
         (a): a is Category => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -2772,8 +3602,9 @@ class BamlStreamClient {
   
   ClassifyMessage2(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<Category>, Category> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(Category | null), Category> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "ClassifyMessage2",
@@ -2784,15 +3615,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<Category>, Category>(
+      return new BamlStream<(Category | null), Category>(
         raw,
-        (a): a is RecursivePartialNull<Category> => a,
+        (a): a is (Category | null) => a,
         (a): a is Category => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -2805,8 +3639,9 @@ class BamlStreamClient {
   
   ClassifyMessage3(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<Category>, Category> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(Category | null), Category> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "ClassifyMessage3",
@@ -2817,15 +3652,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<Category>, Category>(
+      return new BamlStream<(Category | null), Category>(
         raw,
-        (a): a is RecursivePartialNull<Category> => a,
+        (a): a is (Category | null) => a,
         (a): a is Category => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -2838,8 +3676,9 @@ class BamlStreamClient {
   
   CustomTask(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<BookOrder | FlightConfirmation | GroceryReceipt>, BookOrder | FlightConfirmation | GroceryReceipt> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<((partial_types.BookOrder | null) | (partial_types.FlightConfirmation | null) | (partial_types.GroceryReceipt | null) | null), BookOrder | FlightConfirmation | GroceryReceipt> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "CustomTask",
@@ -2850,15 +3689,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<BookOrder | FlightConfirmation | GroceryReceipt>, BookOrder | FlightConfirmation | GroceryReceipt>(
+      return new BamlStream<((partial_types.BookOrder | null) | (partial_types.FlightConfirmation | null) | (partial_types.GroceryReceipt | null) | null), BookOrder | FlightConfirmation | GroceryReceipt>(
         raw,
-        (a): a is RecursivePartialNull<BookOrder | FlightConfirmation | GroceryReceipt> => a,
+        (a): a is ((partial_types.BookOrder | null) | (partial_types.FlightConfirmation | null) | (partial_types.GroceryReceipt | null) | null) => a,
         (a): a is BookOrder | FlightConfirmation | GroceryReceipt => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -2871,8 +3713,9 @@ class BamlStreamClient {
   
   DescribeImage(
       img: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "DescribeImage",
@@ -2883,15 +3726,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -2904,8 +3750,9 @@ class BamlStreamClient {
   
   DescribeImage2(
       classWithImage: ClassWithImage,img2: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "DescribeImage2",
@@ -2916,15 +3763,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -2937,8 +3787,9 @@ class BamlStreamClient {
   
   DescribeImage3(
       classWithImage: ClassWithImage,img2: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "DescribeImage3",
@@ -2949,15 +3800,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -2970,8 +3824,9 @@ class BamlStreamClient {
   
   DescribeImage4(
       classWithImage: ClassWithImage,img2: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "DescribeImage4",
@@ -2982,15 +3837,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3003,8 +3861,9 @@ class BamlStreamClient {
   
   DifferentiateUnions(
       
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<OriginalA | OriginalB>, OriginalA | OriginalB> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<((partial_types.OriginalA | null) | (partial_types.OriginalB | null) | null), OriginalA | OriginalB> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "DifferentiateUnions",
@@ -3015,15 +3874,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<OriginalA | OriginalB>, OriginalA | OriginalB>(
+      return new BamlStream<((partial_types.OriginalA | null) | (partial_types.OriginalB | null) | null), OriginalA | OriginalB>(
         raw,
-        (a): a is RecursivePartialNull<OriginalA | OriginalB> => a,
+        (a): a is ((partial_types.OriginalA | null) | (partial_types.OriginalB | null) | null) => a,
         (a): a is OriginalA | OriginalB => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3036,8 +3898,9 @@ class BamlStreamClient {
   
   DummyOutputFunction(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<DummyOutput>, DummyOutput> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.DummyOutput | null), DummyOutput> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "DummyOutputFunction",
@@ -3048,15 +3911,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<DummyOutput>, DummyOutput>(
+      return new BamlStream<(partial_types.DummyOutput | null), DummyOutput>(
         raw,
-        (a): a is RecursivePartialNull<DummyOutput> => a,
+        (a): a is (partial_types.DummyOutput | null) => a,
         (a): a is DummyOutput => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3069,8 +3935,9 @@ class BamlStreamClient {
   
   DynamicFunc(
       input: DynamicClassOne,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<DynamicClassTwo>, DynamicClassTwo> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.DynamicClassTwo | null), DynamicClassTwo> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "DynamicFunc",
@@ -3081,15 +3948,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<DynamicClassTwo>, DynamicClassTwo>(
+      return new BamlStream<(partial_types.DynamicClassTwo | null), DynamicClassTwo>(
         raw,
-        (a): a is RecursivePartialNull<DynamicClassTwo> => a,
+        (a): a is (partial_types.DynamicClassTwo | null) => a,
         (a): a is DynamicClassTwo => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3102,8 +3972,9 @@ class BamlStreamClient {
   
   DynamicInputOutput(
       input: DynInputOutput,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<DynInputOutput>, DynInputOutput> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.DynInputOutput | null), DynInputOutput> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "DynamicInputOutput",
@@ -3114,15 +3985,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<DynInputOutput>, DynInputOutput>(
+      return new BamlStream<(partial_types.DynInputOutput | null), DynInputOutput>(
         raw,
-        (a): a is RecursivePartialNull<DynInputOutput> => a,
+        (a): a is (partial_types.DynInputOutput | null) => a,
         (a): a is DynInputOutput => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3135,8 +4009,9 @@ class BamlStreamClient {
   
   DynamicListInputOutput(
       input: DynInputOutput[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<DynInputOutput[]>, DynInputOutput[]> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.DynInputOutput | null)[], DynInputOutput[]> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "DynamicListInputOutput",
@@ -3147,15 +4022,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<DynInputOutput[]>, DynInputOutput[]>(
+      return new BamlStream<(partial_types.DynInputOutput | null)[], DynInputOutput[]>(
         raw,
-        (a): a is RecursivePartialNull<DynInputOutput[]> => a,
+        (a): a is (partial_types.DynInputOutput | null)[] => a,
         (a): a is DynInputOutput[] => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3168,8 +4046,9 @@ class BamlStreamClient {
   
   ExpectFailure(
       
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "ExpectFailure",
@@ -3180,15 +4059,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3201,8 +4083,9 @@ class BamlStreamClient {
   
   ExtractContactInfo(
       document: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<ContactInfo>, ContactInfo> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.ContactInfo | null), ContactInfo> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "ExtractContactInfo",
@@ -3213,15 +4096,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<ContactInfo>, ContactInfo>(
+      return new BamlStream<(partial_types.ContactInfo | null), ContactInfo>(
         raw,
-        (a): a is RecursivePartialNull<ContactInfo> => a,
+        (a): a is (partial_types.ContactInfo | null) => a,
         (a): a is ContactInfo => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3234,8 +4120,9 @@ class BamlStreamClient {
   
   ExtractHobby(
       text: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<(string | Hobby)[]>, (string | Hobby)[]> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | Hobby | null)[], (string | Hobby)[]> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "ExtractHobby",
@@ -3246,15 +4133,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<(string | Hobby)[]>, (string | Hobby)[]>(
+      return new BamlStream<(string | Hobby | null)[], (string | Hobby)[]>(
         raw,
-        (a): a is RecursivePartialNull<(string | Hobby)[]> => a,
+        (a): a is (string | Hobby | null)[] => a,
         (a): a is (string | Hobby)[] => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3267,8 +4157,9 @@ class BamlStreamClient {
   
   ExtractNames(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string[]>, string[]> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null)[], string[]> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "ExtractNames",
@@ -3279,15 +4170,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string[]>, string[]>(
+      return new BamlStream<(string | null)[], string[]>(
         raw,
-        (a): a is RecursivePartialNull<string[]> => a,
+        (a): a is (string | null)[] => a,
         (a): a is string[] => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3300,8 +4194,9 @@ class BamlStreamClient {
   
   ExtractPeople(
       text: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<Person[]>, Person[]> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.Person | null)[], Person[]> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "ExtractPeople",
@@ -3312,15 +4207,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<Person[]>, Person[]>(
+      return new BamlStream<(partial_types.Person | null)[], Person[]>(
         raw,
-        (a): a is RecursivePartialNull<Person[]> => a,
+        (a): a is (partial_types.Person | null)[] => a,
         (a): a is Person[] => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3333,8 +4231,9 @@ class BamlStreamClient {
   
   ExtractReceiptInfo(
       email: string,reason: "curiosity" | "personal_finance",
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<ReceiptInfo>, ReceiptInfo> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.ReceiptInfo | null), ReceiptInfo> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "ExtractReceiptInfo",
@@ -3345,15 +4244,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<ReceiptInfo>, ReceiptInfo>(
+      return new BamlStream<(partial_types.ReceiptInfo | null), ReceiptInfo>(
         raw,
-        (a): a is RecursivePartialNull<ReceiptInfo> => a,
+        (a): a is (partial_types.ReceiptInfo | null) => a,
         (a): a is ReceiptInfo => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3366,8 +4268,9 @@ class BamlStreamClient {
   
   ExtractResume(
       resume: string,img?: Image | null,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<Resume>, Resume> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.Resume | null), Resume> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "ExtractResume",
@@ -3378,15 +4281,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<Resume>, Resume>(
+      return new BamlStream<(partial_types.Resume | null), Resume>(
         raw,
-        (a): a is RecursivePartialNull<Resume> => a,
+        (a): a is (partial_types.Resume | null) => a,
         (a): a is Resume => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3399,8 +4305,9 @@ class BamlStreamClient {
   
   ExtractResume2(
       resume: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<Resume>, Resume> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.Resume | null), Resume> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "ExtractResume2",
@@ -3411,15 +4318,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<Resume>, Resume>(
+      return new BamlStream<(partial_types.Resume | null), Resume>(
         raw,
-        (a): a is RecursivePartialNull<Resume> => a,
+        (a): a is (partial_types.Resume | null) => a,
         (a): a is Resume => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3432,8 +4342,9 @@ class BamlStreamClient {
   
   FnClassOptionalOutput(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<ClassOptionalOutput | null>, ClassOptionalOutput | null> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.ClassOptionalOutput | null), ClassOptionalOutput | null> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "FnClassOptionalOutput",
@@ -3444,15 +4355,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<ClassOptionalOutput | null>, ClassOptionalOutput | null>(
+      return new BamlStream<(partial_types.ClassOptionalOutput | null), ClassOptionalOutput | null>(
         raw,
-        (a): a is RecursivePartialNull<ClassOptionalOutput | null> => a,
+        (a): a is (partial_types.ClassOptionalOutput | null) => a,
         (a): a is ClassOptionalOutput | null => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3465,8 +4379,9 @@ class BamlStreamClient {
   
   FnClassOptionalOutput2(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<ClassOptionalOutput2 | null>, ClassOptionalOutput2 | null> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.ClassOptionalOutput2 | null), ClassOptionalOutput2 | null> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "FnClassOptionalOutput2",
@@ -3477,15 +4392,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<ClassOptionalOutput2 | null>, ClassOptionalOutput2 | null>(
+      return new BamlStream<(partial_types.ClassOptionalOutput2 | null), ClassOptionalOutput2 | null>(
         raw,
-        (a): a is RecursivePartialNull<ClassOptionalOutput2 | null> => a,
+        (a): a is (partial_types.ClassOptionalOutput2 | null) => a,
         (a): a is ClassOptionalOutput2 | null => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3498,8 +4416,9 @@ class BamlStreamClient {
   
   FnEnumListOutput(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<EnumOutput[]>, EnumOutput[]> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(EnumOutput | null)[], EnumOutput[]> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "FnEnumListOutput",
@@ -3510,15 +4429,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<EnumOutput[]>, EnumOutput[]>(
+      return new BamlStream<(EnumOutput | null)[], EnumOutput[]>(
         raw,
-        (a): a is RecursivePartialNull<EnumOutput[]> => a,
+        (a): a is (EnumOutput | null)[] => a,
         (a): a is EnumOutput[] => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3531,8 +4453,9 @@ class BamlStreamClient {
   
   FnEnumOutput(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<EnumOutput>, EnumOutput> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(EnumOutput | null), EnumOutput> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "FnEnumOutput",
@@ -3543,15 +4466,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<EnumOutput>, EnumOutput>(
+      return new BamlStream<(EnumOutput | null), EnumOutput>(
         raw,
-        (a): a is RecursivePartialNull<EnumOutput> => a,
+        (a): a is (EnumOutput | null) => a,
         (a): a is EnumOutput => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3564,8 +4490,9 @@ class BamlStreamClient {
   
   FnNamedArgsSingleStringOptional(
       myString?: string | null,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "FnNamedArgsSingleStringOptional",
@@ -3576,15 +4503,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3597,8 +4527,9 @@ class BamlStreamClient {
   
   FnOutputBool(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<boolean>, boolean> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(boolean | null), boolean> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "FnOutputBool",
@@ -3609,15 +4540,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<boolean>, boolean>(
+      return new BamlStream<(boolean | null), boolean>(
         raw,
-        (a): a is RecursivePartialNull<boolean> => a,
+        (a): a is (boolean | null) => a,
         (a): a is boolean => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3630,8 +4564,9 @@ class BamlStreamClient {
   
   FnOutputClass(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<TestOutputClass>, TestOutputClass> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.TestOutputClass | null), TestOutputClass> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "FnOutputClass",
@@ -3642,15 +4577,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<TestOutputClass>, TestOutputClass>(
+      return new BamlStream<(partial_types.TestOutputClass | null), TestOutputClass>(
         raw,
-        (a): a is RecursivePartialNull<TestOutputClass> => a,
+        (a): a is (partial_types.TestOutputClass | null) => a,
         (a): a is TestOutputClass => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3663,8 +4601,9 @@ class BamlStreamClient {
   
   FnOutputClassList(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<TestOutputClass[]>, TestOutputClass[]> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.TestOutputClass | null)[], TestOutputClass[]> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "FnOutputClassList",
@@ -3675,15 +4614,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<TestOutputClass[]>, TestOutputClass[]>(
+      return new BamlStream<(partial_types.TestOutputClass | null)[], TestOutputClass[]>(
         raw,
-        (a): a is RecursivePartialNull<TestOutputClass[]> => a,
+        (a): a is (partial_types.TestOutputClass | null)[] => a,
         (a): a is TestOutputClass[] => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3696,8 +4638,9 @@ class BamlStreamClient {
   
   FnOutputClassNested(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<TestClassNested>, TestClassNested> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.TestClassNested | null), TestClassNested> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "FnOutputClassNested",
@@ -3708,15 +4651,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<TestClassNested>, TestClassNested>(
+      return new BamlStream<(partial_types.TestClassNested | null), TestClassNested>(
         raw,
-        (a): a is RecursivePartialNull<TestClassNested> => a,
+        (a): a is (partial_types.TestClassNested | null) => a,
         (a): a is TestClassNested => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3729,8 +4675,9 @@ class BamlStreamClient {
   
   FnOutputClassWithEnum(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<TestClassWithEnum>, TestClassWithEnum> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.TestClassWithEnum | null), TestClassWithEnum> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "FnOutputClassWithEnum",
@@ -3741,15 +4688,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<TestClassWithEnum>, TestClassWithEnum>(
+      return new BamlStream<(partial_types.TestClassWithEnum | null), TestClassWithEnum>(
         raw,
-        (a): a is RecursivePartialNull<TestClassWithEnum> => a,
+        (a): a is (partial_types.TestClassWithEnum | null) => a,
         (a): a is TestClassWithEnum => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3762,8 +4712,9 @@ class BamlStreamClient {
   
   FnOutputStringList(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string[]>, string[]> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null)[], string[]> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "FnOutputStringList",
@@ -3774,15 +4725,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string[]>, string[]>(
+      return new BamlStream<(string | null)[], string[]>(
         raw,
-        (a): a is RecursivePartialNull<string[]> => a,
+        (a): a is (string | null)[] => a,
         (a): a is string[] => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3795,8 +4749,9 @@ class BamlStreamClient {
   
   FnTestAliasedEnumOutput(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<TestEnum>, TestEnum> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(TestEnum | null), TestEnum> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "FnTestAliasedEnumOutput",
@@ -3807,15 +4762,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<TestEnum>, TestEnum>(
+      return new BamlStream<(TestEnum | null), TestEnum>(
         raw,
-        (a): a is RecursivePartialNull<TestEnum> => a,
+        (a): a is (TestEnum | null) => a,
         (a): a is TestEnum => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3828,8 +4786,9 @@ class BamlStreamClient {
   
   FnTestClassAlias(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<TestClassAlias>, TestClassAlias> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.TestClassAlias | null), TestClassAlias> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "FnTestClassAlias",
@@ -3840,15 +4799,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<TestClassAlias>, TestClassAlias>(
+      return new BamlStream<(partial_types.TestClassAlias | null), TestClassAlias>(
         raw,
-        (a): a is RecursivePartialNull<TestClassAlias> => a,
+        (a): a is (partial_types.TestClassAlias | null) => a,
         (a): a is TestClassAlias => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3861,8 +4823,9 @@ class BamlStreamClient {
   
   FnTestNamedArgsSingleEnum(
       myArg: NamedArgsSingleEnum,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "FnTestNamedArgsSingleEnum",
@@ -3873,15 +4836,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3894,8 +4860,9 @@ class BamlStreamClient {
   
   GetDataType(
       text: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<RaysData>, RaysData> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.RaysData | null), RaysData> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "GetDataType",
@@ -3906,15 +4873,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<RaysData>, RaysData>(
+      return new BamlStream<(partial_types.RaysData | null), RaysData>(
         raw,
-        (a): a is RecursivePartialNull<RaysData> => a,
+        (a): a is (partial_types.RaysData | null) => a,
         (a): a is RaysData => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3927,8 +4897,9 @@ class BamlStreamClient {
   
   GetOrderInfo(
       email: Email,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<OrderInfo>, OrderInfo> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.OrderInfo | null), OrderInfo> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "GetOrderInfo",
@@ -3939,15 +4910,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<OrderInfo>, OrderInfo>(
+      return new BamlStream<(partial_types.OrderInfo | null), OrderInfo>(
         raw,
-        (a): a is RecursivePartialNull<OrderInfo> => a,
+        (a): a is (partial_types.OrderInfo | null) => a,
         (a): a is OrderInfo => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3960,8 +4934,9 @@ class BamlStreamClient {
   
   GetQuery(
       query: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<SearchParams>, SearchParams> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.SearchParams | null), SearchParams> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "GetQuery",
@@ -3972,15 +4947,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<SearchParams>, SearchParams>(
+      return new BamlStream<(partial_types.SearchParams | null), SearchParams>(
         raw,
-        (a): a is RecursivePartialNull<SearchParams> => a,
+        (a): a is (partial_types.SearchParams | null) => a,
         (a): a is SearchParams => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -3993,8 +4971,9 @@ class BamlStreamClient {
   
   MyFunc(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<DynamicOutput>, DynamicOutput> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.DynamicOutput | null), DynamicOutput> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "MyFunc",
@@ -4005,15 +4984,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<DynamicOutput>, DynamicOutput>(
+      return new BamlStream<(partial_types.DynamicOutput | null), DynamicOutput>(
         raw,
-        (a): a is RecursivePartialNull<DynamicOutput> => a,
+        (a): a is (partial_types.DynamicOutput | null) => a,
         (a): a is DynamicOutput => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4026,8 +5008,9 @@ class BamlStreamClient {
   
   OptionalTest_Function(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<(OptionalTest_ReturnType | null)[]>, (OptionalTest_ReturnType | null)[]> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.OptionalTest_ReturnType | null)[], (OptionalTest_ReturnType | null)[]> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "OptionalTest_Function",
@@ -4038,15 +5021,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<(OptionalTest_ReturnType | null)[]>, (OptionalTest_ReturnType | null)[]>(
+      return new BamlStream<(partial_types.OptionalTest_ReturnType | null)[], (OptionalTest_ReturnType | null)[]>(
         raw,
-        (a): a is RecursivePartialNull<(OptionalTest_ReturnType | null)[]> => a,
+        (a): a is (partial_types.OptionalTest_ReturnType | null)[] => a,
         (a): a is (OptionalTest_ReturnType | null)[] => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4059,8 +5045,9 @@ class BamlStreamClient {
   
   PredictAge(
       name: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<FooAny>, FooAny> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.FooAny | null), FooAny> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "PredictAge",
@@ -4071,15 +5058,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<FooAny>, FooAny>(
+      return new BamlStream<(partial_types.FooAny | null), FooAny>(
         raw,
-        (a): a is RecursivePartialNull<FooAny> => a,
+        (a): a is (partial_types.FooAny | null) => a,
         (a): a is FooAny => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4092,8 +5082,9 @@ class BamlStreamClient {
   
   PredictAgeBare(
       inp: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<Checked<number,"too_big">>, Checked<number,"too_big">> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<Checked<(number | null),"too_big">, Checked<number,"too_big">> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "PredictAgeBare",
@@ -4104,15 +5095,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<Checked<number,"too_big">>, Checked<number,"too_big">>(
+      return new BamlStream<Checked<(number | null),"too_big">, Checked<number,"too_big">>(
         raw,
-        (a): a is RecursivePartialNull<Checked<number,"too_big">> => a,
+        (a): a is Checked<(number | null),"too_big"> => a,
         (a): a is Checked<number,"too_big"> => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4125,8 +5119,9 @@ class BamlStreamClient {
   
   PromptTestClaude(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "PromptTestClaude",
@@ -4137,15 +5132,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4158,8 +5156,9 @@ class BamlStreamClient {
   
   PromptTestClaudeChat(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "PromptTestClaudeChat",
@@ -4170,15 +5169,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4191,8 +5193,9 @@ class BamlStreamClient {
   
   PromptTestClaudeChatNoSystem(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "PromptTestClaudeChatNoSystem",
@@ -4203,15 +5206,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4224,8 +5230,9 @@ class BamlStreamClient {
   
   PromptTestOpenAI(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "PromptTestOpenAI",
@@ -4236,15 +5243,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4257,8 +5267,9 @@ class BamlStreamClient {
   
   PromptTestOpenAIChat(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "PromptTestOpenAIChat",
@@ -4269,15 +5280,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4290,8 +5304,9 @@ class BamlStreamClient {
   
   PromptTestOpenAIChatNoSystem(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "PromptTestOpenAIChatNoSystem",
@@ -4302,15 +5317,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4323,8 +5341,9 @@ class BamlStreamClient {
   
   PromptTestStreaming(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "PromptTestStreaming",
@@ -4335,15 +5354,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4356,8 +5378,9 @@ class BamlStreamClient {
   
   ReturnFailingAssert(
       inp: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<number>, number> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(number | null), number> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "ReturnFailingAssert",
@@ -4368,15 +5391,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<number>, number>(
+      return new BamlStream<(number | null), number>(
         raw,
-        (a): a is RecursivePartialNull<number> => a,
+        (a): a is (number | null) => a,
         (a): a is number => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4389,8 +5415,9 @@ class BamlStreamClient {
   
   ReturnMalformedConstraints(
       a: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<MalformedConstraints>, MalformedConstraints> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.MalformedConstraints | null), MalformedConstraints> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "ReturnMalformedConstraints",
@@ -4401,15 +5428,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<MalformedConstraints>, MalformedConstraints>(
+      return new BamlStream<(partial_types.MalformedConstraints | null), MalformedConstraints>(
         raw,
-        (a): a is RecursivePartialNull<MalformedConstraints> => a,
+        (a): a is (partial_types.MalformedConstraints | null) => a,
         (a): a is MalformedConstraints => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4422,8 +5452,9 @@ class BamlStreamClient {
   
   SchemaDescriptions(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<Schema>, Schema> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.Schema | null), Schema> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "SchemaDescriptions",
@@ -4434,15 +5465,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<Schema>, Schema>(
+      return new BamlStream<(partial_types.Schema | null), Schema>(
         raw,
-        (a): a is RecursivePartialNull<Schema> => a,
+        (a): a is (partial_types.Schema | null) => a,
         (a): a is Schema => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4455,8 +5489,9 @@ class BamlStreamClient {
   
   StreamBigNumbers(
       digits: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<BigNumbers>, BigNumbers> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.BigNumbers | null), BigNumbers> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "StreamBigNumbers",
@@ -4467,15 +5502,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<BigNumbers>, BigNumbers>(
+      return new BamlStream<(partial_types.BigNumbers | null), BigNumbers>(
         raw,
-        (a): a is RecursivePartialNull<BigNumbers> => a,
+        (a): a is (partial_types.BigNumbers | null) => a,
         (a): a is BigNumbers => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4488,8 +5526,9 @@ class BamlStreamClient {
   
   StreamFailingAssertion(
       theme: string,length: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<TwoStoriesOneTitle>, TwoStoriesOneTitle> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.TwoStoriesOneTitle | null), TwoStoriesOneTitle> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "StreamFailingAssertion",
@@ -4500,15 +5539,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<TwoStoriesOneTitle>, TwoStoriesOneTitle>(
+      return new BamlStream<(partial_types.TwoStoriesOneTitle | null), TwoStoriesOneTitle>(
         raw,
-        (a): a is RecursivePartialNull<TwoStoriesOneTitle> => a,
+        (a): a is (partial_types.TwoStoriesOneTitle | null) => a,
         (a): a is TwoStoriesOneTitle => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4521,8 +5563,9 @@ class BamlStreamClient {
   
   StreamOneBigNumber(
       digits: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<number>, number> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(number | null), number> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "StreamOneBigNumber",
@@ -4533,15 +5576,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<number>, number>(
+      return new BamlStream<(number | null), number>(
         raw,
-        (a): a is RecursivePartialNull<number> => a,
+        (a): a is (number | null) => a,
         (a): a is number => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4554,8 +5600,9 @@ class BamlStreamClient {
   
   StreamUnionIntegers(
       digits: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<(number | string)[]>, (number | string)[]> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<((number | null) | (string | null) | null)[], (number | string)[]> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "StreamUnionIntegers",
@@ -4566,15 +5613,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<(number | string)[]>, (number | string)[]>(
+      return new BamlStream<((number | null) | (string | null) | null)[], (number | string)[]>(
         raw,
-        (a): a is RecursivePartialNull<(number | string)[]> => a,
+        (a): a is ((number | null) | (string | null) | null)[] => a,
         (a): a is (number | string)[] => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4587,8 +5637,9 @@ class BamlStreamClient {
   
   StreamingCompoundNumbers(
       digits: number,yapping: boolean,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<CompoundBigNumbers>, CompoundBigNumbers> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.CompoundBigNumbers | null), CompoundBigNumbers> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "StreamingCompoundNumbers",
@@ -4599,15 +5650,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<CompoundBigNumbers>, CompoundBigNumbers>(
+      return new BamlStream<(partial_types.CompoundBigNumbers | null), CompoundBigNumbers>(
         raw,
-        (a): a is RecursivePartialNull<CompoundBigNumbers> => a,
+        (a): a is (partial_types.CompoundBigNumbers | null) => a,
         (a): a is CompoundBigNumbers => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4620,8 +5674,9 @@ class BamlStreamClient {
   
   TestAnthropic(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestAnthropic",
@@ -4632,15 +5687,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4653,8 +5711,9 @@ class BamlStreamClient {
   
   TestAnthropicShorthand(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestAnthropicShorthand",
@@ -4665,15 +5724,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4686,8 +5748,9 @@ class BamlStreamClient {
   
   TestAws(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestAws",
@@ -4698,15 +5761,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4719,8 +5785,9 @@ class BamlStreamClient {
   
   TestAzure(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestAzure",
@@ -4731,15 +5798,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4752,8 +5822,9 @@ class BamlStreamClient {
   
   TestCaching(
       input: string,not_cached: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestCaching",
@@ -4764,15 +5835,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4785,8 +5859,9 @@ class BamlStreamClient {
   
   TestFallbackClient(
       
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestFallbackClient",
@@ -4797,15 +5872,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4818,8 +5896,9 @@ class BamlStreamClient {
   
   TestFallbackToShorthand(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestFallbackToShorthand",
@@ -4830,15 +5909,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4851,8 +5933,9 @@ class BamlStreamClient {
   
   TestFnNamedArgsSingleBool(
       myBool: boolean,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestFnNamedArgsSingleBool",
@@ -4863,15 +5946,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4884,8 +5970,9 @@ class BamlStreamClient {
   
   TestFnNamedArgsSingleClass(
       myArg: NamedArgsSingleClass,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestFnNamedArgsSingleClass",
@@ -4896,15 +5983,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4917,8 +6007,9 @@ class BamlStreamClient {
   
   TestFnNamedArgsSingleEnumList(
       myArg: NamedArgsSingleEnumList[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestFnNamedArgsSingleEnumList",
@@ -4929,15 +6020,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4950,8 +6044,9 @@ class BamlStreamClient {
   
   TestFnNamedArgsSingleFloat(
       myFloat: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestFnNamedArgsSingleFloat",
@@ -4962,15 +6057,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -4983,8 +6081,9 @@ class BamlStreamClient {
   
   TestFnNamedArgsSingleInt(
       myInt: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestFnNamedArgsSingleInt",
@@ -4995,15 +6094,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -5016,8 +6118,9 @@ class BamlStreamClient {
   
   TestFnNamedArgsSingleMapStringToClass(
       myMap: Record<string, StringToClassEntry>,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<Record<string, StringToClassEntry>>, Record<string, StringToClassEntry>> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(Record<string, (partial_types.StringToClassEntry | null)> | null), Record<string, StringToClassEntry>> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestFnNamedArgsSingleMapStringToClass",
@@ -5028,15 +6131,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<Record<string, StringToClassEntry>>, Record<string, StringToClassEntry>>(
+      return new BamlStream<(Record<string, (partial_types.StringToClassEntry | null)> | null), Record<string, StringToClassEntry>>(
         raw,
-        (a): a is RecursivePartialNull<Record<string, StringToClassEntry>> => a,
+        (a): a is (Record<string, (partial_types.StringToClassEntry | null)> | null) => a,
         (a): a is Record<string, StringToClassEntry> => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -5049,8 +6155,9 @@ class BamlStreamClient {
   
   TestFnNamedArgsSingleMapStringToMap(
       myMap: Record<string, Record<string, string>>,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<Record<string, Record<string, string>>>, Record<string, Record<string, string>>> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(Record<string, (Record<string, (string | null)> | null)> | null), Record<string, Record<string, string>>> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestFnNamedArgsSingleMapStringToMap",
@@ -5061,15 +6168,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<Record<string, Record<string, string>>>, Record<string, Record<string, string>>>(
+      return new BamlStream<(Record<string, (Record<string, (string | null)> | null)> | null), Record<string, Record<string, string>>>(
         raw,
-        (a): a is RecursivePartialNull<Record<string, Record<string, string>>> => a,
+        (a): a is (Record<string, (Record<string, (string | null)> | null)> | null) => a,
         (a): a is Record<string, Record<string, string>> => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -5082,8 +6192,9 @@ class BamlStreamClient {
   
   TestFnNamedArgsSingleMapStringToString(
       myMap: Record<string, string>,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<Record<string, string>>, Record<string, string>> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(Record<string, (string | null)> | null), Record<string, string>> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestFnNamedArgsSingleMapStringToString",
@@ -5094,15 +6205,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<Record<string, string>>, Record<string, string>>(
+      return new BamlStream<(Record<string, (string | null)> | null), Record<string, string>>(
         raw,
-        (a): a is RecursivePartialNull<Record<string, string>> => a,
+        (a): a is (Record<string, (string | null)> | null) => a,
         (a): a is Record<string, string> => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -5115,8 +6229,9 @@ class BamlStreamClient {
   
   TestFnNamedArgsSingleString(
       myString: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestFnNamedArgsSingleString",
@@ -5127,15 +6242,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -5148,8 +6266,9 @@ class BamlStreamClient {
   
   TestFnNamedArgsSingleStringArray(
       myStringArray: string[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestFnNamedArgsSingleStringArray",
@@ -5160,15 +6279,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -5181,8 +6303,9 @@ class BamlStreamClient {
   
   TestFnNamedArgsSingleStringList(
       myArg: string[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestFnNamedArgsSingleStringList",
@@ -5193,15 +6316,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -5214,8 +6340,9 @@ class BamlStreamClient {
   
   TestGemini(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestGemini",
@@ -5226,15 +6353,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -5247,8 +6377,9 @@ class BamlStreamClient {
   
   TestImageInput(
       img: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestImageInput",
@@ -5259,15 +6390,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -5280,8 +6414,9 @@ class BamlStreamClient {
   
   TestImageInputAnthropic(
       img: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestImageInputAnthropic",
@@ -5292,15 +6427,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -5313,8 +6451,9 @@ class BamlStreamClient {
   
   TestImageListInput(
       imgs: Image[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestImageListInput",
@@ -5325,15 +6464,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -5346,8 +6488,9 @@ class BamlStreamClient {
   
   TestMulticlassNamedArgs(
       myArg: NamedArgsSingleClass,myArg2: NamedArgsSingleClass,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestMulticlassNamedArgs",
@@ -5358,15 +6501,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -5379,8 +6525,9 @@ class BamlStreamClient {
   
   TestOllama(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestOllama",
@@ -5391,15 +6538,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -5412,8 +6562,9 @@ class BamlStreamClient {
   
   TestOpenAILegacyProvider(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestOpenAILegacyProvider",
@@ -5424,15 +6575,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -5445,8 +6599,9 @@ class BamlStreamClient {
   
   TestOpenAIShorthand(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestOpenAIShorthand",
@@ -5457,15 +6612,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -5478,8 +6636,9 @@ class BamlStreamClient {
   
   TestRetryConstant(
       
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestRetryConstant",
@@ -5490,15 +6649,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -5511,8 +6673,9 @@ class BamlStreamClient {
   
   TestRetryExponential(
       
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestRetryExponential",
@@ -5523,15 +6686,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -5544,8 +6710,9 @@ class BamlStreamClient {
   
   TestSingleFallbackClient(
       
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestSingleFallbackClient",
@@ -5556,15 +6723,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -5577,8 +6747,9 @@ class BamlStreamClient {
   
   TestVertex(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<string>, string> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(string | null), string> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "TestVertex",
@@ -5589,15 +6760,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<string>, string>(
+      return new BamlStream<(string | null), string>(
         raw,
-        (a): a is RecursivePartialNull<string> => a,
+        (a): a is (string | null) => a,
         (a): a is string => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -5610,8 +6784,9 @@ class BamlStreamClient {
   
   UnionTest_Function(
       input: string | boolean,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<UnionTest_ReturnType>, UnionTest_ReturnType> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(partial_types.UnionTest_ReturnType | null), UnionTest_ReturnType> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "UnionTest_Function",
@@ -5622,15 +6797,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<UnionTest_ReturnType>, UnionTest_ReturnType>(
+      return new BamlStream<(partial_types.UnionTest_ReturnType | null), UnionTest_ReturnType>(
         raw,
-        (a): a is RecursivePartialNull<UnionTest_ReturnType> => a,
+        (a): a is (partial_types.UnionTest_ReturnType | null) => a,
         (a): a is UnionTest_ReturnType => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
@@ -5643,8 +6821,9 @@ class BamlStreamClient {
   
   UseMalformedConstraints(
       a: MalformedConstraints2,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
-  ): BamlStream<RecursivePartialNull<number>, number> {
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector, signal?: AbortSignal }
+  ): BamlStream<(number | null), number> {
+    const cancel = cancelTokenFor(__baml_options__?.signal)
     try {
       const raw = this.runtime.streamFunction(
         "UseMalformedConstraints",
@@ -5655,15 +6834,18 @@ class BamlStreamClient {
         this.ctx_manager.cloneContext(),
         __baml_options__?.tb?.__tb(),
         __baml_options__?.clientRegistry,
+        __baml_options__?.collector,
+        cancel.token,
       )
-      return new BamlStream<RecursivePartialNull<number>, number>(
+      return new BamlStream<(number | null), number>(
         raw,
-        (a): a is RecursivePartialNull<number> => a,
+        (a): a is (number | null) => a,
         (a): a is number => a,
         this.ctx_manager.cloneContext(),
-        __baml_options__?.tb?.__tb(),
+        cancel.release,
       )
     } catch (error) {
+      cancel.release()
       if (error instanceof Error) {
         const bamlError = createBamlValidationError(error);
         if (bamlError instanceof BamlValidationError) {
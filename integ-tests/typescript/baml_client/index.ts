@@ -19,6 +19,7 @@ $ pnpm add @boundaryml/baml
 export { b } from "./async_client"
 
 export * from "./types"
+export * as partial_types from "./partial_types"
 export * from "./tracing"
 export { resetBamlEnvVars } from "./globals"
-export { BamlValidationError, Checked } from "@boundaryml/baml"
\ No newline at end of file
+export { BamlValidationError, Checked, StreamState } from "@boundaryml/baml"
\ No newline at end of file
@@ -15,7 +15,7 @@ $ pnpm add @boundaryml/baml
 // tslint:disable
 // @ts-nocheck
 // biome-ignore format: autogenerated code
-import { BamlRuntime, FunctionResult, BamlCtxManager, BamlSyncStream, Image, ClientRegistry } from "@boundaryml/baml"
+import { BamlRuntime, FunctionResult, BamlCtxManager, BamlSyncStream, Image, ClientRegistry, Collector } from "@boundaryml/baml"
 import {BigNumbers, Blah, BookOrder, ClassOptionalOutput, ClassOptionalOutput2, ClassWithImage, CompoundBigNumbers, ContactInfo, CustomTaskResult, DummyOutput, DynInputOutput, DynamicClassOne, DynamicClassTwo, DynamicOutput, Earthling, Education, Email, EmailAddress, Event, FakeImage, FlightConfirmation, FooAny, GroceryReceipt, InnerClass, InnerClass2, InputClass, InputClassNested, MalformedConstraints, MalformedConstraints2, Martian, NamedArgsSingleClass, Nested, Nested2, OptionalTest_Prop1, OptionalTest_ReturnType, OrderInfo, OriginalA, OriginalB, Person, PhoneNumber, Quantity, RaysData, ReceiptInfo, ReceiptItem, Recipe, Resume, Schema, SearchParams, SomeClassNestedDynamic, StringToClassEntry, TestClassAlias, TestClassNested, TestClassWithEnum, TestOutputClass, TwoStoriesOneTitle, UnionTest_ReturnType, WithReasoning, AliasedEnum, Category, Category2, Category3, Color, DataType, DynEnumOne, DynEnumTwo, EnumInClass, EnumOutput, Hobby, NamedArgsSingleEnum, NamedArgsSingleEnumList, OptionalTest_CategoryType, OrderStatus, Tag, TestEnum} from "./types"
 import TypeBuilder from "./type_builder"
 import { DO_NOT_USE_DIRECTLY_UNLESS_YOU_KNOW_WHAT_YOURE_DOING_CTX, DO_NOT_USE_DIRECTLY_UNLESS_YOU_KNOW_WHAT_YOURE_DOING_RUNTIME } from "./globals"
@@ -44,7 +44,7 @@ export class BamlSyncClient {
   
   AaaSamOutputFormat(
       recipe: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): Recipe {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -55,6 +55,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as Recipe
     } catch (error: any) {
@@ -69,7 +70,7 @@ export class BamlSyncClient {
   
   AliasedInputClass(
       input: InputClass,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -80,6 +81,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -94,7 +96,7 @@ export class BamlSyncClient {
   
   AliasedInputClass2(
       input: InputClass,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -105,6 +107,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -119,7 +122,7 @@ export class BamlSyncClient {
   
   AliasedInputClassNested(
       input: InputClassNested,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -130,6 +133,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -144,7 +148,7 @@ export class BamlSyncClient {
   
   AliasedInputEnum(
       input: AliasedEnum,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -155,6 +159,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -169,7 +174,7 @@ export class BamlSyncClient {
   
   AliasedInputList(
       input: AliasedEnum[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -180,6 +185,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -194,7 +200,7 @@ export class BamlSyncClient {
   
   AudioInput(
       aud: Audio,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -205,6 +211,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -219,7 +226,7 @@ export class BamlSyncClient {
   
   ClassifyDynEnumTwo(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): (string | DynEnumTwo) {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -230,6 +237,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as (string | DynEnumTwo)
     } catch (error: any) {
@@ -244,7 +252,7 @@ export class BamlSyncClient {
   
   ClassifyMessage(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): Category {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -255,6 +263,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as Category
     } catch (error: any) {
@@ -269,7 +278,7 @@ export class BamlSyncClient {
   
   ClassifyMessage2(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): Category {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -280,6 +289,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as Category
     } catch (error: any) {
@@ -294,7 +304,7 @@ export class BamlSyncClient {
   
   ClassifyMessage3(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): Category {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -305,6 +315,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as Category
     } catch (error: any) {
@@ -319,7 +330,7 @@ export class BamlSyncClient {
   
   CustomTask(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): BookOrder | FlightConfirmation | GroceryReceipt {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -330,6 +341,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as BookOrder | FlightConfirmation | GroceryReceipt
     } catch (error: any) {
@@ -344,7 +356,7 @@ export class BamlSyncClient {
   
   DescribeImage(
       img: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -355,6 +367,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -369,7 +382,7 @@ export class BamlSyncClient {
   
   DescribeImage2(
       classWithImage: ClassWithImage,img2: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -380,6 +393,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -394,7 +408,7 @@ export class BamlSyncClient {
   
   DescribeImage3(
       classWithImage: ClassWithImage,img2: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -405,6 +419,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -419,7 +434,7 @@ export class BamlSyncClient {
   
   DescribeImage4(
       classWithImage: ClassWithImage,img2: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -430,6 +445,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -444,7 +460,7 @@ export class BamlSyncClient {
   
   DifferentiateUnions(
       
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): OriginalA | OriginalB {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -455,6 +471,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as OriginalA | OriginalB
     } catch (error: any) {
@@ -469,7 +486,7 @@ export class BamlSyncClient {
   
   DummyOutputFunction(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): DummyOutput {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -480,6 +497,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as DummyOutput
     } catch (error: any) {
@@ -494,7 +512,7 @@ export class BamlSyncClient {
   
   DynamicFunc(
       input: DynamicClassOne,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): DynamicClassTwo {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -505,6 +523,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as DynamicClassTwo
     } catch (error: any) {
@@ -519,7 +538,7 @@ export class BamlSyncClient {
   
   DynamicInputOutput(
       input: DynInputOutput,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): DynInputOutput {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -530,6 +549,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as DynInputOutput
     } catch (error: any) {
@@ -544,7 +564,7 @@ export class BamlSyncClient {
   
   DynamicListInputOutput(
       input: DynInputOutput[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): DynInputOutput[] {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -555,6 +575,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as DynInputOutput[]
     } catch (error: any) {
@@ -569,7 +590,7 @@ export class BamlSyncClient {
   
   ExpectFailure(
       
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -580,6 +601,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -594,7 +616,7 @@ export class BamlSyncClient {
   
   ExtractContactInfo(
       document: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): ContactInfo {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -605,6 +627,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as ContactInfo
     } catch (error: any) {
@@ -619,7 +642,7 @@ export class BamlSyncClient {
   
   ExtractHobby(
       text: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): (string | Hobby)[] {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -630,6 +653,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as (string | Hobby)[]
     } catch (error: any) {
@@ -644,7 +668,7 @@ export class BamlSyncClient {
   
   ExtractNames(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string[] {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -655,6 +679,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string[]
     } catch (error: any) {
@@ -669,7 +694,7 @@ export class BamlSyncClient {
   
   ExtractPeople(
       text: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): Person[] {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -680,6 +705,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as Person[]
     } catch (error: any) {
@@ -694,7 +720,7 @@ export class BamlSyncClient {
   
   ExtractReceiptInfo(
       email: string,reason: "curiosity" | "personal_finance",
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): ReceiptInfo {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -705,6 +731,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as ReceiptInfo
     } catch (error: any) {
@@ -719,7 +746,7 @@ export class BamlSyncClient {
   
   ExtractResume(
       resume: string,img?: Image | null,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): Resume {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -730,6 +757,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as Resume
     } catch (error: any) {
@@ -744,7 +772,7 @@ export class BamlSyncClient {
   
   ExtractResume2(
       resume: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): Resume {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -755,6 +783,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as Resume
     } catch (error: any) {
@@ -769,7 +798,7 @@ export class BamlSyncClient {
   
   FnClassOptionalOutput(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): ClassOptionalOutput | null {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -780,6 +809,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as ClassOptionalOutput | null
     } catch (error: any) {
@@ -794,7 +824,7 @@ export class BamlSyncClient {
   
   FnClassOptionalOutput2(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): ClassOptionalOutput2 | null {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -805,6 +835,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as ClassOptionalOutput2 | null
     } catch (error: any) {
@@ -819,7 +850,7 @@ export class BamlSyncClient {
   
   FnEnumListOutput(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): EnumOutput[] {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -830,6 +861,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as EnumOutput[]
     } catch (error: any) {
@@ -844,7 +876,7 @@ export class BamlSyncClient {
   
   FnEnumOutput(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): EnumOutput {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -855,6 +887,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as EnumOutput
     } catch (error: any) {
@@ -869,7 +902,7 @@ export class BamlSyncClient {
   
   FnNamedArgsSingleStringOptional(
       myString?: string | null,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -880,6 +913,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -894,7 +928,7 @@ export class BamlSyncClient {
   
   FnOutputBool(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): boolean {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -905,6 +939,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as boolean
     } catch (error: any) {
@@ -919,7 +954,7 @@ export class BamlSyncClient {
   
   FnOutputClass(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): TestOutputClass {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -930,6 +965,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as TestOutputClass
     } catch (error: any) {
@@ -944,7 +980,7 @@ export class BamlSyncClient {
   
   FnOutputClassList(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): TestOutputClass[] {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -955,6 +991,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as TestOutputClass[]
     } catch (error: any) {
@@ -969,7 +1006,7 @@ export class BamlSyncClient {
   
   FnOutputClassNested(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): TestClassNested {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -980,6 +1017,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as TestClassNested
     } catch (error: any) {
@@ -994,7 +1032,7 @@ export class BamlSyncClient {
   
   FnOutputClassWithEnum(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): TestClassWithEnum {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1005,6 +1043,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as TestClassWithEnum
     } catch (error: any) {
@@ -1019,7 +1058,7 @@ export class BamlSyncClient {
   
   FnOutputStringList(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string[] {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1030,6 +1069,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string[]
     } catch (error: any) {
@@ -1044,7 +1084,7 @@ export class BamlSyncClient {
   
   FnTestAliasedEnumOutput(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): TestEnum {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1055,6 +1095,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as TestEnum
     } catch (error: any) {
@@ -1069,7 +1110,7 @@ export class BamlSyncClient {
   
   FnTestClassAlias(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): TestClassAlias {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1080,6 +1121,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as TestClassAlias
     } catch (error: any) {
@@ -1094,7 +1136,7 @@ export class BamlSyncClient {
   
   FnTestNamedArgsSingleEnum(
       myArg: NamedArgsSingleEnum,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1105,6 +1147,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -1119,7 +1162,7 @@ export class BamlSyncClient {
   
   GetDataType(
       text: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): RaysData {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1130,6 +1173,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as RaysData
     } catch (error: any) {
@@ -1144,7 +1188,7 @@ export class BamlSyncClient {
   
   GetOrderInfo(
       email: Email,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): OrderInfo {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1155,6 +1199,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as OrderInfo
     } catch (error: any) {
@@ -1169,7 +1214,7 @@ export class BamlSyncClient {
   
   GetQuery(
       query: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): SearchParams {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1180,6 +1225,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as SearchParams
     } catch (error: any) {
@@ -1194,7 +1240,7 @@ export class BamlSyncClient {
   
   MyFunc(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): DynamicOutput {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1205,6 +1251,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as DynamicOutput
     } catch (error: any) {
@@ -1219,7 +1266,7 @@ export class BamlSyncClient {
   
   OptionalTest_Function(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): (OptionalTest_ReturnType | null)[] {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1230,6 +1277,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as (OptionalTest_ReturnType | null)[]
     } catch (error: any) {
@@ -1244,7 +1292,7 @@ export class BamlSyncClient {
   
   PredictAge(
       name: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): FooAny {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1255,6 +1303,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as FooAny
     } catch (error: any) {
@@ -1269,7 +1318,7 @@ export class BamlSyncClient {
   
   PredictAgeBare(
       inp: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): Checked<number,"too_big"> {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1280,6 +1329,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as Checked<number,"too_big">
     } catch (error: any) {
@@ -1294,7 +1344,7 @@ export class BamlSyncClient {
   
   PromptTestClaude(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1305,6 +1355,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -1319,7 +1370,7 @@ export class BamlSyncClient {
   
   PromptTestClaudeChat(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1330,6 +1381,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -1344,7 +1396,7 @@ export class BamlSyncClient {
   
   PromptTestClaudeChatNoSystem(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1355,6 +1407,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -1369,7 +1422,7 @@ export class BamlSyncClient {
   
   PromptTestOpenAI(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1380,6 +1433,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -1394,7 +1448,7 @@ export class BamlSyncClient {
   
   PromptTestOpenAIChat(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1405,6 +1459,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -1419,7 +1474,7 @@ export class BamlSyncClient {
   
   PromptTestOpenAIChatNoSystem(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1430,6 +1485,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -1444,7 +1500,7 @@ export class BamlSyncClient {
   
   PromptTestStreaming(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1455,6 +1511,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -1469,7 +1526,7 @@ export class BamlSyncClient {
   
   ReturnFailingAssert(
       inp: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): number {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1480,6 +1537,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as number
     } catch (error: any) {
@@ -1494,7 +1552,7 @@ export class BamlSyncClient {
   
   ReturnMalformedConstraints(
       a: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): MalformedConstraints {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1505,6 +1563,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as MalformedConstraints
     } catch (error: any) {
@@ -1519,7 +1578,7 @@ export class BamlSyncClient {
   
   SchemaDescriptions(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): Schema {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1530,6 +1589,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as Schema
     } catch (error: any) {
@@ -1544,7 +1604,7 @@ export class BamlSyncClient {
   
   StreamBigNumbers(
       digits: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): BigNumbers {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1555,6 +1615,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as BigNumbers
     } catch (error: any) {
@@ -1569,7 +1630,7 @@ export class BamlSyncClient {
   
   StreamFailingAssertion(
       theme: string,length: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): TwoStoriesOneTitle {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1580,6 +1641,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as TwoStoriesOneTitle
     } catch (error: any) {
@@ -1594,7 +1656,7 @@ export class BamlSyncClient {
   
   StreamOneBigNumber(
       digits: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): number {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1605,6 +1667,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as number
     } catch (error: any) {
@@ -1619,7 +1682,7 @@ export class BamlSyncClient {
   
   StreamUnionIntegers(
       digits: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): (number | string)[] {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1630,6 +1693,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as (number | string)[]
     } catch (error: any) {
@@ -1644,7 +1708,7 @@ export class BamlSyncClient {
   
   StreamingCompoundNumbers(
       digits: number,yapping: boolean,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): CompoundBigNumbers {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1655,6 +1719,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as CompoundBigNumbers
     } catch (error: any) {
@@ -1669,7 +1734,7 @@ export class BamlSyncClient {
   
   TestAnthropic(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1680,6 +1745,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -1694,7 +1760,7 @@ export class BamlSyncClient {
   
   TestAnthropicShorthand(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1705,6 +1771,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -1719,7 +1786,7 @@ export class BamlSyncClient {
   
   TestAws(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1730,6 +1797,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -1744,7 +1812,7 @@ export class BamlSyncClient {
   
   TestAzure(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1755,6 +1823,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -1769,7 +1838,7 @@ export class BamlSyncClient {
   
   TestCaching(
       input: string,not_cached: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1780,6 +1849,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -1794,7 +1864,7 @@ export class BamlSyncClient {
   
   TestFallbackClient(
       
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1805,6 +1875,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -1819,7 +1890,7 @@ export class BamlSyncClient {
   
   TestFallbackToShorthand(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1830,6 +1901,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -1844,7 +1916,7 @@ export class BamlSyncClient {
   
   TestFnNamedArgsSingleBool(
       myBool: boolean,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1855,6 +1927,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -1869,7 +1942,7 @@ export class BamlSyncClient {
   
   TestFnNamedArgsSingleClass(
       myArg: NamedArgsSingleClass,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1880,6 +1953,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -1894,7 +1968,7 @@ export class BamlSyncClient {
   
   TestFnNamedArgsSingleEnumList(
       myArg: NamedArgsSingleEnumList[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1905,6 +1979,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -1919,7 +1994,7 @@ export class BamlSyncClient {
   
   TestFnNamedArgsSingleFloat(
       myFloat: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1930,6 +2005,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -1944,7 +2020,7 @@ export class BamlSyncClient {
   
   TestFnNamedArgsSingleInt(
       myInt: number,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1955,6 +2031,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -1969,7 +2046,7 @@ export class BamlSyncClient {
   
   TestFnNamedArgsSingleMapStringToClass(
       myMap: Record<string, StringToClassEntry>,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): Record<string, StringToClassEntry> {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -1980,6 +2057,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as Record<string, StringToClassEntry>
     } catch (error: any) {
@@ -1994,7 +2072,7 @@ export class BamlSyncClient {
   
   TestFnNamedArgsSingleMapStringToMap(
       myMap: Record<string, Record<string, string>>,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): Record<string, Record<string, string>> {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -2005,6 +2083,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as Record<string, Record<string, string>>
     } catch (error: any) {
@@ -2019,7 +2098,7 @@ export class BamlSyncClient {
   
   TestFnNamedArgsSingleMapStringToString(
       myMap: Record<string, string>,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): Record<string, string> {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -2030,6 +2109,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as Record<string, string>
     } catch (error: any) {
@@ -2044,7 +2124,7 @@ export class BamlSyncClient {
   
   TestFnNamedArgsSingleString(
       myString: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -2055,6 +2135,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -2069,7 +2150,7 @@ export class BamlSyncClient {
   
   TestFnNamedArgsSingleStringArray(
       myStringArray: string[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -2080,6 +2161,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -2094,7 +2176,7 @@ export class BamlSyncClient {
   
   TestFnNamedArgsSingleStringList(
       myArg: string[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -2105,6 +2187,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -2119,7 +2202,7 @@ export class BamlSyncClient {
   
   TestGemini(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -2130,6 +2213,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -2144,7 +2228,7 @@ export class BamlSyncClient {
   
   TestImageInput(
       img: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -2155,6 +2239,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -2169,7 +2254,7 @@ export class BamlSyncClient {
   
   TestImageInputAnthropic(
       img: Image,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -2180,6 +2265,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -2194,7 +2280,7 @@ export class BamlSyncClient {
   
   TestImageListInput(
       imgs: Image[],
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -2205,6 +2291,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -2219,7 +2306,7 @@ export class BamlSyncClient {
   
   TestMulticlassNamedArgs(
       myArg: NamedArgsSingleClass,myArg2: NamedArgsSingleClass,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -2230,6 +2317,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -2244,7 +2332,7 @@ export class BamlSyncClient {
   
   TestOllama(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -2255,6 +2343,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -2269,7 +2358,7 @@ export class BamlSyncClient {
   
   TestOpenAILegacyProvider(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -2280,6 +2369,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -2294,7 +2384,7 @@ export class BamlSyncClient {
   
   TestOpenAIShorthand(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -2305,6 +2395,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -2319,7 +2410,7 @@ export class BamlSyncClient {
   
   TestRetryConstant(
       
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -2330,6 +2421,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -2344,7 +2436,7 @@ export class BamlSyncClient {
   
   TestRetryExponential(
       
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -2355,6 +2447,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -2369,7 +2462,7 @@ export class BamlSyncClient {
   
   TestSingleFallbackClient(
       
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -2380,6 +2473,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -2394,7 +2488,7 @@ export class BamlSyncClient {
   
   TestVertex(
       input: string,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): string {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -2405,6 +2499,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as string
     } catch (error: any) {
@@ -2419,7 +2514,7 @@ export class BamlSyncClient {
   
   UnionTest_Function(
       input: string | boolean,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): UnionTest_ReturnType {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -2430,6 +2525,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as UnionTest_ReturnType
     } catch (error: any) {
@@ -2444,7 +2540,7 @@ export class BamlSyncClient {
   
   UseMalformedConstraints(
       a: MalformedConstraints2,
-      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry }
+      __baml_options__?: { tb?: TypeBuilder, clientRegistry?: ClientRegistry, collector?: Collector }
   ): number {
     try {
     const raw = this.runtime.callFunctionSync(
@@ -2455,6 +2551,7 @@ export class BamlSyncClient {
       this.ctx_manager.cloneContext(),
       __baml_options__?.tb?.__tb(),
       __baml_options__?.clientRegistry,
+      __baml_options__?.collector,
     )
     return raw.parsed() as number
     } catch (error: any) {